@@ -0,0 +1,608 @@
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  fmt::{self, Display},
+  path::{Component, Path, PathBuf},
+  sync::{Arc, Mutex},
+};
+
+use serde_json::{json, Value};
+use stylex_swc_plugin::{
+  shared::{
+    constants::{
+      long_hand_logical::LONG_HAND_LOGICAL, long_hand_physical::LONG_HAND_PHYSICAL,
+      shorthands_of_longhands::SHORTHANDS_OF_LONGHANDS,
+      shorthands_of_shorthands::SHORTHANDS_OF_SHORTHANDS,
+    },
+    structures::{
+      call_site::CallSite,
+      meta_data::MetaData,
+      module_cache::resolve_module_path,
+      plugin_pass::PluginPass,
+      stylex_options::{StyleXOptions, StyleXOptionsParams},
+      var_usage_graph::VarUsageGraph,
+    },
+    utils::{
+      css::common::swc_parse_css,
+      swc_compat::resolve_module,
+      syntax::{strip_bom, syntax_for_path},
+    },
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::{
+    comments::SingleThreadedComments,
+    errors::{DiagnosticBuilder, Emitter, Handler, Level, HANDLER},
+    sync::Lrc,
+    BytePos, FileName, Globals, SourceMap, GLOBALS,
+  },
+  css::{
+    ast::{Declaration, DeclarationName},
+    visit::{Visit, VisitWith},
+  },
+  ecma::{
+    ast::{Module, ModuleDecl},
+    parser::{Parser, StringInput},
+  },
+};
+use walkdir::WalkDir;
+
+const EXTENSIONS: [&str; 8] = ["js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticKind {
+  /// The file could not be parsed.
+  Parse,
+  /// The transform rejected a StyleX call.
+  Transform,
+  /// The transform warned about a StyleX call.
+  TransformWarning,
+  /// A StyleX call could not be compiled away and is left to run.
+  Deopt,
+  /// A compiled rule uses a property StyleX has no priority for.
+  UnknownProperty,
+  /// A `stylex.defineVars()` key no checked file references.
+  UnusedToken,
+  /// The same declaration is repeated across many namespaces.
+  DuplicateValue,
+  /// A relative import doesn't resolve to a file.
+  UnresolvedImport,
+}
+
+impl DiagnosticKind {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      DiagnosticKind::Parse => "parse",
+      DiagnosticKind::Transform => "transform",
+      DiagnosticKind::TransformWarning => "transform-warning",
+      DiagnosticKind::Deopt => "deopt",
+      DiagnosticKind::UnknownProperty => "unknown-property",
+      DiagnosticKind::UnusedToken => "unused-token",
+      DiagnosticKind::DuplicateValue => "duplicate-value",
+      DiagnosticKind::UnresolvedImport => "unresolved-import",
+    }
+  }
+
+  pub fn is_error(&self) -> bool {
+    !matches!(
+      self,
+      DiagnosticKind::TransformWarning
+        | DiagnosticKind::Deopt
+        | DiagnosticKind::UnknownProperty
+        | DiagnosticKind::UnusedToken
+        | DiagnosticKind::DuplicateValue
+        | DiagnosticKind::UnresolvedImport
+    )
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+  pub file: PathBuf,
+  pub kind: DiagnosticKind,
+  pub message: String,
+}
+
+impl Diagnostic {
+  pub fn to_json(&self) -> Value {
+    json!({
+      "file": self.file.display().to_string(),
+      "severity": if self.kind.is_error() { "error" } else { "warning" },
+      "kind": self.kind.as_str(),
+      "message": self.message,
+    })
+  }
+}
+
+impl Display for Diagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let severity = if self.kind.is_error() {
+      "error"
+    } else {
+      "warning"
+    };
+
+    write!(
+      f,
+      "{}: {}[{}]: {}",
+      self.file.display(),
+      severity,
+      self.kind.as_str(),
+      self.message
+    )
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct CheckReport {
+  pub files: usize,
+  pub diagnostics: Vec<Diagnostic>,
+  /// `stylex.defineVars()` keys referenced by the checked files.
+  pub var_usage: VarUsageGraph,
+  /// `stylex.defineVars()` keys declared by each checked file.
+  pub defined_vars: Vec<(PathBuf, VarUsageGraph)>,
+  /// The compiled declarations of the checked files, by class name.
+  pub declarations: BTreeMap<String, DeclarationUsage>,
+  /// The compiled rules and dependencies of each checked file.
+  pub modules: BTreeMap<PathBuf, CheckedFile>,
+}
+
+/// What a checked file compiles to and depends on.
+#[derive(Debug, Default)]
+pub struct CheckedFile {
+  pub rules: Vec<MetaData>,
+  /// Scripts the file imports, resolved like Node.js resolves them.
+  pub imports: Vec<PathBuf>,
+  /// `stylex.defineVars()` keys the file references.
+  pub var_usage: VarUsageGraph,
+}
+
+/// A compiled declaration and the `stylex.create()` namespaces using it.
+#[derive(Debug, Default)]
+pub struct DeclarationUsage {
+  pub css: String,
+  /// Files and `<variable>.<namespace>` of the namespaces.
+  pub namespaces: Vec<(PathBuf, String)>,
+}
+
+impl CheckReport {
+  /// Reports the declarations used by at least `min_namespaces` namespaces,
+  /// which could be extracted into a shared style.
+  pub fn add_duplicate_values(&mut self, min_namespaces: usize) {
+    for usage in self.declarations.values() {
+      if usage.namespaces.len() < min_namespaces.max(2) {
+        continue;
+      }
+
+      let namespaces = usage
+        .namespaces
+        .iter()
+        .map(|(file, namespace)| format!("{}:{}", file.display(), namespace))
+        .collect::<Vec<_>>();
+
+      self.diagnostics.push(Diagnostic {
+        file: usage.namespaces[0].0.clone(),
+        kind: DiagnosticKind::DuplicateValue,
+        message: format!(
+          "\"{}\" is declared in {} namespaces ({}), consider extracting it into a shared style",
+          usage.css,
+          namespaces.len(),
+          namespaces.join(", ")
+        ),
+      });
+    }
+  }
+
+  /// Reports the declared `stylex.defineVars()` keys none of the checked
+  /// files reference. Only meaningful when every consumer was checked.
+  pub fn add_unused_tokens(&mut self) {
+    for (file, defined_vars) in &self.defined_vars {
+      for (file_name, export_name, key) in defined_vars.entries() {
+        if !self.var_usage.contains(file_name, export_name, key) {
+          self.diagnostics.push(Diagnostic {
+            file: file.clone(),
+            kind: DiagnosticKind::UnusedToken,
+            message: format!("Unused token \"{}.{}\"", export_name, key),
+          });
+        }
+      }
+    }
+  }
+
+  /// The compiled rules of the checked files, in file order.
+  pub fn rules(&self) -> Vec<MetaData> {
+    self
+      .modules
+      .values()
+      .flat_map(|module| module.rules.iter().cloned())
+      .collect()
+  }
+
+  pub fn errors(&self) -> usize {
+    self
+      .diagnostics
+      .iter()
+      .filter(|diagnostic| diagnostic.kind.is_error())
+      .count()
+  }
+
+  pub fn warnings(&self) -> usize {
+    self.diagnostics.len() - self.errors()
+  }
+
+  pub fn to_json(&self) -> Value {
+    json!({
+      "files": self.files,
+      "errors": self.errors(),
+      "warnings": self.warnings(),
+      "diagnostics": self.diagnostics.iter().map(Diagnostic::to_json).collect::<Vec<_>>(),
+    })
+  }
+}
+
+impl Display for CheckReport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for diagnostic in &self.diagnostics {
+      writeln!(f, "{}", diagnostic)?;
+    }
+
+    write!(
+      f,
+      "Checked {} file(s): {} error(s), {} warning(s)",
+      self.files,
+      self.errors(),
+      self.warnings()
+    )
+  }
+}
+
+/// Runs the StyleX transform on every script under `paths` without writing
+/// any output, collecting what it reports.
+pub fn check_paths(paths: &[PathBuf], config: &StyleXOptionsParams) -> CheckReport {
+  let mut report = CheckReport::default();
+
+  for path in paths {
+    for entry in WalkDir::new(path)
+      .follow_links(true)
+      .sort_by_file_name()
+      .into_iter()
+      .filter_entry(|entry| entry.file_name() != "node_modules")
+      .filter_map(Result::ok)
+    {
+      if !entry.file_type().is_file() || !is_script(entry.path()) {
+        continue;
+      }
+
+      report.files += 1;
+
+      match std::fs::read_to_string(entry.path()) {
+        Ok(source) => {
+          let checked = check_module(&source, entry.path(), config);
+
+          report.diagnostics.extend(checked.diagnostics);
+          report.var_usage.merge(&checked.var_usage);
+
+          if !checked.defined_vars.is_empty() {
+            report
+              .defined_vars
+              .push((entry.path().to_path_buf(), checked.defined_vars));
+          }
+
+          for (class_name, css, namespaces) in checked.declarations {
+            let usage = report.declarations.entry(class_name).or_default();
+
+            usage.css = css;
+            usage.namespaces.extend(
+              namespaces
+                .into_iter()
+                .map(|namespace| (entry.path().to_path_buf(), namespace)),
+            );
+          }
+
+          report.modules.insert(
+            entry.path().to_path_buf(),
+            CheckedFile {
+              rules: checked.rules,
+              imports: checked.imports,
+              var_usage: checked.var_usage,
+            },
+          );
+        }
+        Err(error) => report.diagnostics.push(Diagnostic {
+          file: entry.path().to_path_buf(),
+          kind: DiagnosticKind::Parse,
+          message: error.to_string(),
+        }),
+      }
+    }
+  }
+
+  report
+}
+
+/// Transforms `source` as the file at `path` and reports its diagnostics.
+pub fn check_source(source: &str, path: &Path, config: &StyleXOptionsParams) -> Vec<Diagnostic> {
+  check_module(source, path, config).diagnostics
+}
+
+#[derive(Debug, Default)]
+struct CheckedModule {
+  diagnostics: Vec<Diagnostic>,
+  var_usage: VarUsageGraph,
+  defined_vars: VarUsageGraph,
+  /// Class name, CSS and namespaces of each compiled declaration.
+  declarations: Vec<(String, String, Vec<String>)>,
+  rules: Vec<MetaData>,
+  /// Scripts the imports and re-exports of the module resolve to.
+  imports: Vec<PathBuf>,
+}
+
+fn check_module(source: &str, path: &Path, config: &StyleXOptionsParams) -> CheckedModule {
+  let diagnostic = |kind, message| Diagnostic {
+    file: path.to_path_buf(),
+    kind,
+    message,
+  };
+
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Real(path.to_path_buf()), strip_bom(source).into());
+
+  let module =
+    match Parser::new(syntax_for_path(path), StringInput::from(&*fm), None).parse_module() {
+      Ok(module) => module,
+      Err(error) => {
+        return CheckedModule {
+          diagnostics: vec![diagnostic(
+            DiagnosticKind::Parse,
+            error.kind().msg().to_string(),
+          )],
+          ..Default::default()
+        }
+      }
+    };
+
+  let (imports, unresolved_imports) = resolve_imports(&module, path);
+
+  let plugin_pass = PluginPass {
+    cwd: std::env::current_dir().ok(),
+    filename: FileName::Real(path.canonicalize().unwrap_or_else(|_| path.to_path_buf())),
+  };
+
+  let mut options = StyleXOptions::from(config.clone());
+
+  // Calls left to run are found through the call sites of the module
+  options.call_sites = true;
+
+  // Errors and warnings are reported through the SWC diagnostics, errors are
+  // raised as panics as well
+  let reported = Arc::new(Mutex::new(vec![]));
+  let handler = Handler::with_emitter(true, false, Box::new(ReportedDiagnostics(reported.clone())));
+
+  let result = GLOBALS.set(&Globals::new(), || {
+    HANDLER.set(&handler, || {
+      let mut visitor = ModuleTransformVisitor::new_with_options(
+        SingleThreadedComments::default(),
+        plugin_pass,
+        options,
+      );
+
+      visitor.try_fold_module(resolve_module(module))?;
+
+      Ok((
+        used_properties(&visitor),
+        visitor.state().var_usage().clone(),
+        visitor.state().defined_vars().clone(),
+        declarations(&visitor),
+        visitor
+          .state()
+          .injected_rules()
+          .into_iter()
+          .cloned()
+          .collect::<Vec<_>>(),
+        visitor.state().call_sites().to_vec(),
+      ))
+    })
+  });
+
+  let mut diagnostics = reported
+    .lock()
+    .unwrap()
+    .drain(..)
+    .map(|(kind, message)| diagnostic(kind, message))
+    .collect::<Vec<_>>();
+
+  diagnostics.extend(unresolved_imports.into_iter().map(|specifier| {
+    diagnostic(
+      DiagnosticKind::UnresolvedImport,
+      format!("Cannot resolve the import of \"{}\"", specifier),
+    )
+  }));
+
+  match result {
+    Ok((properties, var_usage, defined_vars, declarations, rules, call_sites)) => {
+      diagnostics.extend(
+        call_sites
+          .iter()
+          .filter(|call_site| !call_site.is_static)
+          .map(|call_site| diagnostic(DiagnosticKind::Deopt, deopt_message(&cm, call_site))),
+      );
+
+      diagnostics.extend(
+        properties
+          .into_iter()
+          .filter(|property| !is_known_property(property))
+          .map(|property| {
+            diagnostic(
+              DiagnosticKind::UnknownProperty,
+              format!("Unknown CSS property \"{}\"", property),
+            )
+          }),
+      );
+
+      CheckedModule {
+        diagnostics,
+        var_usage,
+        defined_vars,
+        declarations,
+        rules,
+        imports,
+      }
+    }
+    Err(message) => {
+      // Errors reported at their span are raised with the same message
+      if !diagnostics
+        .iter()
+        .any(|reported| reported.kind == DiagnosticKind::Transform && reported.message == message)
+      {
+        diagnostics.push(diagnostic(DiagnosticKind::Transform, message));
+      }
+
+      CheckedModule {
+        diagnostics,
+        ..Default::default()
+      }
+    }
+  }
+}
+
+/// Collects the diagnostics the transform reports through its handler.
+struct ReportedDiagnostics(Arc<Mutex<Vec<(DiagnosticKind, String)>>>);
+
+impl Emitter for ReportedDiagnostics {
+  fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+    let kind = if db.is_error() {
+      DiagnosticKind::Transform
+    } else if db.level == Level::Warning {
+      DiagnosticKind::TransformWarning
+    } else {
+      return;
+    };
+
+    self.0.lock().unwrap().push((kind, db.message()));
+  }
+}
+
+/// Reports a call left to run at the line and column it starts at.
+fn deopt_message(cm: &SourceMap, call_site: &CallSite) -> String {
+  let location = cm.lookup_char_pos(BytePos(call_site.start));
+
+  format!(
+    "stylex.{}() call at {}:{} could not be compiled and is left to run at runtime",
+    call_site.api,
+    location.line,
+    location.col.0 + 1
+  )
+}
+
+fn used_properties(visitor: &ModuleTransformVisitor<SingleThreadedComments>) -> BTreeSet<String> {
+  let mut collector = PropertyCollector::default();
+
+  for rule in visitor.state().injected_rules() {
+    if let Ok(stylesheet) = swc_parse_css(rule.get_css()).0 {
+      stylesheet.visit_with(&mut collector);
+    }
+  }
+
+  collector.properties
+}
+
+fn declarations(
+  visitor: &ModuleTransformVisitor<SingleThreadedComments>,
+) -> Vec<(String, String, Vec<String>)> {
+  let state = visitor.state();
+
+  state
+    .injected_rules()
+    .into_iter()
+    .filter_map(|rule| {
+      let namespaces = state.class_name_namespaces().get(rule.get_class_name())?;
+
+      Some((
+        rule.get_class_name().to_string(),
+        rule.get_css().to_string(),
+        namespaces.clone(),
+      ))
+    })
+    .collect()
+}
+
+#[derive(Default)]
+pub(crate) struct PropertyCollector {
+  pub(crate) properties: BTreeSet<String>,
+}
+
+impl Visit for PropertyCollector {
+  fn visit_declaration(&mut self, declaration: &Declaration) {
+    if let DeclarationName::Ident(ident) = &declaration.name {
+      self.properties.insert(ident.value.to_string());
+    }
+  }
+}
+
+/// Resolves the imports and re-exports of `module`, the file at `path`, with
+/// the resolver the compiler follows imports with. Returns the scripts they
+/// resolve to and the relative specifiers that resolve to no file. Packages
+/// that aren't installed are left out.
+fn resolve_imports(module: &Module, path: &Path) -> (Vec<PathBuf>, Vec<String>) {
+  let file_path = path.display().to_string();
+
+  let mut imports = vec![];
+  let mut unresolved_imports = vec![];
+
+  let specifiers = module
+    .body
+    .iter()
+    .filter_map(|item| match item.as_module_decl()? {
+      ModuleDecl::Import(import) => Some(&import.src),
+      ModuleDecl::ExportAll(export) => Some(&export.src),
+      ModuleDecl::ExportNamed(export) => export.src.as_ref(),
+      _ => None,
+    })
+    .map(|src| src.value.to_string());
+
+  for specifier in specifiers {
+    match resolve_module_path(&specifier, &file_path) {
+      Some(import) if is_script(&import) => imports.push(normalize_path(&import)),
+      Some(_) => {}
+      None if specifier.starts_with('.') || specifier.starts_with('/') => {
+        unresolved_imports.push(specifier)
+      }
+      None => {}
+    }
+  }
+
+  (imports, unresolved_imports)
+}
+
+/// Resolves the `.` and `..` segments of `path` without touching the file
+/// system, so imported files match the paths they were checked with.
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+  let mut normalized = PathBuf::new();
+
+  for component in path.components() {
+    match component {
+      Component::CurDir => {}
+      Component::ParentDir if normalized.file_name().is_some() => {
+        normalized.pop();
+      }
+      component => normalized.push(component),
+    }
+  }
+
+  normalized
+}
+
+fn is_known_property(property: &str) -> bool {
+  property.starts_with('-')
+    || LONG_HAND_LOGICAL.contains(property)
+    || LONG_HAND_PHYSICAL.contains(property)
+    || SHORTHANDS_OF_LONGHANDS.contains(property)
+    || SHORTHANDS_OF_SHORTHANDS.contains(property)
+}
+
+pub(crate) fn is_script(path: &Path) -> bool {
+  path
+    .extension()
+    .and_then(|extension| extension.to_str())
+    .is_some_and(|extension| EXTENSIONS.contains(&extension))
+}
@@ -0,0 +1,57 @@
+use std::{
+  collections::{BTreeSet, VecDeque},
+  path::PathBuf,
+};
+
+use stylex_swc_plugin::shared::structures::meta_data::MetaData;
+
+use crate::check::{normalize_path, CheckReport};
+
+/// Checked files reachable from `entries`: the files they import, directly or
+/// not, and the files declaring the `stylex.defineVars()` keys those files
+/// reference. Entries are given like the checked paths, e.g.
+/// `src/pages/Home.tsx` for `src`.
+pub fn reachable_files(report: &CheckReport, entries: &[PathBuf]) -> BTreeSet<PathBuf> {
+  let mut reachable = BTreeSet::new();
+  let mut queue = entries
+    .iter()
+    .map(|entry| normalize_path(entry))
+    .collect::<VecDeque<PathBuf>>();
+
+  while let Some(file) = queue.pop_front() {
+    let Some(module) = report.modules.get(&file) else {
+      continue;
+    };
+
+    if !reachable.insert(file) {
+      continue;
+    }
+
+    queue.extend(module.imports.iter().cloned());
+
+    for (file_name, export_name, _) in module.var_usage.entries() {
+      queue.extend(
+        report
+          .defined_vars
+          .iter()
+          .filter(|(_, defined_vars)| defined_vars.keys(file_name, export_name).is_some())
+          .map(|(vars_file, _)| vars_file.clone()),
+      );
+    }
+  }
+
+  reachable
+}
+
+/// The compiled rules of the files reachable from `entries`, e.g. the
+/// critical CSS of server-rendered pages, without the rest of the stylesheet.
+pub fn critical_rules(report: &CheckReport, entries: &[PathBuf]) -> Vec<MetaData> {
+  let reachable = reachable_files(report, entries);
+
+  report
+    .modules
+    .iter()
+    .filter(|(file, _)| reachable.contains(*file))
+    .flat_map(|(_, module)| module.rules.iter().cloned())
+    .collect()
+}
@@ -0,0 +1,355 @@
+use std::{
+  collections::HashMap,
+  fs, io,
+  path::{Path, PathBuf},
+  str::FromStr,
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::{Map, Value};
+use stylex_swc_plugin::{
+  hash::create_hash,
+  shared::structures::{meta_data::MetaData, rule_registry::RuleRegistry},
+};
+
+/// Manifest written next to content hashed stylesheets, mapping their logical
+/// file names, e.g. `stylex.css`, to the hashed ones.
+pub const MANIFEST_FILE_NAME: &str = "stylex-manifest.json";
+
+/// Stylesheet of the compiled `rules`, without duplicates and sorted by
+/// priority, then by CSS.
+///
+/// Rules with an RTL variant are scoped to the direction of the document, as
+/// `html:not([dir='rtl'])` and `html[dir='rtl']` rules. Keyframes can't be
+/// scoped, since both variants share the animation name, so only their LTR
+/// variant is kept.
+pub fn stylesheet(rules: &[MetaData]) -> String {
+  let registry = RuleRegistry::new();
+
+  registry.extend(rules.iter().cloned());
+
+  registry
+    .snapshot()
+    .into_iter()
+    .flat_map(|rule| match rule.get_css_rtl() {
+      Some(rtl) if !rule.get_css().starts_with("@keyframes") => vec![
+        add_ancestor_selector(rule.get_css(), "html:not([dir='rtl'])"),
+        add_ancestor_selector(rtl, "html[dir='rtl']"),
+      ],
+      _ => vec![rule.get_css().to_string()],
+    })
+    .collect::<Vec<String>>()
+    .join("\n")
+}
+
+/// How an extracted stylesheet is split into several files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitBy {
+  /// By priority band: `base` (variables, themes and keyframes),
+  /// `shorthands`, `longhands` and `pseudo-elements`.
+  Priority,
+  /// By the media query rules are wrapped in, e.g. `min-width-768px` for
+  /// `@media (min-width: 768px)`, with the other rules in `base`. Rules
+  /// overriding a media query, e.g. a longhand after a media query of its
+  /// shorthand, go to a later part, e.g. `base-2`.
+  Media,
+}
+
+impl FromStr for SplitBy {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    match value {
+      "priority" => Ok(SplitBy::Priority),
+      "media" => Ok(SplitBy::Media),
+      _ => Err(format!(
+        "Expected \"priority\" or \"media\", but got: {}",
+        value
+      )),
+    }
+  }
+}
+
+/// Rules of one of the files of a split stylesheet.
+#[derive(Debug, Clone)]
+pub struct StylesheetPart {
+  /// Name of the part in its file name, e.g. `base` for `stylex.base.css`.
+  pub name: String,
+  /// Media query the rules are wrapped in, when split by media.
+  pub media: Option<String>,
+  pub rules: Vec<MetaData>,
+}
+
+/// Splits `rules` into parts in load order: runs of rules, sorted like
+/// [`stylesheet`] sorts them, that go to the same file. A file whose rules
+/// are interleaved with the rules of another one is split into several
+/// parts, e.g. `base` and `base-2` around a media query, so loading the
+/// parts in order keeps every rule at its priority.
+pub fn split_rules(rules: &[MetaData], split_by: SplitBy) -> Vec<StylesheetPart> {
+  let mut rules = rules.to_vec();
+
+  rules.sort_by(|a, b| {
+    a.get_priority()
+      .total_cmp(b.get_priority())
+      .then_with(|| a.get_css().cmp(b.get_css()))
+  });
+
+  let mut parts: Vec<StylesheetPart> = vec![];
+  let mut part_counts: HashMap<String, usize> = HashMap::new();
+  let mut current_name: Option<String> = None;
+
+  for rule in rules {
+    let (name, media) = match split_by {
+      SplitBy::Priority => (priority_band(*rule.get_priority()).to_string(), None),
+      SplitBy::Media => match media_query(rule.get_css()) {
+        Some(media) => (media_slug(&media), Some(media)),
+        None => ("base".to_string(), None),
+      },
+    };
+
+    match parts.last_mut() {
+      Some(part) if current_name.as_ref() == Some(&name) => part.rules.push(rule),
+      _ => {
+        let count = part_counts.entry(name.clone()).or_default();
+        *count += 1;
+
+        parts.push(StylesheetPart {
+          name: match *count {
+            1 => name.clone(),
+            count => format!("{}-{}", name, count),
+          },
+          media,
+          rules: vec![rule],
+        });
+
+        current_name = Some(name);
+      }
+    }
+  }
+
+  parts
+}
+
+/// Writes the parts of the stylesheet of `rules` next to `out`, e.g.
+/// `stylex.base.css` and `stylex.min-width-768px.css` for `stylex.css`, and
+/// an index stylesheet importing them, with their media queries, to `out`.
+/// Each part gets the banner and footer of `comments`.
+///
+/// Returns the paths of the written parts and of the index, last.
+pub fn write_split_stylesheets(
+  rules: &[MetaData],
+  split_by: SplitBy,
+  out: &Path,
+  content_hash: bool,
+  comments: &StylesheetComments,
+  date: &str,
+) -> io::Result<Vec<PathBuf>> {
+  let dir = out.parent().unwrap_or(Path::new(""));
+  let mut paths = vec![];
+  let mut imports = vec![];
+
+  for part in split_rules(rules, split_by) {
+    let css = comments.apply(&stylesheet(&part.rules), date);
+    let path = write_stylesheet(
+      &css,
+      &dir.join(file_name_with_segment(out, &part.name)),
+      content_hash,
+    )?;
+
+    let file_name = path
+      .file_name()
+      .map(|file_name| file_name.to_string_lossy().to_string())
+      .unwrap_or_default();
+
+    imports.push(match part.media {
+      Some(media) => format!("@import url(\"{}\") {};", file_name, media),
+      None => format!("@import url(\"{}\");", file_name),
+    });
+
+    paths.push(path);
+  }
+
+  paths.push(write_stylesheet(&imports.join("\n"), out, content_hash)?);
+
+  Ok(paths)
+}
+
+/// Comments added before and after an extracted stylesheet, e.g. build info
+/// or a license. `[hash]` is replaced with the hash of the rules and `[date]`
+/// with the build date, e.g. `2024-07-30`.
+///
+/// Texts that aren't comments yet are wrapped in `/* */`.
+#[derive(Debug, Default, Clone)]
+pub struct StylesheetComments {
+  pub banner: Option<String>,
+  pub footer: Option<String>,
+}
+
+impl StylesheetComments {
+  /// Adds the banner and footer to `css`, built on `date`.
+  pub fn apply(&self, css: &str, date: &str) -> String {
+    let hash = create_hash(css);
+
+    let comment = |text: &str| {
+      let text = text.replace("[hash]", &hash).replace("[date]", date);
+
+      if text.trim_start().starts_with("/*") {
+        text
+      } else {
+        format!("/* {} */", text)
+      }
+    };
+
+    let mut parts = vec![];
+
+    parts.extend(self.banner.as_deref().map(comment));
+    parts.push(css.to_string());
+    parts.extend(self.footer.as_deref().map(comment));
+
+    parts.join("\n")
+  }
+}
+
+/// Current UTC date, e.g. `2024-07-30`.
+pub fn current_date() -> String {
+  let days = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_secs() / 86_400)
+    .unwrap_or_default() as i64;
+
+  let (year, month, day) = civil_from_days(days);
+
+  format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Writes `css` to `out`. With `content_hash`, the stylesheet is named with a
+/// hash of its content instead, e.g. `stylex.1b3x9k.css`, and recorded in the
+/// [`MANIFEST_FILE_NAME`] manifest of its directory.
+///
+/// Returns the path of the written stylesheet.
+pub fn write_stylesheet(css: &str, out: &Path, content_hash: bool) -> io::Result<PathBuf> {
+  let dir = out.parent().unwrap_or(Path::new(""));
+
+  if !dir.as_os_str().is_empty() {
+    fs::create_dir_all(dir)?;
+  }
+
+  if !content_hash {
+    fs::write(out, css)?;
+
+    return Ok(out.to_path_buf());
+  }
+
+  let file_name = out
+    .file_name()
+    .map(|file_name| file_name.to_string_lossy().to_string())
+    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Output must be a file"))?;
+
+  let hashed_file_name = file_name_with_segment(out, &create_hash(css));
+  let hashed_path = dir.join(&hashed_file_name);
+
+  fs::write(&hashed_path, css)?;
+
+  let manifest_path = dir.join(MANIFEST_FILE_NAME);
+
+  // Other stylesheets of the directory keep their entries
+  let mut manifest = fs::read_to_string(&manifest_path)
+    .ok()
+    .and_then(|manifest| serde_json::from_str::<Map<String, Value>>(&manifest).ok())
+    .unwrap_or_default();
+
+  manifest.insert(file_name, Value::String(hashed_file_name));
+
+  fs::write(
+    &manifest_path,
+    serde_json::to_string_pretty(&manifest).map_err(io::Error::from)?,
+  )?;
+
+  Ok(hashed_path)
+}
+
+fn priority_band(priority: f64) -> &'static str {
+  match priority {
+    priority if priority < 1000.0 => "base",
+    priority if priority < 3000.0 => "shorthands",
+    priority if priority < 5000.0 => "longhands",
+    _ => "pseudo-elements",
+  }
+}
+
+fn media_query(css: &str) -> Option<String> {
+  let query = css.strip_prefix("@media")?;
+
+  Some(query[..query.find('{')?].trim().to_string())
+}
+
+/// File name friendly form of a media query, e.g. `min-width-768px` for
+/// `(min-width: 768px)`.
+fn media_slug(media: &str) -> String {
+  media
+    .to_lowercase()
+    .split(|char: char| !char.is_ascii_alphanumeric() && char != '.')
+    .filter(|segment| !segment.is_empty())
+    .collect::<Vec<&str>>()
+    .join("-")
+}
+
+/// File name of `out` with `segment` before its extension, e.g.
+/// `stylex.base.css` for `stylex.css`.
+fn file_name_with_segment(out: &Path, segment: &str) -> String {
+  let stem = out
+    .file_stem()
+    .map(|stem| stem.to_string_lossy().to_string())
+    .unwrap_or_default();
+
+  match out.extension() {
+    Some(extension) => format!("{}.{}.{}", stem, segment, extension.to_string_lossy()),
+    None => format!("{}.{}", stem, segment),
+  }
+}
+
+/// Gregorian date of the `days` since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+  let days = days + 719_468;
+  let era = days.div_euclid(146_097);
+  let day_of_era = days.rem_euclid(146_097);
+  let year_of_era =
+    (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let shifted_month = (5 * day_of_year + 2) / 153;
+  let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+  let month = if shifted_month < 10 {
+    shifted_month + 3
+  } else {
+    shifted_month - 9
+  } as u32;
+  let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+  (year, month, day)
+}
+
+/// Prefixes the selectors of `css` with `ancestor`, inside of its at-rules.
+fn add_ancestor_selector(css: &str, ancestor: &str) -> String {
+  if css.starts_with('@') {
+    if let (Some(start), Some(end)) = (css.find('{'), css.rfind('}')) {
+      return format!(
+        "{}{}{}",
+        &css[..=start],
+        add_ancestor_selector(&css[start + 1..end], ancestor),
+        &css[end..]
+      );
+    }
+  }
+
+  let Some(start) = css.find('{') else {
+    return css.to_string();
+  };
+
+  let selectors = css[..start]
+    .split(',')
+    .map(|selector| format!("{} {}", ancestor, selector.trim()))
+    .collect::<Vec<String>>()
+    .join(", ");
+
+  format!("{}{}", selectors, &css[start..])
+}
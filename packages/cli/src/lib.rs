@@ -0,0 +1,4 @@
+pub mod check;
+pub mod critical;
+pub mod extract;
+pub mod locate;
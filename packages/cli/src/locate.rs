@@ -0,0 +1,173 @@
+use std::{
+  collections::BTreeMap,
+  fmt::{self, Display},
+  fs,
+  path::PathBuf,
+};
+
+use serde_json::{json, Value};
+use stylex_swc_plugin::shared::{
+  structures::meta_data::MetaDataEnvelope, utils::css::common::swc_parse_css,
+};
+use swc_core::css::visit::VisitWith;
+use walkdir::WalkDir;
+
+use crate::check::{is_script, PropertyCollector};
+
+/// Where a compiled class name, e.g. `x1e2nbdu` from a bug report, is
+/// declared in the located modules.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ClassLocation {
+  pub class_name: String,
+  /// The compiled rule, empty when no located module declares the class name.
+  pub css: String,
+  /// The CSS properties the rule declares.
+  pub properties: Vec<String>,
+  /// Compiled modules and `<variable>.<namespace>` of the namespaces
+  /// declaring it.
+  pub namespaces: Vec<(PathBuf, String)>,
+}
+
+impl ClassLocation {
+  pub fn is_found(&self) -> bool {
+    !self.namespaces.is_empty()
+  }
+
+  pub fn to_json(&self) -> Value {
+    json!({
+      "className": self.class_name,
+      "css": self.css,
+      "properties": self.properties,
+      "namespaces": self
+        .namespaces
+        .iter()
+        .map(|(file, namespace)| json!({
+          "file": file.display().to_string(),
+          "namespace": namespace,
+        }))
+        .collect::<Vec<_>>(),
+    })
+  }
+}
+
+impl Display for ClassLocation {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if !self.is_found() {
+      return write!(
+        f,
+        "{}: not declared in the located modules",
+        self.class_name
+      );
+    }
+
+    write!(f, "{}: {}", self.class_name, self.css)?;
+
+    for (file, namespace) in &self.namespaces {
+      write!(
+        f,
+        "\n  {}: {} ({})",
+        file.display(),
+        namespace,
+        self.properties.join(", ")
+      )?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Reads the metadata the plugin adds to the compiled modules under `paths`
+/// and finds the `stylex.create()` namespaces declaring each of
+/// `class_names`. The modules must be compiled with the `exportStyles`
+/// option, which adds the namespaces of the class names to the metadata.
+/// Class names may be given as selectors, e.g. `.x1e2nbdu`.
+pub fn locate_class_names(class_names: &[String], paths: &[PathBuf]) -> Vec<ClassLocation> {
+  let mut declarations: BTreeMap<String, (String, Vec<(PathBuf, String)>)> = BTreeMap::new();
+
+  for path in paths {
+    for entry in WalkDir::new(path)
+      .follow_links(true)
+      .sort_by_file_name()
+      .into_iter()
+      .filter_entry(|entry| entry.file_name() != "node_modules")
+      .filter_map(Result::ok)
+    {
+      if !entry.file_type().is_file() || !is_script(entry.path()) {
+        continue;
+      }
+
+      let Ok(source) = fs::read_to_string(entry.path()) else {
+        continue;
+      };
+
+      let Some(rules) = metadata_comment(&source, "metadata")
+        .and_then(|json| MetaDataEnvelope::from_json(json).ok())
+      else {
+        continue;
+      };
+
+      let namespaces: BTreeMap<String, Vec<String>> = metadata_comment(&source, "class_names")
+        .and_then(|json| serde_json::from_str(json).ok())
+        .unwrap_or_default();
+
+      for rule in rules.rules {
+        let declaration = declarations
+          .entry(rule.get_class_name().to_string())
+          .or_default();
+
+        declaration.0 = rule.get_css().to_string();
+
+        if let Some(namespaces) = namespaces.get(rule.get_class_name()) {
+          declaration.1.extend(
+            namespaces
+              .iter()
+              .map(|namespace| (entry.path().to_path_buf(), namespace.clone())),
+          );
+        }
+      }
+    }
+  }
+
+  class_names
+    .iter()
+    .map(|class_name| {
+      let class_name = class_name.trim().trim_start_matches('.').to_string();
+
+      let Some((css, namespaces)) = declarations.get(&class_name) else {
+        return ClassLocation {
+          class_name,
+          ..Default::default()
+        };
+      };
+
+      ClassLocation {
+        properties: rule_properties(css),
+        css: css.clone(),
+        namespaces: namespaces.clone(),
+        class_name,
+      }
+    })
+    .collect()
+}
+
+/// JSON the plugin writes between the `__stylex_<name>_start__` and
+/// `__stylex_<name>_end__` markers of a compiled module.
+fn metadata_comment<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+  let start_marker = format!("__stylex_{}_start__", name);
+  let end_marker = format!("__stylex_{}_end__", name);
+
+  let start = source.find(&start_marker)? + start_marker.len();
+  let end = start + source[start..].find(&end_marker)?;
+
+  Some(&source[start..end])
+}
+
+fn rule_properties(css: &str) -> Vec<String> {
+  let mut collector = PropertyCollector::default();
+
+  if let Ok(stylesheet) = swc_parse_css(css).0 {
+    stylesheet.visit_with(&mut collector);
+  }
+
+  collector.properties.into_iter().collect()
+}
@@ -0,0 +1,307 @@
+use std::{fs::read_to_string, path::PathBuf, process::ExitCode};
+
+use clap::{Parser, Subcommand};
+use stylex_cli::{
+  check::check_paths,
+  critical::critical_rules,
+  extract::{
+    current_date, stylesheet, write_split_stylesheets, write_stylesheet, SplitBy,
+    StylesheetComments,
+  },
+  locate::locate_class_names,
+};
+use stylex_swc_plugin::shared::structures::stylex_options::{StyleXOptions, StyleXOptionsParams};
+
+#[derive(Parser)]
+#[clap(name = "stylex", version)]
+struct Cli {
+  #[clap(subcommand)]
+  command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+  /// Runs the StyleX transform without emitting code and reports its errors
+  /// and unknown properties.
+  Check {
+    #[clap(
+      required = true,
+      help = "Files or directories to check.",
+      value_name = "PATH"
+    )]
+    paths: Vec<PathBuf>,
+
+    #[clap(
+      short,
+      long,
+      help = "Path to a JSON file with the StyleX plugin options.",
+      value_name = "FILE"
+    )]
+    config: Option<PathBuf>,
+
+    #[clap(long, help = "Print the report as JSON.")]
+    json: bool,
+
+    #[clap(long, help = "Exit with a failure status on warnings too.")]
+    deny_warnings: bool,
+
+    #[clap(
+      long,
+      help = "Warn about defineVars tokens none of the checked files reference."
+    )]
+    unused_tokens: bool,
+
+    #[clap(
+      long,
+      help = "Warn about declarations repeated in at least MIN namespaces.",
+      value_name = "MIN"
+    )]
+    duplicate_values: Option<usize>,
+  },
+  /// Compiles the scripts under the given paths and writes the stylesheet of
+  /// their extracted rules.
+  Extract {
+    #[clap(
+      required = true,
+      help = "Files or directories to extract the rules of.",
+      value_name = "PATH"
+    )]
+    paths: Vec<PathBuf>,
+
+    #[clap(
+      short,
+      long,
+      default_value = "stylex.css",
+      help = "Path of the stylesheet to write.",
+      value_name = "FILE"
+    )]
+    out: PathBuf,
+
+    #[clap(
+      short,
+      long,
+      help = "Path to a JSON file with the StyleX plugin options of the build.",
+      value_name = "FILE"
+    )]
+    config: Option<PathBuf>,
+
+    #[clap(
+      long,
+      help = "Name the stylesheet with a hash of its content and map its name to it in stylex-manifest.json."
+    )]
+    content_hash: bool,
+
+    #[clap(
+      long,
+      help = "Comment to prepend to the stylesheet, with [hash] and [date] placeholders.",
+      value_name = "TEXT"
+    )]
+    banner: Option<String>,
+
+    #[clap(
+      long,
+      help = "Comment to append to the stylesheet, with [hash] and [date] placeholders.",
+      value_name = "TEXT"
+    )]
+    footer: Option<String>,
+
+    #[clap(
+      long,
+      help = "Split the stylesheet into files by \"priority\" band or \"media\" query, imported by the --out stylesheet.",
+      value_name = "BY"
+    )]
+    split: Option<SplitBy>,
+
+    #[clap(
+      long = "entry",
+      help = "Only extract the rules reachable from this module, e.g. the critical CSS of a page.",
+      value_name = "FILE"
+    )]
+    entries: Vec<PathBuf>,
+  },
+  /// Finds the modules and `stylex.create()` namespaces declaring compiled
+  /// class names, e.g. the hashed ones of a production bug report, in the
+  /// metadata of a build.
+  Locate {
+    #[clap(
+      required = true,
+      help = "Class names to locate, e.g. x1e2nbdu.",
+      value_name = "CLASS_NAME"
+    )]
+    class_names: Vec<String>,
+
+    #[clap(
+      short = 'i',
+      long = "in",
+      default_value = ".",
+      help = "Compiled modules or directories of the build output, compiled with the exportStyles option.",
+      value_name = "PATH"
+    )]
+    paths: Vec<PathBuf>,
+
+    #[clap(long, help = "Print the locations as JSON.")]
+    json: bool,
+  },
+}
+
+fn main() -> ExitCode {
+  match Cli::parse().command {
+    Command::Check {
+      paths,
+      config,
+      json,
+      deny_warnings,
+      unused_tokens,
+      duplicate_values,
+    } => {
+      let config = match load_config(config) {
+        Ok(config) => config,
+        Err(error) => {
+          eprintln!("{}", error);
+          return ExitCode::from(2);
+        }
+      };
+
+      let mut report = check_paths(&paths, &config);
+
+      if unused_tokens {
+        report.add_unused_tokens();
+      }
+
+      if let Some(min_namespaces) = duplicate_values {
+        report.add_duplicate_values(min_namespaces);
+      }
+
+      if json {
+        println!("{}", report.to_json());
+      } else {
+        println!("{}", report);
+      }
+
+      if report.errors() > 0 || (deny_warnings && report.warnings() > 0) {
+        ExitCode::FAILURE
+      } else {
+        ExitCode::SUCCESS
+      }
+    }
+    Command::Extract {
+      paths,
+      out,
+      config,
+      content_hash,
+      banner,
+      footer,
+      split,
+      entries,
+    } => {
+      let config = match load_config(config) {
+        Ok(config) => config,
+        Err(error) => {
+          eprintln!("{}", error);
+          return ExitCode::from(2);
+        }
+      };
+
+      let report = check_paths(&paths, &config);
+
+      if report.errors() > 0 {
+        for diagnostic in &report.diagnostics {
+          if diagnostic.kind.is_error() {
+            eprintln!("{}", diagnostic);
+          }
+        }
+
+        return ExitCode::FAILURE;
+      }
+
+      let rules = if entries.is_empty() {
+        report.rules()
+      } else {
+        critical_rules(&report, &entries)
+      };
+
+      let comments = StylesheetComments { banner, footer };
+      let date = current_date();
+
+      let written = match split {
+        Some(split_by) => {
+          write_split_stylesheets(&rules, split_by, &out, content_hash, &comments, &date)
+        }
+        None => write_stylesheet(
+          &comments.apply(&stylesheet(&rules), &date),
+          &out,
+          content_hash,
+        )
+        .map(|path| vec![path]),
+      };
+
+      match written {
+        Ok(paths) => {
+          for path in paths {
+            println!("{}", path.display());
+          }
+
+          ExitCode::SUCCESS
+        }
+        Err(error) => {
+          eprintln!("Failed to write {}: {}", out.display(), error);
+
+          ExitCode::FAILURE
+        }
+      }
+    }
+    Command::Locate {
+      class_names,
+      paths,
+      json,
+    } => {
+      let locations = locate_class_names(&class_names, &paths);
+
+      if json {
+        println!(
+          "{}",
+          serde_json::Value::from(
+            locations
+              .iter()
+              .map(|location| location.to_json())
+              .collect::<Vec<_>>()
+          )
+        );
+      } else {
+        for location in &locations {
+          println!("{}", location);
+        }
+      }
+
+      if locations.iter().all(|location| location.is_found()) {
+        ExitCode::SUCCESS
+      } else {
+        ExitCode::FAILURE
+      }
+    }
+  }
+}
+
+fn load_config(path: Option<PathBuf>) -> Result<StyleXOptionsParams, String> {
+  Ok(
+    path
+      .map(read_config)
+      .transpose()?
+      .unwrap_or_else(default_config),
+  )
+}
+
+fn default_config() -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+fn read_config(path: PathBuf) -> Result<StyleXOptionsParams, String> {
+  let config = read_to_string(&path)
+    .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+
+  serde_json::from_str(&config)
+    .map_err(|error| format!("Invalid StyleX config in {}: {}", path.display(), error))
+}
@@ -0,0 +1,261 @@
+use std::path::{Path, PathBuf};
+
+use stylex_cli::check::{check_paths, check_source, DiagnosticKind};
+use stylex_swc_plugin::shared::structures::stylex_options::{StyleXOptions, StyleXOptionsParams};
+
+fn check(source: &str) -> Vec<(DiagnosticKind, String)> {
+  check_source(
+    source,
+    Path::new("/app/src/Component.tsx"),
+    &StyleXOptionsParams::default(),
+  )
+  .into_iter()
+  .map(|diagnostic| (diagnostic.kind, diagnostic.message))
+  .collect()
+}
+
+#[test]
+fn reports_nothing_for_valid_styles() {
+  assert!(check(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      const styles = stylex.create({ root: { color: 'red', '--x': 1 } });
+      export const props = stylex.props(styles.root);
+    "#
+  )
+  .is_empty());
+}
+
+#[test]
+fn accepts_byte_order_marks_shebangs_and_decorators() {
+  let source = "\u{feff}#!/usr/bin/env node\nimport * as stylex from '@stylexjs/stylex';\nconst styles = stylex.create({ root: { color: 'red' } });\n@observer\nexport class Store {}\n";
+
+  for path in ["/app/src/Store.js", "/app/src/Store.tsx"] {
+    assert!(
+      check_source(source, Path::new(path), &StyleXOptionsParams::default()).is_empty(),
+      "{}",
+      path
+    );
+  }
+}
+
+#[test]
+fn reports_transform_errors() {
+  assert_eq!(
+    check(
+      r#"
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({ root: { color: 'red' } }, {});
+      "#
+    ),
+    vec![(
+      DiagnosticKind::Transform,
+      "stylex.create() expects a single style object, remove the extra arguments.".to_string()
+    )]
+  );
+}
+
+#[test]
+fn reports_unknown_properties() {
+  assert_eq!(
+    check(
+      r#"
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({ root: { colr: 'red', backgroundColor: 'blue' } });
+      "#
+    ),
+    vec![(
+      DiagnosticKind::UnknownProperty,
+      "Unknown CSS property \"colr\"".to_string()
+    )]
+  );
+}
+
+#[test]
+fn reports_calls_left_to_run() {
+  assert_eq!(
+    check(
+      r#"
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({ root: { color: 'red' } });
+        export const rootProps = stylex.props(styles.root, props.style);
+      "#
+    ),
+    vec![(
+      DiagnosticKind::Deopt,
+      "stylex.props() call at 4:34 could not be compiled and is left to run at runtime".to_string()
+    )]
+  );
+}
+
+#[test]
+fn reports_parse_errors() {
+  let diagnostics = check("const = ;");
+
+  assert_eq!(diagnostics.len(), 1);
+  assert_eq!(diagnostics[0].0, DiagnosticKind::Parse);
+}
+
+#[test]
+fn checks_directories_and_serializes_the_report() {
+  let dir = std::env::temp_dir().join(format!("stylex-check-{}", std::process::id()));
+  let nested = dir.join("components");
+
+  std::fs::create_dir_all(nested.join("node_modules")).unwrap();
+  std::fs::write(
+    nested.join("Button.js"),
+    "import * as stylex from '@stylexjs/stylex';\nexport const styles = stylex.create({ root: { colr: 'red' } });",
+  )
+  .unwrap();
+  std::fs::write(nested.join("notes.md"), "stylex.create(").unwrap();
+  std::fs::write(
+    nested.join("node_modules").join("dep.js"),
+    "import * as stylex from '@stylexjs/stylex';\nstylex.create(1);",
+  )
+  .unwrap();
+
+  let report = check_paths(&[PathBuf::from(&dir)], &StyleXOptionsParams::default());
+
+  std::fs::remove_dir_all(&dir).unwrap();
+
+  assert_eq!(report.files, 1);
+  assert_eq!(report.errors(), 0);
+  assert_eq!(report.warnings(), 1);
+
+  let json = report.to_json();
+
+  assert_eq!(json["warnings"], 1);
+  assert_eq!(json["diagnostics"][0]["kind"], "unknown-property");
+  assert_eq!(json["diagnostics"][0]["severity"], "warning");
+}
+
+#[test]
+fn reports_tokens_no_checked_file_references() {
+  let dir = std::env::temp_dir().join(format!("stylex-unused-tokens-{}", std::process::id()));
+
+  std::fs::create_dir_all(&dir).unwrap();
+  std::fs::write(
+    dir.join("tokens.stylex.js"),
+    "import * as stylex from '@stylexjs/stylex';\nexport const colors = stylex.defineVars({ primary: 'red', secondary: 'blue' });",
+  )
+  .unwrap();
+  std::fs::write(
+    dir.join("Button.js"),
+    "import * as stylex from '@stylexjs/stylex';\nimport { colors } from 'tokens.stylex.js';\nexport const styles = stylex.create({ root: { color: colors.primary } });",
+  )
+  .unwrap();
+
+  let mut report = check_paths(
+    &[PathBuf::from(&dir)],
+    &StyleXOptionsParams {
+      unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+      ..StyleXOptionsParams::default()
+    },
+  );
+
+  std::fs::remove_dir_all(&dir).unwrap();
+
+  assert!(report.diagnostics.is_empty());
+
+  report.add_unused_tokens();
+
+  assert_eq!(
+    report
+      .diagnostics
+      .iter()
+      .map(|diagnostic| (
+        &diagnostic.file,
+        diagnostic.kind,
+        diagnostic.message.as_str()
+      ))
+      .collect::<Vec<_>>(),
+    vec![(
+      &dir.join("tokens.stylex.js"),
+      DiagnosticKind::UnusedToken,
+      "Unused token \"colors.secondary\""
+    )]
+  );
+  assert_eq!(report.warnings(), 1);
+}
+
+#[test]
+fn reports_declarations_repeated_across_namespaces() {
+  let dir = std::env::temp_dir().join(format!("stylex-duplicate-values-{}", std::process::id()));
+
+  std::fs::create_dir_all(&dir).unwrap();
+  std::fs::write(
+    dir.join("Button.js"),
+    "import * as stylex from '@stylexjs/stylex';\nexport const styles = stylex.create({ root: { color: 'red' }, label: { color: 'red', padding: 4 } });",
+  )
+  .unwrap();
+  std::fs::write(
+    dir.join("Card.js"),
+    "import * as stylex from '@stylexjs/stylex';\nexport const card = stylex.create({ root: { color: 'red', padding: 4 } });",
+  )
+  .unwrap();
+
+  let mut report = check_paths(&[PathBuf::from(&dir)], &StyleXOptionsParams::default());
+
+  std::fs::remove_dir_all(&dir).unwrap();
+
+  report.add_duplicate_values(3);
+
+  assert_eq!(
+    report
+      .diagnostics
+      .iter()
+      .map(|diagnostic| (diagnostic.kind, diagnostic.message.clone()))
+      .collect::<Vec<_>>(),
+    vec![(
+      DiagnosticKind::DuplicateValue,
+      format!(
+        "\".x1e2nbdu{{color:red}}\" is declared in 3 namespaces ({0}:styles.root, {0}:styles.label, {1}:card.root), consider extracting it into a shared style",
+        dir.join("Button.js").display(),
+        dir.join("Card.js").display()
+      )
+    )]
+  );
+}
+
+#[test]
+fn resolves_imports_and_reports_unresolved_ones() {
+  let dir = std::env::temp_dir().join(format!("stylex-imports-{}", std::process::id()));
+
+  std::fs::create_dir_all(dir.join("components/Button")).unwrap();
+  std::fs::write(
+    dir.join("components/Button/index.ts"),
+    "export const label = 'Button';",
+  )
+  .unwrap();
+  std::fs::write(
+    dir.join("App.js"),
+    "import { label } from './components/Button';\nimport { missing } from './Missing';\nexport * from '@acme/not-installed';",
+  )
+  .unwrap();
+
+  let report = check_paths(&[PathBuf::from(&dir)], &StyleXOptionsParams::default());
+
+  std::fs::remove_dir_all(&dir).unwrap();
+
+  assert_eq!(
+    report.modules[&dir.join("App.js")].imports,
+    vec![dir.join("components/Button/index.ts")]
+  );
+  assert_eq!(
+    report
+      .diagnostics
+      .iter()
+      .map(|diagnostic| (
+        &diagnostic.file,
+        diagnostic.kind,
+        diagnostic.message.as_str()
+      ))
+      .collect::<Vec<_>>(),
+    vec![(
+      &dir.join("App.js"),
+      DiagnosticKind::UnresolvedImport,
+      "Cannot resolve the import of \"./Missing\""
+    )]
+  );
+  assert_eq!(report.warnings(), 1);
+}
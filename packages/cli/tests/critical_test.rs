@@ -0,0 +1,68 @@
+use std::{collections::BTreeSet, path::PathBuf};
+
+use stylex_cli::{
+  check::check_paths,
+  critical::{critical_rules, reachable_files},
+  extract::stylesheet,
+};
+use stylex_swc_plugin::shared::structures::stylex_options::{StyleXOptions, StyleXOptionsParams};
+
+#[test]
+fn extracts_rules_reachable_from_entries() {
+  let dir = std::env::temp_dir().join(format!("stylex-critical-{}", std::process::id()));
+
+  std::fs::create_dir_all(dir.join("pages")).unwrap();
+  std::fs::create_dir_all(dir.join("components")).unwrap();
+  std::fs::write(
+    dir.join("tokens.stylex.js"),
+    "import * as stylex from '@stylexjs/stylex';\nexport const colors = stylex.defineVars({ primary: 'red' });",
+  )
+  .unwrap();
+  std::fs::write(
+    dir.join("components/Button.js"),
+    "import * as stylex from '@stylexjs/stylex';\nimport { colors } from 'tokens.stylex.js';\nexport const styles = stylex.create({ root: { color: colors.primary } });",
+  )
+  .unwrap();
+  std::fs::write(
+    dir.join("pages/Home.js"),
+    "import * as stylex from '@stylexjs/stylex';\nimport { styles } from '../components/Button';\nexport const page = stylex.create({ main: { padding: 8 } });",
+  )
+  .unwrap();
+  std::fs::write(
+    dir.join("pages/About.js"),
+    "import * as stylex from '@stylexjs/stylex';\nexport const page = stylex.create({ main: { margin: 4 } });",
+  )
+  .unwrap();
+
+  let report = check_paths(
+    &[PathBuf::from(&dir)],
+    &StyleXOptionsParams {
+      unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+      ..StyleXOptionsParams::default()
+    },
+  );
+
+  std::fs::remove_dir_all(&dir).unwrap();
+
+  assert!(report.diagnostics.is_empty());
+
+  let entries = [dir.join("pages/./Home.js")];
+
+  assert_eq!(
+    reachable_files(&report, &entries),
+    BTreeSet::from([
+      dir.join("components/Button.js"),
+      dir.join("pages/Home.js"),
+      dir.join("tokens.stylex.js"),
+    ])
+  );
+
+  let css = stylesheet(&critical_rules(&report, &entries));
+
+  assert!(css.contains(":root{"));
+  assert!(css.contains("{color:var(--"));
+  assert!(css.contains("{padding:8px}"));
+  assert!(!css.contains("margin"));
+
+  assert!(critical_rules(&report, &[dir.join("pages/Missing.js")]).is_empty());
+}
@@ -0,0 +1,295 @@
+use stylex_cli::{
+  check::check_paths,
+  extract::{
+    current_date, split_rules, stylesheet, write_split_stylesheets, write_stylesheet, SplitBy,
+    StylesheetComments, MANIFEST_FILE_NAME,
+  },
+};
+use stylex_swc_plugin::shared::structures::{
+  injectable_style::InjectableStyle, meta_data::MetaData, stylex_options::StyleXOptionsParams,
+};
+
+fn rule(class_name: &str, ltr: &str, rtl: Option<&str>, priority: f64) -> MetaData {
+  MetaData::new(
+    class_name.to_string(),
+    InjectableStyle {
+      ltr: ltr.to_string(),
+      rtl: rtl.map(str::to_string),
+      priority: Some(priority),
+    },
+  )
+}
+
+#[test]
+fn sorts_rules_by_priority_without_duplicates() {
+  assert_eq!(
+    stylesheet(&[
+      rule("x2", ".x2:hover{color:blue}", None, 3130.0),
+      rule("x1", ".x1{color:red}", None, 3000.0),
+      rule("x3", ".x3{margin:0}", None, 1000.0),
+      rule("x1", ".x1{color:red}", None, 3000.0),
+    ]),
+    ".x3{margin:0}\n.x1{color:red}\n.x2:hover{color:blue}"
+  );
+}
+
+#[test]
+fn scopes_rtl_rules_to_the_document_direction() {
+  assert_eq!(
+    stylesheet(&[
+      rule(
+        "x1",
+        ".x1{margin-left:4px}",
+        Some(".x1{margin-right:4px}"),
+        3000.0
+      ),
+      rule(
+        "x2",
+        "@media (min-width: 800px){.x2.x2{left:0}}",
+        Some("@media (min-width: 800px){.x2.x2{right:0}}"),
+        3200.0
+      ),
+      rule(
+        "x3-B",
+        "@keyframes x3-B{from{left:0}}",
+        Some("@keyframes x3-B{from{right:0}}"),
+        1.0
+      ),
+    ]),
+    [
+      "@keyframes x3-B{from{left:0}}",
+      "html:not([dir='rtl']) .x1{margin-left:4px}",
+      "html[dir='rtl'] .x1{margin-right:4px}",
+      "@media (min-width: 800px){html:not([dir='rtl']) .x2.x2{left:0}}",
+      "@media (min-width: 800px){html[dir='rtl'] .x2.x2{right:0}}",
+    ]
+    .join("\n")
+  );
+}
+
+#[test]
+fn writes_content_hashed_stylesheet_and_manifest() {
+  let dir = std::env::temp_dir().join(format!("stylex-extract-{}", std::process::id()));
+  let source_dir = dir.join("src");
+  let out_dir = dir.join("dist");
+
+  std::fs::create_dir_all(&source_dir).unwrap();
+  std::fs::write(
+    source_dir.join("Button.js"),
+    "import * as stylex from '@stylexjs/stylex';\nexport const styles = stylex.create({ root: { color: 'red' } });",
+  )
+  .unwrap();
+
+  let report = check_paths(&[source_dir], &StyleXOptionsParams::default());
+  let css = stylesheet(&report.rules());
+
+  let plain_path = write_stylesheet(&css, &out_dir.join("stylex.css"), false).unwrap();
+  let hashed_path = write_stylesheet(&css, &out_dir.join("stylex.css"), true).unwrap();
+  let rewritten_path = write_stylesheet(&css, &out_dir.join("stylex.css"), true).unwrap();
+  let other_path = write_stylesheet(".x{}", &out_dir.join("other.css"), true).unwrap();
+
+  let plain_css = std::fs::read_to_string(&plain_path).unwrap();
+  let hashed_css = std::fs::read_to_string(&hashed_path).unwrap();
+  let manifest: serde_json::Value =
+    serde_json::from_str(&std::fs::read_to_string(out_dir.join(MANIFEST_FILE_NAME)).unwrap())
+      .unwrap();
+
+  std::fs::remove_dir_all(&dir).unwrap();
+
+  let hashed_file_name = hashed_path
+    .file_name()
+    .unwrap()
+    .to_string_lossy()
+    .to_string();
+
+  assert_eq!(css, ".x1e2nbdu{color:red}");
+  assert_eq!(plain_path, out_dir.join("stylex.css"));
+  assert_eq!(plain_css, css);
+  assert_eq!(hashed_css, css);
+  assert_eq!(rewritten_path, hashed_path);
+  assert!(hashed_file_name.starts_with("stylex.") && hashed_file_name.ends_with(".css"));
+  assert_ne!(hashed_file_name, "stylex.css");
+  assert_eq!(manifest["stylex.css"], hashed_file_name.as_str());
+  assert_eq!(
+    manifest["other.css"],
+    other_path.file_name().unwrap().to_string_lossy().as_ref()
+  );
+}
+
+#[test]
+fn wraps_stylesheet_in_banner_and_footer() {
+  let comments = StylesheetComments {
+    banner: Some("/*! Acme UI [hash], built [date] */".to_string()),
+    footer: Some("End of [hash]".to_string()),
+  };
+
+  let css = comments.apply(".x1e2nbdu{color:red}", "2024-07-30");
+  let lines = css.lines().collect::<Vec<&str>>();
+
+  assert_eq!(lines.len(), 3);
+  assert!(lines[0].starts_with("/*! Acme UI ") && lines[0].ends_with(", built 2024-07-30 */"));
+  assert_eq!(lines[1], ".x1e2nbdu{color:red}");
+  assert!(lines[2].starts_with("/* End of ") && lines[2].ends_with(" */"));
+  assert!(!lines[0].contains("[hash]") && !lines[2].contains("[hash]"));
+
+  let banner_hash = &lines[0]["/*! Acme UI ".len()..lines[0].find(',').unwrap()];
+
+  assert!(lines[2].contains(banner_hash));
+  assert_eq!(
+    StylesheetComments::default().apply(".x1e2nbdu{color:red}", "2024-07-30"),
+    ".x1e2nbdu{color:red}"
+  );
+}
+
+#[test]
+fn formats_current_date() {
+  let date = current_date();
+
+  assert_eq!(date.len(), 10);
+  assert!(date
+    .chars()
+    .enumerate()
+    .all(|(index, char)| if index == 4 || index == 7 {
+      char == '-'
+    } else {
+      char.is_ascii_digit()
+    }));
+}
+
+fn split_fixture() -> Vec<MetaData> {
+  vec![
+    rule(
+      "x3",
+      "@media (min-width: 768px){.x3.x3{color:blue}}",
+      None,
+      3200.0,
+    ),
+    rule("x1", ".x1{color:red}", None, 3000.0),
+    rule("x2", ".x2{margin:0}", None, 1000.0),
+    rule("x4-B", "@keyframes x4-B{from{opacity:0}}", None, 1.0),
+    rule("x5", ".x5::before{content:\"\"}", None, 8000.0),
+  ]
+}
+
+#[test]
+fn splits_rules_by_priority_band() {
+  let parts = split_rules(&split_fixture(), SplitBy::Priority)
+    .into_iter()
+    .map(|part| (part.name, part.media, stylesheet(&part.rules)))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    parts,
+    vec![
+      (
+        "base".to_string(),
+        None,
+        "@keyframes x4-B{from{opacity:0}}".to_string()
+      ),
+      ("shorthands".to_string(), None, ".x2{margin:0}".to_string()),
+      (
+        "longhands".to_string(),
+        None,
+        ".x1{color:red}\n@media (min-width: 768px){.x3.x3{color:blue}}".to_string()
+      ),
+      (
+        "pseudo-elements".to_string(),
+        None,
+        ".x5::before{content:\"\"}".to_string()
+      ),
+    ]
+  );
+}
+
+#[test]
+fn writes_stylesheets_split_by_media_with_an_index() {
+  let dir = std::env::temp_dir().join(format!("stylex-split-{}", std::process::id()));
+
+  let paths = write_split_stylesheets(
+    &split_fixture(),
+    SplitBy::Media,
+    &dir.join("stylex.css"),
+    false,
+    &StylesheetComments::default(),
+    "2024-07-30",
+  )
+  .unwrap();
+
+  let contents = paths
+    .iter()
+    .map(|path| {
+      (
+        path.file_name().unwrap().to_string_lossy().to_string(),
+        std::fs::read_to_string(path).unwrap(),
+      )
+    })
+    .collect::<Vec<_>>();
+
+  std::fs::remove_dir_all(&dir).unwrap();
+
+  assert_eq!(
+    contents,
+    vec![
+      (
+        "stylex.base.css".to_string(),
+        "@keyframes x4-B{from{opacity:0}}\n.x2{margin:0}\n.x1{color:red}".to_string()
+      ),
+      (
+        "stylex.min-width-768px.css".to_string(),
+        "@media (min-width: 768px){.x3.x3{color:blue}}".to_string()
+      ),
+      (
+        "stylex.base-2.css".to_string(),
+        ".x5::before{content:\"\"}".to_string()
+      ),
+      (
+        "stylex.css".to_string(),
+        "@import url(\"stylex.base.css\");\n@import url(\"stylex.min-width-768px.css\") (min-width: 768px);\n@import url(\"stylex.base-2.css\");"
+          .to_string()
+      ),
+    ]
+  );
+}
+
+#[test]
+fn splits_rules_by_media_in_priority_order() {
+  let rules = vec![
+    rule("x6", ".x6{margin-top:8px}", None, 4000.0),
+    rule(
+      "x7",
+      "@media (min-width: 768px){.x7.x7{margin:0}}",
+      None,
+      1200.0,
+    ),
+    rule("x8", ".x8{margin:4px}", None, 1000.0),
+  ];
+
+  let parts = split_rules(&rules, SplitBy::Media)
+    .into_iter()
+    .map(|part| (part.name, part.media, stylesheet(&part.rules)))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    parts,
+    vec![
+      ("base".to_string(), None, ".x8{margin:4px}".to_string()),
+      (
+        "min-width-768px".to_string(),
+        Some("(min-width: 768px)".to_string()),
+        "@media (min-width: 768px){.x7.x7{margin:0}}".to_string()
+      ),
+      (
+        "base-2".to_string(),
+        None,
+        ".x6{margin-top:8px}".to_string()
+      ),
+    ]
+  );
+}
+
+#[test]
+fn parses_split_by() {
+  assert_eq!("priority".parse::<SplitBy>(), Ok(SplitBy::Priority));
+  assert_eq!("media".parse::<SplitBy>(), Ok(SplitBy::Media));
+  assert!("size".parse::<SplitBy>().is_err());
+}
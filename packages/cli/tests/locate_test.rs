@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+
+use stylex_cli::locate::locate_class_names;
+
+const ROOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/projects/locate");
+
+#[test]
+fn locates_the_namespaces_declaring_a_class_name() {
+  let dir = PathBuf::from(ROOT_DIR).join("lib");
+
+  let locations = locate_class_names(
+    &[".x1e2nbdu".to_string(), "xunknown".to_string()],
+    &[dir.clone()],
+  );
+
+  assert_eq!(locations.len(), 2);
+
+  let found = &locations[0];
+
+  assert!(found.is_found());
+  assert_eq!(found.class_name, "x1e2nbdu");
+  assert_eq!(found.css, ".x1e2nbdu{color:red}");
+  assert_eq!(found.properties, vec!["color".to_string()]);
+  assert_eq!(
+    found.namespaces,
+    vec![
+      (dir.join("Button.js"), "styles.root".to_string()),
+      (dir.join("Card.js"), "card.root".to_string()),
+    ]
+  );
+  assert_eq!(
+    found.to_string(),
+    format!(
+      "x1e2nbdu: .x1e2nbdu{{color:red}}\n  {}: styles.root (color)\n  {}: card.root (color)",
+      dir.join("Button.js").display(),
+      dir.join("Card.js").display()
+    )
+  );
+  assert_eq!(found.to_json()["namespaces"][1]["namespace"], "card.root");
+
+  let missing = &locations[1];
+
+  assert!(!missing.is_found());
+  assert_eq!(
+    missing.to_string(),
+    "xunknown: not declared in the located modules"
+  );
+}
+
+#[test]
+fn locates_class_names_of_single_modules() {
+  let button = PathBuf::from(ROOT_DIR).join("lib/Button.js");
+
+  let locations = locate_class_names(&["xju2f9n".to_string()], &[button.clone()]);
+
+  assert_eq!(locations[0].css, ".xju2f9n{color:blue}");
+  assert_eq!(
+    locations[0].namespaces,
+    vec![(button, "styles.label".to_string())]
+  );
+}
@@ -0,0 +1,14 @@
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"x1e2nbdu","style":{"rtl":null,"ltr":".x1e2nbdu{color:red}"},"priority":3000},{"class_name":"xju2f9n","style":{"rtl":null,"ltr":".xju2f9n{color:blue}"},"priority":3000}]}__stylex_metadata_end__
+//__stylex_exported_styles_start__{"styles":{"root":[{"property":"color","value":"red"}],"label":[{"property":"color","value":"blue"}]}}__stylex_exported_styles_end__
+//__stylex_class_names_start__{"x1e2nbdu":["styles.root"],"xju2f9n":["styles.label"]}__stylex_class_names_end__
+import * as stylex from '@stylexjs/stylex';
+export const styles = {
+    root: {
+        color: "x1e2nbdu",
+        $$css: true
+    },
+    label: {
+        color: "xju2f9n",
+        $$css: true
+    }
+};
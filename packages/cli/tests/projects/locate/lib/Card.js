@@ -0,0 +1,10 @@
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"x1e2nbdu","style":{"rtl":null,"ltr":".x1e2nbdu{color:red}"},"priority":3000}]}__stylex_metadata_end__
+//__stylex_exported_styles_start__{"card":{"root":[{"property":"color","value":"red"}]}}__stylex_exported_styles_end__
+//__stylex_class_names_start__{"x1e2nbdu":["card.root"]}__stylex_class_names_end__
+import * as stylex from '@stylexjs/stylex';
+export const card = {
+    root: {
+        color: "x1e2nbdu",
+        $$css: true
+    }
+};
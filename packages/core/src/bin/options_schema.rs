@@ -0,0 +1,13 @@
+//! Prints the JSON schema of the StyleX plugin options.
+//!
+//! `cargo run --features json-schema --bin options_schema > stylex-options.schema.json`
+
+use stylex_core::shared::structures::stylex_options::StyleXOptionsParams;
+
+fn main() {
+  println!(
+    "{}",
+    serde_json::to_string_pretty(&StyleXOptionsParams::json_schema())
+      .expect("StyleX options schema should serialize")
+  );
+}
@@ -0,0 +1,114 @@
+//! Hashing behind every generated class name, variable name and theme
+//! identifier, so external tools can predict the names a build produces.
+
+use radix_fmt::radix;
+use stylex_path_resolver::utils::normalize_path_separators;
+
+use crate::shared::{
+  structures::class_name_manifest::ClassNameManifest, utils::interner::intern_hash,
+};
+
+/// Hashes `value` into the base 36 string used in generated names.
+pub fn create_hash(value: &str) -> String {
+  radix(murmur2::murmur2(value.as_bytes(), 1), 36).to_string()
+}
+
+/// Identifier of the `key` of an export of `file_name`, the hash input of
+/// `stylex.defineVars` variables and themes. Path separators are normalized,
+/// so it matches across platforms.
+pub fn gen_file_based_identifier(file_name: &str, export_name: &str, key: Option<&str>) -> String {
+  let key = key.map_or(String::new(), |k| format!(".{}", k));
+
+  format!(
+    "{}//{}{}",
+    normalize_path_separators(file_name),
+    export_name,
+    key
+  )
+}
+
+/// Prefixes a hash input with the package scope of the `hashPackageScope`
+/// option, leaving it unchanged for unscoped builds.
+pub fn scope_hash_input(package_scope: Option<String>, value: String) -> String {
+  match package_scope {
+    Some(package_scope) => format!("{}//{}", package_scope, value),
+    None => value,
+  }
+}
+
+/// Hash input of a rule scoped to `shadow_host` by the `shadowDom` option,
+/// leaving it unchanged for unscoped rules.
+pub fn shadow_host_hash_input(shadow_host: Option<&str>, value: String) -> String {
+  match shadow_host {
+    Some(shadow_host) => format!("{}{}", shadow_host, value),
+    None => value,
+  }
+}
+
+/// Hash input of the class name of a `stylex.create` rule, e.g.
+/// `<>colorred:hover` for `color: 'red'` under `:hover`.
+///
+/// The compiler passes it through [`shadow_host_hash_input`] and then
+/// [`scope_hash_input`] before creating the class name.
+///
+/// `property` is the kebab-cased CSS property and `values` the transformed
+/// values, including fallbacks. Pseudos and at-rules are sorted, with the
+/// at-rules first when `at_rules_first` is set, as for the
+/// `AtRulesFirstInClassNameHash` compat behavior.
+pub fn class_name_hash_input(
+  property: &str,
+  values: &[String],
+  pseudos: &[String],
+  at_rules: &[String],
+  at_rules_first: bool,
+) -> String {
+  let mut sorted_pseudos = pseudos.to_vec();
+  sorted_pseudos.sort();
+
+  let mut sorted_at_rules = at_rules.to_vec();
+  sorted_at_rules.sort();
+
+  let pseudo_hash_string = sorted_pseudos.join("");
+  let at_rule_hash_string = sorted_at_rules.join("");
+
+  let modifier_hash_string = if at_rules_first {
+    format!("{}{}", at_rule_hash_string, pseudo_hash_string)
+  } else {
+    format!("{}{}", pseudo_hash_string, at_rule_hash_string)
+  };
+
+  let modifier_hash_string = if modifier_hash_string.is_empty() {
+    "null".to_string()
+  } else {
+    modifier_hash_string
+  };
+
+  format!(
+    "<>{}{}{}",
+    property,
+    values.join(", "),
+    modifier_hash_string
+  )
+}
+
+/// Class name of a rule with the hash input `hash_input`, e.g. `x1e2nbdu`
+/// for `<>colorrednull` and the default `x` prefix.
+pub fn create_class_name(prefix: &str, hash_input: &str) -> String {
+  format!("{}{}", prefix, intern_hash(hash_input))
+}
+
+/// Class name of a rule with the hash input `hash_input` in builds using the
+/// `classNameManifest` option, where the manifest replaces the hash with a
+/// short name. Hashes missing from the manifest are given a new name, which
+/// is only written to it by [`ClassNameManifest::persist`].
+pub fn create_manifest_class_name(
+  prefix: &str,
+  hash_input: &str,
+  manifest: &ClassNameManifest,
+) -> String {
+  format!(
+    "{}{}",
+    prefix,
+    manifest.class_name(&intern_hash(hash_input))
+  )
+}
@@ -1,7 +1,7 @@
 use crate::shared::structures::order_pair::OrderPair;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub(crate) struct Shorthands;
+pub struct Shorthands;
 
 #[allow(dead_code)]
 impl Shorthands {
@@ -1081,7 +1081,7 @@ impl Shorthands {
     result
   }
 
-  pub(crate) fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
+  pub fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
     match name {
       "all" => Some(Shorthands::all),
       "animation" => Some(Shorthands::animation),
@@ -1197,7 +1197,7 @@ impl Shorthands {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub(crate) struct Aliases;
+pub struct Aliases;
 
 #[allow(dead_code)]
 impl Aliases {
@@ -1387,7 +1387,7 @@ impl Aliases {
     result
   }
 
-  pub(crate) fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
+  pub fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
     match name {
       // @Deprecated
       "borderHorizontal" => Shorthands::get("borderInline"),
@@ -1469,3 +1469,23 @@ impl Aliases {
     }
   }
 }
+
+/// User action pseudo-classes in the order their rules apply, the last
+/// matching one winning, e.g. `:active` over `:hover` while a hovered button
+/// is pressed. They are the last pseudo-classes applied, from
+/// `USER_ACTION_PSEUDO_CLASS_PRIORITY` on, like in `@stylexjs/shared`.
+pub static USER_ACTION_PSEUDO_CLASS_ORDER: [&str; 5] = [
+  ":hover",
+  ":focus-within",
+  ":focus",
+  ":focus-visible",
+  ":active",
+];
+
+pub static USER_ACTION_PSEUDO_CLASS_PRIORITY: f64 = 130.0;
+
+pub static USER_ACTION_PSEUDO_CLASS_PRIORITY_STEP: f64 = 10.0;
+
+/// Number of pseudo-classes an order can hold before reaching the priority of
+/// `@media` rules.
+pub static MAX_USER_ACTION_PSEUDO_CLASSES: usize = 7;
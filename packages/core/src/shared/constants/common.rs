@@ -0,0 +1,91 @@
+use phf::phf_set;
+
+pub static DEFAULT_INJECT_PATH: &str = "@stylexjs/stylex/lib/stylex-inject";
+pub static DEFAULT_CSS_IMPORT: &str = "virtual:stylex.css";
+pub static DEFAULT_SEEN_CACHE_CAPACITY: usize = 10_000;
+pub static DEFAULT_MAX_RULE_SIZE: usize = 16 * 1024;
+/// Longest array-like object, e.g. `{ length: 12 }`, that `Array.from()` is
+/// evaluated for, longer ones are left to the runtime.
+pub static MAX_ARRAY_LIKE_LENGTH: usize = 1_000;
+
+/// Inline runtime emitted by the `constructable-stylesheet` injection target.
+/// `__SHEET__` holds the state shared by all modules and `__INJECT__` takes
+/// the same arguments as `stylex-inject`: rules are inserted once, ordered by
+/// priority, and rules with an RTL variant are scoped by `:dir()`.
+pub static CONSTRUCTABLE_STYLESHEET_RUNTIME: &str = r#"
+var __SHEET__ = globalThis.__stylexSheet || (globalThis.__stylexSheet = function () {
+  var sheet = typeof CSSStyleSheet === "function" ? new CSSStyleSheet() : null;
+  if (sheet && typeof document !== "undefined" && document.adoptedStyleSheets) {
+    document.adoptedStyleSheets = [...document.adoptedStyleSheets, sheet];
+  }
+  return { sheet: sheet, priorities: [], rules: new Set() };
+}());
+var __INJECT__ = function (css, priority, rtl) {
+  if (!__SHEET__.sheet) {
+    return;
+  }
+  var rules = rtl == null
+    ? [css]
+    : css.startsWith("@keyframes")
+      ? [css, rtl]
+      : [":where(:dir(ltr)){" + css + "}", ":where(:dir(rtl)){" + rtl + "}"];
+  rules.forEach(function (rule) {
+    if (__SHEET__.rules.has(rule)) {
+      return;
+    }
+    var index = __SHEET__.priorities.findIndex(function (other) {
+      return other > priority;
+    });
+    if (index === -1) {
+      index = __SHEET__.priorities.length;
+    }
+    try {
+      __SHEET__.sheet.insertRule(rule, index);
+      __SHEET__.priorities.splice(index, 0, priority);
+      __SHEET__.rules.add(rule);
+    } catch (error) {}
+  });
+};
+"#;
+
+/// Comment scoping the rules of a file to shadow roots, see
+/// [`ShadowDom`](crate::shared::structures::stylex_options::ShadowDom).
+pub static SHADOW_DOM_PRAGMA: &str = "@stylex-shadow-dom";
+
+/// Comments selecting how the JSX of a file is compiled, see
+/// [`JsxRuntime`](crate::shared::structures::jsx_runtime::JsxRuntime).
+pub static JSX_RUNTIME_PRAGMA: &str = "@jsxRuntime";
+pub static JSX_IMPORT_SOURCE_PRAGMA: &str = "@jsxImportSource";
+pub static JSX_PRAGMA: &str = "@jsx";
+pub static DEFAULT_JSX_IMPORT_SOURCE: &str = "react";
+pub static DEFAULT_JSX_PRAGMA: &str = "React.createElement";
+
+pub static ISSUES_URL: &str = "https://github.com/dwlad90/stylex-swc-plugin/issues";
+
+// Using MDN data as a source of truth to populate the above sets
+// by group in alphabetical order:
+
+pub static VALID_CALLEES: phf::Set<&'static str> = phf_set! {
+
+  "String", "Number", "Math", "Object", "Array"
+};
+
+pub static INVALID_METHODS: phf::Set<&'static str> = phf_set! {
+  "random",
+  "assign",
+  "defineProperties",
+  "defineProperty",
+  "freeze",
+  "seal",
+  "splice",
+};
+
+pub static COMPILED_KEY: &str = "$$css";
+
+pub static SPLIT_TOKEN: &str = "__$$__";
+
+pub static ROOT_FONT_SIZE: i8 = 16;
+
+pub static THEME_NAME_KEY: &str = "__themeName__";
+
+pub static DEFAULT_EXPORT_NAME: &str = "default";
@@ -1,6 +1,6 @@
 use phf::phf_map;
 
-pub(crate) static CURSOR_FLIP: phf::Map<&'static str, &'static str> = phf_map! {
+pub static CURSOR_FLIP: phf::Map<&'static str, &'static str> = phf_map! {
   "e-resize" => "w-resize",
   "w-resize" => "e-resize",
   "ne-resize" => "nw-resize",
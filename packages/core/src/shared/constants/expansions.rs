@@ -5,7 +5,7 @@ use crate::shared::structures::{
   shorthands_of_shorthands::ShorthandsOfShorthands,
 };
 
-pub(crate) enum EXPANSIONS {
+pub enum EXPANSIONS {
   ApplicationOrder(ApplicationOrder),
   PropertySpecificity(PropertySpecificity),
   ShorthandsOfShorthands(ShorthandsOfShorthands),
@@ -1,7 +1,23 @@
-use crate::shared::{structures::order_pair::OrderPair, utils::css::common::split_value_required};
+use crate::shared::{
+  structures::order_pair::OrderPair,
+  utils::css::common::{split_value, split_value_required},
+};
+
+/// Start and end values of a two-value shorthand such as
+/// `marginInline: '4px 8px'` or `insetBlock: '0 auto'`. A single value is used as is for both.
+fn split_value_pair(raw_value: Option<String>) -> (Option<String>, Option<String>) {
+  let Some(raw_value) = raw_value else {
+    return (None, None);
+  };
+
+  match split_value(Some(&raw_value)) {
+    (start, Some(end), _, _) => (Some(start), Some(end)),
+    _ => (Some(raw_value.clone()), Some(raw_value)),
+  }
+}
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub(crate) struct Shorthands;
+pub struct Shorthands;
 
 #[allow(dead_code)]
 impl Shorthands {
@@ -114,17 +130,20 @@ impl Shorthands {
     ]
   }
   fn inset_inline(raw_value: Option<String>) -> Vec<OrderPair> {
+    let (start, end) = split_value_pair(raw_value);
     let mut result = vec![];
 
-    result.extend(Shorthands::start(raw_value.to_owned()));
-    result.extend(Shorthands::end(raw_value));
+    result.extend(Shorthands::start(start));
+    result.extend(Shorthands::end(end));
 
     result
   }
   fn inset_block(raw_value: Option<String>) -> Vec<OrderPair> {
+    let (top, bottom) = split_value_pair(raw_value);
+
     vec![
-      OrderPair("top".into(), raw_value.to_owned()),
-      OrderPair("bottom".into(), raw_value),
+      OrderPair("top".into(), top),
+      OrderPair("bottom".into(), bottom),
     ]
   }
   fn start(raw_value: Option<String>) -> Vec<OrderPair> {
@@ -175,10 +194,11 @@ impl Shorthands {
     ]
   }
   fn margin_horizontal(raw_value: Option<String>) -> Vec<OrderPair> {
+    let (start, end) = split_value_pair(raw_value);
     let mut result = vec![];
 
-    result.extend(Shorthands::margin_start(raw_value.to_owned()));
-    result.extend(Shorthands::margin_end(raw_value));
+    result.extend(Shorthands::margin_start(start));
+    result.extend(Shorthands::margin_end(end));
 
     result
   }
@@ -211,9 +231,11 @@ impl Shorthands {
     ]
   }
   fn margin_vertical(raw_value: Option<String>) -> Vec<OrderPair> {
+    let (top, bottom) = split_value_pair(raw_value);
+
     vec![
-      OrderPair("marginTop".into(), raw_value.to_owned()),
-      OrderPair("marginBottom".into(), raw_value),
+      OrderPair("marginTop".into(), top),
+      OrderPair("marginBottom".into(), bottom),
     ]
   }
 
@@ -235,10 +257,11 @@ impl Shorthands {
     ]
   }
   fn padding_horizontal(val: Option<String>) -> Vec<OrderPair> {
+    let (start, end) = split_value_pair(val);
     let mut result = vec![];
 
-    result.extend(Shorthands::padding_start(val.to_owned()));
-    result.extend(Shorthands::padding_end(val));
+    result.extend(Shorthands::padding_start(start));
+    result.extend(Shorthands::padding_end(end));
 
     result
   }
@@ -271,13 +294,15 @@ impl Shorthands {
     ]
   }
   fn padding_vertical(val: Option<String>) -> Vec<OrderPair> {
+    let (top, bottom) = split_value_pair(val);
+
     vec![
-      OrderPair("paddingTop".into(), val.to_owned()),
-      OrderPair("paddingBottom".into(), val),
+      OrderPair("paddingTop".into(), top),
+      OrderPair("paddingBottom".into(), bottom),
     ]
   }
 
-  pub(crate) fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
+  pub fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
     match name {
       "border" => Some(Shorthands::border),
       "borderColor" => Some(Shorthands::border_color),
@@ -322,7 +347,7 @@ impl Shorthands {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub(crate) struct Aliases;
+pub struct Aliases;
 
 #[allow(dead_code)]
 impl Aliases {
@@ -450,7 +475,7 @@ impl Aliases {
     vec![OrderPair("scrollMarginBottom".into(), value)]
   }
 
-  pub(crate) fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
+  pub fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
     match name {
       "insetBlockStart" => Some(Aliases::inset_block_start),
       "insetBlockEnd" => Some(Aliases::inset_block_end),
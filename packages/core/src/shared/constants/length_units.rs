@@ -1,6 +1,6 @@
 use phf::phf_set;
 
-pub(crate) static LENGTH_UNITS: phf::Set<&'static str> = phf_set! {
+pub static LENGTH_UNITS: phf::Set<&'static str> = phf_set! {
   "backgroundPositionX",
   "backgroundPositionY",
   "blockSize",
@@ -3,7 +3,7 @@ use phf::phf_set;
 // Using MDN data as a source of truth to populate the above sets
 // by group in alphabetical order:
 
-pub(crate) static LONG_HAND_PHYSICAL: phf::Set<&'static str> = phf_set! {
+pub static LONG_HAND_PHYSICAL: phf::Set<&'static str> = phf_set! {
   "border-bottom-color",
   "border-bottom-style",
   "border-bottom-width",
@@ -0,0 +1,133 @@
+pub static ILLEGAL_ARGUMENT_LENGTH: &str = "stylex() should have 1 argument.";
+pub static NON_STATIC_VALUE: &str =
+  "Only static values are allowed inside of a stylex.create() call.";
+#[allow(dead_code)]
+pub static ESCAPED_STYLEX_VALUE: &str = "Escaping a stylex.create() value is not allowed.";
+pub static UNBOUND_STYLEX_CALL_VALUE: &str =
+  "stylex.create calls must be bound to a bare variable.";
+#[allow(dead_code)]
+pub static ONLY_TOP_LEVEL: &str =
+  "stylex.create() is only allowed at the root of a program.";
+pub static NON_OBJECT_FOR_STYLEX_CALL: &str =
+  "stylex.create() can only accept a style object.";
+pub static EXTRA_ARGUMENT_FOR_STYLEX_CREATE_CALL: &str =
+  "stylex.create() expects a single style object, remove the extra arguments.";
+pub static UNKNOWN_PROP_KEY: &str = "Unknown property key";
+pub static MUST_BE_DEFAULT_IMPORT: &str = "Must be default import";
+#[allow(dead_code)]
+pub static INVALID_PSEUDO: &str = "Invalid pseudo selector, not on the whitelist.";
+pub static INVALID_PSEUDO_OR_AT_RULE: &str = "Invalid pseudo or at-rule.";
+#[allow(dead_code)]
+pub static NO_CONDITIONAL_SHORTHAND: &str =
+  "You cannot use conditional style values for a shorthand property.";
+#[allow(dead_code)]
+pub static ILLEGAL_NAMESPACE_TYPE: &str = "Only a string literal namespace is allowed here.";
+#[allow(dead_code)]
+pub static UNKNOWN_NAMESPACE: &str = "Unknown namespace";
+#[allow(dead_code)]
+pub static ILLEGAL_NESTED_PSEUDO: &str =
+  "Pseudo objects can't be nested more than one level deep.";
+pub static ILLEGAL_PROP_VALUE: &str =
+  "A style value can only contain an array, string or number.";
+pub static ILLEGAL_PROP_ARRAY_VALUE: &str =
+  "A style array value can only contain strings or numbers.";
+pub static ILLEGAL_NAMESPACE_VALUE: &str = "A stylex namespace must be an object.";
+pub static UNSUPPORTED_DYNAMIC_STYLE_BODY: &str =
+  "A dynamic style function can only declare variables before returning a style object.";
+pub static NON_STATIC_SPREAD: &str = "Only static objects can be spread into a style object.";
+#[allow(dead_code)]
+pub static INVALID_SPREAD:&str =  "Imported styles spread with a stylex.create call must be type cast as `XStyle` to verify their type.";
+#[allow(dead_code)]
+pub static LOCAL_ONLY: &str = "The return value of stylex.create() should not be exported.";
+#[allow(dead_code)]
+pub static UNEXPECTED_ARGUMENT: &str =
+  "Unexpected argument passed to the stylex() function.";
+#[allow(dead_code)]
+pub static EXPECTED_FUNCTION_CALL: &str =
+  "Expected a simple function call but found something else.";
+#[allow(dead_code)]
+pub static NO_PARENT_PATH: &str = "Unexpected AST node without a parent path.";
+pub static ONLY_TOP_LEVEL_INCLUDES: &str =
+  "stylex.include() is only at the top level of a style definition object.";
+pub static DUPLICATE_CONDITIONAL: &str =
+  "The same pseudo selector or at-rule cannot be used more than once.";
+#[allow(dead_code)]
+pub static NO_PROJECT_ROOT_DIRECTORY: &str =
+  "The project root directory `rootDir` is not configured.";
+pub static NON_EXPORT_NAMED_DECLARATION: &str =
+  "The return value of stylex.defineVars() must be bound to a named export.";
+pub static INVALID_VARS_FILE_SUFFIX: &str =
+  "stylex.defineVars() is only allowed in files matching the configured validVarsFileSuffixes.";
+#[allow(dead_code)]
+pub static ANONYMOUS_THEME: &str = "stylex.createTheme() must be bound to a named constant.";
+pub static ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS:&str =  "Only named parameters are allowed in Dynamic Style functions. Destructuring, spreading or default values are not allowed.";
+pub static BUILT_IN_FUNCTION: &str = "Evaluation built-in functions not supported";
+
+pub static NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL: &str =
+  "stylex.keyframes() can only accept an object.";
+
+pub static NON_STATIC_KEYFRAME_VALUE: &str =
+  "Only static values are allowed inside of a stylex.keyframes() call.";
+
+pub static NON_OBJECT_KEYFRAME: &str =
+  "Every frame within a stylex.keyframes() call must be an object.";
+
+pub static LINT_UNCLOSED_FUNCTION: &str = "Rule contains an unclosed function";
+pub static UNPREFIXED_CUSTOM_PROPERTIES: &str = "Unprefixed custom properties";
+pub static INVALID_LIGHT_DARK_FUNCTION: &str =
+  "light-dark() takes exactly two colors, a light and a dark one, e.g. light-dark(white, black).";
+
+pub static NON_CONTIGUOUS_VARS: &str =
+  "All variables passed to `stylex.firstThatWorks` must be contiguous.";
+
+pub static INVALID_CLASS_NAME_MANIFEST: &str =
+  "The class name manifest must be a JSON object mapping class name hashes to class names.";
+pub static UNWRITABLE_CLASS_NAME_MANIFEST: &str =
+  "The class name manifest could not be locked or written.";
+pub static INVALID_COMPAT_VERSION: &str =
+  "The compat version must be a @stylexjs release like \"0.6\" or \"0.6.1\", but got:";
+pub static UNSUPPORTED_METADATA_VERSION: &str = "The metadata version must be 1 or 2, but got:";
+pub static INVALID_FILE_GLOB: &str = "Include and exclude patterns must be valid globs, but got:";
+pub static CONFLICTING_OPTIONS: &str =
+  "The StyleX options contain settings that cannot apply together:";
+pub static INVALID_PSEUDO_CLASS_ORDER: &str =
+  "The pseudo class order must list at most 7 pseudo classes like \":hover\", but got:";
+pub static SEEN_CACHE_CAPACITY_REACHED: &str =
+  "The evaluated expression cache reached its capacity, consider splitting the file or raising seenCacheCapacity:";
+pub static ATTRS_CONFLICT: &str =
+  "An attribute of an element spreading stylex.attrs() is set twice and only the last one applies:";
+pub static INVALID_CSS_TYPE_INITIAL_VALUE: &str =
+  "The initial value of a typed variable must match its syntax:";
+pub static DYNAMIC_IMPORT: &str =
+  "Dynamic imports cannot be statically evaluated. Import the values with a top level import declaration instead of:";
+pub static NON_FINITE_NUMBER: &str =
+  "NaN and Infinity are not valid CSS values. Check for divisions by zero or arithmetic with undefined.";
+pub static INCOMPATIBLE_QUANTITY_UNITS: &str =
+  "Arithmetic on values with units needs compatible units, e.g. '8px' * 2 or '8px' + '2px', but got:";
+pub static QUANTITY_AS_NUMBER: &str = "A value with a unit cannot be used as a plain number:";
+pub static UNLOADABLE_MACRO_MODULE: &str = "The macro module could not be read or parsed:";
+pub static INVALID_MACRO: &str =
+  "Functions imported from macro modules must be exported functions with identifier parameters whose body is a single expression or return statement:";
+pub static RECURSIVE_MACRO: &str =
+  "Macros can't call themselves, directly or through other macros. Found the recursive calls:";
+pub static UNBOUND_MACRO_IDENTIFIER: &str =
+  "Macros can only use their parameters, other macros and the built-ins supported by the compiler. Found an unbound identifier in:";
+pub static INVALID_UNIT_HELPER_CALL: &str =
+  "Unit helpers take a single number of pixels, e.g. rem(24). Check the arguments of:";
+pub static REASSIGNED_STYLEX_CREATE_VALUE: &str =
+  "The result of stylex.create() must be bound to a constant and cannot be reassigned:";
+pub static UNCOMPILED_NAMESPACE: &str =
+  "A namespace of a stylex.create() call could not be compiled and is left as written:";
+pub static RUNTIME_CREATED_NAMESPACE: &str =
+  "A namespace of a stylex.create() call could not be compiled and is created at runtime:";
+pub static DEPRECATED_PROPERTY_ALIASES: &str =
+  "Legacy property names are deprecated, use their standard names instead in:";
+pub static FUNCTION_STYLE_VALUE: &str =
+  "Functions are only allowed as dynamic styles at the namespace value level, e.g. `root: (color) => ({ color })`.";
+pub static UNSUPPORTED_EVALUATE_RESULT: &str = "Unsupported evaluated value:";
+pub static ARRAY_FROM_WITHOUT_SOURCE: &str =
+  "Array.from() requires an array, a string or an array-like object such as { length: 12 }.";
+pub static LARGE_RULES: &str =
+  "Rules larger than maxRuleSize are repeated for every condition using their value, consider largeValues: \"variable\" for:";
+pub static ESCAPING_STYLES: &str =
+  "Styles passed as a prop to a component are applied outside of this file, where they can't be validated:";
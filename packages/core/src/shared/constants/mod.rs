@@ -0,0 +1,15 @@
+pub mod application_order;
+pub mod common;
+pub mod cursor_flip;
+pub mod legacy_expand_shorthands_order;
+pub mod length_units;
+pub mod long_hand_logical;
+pub mod long_hand_physical;
+pub mod messages;
+pub mod number_properties;
+pub mod priorities;
+pub mod property_specificity_order;
+pub mod shorthands_of_longhands;
+pub mod shorthands_of_shorthands;
+pub mod time_units;
+pub mod unitless_number_properties;
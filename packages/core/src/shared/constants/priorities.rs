@@ -1,6 +1,6 @@
 use phf::phf_map;
 
-pub(crate) static PSEUDO_CLASS_PRIORITIES: phf::Map<&'static str, &'static f64> = phf_map! {
+pub static PSEUDO_CLASS_PRIORITIES: phf::Map<&'static str, &'static f64> = phf_map! {
   ":is" => &40.0,
   ":where" => &40.0,
   ":not" => &40.0,
@@ -55,23 +55,18 @@ pub(crate) static PSEUDO_CLASS_PRIORITIES: phf::Map<&'static str, &'static f64>
   ":current" => &125.0,
   ":past" => &126.0,
   ":future" => &127.0,
-
-  ":hover" => &130.0,
-  ":focusWithin" => &140.0,
-  ":focus" => &150.0,
-  ":focusVisible" => &160.0,
-  ":active" => &170.0,
+  // User action pseudo-classes are ordered by `USER_ACTION_PSEUDO_CLASS_ORDER`
 };
 
-pub(crate) static AT_RULE_PRIORITIES: phf::Map<&'static str, &'static f64> = phf_map! {
+pub static AT_RULE_PRIORITIES: phf::Map<&'static str, &'static f64> = phf_map! {
   "@supports" => &30.0,
   "@media" => &200.0,
   "@container" => &300.0,
 };
 
-pub(crate) static PSEUDO_ELEMENT_PRIORITY: f64 = 5000.0;
+pub static PSEUDO_ELEMENT_PRIORITY: f64 = 5000.0;
 
-pub(crate) static CAMEL_CASE_PRIORITIES: phf::Map<&'static str, &'static str> = phf_map! {
+pub static CAMEL_CASE_PRIORITIES: phf::Map<&'static str, &'static str> = phf_map! {
   "translatex" => "translateX",
   "translatey" => "translateY",
   "translatez" => "translateZ",
@@ -1,7 +1,7 @@
 use crate::shared::structures::order_pair::OrderPair;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub(crate) struct Shorthands;
+pub struct Shorthands;
 
 #[allow(dead_code)]
 impl Shorthands {
@@ -56,7 +56,7 @@ impl Shorthands {
     );
   }
 
-  pub(crate) fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
+  pub fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
     match name {
       "animation" => Some(Shorthands::animation),
       "background" => Some(Shorthands::background),
@@ -75,7 +75,7 @@ impl Shorthands {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub(crate) struct Aliases;
+pub struct Aliases;
 
 #[allow(dead_code)]
 impl Aliases {
@@ -175,6 +175,16 @@ impl Aliases {
     vec![OrderPair("containIntrinsicWidth".to_string(), value)]
   }
 
+  fn grid_gap(value: Option<String>) -> Vec<OrderPair> {
+    vec![OrderPair("gap".to_string(), value)]
+  }
+  fn grid_row_gap(value: Option<String>) -> Vec<OrderPair> {
+    vec![OrderPair("rowGap".to_string(), value)]
+  }
+  fn grid_column_gap(value: Option<String>) -> Vec<OrderPair> {
+    vec![OrderPair("columnGap".to_string(), value)]
+  }
+
   fn margin_block_start(value: Option<String>) -> Vec<OrderPair> {
     vec![OrderPair("marginTop".to_string(), value)]
   }
@@ -240,7 +250,7 @@ impl Aliases {
     vec![OrderPair("insetInlineEnd".to_string(), val)]
   }
 
-  pub(crate) fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
+  pub fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
     match name {
       // @Deprecated
       "borderHorizontal" => Shorthands::get("borderHorizontal"),
@@ -285,6 +295,9 @@ impl Aliases {
       "borderBottomEndRadius" => Some(Aliases::border_bottom_end_radius),
       "containIntrinsicBlockSize" => Some(Aliases::contain_intrinsic_block_size),
       "containIntrinsicInlineSize" => Some(Aliases::contain_intrinsic_inline_size),
+      "gridGap" => Some(Aliases::grid_gap),
+      "gridRowGap" => Some(Aliases::grid_row_gap),
+      "gridColumnGap" => Some(Aliases::grid_column_gap),
       "marginBlockStart" => Some(Aliases::margin_block_start),
       "marginBlockEnd" => Some(Aliases::margin_block_end),
       "marginStart" => Some(Aliases::margin_start),
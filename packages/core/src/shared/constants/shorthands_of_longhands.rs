@@ -3,7 +3,7 @@ use phf::phf_set;
 // Using MDN data as a source of truth to populate the above sets
 // by group in alphabetical order:
 
-pub(crate) static SHORTHANDS_OF_LONGHANDS: phf::Set<&'static str> = phf_set! {
+pub static SHORTHANDS_OF_LONGHANDS: phf::Set<&'static str> = phf_set! {
   // CSS Animations
   "animation-range",
 
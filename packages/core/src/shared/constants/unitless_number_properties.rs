@@ -3,7 +3,7 @@ use phf::phf_set;
 // Using MDN data as a source of truth to populate the above sets
 // by group in alphabetical order:
 
-pub(crate) static UNITLESS_NUMBER_PROPERTIES: phf::Set<&'static str> = phf_set! {
+pub static UNITLESS_NUMBER_PROPERTIES: phf::Set<&'static str> = phf_set! {
   "WebkitLineClamp",
   "animationIterationCount",
   "aspectRatio",
@@ -1,6 +1,6 @@
 // Represents the current state of a plugin for a file.
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Copy)]
-pub(crate) enum ModuleCycle {
+pub enum ModuleCycle {
   // The plugin is being processed.
   TransformEnter,
   TransformExit,
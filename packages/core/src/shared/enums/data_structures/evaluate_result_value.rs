@@ -0,0 +1,139 @@
+use std::{collections::HashMap, fmt, rc::Rc};
+
+use indexmap::IndexMap;
+use swc_core::{
+  atoms::Atom,
+  ecma::ast::{Expr, KeyValueProp, Lit},
+};
+
+use crate::shared::structures::{
+  functions::FunctionConfig, theme_ref::ThemeRef, types::EvaluationCallback,
+};
+
+pub enum EvaluateResultValue {
+  Expr(Box<Expr>),
+  Vec(Vec<Option<EvaluateResultValue>>),
+  Map(IndexMap<Box<Expr>, Vec<KeyValueProp>>),
+  Entries(IndexMap<Box<Lit>, Box<Expr>>),
+  Callback(EvaluationCallback),
+  FunctionConfig(FunctionConfig),
+  FunctionConfigMap(HashMap<Atom, FunctionConfig>),
+  ThemeRef(ThemeRef),
+}
+
+impl EvaluateResultValue {
+  /// Name of the variant, for the messages of values a caller can't handle.
+  pub fn kind(&self) -> &'static str {
+    match self {
+      Self::Expr(_) => "Expr",
+      Self::Vec(_) => "Vec",
+      Self::Map(_) => "Map",
+      Self::Entries(_) => "Entries",
+      Self::Callback(_) => "Callback",
+      Self::FunctionConfig(_) => "FunctionConfig",
+      Self::FunctionConfigMap(_) => "FunctionConfigMap",
+      Self::ThemeRef(_) => "ThemeRef",
+    }
+  }
+
+  /// Calls the method of `visitor` for the variant of the value.
+  pub fn accept<V: EvaluateResultValueVisitor>(&self, visitor: &mut V) -> V::Output {
+    match self {
+      Self::Expr(expr) => visitor.visit_expr(expr),
+      Self::Vec(items) => visitor.visit_vec(items),
+      Self::Map(map) => visitor.visit_map(map),
+      Self::Entries(entries) => visitor.visit_entries(entries),
+      Self::Callback(callback) => visitor.visit_callback(callback),
+      Self::FunctionConfig(function) => visitor.visit_function_config(function),
+      Self::FunctionConfigMap(functions) => visitor.visit_function_config_map(functions),
+      Self::ThemeRef(theme_ref) => visitor.visit_theme_ref(theme_ref),
+    }
+  }
+}
+
+/// Handling of each variant of an [`EvaluateResultValue`], see
+/// [`EvaluateResultValue::accept`].
+///
+/// Every variant has a required method, so a new variant fails to compile
+/// until each visitor decides how to handle it.
+pub trait EvaluateResultValueVisitor {
+  type Output;
+
+  fn visit_expr(&mut self, expr: &Expr) -> Self::Output;
+
+  fn visit_vec(&mut self, items: &[Option<EvaluateResultValue>]) -> Self::Output;
+
+  fn visit_map(&mut self, map: &IndexMap<Box<Expr>, Vec<KeyValueProp>>) -> Self::Output;
+
+  fn visit_entries(&mut self, entries: &IndexMap<Box<Lit>, Box<Expr>>) -> Self::Output;
+
+  fn visit_callback(&mut self, callback: &EvaluationCallback) -> Self::Output;
+
+  fn visit_function_config(&mut self, function: &FunctionConfig) -> Self::Output;
+
+  fn visit_function_config_map(
+    &mut self,
+    functions: &HashMap<Atom, FunctionConfig>,
+  ) -> Self::Output;
+
+  fn visit_theme_ref(&mut self, theme_ref: &ThemeRef) -> Self::Output;
+}
+
+impl Clone for EvaluateResultValue {
+  fn clone(&self) -> Self {
+    match self {
+      Self::Expr(e) => Self::Expr(e.clone()),
+      Self::Vec(v) => Self::Vec(v.clone()),
+      Self::Map(m) => Self::Map(m.clone()),
+      Self::Entries(e) => Self::Entries(e.clone()),
+      Self::FunctionConfig(f) => Self::FunctionConfig(f.clone()),
+      Self::FunctionConfigMap(f) => Self::FunctionConfigMap(f.clone()),
+      Self::Callback(c) => Self::Callback(Rc::clone(c)),
+      Self::ThemeRef(tr) => Self::ThemeRef(tr.clone()),
+    }
+  }
+}
+
+impl fmt::Debug for EvaluateResultValue {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Expr(e) => f.debug_tuple("Expr").field(e).finish(),
+      Self::Vec(v) => f.debug_tuple("Vec").field(v).finish(),
+      Self::Map(m) => f.debug_tuple("Map").field(m).finish(),
+      Self::Entries(e) => f.debug_tuple("Entries").field(e).finish(),
+      Self::FunctionConfig(e) => f.debug_tuple("FunctionConfig").field(e).finish(),
+      Self::FunctionConfigMap(e) => f.debug_tuple("FunctionConfigMap").field(e).finish(),
+      Self::ThemeRef(e) => f.debug_tuple("ThemeRef").field(e).finish(),
+      Self::Callback(_) => f
+        .debug_tuple("Callback")
+        .field(&"Function Pointer")
+        .finish(),
+    }
+  }
+}
+
+impl PartialEq for EvaluateResultValue {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (Self::Expr(e1), Self::Expr(e2)) => e1 == e2,
+      (Self::Vec(v1), Self::Vec(v2)) => v1 == v2,
+      (Self::ThemeRef(v1), Self::ThemeRef(v2)) => v1 == v2,
+      (Self::Map(m1), Self::Map(m2)) => m1 == m2,
+      (Self::Entries(e1), Self::Entries(e2)) => e1 == e2,
+      (Self::FunctionConfig(f1), Self::FunctionConfig(f2)) => f1 == f2,
+      (Self::FunctionConfigMap(f1), Self::FunctionConfigMap(f2)) => f1 == f2,
+      (Self::Callback(_), Self::Callback(_)) => false,
+      (
+        Self::Expr(_)
+        | Self::Vec(_)
+        | Self::ThemeRef(_)
+        | Self::Map(_)
+        | Self::Entries(_)
+        | Self::FunctionConfig(_)
+        | Self::FunctionConfigMap(_)
+        | Self::Callback(_),
+        _,
+      ) => false,
+    }
+  }
+}
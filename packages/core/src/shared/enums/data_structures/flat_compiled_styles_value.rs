@@ -7,7 +7,7 @@ use crate::shared::structures::{
 use super::css_syntax::CSSSyntax;
 
 #[derive(Debug, PartialEq, Clone, Hash)]
-pub(crate) enum FlatCompiledStylesValue {
+pub enum FlatCompiledStylesValue {
   String(String),
   KeyValue(Pair),
   Null,
@@ -19,50 +19,49 @@ pub(crate) enum FlatCompiledStylesValue {
 }
 
 impl FlatCompiledStylesValue {
-  pub(crate) fn as_tuple(&self) -> Option<(&String, &Expr, &Option<BaseCSSType>)> {
+  pub fn as_tuple(&self) -> Option<(&String, &Expr, &Option<BaseCSSType>)> {
     match self {
       FlatCompiledStylesValue::Tuple(key, value, css_type) => Some((key, value, css_type)),
       _ => None,
     }
   }
 
-  #[cfg(test)]
-  pub(crate) fn as_string(&self) -> Option<&String> {
+  pub fn as_string(&self) -> Option<&String> {
     match self {
       FlatCompiledStylesValue::String(value) => Some(value),
       _ => None,
     }
   }
 
-  pub(crate) fn as_injectable_style(&self) -> Option<&InjectableStyle> {
+  pub fn as_injectable_style(&self) -> Option<&InjectableStyle> {
     match self {
       FlatCompiledStylesValue::InjectableStyle(value) => Some(value),
       _ => None,
     }
   }
 
-  pub(crate) fn _as_bool(&self) -> Option<&bool> {
+  pub fn _as_bool(&self) -> Option<&bool> {
     match self {
       FlatCompiledStylesValue::Bool(value) => Some(value),
       _ => None,
     }
   }
 
-  pub(crate) fn _as_null(&self) -> Option<()> {
+  pub fn _as_null(&self) -> Option<()> {
     match self {
       FlatCompiledStylesValue::Null => Some(()),
       _ => None,
     }
   }
 
-  pub(crate) fn _as_included_style(&self) -> Option<&IncludedStyle> {
+  pub fn _as_included_style(&self) -> Option<&IncludedStyle> {
     match self {
       FlatCompiledStylesValue::IncludedStyle(value) => Some(value),
       _ => None,
     }
   }
 
-  pub(crate) fn as_key_value(&self) -> Option<&Pair> {
+  pub fn as_key_value(&self) -> Option<&Pair> {
     match self {
       FlatCompiledStylesValue::KeyValue(value) => Some(value),
       _ => None,
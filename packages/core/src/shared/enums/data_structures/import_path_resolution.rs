@@ -1,11 +1,11 @@
 #[derive(Debug, PartialEq, Clone)]
-pub(crate) enum ImportPathResolution {
+pub enum ImportPathResolution {
   False,
   Tuple(ImportPathResolutionType, String),
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
-pub(crate) enum ImportPathResolutionType {
+pub enum ImportPathResolutionType {
   ThemeNameRef,
   // FilePath,
 }
@@ -0,0 +1,9 @@
+pub mod css_syntax;
+pub mod evaluate_result_value;
+pub mod flat_compiled_styles_value;
+pub mod fn_result;
+pub mod import_path_resolution;
+pub mod obj_map_type;
+pub mod style_vars_to_keep;
+pub mod top_level_expression;
+pub mod value_with_default;
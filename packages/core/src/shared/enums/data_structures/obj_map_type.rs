@@ -4,7 +4,7 @@ use swc_core::ecma::ast::ObjectLit;
 use super::flat_compiled_styles_value::FlatCompiledStylesValue;
 
 #[derive(Debug, PartialEq, Clone)]
-pub(crate) enum ObjMapType {
+pub enum ObjMapType {
   Object(ObjectLit),
   Map(IndexMap<String, Box<FlatCompiledStylesValue>>),
 }
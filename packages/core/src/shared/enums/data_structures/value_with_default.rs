@@ -23,7 +23,7 @@ impl std::hash::Hash for ValueWithDefault {
 }
 
 impl ValueWithDefault {
-  pub(crate) fn as_map(&self) -> Option<&IndexMap<String, ValueWithDefault>> {
+  pub fn as_map(&self) -> Option<&IndexMap<String, ValueWithDefault>> {
     match self {
       ValueWithDefault::Map(map) => Some(map),
       _ => None,
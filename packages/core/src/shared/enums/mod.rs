@@ -0,0 +1,4 @@
+pub mod core;
+pub mod data_structures;
+pub mod js;
+pub mod misc;
@@ -2,5 +2,5 @@ pub mod constants;
 pub mod enums;
 pub mod regex;
 pub mod structures;
-pub(crate) mod transformers;
+pub mod transformers;
 pub mod utils;
@@ -0,0 +1,17 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+pub static INCLUDED_IDENT_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"__included_\d+__").unwrap());
+
+pub static CSS_RULE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?s)\{[^:]*:(.*)}").unwrap());
+
+pub static DASHIFY_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(^|[a-z])([A-Z])").unwrap());
+
+pub static IDENT_PROP_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-zA-Z\d$_]*$").unwrap());
+
+pub static LENGTH_UNIT_TESTER_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^-?\d+(px|%|em|rem|ex|ch|vh|vw|vmin|vmax)?$").unwrap());
+
+pub static QUANTITY_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^([+-]?(?:\d+\.?\d*|\.\d+)(?:[eE][+-]?\d+)?)([a-zA-Z]+|%)?$").unwrap());
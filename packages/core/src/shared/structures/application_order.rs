@@ -0,0 +1,93 @@
+use crate::shared::constants::application_order::{
+  Aliases, Shorthands, MAX_USER_ACTION_PSEUDO_CLASSES, USER_ACTION_PSEUDO_CLASS_ORDER,
+  USER_ACTION_PSEUDO_CLASS_PRIORITY, USER_ACTION_PSEUDO_CLASS_PRIORITY_STEP,
+};
+
+use super::{order::Order, order_pair::OrderPair};
+
+pub struct ApplicationOrder {}
+
+impl Order for ApplicationOrder {
+  fn get_expansion_fn(property: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
+    let alias_fn = Aliases::get(property);
+
+    if let Some(alias_fn) = alias_fn {
+      return Some(alias_fn);
+    }
+
+    let shorthand = Shorthands::get(property);
+
+    if let Some(shorthand_fn) = shorthand {
+      return Some(shorthand_fn);
+    }
+
+    None
+  }
+}
+
+/// Order in which the rules of the user action pseudo-classes apply, the
+/// last matching one winning. Set with the `pseudoClassOrder` option, e.g. to
+/// apply `:disabled` after `:hover`. Pseudo-classes left out of it get their
+/// usual priority, which is lower than the ones of the order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PseudoClassOrder(Vec<String>);
+
+impl Default for PseudoClassOrder {
+  fn default() -> Self {
+    PseudoClassOrder(
+      USER_ACTION_PSEUDO_CLASS_ORDER
+        .iter()
+        .map(|pseudo_class| pseudo_class.to_string())
+        .collect(),
+    )
+  }
+}
+
+impl PseudoClassOrder {
+  /// Orders `pseudo_classes`, keeping the first of repeated ones. Fails with
+  /// the entry that is not a plain pseudo-class like `:hover`, or with all of
+  /// them when there are more than an order holds.
+  pub fn new(pseudo_classes: &[String]) -> Result<Self, String> {
+    let mut order: Vec<String> = vec![];
+
+    for pseudo_class in pseudo_classes {
+      if !is_plain_pseudo_class(pseudo_class) {
+        return Err(pseudo_class.clone());
+      }
+
+      if !order.contains(pseudo_class) {
+        order.push(pseudo_class.clone());
+      }
+    }
+
+    if order.len() > MAX_USER_ACTION_PSEUDO_CLASSES {
+      return Err(order.join(", "));
+    }
+
+    Ok(PseudoClassOrder(order))
+  }
+
+  pub fn pseudo_classes(&self) -> &[String] {
+    &self.0
+  }
+
+  /// Priority of the rules of `pseudo_class`, `None` when it is not ordered.
+  pub fn priority(&self, pseudo_class: &str) -> Option<f64> {
+    self
+      .0
+      .iter()
+      .position(|ordered| ordered == pseudo_class)
+      .map(|index| {
+        USER_ACTION_PSEUDO_CLASS_PRIORITY + index as f64 * USER_ACTION_PSEUDO_CLASS_PRIORITY_STEP
+      })
+  }
+}
+
+fn is_plain_pseudo_class(pseudo_class: &str) -> bool {
+  pseudo_class.strip_prefix(':').is_some_and(|name| {
+    !name.is_empty()
+      && name
+        .chars()
+        .all(|char| char.is_ascii_alphabetic() || char == '-')
+  })
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// A call of a StyleX API in a transformed module, e.g. for editors offering
+/// namespace completions or reporting namespaces no call uses.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CallSite {
+  /// The called API, e.g. `create` or `props`.
+  pub api: String,
+  /// Byte positions of the call, as in the spans of the parsed module.
+  pub start: u32,
+  pub end: u32,
+  /// `<variable>.<namespace>` of the namespaces a `stylex.create()` call
+  /// declares.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub namespaces_defined: Vec<String>,
+  /// `<variable>.<namespace>` of the namespaces passed to the call, e.g. by
+  /// `stylex.props()`. Namespaces selected with computed keys are not listed.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub namespaces_used: Vec<String>,
+  /// Whether the call was compiled away, rather than left to run.
+  pub is_static: bool,
+}
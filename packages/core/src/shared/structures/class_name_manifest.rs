@@ -0,0 +1,260 @@
+use std::{
+  collections::{BTreeMap, HashSet},
+  fs::{self, OpenOptions},
+  io::ErrorKind,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+  thread,
+  time::{Duration, SystemTime},
+};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+
+use crate::shared::constants::messages::{
+  INVALID_CLASS_NAME_MANIFEST, UNWRITABLE_CLASS_NAME_MANIFEST,
+};
+
+static MANIFESTS: Lazy<DashMap<String, Option<Arc<ClassNameManifest>>>> = Lazy::new(DashMap::new);
+
+/// A lock file not modified for this long is assumed to be left behind by a
+/// crashed build.
+const STALE_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(5);
+
+const FIRST_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Persisted mapping from class name hashes to short class names, used when
+/// the `classNameManifest` option points to an existing file.
+///
+/// New hashes get the next unused name of the sequence `a`, `b`, …, `z`,
+/// `aa`, `ab`, …, so the shortest names go to the first rules compiled and a
+/// class name never changes once it is in the manifest. The file is a JSON
+/// object of hashes to names (an empty file is an empty manifest). It is
+/// locked with a `<manifest>.lock` file and re-read before the first name a
+/// module adds, and the added names are written at once by
+/// [`ClassNameManifest::persist`] when the module is done, so parallel builds
+/// sharing the manifest never assign a name twice.
+#[derive(Debug)]
+pub struct ClassNameManifest {
+  path: PathBuf,
+  names: Mutex<ManifestNames>,
+}
+
+#[derive(Debug, Default)]
+struct ManifestNames {
+  by_hash: BTreeMap<String, String>,
+  used: HashSet<String>,
+  /// Held from the first name added until they are persisted.
+  lock: Option<ManifestLock>,
+}
+
+impl ClassNameManifest {
+  /// Returns the manifest at `path`, loaded once per process, or `None` when
+  /// there is no manifest and class names should stay hashes.
+  pub fn open(path: &str) -> Option<Arc<ClassNameManifest>> {
+    MANIFESTS
+      .entry(path.to_string())
+      .or_insert_with(|| {
+        let path = PathBuf::from(path);
+        let by_hash = read_manifest(&path)?;
+
+        Some(Arc::new(ClassNameManifest {
+          path,
+          names: Mutex::new(ManifestNames::from(by_hash)),
+        }))
+      })
+      .clone()
+  }
+
+  /// Forgets the loaded manifest of `path`, so it is read again on its next use.
+  pub fn invalidate(path: &str) -> bool {
+    MANIFESTS.remove(path).is_some()
+  }
+
+  /// Returns the short name of `hash`, assigning a new one if the manifest
+  /// does not contain it yet. New names are written by
+  /// [`ClassNameManifest::persist`].
+  pub fn class_name(&self, hash: &str) -> String {
+    let mut names = self.names.lock().unwrap_or_else(|error| error.into_inner());
+
+    if let Some(name) = names.by_hash.get(hash) {
+      return name.clone();
+    }
+
+    if names.lock.is_none() {
+      names.lock = Some(ManifestLock::acquire(&self.path));
+
+      // Other builds sharing the manifest may have added names since it was read
+      if let Some(by_hash) = read_manifest(&self.path) {
+        names.merge(by_hash);
+
+        if let Some(name) = names.by_hash.get(hash) {
+          return name.clone();
+        }
+      }
+    }
+
+    let name = names.next_name();
+
+    names.used.insert(name.clone());
+    names.by_hash.insert(hash.to_string(), name.clone());
+
+    name
+  }
+
+  /// Writes the names added since the last call and releases the manifest
+  /// for other builds.
+  pub fn persist(&self) {
+    let mut names = self.names.lock().unwrap_or_else(|error| error.into_inner());
+
+    if let Some(lock) = names.lock.take() {
+      write_manifest(&self.path, &names.by_hash);
+
+      drop(lock);
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self
+      .names
+      .lock()
+      .unwrap_or_else(|error| error.into_inner())
+      .by_hash
+      .len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+}
+
+impl From<BTreeMap<String, String>> for ManifestNames {
+  fn from(by_hash: BTreeMap<String, String>) -> Self {
+    let used = by_hash.values().cloned().collect();
+
+    ManifestNames {
+      by_hash,
+      used,
+      lock: None,
+    }
+  }
+}
+
+impl ManifestNames {
+  fn merge(&mut self, by_hash: BTreeMap<String, String>) {
+    for (hash, name) in by_hash {
+      self.used.insert(name.clone());
+      self.by_hash.entry(hash).or_insert(name);
+    }
+  }
+
+  fn next_name(&self) -> String {
+    (self.by_hash.len()..)
+      .map(short_class_name)
+      .find(|name| !self.used.contains(name))
+      .unwrap()
+  }
+}
+
+/// Returns the `index`-th name of the sequence `a`, …, `z`, `aa`, …, `a9`,
+/// `ba`, …. Names always start with a letter, so they stay valid class names
+/// without a prefix.
+pub fn short_class_name(index: usize) -> String {
+  let mut index = index;
+  let mut count = FIRST_CHARS.len();
+  let mut length = 1;
+
+  while index >= count {
+    index -= count;
+    count *= CHARS.len();
+    length += 1;
+  }
+
+  let mut name = Vec::with_capacity(length);
+
+  for _ in 1..length {
+    name.push(CHARS[index % CHARS.len()]);
+    index /= CHARS.len();
+  }
+
+  name.push(FIRST_CHARS[index]);
+  name.reverse();
+
+  String::from_utf8(name).unwrap()
+}
+
+fn read_manifest(path: &Path) -> Option<BTreeMap<String, String>> {
+  let content = fs::read_to_string(path).ok()?;
+
+  if content.trim().is_empty() {
+    return Some(BTreeMap::new());
+  }
+
+  Some(
+    serde_json::from_str(&content)
+      .unwrap_or_else(|_| panic!("{} {}", INVALID_CLASS_NAME_MANIFEST, path.display())),
+  )
+}
+
+fn write_manifest(path: &Path, by_hash: &BTreeMap<String, String>) {
+  let temp_path = with_suffix(path, ".tmp");
+  let content = format!("{}\n", serde_json::to_string_pretty(by_hash).unwrap());
+
+  fs::write(&temp_path, content)
+    .and_then(|_| fs::rename(&temp_path, path))
+    .unwrap_or_else(|error| panic!("{} {}", UNWRITABLE_CLASS_NAME_MANIFEST, error));
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+  let mut path = path.as_os_str().to_owned();
+
+  path.push(suffix);
+
+  PathBuf::from(path)
+}
+
+/// Lock file held while the manifest is updated, removed when dropped.
+#[derive(Debug)]
+struct ManifestLock {
+  path: PathBuf,
+}
+
+impl ManifestLock {
+  fn acquire(manifest_path: &Path) -> ManifestLock {
+    let path = with_suffix(manifest_path, ".lock");
+
+    loop {
+      match OpenOptions::new().write(true).create_new(true).open(&path) {
+        Ok(_) => return ManifestLock { path },
+        Err(error) if error.kind() == ErrorKind::AlreadyExists => {
+          if is_stale(&path) {
+            let _ = fs::remove_file(&path);
+          } else {
+            thread::sleep(LOCK_RETRY_INTERVAL);
+          }
+        }
+        Err(error) => panic!("{} {}", UNWRITABLE_CLASS_NAME_MANIFEST, error),
+      }
+    }
+  }
+}
+
+/// Whether the lock file at `path` was created longer than
+/// [`STALE_LOCK_TIMEOUT`] ago, regardless of how long this build waited for it.
+fn is_stale(path: &Path) -> bool {
+  fs::metadata(path)
+    .and_then(|metadata| metadata.modified())
+    .is_ok_and(|modified| {
+      SystemTime::now()
+        .duration_since(modified)
+        .is_ok_and(|age| age > STALE_LOCK_TIMEOUT)
+    })
+}
+
+impl Drop for ManifestLock {
+  fn drop(&mut self) {
+    let _ = fs::remove_file(&self.path);
+  }
+}
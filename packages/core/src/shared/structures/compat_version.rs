@@ -0,0 +1,76 @@
+use std::{fmt, str::FromStr};
+
+/// `@stylexjs` release whose hashing and normalization the transform
+/// reproduces, set with the `compatVersion` option.
+///
+/// Without it the transform follows the latest behavior. Older releases
+/// differ in the [`CompatBehavior`]s introduced after them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompatVersion {
+  pub major: u32,
+  pub minor: u32,
+  pub patch: u32,
+}
+
+/// Output differences between `@stylexjs` releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompatBehavior {
+  /// Times of at least `10ms` are written in seconds, e.g. `500ms` as `.5s`.
+  TimingNormalization,
+  /// The at-rules of a style are hashed before its pseudo classes and
+  /// elements rather than after them.
+  AtRulesFirstInClassNameHash,
+}
+
+impl CompatBehavior {
+  /// First release with the behavior.
+  pub fn since(&self) -> CompatVersion {
+    match self {
+      CompatBehavior::TimingNormalization => CompatVersion::new(0, 3, 0),
+      CompatBehavior::AtRulesFirstInClassNameHash => CompatVersion::new(0, 5, 0),
+    }
+  }
+}
+
+impl CompatVersion {
+  pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+    CompatVersion {
+      major,
+      minor,
+      patch,
+    }
+  }
+
+  pub fn has(&self, behavior: CompatBehavior) -> bool {
+    *self >= behavior.since()
+  }
+}
+
+impl FromStr for CompatVersion {
+  type Err = String;
+
+  /// Parses `major.minor` or `major.minor.patch`, optionally prefixed with
+  /// `v`. Pre-release and build suffixes are ignored.
+  fn from_str(version: &str) -> Result<Self, Self::Err> {
+    let trimmed = version.trim();
+    let trimmed = trimmed.strip_prefix('v').unwrap_or(trimmed);
+    let core = trimmed.split(['-', '+']).next().unwrap_or_default();
+
+    let parts = core
+      .split('.')
+      .map(|part| part.parse::<u32>().ok())
+      .collect::<Option<Vec<_>>>();
+
+    match parts.as_deref() {
+      Some([major, minor]) => Ok(CompatVersion::new(*major, *minor, 0)),
+      Some([major, minor, patch]) => Ok(CompatVersion::new(*major, *minor, *patch)),
+      _ => Err(version.to_string()),
+    }
+  }
+}
+
+impl fmt::Display for CompatVersion {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+  }
+}
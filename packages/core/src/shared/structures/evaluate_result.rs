@@ -16,11 +16,11 @@ use super::{
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct EvaluateResult {
-  pub(crate) confident: bool,
+  pub confident: bool,
   pub value: Option<Box<EvaluateResultValue>>,
-  pub(crate) deopt: Option<Box<Expr>>,
-  pub(crate) inline_styles: Option<IndexMap<String, Box<Expr>>>,
-  pub(crate) fns: Option<EvaluateResultFns>,
+  pub deopt: Option<Box<Expr>>,
+  pub inline_styles: Option<IndexMap<String, Box<Expr>>>,
+  pub fns: Option<EvaluateResultFns>,
 }
 
 impl EvaluateResultValue {
@@ -45,7 +45,7 @@ impl EvaluateResultValue {
     }
   }
 
-  pub fn as_entries(&self) -> Option<&IndexMap<Box<Lit>, Box<Lit>>> {
+  pub fn as_entries(&self) -> Option<&IndexMap<Box<Lit>, Box<Expr>>> {
     match self {
       EvaluateResultValue::Entries(value) => Some(value),
       _ => None,
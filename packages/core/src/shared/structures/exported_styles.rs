@@ -0,0 +1,65 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::pre_rule::{PreRule, PreRuleValue, PreRules};
+
+/// Resolved declarations of the namespaces of one `stylex.create()` call.
+pub type ExportedStyles = IndexMap<String, Vec<ExportedDeclaration>>;
+
+/// A declaration of a namespace after shorthand expansion and merging, but
+/// before it is hashed into a class name.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedDeclaration {
+  pub property: String,
+  /// `None` for properties reset with `null`.
+  pub value: Option<ExportedValue>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub pseudos: Vec<String>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub at_rules: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ExportedValue {
+  Single(String),
+  /// Values of `stylex.firstThatWorks()`, the preferred one first.
+  Fallbacks(Vec<String>),
+}
+
+impl ExportedDeclaration {
+  /// Declarations of the flattened rule stored under `key`. Included styles
+  /// are not declarations of the namespace and yield none.
+  pub fn from_pre_rule(key: &str, rule: &PreRules) -> Vec<ExportedDeclaration> {
+    match rule {
+      PreRules::StylesPreRule(styles_pre_rule) => {
+        let value = match styles_pre_rule.get_value() {
+          Some(PreRuleValue::String(value)) => Some(ExportedValue::Single(value)),
+          Some(PreRuleValue::Vec(values)) => Some(ExportedValue::Fallbacks(values)),
+          Some(PreRuleValue::Null) | None => None,
+          Some(PreRuleValue::Expr(_)) => return vec![],
+        };
+
+        vec![ExportedDeclaration {
+          property: styles_pre_rule._get_property().unwrap_or(key).to_string(),
+          value,
+          pseudos: styles_pre_rule._get_pseudos().unwrap_or_default(),
+          at_rules: styles_pre_rule._get_at_rules().unwrap_or_default(),
+        }]
+      }
+      PreRules::PreRuleSet(rule_set) => rule_set
+        .get_rules()
+        .iter()
+        .flat_map(|rule| ExportedDeclaration::from_pre_rule(key, rule))
+        .collect(),
+      PreRules::NullPreRule(_) => vec![ExportedDeclaration {
+        property: key.to_string(),
+        value: None,
+        pseudos: vec![],
+        at_rules: vec![],
+      }],
+      PreRules::PreIncludedStylesRule(_) => vec![],
+    }
+  }
+}
@@ -105,9 +105,9 @@ pub struct FunctionConfig {
 
 #[derive(Debug, Hash, PartialEq, Clone)]
 pub struct Functions {
-  pub(crate) include: FunctionConfig,
-  pub(crate) first_that_works: FunctionConfig,
-  pub(crate) keyframes: FunctionConfig,
+  pub include: FunctionConfig,
+  pub first_that_works: FunctionConfig,
+  pub keyframes: FunctionConfig,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -117,28 +117,28 @@ pub enum FunctionConfigType {
 }
 
 impl FunctionConfigType {
-  pub(crate) fn _as_function_config(&self) -> Option<&FunctionConfig> {
+  pub fn _as_function_config(&self) -> Option<&FunctionConfig> {
     match self {
       Self::Regular(config) => Some(config),
       Self::Map(_) => None,
     }
   }
 
-  pub(crate) fn _as_map(&self) -> Option<&HashMap<Atom, FunctionConfig>> {
+  pub fn _as_map(&self) -> Option<&HashMap<Atom, FunctionConfig>> {
     match self {
       Self::Regular(_) => None,
       Self::Map(map) => Some(map),
     }
   }
 
-  pub(crate) fn as_map_mut(&mut self) -> Option<&mut HashMap<Atom, FunctionConfig>> {
+  pub fn as_map_mut(&mut self) -> Option<&mut HashMap<Atom, FunctionConfig>> {
     match self {
       Self::Regular(_) => None,
       Self::Map(map) => Some(map),
     }
   }
 
-  pub(crate) fn _as_function_config_mut(&mut self) -> Option<&mut FunctionConfig> {
+  pub fn _as_function_config_mut(&mut self) -> Option<&mut FunctionConfig> {
     match self {
       Self::Regular(config) => Some(config),
       Self::Map(_) => None,
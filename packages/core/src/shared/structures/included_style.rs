@@ -1,16 +1,16 @@
 use swc_core::ecma::ast::Expr;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub(crate) struct IncludedStyle {
+pub struct IncludedStyle {
   expr: Expr,
 }
 
 impl IncludedStyle {
-  pub(crate) fn new(expr: Expr) -> Self {
+  pub fn new(expr: Expr) -> Self {
     Self { expr }
   }
 
-  pub(crate) fn get_expr(&self) -> &Expr {
+  pub fn get_expr(&self) -> &Expr {
     &self.expr
   }
 }
@@ -5,16 +5,16 @@ use serde::{Deserialize, Serialize};
 use crate::shared::utils::common::hash_f64;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub(crate) struct InjectableStyleBase {
-  pub(crate) rtl: Option<String>,
-  pub(crate) ltr: String,
+pub struct InjectableStyleBase {
+  pub rtl: Option<String>,
+  pub ltr: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub(crate) struct InjectableStyle {
-  pub(crate) ltr: String,
-  pub(crate) rtl: Option<String>,
-  pub(crate) priority: Option<f64>,
+pub struct InjectableStyle {
+  pub ltr: String,
+  pub rtl: Option<String>,
+  pub priority: Option<f64>,
 }
 
 impl Hash for InjectableStyle {
@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+
+use swc_core::ecma::ast::{ImportSpecifier, Module, ModuleExportName};
+
+use crate::shared::constants::common::{
+  DEFAULT_JSX_IMPORT_SOURCE, DEFAULT_JSX_PRAGMA, JSX_IMPORT_SOURCE_PRAGMA, JSX_PRAGMA,
+  JSX_RUNTIME_PRAGMA,
+};
+
+/// Exports of the automatic runtime module creating elements.
+const AUTOMATIC_RUNTIME_FACTORIES: [&str; 3] = ["jsx", "jsxs", "jsxDEV"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsxRuntimeKind {
+  Automatic,
+  Classic,
+}
+
+/// How the JSX of a module is compiled, as set by its `@jsxRuntime`,
+/// `@jsxImportSource` and `@jsx` pragmas.
+///
+/// Style props behave the same whether a module still contains JSX or was
+/// compiled to element calls before StyleX runs, e.g.
+/// `_jsx(Card, { style: styles })` for `<Card style={styles} />`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsxRuntime {
+  /// `None` when no pragma selects a runtime, calls of both runtimes then
+  /// create elements.
+  pub kind: Option<JsxRuntimeKind>,
+  /// Package of the automatic runtime, e.g. `preact` for
+  /// `preact/jsx-runtime`.
+  pub import_source: String,
+  /// Function creating elements with the classic runtime.
+  pub pragma: String,
+}
+
+impl Default for JsxRuntime {
+  fn default() -> Self {
+    JsxRuntime {
+      kind: None,
+      import_source: DEFAULT_JSX_IMPORT_SOURCE.to_string(),
+      pragma: DEFAULT_JSX_PRAGMA.to_string(),
+    }
+  }
+}
+
+impl JsxRuntime {
+  /// Reads the pragmas of the leading `comments` of a module. Without a
+  /// `@jsxRuntime` pragma, `@jsxImportSource` selects the automatic runtime
+  /// and `@jsx` the classic one.
+  pub fn from_comments<'a>(comments: impl IntoIterator<Item = &'a str>) -> Self {
+    let mut runtime = JsxRuntime::default();
+    let mut implied_kind = None;
+
+    for comment in comments {
+      let mut words = comment
+        .split_whitespace()
+        .map(|word| word.trim_start_matches('*'));
+
+      while let Some(word) = words.next() {
+        if word == JSX_RUNTIME_PRAGMA {
+          match words.next() {
+            Some("automatic") => runtime.kind = Some(JsxRuntimeKind::Automatic),
+            Some("classic") => runtime.kind = Some(JsxRuntimeKind::Classic),
+            _ => {}
+          }
+        } else if word == JSX_IMPORT_SOURCE_PRAGMA {
+          if let Some(import_source) = words.next() {
+            runtime.import_source = import_source.to_string();
+            implied_kind.get_or_insert(JsxRuntimeKind::Automatic);
+          }
+        } else if word == JSX_PRAGMA {
+          if let Some(pragma) = words.next() {
+            runtime.pragma = pragma.to_string();
+            implied_kind.get_or_insert(JsxRuntimeKind::Classic);
+          }
+        }
+      }
+    }
+
+    runtime.kind = runtime.kind.or(implied_kind);
+
+    runtime
+  }
+
+  /// Modules the automatic runtime imports its element factories from.
+  pub fn runtime_modules(&self) -> [String; 2] {
+    [
+      format!("{}/jsx-runtime", self.import_source),
+      format!("{}/jsx-dev-runtime", self.import_source),
+    ]
+  }
+
+  /// Callee paths of the calls creating elements in `module`, e.g. `_jsx`
+  /// for `import { jsx as _jsx } from 'react/jsx-runtime'`, or
+  /// `React.createElement` with the classic runtime.
+  pub fn element_factories(&self, module: &Module) -> HashSet<String> {
+    let mut factories = HashSet::new();
+
+    if self.kind != Some(JsxRuntimeKind::Classic) {
+      let runtime_modules = self.runtime_modules();
+
+      let imports = module
+        .body
+        .iter()
+        .filter_map(|item| item.as_module_decl()?.as_import())
+        .filter(|import| {
+          runtime_modules
+            .iter()
+            .any(|runtime_module| *import.src.value == *runtime_module.as_str())
+        });
+
+      for import in imports {
+        for specifier in &import.specifiers {
+          match specifier {
+            ImportSpecifier::Named(named) => {
+              let imported = match &named.imported {
+                Some(ModuleExportName::Ident(ident)) => ident.sym.as_ref(),
+                Some(ModuleExportName::Str(str)) => str.value.as_ref(),
+                None => named.local.sym.as_ref(),
+              };
+
+              if AUTOMATIC_RUNTIME_FACTORIES.contains(&imported) {
+                factories.insert(named.local.sym.to_string());
+              }
+            }
+            ImportSpecifier::Namespace(namespace) => {
+              factories.extend(
+                AUTOMATIC_RUNTIME_FACTORIES
+                  .iter()
+                  .map(|factory| format!("{}.{}", namespace.local.sym, factory)),
+              );
+            }
+            ImportSpecifier::Default(_) => {}
+          }
+        }
+      }
+    }
+
+    if self.kind != Some(JsxRuntimeKind::Automatic) {
+      factories.insert(self.pragma.clone());
+    }
+
+    factories
+  }
+}
@@ -2,7 +2,7 @@ use crate::shared::constants::legacy_expand_shorthands_order::{Aliases, Shorthan
 
 use super::{order::Order, order_pair::OrderPair};
 
-pub(crate) struct LegacyExpandShorthandsOrder {}
+pub struct LegacyExpandShorthandsOrder {}
 
 impl Order for LegacyExpandShorthandsOrder {
   fn get_expansion_fn(property: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
@@ -0,0 +1,602 @@
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::{Arc, Weak},
+};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use swc_core::ecma::{
+  ast::{
+    ArrowExpr, BlockStmtOrExpr, Callee, Decl, DefaultDecl, Expr, FnExpr, Function, ImportSpecifier,
+    KeyValueProp, ModuleDecl, ModuleItem, Pat, Prop, PropName, Stmt,
+  },
+  atoms::Atom,
+  visit::{Visit, VisitMut, VisitMutWith, VisitWith},
+};
+
+use crate::shared::{
+  constants::{
+    common::DEFAULT_EXPORT_NAME,
+    messages::{INVALID_MACRO, RECURSIVE_MACRO, UNBOUND_MACRO_IDENTIFIER, UNLOADABLE_MACRO_MODULE},
+  },
+  utils::common::{
+    get_arrow_bindings, get_fn_expr_bindings, get_function_bindings, get_imported_name,
+    get_return_expr, is_shadowed,
+  },
+};
+
+use super::module_cache::{parse_module, resolve_module_path, ModuleCache};
+
+static MACRO_MODULES: Lazy<ModuleCache<MacroModule>> = Lazy::new(ModuleCache::default);
+
+/// Globals the evaluator supports, which macro bodies can use besides their
+/// parameters.
+const MACRO_GLOBALS: [&str; 7] = [
+  "undefined",
+  "NaN",
+  "Infinity",
+  "Math",
+  "Object",
+  "Array",
+  "String",
+];
+
+/// Exported function of a macro module, reduced to its parameter names and
+/// the single expression it returns.
+#[derive(Clone, Debug)]
+pub struct MacroFunction {
+  pub params: Vec<Atom>,
+  pub body: Box<Expr>,
+}
+
+impl MacroFunction {
+  /// Returns the body with every parameter replaced by its argument, or by
+  /// `undefined` when the argument is missing.
+  pub fn expand(&self, args: &[Expr]) -> Expr {
+    let mut body = *self.body.clone();
+
+    body.visit_mut_with(&mut ParamReplacer {
+      params: &self.params,
+      args,
+      scopes: vec![],
+    });
+
+    body
+  }
+
+  /// Returns the first identifier of the body that isn't bound by a
+  /// parameter, by a function inside the body or to a supported global.
+  fn find_free_identifier(&self) -> Option<Atom> {
+    let mut finder = FreeIdentifierFinder {
+      params: &self.params,
+      scopes: vec![],
+      found: None,
+    };
+
+    self.body.visit_with(&mut finder);
+
+    finder.found
+  }
+}
+
+/// Module listed in the `macroModules` option, whose exported functions are
+/// evaluated at compile time when called inside style values.
+///
+/// Only functions with identifier parameters whose body is one expression
+/// (or one `return` statement) are macros, e.g.
+/// `` export const rem = (px) => `${px / 16}rem` ``. Their bodies can use their
+/// parameters, the built-ins supported by the evaluator and call other such
+/// functions, declared in the module or imported by it from other files, e.g.
+/// `export const gap = (steps) => rem(steps * 4)`. Macros using other
+/// bindings of the module, or of the file calling them, are rejected.
+///
+/// Macros calling themselves, directly or through other macros, can't be
+/// expanded and are rejected.
+#[derive(Clone, Debug, Default)]
+pub struct MacroModule {
+  path: PathBuf,
+  functions: HashMap<String, MacroFunction>,
+  /// Top level functions by local name, exported or not.
+  locals: HashMap<String, MacroFunction>,
+  /// Imported source and name by local name.
+  imports: HashMap<String, (String, String)>,
+  /// Functions with their nested calls expanded, by local name or by
+  /// `default` for an anonymous default export.
+  expanded: DashMap<String, ExpandedMacro>,
+}
+
+/// A function of a macro module with the calls of other macros expanded.
+#[derive(Clone, Debug)]
+struct ExpandedMacro {
+  function: MacroFunction,
+  /// The other macro modules whose functions were expanded into it, weak so
+  /// modules importing each other don't keep each other alive.
+  dependencies: Vec<(PathBuf, Weak<MacroModule>)>,
+}
+
+impl ExpandedMacro {
+  /// Whether none of the modules it was expanded from changed since.
+  fn is_current(&self) -> bool {
+    self.dependencies.iter().all(|(path, dependency)| {
+      match (
+        MACRO_MODULES.load(path, MacroModule::parse),
+        dependency.upgrade(),
+      ) {
+        (Some(module), Some(dependency)) => Arc::ptr_eq(&module, &dependency),
+        _ => false,
+      }
+    })
+  }
+}
+
+impl MacroModule {
+  /// Returns the macro module at `path`, read and parsed again only after
+  /// the modification time of the file changed.
+  pub fn load(path: &Path) -> Arc<MacroModule> {
+    MACRO_MODULES
+      .load(path, MacroModule::parse)
+      .unwrap_or_else(|| panic!("{} {}", UNLOADABLE_MACRO_MODULE, path.display()))
+  }
+
+  /// Collects the macros exported by `source`, or returns `None` when it
+  /// can't be parsed.
+  pub fn parse(path: &Path, source: &str) -> Option<MacroModule> {
+    let module = parse_module(path, source)?;
+
+    let mut functions = HashMap::new();
+    let mut locals = HashMap::new();
+    let mut imports = HashMap::new();
+
+    for item in &module.body {
+      let decl = match item {
+        ModuleItem::Stmt(Stmt::Decl(decl)) => {
+          locals.extend(decl_to_macros(decl));
+          continue;
+        }
+        ModuleItem::Stmt(_) => continue,
+        ModuleItem::ModuleDecl(decl) => decl,
+      };
+
+      match decl {
+        ModuleDecl::Import(import_decl) if !import_decl.type_only => {
+          for specifier in &import_decl.specifiers {
+            let local = match specifier {
+              ImportSpecifier::Named(named) if !named.is_type_only => &named.local,
+              ImportSpecifier::Default(default) => &default.local,
+              _ => continue,
+            };
+
+            if let Some(imported_name) = get_imported_name(import_decl, local) {
+              imports.insert(
+                local.sym.to_string(),
+                (import_decl.src.value.to_string(), imported_name),
+              );
+            }
+          }
+        }
+        ModuleDecl::ExportDecl(export) => {
+          let macros = decl_to_macros(&export.decl);
+
+          functions.extend(macros.clone());
+          locals.extend(macros);
+        }
+        ModuleDecl::ExportDefaultDecl(export) => {
+          if let DefaultDecl::Fn(fn_expr) = &export.decl {
+            if let Some(function) = function_to_macro(&fn_expr.function) {
+              if let Some(ident) = &fn_expr.ident {
+                locals.insert(ident.sym.to_string(), function.clone());
+              }
+
+              functions.insert(DEFAULT_EXPORT_NAME.to_string(), function);
+            }
+          }
+        }
+        ModuleDecl::ExportDefaultExpr(export) => {
+          if let Some(function) = expr_to_macro(&export.expr) {
+            functions.insert(DEFAULT_EXPORT_NAME.to_string(), function);
+          }
+        }
+        _ => {}
+      }
+    }
+
+    Some(MacroModule {
+      path: path.to_path_buf(),
+      functions,
+      locals,
+      imports,
+      expanded: DashMap::new(),
+    })
+  }
+
+  /// Returns the exported macro `name`, with the calls of other macros in its
+  /// body expanded, or the error to report when it isn't a macro, calls
+  /// itself or its body uses other bindings.
+  pub fn get(&self, name: &str) -> Result<MacroFunction, String> {
+    let function = self
+      .functions
+      .get(name)
+      .ok_or_else(|| format!("{} {}", INVALID_MACRO, name))?;
+
+    let function = self.expand(name, function, &mut vec![])?.function;
+
+    // Identifiers left after the expansion would be evaluated in the scope of
+    // the file calling the macro
+    match function.find_free_identifier() {
+      Some(ident) => Err(format!("{} {}: {}", UNBOUND_MACRO_IDENTIFIER, name, ident)),
+      None => Ok(function),
+    }
+  }
+
+  /// Expands the calls of other macros in `function`, bound to `name` in the
+  /// module. `visiting` holds the functions being expanded, which a call
+  /// back into one of them would expand endlessly.
+  fn expand(
+    &self,
+    name: &str,
+    function: &MacroFunction,
+    visiting: &mut Vec<(PathBuf, String)>,
+  ) -> Result<ExpandedMacro, String> {
+    let cached = self.expanded.get(name).map(|expanded| expanded.clone());
+
+    if let Some(expanded) = cached.filter(ExpandedMacro::is_current) {
+      return Ok(expanded);
+    }
+
+    let key = (self.path.clone(), name.to_string());
+
+    if visiting.contains(&key) {
+      let calls = visiting
+        .iter()
+        .skip_while(|visited| **visited != key)
+        .map(|(_, name)| name.as_str())
+        .chain([name])
+        .collect::<Vec<&str>>();
+
+      return Err(format!("{} {}", RECURSIVE_MACRO, calls.join(" -> ")));
+    }
+
+    visiting.push(key);
+
+    let mut body = function.body.clone();
+    let mut expander = NestedCallExpander {
+      module: self,
+      params: &function.params,
+      scopes: vec![],
+      visiting,
+      dependencies: vec![],
+      error: None,
+    };
+
+    body.visit_mut_with(&mut expander);
+
+    let NestedCallExpander {
+      dependencies,
+      error,
+      ..
+    } = expander;
+
+    visiting.pop();
+
+    if let Some(error) = error {
+      return Err(error);
+    }
+
+    let expanded = ExpandedMacro {
+      function: MacroFunction {
+        params: function.params.clone(),
+        body,
+      },
+      dependencies,
+    };
+
+    self.expanded.insert(name.to_string(), expanded.clone());
+
+    Ok(expanded)
+  }
+
+  /// Returns the macro bound to `name` in the module, declared in it or
+  /// imported from the file the path resolver finds for its source, with its
+  /// nested calls expanded. `None` when `name` isn't bound to a macro.
+  fn get_local(
+    &self,
+    name: &str,
+    visiting: &mut Vec<(PathBuf, String)>,
+  ) -> Result<Option<ExpandedMacro>, String> {
+    if let Some(function) = self.locals.get(name) {
+      return self.expand(name, function, visiting).map(Some);
+    }
+
+    let Some((source, imported_name)) = self.imports.get(name) else {
+      return Ok(None);
+    };
+
+    let Some(module) = self
+      .path
+      .to_str()
+      .and_then(|path| resolve_module_path(source, path))
+      .and_then(|path| MACRO_MODULES.load(&path, MacroModule::parse))
+    else {
+      return Ok(None);
+    };
+
+    let Some(function) = module.functions.get(imported_name) else {
+      return Ok(None);
+    };
+
+    let mut expanded = module.expand(imported_name, function, visiting)?;
+
+    expanded
+      .dependencies
+      .push((module.path.clone(), Arc::downgrade(&module)));
+
+    Ok(Some(expanded))
+  }
+}
+
+fn decl_to_macros(decl: &Decl) -> HashMap<String, MacroFunction> {
+  let mut macros = HashMap::new();
+
+  match decl {
+    Decl::Fn(fn_decl) => {
+      if let Some(function) = function_to_macro(&fn_decl.function) {
+        macros.insert(fn_decl.ident.sym.to_string(), function);
+      }
+    }
+    Decl::Var(var_decl) => {
+      for declarator in &var_decl.decls {
+        let (Pat::Ident(name), Some(init)) = (&declarator.name, &declarator.init) else {
+          continue;
+        };
+
+        if let Some(function) = expr_to_macro(init) {
+          macros.insert(name.sym.to_string(), function);
+        }
+      }
+    }
+    _ => {}
+  }
+
+  macros
+}
+
+fn expr_to_macro(expr: &Expr) -> Option<MacroFunction> {
+  match expr {
+    Expr::Paren(paren) => expr_to_macro(&paren.expr),
+    Expr::Arrow(arrow) => {
+      let params = arrow
+        .params
+        .iter()
+        .map(|param| param.as_ident().map(|ident| ident.sym.clone()))
+        .collect::<Option<Vec<Atom>>>()?;
+
+      let body = match arrow.body.as_ref() {
+        BlockStmtOrExpr::Expr(expr) => expr.clone(),
+        BlockStmtOrExpr::BlockStmt(block) => Box::new(get_return_expr(&block.stmts)?),
+      };
+
+      Some(MacroFunction { params, body })
+    }
+    Expr::Fn(fn_expr) => function_to_macro(&fn_expr.function),
+    _ => None,
+  }
+}
+
+fn function_to_macro(function: &Function) -> Option<MacroFunction> {
+  let params = function
+    .params
+    .iter()
+    .map(|param| param.pat.as_ident().map(|ident| ident.sym.clone()))
+    .collect::<Option<Vec<Atom>>>()?;
+
+  let body = Box::new(get_return_expr(&function.body.as_ref()?.stmts)?);
+
+  Some(MacroFunction { params, body })
+}
+
+/// Replaces the parameters of a macro with its arguments, except inside
+/// functions of the body binding the same names.
+struct ParamReplacer<'a> {
+  params: &'a [Atom],
+  args: &'a [Expr],
+  scopes: Vec<Vec<Atom>>,
+}
+
+impl ParamReplacer<'_> {
+  fn get_arg(&self, name: &Atom) -> Option<Expr> {
+    if is_shadowed(&self.scopes, name) {
+      return None;
+    }
+
+    let index = self.params.iter().position(|param| param == name)?;
+
+    Some(match self.args.get(index) {
+      Some(arg) => arg.clone(),
+      None => Expr::Ident("undefined".into()),
+    })
+  }
+}
+
+impl VisitMut for ParamReplacer<'_> {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Expr::Ident(ident) = expr {
+      if let Some(arg) = self.get_arg(&ident.sym) {
+        *expr = arg;
+
+        return;
+      }
+    }
+
+    expr.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_prop(&mut self, prop: &mut Prop) {
+    if let Prop::Shorthand(ident) = prop {
+      if let Some(arg) = self.get_arg(&ident.sym) {
+        *prop = Prop::KeyValue(KeyValueProp {
+          key: PropName::Ident(ident.clone()),
+          value: Box::new(arg),
+        });
+
+        return;
+      }
+    }
+
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_arrow_expr(&mut self, arrow: &mut ArrowExpr) {
+    self.scopes.push(get_arrow_bindings(arrow));
+    arrow.visit_mut_children_with(self);
+    self.scopes.pop();
+  }
+
+  fn visit_mut_fn_expr(&mut self, fn_expr: &mut FnExpr) {
+    self.scopes.push(get_fn_expr_bindings(fn_expr));
+    fn_expr.visit_mut_children_with(self);
+    self.scopes.pop();
+  }
+
+  fn visit_mut_function(&mut self, function: &mut Function) {
+    self.scopes.push(get_function_bindings(function));
+    function.visit_mut_children_with(self);
+    self.scopes.pop();
+  }
+}
+
+/// Finds identifiers of a macro body bound neither by the macro, by a
+/// function inside the body nor to one of the [`MACRO_GLOBALS`].
+struct FreeIdentifierFinder<'a> {
+  params: &'a [Atom],
+  scopes: Vec<Vec<Atom>>,
+  found: Option<Atom>,
+}
+
+impl FreeIdentifierFinder<'_> {
+  fn check(&mut self, name: &Atom) {
+    if self.found.is_none()
+      && !self.params.contains(name)
+      && !is_shadowed(&self.scopes, name)
+      && !MACRO_GLOBALS.contains(&name.as_ref())
+    {
+      self.found = Some(name.clone());
+    }
+  }
+}
+
+impl Visit for FreeIdentifierFinder<'_> {
+  fn visit_expr(&mut self, expr: &Expr) {
+    if let Expr::Ident(ident) = expr {
+      self.check(&ident.sym);
+    }
+
+    expr.visit_children_with(self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    if let Prop::Shorthand(ident) = prop {
+      self.check(&ident.sym);
+    }
+
+    prop.visit_children_with(self);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+    self.scopes.push(get_arrow_bindings(arrow));
+    arrow.visit_children_with(self);
+    self.scopes.pop();
+  }
+
+  fn visit_fn_expr(&mut self, fn_expr: &FnExpr) {
+    self.scopes.push(get_fn_expr_bindings(fn_expr));
+    fn_expr.visit_children_with(self);
+    self.scopes.pop();
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    self.scopes.push(get_function_bindings(function));
+    function.visit_children_with(self);
+    self.scopes.pop();
+  }
+}
+
+/// Replaces the calls of the macros of `module` with their expanded bodies.
+/// Calls of parameters or functions of the body shadowing a macro, with
+/// spread arguments or of unknown functions are kept.
+struct NestedCallExpander<'a, 'v> {
+  module: &'a MacroModule,
+  params: &'a [Atom],
+  scopes: Vec<Vec<Atom>>,
+  visiting: &'v mut Vec<(PathBuf, String)>,
+  /// The other macro modules whose functions were expanded.
+  dependencies: Vec<(PathBuf, Weak<MacroModule>)>,
+  /// The first recursive call found, after which nothing is expanded.
+  error: Option<String>,
+}
+
+impl VisitMut for NestedCallExpander<'_, '_> {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    expr.visit_mut_children_with(self);
+
+    if self.error.is_some() {
+      return;
+    }
+
+    let Expr::Call(call) = expr else {
+      return;
+    };
+
+    let Callee::Expr(callee) = &call.callee else {
+      return;
+    };
+
+    let Expr::Ident(ident) = callee.as_ref() else {
+      return;
+    };
+
+    if self.params.contains(&ident.sym)
+      || is_shadowed(&self.scopes, &ident.sym)
+      || call.args.iter().any(|arg| arg.spread.is_some())
+    {
+      return;
+    }
+
+    let expanded = match self.module.get_local(&ident.sym, self.visiting) {
+      Ok(Some(expanded)) => expanded,
+      Ok(None) => return,
+      Err(error) => {
+        self.error = Some(error);
+        return;
+      }
+    };
+
+    let args = call
+      .args
+      .iter()
+      .map(|arg| *arg.expr.clone())
+      .collect::<Vec<Expr>>();
+
+    *expr = expanded.function.expand(&args);
+
+    self.dependencies.extend(expanded.dependencies);
+  }
+
+  fn visit_mut_arrow_expr(&mut self, arrow: &mut ArrowExpr) {
+    self.scopes.push(get_arrow_bindings(arrow));
+    arrow.visit_mut_children_with(self);
+    self.scopes.pop();
+  }
+
+  fn visit_mut_fn_expr(&mut self, fn_expr: &mut FnExpr) {
+    self.scopes.push(get_fn_expr_bindings(fn_expr));
+    fn_expr.visit_mut_children_with(self);
+    self.scopes.pop();
+  }
+
+  fn visit_mut_function(&mut self, function: &mut Function) {
+    self.scopes.push(get_function_bindings(function));
+    function.visit_mut_children_with(self);
+    self.scopes.pop();
+  }
+}
@@ -11,12 +11,12 @@ use crate::shared::{
 use super::{functions::FunctionMap, state_manager::StateManager};
 
 #[derive(Clone, Debug)]
-pub(crate) struct MemberTransform {
-  pub(crate) index: i32,
-  pub(crate) bail_out_index: Option<i32>,
-  pub(crate) non_null_props: NonNullProps,
-  pub(crate) state: StateManager,
-  pub(crate) parents: Vec<Expr>,
+pub struct MemberTransform {
+  pub index: i32,
+  pub bail_out_index: Option<i32>,
+  pub non_null_props: NonNullProps,
+  pub state: StateManager,
+  pub parents: Vec<Expr>,
 }
 
 impl Fold for MemberTransform {
@@ -0,0 +1,102 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::shared::structures::injectable_style::InjectableStyle;
+
+use super::injectable_style::InjectableStyleBase;
+
+fn f64_to_int<S>(priority: &f64, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  if priority.fract() == 0.0 {
+    return serializer.serialize_i32(*priority as i32);
+  }
+
+  serializer.serialize_f64(*priority)
+}
+
+/// Version of the rule metadata emitted by default.
+pub const METADATA_VERSION: u8 = 2;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+
+pub struct MetaData {
+  class_name: String,
+  style: InjectableStyleBase,
+  #[serde(serialize_with = "f64_to_int")]
+  priority: f64,
+}
+
+impl MetaData {
+  pub fn new(class_name: String, injectable_style: InjectableStyle) -> Self {
+    Self {
+      class_name,
+      priority: injectable_style.priority.unwrap(),
+      style: InjectableStyleBase::from(injectable_style),
+    }
+  }
+  pub fn _get_style(&self) -> &InjectableStyleBase {
+    &self.style
+  }
+
+  pub fn get_css(&self) -> &str {
+    self.style.ltr.as_str()
+  }
+
+  pub fn get_css_rtl(&self) -> Option<&String> {
+    self.style.rtl.as_ref()
+  }
+
+  pub fn get_class_name(&self) -> &str {
+    &self.class_name
+  }
+
+  pub fn get_priority(&self) -> &f64 {
+    &self.priority
+  }
+
+  pub fn convert_from_injected_styles_map(
+    injected_styles_map: &IndexMap<String, Box<InjectableStyle>>,
+  ) -> Vec<MetaData> {
+    injected_styles_map
+      .into_iter()
+      .map(|(class_name, injectable_style)| {
+        MetaData::new(class_name.clone(), *injectable_style.clone())
+      })
+      .collect::<Vec<MetaData>>()
+  }
+}
+
+/// The rules of a module as read by bundler plugins, tagged with the version
+/// of their shape. Version 1 is the bare array of rules.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MetaDataEnvelope {
+  pub version: u8,
+  pub rules: Vec<MetaData>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum VersionedMetaData {
+  Envelope(MetaDataEnvelope),
+  Rules(Vec<MetaData>),
+}
+
+impl MetaDataEnvelope {
+  /// Serializes `rules` in the shape of metadata `version`.
+  pub fn to_json(rules: Vec<MetaData>, version: u8) -> serde_json::Result<String> {
+    match version {
+      1 => serde_json::to_string(&rules),
+      version => serde_json::to_string(&MetaDataEnvelope { version, rules }),
+    }
+  }
+
+  /// Reads metadata of any version.
+  pub fn from_json(json: &str) -> serde_json::Result<MetaDataEnvelope> {
+    Ok(match serde_json::from_str(json)? {
+      VersionedMetaData::Envelope(envelope) => envelope,
+      VersionedMetaData::Rules(rules) => MetaDataEnvelope { version: 1, rules },
+    })
+  }
+}
@@ -0,0 +1,46 @@
+pub mod application_order;
+pub mod base_css_type;
+pub mod call_site;
+pub mod class_name_manifest;
+pub mod compat_version;
+pub mod evaluate_result;
+pub mod exported_styles;
+pub mod functions;
+pub mod included_style;
+pub mod injectable_style;
+pub mod jsx_runtime;
+pub mod legacy_expand_shorthands_order;
+pub mod macro_module;
+pub mod member_transform;
+pub mod meta_data;
+pub mod module_cache;
+pub mod named_import_source;
+pub mod options_conflict;
+pub mod null_pre_rule;
+pub mod order;
+pub mod order_pair;
+pub mod pair;
+pub mod phase_timings;
+pub mod plugin_pass;
+pub mod pre_included_styles_rule;
+pub mod pre_rule;
+pub mod pre_rule_set;
+pub mod property_specificity;
+pub mod property_specificity_order;
+pub mod quantity;
+pub mod rule_registry;
+pub mod seen_cache;
+pub mod seen_value;
+pub mod shorthands_of_shorthands;
+pub mod state;
+pub mod state_manager;
+pub mod stylex_options;
+pub mod stylex_options_builder;
+pub mod stylex_state_options;
+pub(crate) mod tests;
+pub mod theme_ref;
+pub mod token_module;
+pub mod types;
+pub mod uid_generator;
+pub mod var_usage_graph;
+pub mod vars_cache;
@@ -0,0 +1,109 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::SystemTime,
+};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use swc_core::{
+  common::{sync::Lrc, FileName, SourceMap},
+  ecma::{
+    ast::{EsVersion, Module},
+    parser::parse_file_as_module,
+  },
+};
+
+use crate::shared::utils::syntax::{strip_bom, syntax_for_path};
+
+/// Paths of resolved imports, by the directory of the importing file and the
+/// import source.
+static RESOLVED_IMPORTS: Lazy<DashMap<(PathBuf, String), PathBuf>> = Lazy::new(DashMap::new);
+
+/// Modules of the project the compiler reads while transforming other files,
+/// e.g. [`MacroModule`](super::macro_module::MacroModule) and
+/// [`TokenModule`](super::token_module::TokenModule).
+///
+/// A module is read and parsed again only after the modification time of its
+/// file changed, files without one are parsed on every load.
+#[derive(Debug)]
+pub struct ModuleCache<T> {
+  modules: DashMap<PathBuf, (Option<SystemTime>, Arc<T>)>,
+}
+
+impl<T> Default for ModuleCache<T> {
+  fn default() -> Self {
+    Self {
+      modules: DashMap::new(),
+    }
+  }
+}
+
+impl<T> ModuleCache<T> {
+  /// Returns the module at `path`, or `None` when the file can't be read or
+  /// `parse` rejects its source.
+  pub fn load(&self, path: &Path, parse: impl FnOnce(&Path, &str) -> Option<T>) -> Option<Arc<T>> {
+    let modified = fs::metadata(path)
+      .and_then(|metadata| metadata.modified())
+      .ok();
+
+    if let Some(cached) = self.modules.get(path) {
+      if modified.is_some() && cached.0 == modified {
+        return Some(cached.1.clone());
+      }
+    }
+
+    let source = fs::read_to_string(path).ok()?;
+    let module = Arc::new(parse(path, &source)?);
+
+    self
+      .modules
+      .insert(path.to_path_buf(), (modified, module.clone()));
+
+    Some(module)
+  }
+}
+
+/// Parses the `source` of the module at `path`, or returns `None` when it
+/// isn't valid.
+pub fn parse_module(path: &Path, source: &str) -> Option<Module> {
+  let source_map: Lrc<SourceMap> = Default::default();
+  let file = source_map.new_source_file(
+    FileName::Real(path.to_path_buf()),
+    strip_bom(source).to_string(),
+  );
+
+  parse_file_as_module(
+    &file,
+    syntax_for_path(path),
+    EsVersion::latest(),
+    None,
+    &mut vec![],
+  )
+  .ok()
+}
+
+/// Resolves the `source` of an import in `file_path` like Node.js does.
+/// Resolved paths are reused while their file exists.
+pub fn resolve_module_path(source: &str, file_path: &str) -> Option<PathBuf> {
+  let base_dir = Path::new(file_path).parent()?;
+  let key = (base_dir.to_path_buf(), source.to_string());
+
+  if let Some(path) = RESOLVED_IMPORTS.get(&key) {
+    if path.is_file() {
+      return Some(path.clone());
+    }
+  }
+
+  let path = node_resolve::Resolver::default()
+    .with_basedir(base_dir.to_path_buf())
+    .with_extensions([".ts", ".tsx", ".js", ".jsx", ".mjs", ".cjs"])
+    .with_main_fields(vec![String::from("main"), String::from("module")])
+    .resolve(source)
+    .ok()?;
+
+  RESOLVED_IMPORTS.insert(key, path.clone());
+
+  Some(path)
+}
@@ -1,12 +1,14 @@
 use serde::Deserialize;
 
 #[derive(Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct NamedImportSource {
   pub r#as: String,
   pub from: String,
 }
 
 #[derive(Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum ImportSources {
   Regular(String),
   Named(NamedImportSource),
@@ -36,7 +38,7 @@ pub enum RuntimeInjection {
 }
 
 impl RuntimeInjection {
-  pub(crate) fn _is_named_export(&self) -> bool {
+  pub fn _is_named_export(&self) -> bool {
     match self {
       RuntimeInjection::Boolean(_) => false,
       RuntimeInjection::Regular(_) => false,
@@ -44,7 +46,7 @@ impl RuntimeInjection {
     }
   }
 
-  pub(crate) fn _is_regular_export(&self) -> bool {
+  pub fn _is_regular_export(&self) -> bool {
     match self {
       RuntimeInjection::Boolean(_) => false,
       RuntimeInjection::Regular(_) => true,
@@ -52,7 +54,7 @@ impl RuntimeInjection {
     }
   }
 
-  pub(crate) fn _is_boolean_export(&self) -> bool {
+  pub fn _is_boolean_export(&self) -> bool {
     match self {
       RuntimeInjection::Boolean(_) => true,
       RuntimeInjection::Regular(_) => false,
@@ -60,21 +62,21 @@ impl RuntimeInjection {
     }
   }
 
-  pub(crate) fn _as_boolean(&self) -> Option<&bool> {
+  pub fn _as_boolean(&self) -> Option<&bool> {
     match self {
       RuntimeInjection::Boolean(value) => Some(value),
       RuntimeInjection::Regular(_) => None,
       RuntimeInjection::Named(_named) => None,
     }
   }
-  pub(crate) fn _as_regular(&self) -> Option<&String> {
+  pub fn _as_regular(&self) -> Option<&String> {
     match self {
       RuntimeInjection::Boolean(_) => None,
       RuntimeInjection::Regular(value) => Some(value),
       RuntimeInjection::Named(_) => None,
     }
   }
-  pub(crate) fn _as_named(&self) -> Option<&NamedImportSource> {
+  pub fn _as_named(&self) -> Option<&NamedImportSource> {
     match self {
       RuntimeInjection::Boolean(_) => None,
       RuntimeInjection::Regular(_) => None,
@@ -88,3 +90,19 @@ pub enum RuntimeInjectionState {
   Regular(String),
   Named(NamedImportSource),
 }
+
+/// How the code emitted by runtime injection adds the compiled rules to the
+/// page.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum RuntimeInjectionTarget {
+  /// `<style>` elements managed by `@stylexjs/stylex/lib/stylex-inject`.
+  #[default]
+  StyleElement,
+  /// A constructable `CSSStyleSheet` shared by every module through
+  /// `globalThis.__stylexSheet.sheet`. It is adopted by the document when
+  /// there is one, and can be adopted by shadow roots as well. Without
+  /// `CSSStyleSheet`, e.g. in workers, the rules are dropped.
+  ConstructableStylesheet,
+}
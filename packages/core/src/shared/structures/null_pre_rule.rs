@@ -4,10 +4,10 @@ use super::{
 };
 
 #[derive(Debug, Clone, PartialEq, Copy)]
-pub(crate) struct NullPreRule {}
+pub struct NullPreRule {}
 
 impl NullPreRule {
-  pub(crate) fn new() -> Self {
+  pub fn new() -> Self {
     NullPreRule {}
   }
 }
@@ -0,0 +1,34 @@
+use std::fmt::{self, Display};
+
+/// Settings of the StyleX options that cannot apply together, where one of
+/// them would otherwise be silently ignored or both would rewrite the same
+/// output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionsConflict {
+  /// `dev` and `test` both rewrite the compiled class names.
+  DevWithTest,
+  /// `cssImport` only applies when rules are extracted, but `runtimeInjection`
+  /// or `dev` inject them.
+  CssImportWithRuntimeInjection,
+  /// `runtimeInjectionTarget` is set, but rules are extracted.
+  RuntimeInjectionTargetWithoutRuntimeInjection,
+}
+
+impl Display for OptionsConflict {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      OptionsConflict::DevWithTest => write!(
+        f,
+        "\"dev\" and \"test\" both rewrite class names, enable only one of them"
+      ),
+      OptionsConflict::CssImportWithRuntimeInjection => write!(
+        f,
+        "\"cssImport\" only applies to extracted rules, but \"runtimeInjection\" or \"dev\" injects them"
+      ),
+      OptionsConflict::RuntimeInjectionTargetWithoutRuntimeInjection => write!(
+        f,
+        "\"runtimeInjectionTarget\" is set, but neither \"runtimeInjection\" nor \"dev\" is enabled"
+      ),
+    }
+  }
+}
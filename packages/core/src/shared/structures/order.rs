@@ -1,5 +1,5 @@
 use super::order_pair::OrderPair;
 
-pub(crate) trait Order {
+pub trait Order {
   fn get_expansion_fn(property: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>>;
 }
@@ -0,0 +1,2 @@
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct OrderPair(pub String, pub Option<String>);
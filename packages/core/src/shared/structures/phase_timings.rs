@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+use crate::shared::enums::core::ModuleCycle;
+
+/// Time spent in each phase of the transform of a file, in the order the
+/// phases ran. Serialized as microseconds by phase.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PhaseTimings(Vec<(ModuleCycle, Duration)>);
+
+impl PhaseTimings {
+  pub fn record(&mut self, cycle: ModuleCycle, duration: Duration) {
+    self.0.push((cycle, duration));
+  }
+
+  pub fn get(&self, cycle: ModuleCycle) -> Option<Duration> {
+    self
+      .0
+      .iter()
+      .find(|(recorded, _)| *recorded == cycle)
+      .map(|(_, duration)| *duration)
+  }
+
+  pub fn total(&self) -> Duration {
+    self.0.iter().map(|(_, duration)| *duration).sum()
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &(ModuleCycle, Duration)> {
+    self.0.iter()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
+impl Serialize for PhaseTimings {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(self.0.len()))?;
+
+    for (cycle, duration) in &self.0 {
+      map.serialize_entry(&format!("{:?}", cycle), &duration.as_micros())?;
+    }
+
+    map.end()
+  }
+}
@@ -11,12 +11,12 @@ use super::{
 };
 
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) struct PreIncludedStylesRule {
-  pub(crate) included_styles: Expr,
+pub struct PreIncludedStylesRule {
+  pub included_styles: Expr,
 }
 
 impl PreIncludedStylesRule {
-  pub(crate) fn new(included_styles: Expr) -> Self {
+  pub fn new(included_styles: Expr) -> Self {
     PreIncludedStylesRule { included_styles }
   }
 }
@@ -2,8 +2,12 @@ use std::fmt::Debug;
 
 use swc_core::ecma::ast::Expr;
 
-use crate::shared::utils::{
-  common::type_of, core::convert_style_to_class_name::convert_style_to_class_name,
+use crate::{
+  hash::create_hash,
+  shared::utils::{
+    common::type_of, core::convert_style_to_class_name::convert_style_to_class_name,
+    css::common::transform_value,
+  },
 };
 
 use super::{
@@ -13,7 +17,7 @@ use super::{
 };
 
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) enum PreRuleValue {
+pub enum PreRuleValue {
   Expr(Expr),
   String(String),
   Vec(Vec<String>),
@@ -21,24 +25,24 @@ pub(crate) enum PreRuleValue {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) struct ComputedStyle(pub(crate) String, pub(crate) InjectableStyle);
+pub struct ComputedStyle(pub String, pub InjectableStyle);
 
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) enum CompiledResult {
+pub enum CompiledResult {
   Null,
   IncludedStyle(IncludedStyle),
   ComputedStyles(Vec<ComputedStyle>),
 }
 
 impl CompiledResult {
-  pub(crate) fn as_included_style(&self) -> Option<&IncludedStyle> {
+  pub fn as_included_style(&self) -> Option<&IncludedStyle> {
     match self {
       CompiledResult::IncludedStyle(included_style) => Some(included_style),
       _ => None,
     }
   }
 
-  pub(crate) fn as_computed_styles(&self) -> Option<&Vec<ComputedStyle>> {
+  pub fn as_computed_styles(&self) -> Option<&Vec<ComputedStyle>> {
     match self {
       CompiledResult::ComputedStyles(computed_styles) => Some(computed_styles),
       _ => None,
@@ -46,7 +50,7 @@ impl CompiledResult {
   }
 }
 
-pub(crate) trait PreRule: Debug {
+pub trait PreRule: Debug {
   #[allow(dead_code)]
   fn get_value(&self) -> Option<PreRuleValue>;
   fn compiled(&mut self, state: &StateManager) -> CompiledResult;
@@ -55,15 +59,31 @@ pub(crate) trait PreRule: Debug {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) enum PreRules {
+pub enum PreRules {
   PreIncludedStylesRule(PreIncludedStylesRule),
   PreRuleSet(PreRuleSet),
   StylesPreRule(StylesPreRule),
   NullPreRule(NullPreRule),
 }
 
+impl PreRules {
+  /// Moves the values longer than `max_size` bytes out of the rules, see
+  /// [`StylesPreRule::hoist_large_values`].
+  pub fn hoist_large_values(
+    &mut self,
+    max_size: usize,
+    state: &StateManager,
+  ) -> Vec<(String, String)> {
+    match self {
+      PreRules::StylesPreRule(rule) => rule.hoist_large_values(max_size, state),
+      PreRules::PreRuleSet(rule_set) => rule_set.hoist_large_values(max_size, state),
+      PreRules::PreIncludedStylesRule(_) | PreRules::NullPreRule(_) => vec![],
+    }
+  }
+}
+
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) struct StylesPreRule {
+pub struct StylesPreRule {
   property: String,
   value: PreRuleValue,
   pseudos: Vec<String>,
@@ -71,7 +91,7 @@ pub(crate) struct StylesPreRule {
 }
 
 impl StylesPreRule {
-  pub(crate) fn new(
+  pub fn new(
     property: &str,
     value: PreRuleValue,
     pseudos: Option<Vec<String>>,
@@ -108,13 +128,42 @@ impl StylesPreRule {
       at_rules,
     }
   }
-  pub(crate) fn _get_property(&self) -> Option<&str> {
+  /// Replaces a value longer than `max_size` bytes with a `var()` of a custom
+  /// property named after its hash, and returns the name and value of that
+  /// property. Fallback arrays are kept as is, since a `var()` in them is
+  /// read as a variable fallback.
+  pub fn hoist_large_values(
+    &mut self,
+    max_size: usize,
+    state: &StateManager,
+  ) -> Vec<(String, String)> {
+    let PreRuleValue::String(value) = &mut self.value else {
+      return vec![];
+    };
+
+    if value.len() <= max_size {
+      return vec![];
+    }
+
+    let transformed_value = transform_value(&self.property, value, state);
+
+    let name = format!(
+      "--{}{}",
+      state.options.class_name_prefix,
+      create_hash(&transformed_value)
+    );
+
+    *value = format!("var({})", name);
+
+    vec![(name, transformed_value)]
+  }
+  pub fn _get_property(&self) -> Option<&str> {
     Some(&self.property)
   }
-  pub(crate) fn _get_pseudos(&self) -> Option<Vec<String>> {
+  pub fn _get_pseudos(&self) -> Option<Vec<String>> {
     Some(self.pseudos.to_owned())
   }
-  pub(crate) fn _get_at_rules(&self) -> Option<Vec<String>> {
+  pub fn _get_at_rules(&self) -> Option<Vec<String>> {
     Some(self.at_rules.to_owned())
   }
 }
@@ -6,15 +6,15 @@ use super::{
   state_manager::StateManager,
 };
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) struct PreRuleSet {
+pub struct PreRuleSet {
   rules: Vec<PreRules>,
 }
 
 impl PreRuleSet {
-  pub(crate) fn _new() -> Self {
+  pub fn _new() -> Self {
     PreRuleSet { rules: vec![] }
   }
-  pub(crate) fn create(rules: Vec<PreRules>) -> PreRules {
+  pub fn create(rules: Vec<PreRules>) -> PreRules {
     let flat_rules = rules
       .into_iter()
       .flat_map(|rule| match rule {
@@ -32,6 +32,22 @@ impl PreRuleSet {
       _ => PreRules::PreRuleSet(PreRuleSet { rules: flat_rules }),
     }
   }
+
+  pub fn get_rules(&self) -> &[PreRules] {
+    &self.rules
+  }
+
+  pub fn hoist_large_values(
+    &mut self,
+    max_size: usize,
+    state: &StateManager,
+  ) -> Vec<(String, String)> {
+    self
+      .rules
+      .iter_mut()
+      .flat_map(|rule| rule.hoist_large_values(max_size, state))
+      .collect()
+  }
 }
 
 impl PreRule for PreRuleSet {
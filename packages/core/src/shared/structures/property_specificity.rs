@@ -1,6 +1,6 @@
 use super::{order::Order, order_pair::OrderPair};
 
-pub(crate) struct PropertySpecificity {}
+pub struct PropertySpecificity {}
 
 impl Order for PropertySpecificity {
   fn get_expansion_fn(_property: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
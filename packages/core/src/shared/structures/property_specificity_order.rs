@@ -2,7 +2,7 @@ use crate::shared::constants::property_specificity_order::{Aliases, Shorthands};
 
 use super::{order::Order, order_pair::OrderPair};
 
-pub(crate) struct PropertySpecificityOrder {}
+pub struct PropertySpecificityOrder {}
 
 impl Order for PropertySpecificityOrder {
   fn get_expansion_fn(property: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
@@ -0,0 +1,80 @@
+use std::{fmt, str::FromStr};
+
+use swc_core::ecma::ast::BinaryOp;
+
+use crate::shared::{regex::QUANTITY_REGEX, utils::common::round_f64};
+
+/// A CSS dimension like `1.5rem` or `50%`, or a plain number when `unit` is
+/// empty, used to evaluate arithmetic on string values such as `'8px' * 2`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantity {
+  pub value: f64,
+  pub unit: String,
+}
+
+impl Quantity {
+  pub fn new(value: f64, unit: impl Into<String>) -> Self {
+    Quantity {
+      value,
+      unit: unit.into(),
+    }
+  }
+
+  pub fn is_unitless(&self) -> bool {
+    self.unit.is_empty()
+  }
+
+  /// Applies a binary operator, or returns `None` when the units of the
+  /// operands can't be combined by it.
+  ///
+  /// Sums and differences need the same unit on both sides, products need
+  /// a unitless side and quotients a unitless divisor or the same unit.
+  pub fn apply(&self, op: BinaryOp, other: &Quantity) -> Option<Quantity> {
+    match op {
+      BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mod if self.unit == other.unit => {
+        let value = match op {
+          BinaryOp::Add => self.value + other.value,
+          BinaryOp::Sub => self.value - other.value,
+          _ => self.value % other.value,
+        };
+
+        Some(Quantity::new(value, self.unit.clone()))
+      }
+      BinaryOp::Mul if other.is_unitless() => {
+        Some(Quantity::new(self.value * other.value, self.unit.clone()))
+      }
+      BinaryOp::Mul if self.is_unitless() => {
+        Some(Quantity::new(self.value * other.value, other.unit.clone()))
+      }
+      BinaryOp::Div if other.is_unitless() => {
+        Some(Quantity::new(self.value / other.value, self.unit.clone()))
+      }
+      BinaryOp::Div if self.unit == other.unit => Some(Quantity::new(self.value / other.value, "")),
+      _ => None,
+    }
+  }
+}
+
+impl FromStr for Quantity {
+  type Err = String;
+
+  /// Parses a number followed by an optional unit, e.g. `-.5em` or `10`.
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let Some(captures) = QUANTITY_REGEX.captures(value.trim()) else {
+      return Err(value.to_string());
+    };
+
+    let number = captures[1].parse::<f64>().map_err(|_| value.to_string())?;
+
+    let unit = captures.get(2).map_or("", |unit| unit.as_str());
+
+    Ok(Quantity::new(number, unit))
+  }
+}
+
+impl fmt::Display for Quantity {
+  /// Formats the value rounded to four decimals like other style numbers.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}{}", round_f64(self.value, 4), self.unit)
+  }
+}
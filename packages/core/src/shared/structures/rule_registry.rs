@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use dashmap::DashMap;
+
+use super::meta_data::MetaData;
+
+/// Concurrent collection of the compiled rules of a build, for threads
+/// compiling different files at once. Rules are keyed by their CSS, so a rule
+/// compiled by several files is registered once.
+#[derive(Debug, Default)]
+pub struct RuleRegistry {
+  rules: DashMap<(String, Option<String>), Arc<MetaData>>,
+}
+
+impl RuleRegistry {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Registers `rule` unless a rule with the same CSS already is, and returns
+  /// the registered rule.
+  pub fn insert_or_get(&self, rule: MetaData) -> Arc<MetaData> {
+    let key = (rule.get_css().to_string(), rule.get_css_rtl().cloned());
+
+    self
+      .rules
+      .entry(key)
+      .or_insert_with(|| Arc::new(rule))
+      .clone()
+  }
+
+  pub fn extend(&self, rules: impl IntoIterator<Item = MetaData>) {
+    for rule in rules {
+      self.insert_or_get(rule);
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.rules.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.rules.is_empty()
+  }
+
+  /// The registered rules sorted by priority, then by CSS, so the order
+  /// doesn't depend on which thread registered a rule first.
+  pub fn snapshot(&self) -> Vec<Arc<MetaData>> {
+    let mut rules = self
+      .rules
+      .iter()
+      .map(|entry| entry.value().clone())
+      .collect::<Vec<Arc<MetaData>>>();
+
+    rules.sort_by(|a, b| {
+      a.get_priority()
+        .total_cmp(b.get_priority())
+        .then_with(|| a.get_css().cmp(b.get_css()))
+        .then_with(|| a.get_css_rtl().cmp(&b.get_css_rtl()))
+    });
+
+    rules
+  }
+}
@@ -0,0 +1,143 @@
+use std::{
+  collections::{HashMap, VecDeque},
+  sync::Arc,
+};
+
+use swc_core::ecma::ast::Expr;
+
+use super::seen_value::SeenValue;
+
+/// Evaluated expressions of a file, keyed by the expression. Every key is a
+/// clone of the AST, so the cache is bounded: once `capacity` entries are
+/// stored the least recently used one is evicted. A capacity of `0` leaves it
+/// unbounded.
+///
+/// Every use of an entry stamps it with a new generation and queues the
+/// stamp, so lookups, inserts and evictions take constant time: queued stamps
+/// older than the generation of their entry are skipped when evicting.
+#[derive(Clone, Debug, Default)]
+pub struct SeenCache {
+  entries: HashMap<Arc<Expr>, SeenEntry>,
+  /// Stamps of the entries from the least to the most recently used,
+  /// including outdated ones.
+  recency: VecDeque<(u64, Arc<Expr>)>,
+  generation: u64,
+  capacity: usize,
+  evictions: usize,
+}
+
+#[derive(Clone, Debug)]
+struct SeenEntry {
+  value: Box<SeenValue>,
+  generation: u64,
+}
+
+impl SeenCache {
+  pub fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      ..Default::default()
+    }
+  }
+
+  /// Value of `key`, marked as the most recently used entry.
+  pub fn get(&mut self, key: &Expr) -> Option<&SeenValue> {
+    let (key, _) = self.entries.get_key_value(key)?;
+    let key = key.clone();
+
+    self.touch(&key);
+
+    self.entries.get(&key).map(|entry| entry.value.as_ref())
+  }
+
+  pub fn insert(&mut self, key: Box<Expr>, value: Box<SeenValue>) {
+    let key = Arc::<Expr>::from(key);
+
+    self.entries.insert(
+      key.clone(),
+      SeenEntry {
+        value,
+        generation: 0,
+      },
+    );
+    self.touch(&key);
+
+    if self.capacity == 0 {
+      return;
+    }
+
+    while self.entries.len() > self.capacity {
+      let Some((generation, key)) = self.recency.pop_front() else {
+        break;
+      };
+
+      if self.is_current(generation, &key) {
+        self.entries.remove(&key);
+        self.evictions += 1;
+      }
+    }
+  }
+
+  /// Adds the entries of `other` as the most recently used ones, keeping
+  /// their order.
+  pub fn merge(&mut self, other: &SeenCache) {
+    self.evictions = self.evictions.max(other.evictions);
+
+    for (generation, key) in &other.recency {
+      if !other.is_current(*generation, key) {
+        continue;
+      }
+
+      if let Some(entry) = other.entries.get(key) {
+        self.insert(Box::new(key.as_ref().clone()), entry.value.clone());
+      }
+    }
+  }
+
+  pub fn capacity(&self) -> usize {
+    self.capacity
+  }
+
+  /// Entries evicted since the cache was created.
+  pub fn evictions(&self) -> usize {
+    self.evictions
+  }
+
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  /// Marks `key` as the most recently used entry.
+  fn touch(&mut self, key: &Arc<Expr>) {
+    self.generation += 1;
+
+    if let Some(entry) = self.entries.get_mut(key) {
+      entry.generation = self.generation;
+    }
+
+    self.recency.push_back((self.generation, key.clone()));
+
+    // Drops the outdated stamps once they outnumber the entries, which keeps
+    // the queue linear in the number of entries at a constant amortized cost
+    if self.recency.len() > 2 * self.entries.len() + 16 {
+      let entries = &self.entries;
+
+      self.recency.retain(|(generation, key)| {
+        entries
+          .get(key)
+          .is_some_and(|entry| entry.generation == *generation)
+      });
+    }
+  }
+
+  fn is_current(&self, generation: u64, key: &Expr) -> bool {
+    self
+      .entries
+      .get(key)
+      .is_some_and(|entry| entry.generation == generation)
+  }
+}
@@ -1,7 +1,7 @@
 use crate::shared::enums::data_structures::evaluate_result_value::EvaluateResultValue;
 
 #[derive(Clone, Debug)]
-pub(crate) struct SeenValue {
-  pub(crate) value: Option<Box<EvaluateResultValue>>,
-  pub(crate) resolved: bool,
+pub struct SeenValue {
+  pub value: Option<Box<EvaluateResultValue>>,
+  pub resolved: bool,
 }
@@ -6,11 +6,11 @@ use super::{functions::FunctionMap, state_manager::StateManager};
 
 #[derive(Debug)]
 pub struct EvaluationState {
-  pub(crate) confident: bool,
-  pub(crate) deopt_path: Option<Box<Expr>>,
-  pub(crate) added_imports: HashSet<String>,
-  pub(crate) functions: FunctionMap,
-  pub(crate) traversal_state: StateManager,
+  pub confident: bool,
+  pub deopt_path: Option<Box<Expr>>,
+  pub added_imports: HashSet<String>,
+  pub functions: FunctionMap,
+  pub traversal_state: StateManager,
 }
 
 impl Default for EvaluationState {
@@ -29,7 +29,7 @@ impl Default for EvaluationState {
 }
 
 impl EvaluationState {
-  pub(crate) fn new(traversal_state: &StateManager) -> Self {
+  pub fn new(traversal_state: &StateManager) -> Self {
     EvaluationState {
       confident: true,
       deopt_path: None,
@@ -0,0 +1,1346 @@
+use core::panic;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::hash::Hash;
+use std::option::Option;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use dashmap::DashMap;
+use glob::{MatchOptions, Pattern};
+use indexmap::{IndexMap, IndexSet};
+use once_cell::sync::Lazy;
+use stylex_path_resolver::{
+  errors::ResolveError,
+  resolvers::{
+    find_package_json, find_path_in_roots, find_root_dir, get_package_scope,
+    resolve_file_path_in_roots, resolve_path_in_roots, ResolverOptions, EXTENSIONS,
+  },
+  utils::normalize_path_separators,
+};
+use swc_core::ecma::ast::{
+  CallExpr, Callee, Decl, Expr, ExprStmt, Id, Ident, ImportDecl, ImportDefaultSpecifier,
+  ImportNamedSpecifier, ImportPhase, ImportSpecifier, ImportStarAsSpecifier, Lit, MemberProp,
+  ModuleDecl, ModuleExportName, ModuleItem, ObjectLit, Pat, Stmt, Str, UnaryExpr, UnaryOp, VarDecl,
+  VarDeclKind, VarDeclarator,
+};
+use swc_core::{
+  atoms::Atom,
+  common::{sync::Lrc, EqIgnoreSpan, FileName, SourceMap, DUMMY_SP},
+  ecma::{
+    ast::EsVersion,
+    parser::{parse_file_as_module, Syntax},
+    utils::drop_span,
+  },
+};
+
+use crate::shared::enums::data_structures::{
+  import_path_resolution::{ImportPathResolution, ImportPathResolutionType},
+  style_vars_to_keep::StyleVarsToKeep,
+  top_level_expression::{TopLevelExpression, TopLevelExpressionKind},
+};
+use crate::shared::utils::{
+  ast::factories::binding_ident_factory,
+  common::{
+    extract_filename_from_path, extract_filename_with_ext_from_path, extract_path, get_import_from,
+    get_imported_name, round_f64,
+  },
+  core::runtime_create::{get_callee_binding, get_specifier_local, rebind_callee, runtime_import},
+};
+use crate::shared::{
+  constants::{
+    common::{CONSTRUCTABLE_STYLESHEET_RUNTIME, DEFAULT_INJECT_PATH},
+    messages::{INVALID_FILE_GLOB, UNLOADABLE_MACRO_MODULE},
+  },
+  utils::ast::factories::{
+    expr_or_spread_number_expression_factory, expr_or_spread_string_expression_factory,
+  },
+};
+
+use super::macro_module::{MacroFunction, MacroModule};
+use super::module_cache::resolve_module_path;
+use super::plugin_pass::PluginPass;
+use super::stylex_options::{CheckModuleResolution, StyleXOptions};
+use super::stylex_state_options::StyleXStateOptions;
+use super::token_module::TokenModule;
+use super::uid_generator::UidGenerator;
+use super::{
+  call_site::CallSite, exported_styles::ExportedStyles, meta_data::MetaData,
+  types::StylesObjectMap, var_usage_graph::VarUsageGraph,
+};
+use super::{injectable_style::InjectableStyle, stylex_options::ModuleResolution};
+use super::{
+  named_import_source::{
+    ImportSources, NamedImportSource, RuntimeInjectionState, RuntimeInjectionTarget,
+  },
+  seen_cache::SeenCache,
+};
+
+#[derive(Clone, Debug)]
+pub struct StateManager {
+  pub _state: Box<PluginPass>,
+
+  // Imports
+  pub import_paths: HashSet<String>,
+  pub stylex_import: HashSet<Box<ImportSources>>,
+  pub stylex_props_import: HashSet<Box<Atom>>,
+  pub stylex_attrs_import: HashSet<Box<Atom>>,
+  pub stylex_create_import: HashSet<Box<Atom>>,
+  pub stylex_include_import: HashSet<Box<Atom>>,
+  pub stylex_first_that_works_import: HashSet<Box<Atom>>,
+  pub stylex_keyframes_import: HashSet<Box<Atom>>,
+  pub stylex_define_vars_import: HashSet<Box<Atom>>,
+  pub stylex_create_theme_import: HashSet<Box<Atom>>,
+  pub stylex_types_import: HashSet<Box<Atom>>,
+  pub inject_import_inserted: Option<(Box<Ident>, Box<Ident>)>,
+  pub theme_name: Option<String>,
+
+  pub declarations: Vec<VarDeclarator>,
+  pub top_level_expressions: Vec<TopLevelExpression>,
+  pub all_call_expressions: Vec<CallExpr>,
+  pub var_decl_count_map: HashMap<Atom, i8>,
+  // declarations bound to compiled values or with references inlined into compiled styles
+  pub compiled_var_decls: HashSet<Atom>,
+  // bindings assigned to or updated after their declaration
+  pub reassigned_bindings: HashSet<Id>,
+  pub seen: SeenCache,
+
+  // `stylex.create` calls
+  pub style_map: HashMap<String, Box<StylesObjectMap>>,
+  pub style_vars: HashMap<String, Box<VarDeclarator>>,
+
+  // results of `stylex.create` calls that should be kept
+  pub style_vars_to_keep: HashSet<Box<StyleVarsToKeep>>,
+  pub member_object_ident_count_map: HashMap<Atom, i8>,
+
+  pub in_stylex_create: bool,
+  // whether the module has the shadow DOM pragma comment
+  pub shadow_dom_pragma: bool,
+  // callee paths of the calls creating JSX elements, see `JsxRuntime`
+  pub jsx_element_factories: HashSet<String>,
+
+  pub options: Box<StyleXStateOptions>,
+  pub metadata: IndexMap<String, Vec<MetaData>>,
+  // resolved declarations of `stylex.create` calls, by their variable name
+  pub exported_styles: IndexMap<String, ExportedStyles>,
+  pub var_usage: VarUsageGraph,
+  pub defined_vars: VarUsageGraph,
+  // `<variable>.<namespace>` of the `stylex.create` namespaces using each class name
+  pub class_name_namespaces: IndexMap<String, Vec<String>>,
+  // StyleX calls of the module, collected with the `callSites` option
+  pub call_sites: Vec<CallSite>,
+  // legacy properties of the module compiled through `propertyAliases`, with their standard names
+  pub aliased_properties: IndexMap<String, String>,
+  // rules injected at runtime, by the compiled expression they belong to
+  pub styles_to_inject: IndexMap<Box<Expr>, Vec<MetaData>>,
+  pub prepend_include_module_items: Vec<ModuleItem>,
+  pub prepend_import_module_items: Vec<ModuleItem>,
+  // files imported as a referenced namespace, see `preserve_side_effect_imports`
+  pub side_effect_imports: IndexSet<String>,
+  // imports of the runtime `create` functions of uncompiled namespaces, by the binding they import again
+  pub runtime_create_imports: IndexMap<Atom, (Ident, ModuleItem)>,
+
+  pub injected_keyframes: IndexMap<String, Box<InjectableStyle>>,
+  pub top_imports: Vec<ImportDecl>,
+  // top level bindings of dynamic `import()`s, by the imported source
+  pub dynamic_imports: HashMap<Atom, String>,
+}
+
+impl Default for StateManager {
+  fn default() -> Self {
+    StateManager::new(StyleXOptions::default())
+  }
+}
+
+impl StateManager {
+  pub fn new(stylex_options: StyleXOptions) -> Self {
+    let options = Box::new(StyleXStateOptions::from(stylex_options));
+
+    Self {
+      _state: Box::<PluginPass>::default(),
+      import_paths: HashSet::new(),
+      stylex_import: HashSet::new(),
+      stylex_props_import: HashSet::new(),
+      stylex_attrs_import: HashSet::new(),
+      stylex_create_import: HashSet::new(),
+      stylex_include_import: HashSet::new(),
+      stylex_first_that_works_import: HashSet::new(),
+      stylex_keyframes_import: HashSet::new(),
+      stylex_define_vars_import: HashSet::new(),
+      stylex_create_theme_import: HashSet::new(),
+      stylex_types_import: HashSet::new(),
+      inject_import_inserted: None,
+      style_map: HashMap::new(),
+      style_vars: HashMap::new(),
+      style_vars_to_keep: HashSet::new(),
+      member_object_ident_count_map: HashMap::new(),
+      theme_name: None,
+
+      seen: SeenCache::new(options.seen_cache_capacity),
+
+      top_imports: vec![],
+      dynamic_imports: HashMap::new(),
+
+      declarations: vec![],
+      top_level_expressions: vec![],
+      all_call_expressions: vec![],
+      var_decl_count_map: HashMap::new(),
+      compiled_var_decls: HashSet::new(),
+      reassigned_bindings: HashSet::new(),
+
+      in_stylex_create: false,
+      shadow_dom_pragma: false,
+      jsx_element_factories: HashSet::new(),
+      options,
+
+      metadata: IndexMap::new(),
+      exported_styles: IndexMap::new(),
+      var_usage: VarUsageGraph::default(),
+      defined_vars: VarUsageGraph::default(),
+      class_name_namespaces: IndexMap::new(),
+      call_sites: vec![],
+      aliased_properties: IndexMap::new(),
+      styles_to_inject: IndexMap::new(),
+      prepend_include_module_items: vec![],
+      prepend_import_module_items: vec![],
+      side_effect_imports: IndexSet::new(),
+      runtime_create_imports: IndexMap::new(),
+
+      injected_keyframes: IndexMap::new(),
+    }
+  }
+
+  pub fn import_as(&self, import: &str) -> Option<String> {
+    for import_source in &self.options.import_sources {
+      match import_source {
+        ImportSources::Regular(_) => {}
+        ImportSources::Named(named) => {
+          if named.from.eq(import) {
+            return Some(named.r#as.clone());
+          }
+        }
+      }
+    }
+
+    None
+  }
+
+  pub fn import_sources(&self) -> Vec<ImportSources> {
+    self.options.import_sources.clone()
+  }
+
+  pub fn import_sources_stringified(&self) -> Vec<String> {
+    self
+      .options
+      .import_sources
+      .clone()
+      .into_iter()
+      .map(|import_source| match import_source {
+        ImportSources::Regular(regular) => regular,
+        ImportSources::Named(named) => named.from,
+      })
+      .collect()
+  }
+
+  pub fn stylex_import_stringified(&self) -> Vec<String> {
+    self
+      .stylex_import
+      .clone()
+      .into_iter()
+      .map(|import_source| match import_source.as_ref() {
+        ImportSources::Regular(regular) => regular.clone(),
+        ImportSources::Named(named) => named.clone().r#as,
+      })
+      .collect()
+  }
+
+  /// Compiled rules of the transformed module, grouped by the variable
+  /// they were declared on.
+  pub fn metadata(&self) -> &IndexMap<String, Vec<MetaData>> {
+    &self.metadata
+  }
+
+  /// Resolved declarations of the `stylex.create()` calls of the transformed
+  /// module, grouped by the variable they were declared on.
+  pub fn exported_styles(&self) -> &IndexMap<String, ExportedStyles> {
+    &self.exported_styles
+  }
+
+  /// `stylex.defineVars()` keys referenced by the transformed module.
+  pub fn var_usage(&self) -> &VarUsageGraph {
+    &self.var_usage
+  }
+
+  /// `stylex.defineVars()` keys declared by the transformed module.
+  pub fn defined_vars(&self) -> &VarUsageGraph {
+    &self.defined_vars
+  }
+
+  /// The `stylex.create()` namespaces of the transformed module using each
+  /// compiled class name, as `<variable>.<namespace>`. Namespaces sharing a
+  /// class name declare the same property, value and conditions.
+  pub fn class_name_namespaces(&self) -> &IndexMap<String, Vec<String>> {
+    &self.class_name_namespaces
+  }
+
+  /// The StyleX calls of the transformed module, in source order. Only
+  /// collected with the `callSites` option.
+  pub fn call_sites(&self) -> &[CallSite] {
+    &self.call_sites
+  }
+
+  /// Every compiled rule of the transformed module, in injection order.
+  pub fn injected_rules(&self) -> Vec<&MetaData> {
+    self.metadata.values().flatten().collect()
+  }
+
+  /// The `inject(...)` statements of the compiled rules. They are only emitted
+  /// into the module when runtime injection is enabled.
+  pub fn inject_statements(&self) -> Vec<ModuleItem> {
+    self.inject_statements_for(self.styles_to_inject.keys().map(AsRef::as_ref))
+  }
+
+  /// The `inject(...)` statements of the rules compiled into `exprs`, without
+  /// duplicates and sorted by priority like the extracted CSS. Rules of the
+  /// same priority keep their evaluation order.
+  pub fn inject_statements_for<'a>(
+    &self,
+    exprs: impl IntoIterator<Item = &'a Expr>,
+  ) -> Vec<ModuleItem> {
+    let Some((_, inject_var_ident)) = &self.inject_import_inserted else {
+      return vec![];
+    };
+
+    let mut seen_rules = HashSet::new();
+
+    let mut rules = exprs
+      .into_iter()
+      .filter_map(|expr| self.styles_to_inject.get(expr))
+      .flatten()
+      .filter(|metadata| seen_rules.insert((metadata.get_css(), metadata.get_css_rtl())))
+      .collect::<Vec<&MetaData>>();
+
+    rules.sort_by(|a, b| a.get_priority().total_cmp(b.get_priority()));
+
+    rules
+      .into_iter()
+      .map(|metadata| inject_statement(metadata, inject_var_ident))
+      .collect()
+  }
+
+  /// Side-effect imports of theme files added for `treeshakeCompensation`.
+  pub fn prepend_import_module_items(&self) -> &[ModuleItem] {
+    &self.prepend_import_module_items
+  }
+
+  /// Imports `path` once for its side effects. With
+  /// `preserve_side_effect_imports` the module is imported as a namespace
+  /// referenced right away.
+  pub fn add_side_effect_import(&mut self, path: &str) {
+    if self.options.preserve_side_effect_imports {
+      self.side_effect_imports.insert(path.to_string());
+    } else {
+      append_missing(
+        &mut self.prepend_import_module_items,
+        &[add_import_expression(path)],
+      );
+    }
+  }
+
+  /// The runtime `create` function creating the namespaces of a
+  /// `stylex.create()` call that can't be compiled, read from an import of
+  /// its own rather than the compile time `callee`. Calls sharing a binding
+  /// share the import.
+  pub fn runtime_create_callee(&mut self, callee: &Expr) -> Expr {
+    let Some(binding) = get_callee_binding(callee) else {
+      return callee.clone();
+    };
+
+    if let Some((local, _)) = self.runtime_create_imports.get(&binding.sym) {
+      return rebind_callee(callee, local);
+    }
+
+    let local = UidGenerator::new(&binding.sym).generate_ident();
+
+    let import = self.top_imports.iter().find_map(|import_decl| {
+      import_decl
+        .specifiers
+        .iter()
+        .find(|specifier| get_specifier_local(specifier).sym == binding.sym)
+        .map(|specifier| runtime_import(import_decl, specifier, &local))
+    });
+
+    let Some(import) = import else {
+      return callee.clone();
+    };
+
+    self
+      .runtime_create_imports
+      .insert(binding.sym.clone(), (local.clone(), import));
+
+    rebind_callee(callee, &local)
+  }
+
+  /// Imports of the runtime `create` functions, see
+  /// [`StateManager::runtime_create_callee`].
+  pub fn runtime_create_import_module_items(&self) -> Vec<ModuleItem> {
+    self
+      .runtime_create_imports
+      .values()
+      .map(|(_, import)| import.clone())
+      .collect()
+  }
+
+  pub fn side_effect_import_module_items(&self) -> Vec<ModuleItem> {
+    let uid_generator = UidGenerator::new("sideEffect");
+
+    self
+      .side_effect_imports
+      .iter()
+      .flat_map(|path| {
+        add_referenced_namespace_import_expressions(&uid_generator.generate_ident(), path)
+      })
+      .collect()
+  }
+
+  pub fn is_test(&self) -> bool {
+    self.options.test
+  }
+
+  pub fn is_dev(&self) -> bool {
+    self.options.dev
+  }
+
+  pub fn gen_conditional_classes(&self) -> bool {
+    self.options.gen_conditional_classes
+  }
+
+  pub fn resolve_external_packages(&self) -> bool {
+    self.options.resolve_external_packages
+  }
+
+  /// Whether `filename` is a file that may contain `stylex.defineVars()` calls.
+  pub fn is_valid_vars_file(&self, filename: &str) -> bool {
+    match &self.options.valid_vars_file_suffixes {
+      Some(suffixes) => suffixes
+        .iter()
+        .any(|suffix| matches_file_suffix(suffix, filename)),
+      None => true,
+    }
+  }
+
+  /// Whether the file matches one of the `include` patterns, if any, and
+  /// none of the `exclude` ones.
+  pub fn is_transformed_file(&self) -> bool {
+    let paths = self.get_filter_paths();
+
+    let matches_any = |patterns: &[String]| {
+      patterns.iter().any(|pattern| {
+        let pattern =
+          Pattern::new(pattern).unwrap_or_else(|_| panic!("{} {:?}", INVALID_FILE_GLOB, pattern));
+
+        paths
+          .iter()
+          .any(|path| pattern.matches_with(path, FILE_GLOB_OPTIONS))
+      })
+    };
+
+    (self.options.include.is_empty() || matches_any(&self.options.include))
+      && !matches_any(&self.options.exclude)
+  }
+
+  /// The absolute filename and, within the `cwd`, the relative one.
+  fn get_filter_paths(&self) -> Vec<String> {
+    let FileName::Real(filename) = &self._state.filename else {
+      return vec![];
+    };
+
+    let relative_filename = self
+      ._state
+      .cwd
+      .as_ref()
+      .and_then(|cwd| filename.strip_prefix(cwd).ok())
+      .map(|path| normalize_path_separators(&path.to_string_lossy()));
+
+    std::iter::once(self.get_filename())
+      .chain(relative_filename)
+      .collect()
+  }
+
+  /// Whether `path` points to a theme file. Configured vars file suffixes take
+  /// precedence over `theme_file_extension`, so imports of any other file are
+  /// rejected before the resolver touches the file system.
+  fn matches_theme_file(&self, theme_file_extension: &str, path: &str) -> bool {
+    match &self.options.valid_vars_file_suffixes {
+      Some(suffixes) => suffixes
+        .iter()
+        .any(|suffix| matches_file_suffix(strip_file_extension(suffix), path)),
+      None => matches_file_suffix(theme_file_extension, path),
+    }
+  }
+
+  /// The host selector scoping the rules of this file to a shadow root, see
+  /// [`ShadowDom`](super::stylex_options::ShadowDom).
+  pub fn get_shadow_host(&self) -> Option<String> {
+    let shadow_dom = self.options.shadow_dom.clone().unwrap_or_default();
+
+    let filename = self.get_filename();
+
+    let is_scoped = self.shadow_dom_pragma
+      || shadow_dom
+        .file_suffixes
+        .iter()
+        .any(|suffix| matches_file_suffix(suffix, &filename));
+
+    is_scoped.then(|| shadow_dom.host_selector())
+  }
+
+  pub fn get_short_filename(&self) -> String {
+    extract_filename_from_path(&self._state.filename)
+  }
+  pub fn get_filename(&self) -> String {
+    extract_path(&self._state.filename)
+  }
+  pub fn get_filename_for_hashing(&self) -> Option<String> {
+    let filename = self.get_filename();
+
+    let unstable_module_resolution = self
+      .options
+      .unstable_module_resolution
+      .clone()
+      .unwrap_or_default();
+
+    let theme_file_extension = match &unstable_module_resolution {
+      CheckModuleResolution::CommonJS(ModuleResolution {
+        theme_file_extension,
+        ..
+      }) => theme_file_extension,
+      CheckModuleResolution::Haste(ModuleResolution {
+        theme_file_extension,
+        ..
+      }) => theme_file_extension,
+      CheckModuleResolution::CrossFileParsing(ModuleResolution {
+        theme_file_extension,
+        ..
+      }) => theme_file_extension,
+    }
+    .clone()
+    .unwrap_or(".stylex".to_string());
+
+    if filename.is_empty()
+      || !self.matches_theme_file(theme_file_extension.as_str(), &filename)
+      || self.options.unstable_module_resolution.is_none()
+    {
+      return None;
+    }
+
+    match unstable_module_resolution {
+      CheckModuleResolution::Haste(_) => {
+        let filename = FileName::Real(filename.into());
+        extract_filename_with_ext_from_path(&filename)
+      }
+      CheckModuleResolution::CommonJS(module_resolution)
+      | CheckModuleResolution::CrossFileParsing(module_resolution) => {
+        let root_dirs = module_resolution.get_root_dirs();
+
+        if root_dirs.is_empty() {
+          panic!("root_dir is required for CommonJS");
+        }
+
+        let filename = Path::new(&filename);
+
+        if !self.resolve_external_packages() && find_root_dir(filename, &root_dirs).is_none() {
+          panic!("{}", ResolveError::OutsideRoot(filename.to_path_buf()));
+        }
+
+        let filename_for_hashing = match resolve_path_in_roots(
+          filename,
+          &root_dirs,
+          &module_resolution.resolver_options(),
+        ) {
+          Ok(resolved_path) => resolved_path,
+          Err(error) => panic!("{}", error),
+        };
+
+        Some(filename_for_hashing)
+      }
+    }
+  }
+
+  /// `name@version` of the package owning the transformed file, when the
+  /// `hashPackageScope` option is enabled.
+  pub fn get_package_scope(&self) -> Option<String> {
+    if !self.options.hash_package_scope {
+      return None;
+    }
+
+    package_scope_of(Path::new(&self.get_filename()))
+  }
+
+  /// Package scope of the vars file identified by `file_name_for_hashing`,
+  /// read from the `package.json` of the vars file itself so
+  /// `stylex.defineVars()` and the files importing its variables agree on it.
+  ///
+  /// Vars files the identifier doesn't locate, e.g. Haste file names, are
+  /// not scoped, neither where they are defined nor where they are used.
+  pub fn get_theme_package_scope(&self, file_name_for_hashing: &str) -> Option<String> {
+    if !self.options.hash_package_scope {
+      return None;
+    }
+
+    let root_dirs = match &self.options.unstable_module_resolution {
+      Some(
+        CheckModuleResolution::CommonJS(module_resolution)
+        | CheckModuleResolution::CrossFileParsing(module_resolution),
+      ) => module_resolution.get_root_dirs(),
+      _ => vec![],
+    };
+
+    let theme_file = find_path_in_roots(file_name_for_hashing, &root_dirs)
+      .filter(|theme_file| theme_file.exists())?;
+
+    package_scope_of(&theme_file)
+  }
+
+  pub fn import_path_resolver(&self, import_path: &str) -> ImportPathResolution {
+    let source_file_path = self.get_filename();
+
+    if source_file_path.is_empty() {
+      return ImportPathResolution::False;
+    }
+
+    let Some(unstable_module_resolution) = &self.options.unstable_module_resolution else {
+      return ImportPathResolution::False;
+    };
+
+    match unstable_module_resolution {
+      CheckModuleResolution::CommonJS(module_resolution) => {
+        let root_dirs = module_resolution.get_root_dirs();
+
+        if root_dirs.is_empty() {
+          panic!("root_dir is required for CommonJS");
+        }
+
+        let theme_file_extension = &module_resolution
+          .theme_file_extension
+          .clone()
+          .unwrap_or(".stylex".to_string());
+
+        if !self.matches_theme_file(theme_file_extension.as_str(), import_path) {
+          return ImportPathResolution::False;
+        }
+
+        // Prebuilt packages are referenced by their specifier, so the identifier
+        // stays the same no matter where (or whether) the package is installed.
+        if !self.resolve_external_packages() && is_package_specifier(import_path) {
+          return ImportPathResolution::Tuple(
+            ImportPathResolutionType::ThemeNameRef,
+            import_path.to_string(),
+          );
+        }
+
+        let resolved_file_path = file_path_resolver(
+          import_path,
+          source_file_path,
+          &root_dirs,
+          &module_resolution.resolver_options(),
+        );
+
+        ImportPathResolution::Tuple(ImportPathResolutionType::ThemeNameRef, resolved_file_path)
+      }
+      CheckModuleResolution::Haste(module_resolution) => {
+        let theme_file_extension = module_resolution
+          .theme_file_extension
+          .clone()
+          .unwrap_or(".stylex".to_string());
+
+        if !self.matches_theme_file(theme_file_extension.as_str(), import_path) {
+          return ImportPathResolution::False;
+        }
+
+        ImportPathResolution::Tuple(
+          ImportPathResolutionType::ThemeNameRef,
+          add_file_extension(import_path, &source_file_path),
+        )
+      }
+      _ => unimplemented!("Module resolution is not supported"),
+    }
+  }
+
+  /// Returns the macro `ident` refers to when it is imported from one of the
+  /// `macroModules`.
+  pub fn get_macro_function(&self, ident: &Ident) -> Option<MacroFunction> {
+    let import_decl = get_import_from(self, ident)?;
+    let source = import_decl.src.value.as_str();
+
+    if !self
+      .options
+      .macro_modules
+      .iter()
+      .any(|macro_module| macro_module == source)
+    {
+      return None;
+    }
+
+    let imported_name = get_imported_name(import_decl, ident)?;
+
+    let path = resolve_module_path(source, &self.get_filename())
+      .unwrap_or_else(|| panic!("{} {}", UNLOADABLE_MACRO_MODULE, source));
+
+    let macro_function = MacroModule::load(&path)
+      .get(&imported_name)
+      .unwrap_or_else(|error| panic!("{}", error));
+
+    Some(macro_function)
+  }
+
+  /// Returns the object `expr` refers to, e.g. `typography.body`, when it is
+  /// exported by a [`TokenModule`] the file imports.
+  pub fn get_imported_token_object(&self, expr: &Expr) -> Option<ObjectLit> {
+    let mut members = vec![];
+    let mut object = expr;
+
+    while let Expr::Member(member) = object {
+      members.push(match &member.prop {
+        MemberProp::Ident(ident) => ident.sym.to_string(),
+        MemberProp::Computed(computed) => match computed.expr.as_lit() {
+          Some(Lit::Str(str)) => str.value.to_string(),
+          _ => return None,
+        },
+        MemberProp::PrivateName(_) => return None,
+      });
+
+      object = &member.obj;
+    }
+
+    members.reverse();
+
+    let ident = object.as_ident()?;
+    let import_decl = get_import_from(self, ident)?;
+
+    // Namespace imports select the export with their first member
+    let export_name = match get_imported_name(import_decl, ident) {
+      Some(export_name) => export_name,
+      None if !members.is_empty() => members.remove(0),
+      None => return None,
+    };
+
+    let path = resolve_module_path(&import_decl.src.value, &self.get_filename())?;
+
+    TokenModule::load(&path)?
+      .get(&export_name, &members)
+      .cloned()
+  }
+
+  pub fn get_top_level_expr(
+    &self,
+    kind: &TopLevelExpressionKind,
+    call: &CallExpr,
+  ) -> Option<TopLevelExpression> {
+    self
+      .top_level_expressions
+      .clone()
+      .into_iter()
+      .find(|tpe| kind.eq(&tpe.0) && tpe.1.eq(&Box::new(Expr::Call(call.clone()))))
+  }
+
+  /// Returns the name a top level `call` is exported under, `default` for
+  /// default exports, or `None` when it is not exported.
+  pub fn get_export_name(&self, call: &CallExpr) -> Option<String> {
+    [
+      TopLevelExpressionKind::NamedExport,
+      TopLevelExpressionKind::DefaultExport,
+    ]
+    .iter()
+    .find_map(|kind| self.get_top_level_expr(kind, call))
+    .and_then(|expr| expr.2)
+    .map(|name| name.to_string())
+  }
+
+  pub fn register_styles(
+    &mut self,
+    call: &CallExpr,
+    style: &IndexMap<String, Box<InjectableStyle>>,
+    ast: &Expr,
+    var_name: &Option<String>,
+  ) {
+    if let Some(var_name) = var_name {
+      self.compiled_var_decls.insert(var_name.as_str().into());
+    }
+
+    if style.is_empty() {
+      return;
+    }
+
+    let metadatas = MetaData::convert_from_injected_styles_map(style);
+
+    let mut uid_generator_inject = UidGenerator::new("inject");
+
+    let runtime_injection_default = &RuntimeInjectionState::Regular(String::default());
+
+    let runtime_injection = self
+      .options
+      .runtime_injection
+      .as_ref()
+      .unwrap_or(runtime_injection_default);
+
+    let (inject_module_ident, inject_var_ident) = match self.inject_import_inserted.clone() {
+      Some(idents) => idents,
+      None => {
+        let inject_module_ident = Box::new(uid_generator_inject.generate_ident());
+
+        let inject_var_ident = Box::new(match &runtime_injection {
+          RuntimeInjectionState::Regular(_) => uid_generator_inject.generate_ident(),
+          RuntimeInjectionState::Named(NamedImportSource { r#as, .. }) => {
+            uid_generator_inject = UidGenerator::new(r#as);
+            uid_generator_inject.generate_ident()
+          }
+        });
+
+        self.inject_import_inserted = Some((inject_module_ident.clone(), inject_var_ident.clone()));
+
+        (inject_module_ident, inject_var_ident)
+      }
+    };
+
+    if !metadatas.is_empty() && self.prepend_include_module_items.is_empty() {
+      let first_module_items = match runtime_injection {
+        _ if self.options.runtime_injection_target
+          == RuntimeInjectionTarget::ConstructableStylesheet =>
+        {
+          add_inject_constructable_stylesheet_expressions(&inject_module_ident, &inject_var_ident)
+        }
+        RuntimeInjectionState::Regular(_) => vec![
+          add_inject_default_import_expression(&inject_module_ident),
+          add_inject_var_decl_expression(&inject_var_ident, &inject_module_ident),
+        ],
+        RuntimeInjectionState::Named(_) => {
+          vec![
+            add_inject_named_import_expression(&inject_module_ident, &inject_var_ident),
+            add_inject_var_decl_expression(&inject_var_ident, &inject_module_ident),
+          ]
+        }
+      };
+
+      self.prepend_include_module_items.extend(first_module_items);
+    }
+
+    for metadata in metadatas {
+      self.add_style(
+        var_name.clone().unwrap_or("default".to_string()),
+        metadata.clone(),
+      );
+
+      self.add_style_to_inject(metadata, ast);
+    }
+
+    if self.options.runtime_injection.is_none() {
+      return;
+    }
+
+    if let Some(item) = self
+      .declarations
+      .iter_mut()
+      .find(|decl| match decl.init.as_ref() {
+        Some(boxed_expr) => {
+          matches!(**boxed_expr, Expr::Call(ref existing_call) if existing_call == call)
+        }
+        None => false,
+      })
+    {
+      item.init = Some(Box::new(ast.clone())); // Only clone ast here
+    };
+
+    if let Some((_, item)) = self
+      .style_vars
+      .iter_mut()
+      .find(|(_, decl)| match decl.init.as_ref() {
+        Some(expr) => matches!(**expr, Expr::Call(ref existing_call) if existing_call == call),
+        None => false,
+      })
+    {
+      item.init = Some(Box::new(ast.clone())); // Clone `ast` only when necessary
+    };
+
+    if let Some(TopLevelExpression(_, item, _)) = self
+      .top_level_expressions
+      .iter_mut()
+      .find(|TopLevelExpression(_, decl, _)| matches!(decl, Expr::Call(c) if c == call))
+    {
+      *item = ast.clone();
+    };
+
+    if let Some((index, _)) = self
+      .all_call_expressions
+      .iter()
+      .enumerate()
+      .find(|(_, expr)| expr.eq_ignore_span(&call))
+    {
+      match ast.as_call() {
+        Some(call_expr) => self.all_call_expressions[index] = call_expr.clone(),
+        None => {
+          self.all_call_expressions.remove(index);
+        }
+      }
+    }
+  }
+
+  fn add_style(&mut self, var_name: String, metadata: MetaData) {
+    let value = self.metadata.entry(var_name).or_default();
+    let class_name = metadata.get_class_name(); // Cache the class name
+
+    if !value.iter().any(|item| item.get_class_name() == class_name) {
+      value.push(metadata);
+    }
+  }
+
+  fn add_style_to_inject(&mut self, metadata: MetaData, ast: &Expr) {
+    self
+      .styles_to_inject
+      .entry(Box::new(ast.clone()))
+      .or_default()
+      .push(metadata);
+  }
+
+  // pub fn _get_css_vars(&self) -> HashMap<String, String> {
+  //   self.options.defined_stylex_css_variables.clone()
+  // }
+
+  pub fn get_treeshake_compensation(&self) -> bool {
+    self.options.treeshake_compensation.unwrap_or(false)
+  }
+
+  /// Merges `other` into this state after a nested evaluation.
+  ///
+  /// `other` is usually a copy of this state taken earlier, such as the one
+  /// a `ThemeRef` holds, so every field has a merge policy that adds nothing
+  /// twice and never replaces newer values with older ones:
+  ///
+  /// - sets are united,
+  /// - items, e.g. the module items to prepend, are appended unless present,
+  /// - counters keep the higher count,
+  /// - keyed values keep the entry of this state and add the missing keys,
+  /// - optional values keep the first one set and flags are or-ed.
+  pub fn combine(&mut self, other: &Self) {
+    union_hash_set(&mut self.import_paths, &other.import_paths);
+    union_hash_set(&mut self.stylex_import, &other.stylex_import);
+    union_hash_set(&mut self.stylex_props_import, &other.stylex_props_import);
+    union_hash_set(&mut self.stylex_attrs_import, &other.stylex_attrs_import);
+    union_hash_set(&mut self.stylex_create_import, &other.stylex_create_import);
+    union_hash_set(
+      &mut self.stylex_include_import,
+      &other.stylex_include_import,
+    );
+    union_hash_set(
+      &mut self.stylex_first_that_works_import,
+      &other.stylex_first_that_works_import,
+    );
+    union_hash_set(
+      &mut self.stylex_keyframes_import,
+      &other.stylex_keyframes_import,
+    );
+    union_hash_set(
+      &mut self.stylex_define_vars_import,
+      &other.stylex_define_vars_import,
+    );
+    union_hash_set(
+      &mut self.stylex_create_theme_import,
+      &other.stylex_create_theme_import,
+    );
+    union_hash_set(&mut self.stylex_types_import, &other.stylex_types_import);
+    union_hash_set(&mut self.compiled_var_decls, &other.compiled_var_decls);
+    union_hash_set(&mut self.reassigned_bindings, &other.reassigned_bindings);
+    union_hash_set(&mut self.style_vars_to_keep, &other.style_vars_to_keep);
+    union_hash_set(
+      &mut self.jsx_element_factories,
+      &other.jsx_element_factories,
+    );
+    self
+      .side_effect_imports
+      .extend(other.side_effect_imports.iter().cloned());
+    self.var_usage.merge(&other.var_usage);
+    self.defined_vars.merge(&other.defined_vars);
+    self.seen.merge(&other.seen);
+
+    append_missing(&mut self.declarations, &other.declarations);
+    append_missing(
+      &mut self.top_level_expressions,
+      &other.top_level_expressions,
+    );
+    append_missing(&mut self.all_call_expressions, &other.all_call_expressions);
+    append_missing(&mut self.call_sites, &other.call_sites);
+    append_missing(
+      &mut self.prepend_include_module_items,
+      &other.prepend_include_module_items,
+    );
+    append_missing(
+      &mut self.prepend_import_module_items,
+      &other.prepend_import_module_items,
+    );
+    append_missing(&mut self.top_imports, &other.top_imports);
+
+    max_counts(&mut self.var_decl_count_map, &other.var_decl_count_map);
+    max_counts(
+      &mut self.member_object_ident_count_map,
+      &other.member_object_ident_count_map,
+    );
+
+    insert_missing_hash_map(&mut self.style_map, &other.style_map);
+    insert_missing_hash_map(&mut self.style_vars, &other.style_vars);
+    insert_missing_hash_map(&mut self.dynamic_imports, &other.dynamic_imports);
+    insert_missing_index_map(&mut self.metadata, &other.metadata);
+    insert_missing_index_map(&mut self.exported_styles, &other.exported_styles);
+    insert_missing_index_map(
+      &mut self.class_name_namespaces,
+      &other.class_name_namespaces,
+    );
+    insert_missing_index_map(&mut self.aliased_properties, &other.aliased_properties);
+    insert_missing_index_map(&mut self.styles_to_inject, &other.styles_to_inject);
+    insert_missing_index_map(&mut self.injected_keyframes, &other.injected_keyframes);
+    insert_missing_index_map(
+      &mut self.runtime_create_imports,
+      &other.runtime_create_imports,
+    );
+
+    if self.inject_import_inserted.is_none() {
+      self.inject_import_inserted = other.inject_import_inserted.clone();
+    }
+    if self.theme_name.is_none() {
+      self.theme_name = other.theme_name.clone();
+    }
+
+    self.in_stylex_create |= other.in_stylex_create;
+    self.shadow_dom_pragma |= other.shadow_dom_pragma;
+  }
+}
+
+fn inject_statement(metadata: &MetaData, inject_var_ident: &Ident) -> ModuleItem {
+  let mut stylex_inject_args = vec![
+    expr_or_spread_string_expression_factory(metadata.get_css()),
+    expr_or_spread_number_expression_factory(round_f64(*metadata.get_priority(), 1)),
+  ];
+
+  if let Some(rtl) = metadata.get_css_rtl() {
+    stylex_inject_args.push(expr_or_spread_string_expression_factory(rtl.as_str()));
+  }
+
+  let stylex_call_expr = CallExpr {
+    span: DUMMY_SP,
+    type_args: None,
+    callee: Callee::Expr(Box::new(Expr::Ident(inject_var_ident.clone()))),
+    args: stylex_inject_args,
+  };
+
+  ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+    span: DUMMY_SP,
+    expr: Box::new(Expr::Call(stylex_call_expr)),
+  }))
+}
+
+fn add_inject_default_import_expression(ident: &Ident) -> ModuleItem {
+  ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+    span: DUMMY_SP,
+    specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
+      span: DUMMY_SP,
+      local: ident.clone(),
+    })],
+    src: Box::new(Str {
+      span: DUMMY_SP,
+      raw: None,
+      value: DEFAULT_INJECT_PATH.into(),
+    }),
+    type_only: false,
+    with: None,
+    phase: ImportPhase::Evaluation,
+  }))
+}
+
+pub fn add_import_expression(path: &str) -> ModuleItem {
+  ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+    span: DUMMY_SP,
+    specifiers: vec![],
+    src: Box::new(Str {
+      span: DUMMY_SP,
+      raw: None,
+      value: path.into(),
+    }),
+    type_only: false,
+    with: None,
+    phase: ImportPhase::Evaluation,
+  }))
+}
+
+/// `import * as ident from "path"; void ident;`, which keeps the module in
+/// the graph of bundlers that drop unused imports of side effect free
+/// packages.
+fn add_referenced_namespace_import_expressions(ident: &Ident, path: &str) -> Vec<ModuleItem> {
+  vec![
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+      span: DUMMY_SP,
+      specifiers: vec![ImportSpecifier::Namespace(ImportStarAsSpecifier {
+        span: DUMMY_SP,
+        local: ident.clone(),
+      })],
+      src: Box::new(Str {
+        span: DUMMY_SP,
+        raw: None,
+        value: path.into(),
+      }),
+      type_only: false,
+      with: None,
+      phase: ImportPhase::Evaluation,
+    })),
+    ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+      span: DUMMY_SP,
+      expr: Box::new(Expr::Unary(UnaryExpr {
+        span: DUMMY_SP,
+        op: UnaryOp::Void,
+        arg: Box::new(Expr::Ident(ident.clone())),
+      })),
+    })),
+  ]
+}
+
+fn add_inject_named_import_expression(ident: &Ident, imported_ident: &Ident) -> ModuleItem {
+  ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+    span: DUMMY_SP,
+    specifiers: vec![ImportSpecifier::Named(ImportNamedSpecifier {
+      span: DUMMY_SP,
+      local: ident.clone(),
+      imported: Some(ModuleExportName::Ident(imported_ident.clone())),
+      is_type_only: false,
+    })],
+    src: Box::new(Str {
+      span: DUMMY_SP,
+      raw: None,
+      value: DEFAULT_INJECT_PATH.into(),
+    }),
+    type_only: false,
+    with: None,
+    phase: ImportPhase::Evaluation,
+  }))
+}
+
+/// Declares `sheet_ident` and `inject_ident` with the inline runtime of the
+/// `constructable-stylesheet` injection target, which needs no import.
+fn add_inject_constructable_stylesheet_expressions(
+  sheet_ident: &Ident,
+  inject_ident: &Ident,
+) -> Vec<ModuleItem> {
+  let source = CONSTRUCTABLE_STYLESHEET_RUNTIME
+    .replace("__SHEET__", &sheet_ident.sym)
+    .replace("__INJECT__", &inject_ident.sym);
+
+  let source_map: Lrc<SourceMap> = Default::default();
+  let file = source_map.new_source_file(FileName::Anon, source);
+
+  let module = parse_file_as_module(
+    &file,
+    Syntax::Es(Default::default()),
+    EsVersion::latest(),
+    None,
+    &mut vec![],
+  )
+  .expect("Constructable stylesheet runtime should parse");
+
+  drop_span(module.body)
+}
+
+fn add_inject_var_decl_expression(decl_ident: &Ident, value_ident: &Ident) -> ModuleItem {
+  ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+    declare: false,
+    decls: vec![VarDeclarator {
+      definite: true,
+      span: DUMMY_SP,
+      name: Pat::from(binding_ident_factory(decl_ident.clone())),
+      init: Some(Box::new(Expr::from(value_ident.clone()))),
+    }],
+    kind: VarDeclKind::Var,
+    span: DUMMY_SP,
+  }))))
+}
+
+pub fn matches_file_suffix(allowed_suffix: &str, filename: &str) -> bool {
+  if filename.ends_with(allowed_suffix) {
+    return true;
+  }
+
+  EXTENSIONS.iter().any(|&suffix| {
+    let suffix = if allowed_suffix.is_empty() {
+      suffix
+    } else {
+      &format!("{}{}", allowed_suffix, suffix)[..]
+    };
+    filename.ends_with(suffix)
+  })
+}
+
+fn strip_file_extension(suffix: &str) -> &str {
+  EXTENSIONS
+    .iter()
+    .find_map(|ext| suffix.strip_suffix(ext))
+    .unwrap_or(suffix)
+}
+
+fn is_package_specifier(import_path: &str) -> bool {
+  !import_path.starts_with('.') && !import_path.starts_with('/')
+}
+
+fn add_file_extension(imported_file_path: &str, source_file: &str) -> String {
+  if EXTENSIONS
+    .iter()
+    .any(|ext| imported_file_path.ends_with(ext))
+  {
+    return imported_file_path.to_string();
+  }
+
+  let file_extension = Path::new(source_file)
+    .extension()
+    .and_then(std::ffi::OsStr::to_str)
+    .unwrap_or_default();
+
+  if file_extension.is_empty() {
+    return imported_file_path.to_string();
+  }
+
+  format!("{}.{}", imported_file_path, file_extension)
+}
+
+fn union_hash_set<T: Clone + Eq + Hash>(set: &mut HashSet<T>, other: &HashSet<T>) {
+  set.extend(other.iter().cloned());
+}
+
+fn append_missing<T: Clone + PartialEq>(items: &mut Vec<T>, other: &[T]) {
+  for item in other {
+    if !items.contains(item) {
+      items.push(item.clone());
+    }
+  }
+}
+
+fn max_counts<K: Clone + Eq + Hash>(counts: &mut HashMap<K, i8>, other: &HashMap<K, i8>) {
+  for (key, count) in other {
+    let entry = counts.entry(key.clone()).or_insert(*count);
+
+    *entry = (*entry).max(*count);
+  }
+}
+
+fn insert_missing_hash_map<K: Clone + Eq + Hash, V: Clone>(
+  map: &mut HashMap<K, V>,
+  other: &HashMap<K, V>,
+) {
+  for (key, value) in other {
+    map.entry(key.clone()).or_insert_with(|| value.clone());
+  }
+}
+
+fn insert_missing_index_map<K: Clone + Eq + Hash, V: Clone>(
+  map: &mut IndexMap<K, V>,
+  other: &IndexMap<K, V>,
+) {
+  for (key, value) in other {
+    map.entry(key.clone()).or_insert_with(|| value.clone());
+  }
+}
+
+/// `*` and `?` stop at path separators, only `**` crosses directories.
+const FILE_GLOB_OPTIONS: MatchOptions = MatchOptions {
+  case_sensitive: true,
+  require_literal_separator: true,
+  require_literal_leading_dot: false,
+};
+
+/// Package scopes by directory, read again once the `package.json` they were
+/// read from changes, e.g. in watch mode.
+static PACKAGE_SCOPES: Lazy<DashMap<PathBuf, PackageScope>> = Lazy::new(DashMap::new);
+
+#[derive(Clone)]
+struct PackageScope {
+  /// The `package.json` the scope was read from, with its modification time.
+  package_json: Option<(PathBuf, Option<SystemTime>)>,
+  scope: Option<String>,
+}
+
+impl PackageScope {
+  fn is_fresh(&self) -> bool {
+    self
+      .package_json
+      .as_ref()
+      .is_some_and(|(path, modified)| modified_time(path) == *modified)
+  }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+  fs::metadata(path)
+    .and_then(|metadata| metadata.modified())
+    .ok()
+}
+
+fn package_scope_of(file_path: &Path) -> Option<String> {
+  let directory = file_path.parent()?;
+
+  if let Some(cached) = PACKAGE_SCOPES.get(directory) {
+    if cached.is_fresh() {
+      return cached.scope.clone();
+    }
+  }
+
+  let package_scope = PackageScope {
+    package_json: find_package_json(directory).map(|path| {
+      let modified = modified_time(&path);
+
+      (path, modified)
+    }),
+    scope: get_package_scope(directory),
+  };
+
+  PACKAGE_SCOPES.insert(directory.to_path_buf(), package_scope.clone());
+
+  package_scope.scope
+}
+
+fn file_path_resolver(
+  relative_file_path: &str,
+  source_file_path: String,
+  root_dirs: &[PathBuf],
+  resolver_options: &ResolverOptions,
+) -> String {
+  // Specifiers like `./tokens.stylex.js` already name the file to resolve
+  let imported_ext = EXTENSIONS
+    .iter()
+    .find(|ext| relative_file_path.ends_with(*ext));
+
+  let extensions = match imported_ext {
+    Some(ext) => std::slice::from_ref(ext),
+    None => &EXTENSIONS[..],
+  };
+
+  for ext in extensions.iter() {
+    let import_path_str = if relative_file_path.starts_with('.') && imported_ext.is_none() {
+      format!("{}{}", relative_file_path, ext)
+    } else {
+      relative_file_path.to_string()
+    };
+
+    let resolved_file_path = resolve_file_path_in_roots(
+      &import_path_str,
+      &source_file_path,
+      ext,
+      root_dirs,
+      resolver_options,
+    );
+
+    if let Ok(resolved_path) = resolved_file_path {
+      let resolved_path_str = resolved_path.display().to_string();
+
+      if resolved_path_str.contains("/app/@") {
+        return resolved_path_str.replace("/app/@", "/node_modules/@");
+      } else {
+        return resolved_path_str;
+      }
+    }
+  }
+
+  panic!("Cannot resolve file path: {}", relative_file_path)
+}
@@ -0,0 +1,679 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use serde::Deserialize;
+use stylex_path_resolver::resolvers::{ResolverOptions, TargetEnv};
+
+use crate::shared::{
+  constants::{
+    common::{
+      DEFAULT_CSS_IMPORT, DEFAULT_INJECT_PATH, DEFAULT_MAX_RULE_SIZE, DEFAULT_SEEN_CACHE_CAPACITY,
+    },
+    messages::{INVALID_COMPAT_VERSION, INVALID_PSEUDO_CLASS_ORDER, UNSUPPORTED_METADATA_VERSION},
+  },
+  utils::css::normalizers::pipeline::CssNormalizerPipeline,
+};
+
+use super::{
+  application_order::PseudoClassOrder,
+  compat_version::CompatVersion,
+  meta_data::METADATA_VERSION,
+  named_import_source::{ImportSources, RuntimeInjection, RuntimeInjectionTarget},
+  options_conflict::OptionsConflict,
+  stylex_options_builder::StyleXOptionsBuilder,
+};
+
+#[derive(Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct StyleXOptionsParams {
+  pub style_resolution: Option<StyleResolution>,
+  pub use_rem_for_font_size: Option<bool>,
+  pub runtime_injection: Option<bool>,
+  pub runtime_injection_target: Option<RuntimeInjectionTarget>,
+  pub class_name_prefix: Option<String>,
+  pub defined_stylex_css_variables: Option<HashMap<String, String>>,
+  pub import_sources: Option<Vec<ImportSources>>,
+  pub treeshake_compensation: Option<bool>,
+  pub gen_conditional_classes: Option<bool>,
+  pub dev: Option<bool>,
+  pub test: Option<bool>,
+  pub aliases: Option<Aliases>,
+  #[serde(rename = "unstable_moduleResolution")]
+  pub unstable_module_resolution: Option<ModuleResolution>,
+  pub resolve_external_packages: Option<bool>,
+  pub valid_vars_file_suffixes: Option<Vec<String>>,
+  pub cache_vars: Option<bool>,
+  pub class_name_manifest: Option<String>,
+  pub hash_package_scope: Option<bool>,
+  pub transforms: Option<Transforms>,
+  pub compat_version: Option<String>,
+  pub macro_modules: Option<Vec<String>>,
+  pub unit_helpers: Option<UnitHelpers>,
+  pub env: Option<HashMap<String, String>>,
+  pub shadow_dom: Option<ShadowDom>,
+  pub export_styles: Option<bool>,
+  pub var_usage_graph: Option<bool>,
+  pub canonical_keyframes: Option<bool>,
+  pub include: Option<Vec<String>>,
+  pub exclude: Option<Vec<String>>,
+  pub preserve_side_effect_imports: Option<bool>,
+  pub css_import: Option<CssImport>,
+  pub metadata_version: Option<u8>,
+  pub phase_timings: Option<bool>,
+  pub seen_cache_capacity: Option<usize>,
+  pub attrs_conflicts: Option<AttrsConflicts>,
+  pub pseudo_class_order: Option<Vec<String>>,
+  pub call_sites: Option<bool>,
+  pub max_rule_size: Option<usize>,
+  pub large_values: Option<LargeValues>,
+  pub property_aliases: Option<HashMap<String, String>>,
+  pub light_dark_vars: Option<bool>,
+  pub warn_escaping_styles: Option<bool>,
+  pub runtime_create_fallback: Option<bool>,
+}
+
+impl StyleXOptionsParams {
+  /// Settings contradicting each other, all of them rather than the first
+  /// one so they can be reported together.
+  pub fn conflicts(&self) -> Vec<OptionsConflict> {
+    let is_enabled = |option: Option<bool>| option.unwrap_or(false);
+    let runtime_injection = is_enabled(self.runtime_injection) || is_enabled(self.dev);
+
+    let mut conflicts = vec![];
+
+    if is_enabled(self.dev) && is_enabled(self.test) {
+      conflicts.push(OptionsConflict::DevWithTest);
+    }
+
+    let css_import = self
+      .css_import
+      .as_ref()
+      .and_then(|css_import| css_import.specifier());
+
+    if runtime_injection && css_import.is_some() {
+      conflicts.push(OptionsConflict::CssImportWithRuntimeInjection);
+    }
+
+    if !runtime_injection && self.runtime_injection_target.is_some() {
+      conflicts.push(OptionsConflict::RuntimeInjectionTargetWithoutRuntimeInjection);
+    }
+
+    conflicts
+  }
+}
+
+#[cfg(feature = "json-schema")]
+impl StyleXOptionsParams {
+  /// JSON schema of the options accepted by the plugin, derived from their
+  /// serde representation.
+  pub fn json_schema() -> serde_json::Value {
+    serde_json::to_value(schemars::schema_for!(StyleXOptionsParams))
+      .expect("StyleX options schema should serialize")
+  }
+}
+
+impl Default for StyleXOptionsParams {
+  fn default() -> Self {
+    StyleXOptionsParams {
+      style_resolution: Some(StyleResolution::ApplicationOrder),
+      use_rem_for_font_size: Some(false),
+      runtime_injection: Some(false),
+      runtime_injection_target: None,
+      class_name_prefix: Some("x".to_string()),
+      defined_stylex_css_variables: Some(HashMap::new()),
+      import_sources: None,
+      treeshake_compensation: Some(true),
+      gen_conditional_classes: Some(false),
+      dev: Some(false),
+      test: Some(false),
+      aliases: None,
+      unstable_module_resolution: None,
+      resolve_external_packages: Some(true),
+      valid_vars_file_suffixes: None,
+      cache_vars: Some(false),
+      class_name_manifest: None,
+      hash_package_scope: Some(false),
+      transforms: Some(Transforms::default()),
+      compat_version: None,
+      macro_modules: None,
+      unit_helpers: None,
+      env: None,
+      shadow_dom: None,
+      export_styles: Some(false),
+      var_usage_graph: Some(false),
+      canonical_keyframes: Some(false),
+      include: None,
+      exclude: None,
+      preserve_side_effect_imports: Some(false),
+      css_import: None,
+      metadata_version: Some(METADATA_VERSION),
+      phase_timings: Some(false),
+      seen_cache_capacity: Some(DEFAULT_SEEN_CACHE_CAPACITY),
+      attrs_conflicts: Some(AttrsConflicts::Ignore),
+      pseudo_class_order: None,
+      call_sites: Some(false),
+      max_rule_size: Some(DEFAULT_MAX_RULE_SIZE),
+      large_values: Some(LargeValues::Inline),
+      property_aliases: None,
+      light_dark_vars: Some(false),
+      warn_escaping_styles: Some(false),
+      runtime_create_fallback: Some(false),
+    }
+  }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all(deserialize = "kebab-case", serialize = "PascalCase"))]
+
+pub enum StyleResolution {
+  ApplicationOrder,
+  PropertySpecificity,
+  LegacyExpandShorthands,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+
+pub enum Aliases {
+  String(HashMap<String, String>),
+  StringVec(HashMap<String, Vec<String>>),
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all(deserialize = "camelCase", serialize = "PascalCase"))]
+pub struct ModuleResolution {
+  pub r#type: String,
+  pub root_dir: Option<String>,
+  /// Source roots of a monorepo, e.g. `apps/web` and `packages/ui`, used
+  /// instead of `rootDir`. Files are identified by their path in the deepest
+  /// root containing them, prefixed with the path of that root relative to
+  /// the directory containing every root, e.g. `packages/ui/src/tokens.stylex.js`.
+  pub root_dirs: Option<Vec<String>>,
+  pub theme_file_extension: Option<String>,
+  /// Environment packages are resolved for, `node` by default.
+  pub target_env: Option<ModuleTargetEnv>,
+  /// Custom `exports` conditions of packages, e.g. `worker` or
+  /// `development`, matched besides the ones of the target environment.
+  pub conditions: Option<Vec<String>>,
+}
+
+impl ModuleResolution {
+  /// Roots files are identified relative to, `rootDirs` or else `rootDir`.
+  pub fn get_root_dirs(&self) -> Vec<PathBuf> {
+    match &self.root_dirs {
+      Some(root_dirs) if !root_dirs.is_empty() => root_dirs.iter().map(PathBuf::from).collect(),
+      _ => self.root_dir.iter().map(PathBuf::from).collect(),
+    }
+  }
+
+  pub fn resolver_options(&self) -> ResolverOptions {
+    ResolverOptions {
+      target_env: match self.target_env.clone().unwrap_or_default() {
+        ModuleTargetEnv::Node => TargetEnv::Node,
+        ModuleTargetEnv::Browser => TargetEnv::Browser,
+      },
+      conditions: self.conditions.clone().unwrap_or_default(),
+    }
+  }
+}
+
+/// Environment picking the `node` or `browser` conditions of the `exports` of
+/// packages, along with their `browser` field.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum ModuleTargetEnv {
+  #[default]
+  Node,
+  Browser,
+}
+
+/// StyleX APIs compiled by the plugin. Calls of a disabled API are left
+/// untouched, so another tool can compile them.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default)]
+pub struct Transforms {
+  pub create: bool,
+  /// Covers the legacy `stylex()` call as well.
+  pub props: bool,
+  pub attrs: bool,
+  pub keyframes: bool,
+  pub define_vars: bool,
+  pub create_theme: bool,
+}
+
+impl Default for Transforms {
+  fn default() -> Self {
+    Transforms {
+      create: true,
+      props: true,
+      attrs: true,
+      keyframes: true,
+      define_vars: true,
+      create_theme: true,
+    }
+  }
+}
+
+impl Transforms {
+  /// Whether calls of the StyleX API named `api` are compiled.
+  pub fn is_enabled(&self, api: &str) -> bool {
+    match api {
+      "create" => self.create,
+      "props" => self.props,
+      "attrs" => self.attrs,
+      "keyframes" => self.keyframes,
+      "defineVars" => self.define_vars,
+      "createTheme" => self.create_theme,
+      _ => true,
+    }
+  }
+}
+
+/// Callees converting pixels to unit strings at compile time, e.g. `rem(24)`
+/// to `'1.5rem'`. Set a name to `null` to disable its helper.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default)]
+pub struct UnitHelpers {
+  pub rem: Option<String>,
+  pub em: Option<String>,
+  /// Pixels per `rem` and `em`.
+  pub root_font_size: f64,
+}
+
+impl Default for UnitHelpers {
+  fn default() -> Self {
+    UnitHelpers {
+      rem: Some("rem".to_string()),
+      em: Some("em".to_string()),
+      root_font_size: 16.0,
+    }
+  }
+}
+
+impl UnitHelpers {
+  /// Returns the unit of the helper named `callee`.
+  pub fn unit_of(&self, callee: &str) -> Option<&'static str> {
+    if self.rem.as_deref() == Some(callee) {
+      Some("rem")
+    } else if self.em.as_deref() == Some(callee) {
+      Some("em")
+    } else {
+      None
+    }
+  }
+}
+
+/// Scopes the rules of some files to the shadow root adopting them, by
+/// prefixing their selectors with the host and turning `:root` into it.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default)]
+pub struct ShadowDom {
+  /// Files whose rules are scoped, matched by the end of their path. Files
+  /// with a `@stylex-shadow-dom` comment are scoped as well.
+  pub file_suffixes: Vec<String>,
+  /// Selector matching an ancestor of the host, which makes the scope
+  /// `:host-context(<selector>)` instead of `:host`.
+  pub host_context: Option<String>,
+}
+
+impl ShadowDom {
+  /// The selector replacing `:root` and prefixing the other selectors.
+  pub fn host_selector(&self) -> String {
+    match &self.host_context {
+      Some(context) => format!(":host-context({})", context),
+      None => ":host".to_string(),
+    }
+  }
+}
+
+/// Module imported by files with compiled rules when they are extracted, so a
+/// bundler plugin can serve all rules as a single CSS asset.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum CssImport {
+  /// `virtual:stylex.css` when `true`.
+  Enabled(bool),
+  Specifier(String),
+}
+
+impl CssImport {
+  pub fn specifier(&self) -> Option<String> {
+    match self {
+      CssImport::Enabled(true) => Some(DEFAULT_CSS_IMPORT.to_string()),
+      CssImport::Enabled(false) => None,
+      CssImport::Specifier(specifier) => Some(specifier.clone()),
+    }
+  }
+}
+
+/// Handling of literal `class` and `style` attributes of a JSX element that
+/// also spreads a `stylex.attrs()` call, where the attribute coming last
+/// replaces the other.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum AttrsConflicts {
+  #[default]
+  Ignore,
+  /// Reports which of the two attributes is dropped.
+  Warn,
+  /// Merges static string attributes into the compiled spread, and reports
+  /// the others.
+  Merge,
+}
+
+/// Handling of style values longer than `maxRuleSize`, such as data URI
+/// backgrounds, which are repeated in the rule of every condition using them.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum LargeValues {
+  /// Compiles them into their rules and reports the rules exceeding the size.
+  #[default]
+  Inline,
+  /// Declares each of them once as a custom property of `:root`, which the
+  /// rules reference with `var()`.
+  Variable,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+
+pub enum CheckModuleResolution {
+  CommonJS(ModuleResolution),
+  Haste(ModuleResolution),
+  CrossFileParsing(ModuleResolution),
+}
+
+#[derive(Clone, Debug)]
+pub struct StyleXOptions {
+  pub dev: bool,
+  pub test: bool,
+  pub use_rem_for_font_size: bool,
+  pub class_name_prefix: String,
+  // pub defined_stylex_css_variables: HashMap<String, String>,
+  pub style_resolution: StyleResolution,
+  pub runtime_injection: RuntimeInjection,
+  /// How injected rules reach the page, `<style>` elements by default.
+  pub runtime_injection_target: RuntimeInjectionTarget,
+  pub import_sources: Vec<ImportSources>,
+  pub treeshake_compensation: Option<bool>,
+  pub gen_conditional_classes: bool,
+  // pub aliases: Option<Aliases>,
+  pub unstable_module_resolution: Option<CheckModuleResolution>,
+  pub resolve_external_packages: bool,
+  pub valid_vars_file_suffixes: Option<Vec<String>>,
+  pub cache_vars: bool,
+  /// Path of the manifest assigning short class names, see
+  /// [`ClassNameManifest`](super::class_name_manifest::ClassNameManifest).
+  pub class_name_manifest: Option<String>,
+  /// Mixes the name and version of the package owning a file into its class
+  /// and variable hashes.
+  pub hash_package_scope: bool,
+  /// StyleX APIs compiled by the plugin, all of them by default.
+  pub transforms: Transforms,
+  /// `@stylexjs` release whose output is reproduced, the latest when unset.
+  pub compat_version: Option<CompatVersion>,
+  /// Import sources of modules whose exported functions are evaluated at
+  /// compile time, see [`MacroModule`](super::macro_module::MacroModule).
+  /// This is also the list of pure helper modules: there is no separate
+  /// option, and the helpers a listed module imports are loaded through the
+  /// path resolver without being listed themselves.
+  pub macro_modules: Vec<String>,
+  /// Built-in `rem()` and `em()` helpers, disabled when unset.
+  pub unit_helpers: Option<UnitHelpers>,
+  /// Values of `process.env` members in style values. Without it they are
+  /// not evaluated at compile time.
+  pub env: Option<HashMap<String, String>>,
+  /// Shadow DOM scoping of the rules, disabled when unset.
+  pub shadow_dom: Option<ShadowDom>,
+  /// Adds the resolved declarations of every `stylex.create()` namespace, and
+  /// the namespaces using each class name, to the metadata output, e.g. for
+  /// generating style reference docs or locating the class names of a build.
+  pub export_styles: bool,
+  /// Adds the `stylex.defineVars()` keys referenced by each file to the
+  /// metadata output, see [`VarUsageGraph`](super::var_usage_graph::VarUsageGraph).
+  pub var_usage_graph: bool,
+  /// Hashes `stylex.keyframes()` independently of the order of the
+  /// declarations in each frame, so equal animations share a name. Ignored
+  /// with a `compat_version`, as no release does it.
+  pub canonical_keyframes: bool,
+  /// Glob patterns of the files to transform, all of them when empty. A
+  /// pattern matches the absolute filename or the one relative to the `cwd`.
+  pub include: Vec<String>,
+  /// Glob patterns of the files left untouched, even when included.
+  pub exclude: Vec<String>,
+  /// Imports the files of variables used with `treeshakeCompensation` as a
+  /// referenced namespace instead of a bare `import "..."`, which bundlers
+  /// trusting `sideEffects: false` drop.
+  pub preserve_side_effect_imports: bool,
+  /// Specifier of the module imported by files with extracted rules, see
+  /// [`CssImport`].
+  pub css_import: Option<String>,
+  /// Shape of the rule metadata, see
+  /// [`MetaDataEnvelope`](super::meta_data::MetaDataEnvelope).
+  pub metadata_version: u8,
+  /// Measures the phases of the transform of every file, see
+  /// [`PhaseTimings`](super::phase_timings::PhaseTimings). Off by default, as
+  /// not every WASM host has a clock.
+  pub phase_timings: bool,
+  /// Number of evaluated expressions cached per file before the least
+  /// recently used ones are evicted, `0` for no limit. A warning is reported
+  /// for files reaching it.
+  pub seen_cache_capacity: usize,
+  /// Handling of literal attributes next to a `stylex.attrs()` spread, see
+  /// [`AttrsConflicts`].
+  pub attrs_conflicts: AttrsConflicts,
+  /// Order of the rules of `:hover`, `:focus` and the other user action
+  /// pseudo-classes, see [`PseudoClassOrder`].
+  pub pseudo_class_order: PseudoClassOrder,
+  /// Adds the StyleX calls of each file to the metadata output, see
+  /// [`CallSite`](super::call_site::CallSite).
+  pub call_sites: bool,
+  /// Size in bytes of a rule or style value above which it is handled as
+  /// large, `0` for no limit, see [`LargeValues`].
+  pub max_rule_size: usize,
+  /// Handling of style values above `max_rule_size`, see [`LargeValues`].
+  pub large_values: LargeValues,
+  /// Standard property of each legacy property name, e.g. `marginInline` for
+  /// the React Native `marginHorizontal`. Aliased properties compile as
+  /// their standard one and are reported as deprecated.
+  pub property_aliases: HashMap<String, String>,
+  /// Compiles variables with only a `default` and a
+  /// `@media (prefers-color-scheme: dark)` value into a single
+  /// `light-dark()` value, for targets supporting it. Pages need a
+  /// `color-scheme` including `dark` for the dark value to apply.
+  pub light_dark_vars: bool,
+  /// Warns when a `stylex.create()` namespace is passed as a prop to a
+  /// component, whose styles the file can't validate.
+  pub warn_escaping_styles: bool,
+  /// Creates the `stylex.create()` namespaces that can't be compiled with a
+  /// runtime `create` call next to the compiled ones, reported as warnings.
+  /// Otherwise they fail the build and are left as written.
+  pub runtime_create_fallback: bool,
+  /// Passes applied to every style value, in order.
+  pub css_normalizers: CssNormalizerPipeline,
+}
+
+impl StyleXOptions {
+  pub fn builder() -> StyleXOptionsBuilder {
+    StyleXOptionsBuilder::default()
+  }
+
+  pub fn get_haste_module_resolution(root_dir: Option<String>) -> ModuleResolution {
+    ModuleResolution {
+      r#type: "haste".to_string(),
+      root_dir,
+      root_dirs: None,
+      theme_file_extension: None,
+      target_env: None,
+      conditions: None,
+    }
+  }
+
+  pub fn get_common_js_module_resolution(root_dir: Option<String>) -> ModuleResolution {
+    ModuleResolution {
+      r#type: "commonjs".to_string(),
+      root_dir,
+      root_dirs: None,
+      theme_file_extension: None,
+      target_env: None,
+      conditions: None,
+    }
+  }
+}
+
+impl Default for StyleXOptions {
+  fn default() -> Self {
+    StyleXOptions {
+      style_resolution: StyleResolution::ApplicationOrder,
+      use_rem_for_font_size: false,
+      runtime_injection: RuntimeInjection::Boolean(false),
+      runtime_injection_target: RuntimeInjectionTarget::StyleElement,
+      class_name_prefix: "x".to_string(),
+      // defined_stylex_css_variables: HashMap::new(),
+      import_sources: vec![],
+      dev: false,
+      test: false,
+      treeshake_compensation: None,
+      gen_conditional_classes: false,
+      // aliases: None,
+      unstable_module_resolution: Some(CheckModuleResolution::Haste(
+        StyleXOptions::get_haste_module_resolution(None),
+      )),
+      resolve_external_packages: true,
+      valid_vars_file_suffixes: None,
+      cache_vars: false,
+      class_name_manifest: None,
+      hash_package_scope: false,
+      transforms: Transforms::default(),
+      compat_version: None,
+      macro_modules: vec![],
+      unit_helpers: None,
+      env: None,
+      shadow_dom: None,
+      export_styles: false,
+      var_usage_graph: false,
+      canonical_keyframes: false,
+      include: vec![],
+      exclude: vec![],
+      preserve_side_effect_imports: false,
+      css_import: None,
+      metadata_version: METADATA_VERSION,
+      phase_timings: false,
+      seen_cache_capacity: DEFAULT_SEEN_CACHE_CAPACITY,
+      attrs_conflicts: AttrsConflicts::Ignore,
+      pseudo_class_order: PseudoClassOrder::default(),
+      call_sites: false,
+      max_rule_size: DEFAULT_MAX_RULE_SIZE,
+      large_values: LargeValues::Inline,
+      property_aliases: HashMap::new(),
+      light_dark_vars: false,
+      warn_escaping_styles: false,
+      runtime_create_fallback: false,
+      css_normalizers: CssNormalizerPipeline::default(),
+    }
+  }
+}
+
+impl From<StyleXOptionsParams> for StyleXOptions {
+  fn from(options: StyleXOptionsParams) -> Self {
+    let unstable_module_resolution = match options.unstable_module_resolution {
+      Some(module_resolution) => match module_resolution.r#type.to_lowercase().as_str() {
+        "haste" => Some(CheckModuleResolution::Haste(module_resolution)),
+        "cross-file-parsing" => Some(CheckModuleResolution::CrossFileParsing(module_resolution)),
+        _ => Some(CheckModuleResolution::CommonJS(module_resolution)),
+      },
+      None => None,
+    };
+
+    let runtime_injection = match options.runtime_injection {
+      Some(value) => match value {
+        true => RuntimeInjection::Regular(DEFAULT_INJECT_PATH.to_string()),
+        false => RuntimeInjection::Boolean(options.dev.unwrap_or(false)),
+      },
+      None => RuntimeInjection::Boolean(options.dev.unwrap_or(false)),
+    };
+
+    StyleXOptions {
+      style_resolution: options
+        .style_resolution
+        .unwrap_or(StyleResolution::ApplicationOrder),
+      use_rem_for_font_size: options.use_rem_for_font_size.unwrap_or(false),
+      runtime_injection,
+      runtime_injection_target: options.runtime_injection_target.unwrap_or_default(),
+      class_name_prefix: options.class_name_prefix.unwrap_or("x".to_string()),
+      // defined_stylex_css_variables: options.defined_stylex_css_variables.unwrap_or_default(),
+      import_sources: options.import_sources.unwrap_or_default(),
+      dev: options.dev.unwrap_or(false),
+      test: options.test.unwrap_or(false),
+      treeshake_compensation: options.treeshake_compensation,
+      gen_conditional_classes: options.gen_conditional_classes.unwrap_or(false),
+      // aliases: options.aliases,
+      unstable_module_resolution,
+      resolve_external_packages: options.resolve_external_packages.unwrap_or(true),
+      valid_vars_file_suffixes: options.valid_vars_file_suffixes,
+      cache_vars: options.cache_vars.unwrap_or(false),
+      class_name_manifest: options.class_name_manifest,
+      hash_package_scope: options.hash_package_scope.unwrap_or(false),
+      transforms: options.transforms.unwrap_or_default(),
+      compat_version: options.compat_version.map(|version| {
+        version
+          .parse()
+          .unwrap_or_else(|version| panic!("{} {:?}", INVALID_COMPAT_VERSION, version))
+      }),
+      macro_modules: options.macro_modules.unwrap_or_default(),
+      unit_helpers: options.unit_helpers,
+      env: options.env,
+      shadow_dom: options.shadow_dom,
+      export_styles: options.export_styles.unwrap_or(false),
+      var_usage_graph: options.var_usage_graph.unwrap_or(false),
+      canonical_keyframes: options.canonical_keyframes.unwrap_or(false),
+      include: options.include.unwrap_or_default(),
+      exclude: options.exclude.unwrap_or_default(),
+      preserve_side_effect_imports: options.preserve_side_effect_imports.unwrap_or(false),
+      css_import: options
+        .css_import
+        .and_then(|css_import| css_import.specifier()),
+      metadata_version: options
+        .metadata_version
+        .map_or(METADATA_VERSION, |version| {
+          if !is_supported_metadata_version(version) {
+            panic!("{} {}", UNSUPPORTED_METADATA_VERSION, version)
+          }
+
+          version
+        }),
+      phase_timings: options.phase_timings.unwrap_or(false),
+      seen_cache_capacity: options
+        .seen_cache_capacity
+        .unwrap_or(DEFAULT_SEEN_CACHE_CAPACITY),
+      attrs_conflicts: options.attrs_conflicts.unwrap_or_default(),
+      pseudo_class_order: options.pseudo_class_order.map_or_else(
+        PseudoClassOrder::default,
+        |order| {
+          PseudoClassOrder::new(&order)
+            .unwrap_or_else(|order| panic!("{} {:?}", INVALID_PSEUDO_CLASS_ORDER, order))
+        },
+      ),
+      call_sites: options.call_sites.unwrap_or(false),
+      max_rule_size: options.max_rule_size.unwrap_or(DEFAULT_MAX_RULE_SIZE),
+      large_values: options.large_values.unwrap_or_default(),
+      property_aliases: options.property_aliases.unwrap_or_default(),
+      light_dark_vars: options.light_dark_vars.unwrap_or(false),
+      warn_escaping_styles: options.warn_escaping_styles.unwrap_or(false),
+      runtime_create_fallback: options.runtime_create_fallback.unwrap_or(false),
+      css_normalizers: CssNormalizerPipeline::default(),
+    }
+  }
+}
+
+pub(crate) fn is_supported_metadata_version(version: u8) -> bool {
+  (1..=METADATA_VERSION).contains(&version)
+}
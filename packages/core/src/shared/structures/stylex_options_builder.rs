@@ -0,0 +1,476 @@
+use std::{
+  collections::HashMap,
+  error::Error,
+  fmt::{self, Display},
+};
+
+use crate::shared::{
+  constants::messages::{
+    CONFLICTING_OPTIONS, INVALID_COMPAT_VERSION, INVALID_FILE_GLOB, INVALID_PSEUDO_CLASS_ORDER,
+    UNSUPPORTED_METADATA_VERSION,
+  },
+  utils::css::normalizers::pipeline::{CssNormalizer, CssNormalizerPipeline},
+};
+
+use super::{
+  application_order::PseudoClassOrder,
+  compat_version::CompatVersion,
+  named_import_source::{ImportSources, RuntimeInjectionTarget},
+  options_conflict::OptionsConflict,
+  stylex_options::{
+    is_supported_metadata_version, AttrsConflicts, CssImport, LargeValues, ModuleResolution,
+    ShadowDom, StyleResolution, StyleXOptions, StyleXOptionsParams, Transforms, UnitHelpers,
+  },
+};
+
+const MODULE_RESOLUTION_TYPES: [&str; 3] = ["commonjs", "haste", "cross-file-parsing"];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum StyleXOptionsError {
+  /// The class name prefix is empty or not a valid start of a CSS class name.
+  InvalidClassNamePrefix(String),
+  /// An import source, its `as` name, a macro module or the CSS import is
+  /// empty.
+  EmptyImportSource,
+  /// The module resolution type is not one of `commonjs`, `haste` or `cross-file-parsing`.
+  UnknownModuleResolution(String),
+  /// A `validVarsFileSuffixes` entry is empty.
+  EmptyVarsFileSuffix,
+  /// The compat version is not a `major.minor[.patch]` release.
+  InvalidCompatVersion(String),
+  /// An `include` or `exclude` entry is not a valid glob pattern.
+  InvalidFileGlob(String),
+  /// The metadata version is not one the plugin emits.
+  UnsupportedMetadataVersion(u8),
+  /// A `pseudoClassOrder` entry is not a plain pseudo-class, or it lists too
+  /// many of them.
+  InvalidPseudoClassOrder(String),
+  /// Some settings cannot apply together, see [`OptionsConflict`].
+  ConflictingOptions(Vec<OptionsConflict>),
+}
+
+impl Display for StyleXOptionsError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      StyleXOptionsError::InvalidClassNamePrefix(prefix) => write!(
+        f,
+        "Class name prefix must start with a letter, '_' or '-' and contain only letters, digits, '_' and '-', but got: {:?}",
+        prefix
+      ),
+      StyleXOptionsError::EmptyImportSource => write!(f, "Import sources must not be empty"),
+      StyleXOptionsError::UnknownModuleResolution(r#type) => write!(
+        f,
+        "Module resolution type must be one of {}, but got: {}",
+        MODULE_RESOLUTION_TYPES.join(", "),
+        r#type
+      ),
+      StyleXOptionsError::EmptyVarsFileSuffix => {
+        write!(f, "Vars file suffixes must not be empty")
+      }
+      StyleXOptionsError::InvalidCompatVersion(version) => {
+        write!(f, "{} {:?}", INVALID_COMPAT_VERSION, version)
+      }
+      StyleXOptionsError::InvalidFileGlob(pattern) => {
+        write!(f, "{} {:?}", INVALID_FILE_GLOB, pattern)
+      }
+      StyleXOptionsError::UnsupportedMetadataVersion(version) => {
+        write!(f, "{} {}", UNSUPPORTED_METADATA_VERSION, version)
+      }
+      StyleXOptionsError::InvalidPseudoClassOrder(order) => {
+        write!(f, "{} {:?}", INVALID_PSEUDO_CLASS_ORDER, order)
+      }
+      StyleXOptionsError::ConflictingOptions(conflicts) => {
+        write!(f, "{}", CONFLICTING_OPTIONS)?;
+
+        for conflict in conflicts {
+          write!(f, "\n  - {}", conflict)?;
+        }
+
+        Ok(())
+      }
+    }
+  }
+}
+
+impl Error for StyleXOptionsError {}
+
+/// Typed alternative to deserializing [`StyleXOptionsParams`] from JSON, for
+/// embedders configuring the transform from Rust.
+///
+/// Unset options keep the defaults of [`StyleXOptionsParams`], except for the
+/// module resolution which defaults to `haste` like [`StyleXOptions`].
+#[derive(Clone, Debug)]
+pub struct StyleXOptionsBuilder {
+  params: StyleXOptionsParams,
+  css_normalizers: CssNormalizerPipeline,
+}
+
+impl Default for StyleXOptionsBuilder {
+  fn default() -> Self {
+    StyleXOptionsBuilder {
+      params: StyleXOptionsParams {
+        unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+        ..StyleXOptionsParams::default()
+      },
+      css_normalizers: CssNormalizerPipeline::default(),
+    }
+  }
+}
+
+impl StyleXOptionsBuilder {
+  pub fn dev(mut self, dev: bool) -> Self {
+    self.params.dev = Some(dev);
+    self
+  }
+
+  pub fn test(mut self, test: bool) -> Self {
+    self.params.test = Some(test);
+    self
+  }
+
+  pub fn use_rem_for_font_size(mut self, use_rem_for_font_size: bool) -> Self {
+    self.params.use_rem_for_font_size = Some(use_rem_for_font_size);
+    self
+  }
+
+  pub fn runtime_injection(mut self, runtime_injection: bool) -> Self {
+    self.params.runtime_injection = Some(runtime_injection);
+    self
+  }
+
+  pub fn runtime_injection_target(mut self, target: RuntimeInjectionTarget) -> Self {
+    self.params.runtime_injection_target = Some(target);
+    self
+  }
+
+  pub fn class_name_prefix(mut self, class_name_prefix: impl Into<String>) -> Self {
+    self.params.class_name_prefix = Some(class_name_prefix.into());
+    self
+  }
+
+  pub fn style_resolution(mut self, style_resolution: StyleResolution) -> Self {
+    self.params.style_resolution = Some(style_resolution);
+    self
+  }
+
+  /// Adds an import source next to the previously added ones.
+  pub fn import_source(mut self, import_source: ImportSources) -> Self {
+    self
+      .params
+      .import_sources
+      .get_or_insert_with(Vec::new)
+      .push(import_source);
+    self
+  }
+
+  pub fn treeshake_compensation(mut self, treeshake_compensation: bool) -> Self {
+    self.params.treeshake_compensation = Some(treeshake_compensation);
+    self
+  }
+
+  pub fn gen_conditional_classes(mut self, gen_conditional_classes: bool) -> Self {
+    self.params.gen_conditional_classes = Some(gen_conditional_classes);
+    self
+  }
+
+  pub fn module_resolution(mut self, module_resolution: ModuleResolution) -> Self {
+    self.params.unstable_module_resolution = Some(module_resolution);
+    self
+  }
+
+  pub fn resolve_external_packages(mut self, resolve_external_packages: bool) -> Self {
+    self.params.resolve_external_packages = Some(resolve_external_packages);
+    self
+  }
+
+  pub fn valid_vars_file_suffixes(mut self, suffixes: Vec<String>) -> Self {
+    self.params.valid_vars_file_suffixes = Some(suffixes);
+    self
+  }
+
+  pub fn cache_vars(mut self, cache_vars: bool) -> Self {
+    self.params.cache_vars = Some(cache_vars);
+    self
+  }
+
+  pub fn class_name_manifest(mut self, path: impl Into<String>) -> Self {
+    self.params.class_name_manifest = Some(path.into());
+    self
+  }
+
+  pub fn hash_package_scope(mut self, hash_package_scope: bool) -> Self {
+    self.params.hash_package_scope = Some(hash_package_scope);
+    self
+  }
+
+  pub fn transforms(mut self, transforms: Transforms) -> Self {
+    self.params.transforms = Some(transforms);
+    self
+  }
+
+  pub fn compat_version(mut self, compat_version: &str) -> Self {
+    self.params.compat_version = Some(compat_version.to_string());
+    self
+  }
+
+  /// Adds a module whose exported functions are evaluated at compile time
+  /// when called in style values.
+  pub fn macro_module(mut self, import_source: impl Into<String>) -> Self {
+    self
+      .params
+      .macro_modules
+      .get_or_insert_with(Vec::new)
+      .push(import_source.into());
+    self
+  }
+
+  pub fn unit_helpers(mut self, unit_helpers: UnitHelpers) -> Self {
+    self.params.unit_helpers = Some(unit_helpers);
+    self
+  }
+
+  pub fn shadow_dom(mut self, shadow_dom: ShadowDom) -> Self {
+    self.params.shadow_dom = Some(shadow_dom);
+    self
+  }
+
+  pub fn export_styles(mut self, export_styles: bool) -> Self {
+    self.params.export_styles = Some(export_styles);
+    self
+  }
+
+  pub fn var_usage_graph(mut self, var_usage_graph: bool) -> Self {
+    self.params.var_usage_graph = Some(var_usage_graph);
+    self
+  }
+
+  pub fn canonical_keyframes(mut self, canonical_keyframes: bool) -> Self {
+    self.params.canonical_keyframes = Some(canonical_keyframes);
+    self
+  }
+
+  pub fn include(mut self, patterns: Vec<String>) -> Self {
+    self.params.include = Some(patterns);
+    self
+  }
+
+  pub fn exclude(mut self, patterns: Vec<String>) -> Self {
+    self.params.exclude = Some(patterns);
+    self
+  }
+
+  pub fn preserve_side_effect_imports(mut self, preserve_side_effect_imports: bool) -> Self {
+    self.params.preserve_side_effect_imports = Some(preserve_side_effect_imports);
+    self
+  }
+
+  pub fn css_import(mut self, css_import: CssImport) -> Self {
+    self.params.css_import = Some(css_import);
+    self
+  }
+
+  pub fn metadata_version(mut self, metadata_version: u8) -> Self {
+    self.params.metadata_version = Some(metadata_version);
+    self
+  }
+
+  pub fn phase_timings(mut self, phase_timings: bool) -> Self {
+    self.params.phase_timings = Some(phase_timings);
+    self
+  }
+
+  pub fn seen_cache_capacity(mut self, seen_cache_capacity: usize) -> Self {
+    self.params.seen_cache_capacity = Some(seen_cache_capacity);
+    self
+  }
+
+  pub fn attrs_conflicts(mut self, attrs_conflicts: AttrsConflicts) -> Self {
+    self.params.attrs_conflicts = Some(attrs_conflicts);
+    self
+  }
+
+  /// Sets the order of the user action pseudo-classes, see [`PseudoClassOrder`].
+  pub fn pseudo_class_order(mut self, pseudo_classes: Vec<String>) -> Self {
+    self.params.pseudo_class_order = Some(pseudo_classes);
+    self
+  }
+
+  pub fn call_sites(mut self, call_sites: bool) -> Self {
+    self.params.call_sites = Some(call_sites);
+    self
+  }
+
+  pub fn max_rule_size(mut self, max_rule_size: usize) -> Self {
+    self.params.max_rule_size = Some(max_rule_size);
+    self
+  }
+
+  pub fn large_values(mut self, large_values: LargeValues) -> Self {
+    self.params.large_values = Some(large_values);
+    self
+  }
+
+  /// Compiles the legacy property `alias` as the standard `property`.
+  pub fn property_alias(mut self, alias: impl Into<String>, property: impl Into<String>) -> Self {
+    self
+      .params
+      .property_aliases
+      .get_or_insert_with(HashMap::new)
+      .insert(alias.into(), property.into());
+    self
+  }
+
+  pub fn light_dark_vars(mut self, light_dark_vars: bool) -> Self {
+    self.params.light_dark_vars = Some(light_dark_vars);
+    self
+  }
+
+  pub fn warn_escaping_styles(mut self, warn_escaping_styles: bool) -> Self {
+    self.params.warn_escaping_styles = Some(warn_escaping_styles);
+    self
+  }
+
+  pub fn runtime_create_fallback(mut self, runtime_create_fallback: bool) -> Self {
+    self.params.runtime_create_fallback = Some(runtime_create_fallback);
+    self
+  }
+
+  /// Sets the value of `process.env.<name>` in style values.
+  pub fn env_var(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+    self
+      .params
+      .env
+      .get_or_insert_with(HashMap::new)
+      .insert(name.into(), value.into());
+    self
+  }
+
+  /// Replaces the CSS value normalizer passes, e.g. with
+  /// `CssNormalizerPipeline::default().insert_after(..)`.
+  pub fn css_normalizers(mut self, css_normalizers: CssNormalizerPipeline) -> Self {
+    self.css_normalizers = css_normalizers;
+    self
+  }
+
+  /// Appends a CSS value normalizer after the built-in passes.
+  pub fn css_normalizer(mut self, css_normalizer: impl CssNormalizer + 'static) -> Self {
+    self.css_normalizers = self.css_normalizers.push(css_normalizer);
+    self
+  }
+
+  /// Validates the options and returns them in their JSON config form.
+  ///
+  /// Custom CSS normalizers have no JSON form and are dropped.
+  pub fn build_params(self) -> Result<StyleXOptionsParams, StyleXOptionsError> {
+    validate(&self.params)?;
+
+    Ok(self.params)
+  }
+
+  pub fn build(self) -> Result<StyleXOptions, StyleXOptionsError> {
+    let css_normalizers = self.css_normalizers.clone();
+
+    self.build_params().map(|params| StyleXOptions {
+      css_normalizers,
+      ..StyleXOptions::from(params)
+    })
+  }
+}
+
+fn validate(params: &StyleXOptionsParams) -> Result<(), StyleXOptionsError> {
+  if let Some(prefix) = &params.class_name_prefix {
+    if !is_valid_class_name_prefix(prefix) {
+      return Err(StyleXOptionsError::InvalidClassNamePrefix(prefix.clone()));
+    }
+  }
+
+  for import_source in params.import_sources.iter().flatten() {
+    let is_empty = match import_source {
+      ImportSources::Regular(from) => from.is_empty(),
+      ImportSources::Named(named) => named.from.is_empty() || named.r#as.is_empty(),
+    };
+
+    if is_empty {
+      return Err(StyleXOptionsError::EmptyImportSource);
+    }
+  }
+
+  if params
+    .macro_modules
+    .iter()
+    .flatten()
+    .any(|macro_module| macro_module.is_empty())
+  {
+    return Err(StyleXOptionsError::EmptyImportSource);
+  }
+
+  if matches!(&params.css_import, Some(CssImport::Specifier(specifier)) if specifier.is_empty()) {
+    return Err(StyleXOptionsError::EmptyImportSource);
+  }
+
+  if let Some(module_resolution) = &params.unstable_module_resolution {
+    let r#type = module_resolution.r#type.to_lowercase();
+
+    if !MODULE_RESOLUTION_TYPES.contains(&r#type.as_str()) {
+      return Err(StyleXOptionsError::UnknownModuleResolution(
+        module_resolution.r#type.clone(),
+      ));
+    }
+  }
+
+  if params
+    .valid_vars_file_suffixes
+    .iter()
+    .flatten()
+    .any(|suffix| suffix.is_empty())
+  {
+    return Err(StyleXOptionsError::EmptyVarsFileSuffix);
+  }
+
+  if let Some(compat_version) = &params.compat_version {
+    if compat_version.parse::<CompatVersion>().is_err() {
+      return Err(StyleXOptionsError::InvalidCompatVersion(
+        compat_version.clone(),
+      ));
+    }
+  }
+
+  if let Some(pattern) = params
+    .include
+    .iter()
+    .chain(params.exclude.iter())
+    .flatten()
+    .find(|pattern| glob::Pattern::new(pattern).is_err())
+  {
+    return Err(StyleXOptionsError::InvalidFileGlob(pattern.clone()));
+  }
+
+  if let Some(version) = params.metadata_version {
+    if !is_supported_metadata_version(version) {
+      return Err(StyleXOptionsError::UnsupportedMetadataVersion(version));
+    }
+  }
+
+  if let Some(order) = &params.pseudo_class_order {
+    PseudoClassOrder::new(order).map_err(StyleXOptionsError::InvalidPseudoClassOrder)?;
+  }
+
+  let conflicts = params.conflicts();
+
+  if !conflicts.is_empty() {
+    return Err(StyleXOptionsError::ConflictingOptions(conflicts));
+  }
+
+  Ok(())
+}
+
+fn is_valid_class_name_prefix(prefix: &str) -> bool {
+  let mut chars = prefix.chars();
+
+  match chars.next() {
+    Some(first) if first.is_ascii_alphabetic() || first == '_' || first == '-' => {
+      chars.all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-')
+    }
+    _ => false,
+  }
+}
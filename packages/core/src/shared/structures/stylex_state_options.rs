@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::shared::{
+  constants::common::{DEFAULT_INJECT_PATH, DEFAULT_MAX_RULE_SIZE, DEFAULT_SEEN_CACHE_CAPACITY},
+  utils::css::normalizers::pipeline::CssNormalizerPipeline,
+};
+
+use super::{
+  application_order::PseudoClassOrder,
+  compat_version::{CompatBehavior, CompatVersion},
+  meta_data::METADATA_VERSION,
+  named_import_source::{
+    ImportSources, RuntimeInjection, RuntimeInjectionState, RuntimeInjectionTarget,
+  },
+  stylex_options::{
+    AttrsConflicts, CheckModuleResolution, LargeValues, ShadowDom, StyleResolution, StyleXOptions,
+    Transforms, UnitHelpers,
+  },
+};
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct StyleXStateOptions {
+  pub dev: bool,
+  pub test: bool,
+  pub use_rem_for_font_size: bool,
+  pub class_name_prefix: String,
+  // pub defined_stylex_css_variables: HashMap<String, String>,
+  pub style_resolution: StyleResolution,
+  pub import_sources: Vec<ImportSources>,
+  pub runtime_injection: Option<RuntimeInjectionState>,
+  pub runtime_injection_target: RuntimeInjectionTarget,
+  pub treeshake_compensation: Option<bool>,
+  pub gen_conditional_classes: bool,
+  // pub aliases: Option<HashMap<String, Vec<String>>>,
+  pub unstable_module_resolution: Option<CheckModuleResolution>,
+  pub resolve_external_packages: bool,
+  pub valid_vars_file_suffixes: Option<Vec<String>>,
+  pub cache_vars: bool,
+  pub class_name_manifest: Option<String>,
+  pub hash_package_scope: bool,
+  pub transforms: Transforms,
+  #[serde(skip)]
+  pub compat_version: Option<CompatVersion>,
+  pub macro_modules: Vec<String>,
+  pub unit_helpers: Option<UnitHelpers>,
+  pub env: Option<HashMap<String, String>>,
+  pub shadow_dom: Option<ShadowDom>,
+  pub export_styles: bool,
+  pub var_usage_graph: bool,
+  pub canonical_keyframes: bool,
+  pub include: Vec<String>,
+  pub exclude: Vec<String>,
+  pub preserve_side_effect_imports: bool,
+  pub css_import: Option<String>,
+  pub metadata_version: u8,
+  pub phase_timings: bool,
+  pub seen_cache_capacity: usize,
+  pub attrs_conflicts: AttrsConflicts,
+  #[serde(skip)]
+  pub pseudo_class_order: PseudoClassOrder,
+  pub call_sites: bool,
+  pub max_rule_size: usize,
+  pub large_values: LargeValues,
+  pub property_aliases: HashMap<String, String>,
+  pub light_dark_vars: bool,
+  pub warn_escaping_styles: bool,
+  pub runtime_create_fallback: bool,
+  #[serde(skip)]
+  pub css_normalizers: CssNormalizerPipeline,
+}
+
+impl StyleXStateOptions {
+  /// Whether the `@stylexjs` release targeted by `compatVersion` has
+  /// `behavior`, which is always the case without a compat version.
+  pub fn has_compat_behavior(&self, behavior: CompatBehavior) -> bool {
+    self
+      .compat_version
+      .map_or(true, |version| version.has(behavior))
+  }
+
+  pub fn _new() -> Self {
+    StyleXStateOptions {
+      style_resolution: StyleResolution::ApplicationOrder,
+      use_rem_for_font_size: false,
+      runtime_injection: None,
+      runtime_injection_target: RuntimeInjectionTarget::StyleElement,
+      class_name_prefix: "x".to_string(),
+      // defined_stylex_css_variables: HashMap::new(),
+      import_sources: vec![],
+      dev: false,
+      test: false,
+      treeshake_compensation: None,
+      gen_conditional_classes: false,
+      // aliases: None,
+      unstable_module_resolution: None,
+      resolve_external_packages: true,
+      valid_vars_file_suffixes: None,
+      cache_vars: false,
+      class_name_manifest: None,
+      hash_package_scope: false,
+      transforms: Transforms::default(),
+      compat_version: None,
+      macro_modules: vec![],
+      unit_helpers: None,
+      env: None,
+      shadow_dom: None,
+      export_styles: false,
+      var_usage_graph: false,
+      canonical_keyframes: false,
+      include: vec![],
+      exclude: vec![],
+      preserve_side_effect_imports: false,
+      css_import: None,
+      metadata_version: METADATA_VERSION,
+      phase_timings: false,
+      seen_cache_capacity: DEFAULT_SEEN_CACHE_CAPACITY,
+      attrs_conflicts: AttrsConflicts::Ignore,
+      pseudo_class_order: PseudoClassOrder::default(),
+      call_sites: false,
+      max_rule_size: DEFAULT_MAX_RULE_SIZE,
+      large_values: LargeValues::Inline,
+      property_aliases: HashMap::new(),
+      light_dark_vars: false,
+      warn_escaping_styles: false,
+      runtime_create_fallback: false,
+      css_normalizers: CssNormalizerPipeline::default(),
+    }
+  }
+}
+
+impl Default for CheckModuleResolution {
+  fn default() -> Self {
+    CheckModuleResolution::Haste(StyleXOptions::get_haste_module_resolution(None))
+  }
+}
+impl From<StyleXOptions> for StyleXStateOptions {
+  fn from(options: StyleXOptions) -> Self {
+    let runtime_injection = match options.runtime_injection {
+      RuntimeInjection::Boolean(b) => {
+        if b || options.dev {
+          Some(RuntimeInjectionState::Regular(
+            DEFAULT_INJECT_PATH.to_string(),
+          ))
+        } else {
+          None
+        }
+      }
+      RuntimeInjection::Named(n) => Some(RuntimeInjectionState::Named(n)),
+      RuntimeInjection::Regular(s) => Some(RuntimeInjectionState::Regular(s)),
+    };
+
+    // let aliases = match options.aliases {
+    //   Some(aliases) => match aliases {
+    //     stylex_options::Aliases::String(aliases) => {
+    //       let mut aliases_map = HashMap::new();
+    //       for (key, value) in aliases {
+    //         let vec = vec![value];
+
+    //         aliases_map.insert(key, vec);
+    //       }
+
+    //       Some(aliases_map)
+    //     }
+    //     stylex_options::Aliases::StringVec(aliases) => Some(aliases),
+    //   },
+    //   None => None,
+    // };
+
+    StyleXStateOptions {
+      style_resolution: options.style_resolution,
+      use_rem_for_font_size: options.use_rem_for_font_size,
+      runtime_injection,
+      runtime_injection_target: options.runtime_injection_target,
+      class_name_prefix: options.class_name_prefix,
+      // defined_stylex_css_variables: options.defined_stylex_css_variables,
+      import_sources: options.import_sources,
+      dev: options.dev,
+      test: options.test,
+      treeshake_compensation: options.treeshake_compensation,
+      gen_conditional_classes: options.gen_conditional_classes,
+      // aliases,
+      unstable_module_resolution: options.unstable_module_resolution,
+      resolve_external_packages: options.resolve_external_packages,
+      valid_vars_file_suffixes: options.valid_vars_file_suffixes,
+      cache_vars: options.cache_vars,
+      class_name_manifest: options.class_name_manifest,
+      hash_package_scope: options.hash_package_scope,
+      transforms: options.transforms,
+      compat_version: options.compat_version,
+      macro_modules: options.macro_modules,
+      unit_helpers: options.unit_helpers,
+      env: options.env,
+      shadow_dom: options.shadow_dom,
+      export_styles: options.export_styles,
+      var_usage_graph: options.var_usage_graph,
+      canonical_keyframes: options.canonical_keyframes,
+      include: options.include,
+      exclude: options.exclude,
+      preserve_side_effect_imports: options.preserve_side_effect_imports,
+      css_import: options.css_import,
+      metadata_version: options.metadata_version,
+      phase_timings: options.phase_timings,
+      seen_cache_capacity: options.seen_cache_capacity,
+      attrs_conflicts: options.attrs_conflicts,
+      pseudo_class_order: options.pseudo_class_order,
+      call_sites: options.call_sites,
+      max_rule_size: options.max_rule_size,
+      large_values: options.large_values,
+      property_aliases: options.property_aliases,
+      light_dark_vars: options.light_dark_vars,
+      warn_escaping_styles: options.warn_escaping_styles,
+      runtime_create_fallback: options.runtime_create_fallback,
+      css_normalizers: options.css_normalizers,
+    }
+  }
+}
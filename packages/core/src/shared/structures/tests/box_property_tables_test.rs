@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod box_property_tables {
+  use crate::shared::{
+    structures::{
+      application_order::{ApplicationOrder, PseudoClassOrder},
+      legacy_expand_shorthands_order::LegacyExpandShorthandsOrder,
+      order::Order,
+      order_pair::OrderPair,
+      property_specificity_order::PropertySpecificityOrder,
+    },
+    utils::css::common::get_priority,
+  };
+
+  /// Generates a test per property checking its priority against the
+  /// property tables of `@stylexjs/shared`.
+  macro_rules! upstream_priorities {
+    ($($name:ident: $property:literal => $priority:literal,)*) => {
+      $(
+        #[test]
+        fn $name() {
+          assert_eq!(
+            get_priority($property, &PseudoClassOrder::default()),
+            $priority
+          );
+        }
+      )*
+    };
+  }
+
+  upstream_priorities! {
+    inset: "inset" => 1000.0,
+    inset_block: "inset-block" => 2000.0,
+    inset_inline: "inset-inline" => 2000.0,
+    gap: "gap" => 2000.0,
+    grid_gap: "grid-gap" => 2000.0,
+    inset_block_start: "inset-block-start" => 3000.0,
+    inset_block_end: "inset-block-end" => 3000.0,
+    inset_inline_start: "inset-inline-start" => 3000.0,
+    inset_inline_end: "inset-inline-end" => 3000.0,
+    row_gap: "row-gap" => 3000.0,
+    column_gap: "column-gap" => 3000.0,
+    grid_row_gap: "grid-row-gap" => 3000.0,
+    grid_column_gap: "grid-column-gap" => 3000.0,
+    top: "top" => 4000.0,
+    bottom: "bottom" => 4000.0,
+    left: "left" => 4000.0,
+    right: "right" => 4000.0,
+  }
+
+  fn expand<O: Order>(property: &str, value: &str) -> Vec<OrderPair> {
+    let expansion_fn = O::get_expansion_fn(property).expect("No expansion found");
+
+    expansion_fn(Some(value.to_string()))
+  }
+
+  fn pair(property: &str, value: Option<&str>) -> OrderPair {
+    OrderPair(property.to_string(), value.map(str::to_string))
+  }
+
+  #[test]
+  fn application_order_resets_inset_longhands() {
+    assert_eq!(
+      expand::<ApplicationOrder>("insetBlock", "0"),
+      vec![
+        pair("insetBlock", Some("0")),
+        pair("top", None),
+        pair("bottom", None),
+      ]
+    );
+    assert_eq!(
+      expand::<ApplicationOrder>("gridGap", "1px"),
+      vec![
+        pair("gap", Some("1px")),
+        pair("rowGap", None),
+        pair("columnGap", None),
+      ]
+    );
+  }
+
+  #[test]
+  fn property_specificity_aliases_grid_gaps() {
+    assert_eq!(
+      expand::<PropertySpecificityOrder>("gridGap", "1px"),
+      vec![pair("gap", Some("1px"))]
+    );
+    assert_eq!(
+      expand::<PropertySpecificityOrder>("gridRowGap", "1px"),
+      vec![pair("rowGap", Some("1px"))]
+    );
+    assert_eq!(
+      expand::<PropertySpecificityOrder>("gridColumnGap", "1px"),
+      vec![pair("columnGap", Some("1px"))]
+    );
+  }
+
+  #[test]
+  fn legacy_expand_shorthands_splits_two_value_insets() {
+    assert_eq!(
+      expand::<LegacyExpandShorthandsOrder>("insetInline", "1px 2px"),
+      vec![
+        pair("start", Some("1px")),
+        pair("left", None),
+        pair("right", None),
+        pair("end", Some("2px")),
+        pair("left", None),
+        pair("right", None),
+      ]
+    );
+    assert_eq!(
+      expand::<LegacyExpandShorthandsOrder>("insetBlock", "0 auto"),
+      vec![pair("top", Some("0")), pair("bottom", Some("auto"))]
+    );
+    assert_eq!(
+      expand::<LegacyExpandShorthandsOrder>("gap", "1px 2px"),
+      vec![pair("rowGap", Some("1px")), pair("columnGap", Some("2px"))]
+    );
+  }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod class_name_manifest {
+  use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, SystemTime},
+  };
+
+  use crate::shared::structures::class_name_manifest::{short_class_name, ClassNameManifest};
+
+  fn manifest_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+      "stylex-class-name-manifest-{}-{}.json",
+      name,
+      std::process::id()
+    ));
+
+    let _ = fs::remove_file(&path);
+    ClassNameManifest::invalidate(path.to_str().unwrap());
+
+    path
+  }
+
+  #[test]
+  fn generates_minimal_length_names() {
+    assert_eq!(short_class_name(0), "a");
+    assert_eq!(short_class_name(25), "z");
+    assert_eq!(short_class_name(26), "aa");
+    assert_eq!(short_class_name(27), "ab");
+    assert_eq!(short_class_name(26 + 35), "a9");
+    assert_eq!(short_class_name(26 + 36), "ba");
+    assert_eq!(short_class_name(26 + 26 * 36 - 1), "z9");
+    assert_eq!(short_class_name(26 + 26 * 36), "aaa");
+  }
+
+  #[test]
+  fn keeps_hashes_without_manifest() {
+    let path = manifest_path("missing");
+
+    assert!(ClassNameManifest::open(path.to_str().unwrap()).is_none());
+    assert!(!path.exists());
+  }
+
+  #[test]
+  fn assigns_and_persists_names() {
+    let path = manifest_path("persist");
+    fs::write(&path, "").unwrap();
+
+    let manifest = ClassNameManifest::open(path.to_str().unwrap()).unwrap();
+
+    assert_eq!(manifest.class_name("1abc"), "a");
+    assert_eq!(manifest.class_name("2def"), "b");
+    assert_eq!(manifest.class_name("1abc"), "a");
+    assert_eq!(manifest.len(), 2);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "");
+
+    manifest.persist();
+
+    let persisted: serde_json::Value =
+      serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+
+    assert_eq!(persisted, serde_json::json!({ "1abc": "a", "2def": "b" }));
+    assert!(!path.with_extension("json.lock").exists());
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  fn merges_names_added_by_other_builds() {
+    let path = manifest_path("merge");
+    fs::write(&path, r#"{ "1abc": "a" }"#).unwrap();
+
+    let manifest = ClassNameManifest::open(path.to_str().unwrap()).unwrap();
+
+    fs::write(&path, r#"{ "1abc": "a", "2def": "b" }"#).unwrap();
+
+    assert_eq!(manifest.class_name("3ghi"), "c");
+    assert_eq!(manifest.class_name("2def"), "b");
+
+    manifest.persist();
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  fn breaks_locks_left_by_crashed_builds() {
+    let path = manifest_path("stale-lock");
+    let lock_path = path.with_extension("json.lock");
+    fs::write(&path, "{}").unwrap();
+
+    fs::File::create(&lock_path)
+      .unwrap()
+      .set_modified(SystemTime::now() - Duration::from_secs(60))
+      .unwrap();
+
+    let manifest = ClassNameManifest::open(path.to_str().unwrap()).unwrap();
+
+    assert_eq!(manifest.class_name("1abc"), "a");
+
+    manifest.persist();
+
+    assert!(!lock_path.exists());
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "The class name manifest must be a JSON object")]
+  fn disallows_invalid_manifests() {
+    let path = manifest_path("invalid");
+    fs::write(&path, "[]").unwrap();
+
+    ClassNameManifest::open(path.to_str().unwrap());
+  }
+}
@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod compat_version {
+  use crate::shared::structures::compat_version::{CompatBehavior, CompatVersion};
+
+  #[test]
+  fn parses_release_versions() {
+    assert_eq!("0.6".parse(), Ok(CompatVersion::new(0, 6, 0)));
+    assert_eq!("0.6.1".parse(), Ok(CompatVersion::new(0, 6, 1)));
+    assert_eq!("v0.6.1".parse(), Ok(CompatVersion::new(0, 6, 1)));
+    assert_eq!("0.7.0-beta.1".parse(), Ok(CompatVersion::new(0, 7, 0)));
+  }
+
+  #[test]
+  fn rejects_invalid_versions() {
+    assert!("".parse::<CompatVersion>().is_err());
+    assert!("0".parse::<CompatVersion>().is_err());
+    assert!("latest".parse::<CompatVersion>().is_err());
+    assert!("0.6.x".parse::<CompatVersion>().is_err());
+    assert!("0.6.1.2".parse::<CompatVersion>().is_err());
+  }
+
+  #[test]
+  fn has_behaviors_introduced_up_to_its_release() {
+    let version = CompatVersion::new(0, 4, 2);
+
+    assert!(version.has(CompatBehavior::TimingNormalization));
+    assert!(!version.has(CompatBehavior::AtRulesFirstInClassNameHash));
+    assert!(CompatBehavior::AtRulesFirstInClassNameHash
+      .since()
+      .has(CompatBehavior::AtRulesFirstInClassNameHash));
+  }
+}
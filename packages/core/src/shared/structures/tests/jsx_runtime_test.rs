@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod jsx_runtime {
+  use std::collections::HashSet;
+
+  use swc_core::{
+    common::{sync::Lrc, FileName, SourceMap},
+    ecma::{
+      ast::{EsVersion, Module},
+      parser::parse_file_as_module,
+    },
+  };
+
+  use crate::shared::{
+    structures::jsx_runtime::{JsxRuntime, JsxRuntimeKind},
+    utils::swc_compat::ecmascript_syntax,
+  };
+
+  fn parse(source: &str) -> Module {
+    let source_map: Lrc<SourceMap> = Default::default();
+    let file = source_map.new_source_file(FileName::Anon, source.to_string());
+
+    parse_file_as_module(
+      &file,
+      ecmascript_syntax(false),
+      EsVersion::latest(),
+      None,
+      &mut vec![],
+    )
+    .expect("Module should parse")
+  }
+
+  fn factories(names: &[&str]) -> HashSet<String> {
+    names.iter().map(|name| name.to_string()).collect()
+  }
+
+  #[test]
+  fn defaults_to_react_with_either_runtime() {
+    let runtime = JsxRuntime::from_comments(["* Button component "]);
+
+    assert_eq!(runtime, JsxRuntime::default());
+    assert_eq!(runtime.kind, None);
+    assert_eq!(
+      runtime.runtime_modules(),
+      [
+        "react/jsx-runtime".to_string(),
+        "react/jsx-dev-runtime".to_string()
+      ]
+    );
+  }
+
+  #[test]
+  fn reads_import_source_pragmas() {
+    let runtime = JsxRuntime::from_comments(["* @jsxImportSource preact "]);
+
+    assert_eq!(runtime.kind, Some(JsxRuntimeKind::Automatic));
+    assert_eq!(runtime.import_source, "preact");
+  }
+
+  #[test]
+  fn reads_classic_pragmas() {
+    let runtime = JsxRuntime::from_comments(["* @jsx h ", " @jsxFrag Fragment"]);
+
+    assert_eq!(runtime.kind, Some(JsxRuntimeKind::Classic));
+    assert_eq!(runtime.pragma, "h");
+  }
+
+  #[test]
+  fn prefers_runtime_pragmas_over_implied_runtimes() {
+    let runtime = JsxRuntime::from_comments(["*@jsxImportSource preact\n * @jsxRuntime classic "]);
+
+    assert_eq!(runtime.kind, Some(JsxRuntimeKind::Classic));
+    assert_eq!(runtime.pragma, "React.createElement");
+  }
+
+  #[test]
+  fn collects_factories_imported_from_the_runtime_modules() {
+    let module = parse(
+      r#"
+        import { jsx as _jsx, jsxs as _jsxs, Fragment } from 'react/jsx-runtime';
+        import { jsxDEV } from 'react/jsx-dev-runtime';
+        import * as runtime from 'react/jsx-runtime';
+        import { jsx } from 'preact/jsx-runtime';
+      "#,
+    );
+
+    let runtime = JsxRuntime {
+      kind: Some(JsxRuntimeKind::Automatic),
+      ..JsxRuntime::default()
+    };
+
+    assert_eq!(
+      runtime.element_factories(&module),
+      factories(&[
+        "_jsx",
+        "_jsxs",
+        "jsxDEV",
+        "runtime.jsx",
+        "runtime.jsxs",
+        "runtime.jsxDEV"
+      ])
+    );
+  }
+
+  #[test]
+  fn collects_factories_of_both_runtimes_without_pragmas() {
+    let module = parse(
+      r#"
+        import React from 'react';
+        import { jsx as _jsx } from 'react/jsx-runtime';
+      "#,
+    );
+
+    assert_eq!(
+      JsxRuntime::default().element_factories(&module),
+      factories(&["_jsx", "React.createElement"])
+    );
+  }
+
+  #[test]
+  fn honors_the_pragmas_when_collecting_factories() {
+    let module = parse(
+      r#"
+        import { h } from 'preact';
+        import { jsx as _jsx } from 'react/jsx-runtime';
+        import { jsx as _jsx2 } from 'preact/jsx-runtime';
+      "#,
+    );
+
+    assert_eq!(
+      JsxRuntime::from_comments(["* @jsxImportSource preact "]).element_factories(&module),
+      factories(&["_jsx2"])
+    );
+    assert_eq!(
+      JsxRuntime::from_comments(["* @jsx h "]).element_factories(&module),
+      factories(&["h"])
+    );
+  }
+}
@@ -0,0 +1,209 @@
+#[cfg(test)]
+mod macro_module {
+  use std::path::Path;
+
+  use swc_core::ecma::{
+    ast::{Expr, Lit},
+    utils::drop_span,
+  };
+
+  use crate::shared::{
+    structures::macro_module::MacroModule,
+    utils::ast::convertors::{number_to_expression, string_to_expression},
+  };
+
+  fn parse(source: &str) -> MacroModule {
+    MacroModule::parse(Path::new("macros.ts"), source).expect("Macro module should parse")
+  }
+
+  #[test]
+  fn collects_exported_single_expression_functions() {
+    let module = parse(
+      r#"
+        export const rem = (px: number) => `${px / 16}rem`;
+        export const half = function (value) { return value / 2; };
+        export function double(value) { return value * 2; }
+        export default (color) => color;
+      "#,
+    );
+
+    assert_eq!(module.get("rem").unwrap().params, vec!["px"]);
+    assert_eq!(module.get("half").unwrap().params, vec!["value"]);
+    assert_eq!(module.get("double").unwrap().params, vec!["value"]);
+    assert_eq!(module.get("default").unwrap().params, vec!["color"]);
+  }
+
+  #[test]
+  fn skips_functions_that_are_not_macros() {
+    let module = parse(
+      r#"
+        const local = (value) => value;
+        export const sum = (...values) => values.length;
+        export const pick = ({ value }) => value;
+        export function log(value) { console.log(value); return value; }
+        export const size = 16;
+      "#,
+    );
+
+    for name in ["local", "sum", "pick", "log", "size"] {
+      assert!(module.get(name).is_err(), "{} is not a macro", name);
+    }
+  }
+
+  #[test]
+  fn rejects_invalid_modules() {
+    assert!(MacroModule::parse(Path::new("macros.js"), "export const = ;").is_none());
+  }
+
+  #[test]
+  fn replaces_parameters_with_arguments() {
+    let module = parse("export const shadow = (x, y, color) => [x * 2, y, color];");
+
+    let expanded = module
+      .get("shadow")
+      .unwrap()
+      .expand(&[number_to_expression(4.0), string_to_expression("red")]);
+
+    let Expr::Array(array) = drop_span(expanded) else {
+      panic!("Expected an array");
+    };
+
+    let elems = array
+      .elems
+      .into_iter()
+      .map(|elem| *elem.unwrap().expr)
+      .collect::<Vec<Expr>>();
+
+    assert!(
+      matches!(&elems[0], Expr::Bin(bin) if matches!(bin.left.as_ref(), Expr::Lit(Lit::Num(num)) if num.value == 4.0))
+    );
+    assert!(matches!(&elems[1], Expr::Lit(Lit::Str(str)) if str.value == "red"));
+    assert!(matches!(&elems[2], Expr::Ident(ident) if ident.sym == "undefined"));
+  }
+
+  #[test]
+  fn expands_calls_of_other_macros() {
+    let module = parse(
+      r#"
+        const toRem = (px) => px / 16;
+        export const rem = (px) => `${toRem(px)}rem`;
+        export const gap = (steps) => rem(steps * 4);
+      "#,
+    );
+
+    let Expr::Tpl(tpl) = drop_span(
+      module
+        .get("gap")
+        .unwrap()
+        .expand(&[number_to_expression(2.0)]),
+    ) else {
+      panic!("Expected a template literal");
+    };
+
+    let Expr::Bin(bin) = tpl.exprs[0].as_ref() else {
+      panic!("Expected a division");
+    };
+
+    assert!(matches!(bin.left.as_ref(), Expr::Bin(_)));
+    assert!(matches!(bin.right.as_ref(), Expr::Lit(Lit::Num(num)) if num.value == 16.0));
+  }
+
+  #[test]
+  fn keeps_calls_of_shadowed_macros() {
+    let module = parse(
+      r#"
+        const double = (value) => value * 2;
+        export const apply = (double, value) => double(value);
+      "#,
+    );
+
+    assert!(matches!(*module.get("apply").unwrap().body, Expr::Call(_)));
+  }
+
+  #[test]
+  fn rejects_recursive_macros() {
+    let module = parse(
+      r#"
+        export const fib = (n) => n < 2 ? n : fib(n - 1) + fib(n - 2);
+        const isEven = (n) => n === 0 ? true : isOdd(n - 1);
+        const isOdd = (n) => n === 0 ? false : isEven(n - 1);
+        export const parity = (n) => isEven(n) ? 'even' : 'odd';
+      "#,
+    );
+
+    assert_eq!(
+      module.get("fib").unwrap_err(),
+      "Macros can't call themselves, directly or through other macros. Found the recursive calls: fib -> fib"
+    );
+    assert_eq!(
+      module.get("parity").unwrap_err(),
+      "Macros can't call themselves, directly or through other macros. Found the recursive calls: isEven -> isOdd -> isEven"
+    );
+  }
+
+  #[test]
+  fn keeps_parameters_shadowed_by_nested_functions() {
+    let module =
+      parse("export const scale = (x, values) => values.map((x) => x * 2).concat([x, { x }]);");
+
+    let Expr::Call(concat) = drop_span(
+      module
+        .get("scale")
+        .unwrap()
+        .expand(&[number_to_expression(4.0), string_to_expression("values")]),
+    ) else {
+      panic!("Expected a call");
+    };
+
+    let Expr::Array(array) = concat.args[0].expr.as_ref() else {
+      panic!("Expected an array");
+    };
+
+    assert!(
+      matches!(array.elems[0].as_ref().unwrap().expr.as_ref(), Expr::Lit(Lit::Num(num)) if num.value == 4.0)
+    );
+
+    let Expr::Object(object) = array.elems[1].as_ref().unwrap().expr.as_ref() else {
+      panic!("Expected an object");
+    };
+
+    let key_value = object.props[0].as_prop().unwrap().as_key_value().unwrap();
+
+    assert!(matches!(key_value.value.as_ref(), Expr::Lit(Lit::Num(num)) if num.value == 4.0));
+
+    let callback = concat
+      .callee
+      .as_expr()
+      .unwrap()
+      .as_member()
+      .unwrap()
+      .obj
+      .as_call()
+      .unwrap();
+
+    let Expr::Arrow(arrow) = callback.args[0].expr.as_ref() else {
+      panic!("Expected an arrow function");
+    };
+
+    let body = arrow.body.as_expr().unwrap().as_bin().unwrap();
+
+    assert!(matches!(body.left.as_ref(), Expr::Ident(ident) if ident.sym == "x"));
+  }
+
+  #[test]
+  fn rejects_macros_using_other_bindings() {
+    let module = parse(
+      r#"
+        const base = 16;
+        export const rem = (px) => px / base;
+        export const round = (px) => Math.round(px);
+      "#,
+    );
+
+    assert_eq!(
+      module.get("rem").unwrap_err(),
+      "Macros can only use their parameters, other macros and the built-ins supported by the compiler. Found an unbound identifier in: rem: base"
+    );
+    assert!(module.get("round").is_ok());
+  }
+}
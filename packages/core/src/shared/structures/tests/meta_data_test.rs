@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod meta_data {
+  use crate::shared::structures::meta_data::{MetaDataEnvelope, METADATA_VERSION};
+
+  const RULES: &str = r#"[{"class_name":"x1e2nbdu","style":{"rtl":null,"ltr":".x1e2nbdu{color:red}"},"priority":3000}]"#;
+
+  #[test]
+  fn reads_the_current_envelope() {
+    let json = format!(r#"{{"version":2,"rules":{}}}"#, RULES);
+
+    let envelope = MetaDataEnvelope::from_json(&json).unwrap();
+
+    assert_eq!(envelope.version, METADATA_VERSION);
+    assert_eq!(envelope.rules[0].get_class_name(), "x1e2nbdu");
+    assert_eq!(
+      MetaDataEnvelope::to_json(envelope.rules, METADATA_VERSION).unwrap(),
+      json
+    );
+  }
+
+  #[test]
+  fn reads_the_bare_rules_of_version_1() {
+    let envelope = MetaDataEnvelope::from_json(RULES).unwrap();
+
+    assert_eq!(envelope.version, 1);
+    assert_eq!(envelope.rules[0].get_css(), ".x1e2nbdu{color:red}");
+    assert_eq!(MetaDataEnvelope::to_json(envelope.rules, 1).unwrap(), RULES);
+  }
+}
@@ -0,0 +1,15 @@
+mod box_property_tables_test;
+mod class_name_manifest_test;
+mod compat_version_test;
+mod flatten_raw_style_objects_test;
+mod gen_css_test;
+mod jsx_runtime_test;
+mod macro_module_test;
+mod meta_data_test;
+mod pseudo_class_order_test;
+mod quantity_test;
+mod rule_registry_test;
+mod seen_cache_test;
+mod state_manager_combine_test;
+mod stylex_options_builder_test;
+mod stylex_options_schema_test;
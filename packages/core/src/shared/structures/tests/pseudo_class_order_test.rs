@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod pseudo_class_order {
+  use crate::shared::{
+    structures::application_order::PseudoClassOrder, utils::css::common::get_priority,
+  };
+
+  /// Generates a test per pseudo-class checking its default priority against
+  /// `PSEUDO_CLASS_PRIORITIES` of `@stylexjs/shared`.
+  macro_rules! upstream_priorities {
+    ($($name:ident: $pseudo_class:literal => $priority:literal,)*) => {
+      $(
+        #[test]
+        fn $name() {
+          assert_eq!(
+            get_priority($pseudo_class, &PseudoClassOrder::default()),
+            $priority
+          );
+        }
+      )*
+    };
+  }
+
+  upstream_priorities! {
+    link: ":link" => 80.0,
+    visited: ":visited" => 85.0,
+    enabled: ":enabled" => 91.0,
+    disabled: ":disabled" => 92.0,
+    checked: ":checked" => 101.0,
+    hover: ":hover" => 130.0,
+    focus_within: ":focus-within" => 140.0,
+    focus: ":focus" => 150.0,
+    focus_visible: ":focus-visible" => 160.0,
+    active: ":active" => 170.0,
+    unknown: ":popover-open" => 40.0,
+  }
+
+  #[test]
+  fn applies_link_states_then_user_actions_then_at_rules() {
+    let order = PseudoClassOrder::default();
+
+    let priorities = [":link", ":visited", ":hover", ":focus", ":active", "@media"]
+      .map(|key| get_priority(key, &order));
+
+    assert!(priorities.windows(2).all(|pair| pair[0] < pair[1]));
+  }
+
+  #[test]
+  fn orders_custom_pseudo_classes() {
+    let order = PseudoClassOrder::new(&[
+      ":hover".to_string(),
+      ":focus-visible".to_string(),
+      ":active".to_string(),
+      ":disabled".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(get_priority(":hover", &order), 130.0);
+    assert_eq!(get_priority(":focus-visible", &order), 140.0);
+    assert_eq!(get_priority(":active", &order), 150.0);
+    assert_eq!(get_priority(":disabled", &order), 160.0);
+    assert_eq!(get_priority(":focus", &order), 40.0);
+  }
+
+  #[test]
+  fn keeps_the_first_of_repeated_pseudo_classes() {
+    let order = PseudoClassOrder::new(&[
+      ":hover".to_string(),
+      ":active".to_string(),
+      ":hover".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(order.pseudo_classes(), [":hover", ":active"]);
+  }
+
+  #[test]
+  fn rejects_pseudo_elements_and_functional_pseudo_classes() {
+    assert_eq!(
+      PseudoClassOrder::new(&["::before".to_string()]),
+      Err("::before".to_string())
+    );
+    assert_eq!(
+      PseudoClassOrder::new(&[":not(:hover)".to_string()]),
+      Err(":not(:hover)".to_string())
+    );
+  }
+
+  #[test]
+  fn rejects_orders_reaching_the_media_priority() {
+    let pseudo_classes = [
+      ":hover",
+      ":focus-within",
+      ":focus",
+      ":focus-visible",
+      ":active",
+      ":disabled",
+      ":checked",
+      ":invalid",
+    ]
+    .map(String::from);
+
+    assert!(PseudoClassOrder::new(&pseudo_classes[..7]).is_ok());
+    assert!(PseudoClassOrder::new(&pseudo_classes).is_err());
+  }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod quantity {
+  use swc_core::ecma::ast::BinaryOp;
+
+  use crate::shared::structures::quantity::Quantity;
+
+  #[test]
+  fn parses_numbers_with_units() {
+    assert_eq!("1.5rem".parse(), Ok(Quantity::new(1.5, "rem")));
+    assert_eq!("-.5em".parse(), Ok(Quantity::new(-0.5, "em")));
+    assert_eq!(" 50% ".parse(), Ok(Quantity::new(50.0, "%")));
+    assert_eq!("10".parse(), Ok(Quantity::new(10.0, "")));
+    assert_eq!("1e3".parse(), Ok(Quantity::new(1000.0, "")));
+  }
+
+  #[test]
+  fn rejects_non_quantities() {
+    assert!("".parse::<Quantity>().is_err());
+    assert!("px".parse::<Quantity>().is_err());
+    assert!("red".parse::<Quantity>().is_err());
+    assert!("1px 2px".parse::<Quantity>().is_err());
+    assert!("calc(1px + 2px)".parse::<Quantity>().is_err());
+  }
+
+  #[test]
+  fn applies_operators_to_compatible_units() {
+    let px = Quantity::new(8.0, "px");
+
+    assert_eq!(
+      px.apply(BinaryOp::Add, &Quantity::new(2.0, "px")),
+      Some(Quantity::new(10.0, "px"))
+    );
+    assert_eq!(
+      px.apply(BinaryOp::Mul, &Quantity::new(2.0, "")),
+      Some(Quantity::new(16.0, "px"))
+    );
+    assert_eq!(
+      Quantity::new(2.0, "").apply(BinaryOp::Mul, &px),
+      Some(Quantity::new(16.0, "px"))
+    );
+    assert_eq!(
+      px.apply(BinaryOp::Div, &Quantity::new(4.0, "px")),
+      Some(Quantity::new(2.0, ""))
+    );
+  }
+
+  #[test]
+  fn rejects_operators_on_incompatible_units() {
+    let px = Quantity::new(8.0, "px");
+
+    assert_eq!(px.apply(BinaryOp::Add, &Quantity::new(2.0, "rem")), None);
+    assert_eq!(px.apply(BinaryOp::Sub, &Quantity::new(2.0, "")), None);
+    assert_eq!(px.apply(BinaryOp::Mul, &Quantity::new(2.0, "px")), None);
+    assert_eq!(Quantity::new(2.0, "").apply(BinaryOp::Div, &px), None);
+    assert_eq!(px.apply(BinaryOp::Exp, &Quantity::new(2.0, "")), None);
+  }
+
+  #[test]
+  fn formats_values_rounded_to_four_decimals() {
+    assert_eq!(Quantity::new(1.0 / 3.0, "rem").to_string(), "0.3333rem");
+    assert_eq!(Quantity::new(0.1 * 3.0, "em").to_string(), "0.3em");
+    assert_eq!(Quantity::new(16.0, "px").to_string(), "16px");
+  }
+}
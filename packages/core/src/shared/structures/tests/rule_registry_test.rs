@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod rule_registry {
+  use std::{sync::Arc, thread};
+
+  use crate::shared::structures::{
+    injectable_style::InjectableStyle, meta_data::MetaData, rule_registry::RuleRegistry,
+  };
+
+  fn rule(class_name: &str, css: &str, priority: f64) -> MetaData {
+    MetaData::new(
+      class_name.to_string(),
+      InjectableStyle {
+        ltr: css.to_string(),
+        rtl: None,
+        priority: Some(priority),
+      },
+    )
+  }
+
+  #[test]
+  fn registers_rules_once() {
+    let registry = RuleRegistry::new();
+
+    let first = registry.insert_or_get(rule("x1e2nbdu", ".x1e2nbdu{color:red}", 3000.0));
+    let second = registry.insert_or_get(rule("x1e2nbdu", ".x1e2nbdu{color:red}", 3000.0));
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(registry.len(), 1);
+  }
+
+  #[test]
+  fn registers_rules_of_concurrent_builds_once() {
+    let registry = RuleRegistry::new();
+
+    thread::scope(|scope| {
+      for file in 0..8 {
+        let registry = &registry;
+
+        scope.spawn(move || {
+          registry.extend([
+            rule("x1e2nbdu", ".x1e2nbdu{color:red}", 3000.0),
+            rule(
+              &format!("xfile{}", file),
+              &format!(".xfile{}{{margin:{}px}}", file, file),
+              1000.0,
+            ),
+          ]);
+        });
+      }
+    });
+
+    assert_eq!(registry.len(), 9);
+  }
+
+  #[test]
+  fn snapshots_rules_by_priority_then_css() {
+    let registry = RuleRegistry::new();
+
+    registry.extend([
+      rule("xb", ".xb{color:blue}", 3000.0),
+      rule("xc", ".xc:hover{color:red}", 3130.0),
+      rule("xa", ".xa{color:red}", 3000.0),
+      rule("xd", ".xd{margin:0}", 1000.0),
+    ]);
+
+    assert_eq!(
+      registry
+        .snapshot()
+        .iter()
+        .map(|rule| rule.get_class_name())
+        .collect::<Vec<_>>(),
+      vec!["xd", "xa", "xb", "xc"]
+    );
+  }
+}
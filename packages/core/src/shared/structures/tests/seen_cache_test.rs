@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod seen_cache {
+  use swc_core::ecma::ast::Expr;
+
+  use crate::shared::{
+    structures::{seen_cache::SeenCache, seen_value::SeenValue},
+    utils::ast::convertors::string_to_expression,
+  };
+
+  fn key(value: &str) -> Box<Expr> {
+    Box::new(string_to_expression(value))
+  }
+
+  fn unresolved() -> Box<SeenValue> {
+    Box::new(SeenValue {
+      value: None,
+      resolved: false,
+    })
+  }
+
+  #[test]
+  fn evicts_the_least_recently_used_entry() {
+    let mut cache = SeenCache::new(2);
+
+    cache.insert(key("a"), unresolved());
+    cache.insert(key("b"), unresolved());
+
+    assert!(cache.get(&key("a")).is_some());
+
+    cache.insert(key("c"), unresolved());
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.evictions(), 1);
+    assert!(cache.get(&key("a")).is_some());
+    assert!(cache.get(&key("b")).is_none());
+    assert!(cache.get(&key("c")).is_some());
+  }
+
+  #[test]
+  fn evicts_by_recency_after_many_lookups() {
+    let mut cache = SeenCache::new(2);
+
+    cache.insert(key("a"), unresolved());
+    cache.insert(key("b"), unresolved());
+
+    for _ in 0..100 {
+      assert!(cache.get(&key("a")).is_some());
+    }
+
+    cache.insert(key("c"), unresolved());
+    cache.insert(key("a"), unresolved());
+    cache.insert(key("d"), unresolved());
+
+    assert_eq!(cache.evictions(), 2);
+    assert!(cache.get(&key("a")).is_some());
+    assert!(cache.get(&key("b")).is_none());
+    assert!(cache.get(&key("c")).is_none());
+    assert!(cache.get(&key("d")).is_some());
+  }
+
+  #[test]
+  fn merges_entries_in_their_order_of_use() {
+    let mut cache = SeenCache::new(2);
+    let mut other = SeenCache::new(2);
+
+    other.insert(key("a"), unresolved());
+    other.insert(key("b"), unresolved());
+    assert!(other.get(&key("a")).is_some());
+
+    cache.merge(&other);
+    cache.insert(key("c"), unresolved());
+
+    assert!(cache.get(&key("a")).is_some());
+    assert!(cache.get(&key("b")).is_none());
+  }
+
+  #[test]
+  fn is_unbounded_with_a_capacity_of_zero() {
+    let mut cache = SeenCache::new(0);
+
+    for value in ["a", "b", "c"] {
+      cache.insert(key(value), unresolved());
+    }
+
+    assert_eq!(cache.len(), 3);
+    assert_eq!(cache.evictions(), 0);
+  }
+}
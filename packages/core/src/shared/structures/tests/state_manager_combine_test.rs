@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod state_manager_combine {
+  use swc_core::{atoms::Atom, ecma::ast::ModuleItem};
+
+  use crate::shared::structures::state_manager::{add_import_expression, StateManager};
+
+  fn import(path: &str) -> ModuleItem {
+    add_import_expression(path)
+  }
+
+  #[test]
+  fn adds_no_item_of_an_earlier_copy_twice() {
+    let mut state = StateManager::default();
+
+    state
+      .prepend_import_module_items
+      .push(import("./theme.css"));
+    state.import_paths.insert("./theme".to_string());
+
+    let earlier = state.clone();
+
+    state
+      .prepend_import_module_items
+      .push(import("./button.css"));
+
+    state.combine(&earlier);
+    state.combine(&earlier);
+
+    assert_eq!(
+      state.prepend_import_module_items,
+      vec![import("./theme.css"), import("./button.css")]
+    );
+    assert_eq!(state.import_paths.len(), 1);
+  }
+
+  #[test]
+  fn imports_files_referenced_by_both_states_once() {
+    let mut state = StateManager::default();
+
+    state.add_side_effect_import("otherFile.stylex");
+
+    let mut nested = state.clone();
+
+    nested.add_side_effect_import("otherFile.stylex");
+    nested.add_side_effect_import("tokens.stylex");
+
+    state.add_side_effect_import("otherFile.stylex");
+    state.combine(&nested);
+
+    assert_eq!(
+      state.prepend_import_module_items,
+      vec![import("otherFile.stylex"), import("tokens.stylex")]
+    );
+  }
+
+  #[test]
+  fn appends_items_and_unites_sets_of_other_states() {
+    let mut state = StateManager::default();
+    let mut other = StateManager::default();
+
+    state.prepend_import_module_items.push(import("./a.css"));
+    other.prepend_import_module_items.push(import("./b.css"));
+    other.import_paths.insert("./b".to_string());
+
+    state.combine(&other);
+
+    assert_eq!(
+      state.prepend_import_module_items,
+      vec![import("./a.css"), import("./b.css")]
+    );
+    assert!(state.import_paths.contains("./b"));
+  }
+
+  #[test]
+  fn keeps_the_higher_count() {
+    let mut state = StateManager::default();
+    let mut other = StateManager::default();
+
+    state.var_decl_count_map.insert(Atom::from("styles"), 3);
+    other.var_decl_count_map.insert(Atom::from("styles"), 1);
+    other.var_decl_count_map.insert(Atom::from("theme"), 2);
+    other
+      .member_object_ident_count_map
+      .insert(Atom::from("styles"), 4);
+
+    state.combine(&other);
+
+    assert_eq!(
+      state.var_decl_count_map.get(&Atom::from("styles")),
+      Some(&3)
+    );
+    assert_eq!(state.var_decl_count_map.get(&Atom::from("theme")), Some(&2));
+    assert_eq!(
+      state
+        .member_object_ident_count_map
+        .get(&Atom::from("styles")),
+      Some(&4)
+    );
+  }
+
+  #[test]
+  fn keeps_own_keyed_and_optional_values() {
+    let mut state = StateManager {
+      theme_name: Some("x1".to_string()),
+      ..Default::default()
+    };
+
+    state
+      .aliased_properties
+      .insert("marginHorizontal".to_string(), "marginInline".to_string());
+
+    let mut other = StateManager {
+      theme_name: Some("x2".to_string()),
+      in_stylex_create: true,
+      ..Default::default()
+    };
+
+    other
+      .aliased_properties
+      .insert("marginHorizontal".to_string(), "margin".to_string());
+    other
+      .aliased_properties
+      .insert("paddingVertical".to_string(), "paddingBlock".to_string());
+
+    state.combine(&other);
+
+    assert_eq!(state.theme_name.as_deref(), Some("x1"));
+    assert_eq!(
+      state
+        .aliased_properties
+        .get("marginHorizontal")
+        .map(String::as_str),
+      Some("marginInline")
+    );
+    assert_eq!(
+      state
+        .aliased_properties
+        .get("paddingVertical")
+        .map(String::as_str),
+      Some("paddingBlock")
+    );
+    assert!(state.in_stylex_create);
+  }
+}
@@ -0,0 +1,364 @@
+#[cfg(test)]
+mod stylex_options_builder {
+  use std::path::PathBuf;
+
+  use swc_core::css::ast::Stylesheet;
+
+  use crate::shared::structures::{
+    compat_version::CompatVersion,
+    named_import_source::{
+      ImportSources, NamedImportSource, RuntimeInjection, RuntimeInjectionTarget,
+    },
+    options_conflict::OptionsConflict,
+    stylex_options::{
+      CheckModuleResolution, CssImport, ModuleResolution, ModuleTargetEnv, StyleXOptions,
+      Transforms,
+    },
+    stylex_options_builder::StyleXOptionsError,
+    stylex_state_options::StyleXStateOptions,
+  };
+  use crate::shared::utils::css::normalizers::{
+    base::TimingNormalizer,
+    pipeline::{CssNormalizer, CssNormalizerPipeline},
+  };
+
+  #[test]
+  fn builds_options_with_defaults() {
+    let options = StyleXOptions::builder().build().unwrap();
+
+    assert!(!options.dev);
+    assert_eq!(options.class_name_prefix, "x");
+    assert_eq!(options.runtime_injection, RuntimeInjection::Boolean(false));
+    assert!(matches!(
+      options.unstable_module_resolution,
+      Some(CheckModuleResolution::Haste(_))
+    ));
+  }
+
+  #[test]
+  fn builds_options_from_setters() {
+    let options = StyleXOptions::builder()
+      .dev(true)
+      .class_name_prefix("app")
+      .runtime_injection(true)
+      .runtime_injection_target(RuntimeInjectionTarget::ConstructableStylesheet)
+      .gen_conditional_classes(true)
+      .import_source(ImportSources::Regular("@acme/styles".to_string()))
+      .import_source(ImportSources::Named(NamedImportSource {
+        from: "react-strict-dom".to_string(),
+        r#as: "css".to_string(),
+      }))
+      .module_resolution(StyleXOptions::get_common_js_module_resolution(Some(
+        "/app".to_string(),
+      )))
+      .env_var("NODE_ENV", "production")
+      .env_var("BRAND_COLOR", "tomato")
+      .build()
+      .unwrap();
+
+    assert!(options.dev);
+    assert!(options.gen_conditional_classes);
+    assert_eq!(options.class_name_prefix, "app");
+    assert_eq!(
+      options.runtime_injection,
+      RuntimeInjection::Regular("@stylexjs/stylex/lib/stylex-inject".to_string())
+    );
+    assert_eq!(
+      options.runtime_injection_target,
+      RuntimeInjectionTarget::ConstructableStylesheet
+    );
+    assert_eq!(options.import_sources.len(), 2);
+    assert!(matches!(
+      options.unstable_module_resolution,
+      Some(CheckModuleResolution::CommonJS(ModuleResolution { root_dir: Some(ref root_dir), .. }))
+        if root_dir == "/app"
+    ));
+    assert_eq!(
+      options
+        .env
+        .as_ref()
+        .and_then(|env| env.get("NODE_ENV"))
+        .map(String::as_str),
+      Some("production")
+    );
+    assert_eq!(options.env.map(|env| env.len()), Some(2));
+  }
+
+  #[test]
+  fn disables_individual_transforms() {
+    let options = StyleXOptions::builder()
+      .transforms(Transforms {
+        keyframes: false,
+        define_vars: false,
+        ..Transforms::default()
+      })
+      .build()
+      .unwrap();
+
+    assert!(options.transforms.is_enabled("create"));
+    assert!(!options.transforms.is_enabled("keyframes"));
+    assert!(!options.transforms.is_enabled("defineVars"));
+    assert!(StyleXOptions::builder()
+      .build()
+      .unwrap()
+      .transforms
+      .is_enabled("keyframes"));
+  }
+
+  #[test]
+  fn rejects_invalid_compat_version() {
+    assert_eq!(
+      StyleXOptions::builder()
+        .compat_version("latest")
+        .build()
+        .unwrap_err(),
+      StyleXOptionsError::InvalidCompatVersion("latest".to_string())
+    );
+    assert_eq!(
+      StyleXOptions::builder()
+        .compat_version("0.4")
+        .build()
+        .unwrap()
+        .compat_version,
+      Some(CompatVersion::new(0, 4, 0))
+    );
+  }
+
+  #[test]
+  fn rejects_invalid_class_name_prefix() {
+    for prefix in ["", "1x", "x y"] {
+      assert_eq!(
+        StyleXOptions::builder()
+          .class_name_prefix(prefix)
+          .build()
+          .unwrap_err(),
+        StyleXOptionsError::InvalidClassNamePrefix(prefix.to_string())
+      );
+    }
+  }
+
+  #[test]
+  fn rejects_empty_import_sources() {
+    let error = StyleXOptions::builder()
+      .import_source(ImportSources::Named(NamedImportSource {
+        from: "react-strict-dom".to_string(),
+        r#as: String::default(),
+      }))
+      .build()
+      .unwrap_err();
+
+    assert_eq!(error, StyleXOptionsError::EmptyImportSource);
+  }
+
+  #[test]
+  fn rejects_empty_macro_modules() {
+    let error = StyleXOptions::builder()
+      .macro_module("./macros")
+      .macro_module("")
+      .build()
+      .unwrap_err();
+
+    assert_eq!(error, StyleXOptionsError::EmptyImportSource);
+  }
+
+  #[test]
+  fn resolves_the_css_import_specifier() {
+    let options = StyleXOptions::builder()
+      .css_import(CssImport::Enabled(true))
+      .build()
+      .unwrap();
+
+    assert_eq!(options.css_import.as_deref(), Some("virtual:stylex.css"));
+
+    let error = StyleXOptions::builder()
+      .css_import(CssImport::Specifier(String::default()))
+      .build()
+      .unwrap_err();
+
+    assert_eq!(error, StyleXOptionsError::EmptyImportSource);
+  }
+
+  #[test]
+  fn rejects_unknown_module_resolution() {
+    let error = StyleXOptions::builder()
+      .module_resolution(ModuleResolution {
+        r#type: "node".to_string(),
+        root_dir: None,
+        root_dirs: None,
+        theme_file_extension: None,
+        target_env: None,
+        conditions: None,
+      })
+      .build()
+      .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "Module resolution type must be one of commonjs, haste, cross-file-parsing, but got: node"
+    );
+  }
+
+  #[test]
+  fn reads_target_env_and_conditions_of_module_resolution() {
+    let module_resolution: ModuleResolution = serde_json::from_str(
+      r#"{ "type": "commonJS", "rootDir": "/app", "targetEnv": "browser", "conditions": ["worker"] }"#,
+    )
+    .unwrap();
+
+    let resolver_options = module_resolution.resolver_options();
+
+    assert_eq!(module_resolution.target_env, Some(ModuleTargetEnv::Browser));
+    assert_eq!(
+      resolver_options.export_conditions(),
+      vec!["worker", "browser", "import", "require"]
+    );
+
+    let module_resolution = StyleXOptions::get_common_js_module_resolution(None);
+
+    assert_eq!(
+      module_resolution.resolver_options().export_conditions(),
+      vec!["node", "import", "require"]
+    );
+  }
+
+  #[test]
+  fn prefers_root_dirs_of_module_resolution_over_root_dir() {
+    let module_resolution: ModuleResolution = serde_json::from_str(
+      r#"{ "type": "commonJS", "rootDir": "/repo", "rootDirs": ["/repo/apps/web", "/repo/packages/ui"] }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      module_resolution.get_root_dirs(),
+      vec![
+        PathBuf::from("/repo/apps/web"),
+        PathBuf::from("/repo/packages/ui")
+      ]
+    );
+
+    assert_eq!(
+      StyleXOptions::get_common_js_module_resolution(Some("/repo".to_string())).get_root_dirs(),
+      vec![PathBuf::from("/repo")]
+    );
+    assert!(StyleXOptions::get_common_js_module_resolution(None)
+      .get_root_dirs()
+      .is_empty());
+  }
+
+  #[test]
+  fn rejects_empty_vars_file_suffixes() {
+    let error = StyleXOptions::builder()
+      .valid_vars_file_suffixes(vec![".tokens.ts".to_string(), String::default()])
+      .build()
+      .unwrap_err();
+
+    assert_eq!(error, StyleXOptionsError::EmptyVarsFileSuffix);
+  }
+
+  #[test]
+  fn rejects_invalid_file_globs() {
+    let error = StyleXOptions::builder()
+      .include(vec!["src/**".to_string()])
+      .exclude(vec!["**/[generated/**".to_string()])
+      .build()
+      .unwrap_err();
+
+    assert_eq!(
+      error,
+      StyleXOptionsError::InvalidFileGlob("**/[generated/**".to_string())
+    );
+  }
+
+  #[test]
+  fn rejects_invalid_pseudo_class_order() {
+    let error = StyleXOptions::builder()
+      .pseudo_class_order(vec![":hover".to_string(), "::before".to_string()])
+      .build()
+      .unwrap_err();
+
+    assert_eq!(
+      error,
+      StyleXOptionsError::InvalidPseudoClassOrder("::before".to_string())
+    );
+  }
+
+  #[test]
+  fn reports_all_conflicting_options_together() {
+    let error = StyleXOptions::builder()
+      .dev(true)
+      .test(true)
+      .css_import(CssImport::Enabled(true))
+      .build()
+      .unwrap_err();
+
+    assert_eq!(
+      error,
+      StyleXOptionsError::ConflictingOptions(vec![
+        OptionsConflict::DevWithTest,
+        OptionsConflict::CssImportWithRuntimeInjection,
+      ])
+    );
+    assert_eq!(
+      error.to_string(),
+      "The StyleX options contain settings that cannot apply together:\n  \
+       - \"dev\" and \"test\" both rewrite class names, enable only one of them\n  \
+       - \"cssImport\" only applies to extracted rules, but \"runtimeInjection\" or \"dev\" injects them"
+    );
+  }
+
+  #[test]
+  fn accepts_canonical_keyframes_with_a_compat_version() {
+    let options = StyleXOptions::builder()
+      .compat_version("0.6")
+      .canonical_keyframes(true)
+      .build()
+      .unwrap();
+
+    // Ignored when the keyframes are hashed, the same as with options passed
+    // to the plugin directly
+    assert!(options.canonical_keyframes);
+    assert!(options.compat_version.is_some());
+  }
+
+  #[test]
+  fn rejects_runtime_injection_target_without_runtime_injection() {
+    let error = StyleXOptions::builder()
+      .runtime_injection_target(RuntimeInjectionTarget::ConstructableStylesheet)
+      .build()
+      .unwrap_err();
+
+    assert_eq!(
+      error,
+      StyleXOptionsError::ConflictingOptions(vec![
+        OptionsConflict::RuntimeInjectionTargetWithoutRuntimeInjection
+      ])
+    );
+  }
+
+  #[test]
+  fn keeps_custom_css_normalizers() {
+    #[derive(Clone, Copy)]
+    struct Noop;
+
+    impl CssNormalizer for Noop {
+      fn name(&self) -> &str {
+        "noop"
+      }
+
+      fn normalize(&self, ast: Stylesheet, _: &StyleXStateOptions) -> Stylesheet {
+        ast
+      }
+    }
+
+    let options = StyleXOptions::builder()
+      .css_normalizers(CssNormalizerPipeline::default().remove(TimingNormalizer::NAME))
+      .css_normalizer(Noop)
+      .build()
+      .unwrap();
+
+    assert_eq!(
+      options.css_normalizers.names(),
+      vec!["kebab-case", "zero-dimension", "font-size-to-rem", "noop"]
+    );
+  }
+}
@@ -0,0 +1,101 @@
+#[cfg(all(test, feature = "json-schema"))]
+mod stylex_options_schema {
+  use serde_json::json;
+
+  use crate::shared::structures::stylex_options::StyleXOptionsParams;
+
+  #[test]
+  fn uses_serde_names_for_options() {
+    let schema = StyleXOptionsParams::json_schema();
+    let properties = schema["properties"].as_object().unwrap();
+
+    let mut names = properties.keys().map(String::as_str).collect::<Vec<_>>();
+    names.sort();
+
+    assert_eq!(
+      names,
+      vec![
+        "aliases",
+        "attrsConflicts",
+        "cacheVars",
+        "callSites",
+        "canonicalKeyframes",
+        "classNameManifest",
+        "classNamePrefix",
+        "compatVersion",
+        "cssImport",
+        "definedStylexCssVariables",
+        "dev",
+        "env",
+        "exclude",
+        "exportStyles",
+        "genConditionalClasses",
+        "hashPackageScope",
+        "importSources",
+        "include",
+        "largeValues",
+        "lightDarkVars",
+        "macroModules",
+        "maxRuleSize",
+        "metadataVersion",
+        "phaseTimings",
+        "preserveSideEffectImports",
+        "propertyAliases",
+        "pseudoClassOrder",
+        "resolveExternalPackages",
+        "runtimeCreateFallback",
+        "runtimeInjection",
+        "runtimeInjectionTarget",
+        "seenCacheCapacity",
+        "shadowDom",
+        "styleResolution",
+        "test",
+        "transforms",
+        "treeshakeCompensation",
+        "unitHelpers",
+        "unstable_moduleResolution",
+        "useRemForFontSize",
+        "validVarsFileSuffixes",
+        "varUsageGraph",
+        "warnEscapingStyles",
+      ]
+    );
+  }
+
+  #[test]
+  fn uses_serde_names_for_nested_types() {
+    let schema = StyleXOptionsParams::json_schema();
+    let definitions = &schema["definitions"];
+
+    assert_eq!(
+      definitions["StyleResolution"]["enum"],
+      json!([
+        "application-order",
+        "property-specificity",
+        "legacy-expand-shorthands"
+      ])
+    );
+
+    assert_eq!(definitions["ModuleResolution"]["required"], json!(["type"]));
+    assert!(definitions["ModuleResolution"]["properties"]["rootDir"].is_object());
+    assert_eq!(
+      definitions["ModuleTargetEnv"]["enum"],
+      json!(["node", "browser"])
+    );
+    assert!(definitions["NamedImportSource"]["properties"]["as"].is_object());
+  }
+
+  #[test]
+  fn accepts_every_option_documented_in_schema() {
+    let schema = StyleXOptionsParams::json_schema();
+
+    let options = schema["properties"]
+      .as_object()
+      .unwrap()
+      .keys()
+      .map(|name| (name.clone(), serde_json::Value::Null))
+      .collect::<serde_json::Map<_, _>>();
+
+    assert!(serde_json::from_value::<StyleXOptionsParams>(options.into()).is_ok());
+  }
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
-use crate::shared::utils::common::{create_hash, gen_file_based_identifier};
+use crate::shared::utils::{
+  common::{create_hash, gen_file_based_identifier, scope_hash_input},
+  css::escape::escape_css_ident,
+};
 
 use super::state_manager::StateManager;
 
@@ -13,7 +16,7 @@ pub struct ThemeRef {
 }
 
 impl ThemeRef {
-  pub(crate) fn new(file_name: String, export_name: String, state: StateManager) -> Self {
+  pub fn new(file_name: String, export_name: String, state: StateManager) -> Self {
     Self {
       file_name,
       export_name,
@@ -22,20 +25,31 @@ impl ThemeRef {
     }
   }
 
-  pub(crate) fn get(&mut self, key: &str) -> (String, &StateManager) {
+  pub fn file_name(&self) -> &str {
+    &self.file_name
+  }
+
+  pub fn export_name(&self) -> &str {
+    &self.export_name
+  }
+
+  pub fn get(&mut self, key: &str) -> (String, &StateManager) {
     if key.starts_with("--") {
-      let css_key = format!("var({})", key);
+      let css_key = format!("var({})", escape_css_ident(key));
       return (css_key, &self.state);
     }
     let entry = self.map.entry(key.to_string()).or_insert_with(|| {
-      let str_to_hash = gen_file_based_identifier(
-        &self.file_name,
-        &self.export_name,
-        if key == "__themeName__" {
-          None
-        } else {
-          Some(key)
-        },
+      let str_to_hash = scope_hash_input(
+        self.state.get_theme_package_scope(&self.file_name),
+        gen_file_based_identifier(
+          &self.file_name,
+          &self.export_name,
+          if key == "__themeName__" {
+            None
+          } else {
+            Some(key)
+          },
+        ),
       );
 
       let var_name = format!(
@@ -0,0 +1,127 @@
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+use once_cell::sync::Lazy;
+use swc_core::ecma::ast::{Decl, Expr, Lit, ModuleDecl, ModuleItem, ObjectLit, Pat, PropName};
+
+use crate::shared::{constants::common::DEFAULT_EXPORT_NAME, utils::common::normalize_expr};
+
+use super::module_cache::{parse_module, ModuleCache};
+
+static TOKEN_MODULES: Lazy<ModuleCache<TokenModule>> = Lazy::new(ModuleCache::default);
+
+/// Module imported by a style file whose exported objects can be spread into
+/// `stylex.create()` namespaces, e.g.
+/// `export const typography = { body: { fontSize: 16 } }`.
+///
+/// Only exported objects whose values are literals or such objects are
+/// collected, other exports are ignored.
+#[derive(Clone, Debug, Default)]
+pub struct TokenModule {
+  objects: HashMap<String, ObjectLit>,
+}
+
+impl TokenModule {
+  /// Returns the token module at `path`, read and parsed again only after
+  /// the modification time of the file changed, or `None` when it can't be
+  /// read or parsed.
+  pub fn load(path: &Path) -> Option<Arc<TokenModule>> {
+    TOKEN_MODULES.load(path, TokenModule::parse)
+  }
+
+  /// Collects the static objects exported by `source`, or returns `None`
+  /// when it can't be parsed.
+  pub fn parse(path: &Path, source: &str) -> Option<TokenModule> {
+    let module = parse_module(path, source)?;
+
+    let mut objects = HashMap::new();
+
+    for item in &module.body {
+      let ModuleItem::ModuleDecl(decl) = item else {
+        continue;
+      };
+
+      match decl {
+        ModuleDecl::ExportDecl(export) => {
+          let Decl::Var(var_decl) = &export.decl else {
+            continue;
+          };
+
+          for declarator in &var_decl.decls {
+            let (Pat::Ident(name), Some(init)) = (&declarator.name, &declarator.init) else {
+              continue;
+            };
+
+            if let Some(object) = expr_to_static_object(init) {
+              objects.insert(name.sym.to_string(), object);
+            }
+          }
+        }
+        ModuleDecl::ExportDefaultExpr(export) => {
+          if let Some(object) = expr_to_static_object(&export.expr) {
+            objects.insert(DEFAULT_EXPORT_NAME.to_string(), object);
+          }
+        }
+        _ => {}
+      }
+    }
+
+    Some(TokenModule { objects })
+  }
+
+  /// Returns the object at `members` of the export `export_name`, e.g. the
+  /// `body` object for `typography` and `["body"]`.
+  pub fn get(&self, export_name: &str, members: &[String]) -> Option<&ObjectLit> {
+    members
+      .iter()
+      .try_fold(self.objects.get(export_name)?, |object, member| {
+        get_object_member(object, member)
+      })
+  }
+}
+
+fn expr_to_static_object(expr: &Expr) -> Option<ObjectLit> {
+  let mut expr = expr.clone();
+
+  match normalize_expr(&mut expr) {
+    Expr::Object(object) if is_static_object(object) => Some(object.clone()),
+    _ => None,
+  }
+}
+
+fn is_static_object(object: &ObjectLit) -> bool {
+  object.props.iter().all(|prop| {
+    let Some(key_value) = prop.as_prop().and_then(|prop| prop.as_key_value()) else {
+      return false;
+    };
+
+    let is_static_key = matches!(
+      key_value.key,
+      PropName::Ident(_) | PropName::Str(_) | PropName::Num(_)
+    );
+
+    let is_static_value = match key_value.value.as_ref() {
+      Expr::Lit(Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::Null(_)) => true,
+      Expr::Object(object) => is_static_object(object),
+      _ => false,
+    };
+
+    is_static_key && is_static_value
+  })
+}
+
+fn get_object_member<'a>(object: &'a ObjectLit, member: &str) -> Option<&'a ObjectLit> {
+  // Later keys override earlier ones
+  let key_value = object
+    .props
+    .iter()
+    .rev()
+    .filter_map(|prop| prop.as_prop()?.as_key_value())
+    .find(|key_value| match &key_value.key {
+      PropName::Ident(ident) => &*ident.sym == member,
+      PropName::Str(str) => &*str.value == member,
+      PropName::Num(num) => num.value.to_string() == member,
+      _ => false,
+    })?;
+
+  key_value.value.as_object()
+}
@@ -0,0 +1,26 @@
+use std::{collections::HashMap, rc::Rc};
+
+use indexmap::IndexMap;
+use swc_core::{
+  atoms::Atom,
+  ecma::ast::{BindingIdent, Expr, VarDecl},
+};
+
+use crate::shared::enums::data_structures::{
+  evaluate_result_value::EvaluateResultValue, flat_compiled_styles_value::FlatCompiledStylesValue,
+};
+
+use super::{
+  functions::FunctionConfigType, injectable_style::InjectableStyle,
+  named_import_source::ImportSources,
+};
+
+pub type FlatCompiledStyles = IndexMap<String, Box<FlatCompiledStylesValue>>;
+pub type EvaluateResultFns =
+  IndexMap<String, (Vec<BindingIdent>, Vec<VarDecl>, IndexMap<String, Box<Expr>>)>;
+pub type EvaluationCallback = Rc<dyn Fn(Vec<Option<EvaluateResultValue>>) -> Expr + 'static>;
+pub type FunctionMapMemberExpression =
+  HashMap<Box<ImportSources>, Box<HashMap<Box<Atom>, Box<FunctionConfigType>>>>;
+pub type FunctionMapIdentifiers = HashMap<Box<Atom>, Box<FunctionConfigType>>;
+pub type StylesObjectMap = IndexMap<String, Box<IndexMap<String, Box<FlatCompiledStylesValue>>>>;
+pub type InjectableStylesMap = IndexMap<String, Box<InjectableStyle>>;
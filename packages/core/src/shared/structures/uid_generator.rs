@@ -6,7 +6,7 @@ use swc_core::ecma::ast::Ident;
 use crate::shared::utils::ast::factories::ident_factory;
 
 /// A thread-safe generator for unique identifiers.
-pub(crate) struct UidGenerator {
+pub struct UidGenerator {
   prefix: String,
   counters: DashMap<String, AtomicUsize>,
 }
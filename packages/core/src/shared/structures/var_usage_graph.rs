@@ -0,0 +1,57 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+/// Keys of `stylex.defineVars()` groups, by the file declaring the group and
+/// its export name. Used for both the keys a module consumes and the ones it
+/// declares.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct VarUsageGraph(BTreeMap<String, BTreeMap<String, BTreeSet<String>>>);
+
+impl VarUsageGraph {
+  pub fn add(&mut self, file_name: &str, export_name: &str, key: &str) {
+    self
+      .0
+      .entry(file_name.to_string())
+      .or_default()
+      .entry(export_name.to_string())
+      .or_default()
+      .insert(key.to_string());
+  }
+
+  pub fn merge(&mut self, other: &VarUsageGraph) {
+    for (file_name, groups) in &other.0 {
+      for (export_name, keys) in groups {
+        for key in keys {
+          self.add(file_name, export_name, key);
+        }
+      }
+    }
+  }
+
+  /// Keys of the group exported as `export_name` from `file_name`.
+  pub fn keys(&self, file_name: &str, export_name: &str) -> Option<&BTreeSet<String>> {
+    self.0.get(file_name)?.get(export_name)
+  }
+
+  /// Every `(file_name, export_name, key)` of the graph.
+  pub fn entries(&self) -> impl Iterator<Item = (&str, &str, &str)> {
+    self.0.iter().flat_map(|(file_name, groups)| {
+      groups.iter().flat_map(move |(export_name, keys)| {
+        keys
+          .iter()
+          .map(move |key| (file_name.as_str(), export_name.as_str(), key.as_str()))
+      })
+    })
+  }
+
+  pub fn contains(&self, file_name: &str, export_name: &str, key: &str) -> bool {
+    self
+      .keys(file_name, export_name)
+      .is_some_and(|keys| keys.contains(key))
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
@@ -0,0 +1,98 @@
+use std::{
+  collections::{hash_map::DefaultHasher, HashMap},
+  hash::{Hash, Hasher},
+};
+
+use dashmap::DashMap;
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use stylex_path_resolver::utils::normalize_path_separators;
+use swc_core::ecma::{ast::Expr, utils::drop_span};
+
+use super::{injectable_style::InjectableStyle, stylex_state_options::StyleXStateOptions};
+
+static VARS_CACHE: Lazy<VarsCache> = Lazy::new(VarsCache::default);
+
+/// Compiled result of a single `stylex.defineVars()` call.
+#[derive(Clone, Debug)]
+pub struct CachedVarGroup {
+  pub theme_name: String,
+  pub ast: Expr,
+  pub injected_styles: IndexMap<String, Box<InjectableStyle>>,
+}
+
+/// Var groups of a file by export name, next to the hash of the value each
+/// was compiled from.
+type CachedVarsFile = HashMap<String, (String, CachedVarGroup)>;
+
+/// Process wide cache of compiled var groups, used by long running (watch
+/// mode) builds when the `cacheVars` option is enabled.
+///
+/// Entries are keyed by file path and export name, and are only reused while
+/// the hash of the evaluated `stylex.defineVars()` value stays the same, so an
+/// edited token group is recompiled on its next transform. Watchers should
+/// call [`VarsCache::invalidate`] for deleted or renamed files.
+#[derive(Default)]
+pub struct VarsCache {
+  files: DashMap<String, CachedVarsFile>,
+}
+
+impl VarsCache {
+  pub fn global() -> &'static VarsCache {
+    &VARS_CACHE
+  }
+
+  /// Drops the var groups of `path`. Returns whether anything was cached.
+  pub fn invalidate(&self, path: &str) -> bool {
+    self
+      .files
+      .remove(&normalize_path_separators(path))
+      .is_some()
+  }
+
+  /// Keeps only the files for which `keep` returns `true`.
+  pub fn retain(&self, keep: impl Fn(&str) -> bool) {
+    self.files.retain(|path, _| keep(path));
+  }
+
+  pub fn clear(&self) {
+    self.files.clear();
+  }
+
+  pub fn len(&self) -> usize {
+    self.files.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.files.is_empty()
+  }
+
+  pub fn get(&self, path: &str, value_hash: &str, export_name: &str) -> Option<CachedVarGroup> {
+    let file = self.files.get(&normalize_path_separators(path))?;
+
+    match file.get(export_name) {
+      Some((hash, group)) if hash == value_hash => Some(group.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn insert(&self, path: &str, value_hash: &str, export_name: &str, group: CachedVarGroup) {
+    self
+      .files
+      .entry(normalize_path_separators(path))
+      .or_default()
+      .insert(export_name.to_string(), (value_hash.to_string(), group));
+  }
+}
+
+/// Hashes the evaluated value of a `stylex.defineVars()` call together with
+/// the options that affect its output, so a cached var group is never reused
+/// after either of them changes.
+pub fn hash_define_vars_value(value: &Expr, options: &StyleXStateOptions) -> String {
+  let mut hasher = DefaultHasher::new();
+
+  format!("{:?}", drop_span(value.clone())).hash(&mut hasher);
+  format!("{:?}", options).hash(&mut hasher);
+
+  format!("{:x}", hasher.finish())
+}
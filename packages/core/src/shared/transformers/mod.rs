@@ -0,0 +1,8 @@
+pub mod stylex_create;
+pub mod stylex_create_theme;
+pub mod stylex_define_vars;
+pub mod stylex_first_that_works;
+pub mod stylex_include;
+pub mod stylex_keyframes;
+pub mod stylex_types;
+pub(crate) mod tests;
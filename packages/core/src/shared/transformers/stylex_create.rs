@@ -6,37 +6,72 @@ use crate::shared::{
     evaluate_result_value::EvaluateResultValue, flat_compiled_styles_value::FlatCompiledStylesValue,
   },
   structures::{
+    exported_styles::{ExportedDeclaration, ExportedStyles},
     functions::FunctionMap,
     injectable_style::InjectableStyle,
     pre_rule::{CompiledResult, ComputedStyle, PreRule, PreRules},
     state_manager::StateManager,
-    types::FlatCompiledStyles,
+    stylex_options::LargeValues,
+    types::{FlatCompiledStyles, InjectableStylesMap},
   },
   utils::{
-    ast::convertors::expr_to_str, core::flatten_raw_style_object::flatten_raw_style_object,
+    ast::convertors::expr_to_str,
+    core::{
+      alias_properties::alias_properties, flatten_raw_style_object::flatten_raw_style_object,
+    },
     validators::validate_namespace,
   },
 };
 
-pub(crate) fn stylex_create_set(
+pub fn stylex_create_set(
   namespaces: &EvaluateResultValue,
   state: &mut StateManager,
   functions: &FunctionMap,
 ) -> (
   IndexMap<String, Box<FlatCompiledStyles>>,
-  IndexMap<String, Box<InjectableStyle>>,
+  InjectableStylesMap,
+  ExportedStyles,
 ) {
   let mut resolved_namespaces: IndexMap<String, Box<FlatCompiledStyles>> = IndexMap::new();
-  let mut injected_styles_map: IndexMap<String, Box<InjectableStyle>> = IndexMap::new();
+  let mut injected_styles_map: InjectableStylesMap = IndexMap::new();
+  let mut exported_styles: ExportedStyles = IndexMap::new();
 
   for (namespace_name, namespace) in namespaces.as_map().unwrap() {
-    validate_namespace(namespace, &[]);
+    let namespace = alias_properties(namespace, state);
+
+    validate_namespace(&namespace, &[]).unwrap_or_else(|error| panic!("{}", error));
 
     let mut pseudos = vec![];
     let mut at_rules = vec![];
 
     let mut flattened_namespace =
-      flatten_raw_style_object(namespace, &mut pseudos, &mut at_rules, state, functions);
+      flatten_raw_style_object(&namespace, &mut pseudos, &mut at_rules, state, functions);
+
+    let declarations = flattened_namespace
+      .iter()
+      .flat_map(|(key, rule)| ExportedDeclaration::from_pre_rule(key, rule))
+      .collect::<Vec<ExportedDeclaration>>();
+
+    if state.options.large_values == LargeValues::Variable && state.options.max_rule_size > 0 {
+      let root = state.get_shadow_host();
+
+      for rule in flattened_namespace.values_mut() {
+        for (name, value) in rule.hoist_large_values(state.options.max_rule_size, state) {
+          injected_styles_map.entry(name.clone()).or_insert_with(|| {
+            Box::new(InjectableStyle {
+              ltr: format!(
+                "{}{{{}:{};}}",
+                root.as_deref().unwrap_or(":root"),
+                name,
+                value
+              ),
+              rtl: None,
+              priority: Some(0.0),
+            })
+          });
+        }
+      }
+    }
 
     let compiled_namespace_tuples = flattened_namespace
       .iter_mut()
@@ -110,8 +145,9 @@ pub(crate) fn stylex_create_set(
       Box::new(FlatCompiledStylesValue::Bool(true)),
     );
 
+    exported_styles.insert(resolved_namespace_name.clone(), declarations);
     resolved_namespaces.insert(resolved_namespace_name, Box::new(namespace_obj));
   }
 
-  (resolved_namespaces, injected_styles_map)
+  (resolved_namespaces, injected_styles_map, exported_styles)
 }
@@ -3,7 +3,10 @@ use std::cmp::Ordering;
 use indexmap::IndexMap;
 
 use crate::shared::{
-  constants::common::{COMPILED_KEY, THEME_NAME_KEY},
+  constants::{
+    common::{COMPILED_KEY, THEME_NAME_KEY},
+    messages::UNSUPPORTED_EVALUATE_RESULT,
+  },
   enums::data_structures::{
     evaluate_result_value::EvaluateResultValue, flat_compiled_styles_value::FlatCompiledStylesValue,
   },
@@ -13,12 +16,15 @@ use crate::shared::{
   utils::{
     ast::convertors::expr_to_str,
     common::{create_hash, get_css_value, get_key_str, get_key_values_from_object},
-    core::define_vars_utils::{collect_vars_by_at_rules, priority_for_at_rule, wrap_with_at_rules},
+    core::define_vars_utils::{
+      collect_vars_by_at_rules, light_dark_value, priority_for_at_rule, wrap_with_at_rules,
+    },
+    css::common::scope_selectors_to_host,
     validators::validate_theme_variables,
   },
 };
 
-pub(crate) fn stylex_create_theme(
+pub fn stylex_create_theme(
   theme_vars: &mut EvaluateResultValue,
   variables: &EvaluateResultValue,
   state: &mut StateManager,
@@ -45,9 +51,15 @@ pub(crate) fn stylex_create_theme(
     a_key.cmp(&b_key)
   });
 
-  for key_value in variables_key_values.into_iter() {
+  for mut key_value in variables_key_values.into_iter() {
     let key = get_key_str(&key_value);
 
+    if state.options.light_dark_vars {
+      if let Some(light_dark) = light_dark_value(&key_value.value) {
+        key_value.value = Box::new(light_dark);
+      }
+    }
+
     let theme_vars_str_value = match theme_vars {
       EvaluateResultValue::Expr(expr) => {
         let theme_vars_key_values = get_key_values_from_object(expr.as_object().unwrap());
@@ -69,7 +81,14 @@ pub(crate) fn stylex_create_theme(
         theme_vars_str_value
       }
       EvaluateResultValue::ThemeRef(theme_ref) => theme_ref.get(key.as_str()).0.clone(),
-      _ => unimplemented!("Unsupported theme vars type"),
+      EvaluateResultValue::Vec(_)
+      | EvaluateResultValue::Map(_)
+      | EvaluateResultValue::Entries(_)
+      | EvaluateResultValue::Callback(_)
+      | EvaluateResultValue::FunctionConfig(_)
+      | EvaluateResultValue::FunctionConfigMap(_) => {
+        panic!("{} {}", UNSUPPORTED_EVALUATE_RESULT, theme_vars.kind())
+      }
     };
 
     let name_hash = theme_vars_str_value[6..theme_vars_str_value.len() - 1].to_string();
@@ -117,7 +136,14 @@ pub(crate) fn stylex_create_theme(
 
   for at_rule in sorted_at_rules.into_iter() {
     let decls = rules_by_at_rule.get(at_rule).unwrap().join("");
-    let rule = format!(".{}{{{}}}", override_class_name, decls);
+    let selector = format!(".{}", override_class_name);
+
+    let selector = match state.get_shadow_host() {
+      Some(host) => scope_selectors_to_host(&selector, &host),
+      None => selector,
+    };
+
+    let rule = format!("{}{{{}}}", selector, decls);
 
     if at_rule == "default" {
       styles_to_inject.insert(
@@ -156,7 +182,14 @@ pub(crate) fn stylex_create_theme(
       &FunctionMap::default(),
     ),
     EvaluateResultValue::ThemeRef(theme_ref) => theme_ref.get(THEME_NAME_KEY).0.to_owned(),
-    _ => unimplemented!("Unsupported theme vars type"),
+    EvaluateResultValue::Vec(_)
+    | EvaluateResultValue::Map(_)
+    | EvaluateResultValue::Entries(_)
+    | EvaluateResultValue::Callback(_)
+    | EvaluateResultValue::FunctionConfig(_)
+    | EvaluateResultValue::FunctionConfigMap(_) => {
+      panic!("{} {}", UNSUPPORTED_EVALUATE_RESULT, theme_vars.kind())
+    }
   };
 
   resolved_theme_vars.insert(
@@ -9,12 +9,13 @@ use crate::shared::{
   structures::{injectable_style::InjectableStyle, state_manager::StateManager},
   utils::{
     common::{create_hash, get_css_value},
-    core::define_vars_utils::construct_css_variables_string,
+    core::define_vars_utils::{construct_css_variables_string, light_dark_value},
+    css::escape::escape_css_ident,
     object::obj_map,
   },
 };
 
-pub(crate) fn stylex_define_vars(
+pub fn stylex_define_vars(
   variables: &EvaluateResultValue,
   state: &mut StateManager,
 ) -> (
@@ -46,7 +47,7 @@ pub(crate) fn stylex_define_vars(
 
           // Created hashed variable names with fileName//themeName//key
           let name_hash = if key.starts_with("--") {
-            key.get(2..).unwrap_or_default()
+            &escape_css_ident(key)[2..]
           } else {
             &format!(
               "{}{}",
@@ -55,14 +56,24 @@ pub(crate) fn stylex_define_vars(
             )
           };
 
+          let value = match light_dark_value(value) {
+            Some(light_dark) if state.options.light_dark_vars => Box::new(light_dark),
+            _ => value.clone(),
+          };
+
           let (css_value, css_type) = get_css_value(KeyValueProp {
             key: PropName::Str(key.clone().into()),
-            value: value.clone(),
+            value,
           });
 
           FlatCompiledStylesValue::Tuple(name_hash.to_string(), css_value, css_type)
         }
-        _ => unimplemented!(),
+        FlatCompiledStylesValue::String(_)
+        | FlatCompiledStylesValue::KeyValue(_)
+        | FlatCompiledStylesValue::Null
+        | FlatCompiledStylesValue::IncludedStyle(_)
+        | FlatCompiledStylesValue::Bool(_)
+        | FlatCompiledStylesValue::CSSType(..) => unreachable!("Unsupported value type"),
       };
 
       Box::new(reuslt)
@@ -79,13 +90,21 @@ pub(crate) fn stylex_define_vars(
       FlatCompiledStylesValue::Tuple(key, _, _) => {
         Box::new(FlatCompiledStylesValue::String(format!("var(--{})", key)))
       }
-
-      _ => unreachable!("Unsupported value type"),
+      FlatCompiledStylesValue::String(_)
+      | FlatCompiledStylesValue::KeyValue(_)
+      | FlatCompiledStylesValue::Null
+      | FlatCompiledStylesValue::IncludedStyle(_)
+      | FlatCompiledStylesValue::Bool(_)
+      | FlatCompiledStylesValue::CSSType(..) => unreachable!("Unsupported value type"),
     },
   );
 
-  let injectable_styles =
-    construct_css_variables_string(&variables_map, &theme_name_hash, &mut typed_variables);
+  let injectable_styles = construct_css_variables_string(
+    &variables_map,
+    &theme_name_hash,
+    &mut typed_variables,
+    state.get_shadow_host().as_deref(),
+  );
 
   let injectable_types = obj_map(
     ObjMapType::Map(typed_variables),
@@ -103,7 +122,13 @@ pub(crate) fn stylex_define_vars(
             ..Default::default()
           })
         }
-        _ => unreachable!("Unsupported value type"),
+        FlatCompiledStylesValue::String(_)
+        | FlatCompiledStylesValue::KeyValue(_)
+        | FlatCompiledStylesValue::Null
+        | FlatCompiledStylesValue::IncludedStyle(_)
+        | FlatCompiledStylesValue::InjectableStyle(_)
+        | FlatCompiledStylesValue::Bool(_)
+        | FlatCompiledStylesValue::Tuple(..) => unreachable!("Unsupported value type"),
       };
 
       Box::new(result)
@@ -12,7 +12,7 @@ fn is_var(arg: &Expr) -> bool {
   re.is_match(&str_arg)
 }
 
-pub(crate) fn stylex_first_that_works(args: Vec<Expr>) -> Expr {
+pub fn stylex_first_that_works(args: Vec<Expr>) -> Expr {
   let first_var = args.iter().position(is_var);
 
   match first_var {
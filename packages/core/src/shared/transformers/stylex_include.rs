@@ -14,7 +14,7 @@ fn uuid() -> String {
   format!("__included_{}__", number)
 }
 
-pub(crate) fn stylex_include(args: Vec<Expr>) -> Expr {
+pub fn stylex_include(args: Vec<Expr>) -> Expr {
   if args.len() > 1 {
     panic!("{}", ILLEGAL_ARGUMENT_LENGTH);
   }
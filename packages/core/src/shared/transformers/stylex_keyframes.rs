@@ -0,0 +1,158 @@
+use indexmap::IndexMap;
+use swc_core::ecma::ast::Expr;
+
+use crate::shared::{
+  enums::data_structures::evaluate_result_value::EvaluateResultValue,
+  structures::{
+    functions::{FunctionConfig, FunctionMap, FunctionType},
+    injectable_style::InjectableStyle,
+    order_pair::OrderPair,
+    pair::Pair,
+    pre_rule::PreRuleValue,
+    state_manager::StateManager,
+  },
+  utils::{
+    ast::convertors::{expr_to_str, string_to_expression},
+    common::{create_hash, dashify, get_key_str, get_key_values_from_object},
+    core::flat_map_expanded_shorthands::flat_map_expanded_shorthands,
+    css::common::{generate_ltr, generate_rtl, transform_value},
+    object::{obj_entries, obj_from_entries},
+  },
+};
+
+pub fn stylex_keyframes(
+  frames: &EvaluateResultValue,
+  state: &mut StateManager,
+) -> (String, InjectableStyle) {
+  let mut class_name_prefix = state.options.class_name_prefix.clone();
+
+  if class_name_prefix.is_empty() {
+    class_name_prefix = "x".to_string();
+  }
+
+  let Some(frames) = frames.as_expr().and_then(|expr| expr.as_object()) else {
+    panic!("Values must be an object")
+  };
+
+  // Declaration order does not change the animation, so it can be left out of
+  // the hash. Pinned releases always hash it.
+  let canonical = state.options.canonical_keyframes && state.options.compat_version.is_none();
+
+  let extended_object = get_key_values_from_object(frames)
+    .iter()
+    .map(|frame| {
+      let mut declarations = expand_frame_shorthands(&frame.value, state)
+        .into_iter()
+        .map(|(key, value)| {
+          let key = dashify(&key);
+          let value = transform_value(key.as_str(), value.as_str(), state);
+
+          Pair::new(key, value)
+        })
+        .collect::<Vec<Pair>>();
+
+      if canonical {
+        declarations.sort_by(|a, b| a.key.cmp(&b.key));
+      }
+
+      (get_key_str(frame), declarations)
+    })
+    .collect::<IndexMap<String, Vec<Pair>>>();
+
+  let ltr_styles = map_declarations(&extended_object, generate_ltr);
+  let rtl_styles = map_declarations(&extended_object, |pair| {
+    generate_rtl(pair).unwrap_or(pair.clone())
+  });
+
+  let ltr_string = construct_keyframes_obj(&ltr_styles);
+  let rtl_string = construct_keyframes_obj(&rtl_styles);
+
+  let animation_name = format!(
+    "{}{}-B",
+    class_name_prefix,
+    create_hash(&format!("<>{}", ltr_string))
+  );
+
+  let ltr = format!("@keyframes {}{{{}}}", animation_name, ltr_string);
+  let rtl = if ltr_string == rtl_string {
+    None
+  } else {
+    Some(format!("@keyframes {}{{{}}}", animation_name, rtl_string))
+  };
+
+  (
+    animation_name,
+    InjectableStyle {
+      ltr,
+      rtl,
+      priority: Some(1.0),
+    },
+  )
+}
+
+fn map_declarations(
+  frames: &IndexMap<String, Vec<Pair>>,
+  mapper: impl Fn(&Pair) -> Pair,
+) -> IndexMap<String, Vec<Pair>> {
+  frames
+    .iter()
+    .map(|(key, declarations)| (key.clone(), declarations.iter().map(&mapper).collect()))
+    .collect()
+}
+
+fn construct_keyframes_obj(frames: &IndexMap<String, Vec<Pair>>) -> String {
+  frames
+    .iter()
+    .map(|(key, declarations)| {
+      let declarations = declarations
+        .iter()
+        .filter(|Pair { key, value }| !key.is_empty() && !value.is_empty())
+        .map(|Pair { key, value }| format!("{}:{};", key, value))
+        .collect::<Vec<String>>()
+        .join("");
+
+      format!("{}{{{}}}", key, declarations)
+    })
+    .collect::<Vec<String>>()
+    .join("")
+}
+
+fn expand_frame_shorthands(frame: &Expr, state: &mut StateManager) -> IndexMap<String, String> {
+  let res: Vec<_> = obj_entries(&frame.clone())
+    .iter()
+    .flat_map(|pair| {
+      let key = get_key_str(pair);
+      let value = expr_to_str(pair.value.as_ref(), state, &FunctionMap::default());
+
+      flat_map_expanded_shorthands((key, PreRuleValue::String(value)), &state.options)
+        .into_iter()
+        .filter_map(|pair| {
+          pair.1.as_ref()?;
+
+          Some(pair)
+        })
+        .collect::<Vec<OrderPair>>()
+    })
+    .filter(|item| item.1.is_some())
+    .collect::<Vec<OrderPair>>();
+
+  obj_from_entries(&res)
+}
+
+pub fn get_keyframes_fn() -> FunctionConfig {
+  FunctionConfig {
+    fn_ptr: FunctionType::StylexExprFn(|expr: Expr, local_state: &mut StateManager| -> Expr {
+      let (animation_name, injected_style) =
+        stylex_keyframes(&EvaluateResultValue::Expr(Box::new(expr)), local_state);
+
+      local_state
+        .injected_keyframes
+        .insert(animation_name.clone(), Box::new(injected_style));
+
+      let result = string_to_expression(animation_name.as_str());
+
+      result
+    }),
+    takes_path: false,
+  }
+}
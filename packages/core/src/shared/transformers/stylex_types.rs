@@ -440,7 +440,7 @@ fn url(value: ValueWithDefault) -> Expr {
   base_css_type.into()
 }
 
-pub(crate) static FN_MAP: phf::Map<&'static str, fn(value: ValueWithDefault) -> Expr> = phf_map! {
+pub static FN_MAP: phf::Map<&'static str, fn(value: ValueWithDefault) -> Expr> = phf_map! {
   "angle" => angle,
   "color" => color,
   "image" => image,
@@ -457,7 +457,7 @@ pub(crate) static FN_MAP: phf::Map<&'static str, fn(value: ValueWithDefault) ->
 
 };
 
-pub(crate) fn get_types_fn() -> FunctionConfig {
+pub fn get_types_fn() -> FunctionConfig {
   FunctionConfig {
     fn_ptr: FunctionType::StylexFnsFactory(
       |prop_name| -> Rc<dyn Fn(ValueWithDefault) -> Expr + 'static> {
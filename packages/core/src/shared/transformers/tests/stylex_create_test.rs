@@ -9,7 +9,10 @@ mod stylex_create {
       flat_compiled_styles_value::FlatCompiledStylesValue,
     },
     structures::{
-      functions::FunctionMap, injectable_style::InjectableStyle, state_manager::StateManager,
+      exported_styles::{ExportedDeclaration, ExportedValue},
+      functions::FunctionMap,
+      injectable_style::InjectableStyle,
+      state_manager::StateManager,
       types::FlatCompiledStyles,
     },
     transformers::stylex_create::stylex_create_set,
@@ -158,11 +161,13 @@ mod stylex_create {
     IndexMap<String, Box<FlatCompiledStyles>>,
     IndexMap<String, Box<InjectableStyle>>,
   ) {
-    stylex_create_set(
+    let (resolved_namespaces, injected_styles, _) = stylex_create_set(
       &EvaluateResultValue::Map(style_object),
       &mut StateManager::default(),
       &FunctionMap::default(),
-    )
+    );
+
+    (resolved_namespaces, injected_styles)
   }
 
   #[test]
@@ -609,4 +614,60 @@ mod stylex_create {
     assert_eq!(resolved_namespaces, expected_resolved_namespaces);
     assert_eq!(injected_styles, expected_injected_styles);
   }
+
+  #[test]
+  fn exports_resolved_declarations() {
+    let object = style_nested_object_factory(&[(
+      "default",
+      &[(":hover", &[("backgroundColor", "red"), ("color", "blue")])],
+    )]);
+
+    let (_, _, exported_styles) = stylex_create_set(
+      &EvaluateResultValue::Map(object),
+      &mut StateManager::default(),
+      &FunctionMap::default(),
+    );
+
+    assert_eq!(
+      exported_styles.get("default"),
+      Some(&vec![
+        ExportedDeclaration {
+          property: "backgroundColor".to_string(),
+          value: Some(ExportedValue::Single("red".to_string())),
+          pseudos: vec![":hover".to_string()],
+          at_rules: vec![],
+        },
+        ExportedDeclaration {
+          property: "color".to_string(),
+          value: Some(ExportedValue::Single("blue".to_string())),
+          pseudos: vec![":hover".to_string()],
+          at_rules: vec![],
+        },
+      ])
+    );
+  }
+
+  #[test]
+  fn exports_fallback_declarations() {
+    let object = style_array_object_factory(&[("default", &[("position", &["sticky", "fixed"])])]);
+
+    let (_, _, exported_styles) = stylex_create_set(
+      &EvaluateResultValue::Map(object),
+      &mut StateManager::default(),
+      &FunctionMap::default(),
+    );
+
+    assert_eq!(
+      exported_styles.get("default"),
+      Some(&vec![ExportedDeclaration {
+        property: "position".to_string(),
+        value: Some(ExportedValue::Fallbacks(vec![
+          "sticky".to_string(),
+          "fixed".to_string()
+        ])),
+        pseudos: vec![],
+        at_rules: vec![],
+      }])
+    );
+  }
 }
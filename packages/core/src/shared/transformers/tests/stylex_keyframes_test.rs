@@ -5,7 +5,10 @@ mod stylex_keyframes {
 
   use crate::shared::{
     enums::data_structures::evaluate_result_value::EvaluateResultValue,
-    structures::{injectable_style::InjectableStyle, state_manager::StateManager},
+    structures::{
+      compat_version::CompatVersion, injectable_style::InjectableStyle,
+      state_manager::StateManager, stylex_options::StyleXOptions,
+    },
     transformers::stylex_keyframes::stylex_keyframes,
     utils::ast::{
       convertors::string_to_expression,
@@ -87,4 +90,73 @@ mod stylex_keyframes {
 
     assert_eq!(result, *expected_result.get(key.as_str()).unwrap())
   }
+
+  #[test]
+  fn keeps_every_declaration_of_a_frame() {
+    let keyframes = default_vars_factory(&[
+      ("from", &[("opacity", "0"), ("color", "red")]),
+      ("to", &[("opacity", "1"), ("color", "blue")]),
+    ]);
+
+    let (_, result) = stylex_keyframes(&keyframes, &mut StateManager::default());
+
+    assert_eq!(
+      result.ltr,
+      "@keyframes x9reznu-B{from{opacity:0;color:red;}to{opacity:1;color:blue;}}"
+    );
+  }
+
+  fn canonical_keyframes_state(compat_version: Option<CompatVersion>) -> StateManager {
+    let mut state = StateManager::new(StyleXOptions {
+      canonical_keyframes: true,
+      ..StyleXOptions::default()
+    });
+
+    state.options.compat_version = compat_version;
+
+    state
+  }
+
+  #[test]
+  fn hashes_canonical_keyframes_independent_of_declaration_order() {
+    let keyframes = default_vars_factory(&[
+      ("from", &[("opacity", "0"), ("color", "red")]),
+      ("to", &[("opacity", "1"), ("color", "blue")]),
+    ]);
+    let reordered_keyframes = default_vars_factory(&[
+      ("from", &[("color", "red"), ("opacity", "0")]),
+      ("to", &[("color", "blue"), ("opacity", "1")]),
+    ]);
+
+    let (key, result) = stylex_keyframes(&keyframes, &mut canonical_keyframes_state(None));
+    let (reordered_key, reordered_result) =
+      stylex_keyframes(&reordered_keyframes, &mut canonical_keyframes_state(None));
+
+    assert_eq!(key, reordered_key);
+    assert_eq!(result, reordered_result);
+    assert_eq!(
+      result.ltr,
+      format!(
+        "@keyframes {}{{from{{color:red;opacity:0;}}to{{color:blue;opacity:1;}}}}",
+        key
+      )
+    );
+  }
+
+  #[test]
+  fn keeps_declaration_order_with_a_compat_version() {
+    let keyframes = default_vars_factory(&[("from", &[("opacity", "0"), ("color", "red")])]);
+    let reordered_keyframes =
+      default_vars_factory(&[("from", &[("color", "red"), ("opacity", "0")])]);
+
+    let compat_version = Some(CompatVersion::new(0, 5, 0));
+
+    let (key, _) = stylex_keyframes(&keyframes, &mut canonical_keyframes_state(compat_version));
+    let (reordered_key, _) = stylex_keyframes(
+      &reordered_keyframes,
+      &mut canonical_keyframes_state(compat_version),
+    );
+
+    assert_ne!(key, reordered_key);
+  }
 }
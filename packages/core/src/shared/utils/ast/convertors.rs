@@ -8,15 +8,20 @@ use swc_core::{
 };
 
 use crate::shared::{
-  constants::messages::{ILLEGAL_PROP_VALUE, NON_STATIC_VALUE},
-  enums::misc::VarDeclAction,
+  constants::messages::{
+    ILLEGAL_PROP_VALUE, INCOMPATIBLE_QUANTITY_UNITS, NON_STATIC_VALUE, QUANTITY_AS_NUMBER,
+  },
+  enums::{data_structures::evaluate_result_value::EvaluateResultValue, misc::VarDeclAction},
   regex::IDENT_PROP_REGEX,
-  structures::{functions::FunctionMap, state::EvaluationState, state_manager::StateManager},
+  structures::{
+    functions::FunctionMap, quantity::Quantity, state::EvaluationState, state_manager::StateManager,
+  },
   utils::{
     common::{
-      evaluate_bin_expr, get_expr_from_var_decl, get_string_val_from_lit, get_var_decl_by_ident,
+      evaluate_bin_expr, get_expr_from_var_decl, get_global_number, get_string_val_from_lit,
+      get_var_decl_by_ident,
     },
-    js::evaluate::evaluate_cached,
+    js::evaluate::{deopt, evaluate_cached},
   },
 };
 
@@ -82,12 +87,51 @@ pub fn unari_to_num(unary_expr: &UnaryExpr, state: &mut StateManager, fns: &Func
   }
 }
 
+/// Evaluates a binary expression to a number, or to a string for arithmetic
+/// on quantities with units like `'8px' * 2` or on var references like
+/// `spacing.md + 4`. Comparisons result in `1` or `0`.
+pub fn binary_expr_to_value(
+  binary_expr: &BinExpr,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Option<Expr> {
+  let (left, right) = evaluate_binary_operands(binary_expr, state, fns)?;
+
+  if let Some(quantity) = binary_operands_to_quantity(binary_expr.op, &left, &right) {
+    // Quotients of the same unit are plain numbers
+    if quantity.is_unitless() {
+      return Some(number_to_expression(quantity.value));
+    }
+
+    return Some(string_to_expression(quantity.to_string().as_str()));
+  }
+
+  if let Some(calc) = binary_operands_to_calc(binary_expr.op, &left, &right) {
+    return Some(string_to_expression(calc.as_str()));
+  }
+
+  if let Some(equal) = binary_operands_to_equality(binary_expr.op, &left, &right) {
+    return Some(number_to_expression(if equal { 1.0 } else { 0.0 }));
+  }
+
+  binary_operands_to_num(binary_expr, left, right, state, fns).map(number_to_expression)
+}
+
 pub fn binary_expr_to_num(
   binary_expr: &BinExpr,
   state: &mut EvaluationState,
   fns: &FunctionMap,
 ) -> Option<f64> {
-  let op = binary_expr.op;
+  let (left, right) = evaluate_binary_operands(binary_expr, state, fns)?;
+
+  binary_operands_to_num(binary_expr, left, right, state, fns)
+}
+
+fn evaluate_binary_operands(
+  binary_expr: &BinExpr,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Option<(Box<EvaluateResultValue>, Box<EvaluateResultValue>)> {
   let Some(left) = evaluate_cached(&binary_expr.left, state, fns) else {
     if !state.confident {
       return None;
@@ -104,6 +148,166 @@ pub fn binary_expr_to_num(
     panic!("Right expression is not a number")
   };
 
+  Some((left, right))
+}
+
+/// Returns `None` unless one of the operands is a quantity with a unit and
+/// the other a quantity or number.
+fn binary_operands_to_quantity(
+  op: BinaryOp,
+  left: &EvaluateResultValue,
+  right: &EvaluateResultValue,
+) -> Option<Quantity> {
+  let as_quantity = |value: &EvaluateResultValue| match value.as_expr()? {
+    Expr::Lit(Lit::Num(num)) => Some(Quantity::new(num.value, "")),
+    Expr::Lit(Lit::Str(str)) => str.value.parse::<Quantity>().ok(),
+    _ => None,
+  };
+
+  let left = as_quantity(left)?;
+  let right = as_quantity(right)?;
+
+  if left.is_unitless() && right.is_unitless() {
+    return None;
+  }
+
+  match left.apply(op, &right) {
+    Some(result) => Some(result),
+    None => panic!(
+      r#"{} "{}" {} "{}""#,
+      INCOMPATIBLE_QUANTITY_UNITS, left, op, right
+    ),
+  }
+}
+
+/// Returns a `calc()` of the operands when one of them is a `var()` or
+/// `calc()` that can't be computed statically, e.g. `calc(var(--x) + 4)`.
+///
+/// Plain numbers stay unitless, the property's unit is added to them when
+/// the value is transformed.
+fn binary_operands_to_calc(
+  op: BinaryOp,
+  left: &EvaluateResultValue,
+  right: &EvaluateResultValue,
+) -> Option<String> {
+  if !matches!(
+    op,
+    BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div
+  ) {
+    return None;
+  }
+
+  let as_calc_term = |value: &EvaluateResultValue| match value.as_expr()? {
+    Expr::Lit(Lit::Num(num)) => Some((Quantity::new(num.value, "").to_string(), false)),
+    Expr::Lit(Lit::Str(str)) => {
+      let value = str.value.trim();
+
+      if let Some(inner) = value
+        .strip_prefix("calc(")
+        .and_then(|value| value.strip_suffix(')'))
+      {
+        Some((format!("({})", inner), true))
+      } else if value.starts_with("var(") && value.ends_with(')') {
+        Some((value.to_string(), true))
+      } else {
+        value
+          .parse::<Quantity>()
+          .ok()
+          .map(|quantity| (quantity.to_string(), false))
+      }
+    }
+    _ => None,
+  };
+
+  let (left, is_left_symbolic) = as_calc_term(left)?;
+  let (right, is_right_symbolic) = as_calc_term(right)?;
+
+  if !is_left_symbolic && !is_right_symbolic {
+    return None;
+  }
+
+  Some(format!("calc({} {} {})", left, op, right))
+}
+
+/// Compares strings, `null` and `undefined` by value for the equality
+/// operators, e.g. `process.env.NODE_ENV === 'production'`. Returns `None`
+/// when both operands are numeric, which are compared as numbers instead.
+fn binary_operands_to_equality(
+  op: BinaryOp,
+  left: &EvaluateResultValue,
+  right: &EvaluateResultValue,
+) -> Option<bool> {
+  let is_strict = match op {
+    BinaryOp::EqEqEq | BinaryOp::NotEqEq => true,
+    BinaryOp::EqEq | BinaryOp::NotEq => false,
+    _ => return None,
+  };
+
+  let as_operand = |value: &EvaluateResultValue| match value.as_expr()? {
+    Expr::Lit(Lit::Str(str)) => Some(EqualityOperand::Str(str.value.to_string())),
+    Expr::Lit(Lit::Null(_)) => Some(EqualityOperand::Null),
+    Expr::Ident(ident) if ident.sym == "undefined" => Some(EqualityOperand::Undefined),
+    Expr::Lit(Lit::Num(num)) => Some(EqualityOperand::Num(num.value)),
+    _ => None,
+  };
+
+  let left = as_operand(left)?;
+  let right = as_operand(right)?;
+
+  let equal = match (&left, &right) {
+    (EqualityOperand::Num(_), EqualityOperand::Num(_)) => return None,
+    (EqualityOperand::Str(left), EqualityOperand::Str(right)) => {
+      if left.parse::<f64>().is_ok() && right.parse::<f64>().is_ok() {
+        return None;
+      }
+
+      left == right
+    }
+    (
+      EqualityOperand::Null | EqualityOperand::Undefined,
+      EqualityOperand::Null | EqualityOperand::Undefined,
+    ) => !is_strict || left == right,
+    (EqualityOperand::Str(str), EqualityOperand::Num(_))
+    | (EqualityOperand::Num(_), EqualityOperand::Str(str))
+      if str.parse::<f64>().is_ok() =>
+    {
+      return None;
+    }
+    _ => false,
+  };
+
+  Some(equal == matches!(op, BinaryOp::EqEqEq | BinaryOp::EqEq))
+}
+
+#[derive(PartialEq)]
+enum EqualityOperand {
+  Str(String),
+  Num(f64),
+  Null,
+  Undefined,
+}
+
+fn binary_operands_to_num(
+  binary_expr: &BinExpr,
+  left: Box<EvaluateResultValue>,
+  right: Box<EvaluateResultValue>,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Option<f64> {
+  let op = binary_expr.op;
+
+  let is_not_numeric = |value: &EvaluateResultValue| {
+    value
+      .as_expr()
+      .is_some_and(|expr| !is_numeric_operand(expr))
+  };
+
+  if is_not_numeric(&left) || is_not_numeric(&right) {
+    deopt(&Expr::Bin(binary_expr.clone()), state);
+
+    return None;
+  }
+
   let result = match &op {
     BinaryOp::Add => {
       expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
@@ -344,6 +548,16 @@ pub fn binary_expr_to_num(
   Some(result)
 }
 
+/// Whether `expr_to_num` can coerce the expression without panicking.
+fn is_numeric_operand(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Num(_)) | Expr::Lit(Lit::Bool(_)) => true,
+    Expr::Lit(Lit::Str(str)) => str.value.parse::<f64>().is_ok(),
+    Expr::Ident(_) | Expr::Unary(_) | Expr::Bin(_) => true,
+    _ => false,
+  }
+}
+
 pub fn ident_to_number(ident: &Ident, traveral_state: &mut StateManager, fns: &FunctionMap) -> f64 {
   let var_decl = get_var_decl_by_ident(ident, traveral_state, fns, VarDeclAction::Reduce);
 
@@ -363,7 +577,10 @@ pub fn ident_to_number(ident: &Ident, traveral_state: &mut StateManager, fns: &F
         _ => panic!("Varable {:?} is not a number", var_decl_expr),
       }
     }
-    None => panic!("Variable {} is not declared", ident.sym),
+    // `undefined` is `NaN` in arithmetic
+    None if ident.sym == "undefined" => f64::NAN,
+    None => get_global_number(&ident.sym)
+      .unwrap_or_else(|| panic!("Variable {} is not declared", ident.sym)),
   }
 }
 
@@ -377,13 +594,11 @@ pub fn lit_to_num(lit_num: &Lit) -> f64 {
       }
     }
     Lit::Num(num) => num.value,
-    Lit::Str(str) => {
-      let Result::Ok(num) = str.value.parse::<f64>() else {
-        panic!("Value in not a number");
-      };
-
-      num
-    }
+    Lit::Str(str) => match str.value.parse::<Quantity>() {
+      Ok(quantity) if quantity.is_unitless() => quantity.value,
+      Ok(_) => panic!(r#"{} "{}""#, QUANTITY_AS_NUMBER, str.value),
+      Err(_) => panic!("Value in not a number"),
+    },
     _ => {
       panic!("Value in not a number");
     }
@@ -490,7 +705,7 @@ pub fn number_to_expression(value: f64) -> Expr {
   Expr::from(lit_number_factory(value))
 }
 
-pub(crate) fn big_int_to_expression(value: BigInt) -> Expr {
+pub fn big_int_to_expression(value: BigInt) -> Expr {
   Expr::from(lit_big_int_factory(value))
 }
 
@@ -498,7 +713,7 @@ pub fn string_to_expression(value: &str) -> Expr {
   Expr::Lit(lit_str_factory(value))
 }
 
-pub(crate) fn bool_to_expression(value: bool) -> Expr {
+pub fn bool_to_expression(value: bool) -> Expr {
   Expr::Lit(lit_boolean_factory(value))
 }
 
@@ -506,11 +721,11 @@ pub fn ident_to_expression(value: &str) -> Expr {
   Expr::Ident(ident_factory(value))
 }
 
-pub(crate) fn null_to_expression() -> Expr {
+pub fn null_to_expression() -> Expr {
   Expr::Lit(lit_null_factory())
 }
 
-pub(crate) fn string_to_prop_name(value: &str) -> Option<PropName> {
+pub fn string_to_prop_name(value: &str) -> Option<PropName> {
   if IDENT_PROP_REGEX.is_match(value) && value.parse::<i64>().is_err() {
     Some(PropName::Ident(ident_name_factory(value)))
   } else {
@@ -522,7 +737,7 @@ pub(crate) fn string_to_prop_name(value: &str) -> Option<PropName> {
   }
 }
 
-pub(crate) fn transform_shorthand_to_key_values(prop: &mut Box<Prop>) {
+pub fn transform_shorthand_to_key_values(prop: &mut Box<Prop>) {
   if let Some(ident) = prop.as_shorthand() {
     *prop = Box::new(Prop::from(KeyValueProp {
       key: PropName::Ident(ident_name_factory(&ident.sym)),
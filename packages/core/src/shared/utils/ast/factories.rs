@@ -4,18 +4,20 @@ use swc_core::{
   ecma::ast::{ArrayLit, Expr, ExprOrSpread, ObjectLit, PropOrSpread},
 };
 
+use crate::shared::utils::swc_compat::{ident, ident_name};
+
 use super::convertors::{
   bool_to_expression, number_to_expression, string_to_expression, string_to_prop_name,
 };
 
-pub(crate) fn object_lit_factory(props: Vec<PropOrSpread>) -> ObjectLit {
+pub fn object_lit_factory(props: Vec<PropOrSpread>) -> ObjectLit {
   ObjectLit {
     span: DUMMY_SP,
     props,
   }
 }
 
-pub(crate) fn array_lit_factory(elems: Vec<Option<ExprOrSpread>>) -> ArrayLit {
+pub fn array_lit_factory(elems: Vec<Option<ExprOrSpread>>) -> ArrayLit {
   ArrayLit {
     span: DUMMY_SP,
     elems,
@@ -37,43 +39,41 @@ pub fn prop_or_spread_expression_factory(key: &str, value: Expr) -> PropOrSpread
   }))
 }
 
-pub(crate) fn binding_ident_factory(ident: Ident) -> BindingIdent {
+pub fn binding_ident_factory(ident: Ident) -> BindingIdent {
   BindingIdent::from(ident)
 }
 
-pub(crate) fn lit_str_factory(value: &str) -> Lit {
+pub fn lit_str_factory(value: &str) -> Lit {
   Lit::from(value)
 }
 
-pub(crate) fn lit_number_factory(value: f64) -> Lit {
+pub fn lit_number_factory(value: f64) -> Lit {
   Lit::from(value)
 }
 
-pub(crate) fn lit_big_int_factory(value: BigInt) -> Lit {
+pub fn lit_big_int_factory(value: BigInt) -> Lit {
   Lit::from(value)
 }
 
-pub(crate) fn lit_boolean_factory(value: bool) -> Lit {
+pub fn lit_boolean_factory(value: bool) -> Lit {
   Lit::from(value)
 }
 
-pub(crate) fn lit_null_factory() -> Lit {
+pub fn lit_null_factory() -> Lit {
   Lit::Null(Null { span: DUMMY_SP })
 }
 
-pub(crate) fn ident_factory(name: &str) -> Ident {
-  Ident::from(name)
+pub fn ident_factory(name: &str) -> Ident {
+  ident(name)
 }
 
-pub(crate) fn ident_name_factory(name: &str) -> Ident {
-  ident_factory(name)
-  // TODO: Uncomment this line to use after migration to swc_core >= 0.99.*
-  // IdentName::from(name)
+pub fn ident_name_factory(name: &str) -> Ident {
+  ident_name(name)
 }
 
 // NOTE: Tests only using this function
 #[allow(dead_code)]
-pub(crate) fn prop_or_spread_expr_factory(key: &str, values: Vec<PropOrSpread>) -> PropOrSpread {
+pub fn prop_or_spread_expr_factory(key: &str, values: Vec<PropOrSpread>) -> PropOrSpread {
   let object = ObjectLit {
     span: DUMMY_SP,
     props: values,
@@ -84,12 +84,12 @@ pub(crate) fn prop_or_spread_expr_factory(key: &str, values: Vec<PropOrSpread>)
 
 pub fn key_value_factory(key: &str, value: Expr) -> KeyValueProp {
   KeyValueProp {
-    key: PropName::Ident(Ident::new(key.into(), DUMMY_SP)),
+    key: PropName::Ident(ident_name(key)),
     value: Box::new(value),
   }
 }
 
-pub(crate) fn prop_or_spread_string_factory(key: &str, value: &str) -> PropOrSpread {
+pub fn prop_or_spread_string_factory(key: &str, value: &str) -> PropOrSpread {
   let value = string_to_expression(value);
 
   prop_or_spread_expression_factory(key, value)
@@ -97,7 +97,7 @@ pub(crate) fn prop_or_spread_string_factory(key: &str, value: &str) -> PropOrSpr
 
 // NOTE: Tests only using this function
 #[allow(dead_code)]
-pub(crate) fn prop_or_spread_array_string_factory(key: &str, value: &[&str]) -> PropOrSpread {
+pub fn prop_or_spread_array_string_factory(key: &str, value: &[&str]) -> PropOrSpread {
   let array = ArrayLit {
     span: DUMMY_SP,
     elems: value
@@ -109,20 +109,20 @@ pub(crate) fn prop_or_spread_array_string_factory(key: &str, value: &[&str]) ->
   prop_or_spread_expression_factory(key, Expr::from(array))
 }
 
-pub(crate) fn _prop_or_spread_boolean_factory(key: &str, value: Option<bool>) -> PropOrSpread {
+pub fn _prop_or_spread_boolean_factory(key: &str, value: Option<bool>) -> PropOrSpread {
   match value {
     Some(value) => prop_or_spread_expression_factory(key, bool_to_expression(value)),
     None => panic!("Value is not a boolean"),
   }
 }
 
-pub(crate) fn expr_or_spread_string_expression_factory(value: &str) -> ExprOrSpread {
+pub fn expr_or_spread_string_expression_factory(value: &str) -> ExprOrSpread {
   let expr = Box::new(string_to_expression(value));
 
   ExprOrSpread { expr, spread: None }
 }
 
-pub(crate) fn expr_or_spread_number_expression_factory(value: f64) -> ExprOrSpread {
+pub fn expr_or_spread_number_expression_factory(value: f64) -> ExprOrSpread {
   let expr = Box::new(number_to_expression(value));
 
   ExprOrSpread { expr, spread: None }
@@ -130,11 +130,11 @@ pub(crate) fn expr_or_spread_number_expression_factory(value: f64) -> ExprOrSpre
 
 // NOTE: Tests only using this function
 #[allow(dead_code)]
-pub(crate) fn create_array(values: &[Expr]) -> ArrayLit {
+pub fn create_array(values: &[Expr]) -> ArrayLit {
   array_fabric(values, None)
 }
 
-pub(crate) fn _create_spreaded_array(values: &[Expr]) -> ArrayLit {
+pub fn _create_spreaded_array(values: &[Expr]) -> ArrayLit {
   array_fabric(values, Some(DUMMY_SP))
 }
 
@@ -0,0 +1,1194 @@
+use std::{
+  any::type_name,
+  collections::{HashSet, VecDeque},
+  hash::{DefaultHasher, Hash, Hasher},
+  ops::Deref,
+  path::{Path, PathBuf},
+};
+use stylex_path_resolver::utils::normalize_path_separators;
+use swc_core::{
+  atoms::Atom,
+  common::{util::take::Take, FileName, DUMMY_SP},
+  ecma::{
+    ast::{
+      ArrowExpr, BinaryOp, BlockStmtOrExpr, CallExpr, Callee, Decl, Expr, FnExpr, Function, Id,
+      Ident, ImportDecl, ImportSpecifier, KeyValueProp, Lit, MemberExpr, MemberProp, Module,
+      ModuleDecl, ModuleExportName, ModuleItem, ObjectLit, ObjectPatProp, ParenExpr, Pat, Prop,
+      PropName, PropOrSpread, ReturnStmt, Stmt, TsAsExpr, TsConstAssertion, TsNonNullExpr,
+      TsSatisfiesExpr, TsTypeAssertion, UnaryOp, VarDecl, VarDeclarator,
+    },
+    utils::find_pat_ids,
+    visit::{Visit, VisitMut, VisitMutWith, VisitWith},
+  },
+};
+
+use crate::shared::{
+  constants::{
+    common::{DEFAULT_EXPORT_NAME, ISSUES_URL},
+    messages::{ILLEGAL_PROP_VALUE, NON_FINITE_NUMBER, NON_STATIC_SPREAD},
+  },
+  enums::{
+    core::ModuleCycle,
+    data_structures::top_level_expression::{TopLevelExpression, TopLevelExpressionKind},
+    misc::VarDeclAction,
+  },
+  regex::DASHIFY_REGEX,
+  structures::{
+    base_css_type::BaseCSSType,
+    functions::{FunctionConfigType, FunctionMap, FunctionType},
+    state_manager::StateManager,
+  },
+};
+
+pub use crate::hash::{create_hash, gen_file_based_identifier, scope_hash_input};
+
+use super::ast::{
+  convertors::transform_shorthand_to_key_values,
+  factories::{binding_ident_factory, key_value_factory},
+};
+
+pub fn extract_filename_from_path(path: &FileName) -> String {
+  match path {
+    FileName::Real(path_buf) => Path::new(&normalize_path_separators(path_buf.to_str().unwrap()))
+      .file_stem()
+      .unwrap()
+      .to_str()
+      .unwrap()
+      .to_string(),
+    _ => "UnknownFile".to_string(),
+  }
+}
+
+pub fn extract_path(path: &FileName) -> String {
+  match path {
+    FileName::Real(path_buf) => normalize_path_separators(path_buf.to_str().unwrap()),
+    _ => "UnknownFile".to_string(),
+  }
+}
+
+pub fn extract_filename_with_ext_from_path(path: &FileName) -> Option<String> {
+  match path {
+    FileName::Real(path_buf) => Some(
+      Path::new(&normalize_path_separators(path_buf.to_str().unwrap()))
+        .file_name()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string(),
+    ),
+    _ => None,
+  }
+}
+
+pub fn get_string_val_from_lit(value: &Lit) -> Option<String> {
+  match value {
+    Lit::Str(str) => Some(format!("{}", str.value)),
+    Lit::Num(num) => Some(format!("{}", num.value)),
+    Lit::BigInt(big_int) => Some(format!("{}", big_int.value)),
+    _ => None,
+  }
+}
+
+/// Value of the `NaN` and `Infinity` globals.
+pub fn get_global_number(name: &str) -> Option<f64> {
+  match name {
+    "NaN" => Some(f64::NAN),
+    "Infinity" => Some(f64::INFINITY),
+    _ => None,
+  }
+}
+
+/// Panics for `NaN` and `Infinity`, which have no CSS representation.
+pub fn assert_finite_number(lit: &Lit) {
+  let Lit::Num(num) = lit else {
+    return;
+  };
+
+  if num.value.is_nan() {
+    panic!("{} Found: NaN", NON_FINITE_NUMBER);
+  }
+
+  if num.value.is_infinite() {
+    let sign = if num.value.is_sign_negative() {
+      "-"
+    } else {
+      ""
+    };
+
+    panic!("{} Found: {}Infinity", NON_FINITE_NUMBER, sign);
+  }
+}
+
+pub fn get_key_str(key_value: &KeyValueProp) -> String {
+  let key = &key_value.key;
+  let mut should_wrap_in_quotes = false;
+
+  let key = match key {
+    PropName::Ident(ident) => &*ident.sym,
+    PropName::Str(str) => {
+      should_wrap_in_quotes = false;
+
+      &*str.value
+    }
+    _ => panic!("Key is not recognized"),
+  };
+
+  wrap_key_in_quotes(key, &should_wrap_in_quotes)
+}
+
+pub fn wrap_key_in_quotes(key: &str, should_wrap_in_quotes: &bool) -> String {
+  if *should_wrap_in_quotes {
+    format!("\"{}\"", key)
+  } else {
+    key.to_string()
+  }
+}
+
+pub fn reduce_ident_count<'a>(state: &'a mut StateManager, ident: &'a Ident) {
+  state.compiled_var_decls.insert(ident.sym.clone());
+
+  *state
+    .var_decl_count_map
+    .entry(ident.sym.clone())
+    .or_insert(0) -= 1;
+}
+
+pub fn increase_member_ident(state: &mut StateManager, member_obj: &MemberExpr) {
+  if let Some(obj_ident) = member_obj.obj.as_ident() {
+    increase_member_ident_count(state, &obj_ident.sym);
+  }
+}
+
+pub fn reduce_member_expression_count(state: &mut StateManager, member_expression: &MemberExpr) {
+  if let Some(obj_ident) = member_expression.obj.as_ident() {
+    reduce_member_ident_count(state, &obj_ident.sym);
+  }
+}
+
+pub fn reduce_member_ident_count(state: &mut StateManager, ident_atom: &Atom) {
+  *state
+    .member_object_ident_count_map
+    .entry(ident_atom.clone())
+    .or_insert(0) -= 1;
+}
+
+pub fn increase_ident_count(state: &mut StateManager, ident: &Ident) {
+  increase_ident_count_by_count(state, ident, 1);
+}
+
+pub fn increase_member_ident_count(state: &mut StateManager, ident_atom: &Atom) {
+  increase_member_ident_count_by_count(state, ident_atom, 1);
+}
+
+pub fn increase_ident_count_by_count(state: &mut StateManager, ident: &Ident, count: i8) {
+  let ident_id = &ident.sym;
+
+  *state
+    .var_decl_count_map
+    .entry(ident_id.clone())
+    .or_insert(0) += count;
+}
+
+pub fn increase_member_ident_count_by_count(
+  state: &mut StateManager,
+  ident_atom: &Atom,
+  count: i8,
+) {
+  *state
+    .member_object_ident_count_map
+    .entry(ident_atom.clone())
+    .or_insert(0) += count;
+}
+
+pub fn get_var_decl_by_ident<'a>(
+  ident: &'a Ident,
+  state: &'a mut StateManager,
+  functions: &'a FunctionMap,
+  action: VarDeclAction,
+) -> Option<VarDeclarator> {
+  match action {
+    VarDeclAction::Increase => increase_ident_count(state, ident),
+    VarDeclAction::Reduce => reduce_ident_count(state, ident),
+    VarDeclAction::None => {}
+  };
+
+  match get_var_decl_from(state, ident) {
+    Some(var_decl) => Some(var_decl.clone()),
+    None => {
+      let func = functions.identifiers.get(&ident.sym);
+
+      match func {
+        Some(func) => match func.as_ref() {
+          FunctionConfigType::Regular(func) => match func.fn_ptr.clone() {
+            FunctionType::Mapper(func) => {
+              let result = func();
+
+              let var_decl = VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::from(binding_ident_factory(ident.clone())),
+                init: Some(Box::new(result)),
+                definite: false,
+              };
+
+              Some(var_decl)
+            }
+            _ => panic!("Function type not supported"),
+          },
+          FunctionConfigType::Map(_) => unimplemented!(),
+        },
+        None => None,
+      }
+    }
+  }
+}
+
+pub fn get_import_by_ident<'a>(
+  ident: &'a Ident,
+  state: &'a mut StateManager,
+) -> Option<ImportDecl> {
+  get_import_from(state, ident).cloned()
+}
+
+pub fn get_var_decl_from<'a>(
+  state: &'a StateManager,
+  ident: &'a Ident,
+) -> Option<&'a VarDeclarator> {
+  state.declarations.iter().find(|var_declarator| {
+    if let Pat::Ident(binding_indent) = &var_declarator.name {
+      return binding_indent.sym == ident.sym;
+    }
+
+    false
+  })
+}
+
+pub fn get_import_from<'a>(
+  state: &'a StateManager,
+  ident: &'a Ident,
+) -> Option<&'a ImportDecl> {
+  state.top_imports.iter().find(|import| {
+    import.specifiers.iter().any(|specifier| match specifier {
+      ImportSpecifier::Named(named_import) => {
+        named_import.local.sym == ident.sym || {
+          if let Some(imported) = &named_import.imported {
+            match imported {
+              ModuleExportName::Ident(export_ident) => export_ident.sym == ident.sym,
+              ModuleExportName::Str(str) => str.value == ident.sym,
+            }
+          } else {
+            false
+          }
+        }
+      }
+      ImportSpecifier::Default(default_import) => default_import.local.sym == ident.sym,
+      ImportSpecifier::Namespace(namespace_import) => namespace_import.local.sym == ident.sym,
+    })
+  })
+}
+
+/// Returns the name `ident` is exported under by the module of `import_decl`,
+/// `default` for default imports.
+pub fn get_imported_name(import_decl: &ImportDecl, ident: &Ident) -> Option<String> {
+  import_decl
+    .specifiers
+    .iter()
+    .find_map(|specifier| match specifier {
+      ImportSpecifier::Named(named_import) if ident.sym == named_import.local.sym => {
+        Some(match &named_import.imported {
+          Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+          Some(ModuleExportName::Str(str)) => str.value.to_string(),
+          None => named_import.local.sym.to_string(),
+        })
+      }
+      ImportSpecifier::Default(default_import) if ident.sym == default_import.local.sym => {
+        Some(DEFAULT_EXPORT_NAME.to_string())
+      }
+      _ => None,
+    })
+}
+
+pub fn get_var_decl_by_ident_or_member<'a>(
+  state: &'a StateManager,
+  ident: &'a Ident,
+) -> Option<&'a VarDeclarator> {
+  state.declarations.iter().find(|var_declarator| {
+    if let Pat::Ident(binding_indent) = &var_declarator.name {
+      if binding_indent.sym == ident.sym {
+        return true;
+      }
+    }
+
+    var_declarator
+      .init
+      .as_ref()
+      .and_then(|init| init.as_call())
+      .and_then(|call| call.callee.as_expr())
+      .and_then(|callee| callee.as_member())
+      .and_then(|member| member.prop.as_ident())
+      .map_or(false, |member_ident| member_ident.sym == ident.sym)
+  })
+}
+
+/// Dotted path of a `stylex.create()` call nested in the object literal of a
+/// top level expression, e.g. `config.styles` for
+/// `const config = { styles: stylex.create({}) }` or `default.styles` for
+/// `export default { styles: stylex.create({}) }`.
+pub fn get_object_wrapper_path(call: &CallExpr, state: &StateManager) -> Option<String> {
+  let call_expr = Expr::from(call.clone());
+
+  state
+    .top_level_expressions
+    .iter()
+    .find_map(|TopLevelExpression(_, expr, name)| {
+      let path = find_object_path(expr.as_object()?, &call_expr)?;
+
+      Some(format!("{}.{}", name.as_ref()?, path))
+    })
+}
+
+fn find_object_path(object: &ObjectLit, target: &Expr) -> Option<String> {
+  object.props.iter().find_map(|prop| {
+    let key_value = prop.as_prop()?.as_key_value()?;
+
+    let key = match &key_value.key {
+      PropName::Ident(ident) => ident.sym.to_string(),
+      PropName::Str(str) => str.value.to_string(),
+      _ => return None,
+    };
+
+    let value = match key_value.value.as_ref() {
+      Expr::Paren(paren) => paren.expr.as_ref(),
+      value => value,
+    };
+
+    if value.eq(target) {
+      return Some(key);
+    }
+
+    let path = find_object_path(value.as_object()?, target)?;
+
+    Some(format!("{}.{}", key, path))
+  })
+}
+
+/// Dotted path of a member chain of static keys, e.g. `config.styles` for
+/// `config.styles` or `config['styles']`.
+pub fn get_member_path(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Ident(ident) => Some(ident.sym.to_string()),
+    Expr::Member(member) => {
+      let prop = match &member.prop {
+        MemberProp::Ident(ident) => ident.sym.to_string(),
+        MemberProp::Computed(computed) => match computed.expr.as_lit() {
+          Some(Lit::Str(str)) => str.value.to_string(),
+          _ => return None,
+        },
+        MemberProp::PrivateName(_) => return None,
+      };
+
+      Some(format!("{}.{}", get_member_path(&member.obj)?, prop))
+    }
+    _ => None,
+  }
+}
+
+/// Identifier a member chain starts from, e.g. `config` for
+/// `config.styles.root`.
+pub fn get_member_root_ident(member: &MemberExpr) -> Option<&Ident> {
+  match member.obj.as_ref() {
+    Expr::Ident(ident) => Some(ident),
+    Expr::Member(member) => get_member_root_ident(member),
+    _ => None,
+  }
+}
+
+pub fn get_expr_from_var_decl(var_decl: &VarDeclarator) -> &Expr {
+  match &var_decl.init {
+    Some(var_decl_init) => unwrap_expr(var_decl_init),
+    None => panic!("Variable declaration is not an expression"),
+  }
+}
+
+pub fn evaluate_bin_expr(op: BinaryOp, left: f64, right: f64) -> f64 {
+  match &op {
+    BinaryOp::Add => left + right,
+    BinaryOp::Sub => left - right,
+    BinaryOp::Mul => left * right,
+    BinaryOp::Div => left / right,
+    _ => panic!("Operator '{}' is not supported", op),
+  }
+}
+
+pub fn type_of<T>(_: T) -> &'static str {
+  type_name::<T>()
+}
+
+fn prop_name_eq(a: &PropName, b: &PropName) -> bool {
+  match (a, b) {
+    (PropName::Ident(a), PropName::Ident(b)) => a.sym == b.sym,
+    (PropName::Str(a), PropName::Str(b)) => a.value == b.value,
+    (PropName::Num(a), PropName::Num(b)) => (a.value - b.value).abs() < f64::EPSILON,
+
+    (PropName::BigInt(a), PropName::BigInt(b)) => a.value == b.value,
+    // Add more cases as needed
+    _ => false,
+  }
+}
+
+pub fn remove_duplicates(props: Vec<PropOrSpread>) -> Vec<PropOrSpread> {
+  let mut set = HashSet::new();
+  let mut result = vec![];
+
+  for prop in props.into_iter().rev() {
+    let key = match &prop {
+      PropOrSpread::Prop(prop) => match prop.as_ref() {
+        Prop::Shorthand(ident) => ident.sym.clone(),
+        Prop::KeyValue(kv) => match &kv.key {
+          PropName::Ident(ident) => ident.sym.clone(),
+          PropName::Str(str) => str.value.clone(),
+          _ => continue,
+        },
+        _ => continue,
+      },
+      _ => continue,
+    };
+
+    if set.insert(key) {
+      result.push(prop);
+    }
+  }
+
+  result.reverse();
+
+  result
+}
+
+pub fn deep_merge_props(
+  old_props: Vec<PropOrSpread>,
+  mut new_props: Vec<PropOrSpread>,
+) -> Vec<PropOrSpread> {
+  for prop in old_props {
+    match prop {
+      PropOrSpread::Prop(prop) => match *prop {
+        Prop::KeyValue(mut kv) => {
+          if new_props.iter().any(|p| match p {
+            PropOrSpread::Prop(p) => match p.as_ref() {
+              Prop::KeyValue(ref existing_kv) => prop_name_eq(&kv.key, &existing_kv.key),
+              _ => false,
+            },
+            _ => false,
+          }) {
+            if let Expr::Object(ref mut obj) = *kv.value {
+              new_props.push(PropOrSpread::Prop(Box::new(Prop::from(KeyValueProp {
+                key: kv.key.clone(),
+                value: Box::new(Expr::Object(ObjectLit {
+                  span: DUMMY_SP,
+                  props: obj.props.clone(),
+                })),
+              }))));
+            }
+          } else {
+            new_props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(kv))));
+          }
+        }
+        _ => new_props.push(PropOrSpread::Prop(Box::new(*prop))),
+      },
+      _ => new_props.push(prop),
+    }
+  }
+
+  remove_duplicates(new_props.into_iter().rev().collect())
+}
+
+pub fn get_css_value(key_value: KeyValueProp) -> (Box<Expr>, Option<BaseCSSType>) {
+  let Some(obj) = key_value.value.as_object() else {
+    return (key_value.value, None);
+  };
+
+  for prop in obj.props.clone().into_iter() {
+    match prop {
+      PropOrSpread::Spread(_) => unimplemented!("Spread"),
+      PropOrSpread::Prop(mut prop) => {
+        transform_shorthand_to_key_values(&mut prop);
+
+        match prop.deref() {
+          Prop::KeyValue(key_value) => {
+            if let Some(ident) = key_value.key.as_ident() {
+              if ident.sym == "syntax" {
+                let value = obj.props.iter().find(|prop| {
+                  match prop {
+                    PropOrSpread::Spread(_) => unimplemented!("Spread"),
+                    PropOrSpread::Prop(prop) => {
+                      let mut prop = prop.clone();
+                      transform_shorthand_to_key_values(&mut prop);
+
+                      match prop.as_ref() {
+                        Prop::KeyValue(key_value) => {
+                          if let Some(ident) = key_value.key.as_ident() {
+                            return ident.sym == "value";
+                          }
+                        }
+                        _ => unimplemented!(),
+                      }
+                    }
+                  }
+
+                  false
+                });
+
+                if let Some(value) = value {
+                  let result_key_value = value
+                    .as_prop()
+                    .and_then(|prop| prop.as_key_value())
+                    .unwrap();
+
+                  return (result_key_value.value.clone(), Some(obj.clone().into()));
+                }
+              }
+            }
+          }
+          _ => unimplemented!(),
+        }
+      }
+    }
+  }
+
+  (key_value.value, None)
+}
+
+/// Returns the key values of `object`. Spread object literals are inlined in
+/// place, with later keys overriding earlier ones as they would at runtime.
+pub fn get_key_values_from_object(object: &ObjectLit) -> Vec<KeyValueProp> {
+  let mut key_values: Vec<KeyValueProp> = vec![];
+
+  for prop in object.props.iter() {
+    let prop_key_values = match prop {
+      PropOrSpread::Spread(spread) => match spread.expr.as_ref() {
+        Expr::Object(spread_object) => get_key_values_from_object(spread_object),
+        Expr::Paren(paren) => match paren.expr.as_ref() {
+          Expr::Object(spread_object) => get_key_values_from_object(spread_object),
+          _ => panic!("{}", NON_STATIC_SPREAD),
+        },
+        _ => panic!("{}", NON_STATIC_SPREAD),
+      },
+      PropOrSpread::Prop(prop) => {
+        let mut prop = prop.clone();
+
+        transform_shorthand_to_key_values(&mut prop);
+
+        match prop.as_ref() {
+          Prop::KeyValue(key_value) => vec![key_value.clone()],
+          _ => panic!("{}", ILLEGAL_PROP_VALUE),
+        }
+      }
+    };
+
+    for key_value in prop_key_values {
+      let existing = prop_name_to_string(&key_value.key).and_then(|key| {
+        key_values
+          .iter_mut()
+          .find(|existing| prop_name_to_string(&existing.key).as_deref() == Some(key.as_str()))
+      });
+
+      match existing {
+        Some(existing) => existing.value = key_value.value,
+        None => key_values.push(key_value),
+      }
+    }
+  }
+
+  key_values
+}
+
+fn prop_name_to_string(prop_name: &PropName) -> Option<String> {
+  match prop_name {
+    PropName::Ident(ident) => Some(ident.sym.to_string()),
+    PropName::Str(str) => Some(str.value.to_string()),
+    PropName::Num(num) => Some(num.value.to_string()),
+    _ => None,
+  }
+}
+
+pub fn dashify(s: &str) -> String {
+  // Custom property names are case-sensitive
+  if s.starts_with("--") {
+    return s.to_string();
+  }
+
+  let after = DASHIFY_REGEX.replace_all(s, "$1-$2");
+  after.to_lowercase()
+}
+
+pub fn fill_top_level_expressions(module: &Module, state: &mut StateManager) {
+  module.body.iter().for_each(|item| match item {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+      if let Decl::Var(decl_var) = &export_decl.decl {
+        for decl in &decl_var.decls {
+          fill_top_level_declaration(decl, TopLevelExpressionKind::NamedExport, state);
+        }
+      }
+    }
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_decl)) => {
+      let expr = match export_decl.expr.as_paren() {
+        Some(paren) => &paren.expr,
+        None => &export_decl.expr,
+      };
+
+      // Default exports are imported under the `default` name by other files
+      state.top_level_expressions.push(TopLevelExpression(
+        TopLevelExpressionKind::DefaultExport,
+        *expr.clone(),
+        Some(DEFAULT_EXPORT_NAME.into()),
+      ));
+    }
+    ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => {
+      for decl in &var.decls {
+        fill_top_level_declaration(decl, TopLevelExpressionKind::Stmt, state);
+      }
+    }
+    _ => {}
+  });
+}
+
+fn fill_top_level_declaration(
+  decl: &VarDeclarator,
+  kind: TopLevelExpressionKind,
+  state: &mut StateManager,
+) {
+  let Some(decl_init) = decl.init.as_ref() else {
+    return;
+  };
+
+  if let Some(source) = get_dynamic_import_source(decl_init) {
+    for name in get_pat_idents(&decl.name) {
+      state.dynamic_imports.insert(name, source.clone());
+    }
+  }
+
+  // Destructured declarations can't be evaluated
+  let Pat::Ident(binding_ident) = &decl.name else {
+    return;
+  };
+
+  state.top_level_expressions.push(TopLevelExpression(
+    kind,
+    *decl_init.clone(),
+    Some(binding_ident.sym.clone()),
+  ));
+  state.declarations.push(decl.clone());
+}
+
+/// Returns the source of the dynamic `import()` an expression like
+/// `await import('./tokens.stylex')` or `import('./tokens.stylex').then(..)`
+/// reads from.
+pub fn get_dynamic_import_source(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Paren(paren) => get_dynamic_import_source(&paren.expr),
+    Expr::Await(await_expr) => get_dynamic_import_source(&await_expr.arg),
+    Expr::Member(member) => get_dynamic_import_source(&member.obj),
+    Expr::Call(call) => match &call.callee {
+      Callee::Import(_) => Some(
+        call
+          .args
+          .first()
+          .and_then(|arg| arg.expr.as_lit())
+          .and_then(get_string_val_from_lit)
+          .unwrap_or_default(),
+      ),
+      Callee::Expr(callee) => get_dynamic_import_source(callee),
+      Callee::Super(_) => None,
+    },
+    _ => None,
+  }
+}
+
+/// Returns the variable name and the `process` identifier of a
+/// `process.env.NAME` or `process.env['NAME']` member expression.
+pub fn get_process_env_name(member: &MemberExpr) -> Option<(String, &Ident)> {
+  let env = member.obj.as_member()?;
+  let process = env.obj.as_ident()?;
+
+  if process.sym != "process" || env.prop.as_ident()?.sym != "env" {
+    return None;
+  }
+
+  let name = match &member.prop {
+    MemberProp::Ident(ident) => ident.sym.to_string(),
+    MemberProp::Computed(computed) => get_string_val_from_lit(computed.expr.as_lit()?)?,
+    MemberProp::PrivateName(_) => return None,
+  };
+
+  Some((name, process))
+}
+
+fn get_pat_idents(pat: &Pat) -> Vec<Atom> {
+  match pat {
+    Pat::Ident(binding_ident) => vec![binding_ident.sym.clone()],
+    Pat::Array(array) => array
+      .elems
+      .iter()
+      .flatten()
+      .flat_map(get_pat_idents)
+      .collect(),
+    Pat::Rest(rest) => get_pat_idents(&rest.arg),
+    Pat::Assign(assign) => get_pat_idents(&assign.left),
+    Pat::Object(object) => object
+      .props
+      .iter()
+      .flat_map(|prop| match prop {
+        ObjectPatProp::KeyValue(key_value) => get_pat_idents(&key_value.value),
+        ObjectPatProp::Assign(assign) => vec![assign.key.sym.clone()],
+        ObjectPatProp::Rest(rest) => get_pat_idents(&rest.arg),
+      })
+      .collect(),
+    Pat::Invalid(_) | Pat::Expr(_) => vec![],
+  }
+}
+
+pub fn hash_f64(value: f64) -> u64 {
+  let bits = value.to_bits();
+  let mut hasher = DefaultHasher::new();
+  bits.hash(&mut hasher);
+  hasher.finish()
+}
+
+pub fn round_f64(value: f64, decimal_places: u32) -> f64 {
+  let multiplier = 10f64.powi(decimal_places as i32);
+  (value * multiplier).round() / multiplier
+}
+
+pub fn _resolve_node_package_path(package_name: &str) -> Result<PathBuf, String> {
+  match node_resolve::Resolver::default()
+    .with_basedir(PathBuf::from("./cwd"))
+    .preserve_symlinks(true)
+    .with_extensions([".ts", ".tsx", ".js", ".jsx", ".json"])
+    .with_main_fields(vec![String::from("main"), String::from("module")])
+    .resolve(package_name)
+  {
+    Ok(path) => Ok(path),
+    Err(error) => Err(format!(
+      "Error resolving package {}: {:?}",
+      package_name, error
+    )),
+  }
+}
+
+/// Unwraps parentheses and TypeScript wrappers like `as`, `satisfies` and
+/// `!`, none of which change the value of the wrapped expression.
+pub fn normalize_expr(expr: &mut Expr) -> &mut Expr {
+  match expr {
+    Expr::Paren(ParenExpr { expr, .. })
+    | Expr::TsAs(TsAsExpr { expr, .. })
+    | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+    | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+    | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. })
+    | Expr::TsNonNull(TsNonNullExpr { expr, .. }) => normalize_expr(expr.as_mut()),
+    _ => expr,
+  }
+}
+
+/// Like [`normalize_expr`], for expressions that are only read, e.g. the
+/// initializer `4` of `const size = 4 as const`.
+pub fn unwrap_expr(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(ParenExpr { expr, .. })
+    | Expr::TsAs(TsAsExpr { expr, .. })
+    | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+    | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+    | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. })
+    | Expr::TsNonNull(TsNonNullExpr { expr, .. }) => unwrap_expr(expr),
+    _ => expr,
+  }
+}
+
+/// Removes the TypeScript wrappers of `expr` and every expression within it,
+/// e.g. `{ color: 'red' as const }` to `{ color: 'red' }`.
+pub fn strip_ts_exprs(expr: &mut Expr) {
+  expr.visit_mut_with(&mut TsExprStripper);
+}
+
+struct TsExprStripper;
+
+impl VisitMut for TsExprStripper {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    while let Expr::TsAs(TsAsExpr { expr: inner, .. })
+    | Expr::TsSatisfies(TsSatisfiesExpr { expr: inner, .. })
+    | Expr::TsConstAssertion(TsConstAssertion { expr: inner, .. })
+    | Expr::TsTypeAssertion(TsTypeAssertion { expr: inner, .. })
+    | Expr::TsNonNull(TsNonNullExpr { expr: inner, .. }) = expr
+    {
+      *expr = *inner.take();
+    }
+
+    expr.visit_mut_children_with(self);
+  }
+}
+
+/// `body` of an arrow function with the expression it returns as the body,
+/// see [`get_return_expr`]. Other block bodies are kept.
+///
+/// Only meant for functions evaluated at compile time, since the declared
+/// values are inlined.
+pub fn normalize_arrow_body(body: &BlockStmtOrExpr) -> BlockStmtOrExpr {
+  match body {
+    BlockStmtOrExpr::BlockStmt(block) => match get_return_expr(&block.stmts) {
+      Some(expr) => BlockStmtOrExpr::Expr(Box::new(expr)),
+      None => body.clone(),
+    },
+    BlockStmtOrExpr::Expr(_) => body.clone(),
+  }
+}
+
+/// The variable declarations of a function body `stmts`, one declarator
+/// each, and the expression it returns, for bodies made of declarations of
+/// distinct names followed by a single `return`.
+pub fn split_function_body(stmts: &[Stmt]) -> Option<(Vec<VarDecl>, &Expr)> {
+  let (Stmt::Return(ReturnStmt { arg: Some(arg), .. }), statements) = stmts.split_last()? else {
+    return None;
+  };
+
+  let mut declarations = vec![];
+  let mut names = HashSet::new();
+
+  for statement in statements {
+    let Stmt::Decl(Decl::Var(var_decl)) = statement else {
+      return None;
+    };
+
+    for decl in &var_decl.decls {
+      let (Pat::Ident(binding), Some(_)) = (&decl.name, &decl.init) else {
+        return None;
+      };
+
+      if !names.insert(binding.id.sym.clone()) {
+        return None;
+      }
+
+      declarations.push(VarDecl {
+        decls: vec![decl.clone()],
+        ..*var_decl.clone()
+      });
+    }
+  }
+
+  Some((declarations, arg.as_ref()))
+}
+
+/// The expression returned by a function body `stmts`, see
+/// [`split_function_body`], with the declared values inlined, e.g.
+/// `{ width: (size * 2) }` for
+/// `const doubled = size * 2; return { width: doubled };`.
+///
+/// Values used several times are repeated, so the expression is only meant
+/// to be evaluated at compile time, see [`inline_pure_declarations`] for
+/// emitted code.
+pub fn get_return_expr(stmts: &[Stmt]) -> Option<Expr> {
+  let (declarations, expr) = split_function_body(stmts)?;
+
+  let (declarations, expr) = inline_declarations(declarations, expr.clone(), |_, _| true);
+
+  declarations.is_empty().then_some(expr)
+}
+
+/// Inlines the `declarations` of a function body that are pure and used at
+/// most once into the ones after them and the returned `expr`, e.g.
+/// `{ color: 'red' }` for `const fallback = 'red'; return { color: fallback };`.
+///
+/// Returns the declarations left, which still have to run before `expr`.
+pub fn inline_pure_declarations(declarations: Vec<VarDecl>, expr: Expr) -> (Vec<VarDecl>, Expr) {
+  inline_declarations(declarations, expr, |value, references| {
+    references <= 1 && is_pure_expr(value)
+  })
+}
+
+fn inline_declarations(
+  declarations: Vec<VarDecl>,
+  mut expr: Expr,
+  can_inline: impl Fn(&Expr, usize) -> bool,
+) -> (Vec<VarDecl>, Expr) {
+  let mut declarations = VecDeque::from(declarations);
+  let mut kept_declarations = vec![];
+
+  while let Some(declaration) = declarations.pop_front() {
+    let decl = &declaration.decls[0];
+
+    let (Pat::Ident(binding), Some(init)) = (&decl.name, &decl.init) else {
+      kept_declarations.push(declaration);
+      continue;
+    };
+
+    let name = &binding.id.sym;
+    let value = parenthesize(*init.clone());
+
+    let mut counter = ReferenceCounter {
+      name,
+      value: Some(&value),
+      scopes: vec![],
+      references: 0,
+      captured: false,
+    };
+
+    for declaration in &declarations {
+      declaration.visit_with(&mut counter);
+    }
+
+    expr.visit_with(&mut counter);
+
+    if counter.captured || !can_inline(&value, counter.references) {
+      kept_declarations.push(declaration);
+      continue;
+    }
+
+    let mut inliner = BindingReplacer {
+      name,
+      value: &value,
+      scopes: vec![],
+    };
+
+    for declaration in declarations.iter_mut() {
+      declaration.visit_mut_with(&mut inliner);
+    }
+
+    expr.visit_mut_with(&mut inliner);
+  }
+
+  (kept_declarations, expr)
+}
+
+/// Replaces the references to the binding `name` in `expr` with `value`,
+/// except inside of functions binding the same name.
+pub fn replace_binding(expr: &mut Expr, name: &Atom, value: &Expr) {
+  expr.visit_mut_with(&mut BindingReplacer {
+    name,
+    value,
+    scopes: vec![],
+  });
+}
+
+/// Whether `expr` has no side effects and reads nothing that could change,
+/// so it can move to the place it's used, e.g. `size * 2` but not
+/// `getColor()` or `theme.size`.
+fn is_pure_expr(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(_) | Expr::Ident(_) => true,
+    Expr::Paren(ParenExpr { expr, .. }) => is_pure_expr(expr),
+    Expr::Tpl(tpl) => tpl.exprs.iter().all(|expr| is_pure_expr(expr)),
+    Expr::Unary(unary) => unary.op != UnaryOp::Delete && is_pure_expr(&unary.arg),
+    Expr::Bin(bin) => is_pure_expr(&bin.left) && is_pure_expr(&bin.right),
+    Expr::Cond(cond) => {
+      is_pure_expr(&cond.test) && is_pure_expr(&cond.cons) && is_pure_expr(&cond.alt)
+    }
+    _ => false,
+  }
+}
+
+/// `expr` wrapped in parentheses unless it can replace an identifier as is.
+fn parenthesize(expr: Expr) -> Expr {
+  match expr {
+    Expr::Ident(_)
+    | Expr::Lit(_)
+    | Expr::Member(_)
+    | Expr::Call(_)
+    | Expr::Tpl(_)
+    | Expr::Object(_)
+    | Expr::Array(_)
+    | Expr::Paren(_) => expr,
+    _ => Expr::Paren(ParenExpr {
+      span: DUMMY_SP,
+      expr: Box::new(expr),
+    }),
+  }
+}
+
+/// Names bound by the parameters and declarations of `arrow`, which shadow
+/// the bindings of the same names outside of it.
+pub fn get_arrow_bindings(arrow: &ArrowExpr) -> Vec<Atom> {
+  let mut ids = find_pat_ids::<_, Id>(&arrow.params);
+
+  if let BlockStmtOrExpr::BlockStmt(block) = arrow.body.as_ref() {
+    ids.extend(find_pat_ids::<_, Id>(&block.stmts));
+  }
+
+  ids.into_iter().map(|(sym, _)| sym).collect()
+}
+
+/// Names bound by the parameters and declarations of `function`, see
+/// [`get_arrow_bindings`].
+pub fn get_function_bindings(function: &Function) -> Vec<Atom> {
+  let mut ids = find_pat_ids::<_, Id>(&function.params);
+
+  if let Some(body) = &function.body {
+    ids.extend(find_pat_ids::<_, Id>(&body.stmts));
+  }
+
+  ids.into_iter().map(|(sym, _)| sym).collect()
+}
+
+pub fn get_fn_expr_bindings(fn_expr: &FnExpr) -> Vec<Atom> {
+  fn_expr
+    .ident
+    .iter()
+    .map(|ident| ident.sym.clone())
+    .collect()
+}
+
+pub fn is_shadowed(scopes: &[Vec<Atom>], name: &Atom) -> bool {
+  scopes.iter().any(|scope| scope.contains(name))
+}
+
+/// Counts the references to the binding `name` outside of functions binding
+/// the same name. A reference inside of a function binding a name `value`
+/// reads captures it, as `value` can't be inlined there.
+struct ReferenceCounter<'a> {
+  name: &'a Atom,
+  value: Option<&'a Expr>,
+  scopes: Vec<Vec<Atom>>,
+  references: usize,
+  captured: bool,
+}
+
+impl ReferenceCounter<'_> {
+  fn count(&mut self, name: &Atom) {
+    if name != self.name || is_shadowed(&self.scopes, name) {
+      return;
+    }
+
+    self.references += 1;
+
+    if let Some(value) = self.value {
+      if self
+        .scopes
+        .iter()
+        .flatten()
+        .any(|bound| reads_name(value, bound))
+      {
+        self.captured = true;
+      }
+    }
+  }
+}
+
+impl Visit for ReferenceCounter<'_> {
+  fn visit_expr(&mut self, expr: &Expr) {
+    if let Expr::Ident(ident) = expr {
+      self.count(&ident.sym);
+    }
+
+    expr.visit_children_with(self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    if let Prop::Shorthand(ident) = prop {
+      self.count(&ident.sym);
+    }
+
+    prop.visit_children_with(self);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+    self.scopes.push(get_arrow_bindings(arrow));
+    arrow.visit_children_with(self);
+    self.scopes.pop();
+  }
+
+  fn visit_fn_expr(&mut self, fn_expr: &FnExpr) {
+    self.scopes.push(get_fn_expr_bindings(fn_expr));
+    fn_expr.visit_children_with(self);
+    self.scopes.pop();
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    self.scopes.push(get_function_bindings(function));
+    function.visit_children_with(self);
+    self.scopes.pop();
+  }
+}
+
+fn reads_name(expr: &Expr, name: &Atom) -> bool {
+  let mut counter = ReferenceCounter {
+    name,
+    value: None,
+    scopes: vec![],
+    references: 0,
+    captured: false,
+  };
+
+  expr.visit_with(&mut counter);
+
+  counter.references > 0
+}
+
+/// Replaces the references to the binding `name` with `value`, except
+/// inside of functions binding the same name.
+struct BindingReplacer<'a> {
+  name: &'a Atom,
+  value: &'a Expr,
+  scopes: Vec<Vec<Atom>>,
+}
+
+impl BindingReplacer<'_> {
+  fn is_replaced(&self, name: &Atom) -> bool {
+    name == self.name && !is_shadowed(&self.scopes, name)
+  }
+}
+
+impl VisitMut for BindingReplacer<'_> {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Expr::Ident(ident) = expr {
+      if self.is_replaced(&ident.sym) {
+        *expr = self.value.clone();
+
+        return;
+      }
+    }
+
+    expr.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_prop(&mut self, prop: &mut Prop) {
+    if let Prop::Shorthand(ident) = prop {
+      if self.is_replaced(&ident.sym) {
+        *prop = Prop::KeyValue(key_value_factory(&ident.sym, self.value.clone()));
+
+        return;
+      }
+    }
+
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_arrow_expr(&mut self, arrow: &mut ArrowExpr) {
+    self.scopes.push(get_arrow_bindings(arrow));
+    arrow.visit_mut_children_with(self);
+    self.scopes.pop();
+  }
+
+  fn visit_mut_fn_expr(&mut self, fn_expr: &mut FnExpr) {
+    self.scopes.push(get_fn_expr_bindings(fn_expr));
+    fn_expr.visit_mut_children_with(self);
+    self.scopes.pop();
+  }
+
+  fn visit_mut_function(&mut self, function: &mut Function) {
+    self.scopes.push(get_function_bindings(function));
+    function.visit_mut_children_with(self);
+    self.scopes.pop();
+  }
+}
+
+pub fn sort_numbers_factory() -> impl FnMut(&f64, &f64) -> std::cmp::Ordering {
+  |a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+}
+
+pub fn char_code_at(s: &str, index: usize) -> Option<u32> {
+  s.chars().nth(index).map(|c| c as u32)
+}
+
+/// Report of a crash with `message` while transforming `file_name`, naming
+/// the phase the plugin was in and where to file an issue.
+pub fn crash_report(message: &str, file_name: &str, cycle: ModuleCycle) -> String {
+  format!(
+    "[StyleX] {}\n\nFile: {}\nPhase: {:?}\n\nIf this looks like a bug in the plugin, please file an issue at {}",
+    message, file_name, cycle, ISSUES_URL
+  )
+}
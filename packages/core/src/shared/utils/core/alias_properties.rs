@@ -0,0 +1,54 @@
+use swc_core::ecma::ast::{Expr, KeyValueProp, ObjectLit, Prop, PropName, PropOrSpread};
+
+use crate::shared::{
+  structures::state_manager::StateManager,
+  utils::{ast::factories::ident_name_factory, common::get_key_values_from_object},
+};
+
+/// Renames the properties of `namespace` listed in the `propertyAliases`
+/// option to their standard names, including the ones nested in pseudos and
+/// at-rules, and records each renamed property on `state`.
+pub fn alias_properties(namespace: &[KeyValueProp], state: &mut StateManager) -> Vec<KeyValueProp> {
+  if state.options.property_aliases.is_empty() {
+    return namespace.to_vec();
+  }
+
+  namespace
+    .iter()
+    .map(|key_value| {
+      // Computed keys are left to the namespace validation
+      let key = match &key_value.key {
+        PropName::Ident(ident) => ident.sym.to_string(),
+        PropName::Str(str) => str.value.to_string(),
+        _ => return key_value.clone(),
+      };
+
+      if let Some(property) = state.options.property_aliases.get(&key).cloned() {
+        state.aliased_properties.insert(key, property.clone());
+
+        return KeyValueProp {
+          key: PropName::Ident(ident_name_factory(&property)),
+          value: key_value.value.clone(),
+        };
+      }
+
+      match key_value.value.as_ref() {
+        Expr::Object(object) if key.starts_with('@') || key.starts_with(':') => {
+          let props = alias_properties(&get_key_values_from_object(object), state)
+            .into_iter()
+            .map(|key_value| PropOrSpread::Prop(Box::new(Prop::KeyValue(key_value))))
+            .collect();
+
+          KeyValueProp {
+            key: key_value.key.clone(),
+            value: Box::new(Expr::Object(ObjectLit {
+              span: object.span,
+              props,
+            })),
+          }
+        }
+        _ => key_value.clone(),
+      }
+    })
+    .collect()
+}
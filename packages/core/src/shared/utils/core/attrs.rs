@@ -9,22 +9,22 @@ use crate::shared::{
 
 use super::{parse_nullable_style::ResolvedArg, props::props};
 
-pub(crate) fn attrs(styles: &Vec<ResolvedArg>) -> Option<FnResult> {
+pub fn attrs(styles: &Vec<ResolvedArg>) -> Option<FnResult> {
   let props = props(styles);
 
   let props = props
     .and_then(|props| props.as_props().cloned())
     .and_then(|props| props.as_values().cloned())?;
 
-  let mut attrs_map: IndexMap<String, Box<FlatCompiledStylesValue>> = IndexMap::new();
-
-  if let Some(class_name) = props.get("className") {
-    attrs_map.insert("class".to_string(), class_name.clone());
-  };
-
-  if let Some(_inline_style) = props.get("style") {
-    panic!("Implement inline style");
-  };
+  // Keys keep the order of the props, so the output is stable across builds
+  let attrs_map = props
+    .into_iter()
+    .map(|(key, value)| match key.as_str() {
+      "className" => ("class".to_string(), value),
+      "style" => panic!("Implement inline style"),
+      _ => (key, value),
+    })
+    .collect::<IndexMap<String, Box<FlatCompiledStylesValue>>>();
 
   Some(FnResult::Attrs(
     NestedStringObject::FlatCompiledStylesValues(attrs_map),
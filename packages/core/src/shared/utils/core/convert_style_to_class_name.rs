@@ -1,17 +1,22 @@
-use convert_case::{Case, Casing};
-
-use crate::shared::{
-  constants::messages::{ILLEGAL_PROP_VALUE, NON_CONTIGUOUS_VARS},
-  structures::{
-    injectable_style::InjectableStyle, pre_rule::PreRuleValue, state_manager::StateManager,
+use crate::{
+  hash::{
+    class_name_hash_input, create_class_name, create_manifest_class_name, scope_hash_input,
+    shadow_host_hash_input,
   },
-  utils::{
-    common::{create_hash, dashify},
-    css::common::{generate_rule, transform_value},
+  shared::{
+    constants::messages::{ILLEGAL_PROP_VALUE, NON_CONTIGUOUS_VARS},
+    structures::{
+      class_name_manifest::ClassNameManifest, compat_version::CompatBehavior,
+      injectable_style::InjectableStyle, pre_rule::PreRuleValue, state_manager::StateManager,
+    },
+    utils::{
+      css::common::{generate_rule, transform_value},
+      interner::intern_dashed_key,
+    },
   },
 };
 
-pub(crate) fn convert_style_to_class_name(
+pub fn convert_style_to_class_name(
   obj_entry: (&str, &PreRuleValue),
   pseudos: &mut [String],
   at_rules: &mut [String],
@@ -20,28 +25,7 @@ pub(crate) fn convert_style_to_class_name(
 ) -> (String, String, InjectableStyle) {
   let (key, raw_value) = obj_entry;
 
-  let dashed_key = if key.starts_with("--") {
-    key.to_string()
-  } else {
-    dashify(key).to_case(Case::Kebab)
-  };
-
-  let sorted_pseudos = &mut pseudos.to_vec();
-  sorted_pseudos.sort();
-
-  let sorted_at_rules = &mut at_rules.to_vec();
-  sorted_at_rules.sort();
-
-  let at_rule_hash_string = sorted_at_rules.join("");
-  let pseudo_hash_string = sorted_pseudos.join("");
-
-  let modifier_hash_string = format!("{}{}", at_rule_hash_string, pseudo_hash_string);
-
-  let modifier_hash_string = if modifier_hash_string.is_empty() {
-    "null".to_string()
-  } else {
-    modifier_hash_string
-  };
+  let dashed_key = intern_dashed_key(key);
 
   let value = match raw_value {
     PreRuleValue::String(value) => PreRuleValue::String(transform_value(key, value, state)),
@@ -70,21 +54,41 @@ pub(crate) fn convert_style_to_class_name(
     PreRuleValue::Expr(_) | PreRuleValue::Null => panic!("{}", ILLEGAL_PROP_VALUE),
   };
 
-  let string_to_hash = format!(
-    "<>{}{}{}",
-    dashed_key,
-    value.join(", "),
-    modifier_hash_string
+  let string_to_hash = class_name_hash_input(
+    &dashed_key,
+    &value,
+    pseudos,
+    at_rules,
+    state
+      .options
+      .has_compat_behavior(CompatBehavior::AtRulesFirstInClassNameHash),
   );
 
-  let class_name_hashed = format!("{}{}", prefix, create_hash(string_to_hash.as_str()));
+  let shadow_host = state.get_shadow_host();
+
+  // Scoped rules differ from the unscoped ones, so are their class names
+  let string_to_hash = shadow_host_hash_input(shadow_host.as_deref(), string_to_hash);
+
+  let string_to_hash = scope_hash_input(state.get_package_scope(), string_to_hash);
+
+  let class_name_hashed = match state
+    .options
+    .class_name_manifest
+    .as_deref()
+    .and_then(ClassNameManifest::open)
+  {
+    Some(manifest) => create_manifest_class_name(prefix, &string_to_hash, &manifest),
+    None => create_class_name(prefix, &string_to_hash),
+  };
 
   let css_rules = generate_rule(
     class_name_hashed.as_str(),
-    dashed_key.as_str(),
+    &dashed_key,
     &value,
     pseudos,
     at_rules,
+    shadow_host.as_deref(),
+    &state.options.pseudo_class_order,
   );
 
   (key.to_string(), class_name_hashed, css_rules)
@@ -9,13 +9,18 @@ use crate::shared::{
     flat_compiled_styles_value::FlatCompiledStylesValue, value_with_default::ValueWithDefault,
   },
   structures::injectable_style::InjectableStyle,
-  utils::common::{create_hash, get_key_str, get_key_values_from_object, get_string_val_from_lit},
+  utils::{
+    ast::convertors::string_to_expression,
+    common::{create_hash, get_key_str, get_key_values_from_object, get_string_val_from_lit},
+    css::validators::css_type_initial_value::validate_css_type_initial_value,
+  },
 };
 
-pub(crate) fn construct_css_variables_string(
+pub fn construct_css_variables_string(
   variables: &IndexMap<String, Box<FlatCompiledStylesValue>>,
   theme_name_hash: &String,
   typed_variables: &mut IndexMap<String, Box<FlatCompiledStylesValue>>,
+  shadow_host: Option<&str>,
 ) -> IndexMap<String, Box<InjectableStyle>> {
   let mut rules_by_at_rule: IndexMap<String, Vec<String>> = IndexMap::new();
 
@@ -32,7 +37,7 @@ pub(crate) fn construct_css_variables_string(
       format!("-{}", create_hash(at_rule))
     };
 
-    let mut ltr = format!(":root{{{}}}", value.join(""));
+    let mut ltr = format!("{}{{{}}}", shadow_host.unwrap_or(":root"), value.join(""));
 
     if at_rule != "default" {
       ltr = wrap_with_at_rules(ltr.as_str(), at_rule);
@@ -51,7 +56,7 @@ pub(crate) fn construct_css_variables_string(
   result
 }
 
-pub(crate) fn collect_vars_by_at_rules(
+pub fn collect_vars_by_at_rules(
   key: &String,
   value: &FlatCompiledStylesValue,
   collection: &mut IndexMap<String, Vec<String>>,
@@ -67,6 +72,8 @@ pub(crate) fn collect_vars_by_at_rules(
 
     let initial_value = get_nitial_value_of_css_type(values);
 
+    validate_css_type_initial_value(key, css_type.syntax, &initial_value);
+
     typed_variables.insert(
       hash_name.clone(),
       Box::new(FlatCompiledStylesValue::CSSType(
@@ -148,7 +155,43 @@ fn get_nitial_value_of_css_type(values: &IndexMap<String, ValueWithDefault>) ->
   initial_value
 }
 
-pub(crate) fn wrap_with_at_rules(ltr: &str, at_rule: &str) -> String {
+/// `light-dark()` value of a variable with only a `default` and a
+/// `@media (prefers-color-scheme: dark)` string value, e.g.
+/// `light-dark(white, black)` for `{ default: 'white', '@media
+/// (prefers-color-scheme: dark)': 'black' }`.
+pub fn light_dark_value(value: &Expr) -> Option<Expr> {
+  let object = value.as_object()?;
+
+  let key_values = get_key_values_from_object(object);
+
+  if key_values.len() != 2 {
+    return None;
+  }
+
+  let mut light = None;
+  let mut dark = None;
+
+  for key_value in &key_values {
+    let Expr::Lit(lit @ Lit::Str(_)) = key_value.value.as_ref() else {
+      return None;
+    };
+
+    let key = get_key_str(key_value).replace(char::is_whitespace, "");
+
+    match key.as_str() {
+      "default" => light = get_string_val_from_lit(lit),
+      "@media(prefers-color-scheme:dark)" => dark = get_string_val_from_lit(lit),
+      _ => return None,
+    }
+  }
+
+  Some(string_to_expression(&format!(
+    "light-dark({}, {})",
+    light?, dark?
+  )))
+}
+
+pub fn wrap_with_at_rules(ltr: &str, at_rule: &str) -> String {
   at_rule
     .split(SPLIT_TOKEN)
     .fold(ltr.to_string(), |acc, at_rule| {
@@ -156,7 +199,7 @@ pub(crate) fn wrap_with_at_rules(ltr: &str, at_rule: &str) -> String {
     })
 }
 
-pub(crate) fn priority_for_at_rule(at_rule: &str) -> f64 {
+pub fn priority_for_at_rule(at_rule: &str) -> f64 {
   if at_rule == "default" {
     0.0
   } else {
@@ -5,14 +5,14 @@ use indexmap::IndexMap;
 use crate::shared::{
   constants::common::COMPILED_KEY,
   enums::data_structures::flat_compiled_styles_value::FlatCompiledStylesValue,
-  regex::SANITIZE_CLASS_NAME_REGEX,
   structures::{
     state_manager::StateManager,
     types::{FlatCompiledStyles, StylesObjectMap},
   },
+  utils::css::escape::is_css_ident_char,
 };
 
-pub(crate) fn inject_dev_class_names(
+pub fn inject_dev_class_names(
   obj: &StylesObjectMap,
   var_name: &Option<String>,
   state: &StateManager,
@@ -38,7 +38,7 @@ pub(crate) fn inject_dev_class_names(
   result
 }
 
-pub(crate) fn convert_to_test_styles(
+pub fn convert_to_test_styles(
   obj: &IndexMap<String, Box<FlatCompiledStyles>>,
   var_name: &Option<String>,
   state: &StateManager,
@@ -88,11 +88,11 @@ fn namespace_to_dev_class_name(
       .unwrap_or_default(),
     namespace
   );
-  let sanitized_class_name = SANITIZE_CLASS_NAME_REGEX
-    .replace_all(&class_name, "$1 $2")
-    .to_string();
-
-  sanitized_class_name
+  // Unicode is kept, but whitespace would split the class name in two
+  class_name
+    .chars()
+    .filter(|char| char == &'.' || (is_css_ident_char(*char) && !char.is_whitespace()))
+    .collect()
 }
 
 fn convert_theme_to_base_styles(
@@ -121,7 +121,7 @@ fn convert_theme_to_base_styles(
   overrides_obj_extended
 }
 
-pub(crate) fn convert_theme_to_dev_styles(
+pub fn convert_theme_to_dev_styles(
   variable_name: &Option<String>,
   overrides_obj: &IndexMap<String, Box<FlatCompiledStylesValue>>,
   filename: &str,
@@ -138,7 +138,7 @@ pub(crate) fn convert_theme_to_dev_styles(
   overrides_obj_extended
 }
 
-pub(crate) fn convert_theme_to_test_styles(
+pub fn convert_theme_to_test_styles(
   variable_name: &Option<String>,
   overrides_obj: &IndexMap<String, Box<FlatCompiledStylesValue>>,
   filename: &str,
@@ -3,13 +3,16 @@ use swc_core::{
   common::DUMMY_SP,
   ecma::ast::{
     ArrowExpr, BinExpr, BinaryOp, BindingIdent, BlockStmtOrExpr, CallExpr, Callee, CondExpr, Expr,
-    ExprOrSpread, KeyValueProp, ObjectLit, Pat, Prop, PropOrSpread, UnaryExpr, UnaryOp,
+    ExprOrSpread, Ident, KeyValueProp, ObjectLit, Pat, Prop, PropOrSpread, UnaryExpr, UnaryOp,
+    VarDecl,
   },
 };
 
 use crate::shared::{
   constants::{
-    length_units::LENGTH_UNITS, messages::ILLEGAL_NAMESPACE_VALUE, time_units::get_time_units,
+    length_units::LENGTH_UNITS,
+    messages::{ILLEGAL_NAMESPACE_VALUE, UNSUPPORTED_DYNAMIC_STYLE_BODY},
+    time_units::get_time_units,
   },
   enums::data_structures::evaluate_result_value::EvaluateResultValue,
   structures::{
@@ -26,7 +29,10 @@ use crate::shared::{
         ident_name_factory, object_expression_factory, prop_or_spread_expression_factory,
       },
     },
-    common::{create_hash, normalize_expr},
+    common::{
+      create_hash, get_key_values_from_object, inline_pure_declarations, normalize_expr,
+      replace_binding, split_function_body,
+    },
     css::common::get_number_suffix,
     js::evaluate::{evaluate, evaluate_obj_key},
     validators::validate_dynamic_style_params,
@@ -44,9 +50,14 @@ pub fn evaluate_stylex_create_arg(
 
       let mut fns: EvaluateResultFns = IndexMap::new();
 
-      for prop in &mut style_object.props {
+      let props = match inline_spread_namespaces(&style_object.props, traversal_state, functions) {
+        Ok(props) => props,
+        Err(result) => return result,
+      };
+
+      for prop in props {
         match prop {
-          PropOrSpread::Spread(_) => unimplemented!("Spread"),
+          PropOrSpread::Spread(_) => unreachable!("Spreads are inlined above"),
           PropOrSpread::Prop(prop) => {
             let mut prop = prop.clone();
 
@@ -82,50 +93,77 @@ pub fn evaluate_stylex_create_arg(
                       .filter_map(|param| param.as_ident().cloned())
                       .collect::<Vec<BindingIdent>>();
 
-                    if let BlockStmtOrExpr::Expr(expr) = fn_path.body.as_mut() {
-                      if let Expr::Object(fn_body_object) = normalize_expr(expr) {
-                        let eval_result = evaluate_partial_object_recursively(
-                          fn_body_object,
-                          traversal_state,
-                          functions,
-                          None,
-                        );
-
-                        if !eval_result.confident {
-                          return Box::new(EvaluateResult {
-                            confident: eval_result.confident,
-                            deopt: eval_result.deopt,
-                            value: eval_result.value,
-                            inline_styles: None,
-                            fns: None,
-                          });
-                        }
+                    let (declarations, mut body) = match fn_path.body.as_ref() {
+                      BlockStmtOrExpr::Expr(expr) => (vec![], *expr.clone()),
+                      BlockStmtOrExpr::BlockStmt(block) => {
+                        let Some((declarations, expr)) = split_function_body(&block.stmts) else {
+                          panic!("{}", UNSUPPORTED_DYNAMIC_STYLE_BODY)
+                        };
 
-                        let value = eval_result
-                          .value
-                          .and_then(|value| value.as_expr().cloned())
-                          .and_then(|expr| expr.as_object().cloned())
-                          .expect("Value not an object");
-
-                        let key = expr_to_str(key_expr, traversal_state, functions);
-
-                        fns.insert(key, (params, eval_result.inline_styles.unwrap_or_default()));
-
-                        result_value.insert(
-                          Box::new(key_expr.clone()),
-                          value
-                            .props
-                            .into_iter()
-                            .filter_map(|prop| {
-                              prop.as_prop().and_then(|prop| prop.as_key_value()).cloned()
-                            })
-                            .collect(),
-                        );
-                      } else {
-                        return evaluate(path, traversal_state, functions);
+                        inline_pure_declarations(declarations, expr.clone())
                       }
+                    };
+
+                    // Locals computed at runtime are renamed so they can't resolve to
+                    // bindings of the module with the same names, and become dynamic
+                    let locals = get_declared_idents(&declarations);
+
+                    for local in &locals {
+                      replace_binding(&mut body, &local.sym, &Expr::Ident(to_placeholder(local)));
+                    }
+
+                    if let Expr::Object(fn_body_object) = normalize_expr(&mut body) {
+                      let eval_result = evaluate_partial_object_recursively(
+                        fn_body_object,
+                        traversal_state,
+                        functions,
+                        None,
+                      );
+
+                      if !eval_result.confident {
+                        return Box::new(EvaluateResult {
+                          confident: eval_result.confident,
+                          deopt: eval_result.deopt,
+                          value: eval_result.value,
+                          inline_styles: None,
+                          fns: None,
+                        });
+                      }
+
+                      let value = eval_result
+                        .value
+                        .and_then(|value| value.as_expr().cloned())
+                        .and_then(|expr| expr.as_object().cloned())
+                        .expect("Value not an object");
+
+                      let key = expr_to_str(key_expr, traversal_state, functions);
+
+                      let mut inline_styles = eval_result.inline_styles.unwrap_or_default();
+
+                      for inline_style in inline_styles.values_mut() {
+                        for local in &locals {
+                          replace_binding(
+                            inline_style,
+                            &to_placeholder(local).sym,
+                            &Expr::Ident(local.clone()),
+                          );
+                        }
+                      }
+
+                      fns.insert(key, (params, declarations, inline_styles));
+
+                      result_value.insert(
+                        Box::new(key_expr.clone()),
+                        value
+                          .props
+                          .into_iter()
+                          .filter_map(|prop| {
+                            prop.as_prop().and_then(|prop| prop.as_key_value()).cloned()
+                          })
+                          .collect(),
+                      );
                     } else {
-                      unimplemented!("Block statement")
+                      return evaluate(path, traversal_state, functions);
                     }
                   }
                   _ => {
@@ -137,31 +175,18 @@ pub fn evaluate_stylex_create_arg(
 
                     let value_to_insert = match val.value.unwrap().as_ref() {
                       EvaluateResultValue::Expr(expr) => match expr.as_ref() {
-                        Expr::Object(obj_expr) => {
-                          let mut obj_expr_props: Vec<KeyValueProp> = vec![];
-
-                          for prop in obj_expr.clone().props {
-                            match prop {
-                              PropOrSpread::Spread(_) => unimplemented!("Spread"),
-                              PropOrSpread::Prop(mut prop) => {
-                                transform_shorthand_to_key_values(&mut prop);
-
-                                match prop.as_ref() {
-                                  Prop::KeyValue(obj_expr_prop_kv) => {
-                                    obj_expr_props.push(obj_expr_prop_kv.clone())
-                                  }
-
-                                  _ => unimplemented!(),
-                                }
-                              }
-                            }
-                          }
-
-                          obj_expr_props
-                        }
+                        Expr::Object(obj_expr) => get_key_values_from_object(obj_expr),
                         _ => panic!("{}", ILLEGAL_NAMESPACE_VALUE),
                       },
-                      _ => panic!("{}", ILLEGAL_NAMESPACE_VALUE),
+                      EvaluateResultValue::Vec(_)
+                      | EvaluateResultValue::Map(_)
+                      | EvaluateResultValue::Entries(_)
+                      | EvaluateResultValue::Callback(_)
+                      | EvaluateResultValue::FunctionConfig(_)
+                      | EvaluateResultValue::FunctionConfigMap(_)
+                      | EvaluateResultValue::ThemeRef(_) => {
+                        panic!("{}", ILLEGAL_NAMESPACE_VALUE)
+                      }
                     };
 
                     result_value.insert(Box::new(key_expr.clone()), value_to_insert);
@@ -186,10 +211,63 @@ pub fn evaluate_stylex_create_arg(
         fns: if fns.is_empty() { None } else { Some(fns) },
       })
     }
-    _ => evaluate(path, traversal_state, functions),
+    _ => {
+      let evaluated = evaluate(path, traversal_state, functions);
+
+      // Namespaces built at compile time, e.g. with `Object.fromEntries()`,
+      // are compiled like the object they evaluate to
+      match evaluated.value.as_deref() {
+        Some(EvaluateResultValue::Expr(expr)) if evaluated.confident && expr.is_object() => {
+          evaluate_stylex_create_arg(&mut expr.as_ref().clone(), traversal_state, functions)
+        }
+        _ => evaluated,
+      }
+    }
   }
 }
 
+/// Replaces spread namespaces, e.g. `stylex.create({ ...shared })`, with the
+/// namespaces of the object they evaluate to.
+fn inline_spread_namespaces(
+  props: &[PropOrSpread],
+  traversal_state: &mut StateManager,
+  functions: &FunctionMap,
+) -> Result<Vec<PropOrSpread>, Box<EvaluateResult>> {
+  let mut result = vec![];
+
+  for prop in props {
+    let PropOrSpread::Spread(spread) = prop else {
+      result.push(prop.clone());
+      continue;
+    };
+
+    let evaluated = evaluate(&spread.expr, traversal_state, functions);
+
+    if !evaluated.confident {
+      return Err(evaluated);
+    }
+
+    match evaluated.value.as_deref() {
+      Some(EvaluateResultValue::Expr(expr)) if expr.is_object() => {
+        let object = expr.as_object().expect("Expression is not an object");
+
+        result.extend(object.props.iter().cloned());
+      }
+      _ => {
+        return Err(Box::new(EvaluateResult {
+          confident: false,
+          deopt: Some(spread.expr.clone()),
+          value: None,
+          inline_styles: None,
+          fns: None,
+        }))
+      }
+    }
+  }
+
+  Ok(result)
+}
+
 fn evaluate_partial_object_recursively(
   path: &ObjectLit,
   traversal_state: &mut StateManager,
@@ -411,3 +489,21 @@ fn evaluate_partial_object_recursively(
     fns: None,
   })
 }
+
+/// Identifiers declared by the `declarations` of a dynamic style function.
+fn get_declared_idents(declarations: &[VarDecl]) -> Vec<Ident> {
+  declarations
+    .iter()
+    .flat_map(|declaration| &declaration.decls)
+    .filter_map(|decl| decl.name.as_ident().map(|binding| binding.id.clone()))
+    .collect()
+}
+
+/// Identifier standing for the `local` of a dynamic style function while it's
+/// evaluated, with a name no binding of the module can have.
+fn to_placeholder(local: &Ident) -> Ident {
+  Ident {
+    sym: format!("#{}", local.sym).into(),
+    ..local.clone()
+  }
+}
@@ -5,7 +5,7 @@ use crate::shared::structures::{
   stylex_state_options::StyleXStateOptions,
 };
 
-pub(crate) fn flat_map_expanded_shorthands(
+pub fn flat_map_expanded_shorthands(
   obj_entry: (String, PreRuleValue),
   options: &StyleXStateOptions,
 ) -> Vec<OrderPair> {
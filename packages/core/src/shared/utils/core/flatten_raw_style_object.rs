@@ -2,7 +2,7 @@ use indexmap::IndexMap;
 use regex::Regex;
 use swc_core::{
   common::DUMMY_SP,
-  ecma::ast::{Expr, KeyValueProp, Prop, PropName, PropOrSpread, Str},
+  ecma::ast::{Expr, KeyValueProp, Lit, Null, Prop, PropName, PropOrSpread, Str},
 };
 
 use crate::shared::{
@@ -24,15 +24,15 @@ use crate::shared::{
       transform_bin_expr_to_number, transform_shorthand_to_key_values,
     },
     common::{
-      get_expr_from_var_decl, get_key_str, get_key_values_from_object, get_string_val_from_lit,
-      get_var_decl_by_ident,
+      assert_finite_number, get_expr_from_var_decl, get_key_str, get_key_values_from_object,
+      get_string_val_from_lit, get_var_decl_by_ident,
     },
   },
 };
 
 use super::flat_map_expanded_shorthands::flat_map_expanded_shorthands;
 
-pub(crate) fn flatten_raw_style_object(
+pub fn flatten_raw_style_object(
   style: &[KeyValueProp],
   pseudos: &mut Vec<String>,
   at_rules: &mut Vec<String>,
@@ -63,11 +63,20 @@ pub(crate) fn flatten_raw_style_object(
     match property.value.as_ref() {
       Expr::Array(property_array) => {
         let mut equivalent_pairs: IndexMap<String, Vec<String>> = IndexMap::new();
+        let null_expr = Expr::Lit(Lit::Null(Null { span: DUMMY_SP }));
 
         property_array.elems.iter().for_each(|each_val| {
           if let Some(property) = each_val {
-            match property.expr.as_ref() {
+            // `undefined` entries are skipped like `null` ones
+            let property_expr = match property.expr.as_ref() {
+              Expr::Ident(ident) if ident.sym == "undefined" => &null_expr,
+              expr => expr,
+            };
+
+            match property_expr {
               Expr::Lit(property_lit) => {
+                assert_finite_number(property_lit);
+
                 let pairs = flat_map_expanded_shorthands(
                   (
                     css_property_key.clone(),
@@ -131,6 +140,8 @@ pub(crate) fn flatten_raw_style_object(
         }
       }
       Expr::Lit(property_lit) => {
+        assert_finite_number(property_lit);
+
         if !css_property_key.starts_with(':') && !css_property_key.starts_with('@') {
           let value = get_string_val_from_lit(property_lit);
 
@@ -177,9 +188,9 @@ pub(crate) fn flatten_raw_style_object(
         flattened.insert(css_property_key, pre_rule);
       }
       Expr::Ident(ident) => {
-        let ident = get_var_decl_by_ident(ident, state, fns, VarDeclAction::Reduce);
+        let var_decl = get_var_decl_by_ident(ident, state, fns, VarDeclAction::Reduce);
 
-        match ident {
+        match var_decl {
           Some(var_decl) => {
             let var_decl_expr = get_expr_from_var_decl(&var_decl);
 
@@ -191,6 +202,16 @@ pub(crate) fn flatten_raw_style_object(
 
             flattened.extend(inner_flattened);
           }
+          // `undefined` values are removed like `null` ones
+          None if ident.sym == "undefined" => {
+            let mut property_cloned = property.clone();
+            property_cloned.value = Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP })));
+
+            let inner_flattened =
+              flatten_raw_style_object(&[property_cloned], pseudos, at_rules, state, fns);
+
+            flattened.extend(inner_flattened);
+          }
           None => {
             panic!("{}", NON_STATIC_VALUE)
           }
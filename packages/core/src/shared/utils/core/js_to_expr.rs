@@ -15,7 +15,7 @@ use crate::shared::{
   },
 };
 
-pub(crate) fn remove_objects_with_spreads(
+pub fn remove_objects_with_spreads(
   obj: &IndexMap<String, Box<FlatCompiledStyles>>,
 ) -> IndexMap<String, Box<FlatCompiledStyles>> {
   let mut obj = obj.clone();
@@ -33,20 +33,20 @@ pub(crate) fn remove_objects_with_spreads(
 }
 
 #[derive(Debug, PartialEq, Clone)]
-pub(crate) enum NestedStringObject {
+pub enum NestedStringObject {
   FlatCompiledStyles(IndexMap<String, Box<FlatCompiledStyles>>),
   FlatCompiledStylesValues(IndexMap<String, Box<FlatCompiledStylesValue>>),
 }
 
 impl NestedStringObject {
-  pub(crate) fn _as_styles(&self) -> Option<&IndexMap<String, Box<FlatCompiledStyles>>> {
+  pub fn _as_styles(&self) -> Option<&IndexMap<String, Box<FlatCompiledStyles>>> {
     match self {
       NestedStringObject::FlatCompiledStyles(obj) => Some(obj),
       _ => None,
     }
   }
 
-  pub(crate) fn as_values(&self) -> Option<&IndexMap<String, Box<FlatCompiledStylesValue>>> {
+  pub fn as_values(&self) -> Option<&IndexMap<String, Box<FlatCompiledStylesValue>>> {
     match self {
       NestedStringObject::FlatCompiledStylesValues(obj) => Some(obj),
       _ => None,
@@ -54,7 +54,7 @@ impl NestedStringObject {
   }
 }
 
-pub(crate) fn convert_object_to_ast(obj: &NestedStringObject) -> Expr {
+pub fn convert_object_to_ast(obj: &NestedStringObject) -> Expr {
   let mut props: Vec<PropOrSpread> = vec![];
 
   match obj {
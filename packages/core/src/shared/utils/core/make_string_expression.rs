@@ -14,7 +14,10 @@ use crate::shared::{
   },
 };
 
-use super::{js_to_expr::convert_object_to_ast, parse_nullable_style::ResolvedArg};
+use super::{
+  js_to_expr::convert_object_to_ast, parse_nullable_style::ResolvedArg,
+  stylex_merge::wrap_condition,
+};
 
 fn fn_result_to_expression(fn_result: &FnResult) -> Option<Expr> {
   match fn_result {
@@ -24,7 +27,7 @@ fn fn_result_to_expression(fn_result: &FnResult) -> Option<Expr> {
   }
 }
 
-pub(crate) fn make_string_expression(
+pub fn make_string_expression(
   values: &Vec<ResolvedArg>,
   transform: fn(&Vec<ResolvedArg>) -> Option<FnResult>,
 ) -> Option<Expr> {
@@ -115,7 +118,7 @@ fn gen_bitwise_or_of_conditions(conditions: Vec<Expr>) -> Box<Expr> {
           arg: Box::new(Expr::from(UnaryExpr {
             span: DUMMY_SP,
             op: UnaryOp::Bang,
-            arg: Box::new(condition.clone()),
+            arg: Box::new(wrap_condition(condition)),
           })),
         })),
         op: BinaryOp::LShift,
@@ -12,7 +12,7 @@ use crate::shared::{
   utils::{common::increase_ident_count, js::evaluate::evaluate},
 };
 
-pub(crate) fn member_expression(
+pub fn member_expression(
   member: &MemberExpr,
   index: &mut i32,
   bail_out_index: &mut Option<i32>,
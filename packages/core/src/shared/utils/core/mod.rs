@@ -0,0 +1,18 @@
+pub mod alias_properties;
+pub mod attrs;
+pub mod convert_style_to_class_name;
+pub mod define_vars_utils;
+pub mod dev_class_name;
+pub mod evaluate_stylex_create_arg;
+pub mod flat_map_expanded_shorthands;
+pub mod flatten_raw_style_object;
+pub mod js_to_expr;
+pub mod make_string_expression;
+pub mod member_expression;
+pub mod parse_nullable_style;
+pub mod props;
+pub mod runtime_create;
+pub mod stylex;
+pub mod styleq;
+pub mod stylex_merge;
+pub(crate) mod tests;
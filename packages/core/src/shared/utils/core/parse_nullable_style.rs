@@ -4,18 +4,18 @@ use swc_core::ecma::ast::{Expr, Ident, Lit, MemberExpr, MemberProp};
 use crate::shared::{
   enums::data_structures::flat_compiled_styles_value::FlatCompiledStylesValue,
   structures::state_manager::StateManager,
-  utils::common::{get_string_val_from_lit, reduce_ident_count},
+  utils::common::{get_member_path, get_string_val_from_lit, reduce_ident_count},
 };
 
 #[derive(Debug, PartialEq, Clone)]
-pub(crate) enum StyleObject {
+pub enum StyleObject {
   Style(IndexMap<String, Box<FlatCompiledStylesValue>>),
   Nullable,
   Other,
 }
 
 #[derive(Debug, PartialEq, Clone)]
-pub(crate) enum ResolvedArg {
+pub enum ResolvedArg {
   StyleObject(StyleObject, Ident, MemberExpr),
   ConditionalStyle(
     Box<Expr>,
@@ -26,7 +26,7 @@ pub(crate) enum ResolvedArg {
   ),
 }
 
-pub(crate) fn parse_nullable_style(
+pub fn parse_nullable_style(
   node: &Expr,
   state: &mut StateManager,
   should_reduce_count: bool,
@@ -75,6 +75,18 @@ pub(crate) fn parse_nullable_style(
             MemberProp::PrivateName(_) => {}
           }
         }
+      } else if let Some(obj_path) = get_member_path(&member.obj) {
+        // Styles nested in a wrapper object, e.g. `config.styles.root`
+        if state.style_map.contains_key(&obj_path) {
+          prop_name = match &member.prop {
+            MemberProp::Ident(prop_ident) => Some(prop_ident.sym.to_string()),
+            MemberProp::Computed(computed) => {
+              computed.expr.as_lit().and_then(get_string_val_from_lit)
+            }
+            MemberProp::PrivateName(_) => None,
+          };
+          obj_name = Some(obj_path);
+        }
       }
 
       if let Some(obj_name) = obj_name {
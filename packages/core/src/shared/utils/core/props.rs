@@ -1,18 +1,21 @@
 use indexmap::IndexMap;
 
-use crate::{
-  shared::{
-    enums::data_structures::{
-      flat_compiled_styles_value::FlatCompiledStylesValue, fn_result::FnResult,
-    },
-    utils::core::js_to_expr::NestedStringObject,
+use crate::shared::{
+  enums::data_structures::{
+    flat_compiled_styles_value::FlatCompiledStylesValue, fn_result::FnResult,
+  },
+  utils::core::{
+    js_to_expr::NestedStringObject,
+    styleq::common::{styleq, StyleQResult},
   },
-  transform::styleq::common::{styleq, StyleQResult},
 };
 
 use super::parse_nullable_style::ResolvedArg;
 
-pub(crate) fn props(styles: &Vec<ResolvedArg>) -> Option<FnResult> {
+/// Resolves `stylex.props()` of static `styles` into the object it returns.
+/// Its keys are always listed in the same order, `className` before `style`,
+/// so the output is stable across builds.
+pub fn props(styles: &Vec<ResolvedArg>) -> Option<FnResult> {
   let StyleQResult {
     class_name,
     inline_style,
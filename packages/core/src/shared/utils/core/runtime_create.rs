@@ -0,0 +1,87 @@
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::ast::{
+    CallExpr, Callee, Expr, ExprOrSpread, Ident, ImportDecl, ImportSpecifier, MemberExpr,
+    ModuleDecl, ModuleExportName, ModuleItem, PropOrSpread, SpreadElement,
+  },
+};
+
+use crate::shared::utils::{ast::factories::object_expression_factory, common::unwrap_expr};
+
+/// `...create({ ...namespaces })`, creating at runtime the `namespaces` of a
+/// `stylex.create()` call that can't be compiled, next to the compiled ones.
+/// `callee` is the runtime `create` function, e.g. `_stylex.create`.
+pub fn runtime_create_spread(callee: Expr, namespaces: Vec<PropOrSpread>) -> PropOrSpread {
+  PropOrSpread::Spread(SpreadElement {
+    dot3_token: DUMMY_SP,
+    expr: Box::new(Expr::Call(CallExpr {
+      span: DUMMY_SP,
+      callee: Callee::Expr(Box::new(callee)),
+      args: vec![ExprOrSpread {
+        spread: None,
+        expr: Box::new(object_expression_factory(namespaces)),
+      }],
+      type_args: None,
+    })),
+  })
+}
+
+/// The binding a `stylex.create()` callee is read from, e.g. `stylex` for
+/// `stylex.create` or `create` for a named import.
+pub fn get_callee_binding(callee: &Expr) -> Option<&Ident> {
+  match unwrap_expr(callee) {
+    Expr::Ident(ident) => Some(ident),
+    Expr::Member(MemberExpr { obj, .. }) => get_callee_binding(obj),
+    _ => None,
+  }
+}
+
+/// `callee` read from `binding` instead, e.g. `_stylex.create` for
+/// `stylex.create`.
+pub fn rebind_callee(callee: &Expr, binding: &Ident) -> Expr {
+  match unwrap_expr(callee) {
+    Expr::Ident(_) => Expr::Ident(binding.clone()),
+    Expr::Member(member) => Expr::Member(MemberExpr {
+      obj: Box::new(rebind_callee(&member.obj, binding)),
+      ..member.clone()
+    }),
+    callee => callee.clone(),
+  }
+}
+
+/// The local binding of an import `specifier`.
+pub fn get_specifier_local(specifier: &ImportSpecifier) -> &Ident {
+  match specifier {
+    ImportSpecifier::Named(named) => &named.local,
+    ImportSpecifier::Default(default) => &default.local,
+    ImportSpecifier::Namespace(namespace) => &namespace.local,
+  }
+}
+
+/// The `specifier` of `import_decl` imported again as `local`, e.g.
+/// `import * as _stylex from 'stylex'` for `import * as stylex from 'stylex'`.
+pub fn runtime_import(
+  import_decl: &ImportDecl,
+  specifier: &ImportSpecifier,
+  local: &Ident,
+) -> ModuleItem {
+  let mut specifier = specifier.clone();
+
+  match &mut specifier {
+    ImportSpecifier::Named(named) => {
+      if named.imported.is_none() {
+        named.imported = Some(ModuleExportName::Ident(named.local.clone()));
+      }
+
+      named.local = local.clone();
+    }
+    ImportSpecifier::Default(default) => default.local = local.clone(),
+    ImportSpecifier::Namespace(namespace) => namespace.local = local.clone(),
+  }
+
+  ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+    span: DUMMY_SP,
+    specifiers: vec![specifier],
+    ..import_decl.clone()
+  }))
+}
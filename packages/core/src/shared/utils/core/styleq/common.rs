@@ -13,9 +13,9 @@ use crate::shared::{
   utils::core::parse_nullable_style::{ResolvedArg, StyleObject},
 };
 
-pub(crate) struct StyleQResult {
-  pub(crate) class_name: String,
-  pub(crate) inline_style: Option<FlatCompiledStyles>,
+pub struct StyleQResult {
+  pub class_name: String,
+  pub inline_style: Option<FlatCompiledStyles>,
 }
 
 fn get_hash<T>(obj: T) -> u64
@@ -27,7 +27,33 @@ where
   hasher.finish()
 }
 
-pub(crate) fn styleq(arguments: &Vec<ResolvedArg>) -> StyleQResult {
+/// Returns the key two compiled style properties collide on: the property
+/// together with the set of conditions it applies under. Conditions are
+/// sorted, so `@media_:hover_color` and `:hover_@media_color` collide, while
+/// `:hover_color` and `color` coexist.
+fn collision_key(prop: &str) -> String {
+  let mut conditions = vec![];
+  let mut rest = prop;
+
+  while rest.starts_with(':') || rest.starts_with('@') {
+    let Some((condition, remainder)) = rest.split_once('_') else {
+      break;
+    };
+
+    conditions.push(condition);
+    rest = remainder;
+  }
+
+  if conditions.is_empty() {
+    return rest.to_string();
+  }
+
+  conditions.sort_unstable();
+
+  format!("{}_{}", conditions.join("_"), rest)
+}
+
+pub fn styleq(arguments: &Vec<ResolvedArg>) -> StyleQResult {
   let mut class_name = String::default();
 
   if arguments.is_empty() {
@@ -111,9 +137,11 @@ pub(crate) fn styleq(arguments: &Vec<ResolvedArg>) -> StyleQResult {
                   _ => {}
                 }
 
+                let prop_key = collision_key(prop);
+
                 // Only add to chunks if this property hasn't already been seen
-                if !defined_properties.contains(prop) {
-                  defined_properties.push(prop.clone());
+                if !defined_properties.contains(&prop_key) {
+                  defined_properties.push(prop_key);
 
                   if next_cache.is_some() {
                     defined_properties_chunk.push(prop.clone())
@@ -138,9 +166,13 @@ pub(crate) fn styleq(arguments: &Vec<ResolvedArg>) -> StyleQResult {
             }
 
             if !class_name_chunk.is_empty() {
+              let is_applied = class_name_chunk
+                .split_whitespace()
+                .all(|chunk| class_name.split_whitespace().any(|name| name == chunk));
+
               class_name = if class_name.is_empty() {
                 class_name_chunk.clone()
-              } else if !class_name.contains(class_name_chunk.as_str()) {
+              } else if !is_applied {
                 format!("{} {}", class_name_chunk, class_name)
               } else {
                 class_name
@@ -0,0 +1,321 @@
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::{
+    ast::{
+      BinExpr, BinaryOp, CallExpr, CondExpr, Expr, ExprOrSpread, Lit, MemberExpr, ParenExpr,
+      UnaryExpr, UnaryOp,
+    },
+    visit::FoldWith,
+  },
+};
+
+use crate::shared::{
+  enums::data_structures::{fn_result::FnResult, style_vars_to_keep::NonNullProps},
+  structures::{member_transform::MemberTransform, state_manager::StateManager},
+  utils::{
+    common::{get_member_root_ident, reduce_ident_count, reduce_member_expression_count},
+    core::{
+      make_string_expression::make_string_expression,
+      parse_nullable_style::{parse_nullable_style, ResolvedArg, StyleObject},
+    },
+  },
+};
+
+pub fn stylex_merge(
+  call: &mut CallExpr,
+  transform: fn(&Vec<ResolvedArg>) -> Option<FnResult>,
+  state: &mut StateManager,
+) -> Option<Expr> {
+  let mut bail_out = false;
+  let mut conditional = 0;
+  let mut current_index = -1;
+  let mut bail_out_index = None;
+  let mut resolved_args = vec![];
+
+  let args = call
+    .args
+    .iter()
+    .flat_map(|arg| match arg.expr.as_ref() {
+      Expr::Array(arr) if arg.spread.is_none() => arr.elems.clone(),
+      _ => vec![Some(arg.clone())],
+    })
+    .flatten()
+    .collect::<Vec<ExprOrSpread>>();
+
+  for arg in args.iter() {
+    current_index += 1;
+
+    // Spread elements are only known at runtime, so the call is left to the
+    // runtime merge with the preceding arguments already compiled.
+    if arg.spread.is_some() {
+      bail_out_index = Some(current_index);
+      bail_out = true;
+      break;
+    }
+
+    let arg = arg.expr.as_ref();
+
+    match &arg {
+      Expr::Member(member) => {
+        let resolved = parse_nullable_style(arg, state, false);
+
+        match resolved {
+          StyleObject::Other => {
+            bail_out_index = Some(current_index);
+            bail_out = true;
+          }
+          StyleObject::Style(_) => {
+            resolved_args.push(ResolvedArg::StyleObject(
+              resolved,
+              get_member_root_ident(member)
+                .expect("Member obj is not an ident")
+                .clone(),
+              member.clone(),
+            ));
+          }
+          StyleObject::Nullable => {
+            resolved_args.push(ResolvedArg::StyleObject(
+              resolved,
+              get_member_root_ident(member)
+                .expect("Member obj is not an ident")
+                .clone(),
+              member.clone(),
+            ));
+          }
+        }
+      }
+      Expr::Cond(CondExpr {
+        test, cons, alt, ..
+      }) if is_member(cons) && is_member(alt) => {
+        let primary = parse_nullable_style(cons, state, true);
+        let fallback = parse_nullable_style(alt, state, true);
+
+        if primary.eq(&StyleObject::Other) || fallback.eq(&StyleObject::Other) {
+          bail_out_index = Some(current_index);
+          bail_out = true;
+        } else {
+          let member = alt.as_member().expect("Member expression expected");
+          let ident = get_member_root_ident(member).expect("Member obj is not an ident");
+
+          resolved_args.push(ResolvedArg::ConditionalStyle(
+            test.clone(),
+            Some(primary),
+            Some(fallback),
+            ident.clone(),
+            member.clone(),
+          ));
+
+          conditional += 1;
+        }
+      }
+      Expr::Bin(BinExpr {
+        left,
+        op: BinaryOp::LogicalAnd,
+        right,
+        ..
+      }) if is_member(right) => {
+        let left_resolved = parse_nullable_style(left, state, true);
+        let right_resolved = parse_nullable_style(right, state, true);
+
+        if !left_resolved.eq(&StyleObject::Other) || right_resolved.eq(&StyleObject::Other) {
+          bail_out_index = Some(current_index);
+          bail_out = true;
+        } else {
+          let member = right.as_member().expect("Member expression expected");
+          let ident = get_member_root_ident(member).expect("Member obj is not an ident");
+
+          resolved_args.push(ResolvedArg::ConditionalStyle(
+            left.clone(),
+            Some(right_resolved),
+            None,
+            ident.clone(),
+            member.clone(),
+          ));
+
+          conditional += 1;
+        }
+      }
+      Expr::Cond(_) | Expr::Bin(_) | Expr::Paren(_) => {
+        match flatten_conditional_styles(arg, None, state) {
+          Some(leaves) => {
+            for (guard, style, member) in leaves {
+              let ident = get_member_root_ident(&member)
+                .expect("Member obj is not an ident")
+                .clone();
+
+              match guard {
+                Some(guard) => {
+                  resolved_args.push(ResolvedArg::ConditionalStyle(
+                    Box::new(guard),
+                    Some(style),
+                    None,
+                    ident,
+                    member,
+                  ));
+
+                  conditional += 1;
+                }
+                None => resolved_args.push(ResolvedArg::StyleObject(style, ident, member)),
+              }
+            }
+          }
+          None => {
+            bail_out_index = Some(current_index);
+            bail_out = true;
+          }
+        }
+      }
+
+      _ => {
+        bail_out_index = Some(current_index);
+        bail_out = true;
+      }
+    }
+
+    if conditional > 4 {
+      bail_out = true;
+    }
+
+    if bail_out {
+      break;
+    }
+  }
+  if !state.gen_conditional_classes() && conditional > 0 {
+    bail_out = true;
+  }
+
+  // The call left to the runtime merge keeps reading it from the module's
+  // own import, which is never removed, so no helper import is added for it
+  if bail_out {
+    let mut non_null_props: NonNullProps = NonNullProps::Vec(vec![]);
+
+    let mut index = -1;
+
+    for arg_path in call.args.iter_mut() {
+      index += 1;
+
+      let mut member_transfom = MemberTransform {
+        index,
+        bail_out_index,
+        non_null_props: non_null_props.clone(),
+        state: state.clone(),
+        parents: vec![],
+      };
+
+      arg_path.expr = arg_path.expr.clone().fold_with(&mut member_transfom);
+
+      index = member_transfom.index;
+      bail_out_index = member_transfom.bail_out_index;
+      non_null_props = member_transfom.non_null_props;
+      *state = member_transfom.state;
+    }
+
+    for arg in args.iter() {
+      if let Expr::Member(member_expression) = arg.expr.as_ref() {
+        reduce_member_expression_count(state, member_expression)
+      }
+    }
+  } else {
+    let string_expression = make_string_expression(&resolved_args, transform);
+
+    for arg in &resolved_args {
+      match arg {
+        ResolvedArg::StyleObject(_, ident, member_expr) => {
+          reduce_ident_count(&mut *state, ident);
+          reduce_member_expression_count(state, member_expr)
+        }
+        ResolvedArg::ConditionalStyle(_, _, _, ident, member_expr) => {
+          reduce_ident_count(&mut *state, ident);
+          reduce_member_expression_count(state, member_expr)
+        }
+      }
+    }
+
+    return string_expression;
+  }
+
+  None
+}
+
+fn is_member(expr: &Expr) -> bool {
+  matches!(expr, Expr::Member(_))
+}
+
+/// Flattens nested ternaries and `&&` chains into the styles they can resolve
+/// to, each paired with the condition under which it applies. Returns `None`
+/// when a branch is neither a static style nor a nullish value.
+fn flatten_conditional_styles(
+  expr: &Expr,
+  guard: Option<Expr>,
+  state: &mut StateManager,
+) -> Option<Vec<(Option<Expr>, StyleObject, MemberExpr)>> {
+  match expr {
+    Expr::Paren(paren) => flatten_conditional_styles(&paren.expr, guard, state),
+    Expr::Lit(Lit::Null(_)) => Some(vec![]),
+    Expr::Ident(ident) if ident.sym == "undefined" => Some(vec![]),
+    Expr::Member(member) => match parse_nullable_style(expr, state, true) {
+      StyleObject::Other => None,
+      resolved => Some(vec![(guard, resolved, member.clone())]),
+    },
+    Expr::Bin(BinExpr {
+      left,
+      op: BinaryOp::LogicalAnd,
+      right,
+      ..
+    }) => {
+      if !parse_nullable_style(left, state, true).eq(&StyleObject::Other) {
+        return None;
+      }
+
+      flatten_conditional_styles(right, Some(and_condition(guard, left)), state)
+    }
+    Expr::Cond(CondExpr {
+      test, cons, alt, ..
+    }) => {
+      let negated = Expr::from(UnaryExpr {
+        span: DUMMY_SP,
+        op: UnaryOp::Bang,
+        arg: Box::new(wrap_condition(test)),
+      });
+
+      let mut leaves =
+        flatten_conditional_styles(cons, Some(and_condition(guard.clone(), test)), state)?;
+
+      leaves.extend(flatten_conditional_styles(
+        alt,
+        Some(and_condition(guard, &negated)),
+        state,
+      )?);
+
+      Some(leaves)
+    }
+    _ => None,
+  }
+}
+
+fn and_condition(guard: Option<Expr>, condition: &Expr) -> Expr {
+  match guard {
+    Some(guard) => Expr::from(BinExpr {
+      span: DUMMY_SP,
+      op: BinaryOp::LogicalAnd,
+      left: Box::new(guard),
+      right: Box::new(wrap_condition(condition)),
+    }),
+    None => condition.clone(),
+  }
+}
+
+pub fn wrap_condition(condition: &Expr) -> Expr {
+  match condition {
+    Expr::Ident(_)
+    | Expr::Member(_)
+    | Expr::Call(_)
+    | Expr::Lit(_)
+    | Expr::Unary(_)
+    | Expr::Paren(_) => condition.clone(),
+    _ => Expr::from(ParenExpr {
+      span: DUMMY_SP,
+      expr: Box::new(condition.clone()),
+    }),
+  }
+}
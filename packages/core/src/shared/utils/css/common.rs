@@ -14,12 +14,15 @@ use crate::shared::{
     unitless_number_properties::UNITLESS_NUMBER_PROPERTIES,
   },
   structures::{
-    injectable_style::InjectableStyle, pair::Pair, state_manager::StateManager,
-    stylex_state_options::StyleXStateOptions,
+    application_order::PseudoClassOrder, injectable_style::InjectableStyle, pair::Pair,
+    state_manager::StateManager, stylex_state_options::StyleXStateOptions,
   },
   utils::css::{
-    normalizers::{base::base_normalizer, whitespace_normalizer::whitespace_normalizer},
-    validators::unprefixed_custom_properties::unprefixed_custom_properties_validator,
+    normalizers::whitespace_normalizer::whitespace_normalizer,
+    validators::{
+      light_dark_function::light_dark_function_validator,
+      unprefixed_custom_properties::unprefixed_custom_properties_validator,
+    },
   },
 };
 
@@ -102,7 +105,7 @@ fn property_to_ltr(pair: (&str, &str)) -> Pair {
   }
 }
 
-pub(crate) fn generate_ltr(pair: &Pair) -> Pair {
+pub fn generate_ltr(pair: &Pair) -> Pair {
   property_to_ltr((pair.key.as_str(), pair.value.as_str()))
 }
 
@@ -285,13 +288,13 @@ fn _flip_value(value: &PreRules) -> Option<PreRules> {
   Some(value.clone())
 }
 
-pub(crate) fn generate_rtl(pair: &Pair) -> Option<Pair> {
+pub fn generate_rtl(pair: &Pair) -> Option<Pair> {
   let result = property_to_rtl(pair.key.as_str(), pair.value.as_str());
 
   result
 }
 
-pub(crate) fn split_value_required(str: Option<&str>) -> (String, String, String, String) {
+pub fn split_value_required(str: Option<&str>) -> (String, String, String, String) {
   let values = split_value(str);
 
   let top = values.0;
@@ -302,9 +305,7 @@ pub(crate) fn split_value_required(str: Option<&str>) -> (String, String, String
   (top, right, bottom, left)
 }
 
-pub(crate) fn split_value(
-  str: Option<&str>,
-) -> (String, Option<String>, Option<String>, Option<String>) {
+pub fn split_value(str: Option<&str>) -> (String, Option<String>, Option<String>, Option<String>) {
   let nodes = parse_css(str.unwrap_or(""));
 
   let top = nodes.first().cloned().unwrap_or(String::default());
@@ -321,18 +322,19 @@ const THUMB_VARIANTS: [&str; 3] = [
   "::-ms-thumb",
 ];
 
-pub(crate) fn generate_css_rule(
+pub fn generate_css_rule(
   class_name: &str,
   decls: String,
   pseudos: &mut [String],
   at_rules: &mut [String],
+  shadow_host: Option<&str>,
 ) -> String {
-  let pseudo = pseudos
+  let pseudo_strs: Vec<&str> = pseudos
     .iter()
+    .map(|s| s.as_str())
     .filter(|&p| p != "::thumb")
-    .collect::<Vec<&String>>();
-  let pseudo_strs: Vec<&str> = pseudo.iter().map(|s| s.as_str()).collect();
-  let pseudo = pseudo_strs.join("");
+    .collect();
+  let pseudo = order_pseudos(&pseudo_strs).join("");
   let mut selector_for_at_rules = format!(
     ".{}{}{}",
     class_name,
@@ -352,18 +354,49 @@ pub(crate) fn generate_css_rule(
       .join(", ");
   }
 
+  if let Some(host) = shadow_host {
+    selector_for_at_rules = scope_selectors_to_host(&selector_for_at_rules, host);
+  }
+
   at_rules.iter().fold(
     format!("{}{{{}}}", selector_for_at_rules, decls),
     |acc, at_rule| format!("{}{{{}}}", at_rule, acc),
   )
 }
 
-pub(crate) fn generate_rule(
+/// Orders nested `pseudos` as selectors require them, with the pseudo-classes
+/// nested in a pseudo-element before it, e.g. `:hover::before` for both
+/// `'::before': { ':hover': {} }` and `':hover': { '::before': {} }`.
+///
+/// Pseudo-classes stay after `::part()` and vendor prefixed pseudo-elements,
+/// such as `::-webkit-scrollbar-thumb`, which have states of their own.
+pub fn order_pseudos<'a>(pseudos: &[&'a str]) -> Vec<&'a str> {
+  let mut pseudo_classes = vec![];
+  let mut pseudo_elements = vec![];
+  let mut has_stateful_element = false;
+
+  for &pseudo in pseudos {
+    if pseudo.starts_with("::") {
+      has_stateful_element = pseudo.starts_with("::part(") || pseudo.starts_with("::-");
+      pseudo_elements.push(pseudo);
+    } else if has_stateful_element {
+      pseudo_elements.push(pseudo);
+    } else {
+      pseudo_classes.push(pseudo);
+    }
+  }
+
+  pseudo_classes.into_iter().chain(pseudo_elements).collect()
+}
+
+pub fn generate_rule(
   class_name: &str,
   key: &str,
   values: &Vec<String>,
   pseudos: &mut [String],
   at_rules: &mut [String],
+  shadow_host: Option<&str>,
+  pseudo_class_order: &PseudoClassOrder,
 ) -> InjectableStyle {
   let mut pairs: Vec<Pair> = vec![];
 
@@ -390,16 +423,28 @@ pub(crate) fn generate_rule(
     .collect::<Vec<String>>()
     .join(";");
 
-  let ltr_rule = generate_css_rule(class_name, ltr_decls, pseudos, at_rules);
+  let ltr_rule = generate_css_rule(class_name, ltr_decls, pseudos, at_rules, shadow_host);
   let rtl_rule = if rtl_decls.is_empty() {
     None
   } else {
-    Some(generate_css_rule(class_name, rtl_decls, pseudos, at_rules))
+    Some(generate_css_rule(
+      class_name,
+      rtl_decls,
+      pseudos,
+      at_rules,
+      shadow_host,
+    ))
   };
 
-  let priority = get_priority(key)
-    + pseudos.iter().map(|p| get_priority(p)).sum::<f64>()
-    + at_rules.iter().map(|a| get_priority(a)).sum::<f64>();
+  let priority = get_priority(key, pseudo_class_order)
+    + pseudos
+      .iter()
+      .map(|p| get_priority(p, pseudo_class_order))
+      .sum::<f64>()
+    + at_rules
+      .iter()
+      .map(|a| get_priority(a, pseudo_class_order))
+      .sum::<f64>();
 
   InjectableStyle {
     priority: Some(priority),
@@ -408,7 +453,20 @@ pub(crate) fn generate_rule(
   }
 }
 
-pub(crate) fn get_priority(key: &str) -> f64 {
+/// Prefixes each selector of a generated selector list with the shadow
+/// `host`, replacing `:root` which never matches inside a shadow tree.
+pub fn scope_selectors_to_host(selectors: &str, host: &str) -> String {
+  selectors
+    .split(", ")
+    .map(|selector| match selector {
+      ":root" => host.to_string(),
+      _ => format!("{} {}", host, selector),
+    })
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+pub fn get_priority(key: &str, pseudo_class_order: &PseudoClassOrder) -> f64 {
   if key.starts_with("--") {
     return 1.0;
   };
@@ -442,6 +500,10 @@ pub(crate) fn get_priority(key: &str) -> f64 {
       key
     };
 
+    if let Some(priority) = pseudo_class_order.priority(prop) {
+      return priority;
+    }
+
     return **PSEUDO_CLASS_PRIORITIES.get(prop).unwrap_or(&&40.0);
   };
 
@@ -464,7 +526,7 @@ pub(crate) fn get_priority(key: &str) -> f64 {
   3000.0
 }
 
-pub(crate) fn transform_value(key: &str, value: &str, state: &StateManager) -> String {
+pub fn transform_value(key: &str, value: &str, state: &StateManager) -> String {
   let css_property_value = value.trim();
 
   let value = match &css_property_value.parse::<f64>() {
@@ -476,6 +538,14 @@ pub(crate) fn transform_value(key: &str, value: &str, state: &StateManager) -> S
     Err(_) => css_property_value.to_string(),
   };
 
+  let suffix = get_number_suffix(key);
+
+  let value = if value.contains("calc(") && !suffix.is_empty() {
+    add_suffix_to_calc_sum_numbers(&value, &suffix)
+  } else {
+    value
+  };
+
   if key == "content" || key == "hyphenateCharacter" || key == "hyphenate-character" {
     let val = value.trim();
     if Regex::new(r"^attr\([a-zA-Z0-9-]+\)$")
@@ -514,7 +584,7 @@ pub fn swc_parse_css(source: &str) -> (Result<Stylesheet, Error>, Vec<Error>) {
   (parse_string_input(input, None, config, &mut errors), errors)
 }
 
-pub(crate) fn normalize_css_property_value(
+pub fn normalize_css_property_value(
   css_property: &str,
   css_property_value: &str,
   options: &StyleXStateOptions,
@@ -552,25 +622,16 @@ pub(crate) fn normalize_css_property_value(
       //   // Add other validator functions here...
       // ];
 
-      // let normalizers: Vec<Normalizer> = vec![
-      //   base_normalizer,
-      //   // Add other normalizer functions here...
-      // ];
-
       // for validator in validators {
       //   validator(ast.clone());
       // }
 
+      light_dark_function_validator(&ast, css_property_value);
       unprefixed_custom_properties_validator(ast);
 
-      let parsed_ast = base_normalizer(
-        parsed_css_property_value.unwrap(),
-        options.use_rem_for_font_size,
-      );
-
-      // for normalizer in normalizers {
-      //   parsed_ast = normalizer(parsed_ast, options.use_rem_for_font_size);
-      // }
+      let parsed_ast = options
+        .css_normalizers
+        .run(parsed_css_property_value.unwrap(), options);
 
       let result = whitespace_normalizer(stringify(&parsed_ast));
 
@@ -584,10 +645,96 @@ pub(crate) fn normalize_css_property_value(
   ast_normalized
 }
 
-// type Normalizer = fn(Stylesheet, bool) -> Stylesheet;
 // type Validator = fn(Stylesheet);
 
-pub(crate) fn get_number_suffix(key: &str) -> String {
+/// Adds the property's unit to plain numbers added to or subtracted from
+/// other terms of a `calc()`, e.g. `calc(var(--x) + 4)` to
+/// `calc(var(--x) + 4px)`, as CSS can't add numbers to lengths.
+///
+/// Factors and divisors stay unitless.
+fn add_suffix_to_calc_sum_numbers(value: &str, suffix: &str) -> String {
+  enum Token {
+    Open,
+    Close,
+    Comma,
+    Word(usize, usize),
+  }
+
+  let mut tokens = vec![];
+  let mut word_start: Option<usize> = None;
+
+  for (index, char) in value.char_indices() {
+    let token = match char {
+      // A word before the parenthesis is the function name
+      '(' => Some(Token::Open),
+      ')' => Some(Token::Close),
+      ',' => Some(Token::Comma),
+      char if char.is_whitespace() => None,
+      _ => {
+        word_start.get_or_insert(index);
+        continue;
+      }
+    };
+
+    if let Some(start) = word_start.take() {
+      if char != '(' {
+        tokens.push(Token::Word(start, index));
+      }
+    }
+
+    if let Some(token) = token {
+      tokens.push(token);
+    }
+  }
+
+  if let Some(start) = word_start {
+    tokens.push(Token::Word(start, value.len()));
+  }
+
+  let word = |token: Option<&Token>| match token {
+    Some(Token::Word(start, end)) => Some(&value[*start..*end]),
+    _ => None,
+  };
+
+  let mut result = String::with_capacity(value.len());
+  let mut copied_until = 0;
+
+  for (index, token) in tokens.iter().enumerate() {
+    let Token::Word(start, end) = token else {
+      continue;
+    };
+
+    if value[*start..*end].parse::<f64>().is_err() {
+      continue;
+    }
+
+    let neighbours = [
+      index
+        .checked_sub(1)
+        .and_then(|index| word(tokens.get(index))),
+      word(tokens.get(index + 1)),
+    ];
+
+    let is_summand = neighbours
+      .iter()
+      .any(|word| matches!(word, Some("+" | "-")));
+    let is_factor = neighbours
+      .iter()
+      .any(|word| matches!(word, Some("*" | "/")));
+
+    if is_summand && !is_factor {
+      result.push_str(&value[copied_until..*end]);
+      result.push_str(suffix);
+      copied_until = *end;
+    }
+  }
+
+  result.push_str(&value[copied_until..]);
+
+  result
+}
+
+pub fn get_number_suffix(key: &str) -> String {
   if UNITLESS_NUMBER_PROPERTIES.contains(key) {
     return String::default();
   }
@@ -600,7 +747,7 @@ pub(crate) fn get_number_suffix(key: &str) -> String {
   result.to_string()
 }
 
-pub(crate) fn get_value_from_ident(ident: &Ident) -> String {
+pub fn get_value_from_ident(ident: &Ident) -> String {
   ident.value.to_string()
 }
 
@@ -618,6 +765,55 @@ fn convert_css_function_to_camel_case(function: &str) -> String {
   format!("{}{}", camel_case_name, args)
 }
 
+/// Rewrites single-quoted CSS strings with double quotes, leaving the
+/// contents of every string untouched.
+fn double_quote_strings(css: &str) -> String {
+  let mut result = String::with_capacity(css.len());
+  let mut chars = css.chars();
+
+  while let Some(char) = chars.next() {
+    match char {
+      '"' => {
+        result.push(char);
+
+        while let Some(char) = chars.next() {
+          result.push(char);
+
+          match char {
+            '\\' => result.extend(chars.next()),
+            '"' => break,
+            _ => {}
+          }
+        }
+      }
+      '\'' => {
+        result.push('"');
+
+        while let Some(char) = chars.next() {
+          match char {
+            '\\' => match chars.next() {
+              Some('\'') => result.push('\''),
+              Some(escaped) => {
+                result.push(char);
+                result.push(escaped);
+              }
+              None => result.push(char),
+            },
+            '"' => result.push_str("\\\""),
+            '\'' => break,
+            _ => result.push(char),
+          }
+        }
+
+        result.push('"');
+      }
+      _ => result.push(char),
+    }
+  }
+
+  result
+}
+
 pub fn stringify(node: &Stylesheet) -> String {
   let mut buf = String::new();
   let writer = BasicCssWriter::new(&mut buf, None, BasicCssWriterConfig::default());
@@ -625,7 +821,7 @@ pub fn stringify(node: &Stylesheet) -> String {
 
   codegen.emit(&node).unwrap();
 
-  let mut result = buf.replace('\'', "");
+  let mut result = double_quote_strings(&buf);
 
   if result.contains("--\\") {
     /*
@@ -641,7 +837,7 @@ pub fn stringify(node: &Stylesheet) -> String {
     let re = Regex::new(r"\\3(\d) ").unwrap();
 
     result = re
-      .replace_all(buf.as_str(), |caps: &regex::Captures| {
+      .replace_all(result.as_str(), |caps: &regex::Captures| {
         caps
           .get(1)
           .map_or(String::default(), |m| m.as_str().to_string())
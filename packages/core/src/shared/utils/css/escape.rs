@@ -0,0 +1,60 @@
+/// Whether `char` can appear unescaped in a CSS identifier.
+pub fn is_css_ident_char(char: char) -> bool {
+  char.is_ascii_alphanumeric() || char == '-' || char == '_' || !char.is_ascii()
+}
+
+/// Serializes `ident` as a CSS identifier, like `CSS.escape`.
+///
+/// Escape sequences that are already present (e.g. `\31 ` or `\.`) are kept as
+/// they are, so escaping an escaped identifier is a no-op.
+pub fn escape_css_ident(ident: &str) -> String {
+  let mut result = String::with_capacity(ident.len());
+  let mut chars = ident.chars().peekable();
+  let mut index = 0;
+
+  while let Some(char) = chars.next() {
+    match char {
+      '\\' => {
+        result.push(char);
+
+        match chars.next() {
+          Some(hex) if hex.is_ascii_hexdigit() => {
+            result.push(hex);
+
+            let mut digits = 1;
+
+            while digits < 6 {
+              match chars.next_if(char::is_ascii_hexdigit) {
+                Some(hex) => result.push(hex),
+                None => break,
+              }
+
+              digits += 1;
+            }
+
+            if let Some(whitespace) = chars.next_if(|char| char.is_ascii_whitespace()) {
+              result.push(whitespace);
+            }
+          }
+          Some(escaped) => result.push(escaped),
+          None => result.push('\\'),
+        }
+      }
+      '\0' => result.push('\u{FFFD}'),
+      '\u{1}'..='\u{1F}' | '\u{7F}' => result.push_str(&format!("\\{:x} ", char as u32)),
+      '0'..='9' if index == 0 || (index == 1 && ident.starts_with('-')) => {
+        result.push_str(&format!("\\{:x} ", char as u32))
+      }
+      '-' if index == 0 && chars.peek().is_none() => result.push_str("\\-"),
+      char if is_css_ident_char(char) => result.push(char),
+      char => {
+        result.push('\\');
+        result.push(char);
+      }
+    }
+
+    index += 1;
+  }
+
+  result
+}
@@ -0,0 +1,6 @@
+pub mod common;
+pub mod escape;
+pub mod normalizers;
+pub mod parser;
+pub(crate) mod tests;
+pub mod validators;
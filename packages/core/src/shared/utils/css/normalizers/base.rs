@@ -2,60 +2,153 @@ use swc_core::{
   common::DUMMY_SP,
   css::{
     ast::{
-      ComponentValue, Declaration, DeclarationName, Dimension, Function, Ident, Length,
-      ListOfComponentValues, Number, Stylesheet,
+      ComponentValue, Declaration, DeclarationName, Dimension, Function, Ident, Length, Number,
+      Stylesheet,
     },
     visit::{Fold, FoldWith},
   },
 };
 
-use crate::shared::{constants::common::ROOT_FONT_SIZE, utils::common::dashify};
+use crate::shared::{
+  constants::common::ROOT_FONT_SIZE,
+  structures::{compat_version::CompatBehavior, stylex_state_options::StyleXStateOptions},
+  utils::common::dashify,
+};
 
-struct CssFolder {
-  use_rem_for_font_size: bool,
-  parent_key: Option<String>,
+use super::pipeline::{CssNormalizer, CssNormalizerPipeline};
+
+/// Dashifies the identifiers of `transitionProperty` and `willChange` values.
+#[derive(Debug, Clone, Copy)]
+pub struct KebabCaseNormalizer;
+
+impl KebabCaseNormalizer {
+  pub const NAME: &'static str = "kebab-case";
 }
 
-impl CssFolder {
-  fn convert_font_size_to_rem_normalizer<'a>(
-    &'a mut self,
-    declaration: &'a mut Declaration,
-  ) -> &'a mut Declaration {
-    if let DeclarationName::Ident(ident) = &declaration.name {
-      if ident.value.eq("fontSize") || self.parent_key.as_deref() == Some("fontSize") {
-        self.parent_key = Some("fontSize".into());
-        declaration.value = declaration.value.clone().fold_children_with(self);
-        self.parent_key = None;
-      }
+impl CssNormalizer for KebabCaseNormalizer {
+  fn name(&self) -> &str {
+    Self::NAME
+  }
+
+  fn normalize(&self, ast: Stylesheet, _: &StyleXStateOptions) -> Stylesheet {
+    ast.fold_with(&mut DeclarationFolder {
+      normalize: kebab_case_normalizer,
+    })
+  }
+}
+
+/// Converts durations of 10ms and more to seconds, unless `compatVersion`
+/// targets a release without [`CompatBehavior::TimingNormalization`].
+#[derive(Debug, Clone, Copy)]
+pub struct TimingNormalizer;
+
+impl TimingNormalizer {
+  pub const NAME: &'static str = "timing";
+}
+
+impl CssNormalizer for TimingNormalizer {
+  fn name(&self) -> &str {
+    Self::NAME
+  }
+
+  fn normalize(&self, ast: Stylesheet, options: &StyleXStateOptions) -> Stylesheet {
+    if !options.has_compat_behavior(CompatBehavior::TimingNormalization) {
+      return ast;
     }
 
-    declaration
+    ast.fold_with(&mut DimensionFolder {
+      normalize: timing_normalizer,
+    })
   }
 }
 
-impl Fold for CssFolder {
-  fn fold_list_of_component_values(
-    &mut self,
-    list: ListOfComponentValues,
-  ) -> ListOfComponentValues {
-    list.fold_children_with(self)
+/// Drops the unit of zero dimensions, except for angles and times.
+#[derive(Debug, Clone, Copy)]
+pub struct ZeroDimensionNormalizer;
+
+impl ZeroDimensionNormalizer {
+  pub const NAME: &'static str = "zero-dimension";
+}
+
+impl CssNormalizer for ZeroDimensionNormalizer {
+  fn name(&self) -> &str {
+    Self::NAME
   }
 
-  fn fold_declaration(&mut self, mut declaration: Declaration) -> Declaration {
-    let declaration = kebab_case_normalizer(&mut declaration);
+  fn normalize(&self, ast: Stylesheet, _: &StyleXStateOptions) -> Stylesheet {
+    ast.fold_with(&mut DimensionFolder {
+      normalize: zero_demention_normalizer,
+    })
+  }
+}
+
+/// Converts `px` font sizes to `rem` when `useRemForFontSize` is enabled.
+#[derive(Debug, Clone, Copy)]
+pub struct FontSizeToRemNormalizer;
+
+impl FontSizeToRemNormalizer {
+  pub const NAME: &'static str = "font-size-to-rem";
+}
+
+impl CssNormalizer for FontSizeToRemNormalizer {
+  fn name(&self) -> &str {
+    Self::NAME
+  }
 
-    if self.use_rem_for_font_size {
-      self.convert_font_size_to_rem_normalizer(declaration);
+  fn normalize(&self, ast: Stylesheet, options: &StyleXStateOptions) -> Stylesheet {
+    if !options.use_rem_for_font_size {
+      return ast;
     }
 
-    declaration.clone().fold_children_with(self)
+    ast.fold_with(&mut FontSizeFolder { parent_key: None })
   }
+}
 
+struct DeclarationFolder {
+  normalize: fn(&mut Declaration) -> &mut Declaration,
+}
+
+impl Fold for DeclarationFolder {
+  fn fold_declaration(&mut self, mut declaration: Declaration) -> Declaration {
+    (self.normalize)(&mut declaration);
+
+    declaration
+  }
+}
+
+struct DimensionFolder {
+  normalize: fn(&mut Dimension) -> &mut Dimension,
+}
+
+impl Fold for DimensionFolder {
   fn fold_dimension(&mut self, mut dimension: Dimension) -> Dimension {
-    let dimension = timing_normalizer(&mut dimension);
-    let dimension = zero_demention_normalizer(dimension);
+    (self.normalize)(&mut dimension);
+
+    dimension
+  }
 
-    dimension.clone().fold_children_with(self)
+  fn fold_function(&mut self, func: Function) -> Function {
+    fold_last_function_value(func, self)
+  }
+}
+
+struct FontSizeFolder {
+  parent_key: Option<String>,
+}
+
+impl Fold for FontSizeFolder {
+  fn fold_declaration(&mut self, declaration: Declaration) -> Declaration {
+    if let DeclarationName::Ident(ident) = &declaration.name {
+      if ident.value.eq("fontSize") {
+        self.parent_key = Some("fontSize".into());
+        let declaration = declaration.fold_children_with(self);
+        self.parent_key = None;
+
+        return declaration;
+      }
+    }
+
+    declaration
   }
 
   fn fold_length(&mut self, mut length: Length) -> Length {
@@ -82,15 +175,19 @@ impl Fold for CssFolder {
   }
 
   fn fold_function(&mut self, func: Function) -> Function {
-    let mut fnc = func;
+    fold_last_function_value(func, self)
+  }
+}
 
-    // NOTE: only last css fucntion value should be folded
-    if let Some(last) = fnc.value.last_mut() {
-      *last = last.clone().fold_with(self);
-    }
+fn fold_last_function_value(func: Function, folder: &mut impl Fold) -> Function {
+  let mut fnc = func;
 
-    fnc
+  // NOTE: only last css fucntion value should be folded
+  if let Some(last) = fnc.value.last_mut() {
+    *last = last.clone().fold_with(folder);
   }
+
+  fnc
 }
 
 fn timing_normalizer(dimension: &mut Dimension) -> &mut Dimension {
@@ -149,12 +246,14 @@ fn kebab_case_normalizer(declaration: &mut Declaration) -> &mut Declaration {
   declaration
 }
 
-pub(crate) fn base_normalizer(ast: Stylesheet, use_rem_for_font_size: bool) -> Stylesheet {
-  let mut folder = CssFolder {
+/// Runs the default [`CssNormalizerPipeline`].
+pub fn base_normalizer(ast: Stylesheet, use_rem_for_font_size: bool) -> Stylesheet {
+  let options = StyleXStateOptions {
     use_rem_for_font_size,
-    parent_key: None,
+    ..StyleXStateOptions::_new()
   };
-  ast.fold_with(&mut folder)
+
+  CssNormalizerPipeline::default().run(ast, &options)
 }
 
 fn zero_demention_normalizer(dimension: &mut Dimension) -> &mut Dimension {
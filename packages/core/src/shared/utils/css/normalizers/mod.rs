@@ -0,0 +1,4 @@
+pub mod base;
+pub mod pipeline;
+pub(crate) mod tests;
+pub mod whitespace_normalizer;
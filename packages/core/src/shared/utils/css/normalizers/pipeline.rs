@@ -0,0 +1,98 @@
+use std::{fmt, sync::Arc};
+
+use swc_core::css::ast::Stylesheet;
+
+use crate::shared::structures::stylex_state_options::StyleXStateOptions;
+
+use super::base::{
+  FontSizeToRemNormalizer, KebabCaseNormalizer, TimingNormalizer, ZeroDimensionNormalizer,
+};
+
+/// A single normalization pass over the parsed `* { property: value }` rule of
+/// a style value.
+pub trait CssNormalizer: Send + Sync {
+  /// Identifies the pass when inserting other passes relative to it.
+  fn name(&self) -> &str;
+
+  fn normalize(&self, ast: Stylesheet, options: &StyleXStateOptions) -> Stylesheet;
+}
+
+/// Ordered list of [`CssNormalizer`] passes applied to every style value.
+///
+/// The default pipeline contains the built-in passes; embedders can add their
+/// own passes through [`StyleXOptions`](crate::shared::structures::stylex_options::StyleXOptions).
+#[derive(Clone)]
+pub struct CssNormalizerPipeline {
+  passes: Vec<Arc<dyn CssNormalizer>>,
+}
+
+impl Default for CssNormalizerPipeline {
+  fn default() -> Self {
+    CssNormalizerPipeline {
+      passes: vec![
+        Arc::new(KebabCaseNormalizer),
+        Arc::new(TimingNormalizer),
+        Arc::new(ZeroDimensionNormalizer),
+        Arc::new(FontSizeToRemNormalizer),
+      ],
+    }
+  }
+}
+
+impl fmt::Debug for CssNormalizerPipeline {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_list().entries(self.names()).finish()
+  }
+}
+
+impl CssNormalizerPipeline {
+  /// Creates a pipeline without the built-in passes.
+  pub fn empty() -> Self {
+    CssNormalizerPipeline { passes: vec![] }
+  }
+
+  pub fn names(&self) -> Vec<&str> {
+    self.passes.iter().map(|pass| pass.name()).collect()
+  }
+
+  pub fn push(mut self, pass: impl CssNormalizer + 'static) -> Self {
+    self.passes.push(Arc::new(pass));
+    self
+  }
+
+  /// Inserts `pass` before the pass named `name`, or appends it when there is
+  /// no such pass.
+  pub fn insert_before(mut self, name: &str, pass: impl CssNormalizer + 'static) -> Self {
+    let index = self.position(name).unwrap_or(self.passes.len());
+
+    self.passes.insert(index, Arc::new(pass));
+    self
+  }
+
+  /// Inserts `pass` after the pass named `name`, or appends it when there is
+  /// no such pass.
+  pub fn insert_after(mut self, name: &str, pass: impl CssNormalizer + 'static) -> Self {
+    let index = self
+      .position(name)
+      .map_or(self.passes.len(), |index| index + 1);
+
+    self.passes.insert(index, Arc::new(pass));
+    self
+  }
+
+  pub fn remove(mut self, name: &str) -> Self {
+    self.passes.retain(|pass| pass.name() != name);
+    self
+  }
+
+  pub fn run(&self, ast: Stylesheet, options: &StyleXStateOptions) -> Stylesheet {
+    self
+      .passes
+      .iter()
+      .fold(ast, |ast, pass| pass.normalize(ast, options))
+  }
+
+  fn position(&self, name: &str) -> Option<usize> {
+    self.passes.iter().position(|pass| pass.name() == name)
+  }
+}
@@ -54,7 +54,7 @@ mod normalizers {
 
     assert_eq!(
       stringify(&base_normalizer(
-        swc_parse_css(r#"* {{ quotes: '""'; }}"#).0.unwrap(),
+        swc_parse_css(r#"* {{ quotes: ''; }}"#).0.unwrap(),
         false
       )),
       r#"*{{quotes:""}}"#
@@ -62,7 +62,7 @@ mod normalizers {
 
     assert_eq!(
       stringify(&base_normalizer(
-        swc_parse_css(r#"* {{ quotes: '"123"'; }}"#).0.unwrap(),
+        swc_parse_css(r#"* {{ quotes: '123'; }}"#).0.unwrap(),
         false
       )),
       r#"*{{quotes:"123"}}"#
@@ -70,7 +70,7 @@ mod normalizers {
 
     assert_eq!(
       stringify(&base_normalizer(
-        swc_parse_css(r#"* {{ gridTemplateAreas: '"content"'; }}"#)
+        swc_parse_css(r#"* {{ gridTemplateAreas: "content"; }}"#)
           .0
           .unwrap(),
         false
@@ -80,12 +80,12 @@ mod normalizers {
 
     assert_eq!(
       stringify(&base_normalizer(
-        swc_parse_css(r#"* {{ gridTemplateAreas: '"content" "sidebar"'; }}"#)
+        swc_parse_css(r#"* {{ gridTemplateAreas: "content" "sidebar"; }}"#)
           .0
           .unwrap(),
         false
       )),
-      r#"*{{gridtemplateareas:"content" "sidebar"}}"#
+      r#"*{{gridtemplateareas:"content""sidebar"}}"#
     );
   }
 }
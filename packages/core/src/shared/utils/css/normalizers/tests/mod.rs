@@ -0,0 +1,3 @@
+mod base;
+mod pipeline;
+mod whitespace_normalizer;
@@ -0,0 +1,183 @@
+#[cfg(test)]
+
+mod normalizer_pipeline {
+  use swc_core::css::{
+    ast::{ComponentValue, Declaration, Stylesheet},
+    visit::{Fold, FoldWith},
+  };
+
+  use crate::shared::{
+    structures::stylex_state_options::StyleXStateOptions,
+    utils::css::{
+      common::{stringify, swc_parse_css},
+      normalizers::{
+        base::{
+          FontSizeToRemNormalizer, KebabCaseNormalizer, TimingNormalizer, ZeroDimensionNormalizer,
+        },
+        pipeline::{CssNormalizer, CssNormalizerPipeline},
+      },
+    },
+  };
+
+  const GOLDEN: [(&str, &str); 8] = [
+    (
+      "* { transitionProperty: marginTop, opacity }",
+      "*{transitionproperty:margin-top,opacity}",
+    ),
+    (
+      "* { willChange: backgroundColor }",
+      "*{willchange:background-color}",
+    ),
+    (
+      "* { transitionDuration: 500ms }",
+      "*{transitionduration:.5s}",
+    ),
+    ("* { transitionDuration: 5ms }", "*{transitionduration:5ms}"),
+    ("* { transitionDelay: 0ms }", "*{transitiondelay:0s}"),
+    ("* { transform: rotate(0rad) }", "*{transform:rotate(0deg)}"),
+    (
+      "* { boxShadow: 0px 2px 4px var(--shadow-1) }",
+      "*{boxshadow:0 2px 4px var(--shadow-1)}",
+    ),
+    ("* { fontSize: 24px }", "*{fontsize:1.5rem}"),
+  ];
+
+  fn options(use_rem_for_font_size: bool) -> StyleXStateOptions {
+    StyleXStateOptions {
+      use_rem_for_font_size,
+      ..StyleXStateOptions::_new()
+    }
+  }
+
+  fn parse(css: &str) -> Stylesheet {
+    swc_parse_css(css).0.unwrap()
+  }
+
+  fn run(pass: &dyn CssNormalizer, css: &str, options: &StyleXStateOptions) -> String {
+    stringify(&pass.normalize(parse(css), options))
+  }
+
+  #[test]
+  fn default_pipeline_matches_golden_output() {
+    let options = options(true);
+
+    for (input, expected) in GOLDEN {
+      assert_eq!(
+        stringify(&options.css_normalizers.run(parse(input), &options)),
+        expected,
+        "input: {}",
+        input
+      );
+    }
+  }
+
+  #[test]
+  fn every_pass_is_idempotent() {
+    let options = options(true);
+    let passes: Vec<&dyn CssNormalizer> = vec![
+      &KebabCaseNormalizer,
+      &TimingNormalizer,
+      &ZeroDimensionNormalizer,
+      &FontSizeToRemNormalizer,
+    ];
+
+    for pass in passes {
+      for (input, _) in GOLDEN {
+        let once = pass.normalize(parse(input), &options);
+        let twice = pass.normalize(once.clone(), &options);
+
+        assert_eq!(
+          stringify(&twice),
+          stringify(&once),
+          "pass {} is not idempotent for: {}",
+          pass.name(),
+          input
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn font_size_stays_in_px_without_use_rem_for_font_size() {
+    assert_eq!(
+      run(
+        &FontSizeToRemNormalizer,
+        "* { fontSize: 24px }",
+        &options(false)
+      ),
+      "*{fontsize:24px}"
+    );
+  }
+
+  struct UppercaseIdents;
+
+  impl Fold for UppercaseIdents {
+    fn fold_declaration(&mut self, mut declaration: Declaration) -> Declaration {
+      for value in declaration.value.iter_mut() {
+        if let ComponentValue::Ident(ident) = value {
+          ident.value = ident.value.to_uppercase().into();
+          ident.raw = None;
+        }
+      }
+
+      declaration
+    }
+  }
+
+  struct UppercaseNormalizer;
+
+  impl CssNormalizer for UppercaseNormalizer {
+    fn name(&self) -> &str {
+      "uppercase"
+    }
+
+    fn normalize(&self, ast: Stylesheet, _: &StyleXStateOptions) -> Stylesheet {
+      ast.fold_with(&mut UppercaseIdents)
+    }
+  }
+
+  #[test]
+  fn inserts_custom_passes_relative_to_built_in_ones() {
+    let pipeline = CssNormalizerPipeline::default()
+      .insert_after(KebabCaseNormalizer::NAME, UppercaseNormalizer)
+      .remove(FontSizeToRemNormalizer::NAME);
+
+    assert_eq!(
+      pipeline.names(),
+      vec!["kebab-case", "uppercase", "timing", "zero-dimension"]
+    );
+
+    let pipeline = pipeline.insert_before(KebabCaseNormalizer::NAME, UppercaseNormalizer);
+
+    assert_eq!(
+      pipeline.names(),
+      vec![
+        "uppercase",
+        "kebab-case",
+        "uppercase",
+        "timing",
+        "zero-dimension"
+      ]
+    );
+  }
+
+  #[test]
+  fn runs_custom_passes_in_order() {
+    let options = options(false);
+    let css = "* { willChange: backgroundColor }";
+
+    let after_kebab =
+      CssNormalizerPipeline::default().insert_after(KebabCaseNormalizer::NAME, UppercaseNormalizer);
+    let before_kebab = CssNormalizerPipeline::default()
+      .insert_before(KebabCaseNormalizer::NAME, UppercaseNormalizer);
+
+    assert_eq!(
+      stringify(&after_kebab.run(parse(css), &options)),
+      "*{willchange:BACKGROUND-COLOR}"
+    );
+    assert_eq!(
+      stringify(&before_kebab.run(parse(css), &options)),
+      "*{willchange:-backgroundcolor}"
+    );
+  }
+}
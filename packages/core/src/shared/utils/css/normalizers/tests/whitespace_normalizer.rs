@@ -0,0 +1,120 @@
+#[cfg(test)]
+
+mod whitespace {
+  use crate::shared::{
+    structures::stylex_state_options::StyleXStateOptions,
+    utils::css::{
+      common::normalize_css_property_value,
+      normalizers::whitespace_normalizer::whitespace_normalizer,
+    },
+  };
+
+  fn normalize(property: &str, value: &str) -> String {
+    normalize_css_property_value(property, value, &StyleXStateOptions::_new())
+  }
+
+  #[test]
+  fn removes_spaces_around_commas() {
+    assert_eq!(
+      normalize("fontFamily", "Arial , 'Helvetica Neue',sans-serif"),
+      "Arial,\"Helvetica Neue\",sans-serif"
+    );
+    assert_eq!(
+      normalize("boxShadow", "0 0 0 1px rgba(0, 0, 0, .1) , 0 2px 4px #000"),
+      "0 0 0 1px rgba(0,0,0,.1),0 2px 4px #000"
+    );
+    assert_eq!(
+      normalize("transition", "opacity 0.3s ease,transform 0.3s ease"),
+      "opacity .3s ease,transform .3s ease"
+    );
+  }
+
+  #[test]
+  fn keeps_gradients_intact() {
+    assert_eq!(
+      normalize(
+        "backgroundImage",
+        "linear-gradient(to right, rgba(0,0,0,0.5) 0%, red 100%)"
+      ),
+      "linear-gradient(to right,rgba(0,0,0,.5) 0%,red 100%)"
+    );
+    assert_eq!(
+      normalize("backgroundImage", "linear-gradient(red,blue), url(a.png)"),
+      "linear-gradient(red,blue),url(a.png)"
+    );
+  }
+
+  #[test]
+  fn keeps_grid_templates_intact() {
+    assert_eq!(
+      normalize("gridTemplateAreas", "\"header header\" \"sidebar main\""),
+      "\"header header\" \"sidebar main\""
+    );
+    assert_eq!(
+      normalize(
+        "gridTemplateColumns",
+        "repeat(2,1fr) [full-start] minmax(1em,1fr)"
+      ),
+      "repeat(2,1fr) [full-start] minmax(1em,1fr)"
+    );
+  }
+
+  #[test]
+  fn keeps_urls_and_strings_verbatim() {
+    assert_eq!(
+      normalize(
+        "backgroundImage",
+        "url(data:image/svg+xml;charset=utf-8,%3Csvg%20xmlns%3D%22http%3A%2F%2Fwww.w3.org%2F2000%2Fsvg%22%2F%3E)"
+      ),
+      "url(data:image/svg+xml;charset=utf-8,%3Csvg%20xmlns%3D%22http%3A%2F%2Fwww.w3.org%2F2000%2Fsvg%22%2F%3E)"
+    );
+    assert_eq!(
+      normalize(
+        "backgroundImage",
+        "url(\"data:image/png;base64,iVBORw0KGgo/AAA*B=\")"
+      ),
+      "url(\"data:image/png;base64,iVBORw0KGgo/AAA*B=\")"
+    );
+    assert_eq!(normalize("maskImage", "url(#mask)"), "url(#mask)");
+    assert_eq!(normalize("content", "\"a , b  c\""), "\"a , b  c\"");
+    assert_eq!(normalize("content", "\"\""), "\"\"");
+    assert_eq!(normalize("content", "\"it's\""), "\"it's\"");
+    assert_eq!(normalize("content", "'say \"hi\"'"), r#""say \"hi\"""#);
+    assert_eq!(
+      normalize(
+        "backgroundImage",
+        "url(\"data:image/svg+xml,<svg xmlns='http://www.w3.org/2000/svg'/>\")"
+      ),
+      "url(\"data:image/svg+xml,<svg xmlns='http://www.w3.org/2000/svg'/>\")"
+    );
+  }
+
+  #[test]
+  fn surrounds_math_signs_with_spaces() {
+    assert_eq!(
+      normalize("width", "calc(100% - 10px*2)"),
+      "calc(100% - 10px * 2)"
+    );
+    assert_eq!(normalize("aspectRatio", "16/9"), "16 / 9");
+  }
+
+  #[test]
+  fn restores_separators_dropped_by_the_minifier() {
+    assert_eq!(
+      whitespace_normalizer("*{transition:opacity.3s ease}".to_string()),
+      "opacity .3s ease"
+    );
+    assert_eq!(
+      whitespace_normalizer("*{border:1px solid#000}".to_string()),
+      "1px solid #000"
+    );
+    assert_eq!(
+      whitespace_normalizer("*{gridTemplateAreas:\"a\"\"b\"}".to_string()),
+      "\"a\" \"b\""
+    );
+    assert_eq!(
+      whitespace_normalizer("*{transform:translate(1px)rotate(2deg)}".to_string()),
+      "translate(1px) rotate(2deg)"
+    );
+  }
+}
@@ -0,0 +1,226 @@
+use crate::shared::regex::CSS_RULE_REGEX;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+  Word,
+  String,
+  Url,
+  Function,
+  OpenParen,
+  CloseParen,
+  OpenBracket,
+  CloseBracket,
+  Comma,
+  Operator,
+  Whitespace,
+}
+
+struct Token<'a> {
+  kind: TokenKind,
+  value: &'a str,
+}
+
+/// Canonicalizes the whitespace of a minified declaration value.
+///
+/// Strings and `url()` contents are kept verbatim. Whitespace is collapsed to a
+/// single space and dropped around commas and inside parentheses and brackets,
+/// `*` and `/` are surrounded by spaces, and the separators the minifier drops
+/// between adjacent values (e.g. `ease.3s` or `"a""b"`) are restored.
+pub fn whitespace_normalizer(result: String) -> String {
+  let css_string: &str = if result.contains('{') {
+    CSS_RULE_REGEX
+      .captures(result.as_str())
+      .unwrap()
+      .get(1)
+      .unwrap()
+      .as_str()
+  } else {
+    result.as_str()
+  };
+
+  let mut normalized_css_string = String::with_capacity(css_string.len());
+  let mut previous: Option<TokenKind> = None;
+  let mut has_whitespace = false;
+
+  for token in tokenize(css_string.trim()) {
+    match token.kind {
+      TokenKind::Whitespace => {
+        has_whitespace = true;
+        continue;
+      }
+      TokenKind::Operator => {
+        normalized_css_string.push(' ');
+        normalized_css_string.push_str(token.value);
+        normalized_css_string.push(' ');
+      }
+      kind => {
+        if previous.is_some_and(|previous| needs_space(previous, kind, has_whitespace)) {
+          normalized_css_string.push(' ');
+        }
+
+        normalized_css_string.push_str(token.value);
+      }
+    }
+
+    previous = Some(token.kind);
+    has_whitespace = false;
+  }
+
+  normalized_css_string.trim().to_string()
+}
+
+fn needs_space(previous: TokenKind, next: TokenKind, has_whitespace: bool) -> bool {
+  use TokenKind::*;
+
+  if matches!(
+    previous,
+    Function | OpenParen | OpenBracket | Comma | Operator
+  ) || matches!(next, CloseParen | CloseBracket | Comma)
+  {
+    return false;
+  }
+
+  has_whitespace
+    || matches!(previous, Word | String | Url | CloseParen | CloseBracket)
+      && matches!(
+        next,
+        Word | String | Url | Function | OpenParen | OpenBracket
+      )
+}
+
+fn tokenize(css: &str) -> Vec<Token> {
+  let bytes = css.as_bytes();
+  let mut tokens = vec![];
+  let mut index = 0;
+
+  while index < bytes.len() {
+    let start = index;
+
+    let kind = match bytes[index] {
+      b'"' | b'\'' => {
+        index = skip_string(bytes, index);
+        TokenKind::String
+      }
+      byte if byte.is_ascii_whitespace() => {
+        while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+          index += 1;
+        }
+        TokenKind::Whitespace
+      }
+      b'(' => {
+        index += 1;
+        TokenKind::OpenParen
+      }
+      b')' => {
+        index += 1;
+        TokenKind::CloseParen
+      }
+      b'[' => {
+        index += 1;
+        TokenKind::OpenBracket
+      }
+      b']' => {
+        index += 1;
+        TokenKind::CloseBracket
+      }
+      b',' => {
+        index += 1;
+        TokenKind::Comma
+      }
+      b'*' | b'/' => {
+        index += 1;
+        TokenKind::Operator
+      }
+      _ => {
+        index = skip_word(bytes, index);
+
+        if bytes.get(index) == Some(&b'(') {
+          if css[start..index].eq_ignore_ascii_case("url") {
+            index = skip_url(bytes, index + 1);
+            TokenKind::Url
+          } else {
+            index += 1;
+            TokenKind::Function
+          }
+        } else {
+          TokenKind::Word
+        }
+      }
+    };
+
+    tokens.push(Token {
+      kind,
+      value: &css[start..index],
+    });
+  }
+
+  tokens
+}
+
+fn skip_word(bytes: &[u8], start: usize) -> usize {
+  let mut index = start;
+
+  while index < bytes.len() {
+    match bytes[index] {
+      b'\\' => {
+        index += 2;
+        continue;
+      }
+      // The minifier drops the space before hashes, fractions like `.3s` and
+      // after percentages
+      b'#' if index > start => break,
+      _ if index > start && bytes[index - 1] == b'%' => break,
+      b'.'
+        if index > start
+          && bytes[index - 1].is_ascii_alphabetic()
+          && bytes.get(index + 1).is_some_and(u8::is_ascii_digit) =>
+      {
+        break
+      }
+      b'"' | b'\'' | b'(' | b')' | b'[' | b']' | b',' | b'*' | b'/' => break,
+      byte if byte.is_ascii_whitespace() => break,
+      _ => {}
+    }
+
+    index += 1;
+  }
+
+  index.min(bytes.len())
+}
+
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+  let quote = bytes[start];
+  let mut index = start + 1;
+
+  while index < bytes.len() {
+    match bytes[index] {
+      b'\\' => index += 1,
+      byte if byte == quote => return index + 1,
+      _ => {}
+    }
+
+    index += 1;
+  }
+
+  bytes.len()
+}
+
+fn skip_url(bytes: &[u8], start: usize) -> usize {
+  let mut index = start;
+
+  while index < bytes.len() {
+    match bytes[index] {
+      b'"' | b'\'' => {
+        index = skip_string(bytes, index);
+        continue;
+      }
+      b'\\' => index += 1,
+      b')' => return index + 1,
+      _ => {}
+    }
+
+    index += 1;
+  }
+
+  bytes.len()
+}
@@ -110,18 +110,23 @@ mod css_tests {
 
     assert_eq!(
       transform_value("quotes", r#""''""#, &StateManager::default()),
+      r#""''""#
+    );
+
+    assert_eq!(
+      transform_value("quotes", "''", &StateManager::default()),
       r#""""#
     );
 
     assert_eq!(
-      transform_value("quotes", r#""'123'""#, &StateManager::default()),
+      transform_value("quotes", "'123'", &StateManager::default()),
       r#""123""#
     );
 
     assert_eq!(
       transform_value(
         "gridTemplateAreas",
-        r#"'"content"'"#,
+        r#""content""#,
         &StateManager::default()
       ),
       r#""content""#
@@ -130,7 +135,7 @@ mod css_tests {
     assert_eq!(
       transform_value(
         "gridTemplateAreas",
-        r#"'"content" "sidebar"'"#,
+        r#""content" "sidebar""#,
         &StateManager::default()
       ),
       r#""content" "sidebar""#
@@ -139,7 +144,7 @@ mod css_tests {
     assert_eq!(
       transform_value(
         "gridTemplateAreas",
-        r#"'"content""sidebar"'"#,
+        r#""content""sidebar""#,
         &StateManager::default()
       ),
       r#""content" "sidebar""#
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod common_css_tests {
+  use crate::shared::{
+    structures::state_manager::StateManager,
+    utils::css::common::{
+      generate_css_rule, get_number_suffix, order_pseudos, scope_selectors_to_host, transform_value,
+    },
+  };
+
+  #[test]
+  fn should_transform_css_property_value() {
+    assert_eq!(
+      transform_value("padding", "1", &StateManager::default()),
+      "1px"
+    );
+  }
+
+  #[test]
+  fn should_scope_selectors_to_shadow_host() {
+    assert_eq!(
+      scope_selectors_to_host(".x1::-webkit-slider-thumb, .x1::-moz-range-thumb", ":host"),
+      ":host .x1::-webkit-slider-thumb, :host .x1::-moz-range-thumb"
+    );
+    assert_eq!(
+      scope_selectors_to_host(":root", ":host-context(.dark)"),
+      ":host-context(.dark)"
+    );
+  }
+
+  #[test]
+  fn should_order_pseudo_classes_before_pseudo_elements() {
+    assert_eq!(
+      order_pseudos(&["::before", ":hover"]),
+      vec![":hover", "::before"]
+    );
+    assert_eq!(
+      order_pseudos(&[":hover", "::after"]),
+      vec![":hover", "::after"]
+    );
+    assert_eq!(
+      order_pseudos(&["::placeholder", ":focus", ":hover"]),
+      vec![":focus", ":hover", "::placeholder"]
+    );
+  }
+
+  #[test]
+  fn should_keep_pseudo_classes_of_stateful_pseudo_elements() {
+    assert_eq!(
+      order_pseudos(&["::-webkit-scrollbar-thumb", ":hover"]),
+      vec!["::-webkit-scrollbar-thumb", ":hover"]
+    );
+    assert_eq!(
+      order_pseudos(&[":focus", "::part(label)", ":hover"]),
+      vec![":focus", "::part(label)", ":hover"]
+    );
+  }
+
+  #[test]
+  fn should_generate_pseudo_classes_nested_in_pseudo_elements() {
+    let rule = |pseudos: &mut [String]| {
+      generate_css_rule("x1", "color:red".to_string(), pseudos, &mut [], None)
+    };
+
+    assert_eq!(
+      rule(&mut ["::before".to_string(), ":hover".to_string()]),
+      ".x1:hover::before{color:red}"
+    );
+    assert_eq!(
+      rule(&mut [":hover".to_string(), "::before".to_string()]),
+      ".x1:hover::before{color:red}"
+    );
+  }
+
+  #[test]
+  fn should_add_suffix_to_calc_sum_terms() {
+    assert_eq!(
+      transform_value("width", "calc(var(--x) + 4)", &StateManager::default()),
+      "calc(var(--x) + 4px)"
+    );
+    assert_eq!(
+      transform_value("width", "calc(100 - var(--x))", &StateManager::default()),
+      "calc(100px - var(--x))"
+    );
+    assert_eq!(
+      transform_value("width", "calc(var(--x) * 2)", &StateManager::default()),
+      "calc(var(--x) * 2)"
+    );
+    assert_eq!(
+      transform_value("opacity", "calc(var(--x) + 1)", &StateManager::default()),
+      "calc(var(--x) + 1)"
+    );
+  }
+
+  #[test]
+  fn should_return_correct_suffix() {
+    assert_eq!(get_number_suffix("padding"), "px");
+    assert_eq!(get_number_suffix("opacity"), "");
+    assert_eq!(get_number_suffix("voiceDuration"), "ms");
+  }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod css_type_initial_value {
+  use crate::shared::{
+    enums::data_structures::css_syntax::CSSSyntax,
+    utils::css::validators::css_type_initial_value::validate_css_type_initial_value,
+  };
+
+  #[test]
+  fn accepts_initial_values_matching_their_syntax() {
+    let cases = [
+      (CSSSyntax::Color, "blue"),
+      (CSSSyntax::Color, "#fff"),
+      (CSSSyntax::Color, "rgba(0, 0, 0, 0.8)"),
+      (CSSSyntax::Length, "10px"),
+      (CSSSyntax::Length, "0"),
+      (CSSSyntax::Length, "calc(1rem + 2px)"),
+      (CSSSyntax::LengthPercentage, "50%"),
+      (CSSSyntax::Percentage, "50%"),
+      (CSSSyntax::Number, "1.5"),
+      (CSSSyntax::Integer, "3"),
+      (CSSSyntax::Angle, "45deg"),
+      (CSSSyntax::Time, "500ms"),
+      (CSSSyntax::Resolution, "2dppx"),
+      (CSSSyntax::Url, "url(image.png)"),
+      (CSSSyntax::Image, "linear-gradient(red, blue)"),
+      (CSSSyntax::TransformFunction, "rotate(45deg)"),
+      (CSSSyntax::TransformList, "translateX(10px) scale(2)"),
+    ];
+
+    for (syntax, initial_value) in cases {
+      validate_css_type_initial_value("var", syntax, initial_value);
+    }
+  }
+
+  #[test]
+  #[should_panic(
+    expected = r#"The initial value of a typed variable must match its syntax: "color" is declared as <color> but its initial value "10px" is invalid at 0..4 ("10px")."#
+  )]
+  fn disallows_initial_value_of_another_type() {
+    validate_css_type_initial_value("color", CSSSyntax::Color, "10px");
+  }
+
+  #[test]
+  #[should_panic(expected = r#"is invalid at 4..7 ("2px")."#)]
+  fn disallows_multiple_values_for_single_value_syntax() {
+    validate_css_type_initial_value("size", CSSSyntax::Length, "1px 2px");
+  }
+
+  #[test]
+  #[should_panic(expected = r#"is invalid at 14..18 ("blue")."#)]
+  fn disallows_non_transform_functions_in_transform_list() {
+    validate_css_type_initial_value("transform", CSSSyntax::TransformList, "rotate(45deg) blue");
+  }
+
+  #[test]
+  #[should_panic(expected = r#"is declared as <integer> but its initial value "1.5""#)]
+  fn disallows_fractional_integers() {
+    validate_css_type_initial_value("count", CSSSyntax::Integer, "1.5");
+  }
+
+  #[test]
+  #[should_panic(expected = r#"is declared as <length> but its initial value "var(--x)""#)]
+  fn disallows_computationally_dependent_values() {
+    validate_css_type_initial_value("size", CSSSyntax::Length, "var(--x)");
+  }
+}
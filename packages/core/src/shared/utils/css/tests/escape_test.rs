@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod escape_css_identifiers {
+  use crate::shared::utils::{
+    common::dashify,
+    css::escape::{escape_css_ident, is_css_ident_char},
+  };
+
+  #[test]
+  fn keeps_valid_identifiers() {
+    assert_eq!(escape_css_ident("--myVar"), "--myVar");
+    assert_eq!(escape_css_ident("--Ñame"), "--Ñame");
+    assert_eq!(escape_css_ident("ключ"), "ключ");
+    assert_eq!(escape_css_ident("😀-_a1"), "😀-_a1");
+  }
+
+  #[test]
+  fn escapes_invalid_characters() {
+    assert_eq!(escape_css_ident("--my var"), "--my\\ var");
+    assert_eq!(escape_css_ident("a.b:c"), "a\\.b\\:c");
+    assert_eq!(escape_css_ident("a\u{1}b"), "a\\1 b");
+    assert_eq!(escape_css_ident("a\0b"), "a\u{FFFD}b");
+  }
+
+  #[test]
+  fn escapes_leading_digits() {
+    assert_eq!(escape_css_ident("1a"), "\\31 a");
+    assert_eq!(escape_css_ident("-1a"), "-\\31 a");
+    assert_eq!(escape_css_ident("--1a"), "--1a");
+    assert_eq!(escape_css_ident("-"), "\\-");
+  }
+
+  #[test]
+  fn keeps_existing_escapes() {
+    assert_eq!(escape_css_ident("--\\31 x"), "--\\31 x");
+    assert_eq!(escape_css_ident("caf\\e9"), "caf\\e9");
+    assert_eq!(escape_css_ident("a\\.b"), "a\\.b");
+    assert_eq!(escape_css_ident("a\\"), "a\\\\");
+    assert_eq!(
+      escape_css_ident(&escape_css_ident("--my var")),
+      "--my\\ var"
+    );
+  }
+
+  #[test]
+  fn identifier_chars() {
+    assert!(is_css_ident_char('a'));
+    assert!(is_css_ident_char('ñ'));
+    assert!(is_css_ident_char('😀'));
+    assert!(!is_css_ident_char(' '));
+    assert!(!is_css_ident_char('.'));
+  }
+
+  #[test]
+  fn dashify_keeps_custom_properties() {
+    assert_eq!(dashify("backgroundColor"), "background-color");
+    assert_eq!(dashify("--myVar"), "--myVar");
+    assert_eq!(dashify("--Ñame"), "--Ñame");
+  }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod css_tests {
+  use crate::shared::{
+    structures::state_manager::StateManager, utils::css::common::transform_value,
+  };
+
+  #[test]
+  fn allow_light_dark_colors() {
+    assert_eq!(
+      transform_value(
+        "color",
+        "light-dark(white, black)",
+        &StateManager::default()
+      ),
+      "light-dark(white,black)"
+    );
+    assert_eq!(
+      transform_value(
+        "backgroundColor",
+        "light-dark(rgb(255, 255, 255), var(--dark))",
+        &StateManager::default()
+      ),
+      "light-dark(rgb(255,255,255),var(--dark))"
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "light-dark() takes exactly two colors")]
+  fn disallow_light_dark_with_one_color() {
+    transform_value("color", "light-dark(white)", &StateManager::default());
+  }
+
+  #[test]
+  #[should_panic(expected = "light-dark() takes exactly two colors")]
+  fn disallow_light_dark_with_three_colors() {
+    transform_value(
+      "color",
+      "light-dark(white, gray, black)",
+      &StateManager::default(),
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "light-dark() takes exactly two colors")]
+  fn disallow_light_dark_with_an_empty_color() {
+    transform_value(
+      "borderColor",
+      "light-dark(, black)",
+      &StateManager::default(),
+    );
+  }
+
+  #[test]
+  fn ignore_light_dark_in_strings() {
+    assert_eq!(
+      transform_value(
+        "gridTemplateAreas",
+        "\"light-dark(x)\"",
+        &StateManager::default()
+      ),
+      "\"light-dark(x)\""
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "light-dark() takes exactly two colors")]
+  fn disallow_nested_light_dark_with_one_color() {
+    transform_value(
+      "boxShadow",
+      "0 0 4px LIGHT-DARK(rgb(0 0 0 / 0.2))",
+      &StateManager::default(),
+    );
+  }
+}
@@ -0,0 +1,6 @@
+pub mod css_custom_properties_validation_test;
+pub mod css_tests;
+pub mod css_type_initial_value_test;
+pub mod escape_test;
+pub mod light_dark_function_test;
+pub mod split_value_test;
@@ -0,0 +1,211 @@
+use swc_core::{
+  common::{Span, Spanned},
+  css::ast::{ComponentValue, Declaration, Dimension, Function, FunctionName, Rule},
+};
+
+use crate::shared::{
+  constants::messages::INVALID_CSS_TYPE_INITIAL_VALUE,
+  enums::data_structures::css_syntax::CSSSyntax,
+  utils::css::common::{get_value_from_ident, swc_parse_css},
+};
+
+const DECLARATION_PREFIX: &str = "* { color: ";
+
+const MATH_FUNCTIONS: [&str; 4] = ["calc", "min", "max", "clamp"];
+
+const CSS_WIDE_KEYWORDS: [&str; 5] = ["inherit", "initial", "unset", "revert", "revert-layer"];
+
+const IMAGE_FUNCTIONS: [&str; 4] = ["image", "image-set", "cross-fade", "element"];
+
+const TRANSFORM_FUNCTIONS: [&str; 21] = [
+  "matrix",
+  "matrix3d",
+  "perspective",
+  "rotate",
+  "rotate3d",
+  "rotateX",
+  "rotateY",
+  "rotateZ",
+  "scale",
+  "scale3d",
+  "scaleX",
+  "scaleY",
+  "scaleZ",
+  "skew",
+  "skewX",
+  "skewY",
+  "translate",
+  "translate3d",
+  "translateX",
+  "translateY",
+  "translateZ",
+];
+
+/// Panics unless `initial_value` parses under `syntax`, as the
+/// `initial-value` of the `@property` rule generated for the typed var `name`
+/// would otherwise be dropped by the browser.
+///
+/// The message points at the offending part of the value by its byte range.
+pub fn validate_css_type_initial_value(name: &str, syntax: CSSSyntax, initial_value: &str) {
+  let Some(values) = parse_value(initial_value) else {
+    panic_with_range(name, syntax, initial_value, 0..initial_value.len());
+  };
+
+  if let Some(span) = find_invalid_component(syntax, &values) {
+    let offset = DECLARATION_PREFIX.len();
+    let start = (span.lo.0 as usize).saturating_sub(offset);
+    let end = (span.hi.0 as usize).saturating_sub(offset);
+
+    panic_with_range(name, syntax, initial_value, start..end);
+  }
+}
+
+fn panic_with_range(
+  name: &str,
+  syntax: CSSSyntax,
+  initial_value: &str,
+  range: std::ops::Range<usize>,
+) -> ! {
+  let part = initial_value.get(range.clone()).unwrap_or(initial_value);
+
+  panic!(
+    r#"{} "{}" is declared as {} but its initial value {:?} is invalid at {}..{} ({:?})."#,
+    INVALID_CSS_TYPE_INITIAL_VALUE, name, syntax, initial_value, range.start, range.end, part
+  )
+}
+
+fn parse_value(value: &str) -> Option<Vec<ComponentValue>> {
+  let css = format!("{}{} }}", DECLARATION_PREFIX, value);
+
+  let (result, errors) = swc_parse_css(css.as_str());
+
+  if !errors.is_empty() {
+    return None;
+  }
+
+  let stylesheet = result.ok()?;
+
+  let Some(Rule::QualifiedRule(rule)) = stylesheet.rules.first() else {
+    return None;
+  };
+
+  match rule.block.value.as_slice() {
+    [ComponentValue::Declaration(declaration)] => {
+      let Declaration { value, .. } = declaration.as_ref();
+
+      Some(value.clone())
+    }
+    _ => None,
+  }
+}
+
+/// Returns the span of the first component not allowed by `syntax`.
+fn find_invalid_component(syntax: CSSSyntax, values: &[ComponentValue]) -> Option<Span> {
+  let Some(first) = values.first() else {
+    return Some(Span::default());
+  };
+
+  if syntax == CSSSyntax::TransformList {
+    return values
+      .iter()
+      .find(|value| !is_valid_component(CSSSyntax::TransformFunction, value))
+      .map(|value| value.span());
+  }
+
+  if !is_valid_component(syntax, first) {
+    return Some(first.span());
+  }
+
+  values.get(1).map(|value| value.span())
+}
+
+fn is_valid_component(syntax: CSSSyntax, value: &ComponentValue) -> bool {
+  if let ComponentValue::Function(function) = value {
+    if is_math_function(function) && is_numeric_syntax(syntax) {
+      return true;
+    }
+  }
+
+  match syntax {
+    CSSSyntax::Color => match value {
+      ComponentValue::Color(_) => true,
+      ComponentValue::Ident(ident) => {
+        !CSS_WIDE_KEYWORDS.contains(&get_value_from_ident(ident).to_lowercase().as_str())
+      }
+      _ => false,
+    },
+    CSSSyntax::Length => is_length(value),
+    CSSSyntax::LengthPercentage => {
+      is_length(value) || matches!(value, ComponentValue::Percentage(_))
+    }
+    CSSSyntax::Percentage => matches!(value, ComponentValue::Percentage(_)),
+    CSSSyntax::Number => matches!(
+      value,
+      ComponentValue::Number(_) | ComponentValue::Integer(_)
+    ),
+    CSSSyntax::Integer => matches!(value, ComponentValue::Integer(_)),
+    CSSSyntax::Angle => {
+      matches!(value, ComponentValue::Dimension(dimension) if matches!(dimension.as_ref(), Dimension::Angle(_)))
+    }
+    CSSSyntax::Time => {
+      matches!(value, ComponentValue::Dimension(dimension) if matches!(dimension.as_ref(), Dimension::Time(_)))
+    }
+    CSSSyntax::Resolution => {
+      matches!(value, ComponentValue::Dimension(dimension) if matches!(dimension.as_ref(), Dimension::Resolution(_)))
+    }
+    CSSSyntax::Url => matches!(value, ComponentValue::Url(_)),
+    CSSSyntax::Image => match value {
+      ComponentValue::Url(_) => true,
+      ComponentValue::Function(function) => {
+        let name = function_name(function);
+
+        name.ends_with("gradient") || IMAGE_FUNCTIONS.contains(&name.as_str())
+      }
+      _ => false,
+    },
+    CSSSyntax::TransformFunction | CSSSyntax::TransformList => match value {
+      ComponentValue::Function(function) => {
+        let name = function_name(function);
+
+        TRANSFORM_FUNCTIONS
+          .iter()
+          .any(|transform| transform.eq_ignore_ascii_case(&name))
+      }
+      _ => false,
+    },
+  }
+}
+
+fn is_length(value: &ComponentValue) -> bool {
+  match value {
+    ComponentValue::Dimension(dimension) => matches!(dimension.as_ref(), Dimension::Length(_)),
+    ComponentValue::Integer(integer) => integer.value == 0,
+    ComponentValue::Number(number) => number.value == 0.0,
+    _ => false,
+  }
+}
+
+fn is_numeric_syntax(syntax: CSSSyntax) -> bool {
+  matches!(
+    syntax,
+    CSSSyntax::Length
+      | CSSSyntax::LengthPercentage
+      | CSSSyntax::Percentage
+      | CSSSyntax::Number
+      | CSSSyntax::Integer
+      | CSSSyntax::Angle
+      | CSSSyntax::Time
+      | CSSSyntax::Resolution
+  )
+}
+
+fn is_math_function(function: &Function) -> bool {
+  MATH_FUNCTIONS.contains(&function_name(function).as_str())
+}
+
+fn function_name(function: &Function) -> String {
+  match &function.name {
+    FunctionName::Ident(ident) => get_value_from_ident(ident).to_lowercase(),
+    FunctionName::DashedIdent(ident) => ident.value.to_string(),
+  }
+}
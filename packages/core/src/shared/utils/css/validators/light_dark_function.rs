@@ -0,0 +1,75 @@
+use swc_core::css::{
+  ast::{ComponentValue, Delimiter, DelimiterValue, Function, FunctionName, Stylesheet, Token},
+  visit::{Visit, VisitWith},
+};
+
+use crate::shared::constants::messages::INVALID_LIGHT_DARK_FUNCTION;
+
+const LIGHT_DARK_FUNCTION: &str = "light-dark";
+
+/// Panics on a `light-dark()` function of the parsed `ast` of `value` that
+/// doesn't take exactly a light and a dark color, e.g. `light-dark(white)`.
+/// Strings and custom functions mentioning it, e.g. `content: "light-dark()"`,
+/// are not calls and are left alone.
+pub fn light_dark_function_validator(ast: &Stylesheet, value: &str) {
+  ast.visit_with(&mut LightDarkFunctionValidator { value });
+}
+
+struct LightDarkFunctionValidator<'a> {
+  value: &'a str,
+}
+
+impl Visit for LightDarkFunctionValidator<'_> {
+  fn visit_function(&mut self, function: &Function) {
+    if is_light_dark_function(function) {
+      let mut args = vec![0];
+
+      for component in &function.value {
+        if is_comma(component) {
+          args.push(0);
+        } else if !is_whitespace(component) {
+          if let Some(arg) = args.last_mut() {
+            *arg += 1;
+          }
+        }
+      }
+
+      assert!(
+        args.len() == 2 && args.iter().all(|arg| *arg > 0),
+        "{} Found: {}",
+        INVALID_LIGHT_DARK_FUNCTION,
+        self.value
+      );
+    }
+
+    function.visit_children_with(self);
+  }
+}
+
+fn is_light_dark_function(function: &Function) -> bool {
+  match &function.name {
+    FunctionName::Ident(ident) => ident.value.eq_ignore_ascii_case(LIGHT_DARK_FUNCTION),
+    _ => false,
+  }
+}
+
+fn is_comma(component: &ComponentValue) -> bool {
+  match component {
+    ComponentValue::Delimiter(delimiter) => matches!(
+      delimiter.as_ref(),
+      Delimiter {
+        value: DelimiterValue::Comma,
+        ..
+      }
+    ),
+    ComponentValue::PreservedToken(token) => matches!(token.token, Token::Comma),
+    _ => false,
+  }
+}
+
+fn is_whitespace(component: &ComponentValue) -> bool {
+  match component {
+    ComponentValue::PreservedToken(token) => matches!(token.token, Token::WhiteSpace { .. }),
+    _ => false,
+  }
+}
@@ -0,0 +1,3 @@
+pub mod css_type_initial_value;
+pub mod light_dark_function;
+pub mod unprefixed_custom_properties;
@@ -34,7 +34,7 @@ fn process_qualified_rule(qualified_rule: &QualifiedRule) {
   }
 }
 
-pub(crate) fn unprefixed_custom_properties_validator(ast: Stylesheet) {
+pub fn unprefixed_custom_properties_validator(ast: Stylesheet) {
   for rule in ast.rules.iter() {
     if let Rule::QualifiedRule(qualified_rule) = rule {
       process_qualified_rule(qualified_rule);
@@ -0,0 +1,66 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use convert_case::{Case, Casing};
+use swc_core::atoms::Atom;
+
+use super::{
+  common::{create_hash, dashify},
+  css::escape::escape_css_ident,
+};
+
+// Property keys and hash inputs repeat across every namespace of a build,
+// so the derived strings are computed once per thread and shared as atoms.
+// A long-lived dev server keeps the same threads across builds, so each map
+// is dropped once it holds `MAX_INTERNED` strings.
+const MAX_INTERNED: usize = 10_000;
+
+thread_local! {
+  static DASHED_KEYS: RefCell<HashMap<Atom, Atom>> = RefCell::new(HashMap::new());
+  static HASHES: RefCell<HashMap<Atom, Atom>> = RefCell::new(HashMap::new());
+}
+
+/// Returns the kebab-cased CSS property name for a style key.
+pub fn intern_dashed_key(key: &str) -> Atom {
+  if key.starts_with("--") {
+    return Atom::from(escape_css_ident(key));
+  }
+
+  DASHED_KEYS.with(|keys| {
+    get_or_intern(&mut keys.borrow_mut(), key, || {
+      dashify(key).to_case(Case::Kebab)
+    })
+  })
+}
+
+/// Returns `create_hash(value)`, reusing the result of previous calls.
+pub fn intern_hash(value: &str) -> Atom {
+  HASHES.with(|hashes| get_or_intern(&mut hashes.borrow_mut(), value, || create_hash(value)))
+}
+
+fn get_or_intern(
+  interned: &mut HashMap<Atom, Atom>,
+  key: &str,
+  derive: impl FnOnce() -> String,
+) -> Atom {
+  let key = Atom::from(key);
+
+  if let Some(value) = interned.get(&key) {
+    return value.clone();
+  }
+
+  if interned.len() >= MAX_INTERNED {
+    interned.clear();
+  }
+
+  let value = Atom::from(derive());
+
+  interned.insert(key, value.clone());
+
+  value
+}
+
+/// Drops every interned string of the current thread.
+pub fn clear_interned() {
+  DASHED_KEYS.with(|keys| keys.borrow_mut().clear());
+  HASHES.with(|hashes| hashes.borrow_mut().clear());
+}
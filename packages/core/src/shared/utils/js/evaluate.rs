@@ -10,9 +10,10 @@ use swc_core::{
   common::{EqIgnoreSpan, DUMMY_SP},
   ecma::{
     ast::{
-      ArrayLit, BlockStmtOrExpr, Callee, ComputedPropName, Expr, ExprOrSpread, Ident, KeyValueProp,
-      Lit, MemberProp, ModuleExportName, Number, ObjectLit, Prop, PropName, PropOrSpread,
-      TplElement, VarDeclarator,
+      ArrayLit, BlockStmtOrExpr, CallExpr, Callee, ComputedPropName, Expr, ExprOrSpread, Ident,
+      KeyValueProp, Lit, MemberExpr, MemberProp, Number, ObjectLit, ParenExpr, Prop, PropName,
+      PropOrSpread, TplElement, TsAsExpr, TsConstAssertion, TsNonNullExpr, TsSatisfiesExpr,
+      TsTypeAssertion, UnaryExpr, UnaryOp, VarDeclarator,
     },
     utils::{drop_span, ident::IdentLike, ExprExt},
   },
@@ -20,8 +21,11 @@ use swc_core::{
 
 use crate::shared::{
   constants::{
-    common::{INVALID_METHODS, VALID_CALLEES},
-    messages::{BUILT_IN_FUNCTION, ILLEGAL_PROP_ARRAY_VALUE},
+    common::{DEFAULT_EXPORT_NAME, INVALID_METHODS, MAX_ARRAY_LIKE_LENGTH, VALID_CALLEES},
+    messages::{
+      ARRAY_FROM_WITHOUT_SOURCE, BUILT_IN_FUNCTION, DYNAMIC_IMPORT, ILLEGAL_PROP_ARRAY_VALUE,
+      INVALID_UNIT_HELPER_CALL, NON_STATIC_VALUE, UNSUPPORTED_EVALUATE_RESULT,
+    },
   },
   enums::{
     data_structures::{
@@ -35,33 +39,38 @@ use crate::shared::{
   structures::{
     evaluate_result::EvaluateResult,
     functions::{CallbackType, FunctionConfig, FunctionConfigType, FunctionMap, FunctionType},
+    macro_module::MacroFunction,
     named_import_source::ImportSources,
+    quantity::Quantity,
     seen_value::SeenValue,
     state::EvaluationState,
-    state_manager::{add_import_expression, StateManager},
+    state_manager::StateManager,
     theme_ref::ThemeRef,
     types::{FunctionMapIdentifiers, FunctionMapMemberExpression},
   },
   utils::{
     ast::{
       convertors::{
-        big_int_to_expression, binary_expr_to_num, expr_to_num, expr_to_str, number_to_expression,
-        string_to_expression, transform_shorthand_to_key_values,
+        big_int_to_expression, binary_expr_to_value, bool_to_expression, expr_to_num, expr_to_str,
+        ident_to_expression, number_to_expression, string_to_expression, string_to_prop_name,
+        transform_shorthand_to_key_values,
       },
       factories::{
-        array_expression_factory, ident_name_factory, lit_str_factory, object_expression_factory,
+        array_expression_factory, ident_factory, ident_name_factory, lit_str_factory,
+        object_expression_factory,
       },
     },
     common::{
-      char_code_at, deep_merge_props, get_import_by_ident, get_key_str, get_string_val_from_lit,
-      get_var_decl_by_ident, get_var_decl_from, normalize_expr, remove_duplicates,
-      sort_numbers_factory,
+      assert_finite_number, char_code_at, deep_merge_props, get_dynamic_import_source,
+      get_global_number, get_import_by_ident, get_import_from, get_imported_name, get_key_str,
+      get_process_env_name, get_string_val_from_lit, get_var_decl_by_ident, get_var_decl_from,
+      normalize_arrow_body, normalize_expr, remove_duplicates, sort_numbers_factory, unwrap_expr,
     },
     js::native_functions::{evaluate_filter, evaluate_join, evaluate_map},
   },
 };
 
-pub(crate) fn evaluate_obj_key(
+pub fn evaluate_obj_key(
   prop_kv: &KeyValueProp,
   state: &mut StateManager,
   functions: &FunctionMap,
@@ -146,7 +155,15 @@ pub fn evaluate(
   })
 }
 
-fn deopt(path: &Expr, state: &mut EvaluationState) -> Option<Box<EvaluateResultValue>> {
+fn panic_dynamic_import(source: &str) -> ! {
+  if source.is_empty() {
+    panic!("{} import(...)", DYNAMIC_IMPORT)
+  }
+
+  panic!("{} import('{}')", DYNAMIC_IMPORT, source)
+}
+
+pub fn deopt(path: &Expr, state: &mut EvaluationState) -> Option<Box<EvaluateResultValue>> {
   if state.confident {
     state.confident = false;
     state.deopt_path = Some(Box::new(path.clone()));
@@ -166,9 +183,13 @@ fn _evaluate(
 
   let path = normalize_expr(path);
 
+  if let Some(source) = get_dynamic_import_source(path) {
+    panic_dynamic_import(&source);
+  }
+
   let result: Option<Box<EvaluateResultValue>> = match path {
     Expr::Arrow(arrow) => {
-      let body = arrow.body.clone();
+      let body = Box::new(normalize_arrow_body(&arrow.body));
       let params = arrow.params.clone();
 
       let ident_params = params
@@ -235,15 +256,12 @@ fn _evaluate(
 
                   let value = result.value;
 
-                  let expr = match value {
-                    Some(res) => res
-                      .as_expr()
-                      .expect("Evaluation result must be an expression")
-                      .clone(),
-                    None => unreachable!("Evaluation result must be non optional"),
-                  };
-
-                  expr
+                  match value {
+                    Some(res) => {
+                      result_to_expr(&res).expect("Evaluation result must be an expression")
+                    }
+                    None => panic!("{}", NON_STATIC_VALUE),
+                  }
                 }
               };
 
@@ -288,29 +306,54 @@ fn _evaluate(
 
       None
     }
-    Expr::TsAs(_) => unimplemented!("TsAs"),
-    Expr::TsSatisfies(_) => unimplemented!("TsSatisfies"),
-    Expr::Seq(_) => unimplemented!("Seq"),
+    Expr::Seq(_) => deopt(path, state),
     Expr::Lit(lit_path) => Some(Box::new(EvaluateResultValue::Expr(Box::new(Expr::Lit(
       lit_path.clone(),
     ))))),
     Expr::Tpl(tpl) => evaluate_quasis(&Expr::Tpl(tpl.clone()), &tpl.quasis, false, state, fns),
-    #[allow(dead_code)]
-    Expr::TaggedTpl(_tagged_tpl) => {
-      unimplemented!("TaggedTpl");
-      // TODO: Uncomment this for implementation of TaggedTpl
-      // evaluate_quasis(
-      //   &Expr::TaggedTpl(_tagged_tpl.clone()),
-      //   &_tagged_tpl.tpl.quasis,
-      //   false,
-      //   state,
-      // )
+    Expr::TaggedTpl(tagged_tpl) => {
+      if is_string_raw(&tagged_tpl.tag) {
+        evaluate_quasis(
+          &Expr::TaggedTpl(tagged_tpl.clone()),
+          &tagged_tpl.tpl.quasis,
+          true,
+          state,
+          fns,
+        )
+      } else {
+        deopt(path, state)
+      }
     }
-    Expr::Cond(_) => unimplemented!("Cond"),
-    Expr::Paren(_) => {
-      panic!("Paren must be normalized before evaluation")
+    Expr::Cond(cond) => {
+      let test = evaluate_cached(&cond.test, state, fns);
+
+      if !state.confident {
+        return None;
+      }
+
+      let Some(test) = test.as_deref().and_then(is_truthy) else {
+        return deopt(&cond.test, state);
+      };
+
+      if test {
+        evaluate_cached(&cond.cons, state, fns)
+      } else {
+        evaluate_cached(&cond.alt, state, fns)
+      }
     }
+    Expr::Paren(ParenExpr { expr, .. })
+    | Expr::TsAs(TsAsExpr { expr, .. })
+    | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+    | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+    | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. })
+    | Expr::TsNonNull(TsNonNullExpr { expr, .. }) => evaluate_cached(expr, state, fns),
     Expr::Member(member) => {
+      if let Some((name, process)) = get_process_env_name(member) {
+        if let Some(value) = get_env_value(&name, process, &state.traversal_state) {
+          return Some(Box::new(EvaluateResultValue::Expr(Box::new(value))));
+        }
+      }
+
       let parent_is_call_expr = state
         .traversal_state
         .all_call_expressions
@@ -361,42 +404,49 @@ fn _evaluate(
           EvaluateResultValue::Expr(expr) => match expr.as_ref() {
             Expr::Array(ArrayLit { elems, .. }) => {
               let Some(eval_res) = propery else {
-                panic!("Property not found");
+                return deopt(path, state);
               };
 
               let EvaluateResultValue::Expr(expr) = eval_res.as_ref() else {
-                panic!("Property not found");
+                return deopt(path, state);
               };
 
               let Expr::Lit(Lit::Num(Number { value, .. })) = *expr.as_expr() else {
-                panic!("Member not found");
+                return deopt(path, state);
               };
 
               let property = elems.get(value as usize)?;
 
               let Some(ExprOrSpread { expr, .. }) = property else {
-                panic!("Member not found");
+                return deopt(path, state);
               };
 
               Some(Box::new(EvaluateResultValue::Expr(expr.clone())))
             }
             Expr::Object(ObjectLit { props, .. }) => {
               let Some(eval_res) = propery else {
-                panic!("Property not found");
+                return deopt(path, state);
               };
 
               let EvaluateResultValue::Expr(ident) = eval_res.as_ref() else {
-                panic!("Property not found");
+                return deopt(path, state);
               };
 
               let Expr::Ident(ident) = ident.as_expr().clone() else {
-                panic!("Member not found");
+                return deopt(path, state);
               };
 
+              let has_only_key_values = props.iter().all(|prop| match prop {
+                PropOrSpread::Spread(_) => false,
+                PropOrSpread::Prop(prop) => prop.is_key_value() || prop.is_shorthand(),
+              });
+
+              if !has_only_key_values {
+                return deopt(path, state);
+              }
+
               let property = props.iter().find(|prop| match prop {
-                PropOrSpread::Spread(_) => {
-                  unimplemented!("Spread");
-                }
+                PropOrSpread::Spread(_) => false,
                 PropOrSpread::Prop(prop) => {
                   let mut prop = prop.clone();
 
@@ -408,7 +458,7 @@ fn _evaluate(
 
                       ident.sym == key
                     }
-                    _ => unimplemented!("Prop"),
+                    _ => false,
                   }
                 }
               })?;
@@ -425,7 +475,7 @@ fn _evaluate(
                 panic!("Member not found");
               }
             }
-            _ => unimplemented!("Expression"),
+            _ => deopt(path, state),
           },
           EvaluateResultValue::FunctionConfigMap(fc_map) => {
             let key = match propery {
@@ -434,7 +484,15 @@ fn _evaluate(
                   Expr::Ident(ident) => Box::new(ident.clone()),
                   _ => panic!("Member not found"),
                 },
-                _ => unimplemented!(),
+                value @ (EvaluateResultValue::Vec(_)
+                | EvaluateResultValue::Map(_)
+                | EvaluateResultValue::Entries(_)
+                | EvaluateResultValue::Callback(_)
+                | EvaluateResultValue::FunctionConfig(_)
+                | EvaluateResultValue::FunctionConfigMap(_)
+                | EvaluateResultValue::ThemeRef(_)) => {
+                  panic!("{} {}", UNSUPPORTED_EVALUATE_RESULT, value.kind())
+                }
               },
               None => panic!("Member not found"),
             };
@@ -455,7 +513,15 @@ fn _evaluate(
                     panic!("Member not found")
                   }
                 },
-                _ => unimplemented!(),
+                value @ (EvaluateResultValue::Vec(_)
+                | EvaluateResultValue::Map(_)
+                | EvaluateResultValue::Entries(_)
+                | EvaluateResultValue::Callback(_)
+                | EvaluateResultValue::FunctionConfig(_)
+                | EvaluateResultValue::FunctionConfigMap(_)
+                | EvaluateResultValue::ThemeRef(_)) => {
+                  panic!("{} {}", UNSUPPORTED_EVALUATE_RESULT, value.kind())
+                }
               },
               None => panic!("Member not found"),
             };
@@ -465,49 +531,92 @@ fn _evaluate(
             let (value, updated_state) = &cloned_theme_ref.get(&key);
 
             state.traversal_state.combine(updated_state);
+            state.traversal_state.var_usage.add(
+              theme_ref.file_name(),
+              theme_ref.export_name(),
+              &key,
+            );
 
             return Some(Box::new(EvaluateResultValue::Expr(Box::new(
               string_to_expression(value.as_str()),
             ))));
           }
-          _ => unimplemented!("EvaluateResultValue"),
+          _ => deopt(path, state),
         }
       } else {
         None
       }
     }
-    Expr::Unary(_) => unimplemented!("Unary"),
+    Expr::Unary(unary) => {
+      let unary = unary.clone();
+
+      evaluate_unary(path, &unary, state, fns)
+    }
     Expr::Array(arr_path) => {
       let mut arr: Vec<Option<EvaluateResultValue>> = vec![];
 
       for elem in arr_path.elems.iter().flatten() {
         let elem_value = evaluate(&elem.expr, &mut state.traversal_state, &state.functions);
 
-        if elem_value.confident {
-          arr.push(elem_value.value.map(|value| *value));
-        } else {
+        if !elem_value.confident {
           return None;
         }
+
+        if elem.spread.is_some() {
+          // Spread iterables are copied into the new array item by item
+          let Some(items) = elem_value
+            .value
+            .and_then(|value| get_iterable_items(&value))
+          else {
+            return deopt(path, state);
+          };
+
+          arr.extend(
+            items
+              .into_iter()
+              .map(|item| Some(EvaluateResultValue::Expr(Box::new(item)))),
+          );
+        } else {
+          arr.push(elem_value.value.map(|value| *value));
+        }
       }
 
       Some(Box::new(EvaluateResultValue::Vec(arr)))
     }
     Expr::Object(obj_path) => {
+      // Large `stylex.create` calls are mostly plain literals,
+      // so skip the per-prop evaluation and caching for them
+      if let Some(obj) = evaluate_literal_only_object(obj_path) {
+        return Some(Box::new(EvaluateResultValue::Expr(Box::new(Expr::Object(
+          obj,
+        )))));
+      }
+
       let mut props = vec![];
 
       for prop in &obj_path.props {
         match prop {
           PropOrSpread::Spread(prop) => {
-            let spread_expression = evaluate_cached(&prop.expr, state, fns);
-
-            if !state.confident {
-              return deopt(path, state);
-            }
+            let token_object = state
+              .traversal_state
+              .get_imported_token_object(&prop.expr)
+              .and_then(|object| evaluate_literal_only_object(&object));
+
+            let new_props = match token_object {
+              Some(object) => object,
+              None => {
+                let spread_expression = evaluate_cached(&prop.expr, state, fns);
+
+                if !state.confident {
+                  return deopt(path, state);
+                }
 
-            let new_props = spread_expression
-              .and_then(|spread| spread.as_expr().cloned())
-              .and_then(|expr| expr.object())
-              .expect("Spread must be an object");
+                spread_expression
+                  .and_then(|spread| spread.as_expr().cloned())
+                  .and_then(|expr| expr.object())
+                  .expect("Spread must be an object")
+              }
+            };
 
             let merged_object = deep_merge_props(props, new_props.props);
 
@@ -603,6 +712,7 @@ fn _evaluate(
                       let expr = match expr {
                         Expr::Array(array) => Expr::Array(array),
                         Expr::Lit(lit) => Expr::Lit(lit),
+                        Expr::Ident(ident) if ident.sym == "undefined" => Expr::Ident(ident),
                         _ => panic!("{}", ILLEGAL_PROP_ARRAY_VALUE,),
                       };
 
@@ -620,7 +730,7 @@ fn _evaluate(
                     Box::new(Expr::Array(array))
                   }
                   EvaluateResultValue::Callback(_cb) => {
-                    unimplemented!("EvaluateResultValue::Callback");
+                    return deopt(path, state);
                   }
                   _ => {
                     panic!("Property value must be an expression")
@@ -633,7 +743,7 @@ fn _evaluate(
                 }))));
               }
 
-              _ => unimplemented!(),
+              _ => return deopt(path, state),
             }
           }
         }
@@ -649,9 +759,7 @@ fn _evaluate(
       )))));
     }
     Expr::Bin(bin) => {
-      if let Some(result) = binary_expr_to_num(bin, state, fns) {
-        let result = number_to_expression(result);
-
+      if let Some(result) = binary_expr_to_value(bin, state, fns) {
         return Some(Box::new(EvaluateResultValue::Expr(Box::new(result))));
       } else {
         None
@@ -662,6 +770,16 @@ fn _evaluate(
       let mut func: Option<Box<FunctionConfig>> = None;
 
       if let Callee::Expr(callee_expr) = &call.callee {
+        if let Expr::Ident(ident) = callee_expr.as_ref() {
+          if let Some(macro_function) = state.traversal_state.get_macro_function(ident) {
+            return evaluate_macro_call(&macro_function, call, state, fns);
+          }
+
+          if let Some((unit, root_font_size)) = get_unit_helper(ident, &state.traversal_state) {
+            return evaluate_unit_helper_call(unit, root_font_size, call, state, fns);
+          }
+        }
+
         if get_binding(callee_expr, &mut state.traversal_state).is_none()
           && is_valid_callee(callee_expr)
         {
@@ -842,10 +960,10 @@ fn _evaluate(
 
                               let value = elems
                                 .get(1)
-                                .and_then(|e| e.expr.as_lit())
-                                .expect("Value must be a literal");
+                                .map(|e| e.expr.clone())
+                                .expect("Entry must have a value");
 
-                              entries_result.insert(Box::new(key.clone()), Box::new(value.clone()));
+                              entries_result.insert(Box::new(key.clone()), value);
                             }
                           }
                           EvaluateResultValue::Vec(vec) => {
@@ -863,15 +981,11 @@ fn _evaluate(
 
                               let value = entry
                                 .get(1)
-                                .and_then(|item| item.clone())
-                                .and_then(|item| item.as_expr().cloned())
-                                .and_then(|expr| expr.as_lit().cloned())
-                                .expect("Value must be a literal");
+                                .and_then(|item| item.as_ref())
+                                .and_then(result_to_expr)
+                                .expect("Entry must have a value");
 
-                              entries_result.insert(
-                                Box::new(key.clone().clone()),
-                                Box::new(value.clone().clone()),
-                              );
+                              entries_result.insert(Box::new(key.clone()), Box::new(value));
                             }
                           }
                           _ => {
@@ -973,7 +1087,7 @@ fn _evaluate(
                           .and_then(|expr| expr.as_object().cloned())
                           .expect("Object.entries requires an object");
 
-                        let mut entries: IndexMap<Box<Lit>, Box<Lit>> = IndexMap::new();
+                        let mut entries: IndexMap<Box<Lit>, Box<Expr>> = IndexMap::new();
 
                         for prop in &object.props {
                           let expr = prop.as_prop().map(|prop| *prop.clone()).expect("Spread");
@@ -991,7 +1105,7 @@ fn _evaluate(
 
                           entries.insert(
                             Box::new(lit_str_factory(key.as_str())),
-                            Box::new(value.clone()),
+                            Box::new(Expr::Lit(value.clone())),
                           );
                         }
 
@@ -1002,6 +1116,9 @@ fn _evaluate(
                       }
                     }
                   }
+                  "Array" => {
+                    return evaluate_array_constructor_call(&method_name, call, state, fns);
+                  }
                   _ => panic!("{} - {}", BUILT_IN_FUNCTION, callee_name),
                 }
               } else {
@@ -1095,7 +1212,7 @@ fn _evaluate(
                         "filter" => CallbackType::Array(ArrayJS::Filter),
                         "join" => CallbackType::Array(ArrayJS::Join),
                         "entries" => CallbackType::Object(ObjectJS::Entries),
-                        _ => unimplemented!("Array method '{}' implemented yet", prop_name),
+                        _ => return deopt(path, state),
                       })),
                       takes_path: false,
                     }));
@@ -1109,7 +1226,7 @@ fn _evaluate(
                           "map" => CallbackType::Array(ArrayJS::Map),
                           "filter" => CallbackType::Array(ArrayJS::Filter),
                           "entries" => CallbackType::Object(ObjectJS::Entries),
-                          _ => unimplemented!("Method '{}' implemented yet", prop_name),
+                          _ => return deopt(path, state),
                         })),
                         takes_path: false,
                       }));
@@ -1130,7 +1247,7 @@ fn _evaluate(
                         fn_ptr: FunctionType::Callback(Box::new(match prop_name.as_str() {
                           "concat" => CallbackType::String(StringJS::Concat),
                           "charCodeAt" => CallbackType::String(StringJS::CharCodeAt),
-                          _ => unimplemented!("Method '{}' implemented yet", prop_name),
+                          _ => return deopt(path, state),
                         })),
                         takes_path: false,
                       }));
@@ -1139,7 +1256,7 @@ fn _evaluate(
                         expr.clone(),
                       ))]));
                     }
-                    _ => unimplemented!("Expression evaluation not implemented"),
+                    _ => return deopt(path, state),
                   },
                   EvaluateResultValue::FunctionConfig(fc) => match fc.fn_ptr {
                     FunctionType::StylexFnsFactory(sxfns) => {
@@ -1156,8 +1273,11 @@ fn _evaluate(
                     }
                     _ => unimplemented!(),
                   },
-                  _ => {
-                    panic!("Evaluation result")
+                  value @ (EvaluateResultValue::Entries(_)
+                  | EvaluateResultValue::Callback(_)
+                  | EvaluateResultValue::FunctionConfigMap(_)
+                  | EvaluateResultValue::ThemeRef(_)) => {
+                    panic!("{} {}", UNSUPPORTED_EVALUATE_RESULT, value.kind())
                   }
                 }
               } else if let Some(prop_id) = is_id_prop(property) {
@@ -1323,7 +1443,7 @@ fn _evaluate(
 
                     let value: ExprOrSpread = ExprOrSpread {
                       spread: None,
-                      expr: Box::new(Expr::from(*value.clone())),
+                      expr: value.clone(),
                     };
 
                     entry_elems.push(Some(ExprOrSpread {
@@ -1358,15 +1478,16 @@ fn _evaluate(
                   let mut entry_elems = vec![];
 
                   for (key, value) in entries {
-                    let ident_name = if let Lit::Str(lit_str) = key.as_ref() {
-                      ident_name_factory(lit_str.value.as_str())
-                    } else {
-                      panic!("Expected a string literal")
+                    let key = match key.as_ref() {
+                      Lit::Str(lit_str) => string_to_prop_name(lit_str.value.as_str())
+                        .expect("Key is not a valid property name"),
+                      Lit::Num(num) => PropName::Num(num.clone()),
+                      _ => panic!("Expected a string literal"),
                     };
 
                     let prop = PropOrSpread::Prop(Box::new(Prop::from(KeyValueProp {
-                      key: PropName::Ident(ident_name),
-                      value: Box::new(Expr::from(*value.clone())),
+                      key,
+                      value: value.clone(),
                     })));
 
                     entry_elems.push(prop);
@@ -1499,9 +1620,7 @@ fn _evaluate(
 
       return deopt(path, state);
     }
-    _ => {
-      panic!("_evaluate not implemented this type of expression");
-    }
+    _ => deopt(path, state),
   };
 
   if result.is_none() && path.is_ident() {
@@ -1528,14 +1647,22 @@ fn _evaluate(
         return result;
       }
       None => {
-        let name = ident.sym.to_string();
+        if let Some(source) = state.traversal_state.dynamic_imports.get(&ident.sym) {
+          panic_dynamic_import(source);
+        }
 
-        if name == "undefined" || name == "infinity" || name == "NaN" {
+        if ident.sym == "undefined" {
           return Some(Box::new(EvaluateResultValue::Expr(Box::new(Expr::from(
             ident.clone(),
           )))));
         }
 
+        if let Some(value) = get_global_number(&ident.sym) {
+          return Some(Box::new(EvaluateResultValue::Expr(Box::new(
+            number_to_expression(value),
+          ))));
+        }
+
         let binding =
           get_import_by_ident(ident, &mut state.traversal_state).and_then(|import_decl| {
             if import_decl
@@ -1550,34 +1677,18 @@ fn _evaluate(
           });
 
         if let Some(import_path) = binding {
-          let import_specifier = import_path
-            .specifiers
-            .iter()
-            .find_map(|import| {
-              if let Some(name_import) = import.as_named() {
-                if ident.sym == name_import.local.sym {
-                  return Some(name_import);
-                }
-              }
-              None
-            })
-            .expect("Import specifier not found");
-          let imported = import_specifier
-            .imported
-            .clone()
-            .unwrap_or(ModuleExportName::Ident(import_specifier.local.clone()));
+          let imported_name =
+            get_imported_name(&import_path, ident).expect("Import specifier not found");
 
           let abs_path = &state
             .traversal_state
             .import_path_resolver(&import_path.src.value);
 
-          let imported_name = match imported {
-            ModuleExportName::Ident(ident) => ident.sym.to_string(),
-            ModuleExportName::Str(str) => str.value.to_string(),
-          };
-
+          // `stylex.defineVars()` can only be bound to named exports
           let return_value = match abs_path {
-            ImportPathResolution::Tuple(ImportPathResolutionType::ThemeNameRef, value) => {
+            ImportPathResolution::Tuple(ImportPathResolutionType::ThemeNameRef, value)
+              if imported_name != DEFAULT_EXPORT_NAME =>
+            {
               evaluate_theme_ref(value, imported_name, &state.traversal_state)
             }
             _ => {
@@ -1593,8 +1704,7 @@ fn _evaluate(
             {
               state
                 .traversal_state
-                .prepend_import_module_items
-                .push(add_import_expression(&import_path_src));
+                .add_side_effect_import(&import_path_src);
 
               state.added_imports.insert(import_path_src);
             }
@@ -1613,6 +1723,311 @@ fn _evaluate(
   result
 }
 
+/// Evaluates the body of a macro with its parameters replaced by the
+/// evaluated arguments of the call.
+fn evaluate_macro_call(
+  macro_function: &MacroFunction,
+  call: &CallExpr,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Option<Box<EvaluateResultValue>> {
+  let mut args = Vec::with_capacity(call.args.len());
+
+  for arg in &call.args {
+    if arg.spread.is_some() {
+      return deopt(&arg.expr, state);
+    }
+
+    let value = evaluate_cached(&arg.expr, state, fns)?;
+
+    let Some(expr) = value.as_expr() else {
+      return deopt(&arg.expr, state);
+    };
+
+    args.push(expr.clone());
+  }
+
+  evaluate_cached(&macro_function.expand(&args), state, fns)
+}
+
+/// Returns the value of `process.env.<name>` from the `env` option, which is
+/// `undefined` for names missing from it, unless `process` is bound in the
+/// file or the option is unset.
+fn get_env_value(name: &str, process: &Ident, state: &StateManager) -> Option<Expr> {
+  let env = state.options.env.as_ref()?;
+
+  if get_var_decl_from(state, process).is_some() || get_import_from(state, process).is_some() {
+    return None;
+  }
+
+  Some(match env.get(name) {
+    Some(value) => string_to_expression(value),
+    None => Expr::from(ident_factory("undefined")),
+  })
+}
+
+/// Returns the unit and root font size of the built-in unit helper `ident`
+/// refers to, unless the name is bound in the file.
+fn get_unit_helper(ident: &Ident, state: &StateManager) -> Option<(&'static str, f64)> {
+  let unit_helpers = state.options.unit_helpers.as_ref()?;
+  let unit = unit_helpers.unit_of(&ident.sym)?;
+
+  if get_var_decl_from(state, ident).is_some() || get_import_from(state, ident).is_some() {
+    return None;
+  }
+
+  Some((unit, unit_helpers.root_font_size))
+}
+
+/// Converts the pixels passed to a unit helper like `rem(24)` to a string
+/// like `'1.5rem'`.
+fn evaluate_unit_helper_call(
+  unit: &str,
+  root_font_size: f64,
+  call: &CallExpr,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Option<Box<EvaluateResultValue>> {
+  let panic_invalid_call = || -> ! {
+    let callee = call
+      .callee
+      .as_expr()
+      .and_then(|callee| callee.as_ident())
+      .map(|ident| ident.sym.to_string())
+      .unwrap_or_default();
+
+    panic!("{} {}()", INVALID_UNIT_HELPER_CALL, callee)
+  };
+
+  let [arg] = call.args.as_slice() else {
+    panic_invalid_call();
+  };
+
+  if arg.spread.is_some() {
+    panic_invalid_call();
+  }
+
+  let value = evaluate_cached(&arg.expr, state, fns)?;
+
+  let Some(Expr::Lit(Lit::Num(pixels))) = value.as_expr() else {
+    panic_invalid_call();
+  };
+
+  let quantity = Quantity::new(pixels.value / root_font_size, unit);
+
+  Some(Box::new(EvaluateResultValue::Expr(Box::new(
+    string_to_expression(quantity.to_string().as_str()),
+  ))))
+}
+
+/// Evaluates `Array.from()` and `Array.of()` into a new array, e.g.
+/// `Array.from({ length: 3 }, (_, i) => i * 4)` into `[0, 4, 8]`.
+fn evaluate_array_constructor_call(
+  method_name: &str,
+  call: &CallExpr,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Option<Box<EvaluateResultValue>> {
+  let path = Expr::from(call.clone());
+
+  if call.args.iter().any(|arg| arg.spread.is_some()) {
+    return deopt(&path, state);
+  }
+
+  let items = match method_name {
+    "of" => {
+      let mut items = vec![];
+
+      for arg in &call.args {
+        match evaluate_cached(&arg.expr, state, fns).and_then(|value| value.as_expr().cloned()) {
+          Some(item) => items.push(item),
+          None => return deopt(&path, state),
+        }
+      }
+
+      items
+    }
+    "from" => {
+      let Some(source) = call.args.first() else {
+        panic!("{}", ARRAY_FROM_WITHOUT_SOURCE)
+      };
+
+      let Some(items) = evaluate_cached(&source.expr, state, fns)
+        .and_then(|value| get_iterable_items(&value).or_else(|| get_array_like_items(&value)))
+      else {
+        return deopt(&path, state);
+      };
+
+      match call.args.get(1) {
+        Some(map_fn) => {
+          let Some(map_fn) = evaluate_cached(&map_fn.expr, state, fns) else {
+            return deopt(&path, state);
+          };
+
+          let Some(cb) = map_fn.as_callback() else {
+            return deopt(&path, state);
+          };
+
+          items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+              cb(vec![
+                Some(EvaluateResultValue::Expr(Box::new(item))),
+                Some(EvaluateResultValue::Expr(Box::new(number_to_expression(
+                  index as f64,
+                )))),
+              ])
+            })
+            .collect()
+        }
+        None => items,
+      }
+    }
+    _ => panic!("{} - Array:{}", BUILT_IN_FUNCTION, method_name),
+  };
+
+  Some(Box::new(EvaluateResultValue::Expr(Box::new(
+    array_expression_factory(
+      items
+        .into_iter()
+        .map(|item| {
+          Some(ExprOrSpread {
+            spread: None,
+            expr: Box::new(item),
+          })
+        })
+        .collect(),
+    ),
+  ))))
+}
+
+/// An evaluated value as an expression, with evaluated arrays such as the
+/// `[key, value]` entries returned by a callback turned back into array
+/// literals.
+fn result_to_expr(value: &EvaluateResultValue) -> Option<Expr> {
+  match value {
+    EvaluateResultValue::Expr(expr) => Some(*expr.clone()),
+    EvaluateResultValue::Vec(items) => items
+      .iter()
+      .map(|item| {
+        let expr = match item {
+          Some(item) => result_to_expr(item)?,
+          None => Expr::from(ident_factory("undefined")),
+        };
+
+        Some(Some(ExprOrSpread {
+          spread: None,
+          expr: Box::new(expr),
+        }))
+      })
+      .collect::<Option<Vec<_>>>()
+      .map(array_expression_factory),
+    _ => None,
+  }
+}
+
+/// Items of an evaluated array or string, as iterated by a spread or
+/// `Array.from()`. Holes are read as `undefined`.
+fn get_iterable_items(value: &EvaluateResultValue) -> Option<Vec<Expr>> {
+  match value {
+    EvaluateResultValue::Vec(items) => items
+      .iter()
+      .map(|item| match item {
+        Some(item) => item.as_expr().cloned(),
+        None => Some(Expr::from(ident_factory("undefined"))),
+      })
+      .collect(),
+    EvaluateResultValue::Expr(expr) => match expr.as_ref() {
+      Expr::Array(array) => array
+        .elems
+        .iter()
+        .map(|elem| match elem {
+          Some(elem) if elem.spread.is_some() => None,
+          Some(elem) => Some(*elem.expr.clone()),
+          None => Some(Expr::from(ident_factory("undefined"))),
+        })
+        .collect(),
+      Expr::Lit(Lit::Str(str)) => Some(
+        str
+          .value
+          .chars()
+          .map(|char| string_to_expression(char.to_string().as_str()))
+          .collect(),
+      ),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// `undefined` items of an array-like object such as `{ length: 3 }`, or
+/// `None` when it's longer than [`MAX_ARRAY_LIKE_LENGTH`].
+fn get_array_like_items(value: &EvaluateResultValue) -> Option<Vec<Expr>> {
+  let object = value.as_expr()?.as_object()?;
+
+  let length = object.props.iter().find_map(|prop| {
+    let key_value = prop.as_prop()?.as_key_value()?;
+
+    if get_key_str(key_value) != "length" {
+      return None;
+    }
+
+    match key_value.value.as_ref() {
+      Expr::Lit(Lit::Num(num)) if num.value >= 0.0 && num.value.fract() == 0.0 => Some(num.value),
+      _ => None,
+    }
+  })?;
+
+  if length > MAX_ARRAY_LIKE_LENGTH as f64 {
+    return None;
+  }
+
+  let length = length as usize;
+
+  Some(vec![Expr::from(ident_factory("undefined")); length])
+}
+
+fn evaluate_literal_only_object(obj: &ObjectLit) -> Option<ObjectLit> {
+  let mut props = Vec::with_capacity(obj.props.len());
+
+  for prop in &obj.props {
+    let PropOrSpread::Prop(prop) = prop else {
+      return None;
+    };
+
+    let Prop::KeyValue(key_value) = prop.as_ref() else {
+      return None;
+    };
+
+    let key = match &key_value.key {
+      PropName::Ident(ident) => ident.sym.to_string(),
+      PropName::Str(str) => str.value.to_string(),
+      PropName::Num(num) => num.value.to_string(),
+      PropName::BigInt(big_int) => big_int.value.to_string(),
+      PropName::Computed(_) => return None,
+    };
+
+    let value = match key_value.value.as_ref() {
+      Expr::Lit(Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::Null(_) | Lit::BigInt(_)) => {
+        key_value.value.clone()
+      }
+      Expr::Object(nested) => Box::new(Expr::Object(evaluate_literal_only_object(nested)?)),
+      _ => return None,
+    };
+
+    props.push(PropOrSpread::Prop(Box::new(Prop::from(KeyValueProp {
+      key: PropName::Ident(ident_name_factory(key.as_str())),
+      value,
+    }))));
+  }
+
+  Some(ObjectLit {
+    props: remove_duplicates(props),
+    span: DUMMY_SP,
+  })
+}
+
 fn args_to_numbers(
   args: &[Option<EvaluateResultValue>],
   state: &mut EvaluationState,
@@ -1626,7 +2041,12 @@ fn args_to_numbers(
           vec![expr_to_num(expr, &mut state.traversal_state, fns)]
         }
         EvaluateResultValue::Vec(vec) => args_to_numbers(vec, state, fns),
-        _ => unreachable!("Math.min/max requires a number"),
+        EvaluateResultValue::Map(_)
+        | EvaluateResultValue::Entries(_)
+        | EvaluateResultValue::Callback(_)
+        | EvaluateResultValue::FunctionConfig(_)
+        | EvaluateResultValue::FunctionConfigMap(_)
+        | EvaluateResultValue::ThemeRef(_) => unreachable!("Math.min/max requires a number"),
       },
       None => vec![],
     })
@@ -1640,6 +2060,21 @@ fn get_binding(callee: &Expr, state: &mut StateManager) -> Option<VarDeclarator>
   }
 }
 
+/// Whether the `tag` of a tagged template is `String.raw`, the only tag
+/// known to return the raw strings of the template joined with its values.
+fn is_string_raw(tag: &Expr) -> bool {
+  let Expr::Member(MemberExpr {
+    obj,
+    prop: MemberProp::Ident(prop),
+    ..
+  }) = unwrap_expr(tag)
+  else {
+    return false;
+  };
+
+  &*prop.sym == "raw" && obj.as_ident().is_some_and(|obj| &*obj.sym == "String")
+}
+
 fn is_valid_callee(callee: &Expr) -> bool {
   match callee {
     Expr::Ident(ident) => {
@@ -1681,7 +2116,7 @@ fn is_id_prop(prop: &MemberProp) -> Option<Atom> {
   }
 }
 
-pub(crate) fn evaluate_quasis(
+pub fn evaluate_quasis(
   tpl_expr: &Expr,
   quasis: &[TplElement],
   raw: bool,
@@ -1722,6 +2157,8 @@ pub(crate) fn evaluate_quasis(
 
         let lit = expr.as_lit().expect("Literal not found");
 
+        assert_finite_number(lit);
+
         let lit_str = get_string_val_from_lit(lit);
 
         if let Some(lit_str) = lit_str {
@@ -1740,20 +2177,144 @@ pub(crate) fn evaluate_quasis(
   ))))
 }
 
-pub(crate) fn evaluate_cached(
+/// Evaluates a unary expression as JavaScript does, e.g. `!flag` or
+/// `typeof value`, deopting when the operand isn't static.
+fn evaluate_unary(
+  path: &Expr,
+  unary: &UnaryExpr,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Option<Box<EvaluateResultValue>> {
+  let result = match unary.op {
+    UnaryOp::Void => ident_to_expression("undefined"),
+    UnaryOp::Delete => return deopt(path, state),
+    UnaryOp::TypeOf if matches!(*unary.arg, Expr::Fn(_) | Expr::Arrow(_) | Expr::Class(_)) => {
+      string_to_expression("function")
+    }
+    op => {
+      let arg = evaluate_cached(&unary.arg, state, fns);
+
+      if !state.confident {
+        return None;
+      }
+
+      let arg = arg?;
+
+      let result = match op {
+        UnaryOp::Bang => is_truthy(&arg).map(|value| bool_to_expression(!value)),
+        UnaryOp::Plus => to_number(&arg).map(number_to_expression),
+        UnaryOp::Minus => to_number(&arg).map(|value| number_to_expression(-value)),
+        UnaryOp::Tilde => {
+          to_number(&arg).map(|value| number_to_expression(!to_int32(value) as f64))
+        }
+        UnaryOp::TypeOf => type_of(&arg).map(string_to_expression),
+        UnaryOp::Void | UnaryOp::Delete => unreachable!(),
+      };
+
+      match result {
+        Some(result) => result,
+        None => return deopt(path, state),
+      }
+    }
+  };
+
+  Some(Box::new(EvaluateResultValue::Expr(Box::new(result))))
+}
+
+/// The number an evaluated `value` converts to in JavaScript, `None` when it
+/// isn't known statically.
+fn to_number(value: &EvaluateResultValue) -> Option<f64> {
+  let EvaluateResultValue::Expr(expr) = value else {
+    return None;
+  };
+
+  match expr.as_ref() {
+    Expr::Lit(Lit::Num(num)) => Some(num.value),
+    Expr::Lit(Lit::Bool(bool)) => Some(if bool.value { 1.0 } else { 0.0 }),
+    Expr::Lit(Lit::Null(_)) => Some(0.0),
+    Expr::Lit(Lit::Str(str)) => {
+      let value = str.value.trim();
+
+      Some(match value {
+        "" => 0.0,
+        "Infinity" | "+Infinity" => f64::INFINITY,
+        "-Infinity" => f64::NEG_INFINITY,
+        _ => value
+          .parse::<f64>()
+          .ok()
+          .filter(|number| number.is_finite())
+          .unwrap_or(f64::NAN),
+      })
+    }
+    Expr::Ident(ident) if &*ident.sym == "undefined" || &*ident.sym == "NaN" => Some(f64::NAN),
+    Expr::Ident(ident) if &*ident.sym == "Infinity" => Some(f64::INFINITY),
+    _ => None,
+  }
+}
+
+/// The 32 bit integer JavaScript converts `value` to for bitwise operators.
+fn to_int32(value: f64) -> i32 {
+  if !value.is_finite() {
+    return 0;
+  }
+
+  (value.trunc() % 4_294_967_296.0) as i64 as u32 as i32
+}
+
+/// The `typeof` of an evaluated `value`, `None` when it isn't known
+/// statically.
+fn type_of(value: &EvaluateResultValue) -> Option<&'static str> {
+  let EvaluateResultValue::Expr(expr) = value else {
+    return Some(match value {
+      EvaluateResultValue::Callback(_) | EvaluateResultValue::FunctionConfig(_) => "function",
+      _ => "object",
+    });
+  };
+
+  match expr.as_ref() {
+    Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => Some("string"),
+    Expr::Lit(Lit::Num(_)) => Some("number"),
+    Expr::Lit(Lit::Bool(_)) => Some("boolean"),
+    Expr::Lit(Lit::BigInt(_)) => Some("bigint"),
+    Expr::Lit(Lit::Null(_)) | Expr::Array(_) | Expr::Object(_) => Some("object"),
+    Expr::Fn(_) | Expr::Arrow(_) | Expr::Class(_) => Some("function"),
+    Expr::Ident(ident) if &*ident.sym == "undefined" => Some("undefined"),
+    Expr::Ident(ident) if &*ident.sym == "NaN" || &*ident.sym == "Infinity" => Some("number"),
+    _ => None,
+  }
+}
+
+/// Whether an evaluated `value` is truthy in JavaScript, `None` when it
+/// isn't known statically.
+fn is_truthy(value: &EvaluateResultValue) -> Option<bool> {
+  let EvaluateResultValue::Expr(expr) = value else {
+    // Arrays, objects and functions
+    return Some(true);
+  };
+
+  match expr.as_ref() {
+    Expr::Lit(Lit::Bool(bool)) => Some(bool.value),
+    Expr::Lit(Lit::Num(num)) => Some(num.value != 0.0 && !num.value.is_nan()),
+    Expr::Lit(Lit::Str(str)) => Some(!str.value.is_empty()),
+    Expr::Lit(Lit::Null(_)) => Some(false),
+    Expr::Ident(ident) if &*ident.sym == "undefined" || &*ident.sym == "NaN" => Some(false),
+    Expr::Array(_) | Expr::Object(_) | Expr::Arrow(_) | Expr::Fn(_) => Some(true),
+    _ => None,
+  }
+}
+
+pub fn evaluate_cached(
   path: &Expr,
   state: &mut EvaluationState,
   fns: &FunctionMap,
 ) -> Option<Box<EvaluateResultValue>> {
   let mut cleaned_path = drop_span(path.clone());
-  let existing = state.traversal_state.seen.get(&cleaned_path);
+  let existing = state.traversal_state.seen.get(&cleaned_path).cloned();
 
   match existing {
     Some(evaluated_value) => {
       if evaluated_value.resolved {
-        let resolved = evaluated_value.value.clone();
-
-        return resolved;
+        return evaluated_value.value;
       }
       deopt(path, state)
     }
@@ -0,0 +1,2 @@
+pub mod native_functions;
+pub mod evaluate;
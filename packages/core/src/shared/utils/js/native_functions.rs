@@ -0,0 +1,292 @@
+use crate::shared::{
+  constants::messages::UNSUPPORTED_EVALUATE_RESULT,
+  enums::data_structures::evaluate_result_value::{
+    EvaluateResultValue, EvaluateResultValueVisitor,
+  },
+  structures::{
+    functions::{FunctionConfig, FunctionMap},
+    state_manager::StateManager,
+    theme_ref::ThemeRef,
+    types::EvaluationCallback,
+  },
+  utils::ast::{
+    convertors::{expr_to_str, lit_to_num, string_to_expression},
+    factories::array_expression_factory,
+  },
+};
+use indexmap::IndexMap;
+use std::{collections::HashMap, rc::Rc};
+use swc_core::{
+  atoms::Atom,
+  common::DUMMY_SP,
+  ecma::ast::{ArrayLit, Expr, ExprOrSpread, KeyValueProp, Lit},
+};
+
+pub fn evaluate_map(
+  funcs: &[Box<EvaluateResultValue>],
+  args: &[Option<EvaluateResultValue>],
+) -> Option<Box<EvaluateResultValue>> {
+  let cb = funcs.first()?;
+
+  let cb = cb.as_callback()?;
+
+  let func_result = args
+    .iter()
+    .filter_map(|arg| {
+      let result = arg.as_ref()?;
+
+      result.accept(&mut MapArg { cb, arg })
+    })
+    .collect::<Vec<Expr>>();
+
+  // A single array argument is mapped into the array of its results
+  match (args, func_result.first()) {
+    ([Some(EvaluateResultValue::Vec(_))], Some(Expr::Array(array))) => Some(Box::new(
+      EvaluateResultValue::Expr(Box::new(Expr::from(array.clone()))),
+    )),
+    _ => Some(Box::new(EvaluateResultValue::Expr(Box::new(
+      array_expression_factory(
+        func_result
+          .into_iter()
+          .map(|expr| {
+            Some(ExprOrSpread {
+              spread: None,
+              expr: Box::new(expr),
+            })
+          })
+          .collect(),
+      ),
+    )))),
+  }
+}
+
+pub fn evaluate_join(
+  funcs: &[Box<EvaluateResultValue>],
+  args: &[Option<EvaluateResultValue>],
+  state: &mut StateManager,
+  functions: &FunctionMap,
+) -> Option<Box<EvaluateResultValue>> {
+  let join_arg = funcs.first()?;
+
+  let join_arg = expr_to_str(join_arg.as_expr()?, state, functions);
+
+  let result = args
+    .iter()
+    .map(|arg_ref| {
+      let str_arg = arg_ref
+        .as_ref()
+        .and_then(|arg| arg.as_expr())
+        .map(|arg| expr_to_str(arg, state, functions))
+        .expect("Failed parsing \"join\" argument to string");
+
+      str_arg
+    })
+    .collect::<Vec<String>>()
+    .join(&join_arg);
+
+  Some(Box::new(EvaluateResultValue::Expr(Box::new(
+    string_to_expression(&result),
+  ))))
+}
+
+pub fn evaluate_filter(
+  funcs: &[Box<EvaluateResultValue>],
+  args: &[Option<EvaluateResultValue>],
+) -> Option<Box<EvaluateResultValue>> {
+  let cb = funcs.first()?;
+
+  let cb = cb.as_callback()?;
+
+  let func_result = args
+    .iter()
+    .filter_map(|arg| {
+      let result = arg.as_ref()?;
+
+      result.accept(&mut FilterArg { cb, arg })
+    })
+    .collect::<Vec<Expr>>();
+
+  match func_result.first() {
+    Some(Expr::Array(array)) => Some(Box::new(EvaluateResultValue::Expr(Box::new(Expr::from(
+      array.clone(),
+    ))))),
+    _ => Some(Box::new(EvaluateResultValue::Expr(Box::new(Expr::from(
+      ArrayLit {
+        span: DUMMY_SP,
+        elems: func_result
+          .into_iter()
+          .map(|expr| {
+            Some(ExprOrSpread {
+              spread: None,
+              expr: Box::new(expr),
+            })
+          })
+          .collect(),
+      },
+    ))))),
+  }
+}
+
+/// Result of an `Array.prototype.map()` callback for an argument, or the
+/// array of its results for the items of an array argument.
+struct MapArg<'a> {
+  cb: &'a EvaluationCallback,
+  arg: &'a Option<EvaluateResultValue>,
+}
+
+impl EvaluateResultValueVisitor for MapArg<'_> {
+  type Output = Option<Expr>;
+
+  fn visit_expr(&mut self, _expr: &Expr) -> Self::Output {
+    Some(evaluate_map_cb(self.cb, self.arg))
+  }
+
+  fn visit_vec(&mut self, items: &[Option<EvaluateResultValue>]) -> Self::Output {
+    let func_result = items
+      .iter()
+      .map(|expr| {
+        let expr = evaluate_map_cb(self.cb, expr);
+
+        EvaluateResultValue::Expr(Box::new(expr))
+      })
+      .collect::<Vec<EvaluateResultValue>>();
+
+    let elems = func_result
+      .into_iter()
+      .map(|item| {
+        Some(ExprOrSpread {
+          spread: None,
+          expr: Box::new(item.as_expr()?.clone()),
+        })
+      })
+      .collect::<Vec<Option<ExprOrSpread>>>();
+
+    Some(array_expression_factory(elems))
+  }
+
+  fn visit_map(&mut self, _map: &IndexMap<Box<Expr>, Vec<KeyValueProp>>) -> Self::Output {
+    unsupported_arg("Map")
+  }
+
+  fn visit_entries(&mut self, _entries: &IndexMap<Box<Lit>, Box<Expr>>) -> Self::Output {
+    unsupported_arg("Entries")
+  }
+
+  fn visit_callback(&mut self, _callback: &EvaluationCallback) -> Self::Output {
+    unsupported_arg("Callback")
+  }
+
+  fn visit_function_config(&mut self, _function: &FunctionConfig) -> Self::Output {
+    unsupported_arg("FunctionConfig")
+  }
+
+  fn visit_function_config_map(
+    &mut self,
+    _functions: &HashMap<Atom, FunctionConfig>,
+  ) -> Self::Output {
+    unsupported_arg("FunctionConfigMap")
+  }
+
+  fn visit_theme_ref(&mut self, _theme_ref: &ThemeRef) -> Self::Output {
+    unsupported_arg("ThemeRef")
+  }
+}
+
+/// Argument kept by an `Array.prototype.filter()` callback, or the array of
+/// the kept items of an array argument.
+struct FilterArg<'a> {
+  cb: &'a EvaluationCallback,
+  arg: &'a Option<EvaluateResultValue>,
+}
+
+impl EvaluateResultValueVisitor for FilterArg<'_> {
+  type Output = Option<Expr>;
+
+  fn visit_expr(&mut self, expr: &Expr) -> Self::Output {
+    evaluate_filter_cb(self.cb, self.arg, expr)
+  }
+
+  fn visit_vec(&mut self, items: &[Option<EvaluateResultValue>]) -> Self::Output {
+    let func_result = items
+      .iter()
+      .filter_map(|expr| {
+        let result = evaluate_filter_cb(self.cb, expr, expr.as_ref()?.as_expr()?);
+
+        result.map(|expr| EvaluateResultValue::Expr(Box::new(expr)))
+      })
+      .collect::<Vec<EvaluateResultValue>>();
+
+    let elems = func_result
+      .into_iter()
+      .map(|item| {
+        Some(ExprOrSpread {
+          spread: None,
+          expr: Box::new(item.as_expr()?.clone()),
+        })
+      })
+      .collect::<Vec<Option<ExprOrSpread>>>();
+
+    Some(Expr::Array(ArrayLit {
+      span: DUMMY_SP,
+      elems,
+    }))
+  }
+
+  fn visit_map(&mut self, _map: &IndexMap<Box<Expr>, Vec<KeyValueProp>>) -> Self::Output {
+    unsupported_arg("Map")
+  }
+
+  fn visit_entries(&mut self, _entries: &IndexMap<Box<Lit>, Box<Expr>>) -> Self::Output {
+    unsupported_arg("Entries")
+  }
+
+  fn visit_callback(&mut self, _callback: &EvaluationCallback) -> Self::Output {
+    unsupported_arg("Callback")
+  }
+
+  fn visit_function_config(&mut self, _function: &FunctionConfig) -> Self::Output {
+    unsupported_arg("FunctionConfig")
+  }
+
+  fn visit_function_config_map(
+    &mut self,
+    _functions: &HashMap<Atom, FunctionConfig>,
+  ) -> Self::Output {
+    unsupported_arg("FunctionConfigMap")
+  }
+
+  fn visit_theme_ref(&mut self, _theme_ref: &ThemeRef) -> Self::Output {
+    unsupported_arg("ThemeRef")
+  }
+}
+
+/// Panics for an argument of an array method the callbacks can't be called
+/// with.
+fn unsupported_arg(kind: &str) -> ! {
+  panic!("{} {}", UNSUPPORTED_EVALUATE_RESULT, kind)
+}
+
+pub fn evaluate_map_cb(
+  cb: &Rc<dyn Fn(Vec<Option<EvaluateResultValue>>) -> Expr>,
+  cb_arg: &Option<EvaluateResultValue>,
+) -> Expr {
+  (cb)(vec![cb_arg.clone()])
+}
+
+pub fn evaluate_filter_cb(
+  cb: &Rc<dyn Fn(Vec<Option<EvaluateResultValue>>) -> Expr>,
+  cb_arg: &Option<EvaluateResultValue>,
+  item: &Expr,
+) -> Option<Expr> {
+  let result = evaluate_map_cb(cb, cb_arg);
+
+  let Some(lit) = result.as_lit() else {
+    panic!("Expr is not a literal");
+  };
+
+  if lit_to_num(lit) == 0.0 {
+    None
+  } else {
+    Some(item.clone())
+  }
+}
@@ -0,0 +1,11 @@
+pub mod common;
+pub mod core;
+pub mod css;
+pub mod interner;
+pub mod ast;
+pub mod js;
+pub mod object;
+pub mod swc_compat;
+pub mod syntax;
+pub(crate) mod tests;
+pub mod validators;
@@ -9,7 +9,7 @@ use crate::shared::{
   utils::common::{get_key_str, get_key_values_from_object},
 };
 
-pub(crate) fn obj_map<F>(
+pub fn obj_map<F>(
   prop_values: ObjMapType,
   state: &mut StateManager,
   mapper: F,
@@ -51,7 +51,7 @@ where
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub(crate) struct Pipe<T> {
+pub struct Pipe<T> {
   value: T,
 }
 
@@ -76,7 +76,7 @@ impl<T> Pipe<T> {
   }
 }
 
-pub(crate) fn obj_entries(obj: &Expr) -> Vec<KeyValueProp> {
+pub fn obj_entries(obj: &Expr) -> Vec<KeyValueProp> {
   let mut ret_val = Vec::new();
   let object = obj.as_object().expect("Object expected");
 
@@ -89,7 +89,7 @@ pub(crate) fn obj_entries(obj: &Expr) -> Vec<KeyValueProp> {
   ret_val
 }
 
-pub(crate) fn obj_from_entries(entries: &Vec<OrderPair>) -> IndexMap<String, String> {
+pub fn obj_from_entries(entries: &Vec<OrderPair>) -> IndexMap<String, String> {
   let mut map = IndexMap::new();
 
   for OrderPair(key, value) in entries {
@@ -99,7 +99,7 @@ pub(crate) fn obj_from_entries(entries: &Vec<OrderPair>) -> IndexMap<String, Str
   map
 }
 
-pub(crate) fn obj_map_keys(
+pub fn obj_map_keys(
   entries: &IndexMap<String, String>,
   mapper: fn(&str) -> String,
 ) -> IndexMap<String, Box<FlatCompiledStylesValue>> {
@@ -119,7 +119,7 @@ pub(crate) fn obj_map_keys(
   map
 }
 
-pub(crate) fn _obj_map_entries(
+pub fn _obj_map_entries(
   entries: &IndexMap<String, String>,
   mapper: fn((&str, &str)) -> Pair,
 ) -> IndexMap<String, FlatCompiledStylesValue> {
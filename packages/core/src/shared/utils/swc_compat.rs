@@ -0,0 +1,57 @@
+//! The few `swc_core` APIs whose shape changes between releases, e.g.
+//! `TsConfig` becoming `TsSyntax` or `Ident` splitting off `IdentName`.
+//! Code parsing sources, naming identifiers or resolving scopes goes through
+//! here, so bumping `swc_core` only touches this module.
+
+use swc_core::ecma::{
+  ast::Ident,
+  parser::{EsSyntax, Syntax, TsSyntax},
+};
+#[cfg(feature = "transforms")]
+use swc_core::{
+  common::Mark,
+  ecma::{ast::Module, transforms::base::resolver, visit::FoldWith},
+};
+
+/// Parser syntax for TypeScript, with JSX when `tsx` is set. Decorators are
+/// enabled, so classes using them still parse.
+pub fn typescript_syntax(tsx: bool) -> Syntax {
+  Syntax::Typescript(TsSyntax {
+    tsx,
+    decorators: true,
+    ..Default::default()
+  })
+}
+
+/// Parser syntax for JavaScript, with JSX when `jsx` is set. Decorators are
+/// enabled, also before `export`, so classes using them still parse.
+pub fn ecmascript_syntax(jsx: bool) -> Syntax {
+  Syntax::Es(EsSyntax {
+    jsx,
+    decorators: true,
+    decorators_before_export: true,
+    ..Default::default()
+  })
+}
+
+/// Identifier named `name`, without a span or syntax context.
+pub fn ident(name: &str) -> Ident {
+  Ident::from(name)
+}
+
+/// Identifier used as a property or member name.
+pub fn ident_name(name: &str) -> Ident {
+  ident(name)
+  // TODO: Use this line after migration to swc_core >= 0.99.*
+  // IdentName::from(name)
+}
+
+/// Resolves the scopes of `module` with fresh unresolved and top level
+/// marks, as SWC does before running plugins. Must run within `GLOBALS`.
+#[cfg(feature = "transforms")]
+pub fn resolve_module(module: Module) -> Module {
+  let unresolved_mark = Mark::new();
+  let top_level_mark = Mark::new();
+
+  module.fold_with(&mut resolver(unresolved_mark, top_level_mark, false))
+}
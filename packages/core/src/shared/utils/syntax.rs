@@ -0,0 +1,21 @@
+use std::path::Path;
+
+use swc_core::ecma::parser::Syntax;
+
+use super::swc_compat::{ecmascript_syntax, typescript_syntax};
+
+/// Parser syntax of the script at `path`: TypeScript for `.ts`, `.mts` and
+/// `.cts` files, TSX for `.tsx` files and JavaScript with JSX for all others.
+/// Decorators are enabled, so classes using them still parse.
+pub fn syntax_for_path(path: &Path) -> Syntax {
+  match path.extension().and_then(|extension| extension.to_str()) {
+    Some(extension @ ("ts" | "mts" | "cts" | "tsx")) => typescript_syntax(extension == "tsx"),
+    _ => ecmascript_syntax(true),
+  }
+}
+
+/// `source` without its leading byte order mark, which editors on Windows
+/// like to add and the parser doesn't expect.
+pub fn strip_bom(source: &str) -> &str {
+  source.strip_prefix('\u{feff}').unwrap_or(source)
+}
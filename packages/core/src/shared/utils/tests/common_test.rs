@@ -0,0 +1,299 @@
+#[cfg(test)]
+mod windows_paths {
+  use std::path::PathBuf;
+
+  use swc_core::common::FileName;
+
+  use crate::shared::utils::common::{
+    extract_filename_from_path, extract_filename_with_ext_from_path, extract_path,
+    gen_file_based_identifier,
+  };
+
+  #[test]
+  fn gen_file_based_identifier_normalizes_separators() {
+    assert_eq!(
+      gen_file_based_identifier("src\\tokens.stylex.js", "colors", Some("primary")),
+      "src/tokens.stylex.js//colors.primary"
+    );
+  }
+
+  #[test]
+  fn gen_file_based_identifier_matches_across_platforms() {
+    assert_eq!(
+      gen_file_based_identifier(
+        "node_modules\\@acme\\tokens\\colors.stylex.js",
+        "colors",
+        None
+      ),
+      gen_file_based_identifier("node_modules/@acme/tokens/colors.stylex.js", "colors", None)
+    );
+  }
+
+  #[test]
+  fn extract_path_normalizes_separators() {
+    let file_name = FileName::Real(PathBuf::from("C:\\app\\src\\Button.tsx"));
+
+    assert_eq!(extract_path(&file_name), "C:/app/src/Button.tsx");
+  }
+
+  #[test]
+  fn extract_file_names_from_windows_paths() {
+    let file_name = FileName::Real(PathBuf::from("C:\\app\\src\\tokens.stylex.js"));
+
+    assert_eq!(extract_filename_from_path(&file_name), "tokens.stylex");
+    assert_eq!(
+      extract_filename_with_ext_from_path(&file_name),
+      Some("tokens.stylex.js".to_string())
+    );
+  }
+}
+
+#[cfg(test)]
+mod key_values_from_object {
+  use swc_core::{
+    common::DUMMY_SP,
+    ecma::ast::{Expr, PropOrSpread, SpreadElement},
+  };
+
+  use crate::shared::utils::{
+    ast::{
+      convertors::{number_to_expression, string_to_expression},
+      factories::{
+        object_expression_factory, object_lit_factory, prop_or_spread_expression_factory,
+      },
+    },
+    common::get_key_values_from_object,
+  };
+
+  fn spread(expr: Expr) -> PropOrSpread {
+    PropOrSpread::Spread(SpreadElement {
+      dot3_token: DUMMY_SP,
+      expr: Box::new(expr),
+    })
+  }
+
+  #[test]
+  fn inlines_spread_object_literals_in_place() {
+    let object = object_lit_factory(vec![
+      prop_or_spread_expression_factory("color", string_to_expression("red")),
+      spread(object_expression_factory(vec![
+        prop_or_spread_expression_factory("opacity", number_to_expression(0.5)),
+        prop_or_spread_expression_factory("color", string_to_expression("blue")),
+      ])),
+      prop_or_spread_expression_factory("opacity", number_to_expression(1.0)),
+    ]);
+
+    let key_values = get_key_values_from_object(&object)
+      .into_iter()
+      .map(|key_value| {
+        (
+          key_value.key.as_ident().unwrap().sym.to_string(),
+          *key_value.value,
+        )
+      })
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      key_values,
+      vec![
+        ("color".to_string(), string_to_expression("blue")),
+        ("opacity".to_string(), number_to_expression(1.0)),
+      ]
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "Only static objects can be spread into a style object.")]
+  fn rejects_spread_of_non_static_values() {
+    let object = object_lit_factory(vec![spread(string_to_expression("red"))]);
+
+    get_key_values_from_object(&object);
+  }
+}
+
+#[cfg(test)]
+mod crash_reports {
+  use crate::shared::{enums::core::ModuleCycle, utils::common::crash_report};
+
+  #[test]
+  fn names_the_file_and_phase_of_a_crash() {
+    let report = crash_report(
+      "Only static values are allowed inside of a stylex.create() call.",
+      "/app/src/Component.js",
+      ModuleCycle::TransformEnter,
+    );
+
+    assert!(report.starts_with(
+      "[StyleX] Only static values are allowed inside of a stylex.create() call.\n\n\
+       File: /app/src/Component.js\nPhase: TransformEnter"
+    ));
+  }
+
+  #[test]
+  fn links_where_to_file_an_issue() {
+    let report = crash_report(
+      "Unknown error",
+      "/app/src/Component.js",
+      ModuleCycle::Cleaning,
+    );
+
+    assert!(report
+      .ends_with("please file an issue at https://github.com/dwlad90/stylex-swc-plugin/issues"));
+  }
+}
+
+#[cfg(test)]
+mod function_bodies {
+  use std::path::Path;
+
+  use swc_core::ecma::{
+    ast::{BlockStmtOrExpr, Expr, ModuleItem, Stmt, VarDecl},
+    utils::drop_span,
+  };
+
+  use crate::shared::{
+    structures::module_cache::parse_module,
+    utils::common::{get_return_expr, inline_pure_declarations, split_function_body},
+  };
+
+  fn parse_body(source: &str) -> Vec<Stmt> {
+    let module = parse_module(
+      Path::new("styles.js"),
+      &format!("(width, color) => {{ {} }};", source),
+    )
+    .expect("Function should parse");
+
+    let Some(ModuleItem::Stmt(Stmt::Expr(stmt))) = module.body.into_iter().next() else {
+      panic!("Expected an expression statement");
+    };
+
+    let Expr::Arrow(arrow) = *stmt.expr else {
+      panic!("Expected an arrow function");
+    };
+
+    let BlockStmtOrExpr::BlockStmt(block) = *arrow.body else {
+      panic!("Expected a block body");
+    };
+
+    block.stmts
+  }
+
+  fn parse_expr(source: &str) -> Expr {
+    let module = parse_module(Path::new("styles.js"), &format!("[{}];", source))
+      .expect("Expression should parse");
+
+    let Some(ModuleItem::Stmt(Stmt::Expr(stmt))) = module.body.into_iter().next() else {
+      panic!("Expected an expression statement");
+    };
+
+    let Expr::Array(array) = *stmt.expr else {
+      panic!("Expected an array");
+    };
+
+    *array.elems[0].clone().expect("Expected an element").expr
+  }
+
+  fn inline(source: &str) -> (Vec<String>, Expr) {
+    let stmts = parse_body(source);
+    let (declarations, expr) = split_function_body(&stmts).expect("Body should split");
+
+    let (declarations, expr) = inline_pure_declarations(declarations, expr.clone());
+
+    (declared_names(&declarations), drop_span(expr))
+  }
+
+  fn declared_names(declarations: &[VarDecl]) -> Vec<String> {
+    declarations
+      .iter()
+      .map(|declaration| {
+        declaration.decls[0]
+          .name
+          .as_ident()
+          .unwrap()
+          .id
+          .sym
+          .to_string()
+      })
+      .collect()
+  }
+
+  #[test]
+  fn inlines_pure_values_used_once() {
+    let (declarations, expr) = inline(
+      "const doubled = width * 2; const fallback = 'red'; return { width: doubled, color, backgroundColor: fallback };",
+    );
+
+    assert!(declarations.is_empty());
+    assert_eq!(
+      expr,
+      drop_span(parse_expr(
+        "{ width: (width * 2), color, backgroundColor: 'red' }"
+      ))
+    );
+  }
+
+  #[test]
+  fn keeps_values_used_several_times_or_with_side_effects() {
+    let (declarations, expr) = inline(
+      "const doubled = width * 2; const fallback = getColor(); return { width: doubled, height: doubled, color: fallback };",
+    );
+
+    assert_eq!(declarations, vec!["doubled", "fallback"]);
+    assert_eq!(
+      expr,
+      drop_span(parse_expr(
+        "{ width: doubled, height: doubled, color: fallback }"
+      ))
+    );
+  }
+
+  #[test]
+  fn leaves_shadowed_names_alone() {
+    let (declarations, expr) =
+      inline("const unit = 'px'; return { width: width + unit, margin: [1].map((unit) => unit) };");
+
+    assert!(declarations.is_empty());
+    assert_eq!(
+      expr,
+      drop_span(parse_expr(
+        "{ width: width + 'px', margin: [1].map((unit) => unit) }"
+      ))
+    );
+  }
+
+  #[test]
+  fn keeps_values_captured_by_shadowing_functions() {
+    let (declarations, expr) =
+      inline("const size = width * 2; return { width: [1].map((width) => size) };");
+
+    assert_eq!(declarations, vec!["size"]);
+    assert_eq!(
+      expr,
+      drop_span(parse_expr("{ width: [1].map((width) => size) }"))
+    );
+  }
+
+  #[test]
+  fn inlines_every_value_for_compile_time_evaluation() {
+    let stmts = parse_body("const doubled = width * 2; return [doubled, doubled];");
+
+    assert_eq!(
+      get_return_expr(&stmts).map(drop_span),
+      Some(drop_span(parse_expr("[(width * 2), (width * 2)]")))
+    );
+  }
+
+  #[test]
+  fn rejects_bodies_with_captured_values_for_compile_time_evaluation() {
+    let stmts = parse_body("const size = width * 2; return [1].map((width) => size);");
+
+    assert!(get_return_expr(&stmts).is_none());
+  }
+
+  #[test]
+  fn rejects_redeclared_names() {
+    let stmts = parse_body("var size = 1; var size = 2; return { width: size };");
+
+    assert!(split_function_body(&stmts).is_none());
+  }
+}
@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod hash_stability {
+  use proptest::{prelude::*, sample::subsequence};
+
+  use crate::{
+    hash::{class_name_hash_input, create_class_name, create_hash},
+    shared::{
+      structures::{
+        compat_version::CompatBehavior, pre_rule::PreRuleValue, state_manager::StateManager,
+      },
+      utils::{
+        core::convert_style_to_class_name::convert_style_to_class_name,
+        css::common::transform_value, interner::intern_dashed_key,
+      },
+    },
+  };
+
+  const PROPERTIES: [&str; 11] = [
+    "margin",
+    "padding",
+    "width",
+    "top",
+    "borderRadius",
+    "color",
+    "display",
+    "opacity",
+    "zIndex",
+    "lineHeight",
+    "flexGrow",
+  ];
+
+  const PSEUDOS: [&str; 5] = [":hover", ":focus", ":active", ":focus-visible", "::before"];
+
+  const AT_RULES: [&str; 3] = [
+    "@media (min-width: 768px)",
+    "@supports (display: grid)",
+    "@container (max-width: 40rem)",
+  ];
+
+  fn property() -> impl Strategy<Value = String> {
+    prop::sample::select(PROPERTIES.to_vec()).prop_map(String::from)
+  }
+
+  fn value() -> impl Strategy<Value = String> {
+    prop_oneof![
+      (
+        -500i32..500,
+        prop::sample::select(vec!["", "px", "rem", "em", "%", "vh"])
+      )
+        .prop_map(|(number, unit)| format!("{}{}", number, unit)),
+      (0u32..1000).prop_map(|hundredths| format!("{}", hundredths as f64 / 100.0)),
+      prop::sample::select(vec![
+        "auto",
+        "inherit",
+        "red",
+        "#fff",
+        "transparent",
+        "none",
+        "var(--gap)",
+      ])
+      .prop_map(String::from),
+    ]
+  }
+
+  fn modifiers(all: &[&str]) -> impl Strategy<Value = Vec<String>> {
+    subsequence(
+      all
+        .iter()
+        .map(|modifier| modifier.to_string())
+        .collect::<Vec<_>>(),
+      0..=all.len(),
+    )
+    .prop_shuffle()
+  }
+
+  fn sorted(modifiers: &[String]) -> Vec<String> {
+    let mut sorted = modifiers.to_vec();
+    sorted.sort();
+    sorted
+  }
+
+  fn convert(
+    property: &str,
+    value: &str,
+    pseudos: &[String],
+    at_rules: &[String],
+  ) -> (String, String, String, Option<String>) {
+    let (key, class_name, style) = convert_style_to_class_name(
+      (property, &PreRuleValue::String(value.to_string())),
+      &mut pseudos.to_vec(),
+      &mut at_rules.to_vec(),
+      "x",
+      &StateManager::default(),
+    );
+
+    (key, class_name, style.ltr, style.rtl)
+  }
+
+  proptest! {
+    #[test]
+    fn hashes_are_deterministic_base36_strings(input in any::<String>()) {
+      let hash = create_hash(&input);
+
+      prop_assert_eq!(&hash, &create_hash(&input));
+      prop_assert!(!hash.is_empty());
+      prop_assert!(hash.chars().all(|c| c.is_ascii_digit() || c.is_ascii_lowercase()));
+    }
+
+    #[test]
+    fn hash_inputs_ignore_the_order_of_modifiers(
+      property in property(),
+      value in value(),
+      pseudos in modifiers(&PSEUDOS),
+      at_rules in modifiers(&AT_RULES),
+      at_rules_first in any::<bool>(),
+    ) {
+      let values = vec![value];
+
+      prop_assert_eq!(
+        class_name_hash_input(&property, &values, &pseudos, &at_rules, at_rules_first),
+        class_name_hash_input(
+          &property,
+          &values,
+          &sorted(&pseudos),
+          &sorted(&at_rules),
+          at_rules_first
+        )
+      );
+    }
+
+    #[test]
+    fn normalized_values_are_stable(property in property(), value in value()) {
+      let state = StateManager::default();
+      let normalized = transform_value(&property, &value, &state);
+
+      prop_assert_eq!(transform_value(&property, &normalized, &state), normalized);
+    }
+
+    #[test]
+    fn compiled_rules_are_pure_functions_of_their_inputs(
+      property in property(),
+      value in value(),
+      pseudos in modifiers(&PSEUDOS),
+      at_rules in modifiers(&AT_RULES),
+    ) {
+      prop_assert_eq!(
+        convert(&property, &value, &pseudos, &at_rules),
+        convert(&property, &value, &pseudos, &at_rules)
+      );
+
+      let (_, class_name, ltr, _) = convert(&property, &value, &pseudos, &at_rules);
+      let (_, sorted_class_name, _, _) =
+        convert(&property, &value, &sorted(&pseudos), &sorted(&at_rules));
+
+      prop_assert_eq!(&class_name, &sorted_class_name);
+      prop_assert!(ltr.contains(&format!(".{}", class_name)), "{}", ltr);
+    }
+
+    #[test]
+    fn class_names_match_the_predicted_hash(
+      property in property(),
+      value in value(),
+      pseudos in modifiers(&PSEUDOS),
+      at_rules in modifiers(&AT_RULES),
+    ) {
+      let state = StateManager::default();
+
+      let input = class_name_hash_input(
+        &intern_dashed_key(&property),
+        &[transform_value(&property, &value, &state)],
+        &pseudos,
+        &at_rules,
+        state
+          .options
+          .has_compat_behavior(CompatBehavior::AtRulesFirstInClassNameHash),
+      );
+
+      prop_assert_eq!(
+        convert(&property, &value, &pseudos, &at_rules).1,
+        create_class_name("x", &input)
+      );
+    }
+  }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod class_names {
+  use crate::hash::{
+    class_name_hash_input, create_class_name, scope_hash_input, shadow_host_hash_input,
+  };
+
+  fn values(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+  }
+
+  #[test]
+  fn predicts_class_name_of_plain_rule() {
+    let input = class_name_hash_input("color", &values(&["red"]), &[], &[], false);
+
+    assert_eq!(input, "<>colorrednull");
+    assert_eq!(create_class_name("x", &input), "x1e2nbdu");
+  }
+
+  #[test]
+  fn predicts_class_name_of_pseudo_rule() {
+    let input = class_name_hash_input(
+      "color",
+      &values(&["blue"]),
+      &values(&[":hover"]),
+      &[],
+      false,
+    );
+
+    assert_eq!(create_class_name("x", &input), "x17z2mba");
+  }
+
+  #[test]
+  fn sorts_modifiers() {
+    assert_eq!(
+      class_name_hash_input(
+        "color",
+        &values(&["red"]),
+        &values(&[":hover", ":focus"]),
+        &values(&["@supports (color: red)", "@media (min-width: 1000px)"]),
+        false
+      ),
+      "<>colorred:focus:hover@media (min-width: 1000px)@supports (color: red)"
+    );
+  }
+
+  #[test]
+  fn puts_at_rules_first_for_compat_behavior() {
+    assert_eq!(
+      class_name_hash_input(
+        "color",
+        &values(&["red"]),
+        &values(&[":hover"]),
+        &values(&["@media (min-width: 1000px)"]),
+        true
+      ),
+      "<>colorred@media (min-width: 1000px):hover"
+    );
+  }
+
+  #[test]
+  fn joins_fallback_values() {
+    assert_eq!(
+      class_name_hash_input("position", &values(&["sticky", "fixed"]), &[], &[], false),
+      "<>positionsticky, fixednull"
+    );
+  }
+
+  #[test]
+  fn scopes_hash_input() {
+    assert_eq!(
+      scope_hash_input(Some("@acme/ui".to_string()), "<>colorrednull".to_string()),
+      "@acme/ui//<>colorrednull"
+    );
+    assert_eq!(
+      scope_hash_input(None, "<>colorrednull".to_string()),
+      "<>colorrednull"
+    );
+  }
+
+  #[test]
+  fn scopes_hash_input_to_shadow_hosts() {
+    assert_eq!(
+      shadow_host_hash_input(Some(":host"), "<>colorrednull".to_string()),
+      ":host<>colorrednull"
+    );
+    assert_eq!(
+      shadow_host_hash_input(None, "<>colorrednull".to_string()),
+      "<>colorrednull"
+    );
+  }
+}
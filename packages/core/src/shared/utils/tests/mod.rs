@@ -0,0 +1,4 @@
+mod common_test;
+mod hash_stability_test;
+mod hash_test;
+mod syntax_test;
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod syntax {
+  use std::path::Path;
+
+  use swc_core::{
+    common::{sync::Lrc, FileName, SourceMap},
+    ecma::{
+      ast::{EsVersion, Module},
+      parser::parse_file_as_module,
+    },
+  };
+
+  use crate::shared::utils::syntax::{strip_bom, syntax_for_path};
+
+  const SOURCE: &str = "\u{feff}#!/usr/bin/env node\n@sealed\nexport class Button {}\n";
+
+  fn parse(path: &str, source: &str) -> Option<Module> {
+    let source_map: Lrc<SourceMap> = Default::default();
+    let file =
+      source_map.new_source_file(FileName::Real(path.into()), strip_bom(source).to_string());
+
+    parse_file_as_module(
+      &file,
+      syntax_for_path(Path::new(path)),
+      EsVersion::latest(),
+      None,
+      &mut vec![],
+    )
+    .ok()
+  }
+
+  #[test]
+  fn strips_byte_order_mark() {
+    assert_eq!(strip_bom("\u{feff}const a = 1;"), "const a = 1;");
+    assert_eq!(strip_bom("const a = 1;"), "const a = 1;");
+  }
+
+  #[test]
+  fn parses_shebangs_and_decorators_of_every_script() {
+    for path in [
+      "/app/a.js",
+      "/app/a.jsx",
+      "/app/a.ts",
+      "/app/a.tsx",
+      "/app/a.mts",
+    ] {
+      let module = parse(path, SOURCE).unwrap_or_else(|| panic!("Failed to parse {}", path));
+
+      assert_eq!(
+        module.shebang.as_deref(),
+        Some("/usr/bin/env node"),
+        "{}",
+        path
+      );
+      assert_eq!(module.body.len(), 1, "{}", path);
+    }
+  }
+
+  #[test]
+  fn parses_jsx_and_typescript_by_extension() {
+    assert!(parse("/app/a.js", "export const a = <div />;").is_some());
+    assert!(parse("/app/a.tsx", "export const a = <div />;").is_some());
+    assert!(parse("/app/a.ts", "export const a: number = 1;").is_some());
+  }
+}
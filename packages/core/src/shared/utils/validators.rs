@@ -2,17 +2,23 @@ use std::collections::HashSet;
 
 use swc_core::{
   atoms::Atom,
-  ecma::ast::{CallExpr, Expr, KeyValueProp, Lit, Pat, PropName, VarDeclarator},
+  common::{errors::HANDLER, Span, Spanned},
+  ecma::ast::{
+    CallExpr, Callee, Expr, KeyValueProp, Lit, ObjectLit, Pat, Prop, PropName, PropOrSpread,
+    VarDeclarator,
+  },
 };
 
 use crate::shared::{
   constants::{
     common::THEME_NAME_KEY,
     messages::{
-      DUPLICATE_CONDITIONAL, ILLEGAL_ARGUMENT_LENGTH, ILLEGAL_PROP_ARRAY_VALUE, ILLEGAL_PROP_VALUE,
-      INVALID_PSEUDO_OR_AT_RULE, NON_EXPORT_NAMED_DECLARATION, NON_OBJECT_FOR_STYLEX_CALL,
-      NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL, NON_OBJECT_KEYFRAME, NON_STATIC_KEYFRAME_VALUE,
-      NON_STATIC_VALUE, ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS, ONLY_TOP_LEVEL_INCLUDES,
+      DUPLICATE_CONDITIONAL, EXTRA_ARGUMENT_FOR_STYLEX_CREATE_CALL, FUNCTION_STYLE_VALUE,
+      ILLEGAL_ARGUMENT_LENGTH, ILLEGAL_PROP_ARRAY_VALUE, ILLEGAL_PROP_VALUE,
+      INVALID_PSEUDO_OR_AT_RULE, INVALID_VARS_FILE_SUFFIX, NON_EXPORT_NAMED_DECLARATION,
+      NON_OBJECT_FOR_STYLEX_CALL, NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL, NON_OBJECT_KEYFRAME,
+      NON_STATIC_KEYFRAME_VALUE, NON_STATIC_VALUE,
+      ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS, ONLY_TOP_LEVEL_INCLUDES,
       UNBOUND_STYLEX_CALL_VALUE,
     },
   },
@@ -27,13 +33,13 @@ use crate::shared::{
       convertors::string_to_expression,
       factories::{ident_factory, key_value_factory},
     },
-    common::{get_string_val_from_lit, get_var_decl_by_ident_or_member},
+    common::{get_object_wrapper_path, get_string_val_from_lit, get_var_decl_by_ident_or_member},
   },
 };
 
 use super::common::{get_key_str, get_key_values_from_object};
 
-pub(crate) fn validate_stylex_create(call: &CallExpr, state: &mut StateManager) {
+pub fn validate_stylex_create(call: &CallExpr, state: &mut StateManager) {
   if !is_create_call(call, state) {
     return;
   }
@@ -47,23 +53,115 @@ pub(crate) fn validate_stylex_create(call: &CallExpr, state: &mut StateManager)
       || state
         .top_level_expressions
         .iter()
-        .any(|TopLevelExpression(_, call_item, _)| { call_item.eq(&call_expr) }),
+        .any(|TopLevelExpression(_, call_item, _)| { call_item.eq(&call_expr) })
+      || get_object_wrapper_path(call, state).is_some(),
     "{}",
     UNBOUND_STYLEX_CALL_VALUE
   );
 
+  if let Some(extra_arg) = call.args.get(1) {
+    panic_at(extra_arg.span(), EXTRA_ARGUMENT_FOR_STYLEX_CREATE_CALL);
+  }
+
   assert!(call.args.len() == 1, "{}", ILLEGAL_ARGUMENT_LENGTH);
 
   let first_args = &call.args[0];
 
   assert!(
-    first_args.expr.is_object(),
+    first_args.expr.is_object() || is_object_from_entries_call(&first_args.expr),
     "{}",
     NON_OBJECT_FOR_STYLEX_CALL
-  )
+  );
+
+  if let Some(namespaces) = first_args.expr.as_object() {
+    validate_no_function_style_values(namespaces);
+  }
+}
+
+/// Panics at the first function or class of the `stylex.create()`
+/// namespaces other than the arrow functions of dynamic styles, which are
+/// only allowed as namespace values.
+/// Whether `expr` builds the namespaces with `Object.fromEntries()`, e.g.
+/// `Object.fromEntries(sizes.map((size) => [size, { width: size }]))`.
+fn is_object_from_entries_call(expr: &Expr) -> bool {
+  let Some(member) = expr
+    .as_call()
+    .and_then(|call| call.callee.as_expr())
+    .and_then(|callee| callee.as_member())
+  else {
+    return false;
+  };
+
+  member.obj.as_ident().is_some_and(|obj| obj.sym == "Object")
+    && member
+      .prop
+      .as_ident()
+      .is_some_and(|prop| prop.sym == "fromEntries")
+}
+
+fn validate_no_function_style_values(namespaces: &ObjectLit) {
+  for prop in &namespaces.props {
+    let PropOrSpread::Prop(prop) = prop else {
+      continue;
+    };
+
+    match prop.as_ref() {
+      Prop::KeyValue(key_value) => match unparen(&key_value.value) {
+        Expr::Arrow(_) => {}
+        Expr::Object(styles) => validate_no_function_values(styles),
+        value => validate_no_function_value(value),
+      },
+      Prop::Method(method) => panic_at(method.function.span, FUNCTION_STYLE_VALUE),
+      _ => {}
+    }
+  }
+}
+
+fn validate_no_function_values(styles: &ObjectLit) {
+  for prop in &styles.props {
+    let PropOrSpread::Prop(prop) = prop else {
+      continue;
+    };
+
+    match prop.as_ref() {
+      Prop::KeyValue(key_value) => validate_no_function_value(&key_value.value),
+      Prop::Method(method) => panic_at(method.function.span, FUNCTION_STYLE_VALUE),
+      _ => {}
+    }
+  }
+}
+
+fn validate_no_function_value(value: &Expr) {
+  match unparen(value) {
+    Expr::Fn(_) | Expr::Arrow(_) | Expr::Class(_) => panic_at(value.span(), FUNCTION_STYLE_VALUE),
+    Expr::Object(styles) => validate_no_function_values(styles),
+    Expr::Array(array) => {
+      for elem in array.elems.iter().flatten() {
+        validate_no_function_value(&elem.expr);
+      }
+    }
+    _ => {}
+  }
 }
 
-pub(crate) fn validate_stylex_keyframes_indent(var_decl: &VarDeclarator, state: &mut StateManager) {
+fn unparen(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(paren) => unparen(&paren.expr),
+    _ => expr,
+  }
+}
+
+/// Panics with `message`, reporting it at `span` first when the host
+/// installed an error handler so that the diagnostic points at the code.
+fn panic_at(span: Span, message: &str) -> ! {
+  if HANDLER.is_set() {
+    HANDLER.with(|handler| handler.struct_span_err(span, message).emit());
+  }
+
+  panic!("{}", message)
+}
+
+pub fn validate_stylex_keyframes_indent(var_decl: &VarDeclarator, state: &mut StateManager) {
   let init = match &var_decl.init {
     Some(init) => init.clone().call().expect(NON_STATIC_KEYFRAME_VALUE),
     None => panic!("{}", NON_STATIC_KEYFRAME_VALUE),
@@ -98,7 +196,7 @@ pub(crate) fn validate_stylex_keyframes_indent(var_decl: &VarDeclarator, state:
   )
 }
 
-pub(crate) fn validate_stylex_create_theme_indent(
+pub fn validate_stylex_create_theme_indent(
   var_decl: &Option<Box<VarDeclarator>>,
   call: &CallExpr,
   state: &mut StateManager,
@@ -133,7 +231,7 @@ pub(crate) fn validate_stylex_create_theme_indent(
   assert!(init.args.len() == 2, "{}", ILLEGAL_ARGUMENT_LENGTH);
 }
 
-pub(crate) fn validate_stylex_define_vars(call: &CallExpr, state: &mut StateManager) {
+pub fn validate_stylex_define_vars(call: &CallExpr, state: &mut StateManager) {
   if !is_define_vars_call(call, state) {
     return;
   }
@@ -161,21 +259,27 @@ pub(crate) fn validate_stylex_define_vars(call: &CallExpr, state: &mut StateMana
     "{}",
     NON_EXPORT_NAMED_DECLARATION
   );
+
+  assert!(
+    state.is_valid_vars_file(&state.get_filename()),
+    "{}",
+    INVALID_VARS_FILE_SUFFIX
+  );
 }
 
-pub(crate) fn is_create_call(call: &CallExpr, state: &StateManager) -> bool {
+pub fn is_create_call(call: &CallExpr, state: &StateManager) -> bool {
   is_target_call(("create", &state.stylex_create_import), call, state)
 }
 
-pub(crate) fn is_props_call(call: &CallExpr, state: &StateManager) -> bool {
+pub fn is_props_call(call: &CallExpr, state: &StateManager) -> bool {
   is_target_call(("props", &state.stylex_props_import), call, state)
 }
 
-pub(crate) fn is_attrs_call(call: &CallExpr, state: &StateManager) -> bool {
+pub fn is_attrs_call(call: &CallExpr, state: &StateManager) -> bool {
   is_target_call(("attrs", &state.stylex_attrs_import), call, state)
 }
 
-pub(crate) fn is_keyframes_call(var_decl: &VarDeclarator, state: &StateManager) -> bool {
+pub fn is_keyframes_call(var_decl: &VarDeclarator, state: &StateManager) -> bool {
   let init = match &var_decl.init {
     Some(init) => init.clone().call(),
     None => None,
@@ -188,7 +292,7 @@ pub(crate) fn is_keyframes_call(var_decl: &VarDeclarator, state: &StateManager)
   }
 }
 
-pub(crate) fn is_create_theme_call(call: &CallExpr, state: &StateManager) -> bool {
+pub fn is_create_theme_call(call: &CallExpr, state: &StateManager) -> bool {
   is_target_call(
     ("createTheme", &state.stylex_create_theme_import),
     call,
@@ -196,7 +300,7 @@ pub(crate) fn is_create_theme_call(call: &CallExpr, state: &StateManager) -> boo
   )
 }
 
-pub(crate) fn is_define_vars_call(call: &CallExpr, state: &StateManager) -> bool {
+pub fn is_define_vars_call(call: &CallExpr, state: &StateManager) -> bool {
   is_target_call(
     ("defineVars", &state.stylex_define_vars_import),
     call,
@@ -204,11 +308,47 @@ pub(crate) fn is_define_vars_call(call: &CallExpr, state: &StateManager) -> bool
   )
 }
 
-pub(crate) fn is_target_call(
+/// The StyleX API `call` calls, `stylex` for the legacy `stylex()` function.
+pub fn get_stylex_api(call: &CallExpr, state: &StateManager) -> Option<&'static str> {
+  let apis = [
+    ("create", &state.stylex_create_import),
+    ("props", &state.stylex_props_import),
+    ("attrs", &state.stylex_attrs_import),
+    ("keyframes", &state.stylex_keyframes_import),
+    ("defineVars", &state.stylex_define_vars_import),
+    ("createTheme", &state.stylex_create_theme_import),
+    ("firstThatWorks", &state.stylex_first_that_works_import),
+    ("include", &state.stylex_include_import),
+  ];
+
+  if let Some((api, _)) = apis
+    .into_iter()
+    .find(|&(api, imports)| is_target_call((api, imports), call, state))
+  {
+    return Some(api);
+  }
+
+  let is_stylex_call = match &call.callee {
+    Callee::Expr(callee) => callee.as_ident().is_some_and(|ident| {
+      state
+        .stylex_import_stringified()
+        .contains(&ident.sym.to_string())
+    }),
+    _ => false,
+  };
+
+  is_stylex_call.then_some("stylex")
+}
+
+pub fn is_target_call(
   (call_name, imports_map): (&str, &HashSet<Box<Atom>>),
   call: &CallExpr,
   state: &StateManager,
 ) -> bool {
+  if !state.options.transforms.is_enabled(call_name) {
+    return false;
+  }
+
   let is_create_ident = call.callee.as_expr().map_or(false, |expr| {
     expr
       .as_ident()
@@ -236,7 +376,13 @@ pub(crate) fn is_target_call(
 
   is_create_ident || is_create_member
 }
-pub(crate) fn validate_namespace(namespaces: &[KeyValueProp], conditions: &[String]) {
+
+/// Checks the keys and values of a `stylex.create()` namespace, nested in
+/// `conditions`, and returns the error to report for the first invalid one.
+pub fn validate_namespace(
+  namespaces: &[KeyValueProp],
+  conditions: &[String],
+) -> Result<(), String> {
   for namespace in namespaces {
     let key = match &namespace.key {
       PropName::Ident(key) => format!("{}", key.sym),
@@ -246,32 +392,30 @@ pub(crate) fn validate_namespace(namespaces: &[KeyValueProp], conditions: &[Stri
           || key.value == "default"
           || namespace.value.is_lit())
         {
-          panic!("{}", INVALID_PSEUDO_OR_AT_RULE)
+          return Err(INVALID_PSEUDO_OR_AT_RULE.to_string());
         }
         key.value.to_string()
       }
-      _ => panic!("{}", NON_STATIC_VALUE),
+      _ => return Err(NON_STATIC_VALUE.to_string()),
     };
 
     match namespace.value.as_ref() {
       Expr::Lit(lit) => {
         if let Lit::Str(_) | Lit::Null(_) | Lit::Num(_) | Lit::BigInt(_) = lit {
         } else {
-          panic!("{}", ILLEGAL_PROP_VALUE);
+          return Err(ILLEGAL_PROP_VALUE.to_string());
         }
       }
       Expr::Array(array) => {
         for elem in array.elems.iter().flatten() {
-          assert!(
-            elem.spread.is_none(),
-            "{}",
-            "Spread operator not implemented"
-          );
-
-          if let Expr::Lit(_) = elem.expr.as_ref() {
-            // Do nothing
-          } else {
-            panic!("{}", ILLEGAL_PROP_ARRAY_VALUE);
+          if elem.spread.is_some() {
+            return Err("Spread operator not implemented".to_string());
+          }
+
+          match elem.expr.as_ref() {
+            Expr::Lit(_) => {}
+            Expr::Ident(ident) if ident.sym == "undefined" => {}
+            _ => return Err(ILLEGAL_PROP_ARRAY_VALUE.to_string()),
           }
         }
       }
@@ -280,7 +424,7 @@ pub(crate) fn validate_namespace(namespaces: &[KeyValueProp], conditions: &[Stri
 
         if key.starts_with('@') || key.starts_with(':') {
           if conditions.contains(&key) {
-            panic!("{}", DUPLICATE_CONDITIONAL);
+            return Err(DUPLICATE_CONDITIONAL.to_string());
           }
 
           let nested_key_values = get_key_values_from_object(object);
@@ -288,42 +432,45 @@ pub(crate) fn validate_namespace(namespaces: &[KeyValueProp], conditions: &[Stri
           let mut extended_conditions = conditions.to_vec();
           extended_conditions.push(key);
 
-          validate_namespace(&nested_key_values, &extended_conditions);
+          validate_namespace(&nested_key_values, &extended_conditions)?;
         } else {
           let conditional_styles_key_values = get_key_values_from_object(object);
 
           for conditional_style in &conditional_styles_key_values {
-            validate_conditional_styles(conditional_style, conditions);
+            validate_conditional_styles(conditional_style, conditions)?;
           }
         }
       }
       _ => {
-        if INCLUDED_IDENT_REGEX.is_match(&key) {
-          assert!(conditions.is_empty(), "{}", ONLY_TOP_LEVEL_INCLUDES)
+        if INCLUDED_IDENT_REGEX.is_match(&key) && !conditions.is_empty() {
+          return Err(ONLY_TOP_LEVEL_INCLUDES.to_string());
         }
       }
     }
   }
+
+  Ok(())
 }
 
-pub(crate) fn validate_dynamic_style_params(params: &[Pat]) {
+pub fn validate_dynamic_style_params(params: &[Pat]) {
   if params.iter().any(|param| !param.is_ident()) {
     panic!("{}", ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS);
   }
 }
 
-pub(crate) fn validate_conditional_styles(inner_key_value: &KeyValueProp, conditions: &[String]) {
+pub fn validate_conditional_styles(
+  inner_key_value: &KeyValueProp,
+  conditions: &[String],
+) -> Result<(), String> {
   let inner_key = get_key_str(inner_key_value);
   let inner_value = inner_key_value.value.clone();
 
-  assert!(
-    (inner_key.starts_with(':') || inner_key.starts_with('@') || inner_key == "default"),
-    "{}",
-    INVALID_PSEUDO_OR_AT_RULE,
-  );
+  if !(inner_key.starts_with(':') || inner_key.starts_with('@') || inner_key == "default") {
+    return Err(INVALID_PSEUDO_OR_AT_RULE.to_string());
+  }
 
   if conditions.contains(&inner_key) {
-    panic!("{}", DUPLICATE_CONDITIONAL);
+    return Err(DUPLICATE_CONDITIONAL.to_string());
   }
 
   match inner_value.as_ref() {
@@ -333,7 +480,7 @@ pub(crate) fn validate_conditional_styles(inner_key_value: &KeyValueProp, condit
         match elem {
           Some(elem) => match elem.expr.as_ref() {
             Expr::Lit(_) => {}
-            _ => panic!("{}", ILLEGAL_PROP_VALUE),
+            _ => return Err(ILLEGAL_PROP_VALUE.to_string()),
           },
           None => {}
         }
@@ -346,19 +493,21 @@ pub(crate) fn validate_conditional_styles(inner_key_value: &KeyValueProp, condit
       extended_conditions.push(inner_key);
 
       for nested_key_value in nested_key_values.iter() {
-        validate_conditional_styles(nested_key_value, &extended_conditions);
+        validate_conditional_styles(nested_key_value, &extended_conditions)?;
       }
     }
     Expr::Ident(_) => {
       if INCLUDED_IDENT_REGEX.is_match(&inner_key) {
-        panic!("{}", ONLY_TOP_LEVEL_INCLUDES);
+        return Err(ONLY_TOP_LEVEL_INCLUDES.to_string());
       }
     }
-    _ => panic!("{}", ILLEGAL_PROP_VALUE),
+    _ => return Err(ILLEGAL_PROP_VALUE.to_string()),
   }
+
+  Ok(())
 }
 
-pub(crate) fn assert_valid_keyframes(obj: &EvaluateResultValue) {
+pub fn assert_valid_keyframes(obj: &EvaluateResultValue) {
   match obj {
     EvaluateResultValue::Expr(expr) => match expr.as_ref() {
       Expr::Object(object) => {
@@ -373,11 +522,17 @@ pub(crate) fn assert_valid_keyframes(obj: &EvaluateResultValue) {
       }
       _ => panic!("{}", NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL),
     },
-    _ => panic!("{}", NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL),
+    EvaluateResultValue::Vec(_)
+    | EvaluateResultValue::Map(_)
+    | EvaluateResultValue::Entries(_)
+    | EvaluateResultValue::Callback(_)
+    | EvaluateResultValue::FunctionConfig(_)
+    | EvaluateResultValue::FunctionConfigMap(_)
+    | EvaluateResultValue::ThemeRef(_) => panic!("{}", NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL),
   }
 }
 
-pub(crate) fn validate_theme_variables(
+pub fn validate_theme_variables(
   variables: &EvaluateResultValue,
   state: &mut StateManager,
 ) -> KeyValueProp {
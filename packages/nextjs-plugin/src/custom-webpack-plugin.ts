@@ -2,7 +2,7 @@ import path from 'path';
 import stylexBabelPlugin from '@stylexjs/babel-plugin';
 import webpack from 'webpack';
 import fs from 'fs/promises';
-import { PluginRule } from './types';
+import { getPluginRules, PluginRule } from './types';
 
 import type { Rule } from '@stylexjs/babel-plugin';
 import type { Compiler, WebpackError } from 'webpack';
@@ -159,10 +159,10 @@ class StylexPlugin {
         }
       );
 
-      const metadata = { stylex: [] };
+      const metadata: { stylex: PluginRule[] } = { stylex: [] };
 
       try {
-        metadata.stylex = JSON.parse(metadataStr);
+        metadata.stylex = getPluginRules(JSON.parse(metadataStr));
       } catch (e) {
         console.error('error parsing metadata', e);
       }
@@ -178,7 +178,7 @@ class StylexPlugin {
         logger.debug(`Read stylex styles from ${filename}:`, metadata.stylex);
 
         const oldClassNames = new Set(oldRules.map(rule => rule[0]));
-        const newClassNames = new Set(metadata.stylex.map(rule => rule[0]));
+        const newClassNames = new Set(metadata.stylex.map(rule => rule.class_name));
 
         // If there are any new classNames in the output we need to recompile
         // the CSS bundle.
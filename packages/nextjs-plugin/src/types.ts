@@ -3,3 +3,13 @@ export type PluginRule = {
   style: { ltr: string; rtl?: null | string };
   priority: number;
 };
+
+/**
+ * Rule metadata of a module: a versioned envelope since version 2 and the bare
+ * array of rules before it.
+ */
+export type PluginMetadata = PluginRule[] | { version: number; rules: PluginRule[] };
+
+export function getPluginRules(metadata: PluginMetadata): PluginRule[] {
+  return Array.isArray(metadata) ? metadata : metadata.rules;
+}
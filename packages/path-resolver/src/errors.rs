@@ -0,0 +1,74 @@
+use std::{
+  error::Error,
+  fmt::{self, Display},
+  io,
+  path::PathBuf,
+};
+
+#[derive(Debug)]
+pub enum ResolveError {
+  /// The path to resolve does not point to a source file.
+  NotAFile(PathBuf),
+  /// No `package.json` could be found for the path.
+  PackageNotFound(PathBuf),
+  /// None of the package `exports` entries matches the requested subpath.
+  ExportsMismatch {
+    package: String,
+    subpath: String,
+  },
+  /// The path is neither inside the root directory nor part of a workspace package.
+  OutsideRoot(PathBuf),
+  /// The `package.json` at the path is not valid JSON.
+  PackageJson {
+    path: PathBuf,
+    error: serde_json::Error,
+  },
+  Io(io::Error),
+}
+
+impl Display for ResolveError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ResolveError::NotAFile(path) => {
+        write!(
+          f,
+          "Resolve path must be a file, but got: {}",
+          path.display()
+        )
+      }
+      ResolveError::PackageNotFound(path) => {
+        write!(f, "No package.json found for path: {}", path.display())
+      }
+      ResolveError::ExportsMismatch { package, subpath } => write!(
+        f,
+        "No exports entry of package {} matches the subpath: {}",
+        package, subpath
+      ),
+      ResolveError::OutsideRoot(path) => write!(
+        f,
+        "Path is outside of the root directory and workspace packages: {}",
+        path.display()
+      ),
+      ResolveError::PackageJson { path, error } => {
+        write!(f, "Failed to parse {}: {}", path.display(), error)
+      }
+      ResolveError::Io(error) => write!(f, "{}", error),
+    }
+  }
+}
+
+impl Error for ResolveError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      ResolveError::PackageJson { error, .. } => Some(error),
+      ResolveError::Io(error) => Some(error),
+      _ => None,
+    }
+  }
+}
+
+impl From<io::Error> for ResolveError {
+  fn from(error: io::Error) -> Self {
+    ResolveError::Io(error)
+  }
+}
@@ -1,3 +1,4 @@
+pub mod errors;
 mod package_json;
 pub mod resolvers;
-mod utils;
+pub mod utils;
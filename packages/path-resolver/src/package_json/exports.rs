@@ -0,0 +1,120 @@
+use std::{collections::HashMap, fmt};
+
+use serde::{
+  de::{Error, MapAccess, SeqAccess, Visitor},
+  ser::SerializeMap,
+  Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// `exports` field of a package manifest. Objects keep the order of their
+/// keys, since the first condition matching in it picks the target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageExports {
+  Target(String),
+  /// Subpaths, e.g. `"./colors"`, or conditions, e.g. `"import"`, in order.
+  Map(Vec<(String, PackageExports)>),
+  /// Fallback targets, the first one resolved wins.
+  Fallbacks(Vec<PackageExports>),
+  /// Target excluded from the package.
+  Null,
+}
+
+impl PackageExports {
+  /// Targets of the subpaths the package exports, e.g. `./dist/index.js` for
+  /// `.`, picked by the first key of each condition object that is
+  /// `default` or one of `conditions`.
+  pub fn subpaths(&self, conditions: &[String]) -> HashMap<String, String> {
+    match self {
+      PackageExports::Map(entries) if entries.iter().any(|(key, _)| key.starts_with('.')) => {
+        entries
+          .iter()
+          .filter_map(|(subpath, target)| Some((subpath.clone(), target.resolve(conditions)?)))
+          .collect()
+      }
+      exports => exports
+        .resolve(conditions)
+        .map(|target| HashMap::from([(".".to_string(), target)]))
+        .unwrap_or_default(),
+    }
+  }
+
+  fn resolve(&self, conditions: &[String]) -> Option<String> {
+    match self {
+      PackageExports::Target(target) => Some(target.clone()),
+      PackageExports::Map(entries) => entries.iter().find_map(|(condition, target)| {
+        if condition == "default" || conditions.contains(condition) {
+          target.resolve(conditions)
+        } else {
+          None
+        }
+      }),
+      PackageExports::Fallbacks(targets) => {
+        targets.iter().find_map(|target| target.resolve(conditions))
+      }
+      PackageExports::Null => None,
+    }
+  }
+}
+
+impl<'de> Deserialize<'de> for PackageExports {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_any(PackageExportsVisitor)
+  }
+}
+
+struct PackageExportsVisitor;
+
+impl<'de> Visitor<'de> for PackageExportsVisitor {
+  type Value = PackageExports;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("a path, an array or an object of package exports")
+  }
+
+  fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+    Ok(PackageExports::Target(value.to_string()))
+  }
+
+  fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+    Ok(PackageExports::Null)
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+    let mut targets = vec![];
+
+    while let Some(target) = seq.next_element()? {
+      targets.push(target);
+    }
+
+    Ok(PackageExports::Fallbacks(targets))
+  }
+
+  fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+    let mut entries = vec![];
+
+    while let Some(entry) = map.next_entry()? {
+      entries.push(entry);
+    }
+
+    Ok(PackageExports::Map(entries))
+  }
+}
+
+impl Serialize for PackageExports {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match self {
+      PackageExports::Target(target) => serializer.serialize_str(target),
+      PackageExports::Map(entries) => {
+        let mut map = serializer.serialize_map(Some(entries.len()))?;
+
+        for (key, value) in entries {
+          map.serialize_entry(key, value)?;
+        }
+
+        map.end()
+      }
+      PackageExports::Fallbacks(targets) => serializer.collect_seq(targets),
+      PackageExports::Null => serializer.serialize_unit(),
+    }
+  }
+}
@@ -1,60 +1,60 @@
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, env};
 use std::{default::Default, fs::read_to_string};
 
 use package_json::{PackageDependencies, PackageJsonManager};
 use std::path::{Path, PathBuf};
 
+use crate::errors::ResolveError;
+
+pub(crate) mod exports;
+mod tests;
+
+use exports::PackageExports;
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PackageJsonExtended {
   pub name: String,
   #[serde(skip_serializing_if = "Option::is_none")]
+  pub version: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub main: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub module: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub exports: Option<HashMap<String, String>>,
+  pub exports: Option<PackageExports>,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub dependencies: Option<PackageDependencies>,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub dev_dependencies: Option<PackageDependencies>,
 }
 
-pub(crate) fn get_package_json(path: &Path) -> (PackageJsonExtended, PackageJsonManager) {
-  let (package_json_content, manager) = get_package_json_path(path);
-
-  match package_json_content {
-    Some(file) => {
-      let data = read_to_string(file.display().to_string().as_str());
-
-      match data {
-        Ok(package_json_raw) => {
-          let json =
-            serde_json::from_str::<PackageJsonExtended>(package_json_raw.as_str()).unwrap();
-
-          (json, manager)
-        }
-        Err(_) => panic!(
-          "Failed to read package.json file: {}/{}",
-          env::current_dir().unwrap().display(),
-          file.display()
-        ),
-      }
-    }
-    None => {
-      panic!("No package.json found for path: {:?}", path.display());
+pub(crate) fn get_package_json(
+  path: &Path,
+) -> Result<(PackageJsonExtended, PackageJsonManager), ResolveError> {
+  let (file, manager) = get_package_json_path(path)?;
+
+  Ok((read_package_json(&file)?, manager))
+}
+
+pub(crate) fn read_package_json(file: &Path) -> Result<PackageJsonExtended, ResolveError> {
+  let package_json_raw = read_to_string(file.display().to_string().as_str())?;
+
+  serde_json::from_str::<PackageJsonExtended>(package_json_raw.as_str()).map_err(|error| {
+    ResolveError::PackageJson {
+      path: file.to_path_buf(),
+      error,
     }
-  }
+  })
 }
 
-pub(crate) fn get_package_json_path(path: &Path) -> (Option<PathBuf>, PackageJsonManager) {
+pub(crate) fn get_package_json_path(
+  path: &Path,
+) -> Result<(PathBuf, PackageJsonManager), ResolveError> {
   let mut manager = PackageJsonManager::new();
 
   match manager.locate_closest_from(path) {
-    Ok(file) => (Option::Some(file), manager),
-    Err(error) => {
-      panic!("Error: {}, path: {}", error, path.display());
-    }
+    Ok(file) => Ok((file, manager)),
+    Err(_) => Err(ResolveError::PackageNotFound(path.to_path_buf())),
   }
 }
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod package_exports_tests {
+  use std::collections::HashMap;
+
+  use crate::{
+    package_json::exports::PackageExports,
+    resolvers::{ResolverOptions, TargetEnv},
+  };
+
+  fn parse(exports: &str) -> PackageExports {
+    serde_json::from_str(exports).expect("Exports must be valid")
+  }
+
+  fn conditions(target_env: TargetEnv, custom_conditions: &[&str]) -> Vec<String> {
+    ResolverOptions {
+      target_env,
+      conditions: custom_conditions.iter().map(|c| c.to_string()).collect(),
+    }
+    .export_conditions()
+  }
+
+  #[test]
+  fn resolves_subpath_targets() {
+    let exports = parse(r#"{ ".": "./index.js", "./colors": "./colors.js" }"#);
+
+    assert_eq!(
+      exports.subpaths(&conditions(TargetEnv::Node, &[])),
+      HashMap::from([
+        (".".to_string(), "./index.js".to_string()),
+        ("./colors".to_string(), "./colors.js".to_string()),
+      ])
+    );
+  }
+
+  #[test]
+  fn resolves_sugared_exports_as_main_subpath() {
+    assert_eq!(
+      parse(r#""./index.js""#).subpaths(&conditions(TargetEnv::Node, &[])),
+      HashMap::from([(".".to_string(), "./index.js".to_string())])
+    );
+
+    assert_eq!(
+      parse(r#"{ "browser": "./browser.js", "default": "./index.js" }"#)
+        .subpaths(&conditions(TargetEnv::Browser, &[])),
+      HashMap::from([(".".to_string(), "./browser.js".to_string())])
+    );
+  }
+
+  #[test]
+  fn resolves_conditions_of_target_env() {
+    let exports = parse(
+      r#"{
+        "./tokens": {
+          "browser": "./tokens.browser.js",
+          "node": "./tokens.node.js",
+          "default": "./tokens.js"
+        }
+      }"#,
+    );
+
+    assert_eq!(
+      exports.subpaths(&conditions(TargetEnv::Node, &[]))["./tokens"],
+      "./tokens.node.js"
+    );
+    assert_eq!(
+      exports.subpaths(&conditions(TargetEnv::Browser, &[]))["./tokens"],
+      "./tokens.browser.js"
+    );
+  }
+
+  #[test]
+  fn resolves_custom_conditions_in_object_order() {
+    let exports = parse(
+      r#"{
+        "./tokens": {
+          "worker": "./tokens.worker.js",
+          "development": { "import": "./tokens.dev.mjs" },
+          "default": "./tokens.js"
+        }
+      }"#,
+    );
+
+    assert_eq!(
+      exports.subpaths(&conditions(TargetEnv::Node, &[]))["./tokens"],
+      "./tokens.js"
+    );
+    assert_eq!(
+      exports.subpaths(&conditions(TargetEnv::Node, &["development"]))["./tokens"],
+      "./tokens.dev.mjs"
+    );
+    assert_eq!(
+      exports.subpaths(&conditions(TargetEnv::Node, &["development", "worker"]))["./tokens"],
+      "./tokens.worker.js"
+    );
+  }
+
+  #[test]
+  fn skips_excluded_and_unmatched_targets() {
+    let exports = parse(
+      r#"{
+        "./internal": null,
+        "./worker": { "worker": "./worker.js" },
+        "./colors": ["./colors.mjs", "./colors.js"]
+      }"#,
+    );
+
+    assert_eq!(
+      exports.subpaths(&conditions(TargetEnv::Node, &[])),
+      HashMap::from([("./colors".to_string(), "./colors.mjs".to_string())])
+    );
+  }
+
+  #[test]
+  fn serializes_exports_in_order() {
+    let exports = r#"{"./b":{"node":"./b.js","default":null},"./a":["./a.js"]}"#;
+
+    assert_eq!(serde_json::to_string(&parse(exports)).unwrap(), exports);
+  }
+}
+
+#[cfg(test)]
+mod package_json_tests {
+  use std::path::PathBuf;
+
+  use crate::{errors::ResolveError, package_json::get_package_json};
+
+  #[test]
+  fn reports_invalid_package_json_files() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/invalid-package-json");
+
+    match get_package_json(&path) {
+      Err(ResolveError::PackageJson { path, .. }) => {
+        assert!(path.ends_with("invalid-package-json/package.json"))
+      }
+      result => panic!("Expected a package.json error, got: {:?}", result.err()),
+    }
+  }
+}
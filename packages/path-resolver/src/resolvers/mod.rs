@@ -4,21 +4,73 @@ use std::path::{Path, PathBuf};
 use std::{collections::HashMap, default::Default};
 use swc_core::{
   common::FileName,
-  ecma::loader::{resolve::Resolve, resolvers::node::NodeModulesResolver, TargetEnv},
+  ecma::loader::{resolve::Resolve, resolvers::node::NodeModulesResolver},
 };
 
-use std::fs;
+pub use swc_core::ecma::loader::TargetEnv;
+
+use std::{fs, io};
 
 use crate::{
-  package_json::get_package_json,
-  utils::{contains_subpath, relative_path},
+  errors::ResolveError,
+  package_json::{get_package_json, get_package_json_path, read_package_json},
+  utils::{find_subpath_dir, normalize_path, normalize_path_separators, relative_path},
 };
 
 mod tests;
 
 pub const EXTENSIONS: [&str; 8] = [".tsx", ".ts", ".jsx", ".js", ".mjs", ".cjs", ".mdx", ".md"];
 
-pub fn resolve_path(processing_file: &Path, root_dir: &Path) -> String {
+/// Environment packages are resolved for, picking the `browser` or `node`
+/// conditions of their `exports`, together with custom conditions, e.g.
+/// `worker` or `development`.
+#[derive(Debug, Clone)]
+pub struct ResolverOptions {
+  pub target_env: TargetEnv,
+  pub conditions: Vec<String>,
+}
+
+impl Default for ResolverOptions {
+  fn default() -> Self {
+    ResolverOptions {
+      target_env: TargetEnv::Node,
+      conditions: vec![],
+    }
+  }
+}
+
+impl ResolverOptions {
+  /// Conditions of `exports` objects that match, besides `default`.
+  pub fn export_conditions(&self) -> Vec<String> {
+    let env_condition = match self.target_env {
+      TargetEnv::Browser => "browser",
+      TargetEnv::Node => "node",
+    };
+
+    let mut conditions = self.conditions.clone();
+
+    for condition in [env_condition, "import", "require"] {
+      if !conditions.iter().any(|c| c == condition) {
+        conditions.push(condition.to_string());
+      }
+    }
+
+    conditions
+  }
+}
+
+pub fn resolve_path(processing_file: &Path, root_dir: &Path) -> Result<String, ResolveError> {
+  resolve_path_with_options(processing_file, root_dir, &ResolverOptions::default())
+}
+
+pub fn resolve_path_with_options(
+  processing_file: &Path,
+  root_dir: &Path,
+  options: &ResolverOptions,
+) -> Result<String, ResolveError> {
+  let processing_file = normalize_path(processing_file);
+  let root_dir = normalize_path(root_dir);
+
   let file_pattern = Regex::new(r"\.(jsx?|tsx?|mdx?|mjs|cjs)$").unwrap(); // Matches common file extensions
 
   if !file_pattern.is_match(processing_file.to_str().unwrap()) {
@@ -37,10 +89,7 @@ pub fn resolve_path(processing_file: &Path, root_dir: &Path) -> String {
       processing_path = processing_file.to_path_buf();
     }
 
-    panic!(
-      r#"Resolve path must be a file, but got: {}"#,
-      processing_path.display()
-    );
+    return Err(ResolveError::NotAFile(processing_path));
   }
 
   let cwd: PathBuf;
@@ -55,81 +104,81 @@ pub fn resolve_path(processing_file: &Path, root_dir: &Path) -> String {
     cwd = "cwd".into();
   }
 
-  let mut stripped_path = match processing_file.strip_prefix(root_dir) {
+  let mut stripped_path = match processing_file.strip_prefix(&root_dir) {
     Ok(stripped) => stripped.to_path_buf(),
     Err(_) => {
-      let resolver = NodeModulesResolver::new(TargetEnv::Node, Default::default(), true);
+      let resolver = NodeModulesResolver::new(options.target_env, Default::default(), true);
 
-      let (package_json, _) = get_package_json(cwd.as_path());
+      let (package_json, _) = get_package_json(cwd.as_path())?;
 
-      let relative_package_path = relative_path(processing_file, root_dir);
+      let relative_package_path = relative_path(&processing_file, &root_dir);
 
       let mut package_dependencies = package_json.dependencies.unwrap_or_default();
       let package_dev_dependencies = package_json.dev_dependencies.unwrap_or_default();
 
       package_dependencies.extend(package_dev_dependencies);
 
+      let workspace_dependencies = package_dependencies
+        .iter()
+        .filter(|(_, version)| version.starts_with("workspace"))
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<&str>>();
+
       let mut potential_package_path: String = Default::default();
 
-      for (name, version) in package_dependencies.iter() {
-        if version.starts_with("workspace") {
-          let file_name = FileName::Real(cwd.to_path_buf());
-
-          let potential_path_section = name.split("/").last().unwrap_or_default();
-
-          if contains_subpath(&relative_package_path, Path::new(&potential_path_section)) {
-            let relative_package_path_str = relative_package_path.display().to_string();
-
-            let potential_file_path = relative_package_path_str
-              .split(potential_path_section)
-              .last()
-              .unwrap_or_default();
-
-            if !potential_file_path.is_empty()
-              || relative_package_path_str
-                .ends_with(format!("/{}", potential_path_section).as_str())
-            {
-              let resolved_node_modules_path = get_node_modules_path(&resolver, &file_name, name);
-
-              if let Some(resolved_node_modules_path) = resolved_node_modules_path {
-                if let FileName::Real(real_resolved_node_modules_path) =
-                  resolved_node_modules_path.filename
-                {
-                  let (potential_package_json, _) =
-                    get_package_json(real_resolved_node_modules_path.as_path());
-
-                  match &potential_package_json.exports {
-                    Some(exports) => resolve_package_json_exports(
-                      potential_file_path,
-                      exports,
-                      &mut potential_package_path,
-                      &real_resolved_node_modules_path,
-                    ),
-                    None => {
-                      let node_modules_regex = Regex::new(r".*node_modules").unwrap();
-
-                      potential_package_path = node_modules_regex
-                        .replace(
-                          real_resolved_node_modules_path
-                            .display()
-                            .to_string()
-                            .as_str(),
-                          "node_modules",
-                        )
-                        .to_string();
-                    }
-                  }
+      if let Some((name, potential_file_path)) = find_workspace_package(
+        &processing_file,
+        &relative_package_path,
+        &workspace_dependencies,
+      ) {
+        let file_name = FileName::Real(cwd.to_path_buf());
+
+        let resolved_node_modules_path = get_node_modules_path(&resolver, &file_name, name);
+
+        if let Some(resolved_node_modules_path) = resolved_node_modules_path {
+          if let FileName::Real(real_resolved_node_modules_path) =
+            resolved_node_modules_path.filename
+          {
+            let (potential_package_json, _) =
+              get_package_json(real_resolved_node_modules_path.as_path())?;
+
+            match &potential_package_json.exports {
+              Some(exports) => {
+                // Falls back to the `node_modules/<name>` path below
+                if let Err(error) = resolve_package_json_exports(
+                  name,
+                  &potential_file_path,
+                  &exports.subpaths(&options.export_conditions()),
+                  &mut potential_package_path,
+                  &real_resolved_node_modules_path,
+                ) {
+                  tracing::warn!("{}", error);
                 }
               }
-
-              if potential_package_path.is_empty() {
-                potential_package_path = format!("node_modules/{}{}", name, potential_file_path);
+              None => {
+                let node_modules_regex = Regex::new(r".*node_modules").unwrap();
+
+                potential_package_path = node_modules_regex
+                  .replace(
+                    normalize_path_separators(
+                      &real_resolved_node_modules_path.display().to_string(),
+                    )
+                    .as_str(),
+                    "node_modules",
+                  )
+                  .to_string();
               }
-
-              break;
             }
           }
         }
+
+        if potential_package_path.is_empty() {
+          potential_package_path = format!("node_modules/{}{}", name, potential_file_path);
+        }
+      }
+
+      if potential_package_path.is_empty() {
+        return Err(ResolveError::OutsideRoot(processing_file));
       }
 
       PathBuf::from(potential_package_path)
@@ -140,7 +189,7 @@ pub fn resolve_path(processing_file: &Path, root_dir: &Path) -> String {
     stripped_path = stripped_path.strip_prefix(cwd).unwrap().to_path_buf();
   }
 
-  let resolved_path = stripped_path.clean().display().to_string();
+  let resolved_path = normalize_path_separators(&stripped_path.clean().display().to_string());
 
   #[cfg(test)]
   {
@@ -153,7 +202,161 @@ pub fn resolve_path(processing_file: &Path, root_dir: &Path) -> String {
     );
   }
 
-  resolved_path
+  Ok(resolved_path)
+}
+
+/// Returns the root of `root_dirs` containing `path`, the deepest one when
+/// roots are nested, e.g. `packages/ui` over the repository root.
+pub fn find_root_dir<'a>(path: &Path, root_dirs: &'a [PathBuf]) -> Option<&'a Path> {
+  let path = normalize_path(path).clean();
+
+  root_dirs
+    .iter()
+    .filter(|root_dir| path.starts_with(normalize_path(root_dir).clean()))
+    .max_by_key(|root_dir| normalize_path(root_dir).clean().components().count())
+    .map(PathBuf::as_path)
+}
+
+/// Returns the deepest directory containing every root of `root_dirs`, the
+/// root itself when there is only one.
+fn find_roots_base_dir(root_dirs: &[PathBuf]) -> Option<PathBuf> {
+  let mut root_dirs = root_dirs
+    .iter()
+    .map(|root_dir| normalize_path(root_dir).clean());
+
+  let mut base_dir = root_dirs.next()?;
+
+  for root_dir in root_dirs {
+    while !root_dir.starts_with(&base_dir) {
+      if !base_dir.pop() {
+        break;
+      }
+    }
+  }
+
+  Some(base_dir)
+}
+
+/// Prefixes the path of a file resolved relative to `root_dir` with the path
+/// of that root relative to the base of `root_dirs`, so files at the same
+/// path in different roots, e.g. `apps/web/src/tokens.stylex.js` and
+/// `packages/ui/src/tokens.stylex.js`, get distinct identifiers. Paths in a
+/// single root are left as they are.
+fn prefix_root_dir(resolved_path: &str, root_dir: &Path, root_dirs: &[PathBuf]) -> String {
+  let root_dir = normalize_path(root_dir).clean();
+
+  let root_prefix = find_roots_base_dir(root_dirs)
+    .and_then(|base_dir| root_dir.strip_prefix(base_dir).ok().map(Path::to_path_buf))
+    .unwrap_or_default();
+
+  normalize_path_separators(
+    &root_prefix
+      .join(resolved_path)
+      .clean()
+      .display()
+      .to_string(),
+  )
+}
+
+/// Resolves `processing_file` relative to the root of `root_dirs` containing
+/// it, prefixed with the path of that root relative to the other roots, see
+/// [`find_path_in_roots`] for the way back.
+///
+/// Files outside of every root, e.g. installed packages, are resolved
+/// relative to the first root.
+pub fn resolve_path_in_roots(
+  processing_file: &Path,
+  root_dirs: &[PathBuf],
+  options: &ResolverOptions,
+) -> Result<String, ResolveError> {
+  if let Some(root_dir) = find_root_dir(processing_file, root_dirs) {
+    let resolved_path = resolve_path_with_options(processing_file, root_dir, options)?;
+
+    return Ok(prefix_root_dir(&resolved_path, root_dir, root_dirs));
+  }
+
+  let root_dir = root_dirs
+    .first()
+    .ok_or_else(|| ResolveError::OutsideRoot(processing_file.to_path_buf()))?;
+
+  resolve_path_with_options(processing_file, root_dir, options)
+}
+
+/// Returns the file identified by `resolved_path`, as returned by
+/// [`resolve_path_in_roots`]: the path below the base of `root_dirs` when
+/// it lands in one of the roots, otherwise below the first root.
+pub fn find_path_in_roots(resolved_path: &str, root_dirs: &[PathBuf]) -> Option<PathBuf> {
+  let resolved_path = resolved_path.trim_start_matches('/');
+
+  let path = find_roots_base_dir(root_dirs)?.join(resolved_path).clean();
+
+  if find_root_dir(&path, root_dirs).is_some() {
+    return Some(path);
+  }
+
+  root_dirs
+    .first()
+    .map(|root_dir| root_dir.join(resolved_path).clean())
+}
+
+/// Finds the workspace dependency that owns `processing_file`, together with
+/// the path of the file inside of that package.
+///
+/// The closest package manifest decides first, since workspace directories
+/// don't have to be named after their packages. Otherwise the full package
+/// name (including its scope) has to appear as consecutive path segments,
+/// preferring the longest name so `@acme/tokens` wins over `tokens`.
+fn find_workspace_package<'a>(
+  processing_file: &Path,
+  relative_package_path: &Path,
+  workspace_dependencies: &[&'a str],
+) -> Option<(&'a str, String)> {
+  if let Some(parent) = processing_file.parent() {
+    if let Ok((manifest_path, _)) = get_package_json_path(parent) {
+      let manifest = read_package_json(&manifest_path).ok();
+      let package_dir = manifest_path.parent().map(normalize_path);
+
+      let workspace_package = manifest.and_then(|manifest| {
+        workspace_dependencies
+          .iter()
+          .find(|name| **name == manifest.name)
+      });
+
+      if let (Some(name), Some(package_dir)) = (workspace_package, package_dir) {
+        if let Ok(file_path) = processing_file.strip_prefix(&package_dir) {
+          return Some((
+            name,
+            format!(
+              "/{}",
+              normalize_path_separators(&file_path.display().to_string())
+            ),
+          ));
+        }
+      }
+    }
+  }
+
+  let relative_package_path = normalize_path(relative_package_path);
+
+  let mut candidates = workspace_dependencies.to_vec();
+  candidates.sort_by_key(|name| std::cmp::Reverse(name.len()));
+
+  candidates.into_iter().find_map(|name| {
+    let package_dir = find_subpath_dir(&relative_package_path, Path::new(name))?;
+    let file_path = relative_package_path.strip_prefix(package_dir).ok()?;
+
+    if file_path.as_os_str().is_empty() {
+      return Some((name, String::new()));
+    }
+
+    Some((
+      name,
+      format!(
+        "/{}",
+        normalize_path_separators(&file_path.display().to_string())
+      ),
+    ))
+  })
 }
 
 fn get_node_modules_path(
@@ -177,11 +380,12 @@ fn get_node_modules_path(
 }
 
 fn resolve_package_json_exports(
+  package_name: &str,
   potential_file_path: &str,
   exports: &HashMap<String, String>,
   potential_package_path: &mut String,
   real_resolved_node_modules_path: &Path,
-) {
+) -> Result<(), ResolveError> {
   let potential_file_path_without_extension = PathBuf::from(potential_file_path)
     .with_extension("")
     .display()
@@ -220,9 +424,31 @@ fn resolve_package_json_exports(
   }
 
   if potential_package_path.is_empty() {
-    eprintln!("Unfortunatly, the exports field is not yet fully supported, so path resolving may work not as expected");
     // TODO: implement exports field resolution
+    return Err(ResolveError::ExportsMismatch {
+      package: package_name.to_string(),
+      subpath: potential_file_path.to_string(),
+    });
   }
+
+  Ok(())
+}
+
+/// Returns `name@version` (or just `name` for unversioned packages) of the
+/// package owning `path`, read from its closest `package.json`.
+pub fn get_package_scope(path: &Path) -> Option<String> {
+  let (package_json, _) = get_package_json(path).ok()?;
+
+  match package_json.version {
+    Some(version) => Some(format!("{}@{}", package_json.name, version)),
+    None => Some(package_json.name),
+  }
+}
+
+/// Returns the closest `package.json` of `path`, the one
+/// [`get_package_scope`] reads.
+pub fn find_package_json(path: &Path) -> Option<PathBuf> {
+  get_package_json_path(path).ok().map(|(file, _)| file)
 }
 
 pub fn resolve_file_path(
@@ -230,20 +456,86 @@ pub fn resolve_file_path(
   source_file_path: &str,
   ext: &str,
   root_path: &str,
-) -> std::io::Result<PathBuf> {
-  let source_dir = Path::new(source_file_path).parent().unwrap();
+) -> Result<PathBuf, ResolveError> {
+  resolve_file_path_with_options(
+    import_path_str,
+    source_file_path,
+    ext,
+    root_path,
+    &ResolverOptions::default(),
+  )
+}
+
+/// Resolves `import_path_str` imported by `source_file_path` relative to the
+/// root of `root_dirs` containing the imported file, so every importer of a
+/// file agrees on its path.
+pub fn resolve_file_path_in_roots(
+  import_path_str: &str,
+  source_file_path: &str,
+  ext: &str,
+  root_dirs: &[PathBuf],
+  options: &ResolverOptions,
+) -> Result<PathBuf, ResolveError> {
+  let source_path = Path::new(source_file_path);
+
+  let imported_path = if import_path_str.starts_with('.') {
+    source_path
+      .parent()
+      .unwrap_or(Path::new(""))
+      .join(import_path_str)
+  } else {
+    source_path.to_path_buf()
+  };
+
+  let imported_root_dir = find_root_dir(&imported_path, root_dirs);
+
+  let root_dir = imported_root_dir
+    .or_else(|| find_root_dir(source_path, root_dirs))
+    .or_else(|| root_dirs.first().map(PathBuf::as_path))
+    .ok_or_else(|| ResolveError::OutsideRoot(source_path.to_path_buf()))?;
+
+  let resolved_file_path = resolve_file_path_with_options(
+    import_path_str,
+    source_file_path,
+    ext,
+    &root_dir.display().to_string(),
+    options,
+  )?;
+
+  // Relative imports resolve to the path in the root, which identifies the
+  // file like `resolve_path_in_roots` does for the imported file itself
+  match imported_root_dir {
+    Some(root_dir) if import_path_str.starts_with('.') => Ok(PathBuf::from(prefix_root_dir(
+      &resolved_file_path.display().to_string(),
+      root_dir,
+      root_dirs,
+    ))),
+    _ => Ok(resolved_file_path),
+  }
+}
+
+pub fn resolve_file_path_with_options(
+  import_path_str: &str,
+  source_file_path: &str,
+  ext: &str,
+  root_path: &str,
+  options: &ResolverOptions,
+) -> Result<PathBuf, ResolveError> {
+  let source_file_path = normalize_path_separators(source_file_path);
+  let root_path = normalize_path_separators(root_path);
+
+  let source_dir = Path::new(&source_file_path).parent().unwrap();
 
   let mut resolved_file_path = (if import_path_str.starts_with('.') {
-    let root_path: &Path = Path::new(root_path);
+    let root_path: &Path = Path::new(&root_path);
 
-    let resolved_import_path = PathBuf::from(resolve_path(
+    PathBuf::from(resolve_path_with_options(
       source_dir.join(import_path_str).as_path(),
       root_path,
-    ));
-
-    resolved_import_path
+      options,
+    )?)
   } else if import_path_str.starts_with('/') {
-    Path::new(root_path).join(import_path_str)
+    Path::new(&root_path).join(import_path_str)
   } else {
     let path = Path::new("node_modules").join(import_path_str);
 
@@ -281,7 +573,7 @@ pub fn resolve_file_path(
 
   #[cfg(test)]
   {
-    cwd = root_path;
+    cwd = &root_path;
   }
 
   #[cfg(not(test))]
@@ -296,14 +588,18 @@ pub fn resolve_file_path(
     path_to_check = Path::new(cwd).join(path_to_check);
   }
 
+  // Relative imports resolve to a path in `root_path`
+  let root_path_to_check = Path::new(&root_path).join(cleaned_path);
+
   if fs::metadata(path_to_check.clone()).is_ok()
     || fs::metadata(node_modules_path_to_check.clone()).is_ok()
+    || fs::metadata(root_path_to_check).is_ok()
   {
-    Ok(resolved_file_path.to_path_buf())
+    Ok(normalize_path(&resolved_file_path))
   } else {
-    Err(std::io::Error::new(
-      std::io::ErrorKind::NotFound,
-      "File not found",
-    ))
+    Err(ResolveError::Io(io::Error::new(
+      io::ErrorKind::NotFound,
+      format!("File not found: {}", resolved_file_path.display()),
+    )))
   }
 }
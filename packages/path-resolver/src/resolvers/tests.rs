@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod resolve_path_tests {
-  use crate::resolvers::resolve_path;
+  use crate::resolvers::{
+    find_path_in_roots, find_root_dir, get_package_scope, resolve_file_path, resolve_path,
+    resolve_path_in_roots, ResolverOptions,
+  };
   use path_clean::PathClean;
   use std::{
     env,
@@ -32,6 +35,10 @@ mod resolve_path_tests {
     new_cwd
   }
 
+  fn to_windows_path(path: &Path) -> PathBuf {
+    PathBuf::from(path.display().to_string().replace('/', "\\"))
+  }
+
   #[test]
   fn resolve_work_dir_packages() {
     let test_path = PathBuf::from("workspace");
@@ -40,7 +47,8 @@ mod resolve_path_tests {
       resolve_path(
         fixture(&test_path, "test/index.js").as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "test/index.js"
     );
 
@@ -48,7 +56,8 @@ mod resolve_path_tests {
       resolve_path(
         fixture(&test_path, "index.js").as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "index.js"
     );
   }
@@ -61,7 +70,8 @@ mod resolve_path_tests {
     resolve_path(
       fixture(&test_path, "index.jsx").as_path(),
       get_root_dir(&test_path).as_path(),
-    );
+    )
+    .unwrap();
   }
 
   #[test]
@@ -76,7 +86,8 @@ mod resolve_path_tests {
         )
         .as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/@stylex/open-props/lib/colors.stylex.js"
     );
   }
@@ -95,7 +106,8 @@ mod resolve_path_tests {
       )
       .as_path(),
       get_root_dir(&test_path).as_path(),
-    );
+    )
+    .unwrap();
   }
 
   #[test]
@@ -112,7 +124,8 @@ mod resolve_path_tests {
       )
       .as_path(),
       get_root_dir(&test_path).as_path(),
-    );
+    )
+    .unwrap();
   }
 
   #[test]
@@ -123,7 +136,8 @@ mod resolve_path_tests {
       resolve_path(
         fixture(&test_path, "node_modules/stylex-lib/colors.stylex.js").as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/stylex-lib/colors.stylex.js"
     );
   }
@@ -141,7 +155,8 @@ mod resolve_path_tests {
         )
         .as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/stylex-lib/colors.stylex.js"
     );
   }
@@ -159,11 +174,88 @@ mod resolve_path_tests {
         )
         .as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/@stylex/theme-lib/colors.stylex.js"
     );
   }
 
+  #[test]
+  fn workspace_scoped_package_from_manifest() {
+    let test_path = PathBuf::from("scoped");
+    let local_package_test_path = PathBuf::from("");
+
+    assert_eq!(
+      resolve_path(
+        fixture(
+          &local_package_test_path,
+          "scoped-packages/acme/tokens/colors.stylex.js"
+        )
+        .as_path(),
+        get_root_dir(&test_path).as_path()
+      )
+      .unwrap(),
+      "node_modules/@acme/tokens/colors.stylex.js"
+    );
+  }
+
+  #[test]
+  fn workspace_unscoped_package_colliding_with_scoped_name() {
+    let test_path = PathBuf::from("scoped");
+    let local_package_test_path = PathBuf::from("");
+
+    assert_eq!(
+      resolve_path(
+        fixture(
+          &local_package_test_path,
+          "scoped-packages/tokens/colors.stylex.js"
+        )
+        .as_path(),
+        get_root_dir(&test_path).as_path()
+      )
+      .unwrap(),
+      "node_modules/tokens/colors.stylex.js"
+    );
+  }
+
+  #[test]
+  fn workspace_scoped_package_without_manifest() {
+    let test_path = PathBuf::from("scoped");
+    let local_package_test_path = PathBuf::from("");
+
+    assert_eq!(
+      resolve_path(
+        fixture(
+          &local_package_test_path,
+          "scoped-packages/@acme/tokens/spacing.stylex.js"
+        )
+        .as_path(),
+        get_root_dir(&test_path).as_path()
+      )
+      .unwrap(),
+      "node_modules/@acme/tokens/spacing.stylex.js"
+    );
+  }
+
+  #[test]
+  fn workspace_package_file_path_repeating_the_package_name() {
+    let test_path = PathBuf::from("scoped");
+    let local_package_test_path = PathBuf::from("");
+
+    assert_eq!(
+      resolve_path(
+        fixture(
+          &local_package_test_path,
+          "scoped-packages/@acme/tokens/vendor/@acme/tokens.stylex.js"
+        )
+        .as_path(),
+        get_root_dir(&test_path).as_path()
+      )
+      .unwrap(),
+      "node_modules/@acme/tokens/vendor/@acme/tokens.stylex.js"
+    );
+  }
+
   #[test]
   fn external_package_main_exports() {
     let test_path = PathBuf::from("exports");
@@ -176,7 +268,8 @@ mod resolve_path_tests {
         )
         .as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/stylex-lib-dist-main/dist/index.jsx"
     );
   }
@@ -192,7 +285,8 @@ mod resolve_path_tests {
         )
         .as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/stylex-lib-dist-module/dist/index.jsx"
     );
   }
@@ -209,7 +303,8 @@ mod resolve_path_tests {
         )
         .as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/stylex-lib-dist-exports/dist/index.js"
     );
   }
@@ -227,26 +322,30 @@ mod resolve_path_tests {
         )
         .as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/stylex-lib-dist-main-local/dist/index.jsx"
     );
   }
 
   #[test]
-  #[should_panic(
-    expected = "Resolve path must be a file, but got: fixtures/packages/stylex-lib-dist-main-local"
-  )]
   fn resolve_work_dir_not_existed_workspace_package_main_exports() {
     let test_path = PathBuf::from("exports");
     let local_package_test_path = PathBuf::from("");
 
-    resolve_path(
+    let error = resolve_path(
       fixture(
         &local_package_test_path,
         "packages/stylex-lib-dist-main-local",
       )
       .as_path(),
       get_root_dir(&test_path).as_path(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "Resolve path must be a file, but got: fixtures/packages/stylex-lib-dist-main-local"
     );
   }
 
@@ -263,7 +362,8 @@ mod resolve_path_tests {
         )
         .as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/stylex-lib-dist-module-local/dist/index.jsx"
     );
   }
@@ -281,7 +381,8 @@ mod resolve_path_tests {
         )
         .as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/stylex-lib-dist-exports/dist/index.js"
     );
 
@@ -293,7 +394,8 @@ mod resolve_path_tests {
         )
         .as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/stylex-lib-dist-exports-local/dist/index.js"
     );
 
@@ -305,112 +407,128 @@ mod resolve_path_tests {
         )
         .as_path(),
         get_root_dir(&test_path).as_path()
-      ),
+      )
+      .unwrap(),
       "node_modules/stylex-lib-dist-exports-local/dist/colors.stylex.js"
     );
   }
 
   #[test]
-  #[should_panic(
-    expected = "Resolve path must be a file, but got: fixtures/packages/stylex-lib-dist-module-local"
-  )]
   fn resolve_path_not_a_file_workspace_package_module_exports() {
     let test_path = PathBuf::from("exports");
     let local_package_test_path = PathBuf::from("");
 
-    resolve_path(
+    let error = resolve_path(
       fixture(
         &local_package_test_path,
         "packages/stylex-lib-dist-module-local",
       )
       .as_path(),
       get_root_dir(&test_path).as_path(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "Resolve path must be a file, but got: fixtures/packages/stylex-lib-dist-module-local"
     );
   }
 
   #[test]
-  #[should_panic(
-    expected = "Resolve path must be a file, but got: fixtures/packages/stylex-lib-dist-exports/colors.stylex"
-  )]
   fn resolve_work_dir_not_existed_workspace_package_exports() {
     let test_path = PathBuf::from("exports");
     let local_package_test_path = PathBuf::from("");
 
-    resolve_path(
+    let error = resolve_path(
       fixture(
         &local_package_test_path,
         "packages/stylex-lib-dist-exports/colors.stylex",
       )
       .as_path(),
       get_root_dir(&test_path).as_path(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "Resolve path must be a file, but got: fixtures/packages/stylex-lib-dist-exports/colors.stylex"
     );
   }
 
   #[test]
-  #[should_panic(
-    expected = "Resolve path must be a file, but got: fixtures/exports/node_modules/stylex-lib-dist-exports/colors.stylex"
-  )]
   fn resolve_work_dir_not_existed_external_package_exports() {
     let test_path = PathBuf::from("exports");
 
-    resolve_path(
+    let error = resolve_path(
       fixture(
         &test_path,
         "node_modules/stylex-lib-dist-exports/colors.stylex",
       )
       .as_path(),
       get_root_dir(&test_path).as_path(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "Resolve path must be a file, but got: fixtures/exports/node_modules/stylex-lib-dist-exports/colors.stylex"
     );
   }
 
   #[test]
-  #[should_panic(
-    expected = "Resolve path must be a file, but got: fixtures/exports/node_modules/stylex-lib-dist-exports"
-  )]
   fn failed_resolve_root_package_path() {
     let test_path = PathBuf::from("exports");
 
+    let error = resolve_path(
+      fixture(&test_path, "node_modules/stylex-lib-dist-exports").as_path(),
+      get_root_dir(&test_path).as_path(),
+    )
+    .unwrap_err();
+
     assert_eq!(
-      resolve_path(
-        fixture(&test_path, "node_modules/stylex-lib-dist-exports").as_path(),
-        get_root_dir(&test_path).as_path()
-      ),
-      "node_modules/stylex-lib-dist-exports/dist/index.js"
+      error.to_string(),
+      "Resolve path must be a file, but got: fixtures/exports/node_modules/stylex-lib-dist-exports"
     );
   }
 
   #[test]
-  #[should_panic(
-    expected = "Resolve path must be a file, but got: fixtures/exports/node_modules/stylex-lib-dist-exports/colors.stylex"
-  )]
   fn failed_resolve_package_exports_dir_path() {
     let test_path = PathBuf::from("exports");
 
-    resolve_path(
+    let error = resolve_path(
       fixture(
         &test_path,
         "node_modules/stylex-lib-dist-exports/colors.stylex",
       )
       .as_path(),
       get_root_dir(&test_path).as_path(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "Resolve path must be a file, but got: fixtures/exports/node_modules/stylex-lib-dist-exports/colors.stylex"
     );
   }
 
   #[test]
-  #[should_panic(
-    expected = "Resolve path must be a file, but got: fixtures/packages/stylex-lib-dist-exports-local"
-  )]
   fn failed_resolve_local_package_root_dir_path() {
     let test_path = PathBuf::from("exports");
     let local_package_test_path = PathBuf::from("");
 
-    resolve_path(
+    let error = resolve_path(
       fixture(
         &local_package_test_path,
         "packages/stylex-lib-dist-exports-local",
       )
       .as_path(),
       get_root_dir(&test_path).as_path(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "Resolve path must be a file, but got: fixtures/packages/stylex-lib-dist-exports-local"
     );
   }
 
@@ -429,6 +547,158 @@ mod resolve_path_tests {
       )
       .as_path(),
       get_root_dir(&test_path).as_path(),
+    )
+    .unwrap();
+  }
+
+  #[test]
+  fn resolve_work_dir_packages_with_windows_separators() {
+    let test_path = PathBuf::from("workspace");
+
+    assert_eq!(
+      resolve_path(
+        to_windows_path(&fixture(&test_path, "test/index.js")).as_path(),
+        to_windows_path(&get_root_dir(&test_path)).as_path()
+      )
+      .unwrap(),
+      "test/index.js"
+    );
+  }
+
+  #[test]
+  fn resolve_file_path_with_windows_separators() {
+    let test_path = PathBuf::from("workspace");
+    let root_dir = to_windows_path(&get_root_dir(&test_path));
+
+    assert_eq!(
+      resolve_file_path(
+        "../index.js",
+        to_windows_path(&fixture(&test_path, "test/index.js"))
+          .to_str()
+          .unwrap(),
+        ".js",
+        root_dir.to_str().unwrap(),
+      )
+      .unwrap(),
+      PathBuf::from("index.js")
+    );
+  }
+
+  #[test]
+  fn not_a_file_error_with_windows_separators() {
+    let test_path = PathBuf::from("workspace");
+
+    let error = resolve_path(
+      to_windows_path(&fixture(&test_path, "test")).as_path(),
+      to_windows_path(&get_root_dir(&test_path)).as_path(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "Resolve path must be a file, but got: fixtures/workspace/test"
+    );
+  }
+
+  #[test]
+  fn resolve_path_relative_to_deepest_root() {
+    let test_path = PathBuf::from("workspace");
+    let root_dir = get_root_dir(&test_path);
+    let root_dirs = vec![root_dir.clone(), root_dir.join("test")];
+
+    assert_eq!(
+      resolve_path_in_roots(
+        fixture(&test_path, "test/index.js").as_path(),
+        &root_dirs,
+        &ResolverOptions::default()
+      )
+      .unwrap(),
+      "test/index.js"
+    );
+
+    assert_eq!(
+      resolve_path_in_roots(
+        fixture(&test_path, "index.js").as_path(),
+        &root_dirs,
+        &ResolverOptions::default()
+      )
+      .unwrap(),
+      "index.js"
+    );
+  }
+
+  #[test]
+  fn resolve_same_paths_in_different_roots() {
+    let workspace_dir = get_root_dir(&PathBuf::from("workspace"));
+    let scoped_dir = fixture(&PathBuf::from("scoped"), "");
+    let root_dirs = vec![workspace_dir.clone(), scoped_dir.clone()];
+
+    let workspace_path = resolve_path_in_roots(
+      workspace_dir.join("index.js").as_path(),
+      &root_dirs,
+      &ResolverOptions::default(),
+    )
+    .unwrap();
+
+    let scoped_path = resolve_path_in_roots(
+      scoped_dir.join("index.js").as_path(),
+      &root_dirs,
+      &ResolverOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!(workspace_path, "workspace/index.js");
+    assert_eq!(scoped_path, "scoped/index.js");
+
+    assert_eq!(
+      find_path_in_roots(&workspace_path, &root_dirs),
+      Some(workspace_dir.join("index.js"))
+    );
+    assert_eq!(
+      find_path_in_roots(&scoped_path, &root_dirs),
+      Some(scoped_dir.join("index.js"))
+    );
+  }
+
+  #[test]
+  fn find_root_dir_containing_path() {
+    let root_dirs = vec![
+      PathBuf::from("/repo"),
+      PathBuf::from("/repo/apps/web"),
+      PathBuf::from("/repo/packages/ui"),
+    ];
+
+    assert_eq!(
+      find_root_dir(Path::new("/repo/apps/web/src/index.js"), &root_dirs),
+      Some(Path::new("/repo/apps/web"))
+    );
+    assert_eq!(
+      find_root_dir(
+        Path::new("/repo/apps/web/src/../../../packages/ui/tokens.stylex.js"),
+        &root_dirs
+      ),
+      Some(Path::new("/repo/packages/ui"))
+    );
+    assert_eq!(
+      find_root_dir(Path::new("/repo/scripts/build.js"), &root_dirs),
+      Some(Path::new("/repo"))
+    );
+    assert_eq!(
+      find_root_dir(Path::new("/other/index.js"), &root_dirs),
+      None
+    );
+  }
+
+  #[test]
+  fn package_scope_of_closest_package() {
+    assert_eq!(
+      get_package_scope(fixture(&PathBuf::from("workspace"), "test").as_path()),
+      Some("workspace-app@0.1.0".to_string())
+    );
+
+    assert_eq!(
+      get_package_scope(fixture(&PathBuf::from("scoped-packages"), "acme/tokens").as_path()),
+      Some("@acme/tokens@0.1.0".to_string())
     );
   }
 }
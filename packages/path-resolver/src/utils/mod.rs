@@ -2,13 +2,33 @@ use std::path::{Path, PathBuf};
 
 use path_clean::PathClean;
 
-pub(crate) fn contains_subpath(path: &Path, sub_path: &Path) -> bool {
-  path
-    .display()
-    .to_string()
-    .split("/")
-    .any(|part| part == sub_path.display().to_string())
+/// Converts Windows `\` separators to `/`, so paths can be compared and
+/// used in identifiers the same way on every platform.
+pub fn normalize_path_separators(path: &str) -> String {
+  path.replace('\\', "/")
 }
+
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+  PathBuf::from(normalize_path_separators(&path.display().to_string()))
+}
+
+/// Returns the leading part of `path` up to and including the first place
+/// `sub_path` appears in it as whole, consecutive segments, so `@acme/tokens`
+/// matches `packages/@acme/tokens/colors.js` but `tokens` doesn't match
+/// `packages/acme-tokens/colors.js`.
+pub(crate) fn find_subpath_dir(path: &Path, sub_path: &Path) -> Option<PathBuf> {
+  let path = normalize_path_separators(&path.display().to_string());
+  let sub_path = normalize_path_separators(&sub_path.display().to_string());
+
+  let parts = path.split('/').collect::<Vec<&str>>();
+  let sub_parts = sub_path.split('/').collect::<Vec<&str>>();
+
+  parts
+    .windows(sub_parts.len())
+    .position(|window| window == sub_parts.as_slice())
+    .map(|index| PathBuf::from(parts[..index + sub_parts.len()].join("/")))
+}
+
 pub fn relative_path(file_path: &Path, root: &Path) -> PathBuf {
   pathdiff::diff_paths(file_path, root)
     .expect("Path resolution failed")
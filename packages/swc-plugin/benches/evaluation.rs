@@ -0,0 +1,43 @@
+mod utils;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use stylex_swc_plugin::shared::{
+  structures::{functions::FunctionMap, state_manager::StateManager},
+  utils::js::evaluate::evaluate,
+};
+use swc_core::ecma::ast::{Expr, ModuleItem};
+use utils::{large_create_source, parse_module};
+
+/// Extracts the object passed to the first `stylex.create` call of the fixture.
+fn create_argument(source: &str) -> Expr {
+  let module = parse_module(source);
+
+  module
+    .body
+    .iter()
+    .find_map(|item| match item {
+      ModuleItem::ModuleDecl(decl) => decl.as_export_decl()?.decl.as_var()?.decls.first(),
+      _ => None,
+    })
+    .and_then(|decl| decl.init.as_ref())
+    .and_then(|init| init.as_call())
+    .and_then(|call| call.args.first())
+    .map(|arg| *arg.expr.clone())
+    .expect("Fixture must contain an exported stylex.create call")
+}
+
+fn bench_evaluation(c: &mut Criterion) {
+  let large_object = create_argument(&large_create_source(500));
+  let functions = FunctionMap::default();
+
+  c.bench_function("evaluate/object_500_declarations", |b| {
+    b.iter(|| {
+      let mut state = StateManager::default();
+
+      evaluate(black_box(&large_object), &mut state, &functions)
+    })
+  });
+}
+
+criterion_group!(benches, bench_evaluation);
+criterion_main!(benches);
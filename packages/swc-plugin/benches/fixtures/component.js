@@ -0,0 +1,33 @@
+import * as stylex from '@stylexjs/stylex';
+
+const styles = stylex.create({
+  root: {
+    display: 'flex',
+    flexDirection: 'column',
+    padding: 16,
+    backgroundColor: {
+      default: 'white',
+      ':hover': 'whitesmoke',
+      '@media (prefers-color-scheme: dark)': 'black',
+    },
+  },
+  title: {
+    fontSize: '1.5rem',
+    fontWeight: 600,
+    color: 'rebeccapurple',
+    marginBottom: 8,
+  },
+  body: {
+    lineHeight: 1.5,
+    color: '#333',
+  },
+});
+
+export default function Card({ title, children }) {
+  return (
+    <article {...stylex.props(styles.root)}>
+      <h2 {...stylex.props(styles.title)}>{title}</h2>
+      <div {...stylex.props(styles.body)}>{children}</div>
+    </article>
+  );
+}
@@ -0,0 +1,86 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use stylex_swc_plugin::shared::utils::{
+  common::create_hash,
+  interner::{clear_interned, intern_dashed_key, intern_hash},
+};
+
+const PROPERTY_KEYS: [&str; 12] = [
+  "backgroundColor",
+  "borderTopLeftRadius",
+  "color",
+  "display",
+  "fontFamily",
+  "fontSize",
+  "gridTemplateColumns",
+  "marginInlineStart",
+  "paddingBlockEnd",
+  "textDecorationLine",
+  "transitionTimingFunction",
+  "WebkitAppearance",
+];
+
+fn hash_inputs() -> Vec<String> {
+  PROPERTY_KEYS
+    .iter()
+    .flat_map(|key| (0..8).map(move |i| format!("<>{}{}pxnull", key, i * 4)))
+    .collect()
+}
+
+fn bench_dashed_keys(c: &mut Criterion) {
+  let mut group = c.benchmark_group("dashed_key");
+
+  group.bench_function("cold", |b| {
+    b.iter(|| {
+      clear_interned();
+
+      for _ in 0..50 {
+        for key in PROPERTY_KEYS {
+          black_box(intern_dashed_key(black_box(key)));
+        }
+      }
+    })
+  });
+
+  group.bench_function("interned", |b| {
+    b.iter(|| {
+      for _ in 0..50 {
+        for key in PROPERTY_KEYS {
+          black_box(intern_dashed_key(black_box(key)));
+        }
+      }
+    })
+  });
+
+  group.finish();
+}
+
+fn bench_hashes(c: &mut Criterion) {
+  let inputs = hash_inputs();
+
+  let mut group = c.benchmark_group("class_name_hash");
+
+  group.bench_function("create_hash", |b| {
+    b.iter(|| {
+      for _ in 0..10 {
+        for input in &inputs {
+          black_box(create_hash(black_box(input)));
+        }
+      }
+    })
+  });
+
+  group.bench_function("interned", |b| {
+    b.iter(|| {
+      for _ in 0..10 {
+        for input in &inputs {
+          black_box(intern_hash(black_box(input)));
+        }
+      }
+    })
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, bench_dashed_keys, bench_hashes);
+criterion_main!(benches);
@@ -0,0 +1,72 @@
+mod utils;
+
+use std::{
+  alloc::{GlobalAlloc, Layout, System},
+  sync::atomic::{AtomicUsize, Ordering},
+};
+
+use utils::{large_create_source, large_module_source, parse_module, transform_module};
+
+/// Allocator keeping track of the bytes allocated at once, as criterion only
+/// measures time.
+struct PeakAllocator {
+  current: AtomicUsize,
+  peak: AtomicUsize,
+}
+
+unsafe impl GlobalAlloc for PeakAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let ptr = System.alloc(layout);
+
+    if !ptr.is_null() {
+      let current = self.current.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+
+      self.peak.fetch_max(current, Ordering::Relaxed);
+    }
+
+    ptr
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout);
+
+    self.current.fetch_sub(layout.size(), Ordering::Relaxed);
+  }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator {
+  current: AtomicUsize::new(0),
+  peak: AtomicUsize::new(0),
+};
+
+/// Bytes allocated on top of the already parsed module at the peak of its
+/// transform.
+fn peak_transform_bytes(source: &str) -> usize {
+  let module = parse_module(source);
+  let baseline = ALLOCATOR.current.load(Ordering::Relaxed);
+
+  ALLOCATOR.peak.store(baseline, Ordering::Relaxed);
+
+  drop(transform_module(&module));
+
+  ALLOCATOR.peak.load(Ordering::Relaxed) - baseline
+}
+
+fn main() {
+  let fixtures = [
+    ("component", include_str!("fixtures/component.js").to_string()),
+    ("create_500_declarations", large_create_source(500)),
+    ("module_200_components", large_module_source(200)),
+  ];
+
+  for (name, source) in &fixtures {
+    let bytes = peak_transform_bytes(source);
+
+    println!(
+      "memory/{:<32} peak {:>10.1} KiB",
+      name,
+      bytes as f64 / 1024.0
+    );
+  }
+}
@@ -0,0 +1,24 @@
+use std::path::Path;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use stylex_path_resolver::resolvers::resolve_path;
+
+fn bench_resolution(c: &mut Criterion) {
+  let root_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+  let files = [
+    root_dir.join("tests/fixture/card/input.js"),
+    root_dir.join("tests/fixture/page/input.js"),
+    root_dir.join("benches/fixtures/component.js"),
+  ];
+
+  c.bench_function("resolve_path/project_files", |b| {
+    b.iter(|| {
+      for file in &files {
+        black_box(resolve_path(black_box(file), root_dir).expect("File must be resolvable"));
+      }
+    })
+  });
+}
+
+criterion_group!(benches, bench_resolution);
+criterion_main!(benches);
@@ -0,0 +1,29 @@
+mod utils;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use utils::{large_create_source, parse_module, theme_chain_source, transform_module};
+
+fn bench_transform(c: &mut Criterion) {
+  let component = parse_module(include_str!("fixtures/component.js"));
+  let large_create = parse_module(&large_create_source(500));
+  let theme_chain = parse_module(&theme_chain_source(8));
+
+  let mut group = c.benchmark_group("transform");
+
+  group.bench_function("small_component", |b| {
+    b.iter(|| transform_module(black_box(&component)))
+  });
+
+  group.bench_function("create_500_declarations", |b| {
+    b.iter(|| transform_module(black_box(&large_create)))
+  });
+
+  group.bench_function("deep_theme_chain", |b| {
+    b.iter(|| transform_module(black_box(&theme_chain)))
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, bench_transform);
+criterion_main!(benches);
@@ -0,0 +1,155 @@
+#![allow(dead_code)]
+
+use std::fmt::Write;
+
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::{
+    comments::SingleThreadedComments, sync::Lrc, FileName, Globals, Mark, SourceMap, GLOBALS,
+  },
+  ecma::{
+    ast::Module,
+    parser::{Parser, StringInput, Syntax, TsSyntax},
+    transforms::base::resolver,
+    visit::FoldWith,
+  },
+};
+
+const PROPERTIES: [(&str, &str); 10] = [
+  ("color", "'red'"),
+  ("backgroundColor", "'blue'"),
+  ("padding", "4"),
+  ("margin", "'8px'"),
+  ("fontSize", "'1rem'"),
+  ("lineHeight", "1.5"),
+  ("display", "'flex'"),
+  ("borderRadius", "2"),
+  ("opacity", "0.5"),
+  ("transitionDuration", "'100ms'"),
+];
+
+/// A `stylex.create` call with `declarations` declarations spread over namespaces of ten.
+pub(crate) fn large_create_source(declarations: usize) -> String {
+  let mut source = String::from("import * as stylex from '@stylexjs/stylex';\n\n");
+
+  source.push_str("export const styles = stylex.create({\n");
+
+  for namespace in 0..declarations.div_ceil(PROPERTIES.len()) {
+    writeln!(source, "  ns{}: {{", namespace).unwrap();
+
+    for (index, (key, value)) in PROPERTIES.iter().enumerate() {
+      if namespace * PROPERTIES.len() + index >= declarations {
+        break;
+      }
+
+      writeln!(source, "    {}: {},", key, value).unwrap();
+    }
+
+    source.push_str("  },\n");
+  }
+
+  source.push_str("});\n");
+
+  source
+}
+
+/// Style values computed through a chain of `depth` dependent constants.
+pub(crate) fn theme_chain_source(depth: usize) -> String {
+  let mut source = String::from("import * as stylex from '@stylexjs/stylex';\n\n");
+
+  source.push_str("const space0 = 4;\n");
+
+  for level in 1..depth {
+    writeln!(
+      source,
+      "const space{} = space{} * 2 - {};",
+      level,
+      level - 1,
+      level
+    )
+    .unwrap();
+  }
+
+  source.push_str("\nexport const styles = stylex.create({\n  root: {\n");
+  writeln!(source, "    padding: space{},", depth - 1).unwrap();
+  writeln!(source, "    margin: space{},", depth / 2).unwrap();
+  source.push_str("  },\n});\n");
+
+  source
+}
+
+/// A module of `components` components, each with its own `stylex.create`
+/// call of which only some namespaces are used.
+pub(crate) fn large_module_source(components: usize) -> String {
+  let mut source = String::from("import * as stylex from '@stylexjs/stylex';\n");
+
+  for component in 0..components {
+    writeln!(
+      source,
+      r#"
+const gap{component} = {component} % 8;
+
+const styles{component} = stylex.create({{
+  root: {{
+    display: 'flex',
+    gap: gap{component},
+    color: null,
+  }},
+  active: {{
+    backgroundColor: {{ default: 'white', ':hover': 'whitesmoke' }},
+  }},
+  unused: {{
+    margin: gap{component} * 2,
+  }},
+}});
+
+export function Component{component}({{ active }}) {{
+  return <div {{...stylex.props(styles{component}.root, active && styles{component}.active)}} />;
+}}"#
+    )
+    .unwrap();
+  }
+
+  source
+}
+
+pub(crate) fn parse_module(source: &str) -> Module {
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Anon, source.into());
+
+  Parser::new(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    StringInput::from(&*fm),
+    None,
+  )
+  .parse_module()
+  .expect("Benchmark fixture must be parsable")
+}
+
+pub(crate) fn transform_module(module: &Module) -> Module {
+  GLOBALS.set(&Globals::new(), || {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+
+    let mut config = StyleXOptionsParams::default();
+
+    let module = module
+      .clone()
+      .fold_with(&mut resolver(unresolved_mark, top_level_mark, false));
+
+    module.fold_with(&mut ModuleTransformVisitor::new_test(
+      SingleThreadedComments::default(),
+      &PluginPass {
+        cwd: None,
+        filename: FileName::Real("/app/src/Bench.tsx".into()),
+      },
+      Some(&mut config),
+    ))
+  })
+}
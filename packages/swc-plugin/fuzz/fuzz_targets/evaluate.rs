@@ -0,0 +1,42 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use stylex_swc_plugin::shared::{
+  structures::{functions::FunctionMap, state_manager::StateManager},
+  utils::js::evaluate::evaluate,
+};
+use stylex_swc_plugin_fuzz::run_ignoring_diagnostics;
+use swc_core::{
+  common::{sync::Lrc, FileName, Globals, SourceMap, GLOBALS},
+  ecma::parser::{Parser, StringInput, Syntax, TsSyntax},
+};
+
+fuzz_target!(|source: &str| {
+  // Deeply nested expressions overflow the parser's stack before reaching the evaluator
+  if source.len() > 4096 {
+    return;
+  }
+
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Anon, source.into());
+
+  let Ok(expr) = Parser::new(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    StringInput::from(&*fm),
+    None,
+  )
+  .parse_expr() else {
+    return;
+  };
+
+  run_ignoring_diagnostics(|| {
+    GLOBALS.set(&Globals::new(), || {
+      let mut state = StateManager::default();
+
+      evaluate(&expr, &mut state, &FunctionMap::default());
+    })
+  });
+});
@@ -0,0 +1,26 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use stylex_swc_plugin::shared::utils::css::{
+  common::{stringify, swc_parse_css},
+  normalizers::base::base_normalizer,
+};
+use stylex_swc_plugin_fuzz::run_ignoring_diagnostics;
+
+fuzz_target!(|input: (bool, &str)| {
+  let (use_rem_for_font_size, declaration) = input;
+
+  let (parsed_css, errors) = swc_parse_css(&format!("* {{ {} }}", declaration));
+
+  let Ok(ast) = parsed_css else {
+    return;
+  };
+
+  if !errors.is_empty() {
+    return;
+  }
+
+  run_ignoring_diagnostics(|| {
+    stringify(&base_normalizer(ast, use_rem_for_font_size));
+  });
+});
@@ -0,0 +1,32 @@
+use std::panic::{self, AssertUnwindSafe};
+
+/// Panic messages produced by Rust itself rather than by the plugin's diagnostics.
+const INTERNAL_PANICS: [&str; 6] = [
+  "not implemented",
+  "not yet implemented",
+  "internal error: entered unreachable code",
+  "called `Option::unwrap()`",
+  "called `Result::unwrap()`",
+  "index out of bounds",
+];
+
+/// Runs `f`, ignoring the panics the plugin uses to report invalid input
+/// and re-raising the ones that point at a crash in the plugin.
+pub fn run_ignoring_diagnostics<F: FnOnce()>(f: F) {
+  let Err(error) = panic::catch_unwind(AssertUnwindSafe(f)) else {
+    return;
+  };
+
+  let message = error
+    .downcast_ref::<String>()
+    .map(String::as_str)
+    .or_else(|| error.downcast_ref::<&str>().copied())
+    .unwrap_or_default();
+
+  if INTERNAL_PANICS
+    .iter()
+    .any(|internal| message.starts_with(internal))
+  {
+    panic::resume_unwind(error);
+  }
+}
@@ -0,0 +1,49 @@
+//! Entry point of the Wasm plugin. It is bound to the plugin ABI of the
+//! `swc_core` version, so it is only built with the `plugin` feature.
+
+use std::path::PathBuf;
+
+use swc_core::{
+  common::FileName,
+  ecma::ast::Program,
+  plugin::{
+    metadata::TransformPluginMetadataContextKind,
+    plugin_transform,
+    proxies::{PluginCommentsProxy, TransformPluginProgramMetadata},
+  },
+};
+
+use crate::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+
+#[plugin_transform]
+pub(crate) fn process_transform(
+  program: Program,
+  metadata: TransformPluginProgramMetadata,
+) -> Program {
+  let mut config = serde_json::from_str::<StyleXOptionsParams>(
+    &metadata
+      .get_transform_plugin_config()
+      .expect("failed to get plugin config for stylex"),
+  )
+  .expect("invalid config for stylex");
+
+  let filename: FileName = match metadata.get_context(&TransformPluginMetadataContextKind::Filename)
+  {
+    Some(s) => FileName::Real(s.into()),
+    None => FileName::Anon,
+  };
+
+  let cwd: Option<PathBuf> = metadata
+    .get_context(&TransformPluginMetadataContextKind::Cwd)
+    .map(PathBuf::from);
+
+  let plugin_pass = Box::new(PluginPass { cwd, filename });
+
+  let mut stylex: ModuleTransformVisitor<PluginCommentsProxy> =
+    ModuleTransformVisitor::new(PluginCommentsProxy, plugin_pass, &mut config);
+
+  stylex.fold_program(program)
+}
@@ -1,15 +0,0 @@
-pub(crate) mod application_order;
-pub(crate) mod common;
-pub(crate) mod cursor_flip;
-pub(crate) mod legacy_expand_shorthands_order;
-pub(crate) mod length_units;
-pub(crate) mod long_hand_logical;
-pub(crate) mod long_hand_physical;
-pub mod messages;
-pub(crate) mod number_properties;
-pub(crate) mod priorities;
-pub(crate) mod property_specificity_order;
-pub(crate) mod shorthands_of_longhands;
-pub(crate) mod shorthands_of_shorthands;
-pub(crate) mod time_units;
-pub(crate) mod unitless_number_properties;
@@ -1,70 +0,0 @@
-use std::{collections::HashMap, fmt, rc::Rc};
-
-use indexmap::IndexMap;
-use swc_core::{
-  atoms::Atom,
-  ecma::ast::{Expr, KeyValueProp, Lit},
-};
-
-use crate::shared::structures::{
-  functions::FunctionConfig, theme_ref::ThemeRef, types::EvaluationCallback,
-};
-
-pub enum EvaluateResultValue {
-  Expr(Box<Expr>),
-  Vec(Vec<Option<EvaluateResultValue>>),
-  Map(IndexMap<Box<Expr>, Vec<KeyValueProp>>),
-  Entries(IndexMap<Box<Lit>, Box<Lit>>),
-  Callback(EvaluationCallback),
-  FunctionConfig(FunctionConfig),
-  FunctionConfigMap(HashMap<Atom, FunctionConfig>),
-  ThemeRef(ThemeRef),
-}
-
-impl Clone for EvaluateResultValue {
-  fn clone(&self) -> Self {
-    match self {
-      Self::Expr(e) => Self::Expr(e.clone()),
-      Self::Vec(v) => Self::Vec(v.clone()),
-      Self::Map(m) => Self::Map(m.clone()),
-      Self::Entries(e) => Self::Entries(e.clone()),
-      Self::FunctionConfig(f) => Self::FunctionConfig(f.clone()),
-      Self::FunctionConfigMap(f) => Self::FunctionConfigMap(f.clone()),
-      Self::Callback(c) => Self::Callback(Rc::clone(c)),
-      Self::ThemeRef(tr) => Self::ThemeRef(tr.clone()),
-    }
-  }
-}
-
-impl fmt::Debug for EvaluateResultValue {
-  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    match self {
-      Self::Expr(e) => f.debug_tuple("Expr").field(e).finish(),
-      Self::Vec(v) => f.debug_tuple("Vec").field(v).finish(),
-      Self::Map(m) => f.debug_tuple("Map").field(m).finish(),
-      Self::Entries(e) => f.debug_tuple("Entries").field(e).finish(),
-      Self::FunctionConfig(e) => f.debug_tuple("FunctionConfig").field(e).finish(),
-      Self::FunctionConfigMap(e) => f.debug_tuple("FunctionConfigMap").field(e).finish(),
-      Self::ThemeRef(e) => f.debug_tuple("ThemeRef").field(e).finish(),
-      Self::Callback(_) => f
-        .debug_tuple("Callback")
-        .field(&"Function Pointer")
-        .finish(),
-    }
-  }
-}
-
-impl PartialEq for EvaluateResultValue {
-  fn eq(&self, other: &Self) -> bool {
-    match (self, other) {
-      (Self::Expr(e1), Self::Expr(e2)) => e1 == e2,
-      (Self::Vec(v1), Self::Vec(v2)) => v1 == v2,
-      (Self::ThemeRef(v1), Self::ThemeRef(v2)) => v1 == v2,
-      (Self::Map(m1), Self::Map(m2)) => m1 == m2,
-      (Self::FunctionConfig(f1), Self::FunctionConfig(f2)) => f1 == f2,
-      (Self::FunctionConfigMap(f1), Self::FunctionConfigMap(f2)) => f1 == f2,
-      (Self::Callback(_), Self::Callback(_)) => false,
-      _ => false,
-    }
-  }
-}
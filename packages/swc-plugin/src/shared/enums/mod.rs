@@ -1,4 +0,0 @@
-pub(crate) mod core;
-pub mod data_structures;
-pub(crate) mod js;
-pub(crate) mod misc;
@@ -1,30 +0,0 @@
-pub(crate) mod application_order;
-pub(crate) mod base_css_type;
-pub mod evaluate_result;
-pub mod functions;
-pub(crate) mod included_style;
-pub(crate) mod injectable_style;
-pub(crate) mod legacy_expand_shorthands_order;
-pub(crate) mod member_transform;
-pub(crate) mod meta_data;
-pub mod named_import_source;
-pub(crate) mod null_pre_rule;
-pub(crate) mod order;
-pub(crate) mod order_pair;
-pub(crate) mod pair;
-pub mod plugin_pass;
-pub(crate) mod pre_included_styles_rule;
-pub(crate) mod pre_rule;
-pub(crate) mod pre_rule_set;
-pub(crate) mod property_specificity;
-pub(crate) mod property_specificity_order;
-pub(crate) mod seen_value;
-pub(crate) mod shorthands_of_shorthands;
-pub(crate) mod state;
-pub mod state_manager;
-pub mod stylex_options;
-pub(crate) mod stylex_state_options;
-pub(crate) mod tests;
-pub(crate) mod theme_ref;
-pub(crate) mod types;
-pub(crate) mod uid_generator;
@@ -1,2 +0,0 @@
-#[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub(crate) struct OrderPair(pub String, pub Option<String>);
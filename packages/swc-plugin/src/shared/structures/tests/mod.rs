@@ -1,2 +0,0 @@
-mod flatten_raw_style_objects_test;
-mod gen_css_test;
@@ -1,8 +0,0 @@
-pub(crate) mod stylex_create;
-pub(crate) mod stylex_create_theme;
-pub(crate) mod stylex_define_vars;
-pub(crate) mod stylex_first_that_works;
-pub(crate) mod stylex_include;
-pub(crate) mod stylex_keyframes;
-pub(crate) mod stylex_types;
-pub(crate) mod tests;
@@ -1,5 +0,0 @@
-pub(crate) mod common;
-pub(crate) mod normalizers;
-pub(crate) mod parser;
-pub(crate) mod tests;
-pub(crate) mod validators;
@@ -1,3 +0,0 @@
-pub(crate) mod base;
-pub(crate) mod tests;
-pub(crate) mod whitespace_normalizer;
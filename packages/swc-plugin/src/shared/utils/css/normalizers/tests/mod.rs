@@ -1 +0,0 @@
-mod base;
@@ -1,3 +0,0 @@
-pub(crate) mod css_custom_properties_validation_test;
-pub(crate) mod css_tests;
-pub(crate) mod split_value_test;
@@ -1 +0,0 @@
-pub(crate) mod unprefixed_custom_properties;
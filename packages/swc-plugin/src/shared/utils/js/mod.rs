@@ -1,2 +0,0 @@
-pub(crate) mod native_functions;
-pub mod evaluate;
@@ -1,7 +0,0 @@
-pub mod common;
-pub mod core;
-pub mod css;
-pub mod ast;
-pub mod js;
-pub mod object;
-pub(crate) mod validators;
@@ -0,0 +1,95 @@
+//! Compiles StyleX sources outside of an SWC host, so component libraries can
+//! snapshot their compiled output without setting up the SWC test harness.
+
+use std::path::Path;
+
+use swc_core::{
+  common::{comments::SingleThreadedComments, sync::Lrc, FileName, Globals, SourceMap, GLOBALS},
+  ecma::{
+    codegen::{text_writer::JsWriter, Config, Emitter},
+    parser::{Parser, StringInput},
+    transforms::base::{fixer::fixer, hygiene::hygiene},
+    visit::FoldWith,
+  },
+};
+
+use crate::{
+  shared::{
+    structures::{
+      meta_data::MetaData, plugin_pass::PluginPass, stylex_options::StyleXOptionsParams,
+    },
+    utils::{
+      swc_compat::resolve_module,
+      syntax::{strip_bom, syntax_for_path},
+    },
+  },
+  ModuleTransformVisitor,
+};
+
+/// Compiled output of a source transformed with [`transform_str`].
+#[derive(Debug, Clone)]
+pub struct TransformOutput {
+  pub code: String,
+  /// Every compiled rule of the source, in injection order.
+  pub rules: Vec<MetaData>,
+}
+
+/// Transforms `source` as the file at `filename` with `options`, the way the
+/// plugin would inside SWC. TypeScript syntax is used for `.ts`, `.tsx`,
+/// `.mts` and `.cts` files, JSX is enabled for all others. Shebangs,
+/// decorators and a leading byte order mark are accepted.
+///
+/// Panics when `source` cannot be parsed or the transform rejects it, with
+/// the same message the plugin reports.
+pub fn transform_str(
+  source: &str,
+  filename: &str,
+  mut options: StyleXOptionsParams,
+) -> TransformOutput {
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Real(filename.into()), strip_bom(source).into());
+  let comments = SingleThreadedComments::default();
+
+  let module = Parser::new(
+    syntax_for_path(Path::new(filename)),
+    StringInput::from(&*fm),
+    Some(&comments),
+  )
+  .parse_module()
+  .unwrap_or_else(|error| panic!("Failed to parse {}: {:?}", filename, error.kind()));
+
+  let plugin_pass = Box::new(PluginPass {
+    cwd: None,
+    filename: FileName::Real(filename.into()),
+  });
+
+  GLOBALS.set(&Globals::new(), || {
+    let mut visitor = ModuleTransformVisitor::new(comments.clone(), plugin_pass, &mut options);
+
+    let module = resolve_module(module)
+      .fold_with(&mut visitor)
+      .fold_with(&mut hygiene())
+      .fold_with(&mut fixer(Some(&comments)));
+
+    let mut code = vec![];
+
+    Emitter {
+      cfg: Config::default(),
+      cm: cm.clone(),
+      comments: Some(&comments),
+      wr: JsWriter::new(cm.clone(), "\n", &mut code, None),
+    }
+    .emit_module(&module)
+    .expect("Failed to emit the transformed module");
+
+    TransformOutput {
+      code: String::from_utf8(code).expect("Emitted code is not valid UTF-8"),
+      rules: visitor
+        .state()
+        .injected_rules()
+        .into_iter()
+        .cloned()
+        .collect(),
+    }
+  })
+}
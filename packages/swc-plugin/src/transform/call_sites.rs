@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+
+use swc_core::{
+  atoms::Atom,
+  common::{comments::Comments, BytePos, Spanned},
+  ecma::{
+    ast::{CallExpr, Lit, MemberExpr, MemberProp, Module, PropName},
+    visit::{Visit, VisitWith},
+  },
+};
+
+use crate::{
+  shared::{
+    structures::{call_site::CallSite, state_manager::StateManager},
+    utils::{
+      common::{get_member_path, get_object_wrapper_path},
+      validators::get_stylex_api,
+    },
+  },
+  ModuleTransformVisitor,
+};
+
+impl<C> ModuleTransformVisitor<C>
+where
+  C: Comments,
+{
+  /// Records the StyleX calls found while initializing, before any of them
+  /// is compiled. Every call counts as static until
+  /// [`Self::mark_dynamic_call_sites`] finds it in the transformed module.
+  pub(crate) fn collect_call_sites(&mut self) {
+    let call_sites = self
+      .state
+      .all_call_expressions
+      .iter()
+      .filter_map(|call| {
+        let api = get_stylex_api(call, &self.state)?;
+
+        let namespaces_defined = match api {
+          "create" => get_defined_namespaces(call, &self.state),
+          _ => vec![],
+        };
+
+        let namespaces_used = match api {
+          "props" | "attrs" | "stylex" => get_used_namespaces(call),
+          _ => vec![],
+        };
+
+        Some(CallSite {
+          api: api.to_string(),
+          start: call.span.lo.0,
+          end: call.span.hi.0,
+          namespaces_defined,
+          namespaces_used,
+          is_static: true,
+        })
+      })
+      .collect();
+
+    self.state.call_sites = call_sites;
+  }
+
+  /// Marks the calls left in the transformed `module` as not compiled.
+  pub(crate) fn mark_dynamic_call_sites(&mut self, module: &Module) {
+    let mut remaining_calls = RemainingCalls::default();
+
+    module.visit_with(&mut remaining_calls);
+
+    for call_site in &mut self.state.call_sites {
+      call_site.is_static = !remaining_calls
+        .spans
+        .contains(&(BytePos(call_site.start), BytePos(call_site.end)));
+    }
+  }
+}
+
+/// `<variable>.<namespace>` of the namespaces of a `stylex.create()` call
+/// declared on a top level variable, or nested in the object of one, e.g.
+/// `config.styles.<namespace>`.
+fn get_defined_namespaces(call: &CallExpr, state: &StateManager) -> Vec<String> {
+  let var_name = state
+    .declarations
+    .iter()
+    .find_map(|decl| {
+      let init = decl.init.as_deref()?;
+
+      if init.span() != call.span {
+        return None;
+      }
+
+      decl.name.as_ident().map(|ident| ident.sym.to_string())
+    })
+    .or_else(|| get_object_wrapper_path(call, state));
+
+  let (Some(var_name), Some(object)) = (
+    var_name,
+    call.args.first().and_then(|arg| arg.expr.as_object()),
+  ) else {
+    return vec![];
+  };
+
+  object
+    .props
+    .iter()
+    .filter_map(|prop| prop.as_prop()?.as_key_value())
+    .filter_map(|key_value| match &key_value.key {
+      PropName::Ident(ident) => Some(ident.sym.clone()),
+      PropName::Str(str) => Some(str.value.clone()),
+      _ => None,
+    })
+    .map(|namespace| format!("{}.{}", var_name, namespace))
+    .collect()
+}
+
+/// `<variable>.<namespace>` of the members passed to `call`, in order, with
+/// the path of the wrapper object as the variable for nested styles.
+fn get_used_namespaces(call: &CallExpr) -> Vec<String> {
+  let mut used_namespaces = UsedNamespaces::default();
+
+  for arg in &call.args {
+    arg.expr.visit_with(&mut used_namespaces);
+  }
+
+  used_namespaces.namespaces
+}
+
+#[derive(Default)]
+struct UsedNamespaces {
+  namespaces: Vec<String>,
+}
+
+impl Visit for UsedNamespaces {
+  fn visit_member_expr(&mut self, member: &MemberExpr) {
+    let Some(object) = get_member_path(&member.obj) else {
+      return member.visit_children_with(self);
+    };
+
+    let namespace: Option<Atom> = match &member.prop {
+      MemberProp::Ident(ident) => Some(ident.sym.clone()),
+      MemberProp::Computed(computed) => match computed.expr.as_lit() {
+        Some(Lit::Str(str)) => Some(str.value.clone()),
+        _ => None,
+      },
+      MemberProp::PrivateName(_) => None,
+    };
+
+    if let Some(namespace) = namespace {
+      let namespace = format!("{}.{}", object, namespace);
+
+      if !self.namespaces.contains(&namespace) {
+        self.namespaces.push(namespace);
+      }
+    }
+  }
+}
+
+/// Spans of the calls of a module.
+#[derive(Default)]
+struct RemainingCalls {
+  spans: HashSet<(BytePos, BytePos)>,
+}
+
+impl Visit for RemainingCalls {
+  fn visit_call_expr(&mut self, call: &CallExpr) {
+    self.spans.insert((call.span.lo, call.span.hi));
+
+    call.visit_children_with(self);
+  }
+}
@@ -3,6 +3,7 @@ mod fold_export_default_expr;
 mod fold_expr;
 mod fold_ident;
 mod fold_import_decl;
+mod fold_jsx_opening_element;
 mod fold_member_expression;
 mod fold_module;
 mod fold_module_items;
@@ -15,8 +16,8 @@ use swc_core::{
   common::comments::Comments,
   ecma::{
     ast::{
-      ExportDecl, ExportDefaultExpr, Expr, Ident, ImportDecl, MemberExpr, Module, ModuleItem, Stmt,
-      VarDeclarator,
+      ExportDecl, ExportDefaultExpr, Expr, Ident, ImportDecl, JSXOpeningElement, MemberExpr,
+      Module, ModuleItem, Stmt, VarDeclarator,
     },
     visit::{noop_fold_type, Fold},
   },
@@ -77,6 +78,10 @@ where
     self.fold_export_default_expr_impl(export_default_expr)
   }
 
+  fn fold_jsx_opening_element(&mut self, element: JSXOpeningElement) -> JSXOpeningElement {
+    self.fold_jsx_opening_element_impl(element)
+  }
+
   fn fold_ident(&mut self, ident: Ident) -> Ident {
     self.fold_ident_impl(ident)
   }
@@ -1,4 +1,7 @@
-use swc_core::{common::comments::Comments, ecma::ast::ExportDefaultExpr};
+use swc_core::{
+  common::comments::Comments,
+  ecma::{ast::ExportDefaultExpr, visit::FoldWith},
+};
 
 use crate::{
   shared::{enums::core::ModuleCycle, utils::common::normalize_expr},
@@ -17,12 +20,22 @@ where
       return export_default_expr;
     }
 
+    // Count the references of the default export, so the bindings it uses are never removed
+    if self.cycle == ModuleCycle::Initializing {
+      return export_default_expr.fold_children_with(self);
+    }
+
     if self.cycle == ModuleCycle::TransformEnter || self.cycle == ModuleCycle::TransformExit {
       let normalized_expr = normalize_expr(&mut export_default_expr.expr);
 
       if let Some(value) = self.transform_call_expression(normalized_expr) {
         *export_default_expr.expr = value;
+
+        return export_default_expr;
       }
+
+      // Calls nested in a default exported object, e.g. `export default { styles: stylex.create(...) }`
+      return export_default_expr.fold_children_with(self);
     }
 
     export_default_expr
@@ -1,6 +1,9 @@
 use swc_core::{
   common::comments::Comments,
-  ecma::{ast::Expr, visit::FoldWith},
+  ecma::{
+    ast::{AssignTarget, Expr, Ident, SimpleAssignTarget},
+    visit::FoldWith,
+  },
 };
 
 use crate::{shared::enums::core::ModuleCycle, ModuleTransformVisitor};
@@ -15,9 +18,21 @@ where
     }
 
     if self.cycle == ModuleCycle::Initializing {
+      self.strip_stylex_call_types(&mut expr);
+
       if let Some(call_expr) = expr.as_call() {
         self.state.all_call_expressions.push(call_expr.clone());
       }
+
+      if let Some(ident) = get_reassigned_ident(&expr) {
+        self.state.reassigned_bindings.insert(ident.to_id());
+      }
+    }
+
+    if self.cycle == ModuleCycle::PreCleaning {
+      if let Some(call_expr) = expr.as_call() {
+        self.keep_element_call_props(call_expr);
+      }
     }
 
     if self.cycle == ModuleCycle::TransformEnter || self.cycle == ModuleCycle::TransformExit {
@@ -29,3 +44,16 @@ where
     expr.fold_children_with(self)
   }
 }
+
+/// The identifier assigned to or updated by `expr`, e.g. `styles` in
+/// `styles = other` or `styles++`.
+fn get_reassigned_ident(expr: &Expr) -> Option<&Ident> {
+  match expr {
+    Expr::Assign(assign) => match &assign.left {
+      AssignTarget::Simple(SimpleAssignTarget::Ident(binding)) => Some(&binding.id),
+      _ => None,
+    },
+    Expr::Update(update) => update.arg.as_ident(),
+    _ => None,
+  }
+}
@@ -0,0 +1,343 @@
+use swc_core::{
+  atoms::Atom,
+  common::comments::Comments,
+  ecma::{
+    ast::{
+      CallExpr, Expr, JSXAttr, JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXElementName, JSXExpr,
+      JSXOpeningElement, KeyValueProp, Lit, MemberProp, ObjectLit, Prop, PropName, PropOrSpread,
+      Str,
+    },
+    visit::FoldWith,
+  },
+};
+
+use crate::{
+  shared::{
+    constants::messages::{ATTRS_CONFLICT, ESCAPING_STYLES},
+    enums::{
+      core::ModuleCycle,
+      data_structures::style_vars_to_keep::{NonNullProp, NonNullProps, StyleVarsToKeep},
+    },
+    structures::stylex_options::AttrsConflicts,
+    utils::{common::get_member_path, validators::is_attrs_call},
+  },
+  ModuleTransformVisitor,
+};
+
+/// Attributes a `stylex.attrs()` call may set.
+const ATTRS_NAMES: [&str; 2] = ["class", "style"];
+
+/// A literal attribute set by a `stylex.attrs()` spread of the same element
+/// as well.
+struct AttrsConflict {
+  index: usize,
+  name: String,
+  /// Whether the literal attribute comes after the spread, and so replaces
+  /// the compiled one.
+  is_last: bool,
+  value: Option<String>,
+}
+
+impl<C> ModuleTransformVisitor<C>
+where
+  C: Comments,
+{
+  pub(crate) fn fold_jsx_opening_element_impl(
+    &mut self,
+    element: JSXOpeningElement,
+  ) -> JSXOpeningElement {
+    if self.cycle == ModuleCycle::PreCleaning {
+      self.keep_style_props(&element);
+
+      return element.fold_children_with(self);
+    }
+
+    let is_transforming = matches!(
+      self.cycle,
+      ModuleCycle::TransformEnter | ModuleCycle::TransformExit
+    );
+
+    if !is_transforming || self.state.options.attrs_conflicts == AttrsConflicts::Ignore {
+      return element.fold_children_with(self);
+    }
+
+    let spread_index = element.attrs.iter().position(|attr| match attr {
+      JSXAttrOrSpread::SpreadElement(spread) => spread
+        .expr
+        .as_call()
+        .is_some_and(|call| is_attrs_call(call, &self.state)),
+      JSXAttrOrSpread::JSXAttr(_) => false,
+    });
+
+    let mut element = element.fold_children_with(self);
+
+    let Some(spread_index) = spread_index else {
+      return element;
+    };
+
+    let compiled = match &element.attrs[spread_index] {
+      JSXAttrOrSpread::SpreadElement(spread) => spread.expr.as_object(),
+      JSXAttrOrSpread::JSXAttr(_) => None,
+    };
+
+    // The call may be compiled in either transform phase, one left after the
+    // last of them has dynamic styles that may set any of the attributes
+    if compiled.is_none() && self.cycle != ModuleCycle::TransformExit {
+      return element;
+    }
+
+    let conflicts = element
+      .attrs
+      .iter()
+      .enumerate()
+      .filter_map(|(index, attr)| match attr {
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+          name: JSXAttrName::Ident(name),
+          value,
+          ..
+        }) if ATTRS_NAMES.contains(&name.sym.as_ref())
+          && compiled.map_or(true, |object| has_prop(object, &name.sym)) =>
+        {
+          Some(AttrsConflict {
+            index,
+            name: name.sym.to_string(),
+            is_last: index > spread_index,
+            value: match value {
+              Some(JSXAttrValue::Lit(Lit::Str(value))) => Some(value.value.to_string()),
+              _ => None,
+            },
+          })
+        }
+        _ => None,
+      })
+      .collect::<Vec<AttrsConflict>>();
+
+    let mut merged_indexes = vec![];
+
+    for conflict in conflicts {
+      let compiled_value = match &mut element.attrs[spread_index] {
+        JSXAttrOrSpread::SpreadElement(spread) => spread
+          .expr
+          .as_mut_object()
+          .and_then(|object| string_prop_mut(object, &conflict.name)),
+        JSXAttrOrSpread::JSXAttr(_) => None,
+      };
+
+      match (
+        self.state.options.attrs_conflicts,
+        compiled_value,
+        &conflict.value,
+      ) {
+        (AttrsConflicts::Merge, Some(compiled_value), Some(value)) => {
+          let separator = if conflict.name == "style" { ';' } else { ' ' };
+
+          let values = if conflict.is_last {
+            [compiled_value.value.as_ref(), value.as_str()]
+          } else {
+            [value.as_str(), compiled_value.value.as_ref()]
+          };
+
+          compiled_value.value = join_values(values, separator).into();
+          compiled_value.raw = None;
+
+          merged_indexes.push(conflict.index);
+        }
+        _ => self.warn(&format!(
+          "{} \"{}\" in {}, the {} wins",
+          ATTRS_CONFLICT,
+          conflict.name,
+          self.state.get_filename(),
+          if conflict.is_last {
+            "literal attribute"
+          } else {
+            "stylex.attrs() spread"
+          }
+        )),
+      }
+    }
+
+    for index in merged_indexes.into_iter().rev() {
+      element.attrs.remove(index);
+    }
+
+    element
+  }
+
+  /// Keeps the `stylex.create()` namespaces passed as props of `element`.
+  /// Namespaces of static members are kept while folding them, every
+  /// namespace of a style object passed whole or through a dynamic member,
+  /// e.g. `styles[variant]`, is kept here.
+  fn keep_style_props(&mut self, element: &JSXOpeningElement) {
+    let is_component = match &element.name {
+      JSXElementName::Ident(ident) => is_component_name(&ident.sym),
+      JSXElementName::JSXMemberExpr(_) => true,
+      JSXElementName::JSXNamespacedName(_) => false,
+    };
+
+    for attr in &element.attrs {
+      let expr = match attr {
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+          value: Some(JSXAttrValue::JSXExprContainer(container)),
+          ..
+        }) => match &container.expr {
+          JSXExpr::Expr(expr) => expr.as_ref(),
+          JSXExpr::JSXEmptyExpr(_) => continue,
+        },
+        JSXAttrOrSpread::SpreadElement(spread) => spread.expr.as_ref(),
+        _ => continue,
+      };
+
+      if let Some((var_name, namespace)) = get_style_prop(expr) {
+        self.keep_style_prop(is_component, var_name, namespace);
+      }
+    }
+  }
+
+  /// Keeps the namespaces passed as props of an element compiled to a call
+  /// of the module's JSX runtime, e.g. `_jsx(Card, { style: styles })`, as
+  /// for `<Card style={styles} />`.
+  pub(crate) fn keep_element_call_props(&mut self, call: &CallExpr) {
+    let is_element_call = call
+      .callee
+      .as_expr()
+      .and_then(|callee| get_member_path(callee))
+      .is_some_and(|callee| self.state.jsx_element_factories.contains(&callee));
+
+    if !is_element_call {
+      return;
+    }
+
+    let (Some(element), Some(props)) = (
+      call.args.first(),
+      call.args.get(1).and_then(|props| props.expr.as_object()),
+    ) else {
+      return;
+    };
+
+    let is_component = match element.expr.as_ref() {
+      Expr::Ident(ident) => is_component_name(&ident.sym),
+      Expr::Member(_) => true,
+      _ => false,
+    };
+
+    for prop in &props.props {
+      let style_prop = match prop {
+        PropOrSpread::Spread(spread) => get_style_prop(&spread.expr),
+        PropOrSpread::Prop(prop) => match prop.as_ref() {
+          Prop::KeyValue(key_value) => get_style_prop(&key_value.value),
+          Prop::Shorthand(ident) => Some((ident.sym.clone(), NonNullProp::True)),
+          _ => None,
+        },
+      };
+
+      if let Some((var_name, namespace)) = style_prop {
+        self.keep_style_prop(is_component, var_name, namespace);
+      }
+    }
+  }
+
+  /// Keeps `namespace` of the `var_name` style object passed as a prop,
+  /// warning about styles escaping into components.
+  fn keep_style_prop(&mut self, is_component: bool, var_name: Atom, namespace: NonNullProp) {
+    if !self.state.style_map.contains_key(var_name.as_str()) {
+      return;
+    }
+
+    if is_component && self.state.options.warn_escaping_styles {
+      let prop = match &namespace {
+        NonNullProp::Atom(namespace) => format!("{}.{}", var_name, namespace),
+        NonNullProp::True => var_name.to_string(),
+      };
+
+      self.warn(&format!(
+        "{} {} in {}",
+        ESCAPING_STYLES,
+        prop,
+        self.state.get_filename()
+      ));
+    }
+
+    self
+      .state
+      .style_vars_to_keep
+      .insert(Box::new(StyleVarsToKeep(
+        var_name,
+        namespace,
+        NonNullProps::True,
+      )));
+  }
+}
+
+/// Whether an element name refers to a component rather than a host element.
+fn is_component_name(name: &str) -> bool {
+  name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// The style object and namespace of a prop value passing styles, e.g.
+/// `styles` and `compact` for `styles.compact`.
+fn get_style_prop(expr: &Expr) -> Option<(Atom, NonNullProp)> {
+  match expr {
+    Expr::Ident(ident) => Some((ident.sym.clone(), NonNullProp::True)),
+    Expr::Member(member) => {
+      let ident = member.obj.as_ident()?;
+
+      let namespace = match &member.prop {
+        MemberProp::Ident(ident) => NonNullProp::Atom(ident.sym.clone()),
+        MemberProp::Computed(computed) => match computed.expr.as_lit() {
+          Some(Lit::Str(str)) => NonNullProp::Atom(str.value.clone()),
+          _ => NonNullProp::True,
+        },
+        MemberProp::PrivateName(_) => return None,
+      };
+
+      Some((ident.sym.clone(), namespace))
+    }
+    _ => None,
+  }
+}
+
+fn has_prop(object: &ObjectLit, name: &str) -> bool {
+  object.props.iter().any(|prop| match prop {
+    PropOrSpread::Prop(prop) => prop
+      .as_key_value()
+      .is_some_and(|KeyValueProp { key, .. }| is_prop_named(key, name)),
+    PropOrSpread::Spread(_) => false,
+  })
+}
+
+/// The string value of the `name` property of `object`.
+fn string_prop_mut<'a>(object: &'a mut ObjectLit, name: &str) -> Option<&'a mut Str> {
+  object.props.iter_mut().find_map(|prop| match prop {
+    PropOrSpread::Prop(prop) => match prop.as_mut() {
+      Prop::KeyValue(KeyValueProp { key, value }) if is_prop_named(key, name) => {
+        match value.as_mut() {
+          Expr::Lit(Lit::Str(value)) => Some(value),
+          _ => None,
+        }
+      }
+      _ => None,
+    },
+    PropOrSpread::Spread(_) => None,
+  })
+}
+
+fn is_prop_named(key: &PropName, name: &str) -> bool {
+  match key {
+    PropName::Ident(ident) => &*ident.sym == name,
+    PropName::Str(str) => &*str.value == name,
+    _ => false,
+  }
+}
+
+/// Joins the non-empty `values` with `separator`, e.g. `"a b"` and `"c"` to
+/// `"a b c"` for class names.
+fn join_values(values: [&str; 2], separator: char) -> String {
+  values
+    .iter()
+    .map(|value| {
+      value.trim_matches(|character: char| character == separator || character.is_whitespace())
+    })
+    .filter(|value| !value.is_empty())
+    .collect::<Vec<&str>>()
+    .join(&separator.to_string())
+}
@@ -1,11 +1,36 @@
+use std::time::Instant;
+
 use swc_core::{
-  common::comments::{Comment, CommentKind, Comments},
-  ecma::{ast::Module, visit::FoldWith},
+  common::{
+    comments::{Comment, CommentKind, Comments},
+    Spanned,
+  },
+  ecma::{
+    ast::{Module, ModuleItem},
+    visit::FoldWith,
+  },
 };
 
 use crate::{
   shared::{
-    enums::core::ModuleCycle, structures::meta_data::MetaData, utils::common::fill_top_level_expressions,
+    constants::{
+      common::SHADOW_DOM_PRAGMA,
+      messages::{DEPRECATED_PROPERTY_ALIASES, LARGE_RULES, SEEN_CACHE_CAPACITY_REACHED},
+    },
+    enums::core::ModuleCycle,
+    structures::{
+      class_name_manifest::ClassNameManifest,
+      jsx_runtime::JsxRuntime,
+      meta_data::{MetaData, MetaDataEnvelope},
+      phase_timings::PhaseTimings,
+      state_manager::add_import_expression,
+      stylex_options::LargeValues,
+    },
+    utils::common::fill_top_level_expressions,
+  },
+  transform::{
+    pipeline::{transform_pipeline, PhaseState},
+    CrashContext,
   },
   ModuleTransformVisitor,
 };
@@ -15,52 +40,311 @@ where
   C: Comments,
 {
   pub(crate) fn fold_module_impl(&mut self, module: Module) -> Module {
-    let mut module = module.fold_children_with(self);
-
-    if !self.state.import_paths.is_empty() {
-      fill_top_level_expressions(&module, &mut self.state);
-
-      self.cycle = ModuleCycle::TransformEnter;
-      module = module.fold_children_with(self);
-
-      self.cycle = ModuleCycle::TransformExit;
-      module = module.fold_children_with(self);
-
-      if self.state.options.runtime_injection.is_some() {
-        self.cycle = ModuleCycle::InjectStyles;
-        module = module.fold_children_with(self);
-      } else {
-        // Preparing stylex metadata for css extraction
-        self.comments.add_leading(
-          module.span.lo,
-          Comment {
-            kind: CommentKind::Line,
-            text: format!(
-              "__stylex_metadata_start__{}__stylex_metadata_end__",
-              serde_json::to_string(
-                &self
-                  .state
-                  .metadata
-                  .iter()
-                  .flat_map(|v| v.1.clone())
-                  .collect::<Vec<MetaData>>()
-              )
-              .unwrap()
-            )
-            .into(),
-            span: module.span,
-          },
+    self.phase_timings = PhaseTimings::default();
+
+    if !self.state.is_transformed_file() {
+      self.start_phase(ModuleCycle::Skip);
+
+      return module;
+    }
+
+    if self.state.options.shadow_dom.is_some() {
+      self.state.shadow_dom_pragma = self.has_leading_pragma(&module, SHADOW_DOM_PRAGMA);
+    }
+
+    let mut module = self.run_phase(ModuleCycle::Initializing, module);
+
+    if self.state.import_paths.is_empty() {
+      self.start_phase(ModuleCycle::Skip);
+
+      return module;
+    }
+
+    fill_top_level_expressions(&module, &mut self.state);
+
+    let leading_comments = self.leading_comments(&module);
+
+    self.state.jsx_element_factories =
+      JsxRuntime::from_comments(leading_comments.iter().map(|comment| comment.text.as_ref()))
+        .element_factories(&module);
+
+    if self.state.options.call_sites {
+      self.collect_call_sites();
+    }
+
+    let runtime_injection = self.state.options.runtime_injection.is_some();
+
+    for cycle in transform_pipeline(runtime_injection) {
+      module = self.run_phase(cycle, module);
+    }
+
+    insert_after_directives(&mut module, self.state.runtime_create_import_module_items());
+
+    // The short class names the module added are written at once
+    if let Some(manifest) = self
+      .state
+      .options
+      .class_name_manifest
+      .as_deref()
+      .and_then(ClassNameManifest::open)
+    {
+      manifest.persist();
+    }
+
+    self.warn_on_seen_cache_evictions();
+    self.warn_on_large_rules();
+    self.warn_on_aliased_properties();
+
+    if self.state.options.call_sites {
+      self.mark_dynamic_call_sites(&module);
+    }
+
+    if !runtime_injection {
+      // Preparing stylex metadata for css extraction
+      self.add_metadata_comment(
+        &module,
+        "metadata",
+        MetaDataEnvelope::to_json(
+          self
+            .state
+            .metadata
+            .iter()
+            .flat_map(|v| v.1.clone())
+            .collect::<Vec<MetaData>>(),
+          self.state.options.metadata_version,
+        ),
+      );
+
+      if self.state.options.export_styles {
+        self.add_metadata_comment(
+          &module,
+          "exported_styles",
+          serde_json::to_string(&self.state.exported_styles),
+        );
+        self.add_metadata_comment(
+          &module,
+          "class_names",
+          serde_json::to_string(&self.state.class_name_namespaces),
         );
       }
 
-      self.cycle = ModuleCycle::PreCleaning;
-      module = module.fold_children_with(self);
+      if self.state.options.var_usage_graph {
+        self.add_metadata_comment(
+          &module,
+          "var_usage",
+          serde_json::to_string(&self.state.var_usage),
+        );
+      }
+
+      if self.state.options.call_sites {
+        self.add_metadata_comment(
+          &module,
+          "call_sites",
+          serde_json::to_string(&self.state.call_sites),
+        );
+      }
+
+      if self.state.options.phase_timings {
+        self.add_metadata_comment(
+          &module,
+          "phase_timings",
+          serde_json::to_string(&self.phase_timings),
+        );
+      }
+
+      if let Some(specifier) = &self.state.options.css_import {
+        if self.state.metadata.values().any(|rules| !rules.is_empty()) {
+          add_css_import(&mut module, specifier);
+        }
+      }
+    }
+
+    module
+  }
+
+  /// Reports files evicting evaluated expressions from the `seen` cache, as
+  /// they are evaluated again on their next use.
+  fn warn_on_seen_cache_evictions(&self) {
+    let seen = &self.state.seen;
+
+    if seen.evictions() == 0 {
+      return;
+    }
+
+    self.warn(&format!(
+      "{} {} ({} of {} entries evicted)",
+      SEEN_CACHE_CAPACITY_REACHED,
+      self.state.get_filename(),
+      seen.evictions(),
+      seen.capacity()
+    ));
+  }
+
+  /// Reports the rules of the file larger than `maxRuleSize`, which are
+  /// repeated for every condition using the same value.
+  fn warn_on_large_rules(&self) {
+    let max_rule_size = self.state.options.max_rule_size;
+
+    if max_rule_size == 0 || self.state.options.large_values != LargeValues::Inline {
+      return;
+    }
+
+    let large_rules = self
+      .state
+      .injected_rules()
+      .into_iter()
+      .filter(|rule| rule.get_css().len() > max_rule_size)
+      .map(|rule| format!("{} ({} bytes)", rule.get_class_name(), rule.get_css().len()))
+      .collect::<Vec<String>>();
+
+    if large_rules.is_empty() {
+      return;
+    }
+
+    self.warn(&format!(
+      "{} {}: {}",
+      LARGE_RULES,
+      self.state.get_filename(),
+      large_rules.join(", ")
+    ));
+  }
+
+  /// Reports the legacy properties of the file compiled through
+  /// `propertyAliases`, with the standard names replacing them.
+  fn warn_on_aliased_properties(&self) {
+    if self.state.aliased_properties.is_empty() {
+      return;
+    }
+
+    let aliases = self
+      .state
+      .aliased_properties
+      .iter()
+      .map(|(alias, property)| format!("{} -> {}", alias, property))
+      .collect::<Vec<String>>();
+
+    self.warn(&format!(
+      "{} {}: {}",
+      DEPRECATED_PROPERTY_ALIASES,
+      self.state.get_filename(),
+      aliases.join(", ")
+    ));
+  }
+
+  /// Adds the serialized `json` between `__stylex_<name>_start__` and
+  /// `__stylex_<name>_end__` markers in a leading comment of `module`.
+  fn add_metadata_comment(&self, module: &Module, name: &str, json: serde_json::Result<String>) {
+    // Comments of the module are printed before its shebang, which has to
+    // stay on the first line, so they go to its first item instead
+    let pos = module
+      .shebang
+      .as_ref()
+      .and_then(|_| {
+        module
+          .body
+          .iter()
+          .map(|item| item.span_lo())
+          .find(|lo| !lo.is_dummy())
+      })
+      .unwrap_or(module.span.lo);
+
+    self.comments.add_leading(
+      pos,
+      Comment {
+        kind: CommentKind::Line,
+        text: format!(
+          "__stylex_{}_start__{}__stylex_{}_end__",
+          name,
+          json.unwrap(),
+          name
+        )
+        .into(),
+        span: module.span,
+      },
+    );
+  }
+
+  /// Folds `module` with `cycle` as the current phase.
+  fn run_phase(&mut self, cycle: ModuleCycle, module: Module) -> Module {
+    self.start_phase(cycle);
 
-      self.cycle = ModuleCycle::Cleaning;
-      module.fold_children_with(self)
-    } else {
-      self.cycle = ModuleCycle::Skip;
-      module
+    if !self.state.options.phase_timings {
+      return module.fold_children_with(self);
     }
+
+    let started = Instant::now();
+    let module = module.fold_children_with(self);
+    let elapsed = started.elapsed();
+
+    tracing::debug!(
+      file = %self.state.get_filename(),
+      phase = ?cycle,
+      elapsed_us = elapsed.as_micros() as u64,
+      "StyleX phase finished"
+    );
+
+    self.phase_timings.record(cycle, elapsed);
+
+    module
   }
+
+  fn start_phase(&mut self, cycle: ModuleCycle) {
+    self.cycle = cycle;
+    CrashContext::set_cycle(cycle);
+    self.phase_state = PhaseState::new(cycle, &self.state);
+  }
+
+  /// Whether a comment before the first statement of `module` contains
+  /// `pragma`.
+  fn has_leading_pragma(&self, module: &Module, pragma: &str) -> bool {
+    self
+      .leading_comments(module)
+      .iter()
+      .any(|comment| comment.text.contains(pragma))
+  }
+
+  /// The comments before the first statement of `module`.
+  fn leading_comments(&self, module: &Module) -> Vec<Comment> {
+    let positions = [
+      Some(module.span.lo),
+      module.body.first().map(|item| item.span_lo()),
+    ];
+
+    positions
+      .into_iter()
+      .flatten()
+      .filter_map(|pos| self.comments.get_leading(pos))
+      .flatten()
+      .collect()
+  }
+}
+
+/// Imports `specifier` after the directives of `module`, unless it already
+/// imports it.
+fn add_css_import(module: &mut Module, specifier: &str) {
+  let is_imported = module.body.iter().any(|item| {
+    item
+      .as_module_decl()
+      .and_then(|decl| decl.as_import())
+      .is_some_and(|import| import.src.value == *specifier)
+  });
+
+  if is_imported {
+    return;
+  }
+
+  insert_after_directives(module, vec![add_import_expression(specifier)]);
+}
+
+/// Inserts `items` at the top of `module`, after its directives.
+fn insert_after_directives(module: &mut Module, items: Vec<ModuleItem>) {
+  let directives = module
+    .body
+    .iter()
+    .take_while(|item| {
+      matches!(item, ModuleItem::Stmt(stmt) if stmt.as_expr().is_some_and(|expr| expr.expr.is_lit()))
+    })
+    .count();
+
+  module.body.splice(directives..directives, items);
 }
@@ -1,16 +1,13 @@
+use swc_core::ecma::ast::ExportDecl;
 use swc_core::{
-  common::{comments::Comments, DUMMY_SP},
+  common::comments::Comments,
   ecma::{
-    ast::{Decl, Expr, Ident, Lit, ModuleDecl, ModuleItem, Pat, Stmt, VarDeclarator},
+    ast::{Decl, Expr, Lit, ModuleDecl, ModuleItem, Pat, Stmt},
     visit::FoldWith,
   },
 };
-use swc_core::ecma::ast::ExportDecl;
 
-use crate::{
-  shared::{enums::core::ModuleCycle, utils::ast::factories::binding_ident_factory},
-  ModuleTransformVisitor,
-};
+use crate::{shared::enums::core::ModuleCycle, ModuleTransformVisitor};
 
 impl<C> ModuleTransformVisitor<C>
 where
@@ -32,15 +29,7 @@ where
           }
         });
 
-        let transformed_module_items = module_items.fold_children_with(self);
-
-        if self.state.import_paths.is_empty() {
-          self.cycle = ModuleCycle::Skip;
-
-          return transformed_module_items;
-        }
-
-        transformed_module_items
+        module_items.fold_children_with(self)
       }
       ModuleCycle::TransformEnter => module_items.fold_children_with(self),
       ModuleCycle::TransformExit => module_items.fold_children_with(self),
@@ -50,74 +39,43 @@ where
           self.state.prepend_include_module_items.clone();
 
         result_module_items.extend(self.state.prepend_import_module_items.clone());
+        result_module_items.extend(self.state.side_effect_import_module_items());
 
-        let mut items_to_skip: usize = 0;
-
-        if let Some(first) = module_items
+        let is_directive = module_items
           .first()
           .and_then(|first| first.as_stmt())
           .and_then(|stmp| stmp.as_expr())
-        {
-          if let Some(Lit::Str(_)) = first.expr.as_lit() {
-            result_module_items.insert(0, module_items.first().unwrap().clone());
-            items_to_skip = 1;
-          }
+          .is_some_and(|first| matches!(first.expr.as_lit(), Some(Lit::Str(_))));
+
+        // All rules of the module are injected together, before the first
+        // declaration they were compiled into
+        let mut inject_statements = Some(
+          self.state.inject_statements_for(
+            module_items
+              .iter()
+              .flat_map(compiled_inits)
+              .filter(|init| self.state.styles_to_inject.contains_key(*init)),
+          ),
+        );
+
+        let mut items = module_items.into_iter();
+
+        if is_directive {
+          result_module_items.insert(0, items.next().unwrap());
         }
 
-        for module_item in module_items.iter().skip(items_to_skip) {
-          if let Some(decls) = match &module_item {
-            ModuleItem::ModuleDecl(decl) => match decl {
-              ModuleDecl::ExportDecl(export_decl) => export_decl.decl.as_var().map(|var_decl| {
-                var_decl
-                  .decls
-                  .iter() // Use iter() to avoid cloning the entire collection
-                  .filter(|decl| {
-                    decl
-                      .init
-                      .as_ref() // Use as_ref to convert Option<T> to Option<&T>
-                      .map_or(false, |init| init.is_object() || init.is_lit())
-                  })
-                  .cloned() // Clone only the filtered elements
-                  .collect::<Vec<VarDeclarator>>()
-              }),
-              ModuleDecl::ExportDefaultExpr(export_default_expr) => {
-                export_default_expr.expr.as_object().map(|obj| {
-                  vec![VarDeclarator {
-                    definite: true,
-                    span: DUMMY_SP,
-                    name: Pat::Ident(binding_ident_factory(Ident::from("default"))),
-                    init: Some(Box::new(Expr::from(obj.clone()))),
-                  }]
-                })
-              }
-              _ => None,
-            },
-            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => Some(
-              var_decl
-                .decls
-                .iter()
-                .filter(|decl| {
-                  decl
-                    .init
-                    .as_ref()
-                    .map_or(false, |init| init.is_object() || init.is_lit())
-                })
-                .cloned()
-                .collect::<Vec<VarDeclarator>>(),
-            ),
-            _ => None,
-          } {
-            for decl in decls {
-              let key = decl.init.clone().unwrap();
-
-              if let Some(metadata_items) = self.state.styles_to_inject.get(key.as_ref()) {
-                for module_item in metadata_items.iter() {
-                  result_module_items.push(module_item.clone());
-                }
-              }
+        for module_item in items {
+          let has_styles_to_inject = compiled_inits(&module_item)
+            .into_iter()
+            .any(|init| self.state.styles_to_inject.contains_key(init));
+
+          if has_styles_to_inject {
+            if let Some(statements) = inject_statements.take() {
+              result_module_items.extend(statements);
             }
           }
-          result_module_items.push(module_item.clone());
+
+          result_module_items.push(module_item);
         }
 
         result_module_items
@@ -138,3 +96,51 @@ where
     }
   }
 }
+
+/// Inits of the declarators of `module_item` that may be a compiled style
+/// object, along with the objects nested in them, e.g. `config.styles` of
+/// `const config = { styles: stylex.create(...) }`.
+fn compiled_inits(module_item: &ModuleItem) -> Vec<&Expr> {
+  let decls = match module_item {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+      match export_decl.decl.as_var() {
+        Some(var_decl) => &var_decl.decls,
+        None => return vec![],
+      }
+    }
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_default_expr)) => {
+      return match export_default_expr.expr.as_ref() {
+        expr @ Expr::Object(_) => with_nested_objects(expr),
+        _ => vec![],
+      };
+    }
+    ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => &var_decl.decls,
+    _ => return vec![],
+  };
+
+  decls
+    .iter()
+    .filter_map(|decl| decl.init.as_deref())
+    .filter(|init| init.is_object() || init.is_lit())
+    .flat_map(with_nested_objects)
+    .collect()
+}
+
+/// `expr` followed by the object values nested in it.
+fn with_nested_objects(expr: &Expr) -> Vec<&Expr> {
+  let mut exprs = vec![expr];
+
+  if let Expr::Object(object) = expr {
+    exprs.extend(
+      object
+        .props
+        .iter()
+        .filter_map(|prop| prop.as_prop()?.as_key_value())
+        .map(|key_value| key_value.value.as_ref())
+        .filter(|value| value.is_object())
+        .flat_map(with_nested_objects),
+    );
+  }
+
+  exprs
+}
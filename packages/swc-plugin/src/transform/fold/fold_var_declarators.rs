@@ -26,13 +26,19 @@ where
           if let Pat::Ident(bind_ident) = &decl.name {
             let decl_id = &bind_ident.sym;
 
-            if self.state.var_decl_count_map.contains_key(decl_id) {
-              let count = self.state.var_decl_count_map.get(decl_id).unwrap();
+            // Only `stylex.create` objects and bindings inlined into compiled styles are
+            // candidates for removal, unrelated unused declarations are left untouched.
+            let is_compiled = self.state.style_vars.contains_key(decl_id.as_str())
+              || self.state.compiled_var_decls.contains(decl_id);
 
-              // Remove the variable declaration if it is used only once after transformation.
-              let is_used = count > &1;
+            if !is_compiled {
+              return true;
+            }
 
-              return is_used;
+            if let Some(count) = self.state.var_decl_count_map.get(decl_id) {
+              // Remove the declaration only when every reference to it was compiled away,
+              // the remaining count being the declaration itself.
+              return count > &1;
             }
           }
 
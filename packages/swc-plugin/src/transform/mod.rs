@@ -1,26 +1,46 @@
-use std::collections::HashSet;
+use std::{
+  any::Any,
+  cell::{Cell, RefCell},
+  collections::HashSet,
+  panic::{self, AssertUnwindSafe},
+  sync::Once,
+};
 
 use swc_core::{
-  common::comments::Comments,
-  ecma::ast::{CallExpr, Callee, Expr, Id, MemberProp, VarDeclarator},
+  common::{comments::Comments, errors::HANDLER, util::take::Take},
+  ecma::{
+    ast::{CallExpr, Callee, Expr, Id, MemberProp, Module, Program, VarDeclarator},
+    visit::FoldWith,
+  },
 };
 
 use crate::{
   shared::{
-    enums::core::ModuleCycle,
+    constants::common::DEFAULT_EXPORT_NAME,
+    enums::{core::ModuleCycle, data_structures::top_level_expression::TopLevelExpressionKind},
     structures::{
       named_import_source::{ImportSources, RuntimeInjection},
+      phase_timings::PhaseTimings,
       plugin_pass::PluginPass,
       state_manager::StateManager,
-      stylex_options::StyleXOptions,
+      stylex_options::{StyleXOptions, StyleXOptionsParams},
+    },
+    utils::{
+      common::{
+        crash_report, get_object_wrapper_path, increase_ident_count, normalize_expr, strip_ts_exprs,
+      },
+      validators::get_stylex_api,
     },
-    utils::common::increase_ident_count,
   },
-  StyleXOptionsParams,
+  transform::pipeline::PhaseState,
 };
 
+#[cfg(any(feature = "plugin", feature = "testing"))]
+use crate::shared::structures::stylex_options_builder::StyleXOptionsError;
+
+mod call_sites;
 mod fold;
-pub(crate) mod styleq;
+mod pipeline;
 pub(crate) mod stylex;
 
 pub struct ModuleTransformVisitor<C>
@@ -29,7 +49,8 @@ where
 {
   comments: C,
   cycle: ModuleCycle,
-  props_declaration: Option<Id>,
+  phase_state: PhaseState,
+  phase_timings: PhaseTimings,
   pub(crate) state: Box<StateManager>,
 }
 
@@ -37,11 +58,18 @@ impl<C> ModuleTransformVisitor<C>
 where
   C: Comments,
 {
+  #[cfg(any(feature = "plugin", feature = "testing"))]
   pub(crate) fn new(
     comments: C,
     plugin_pass: Box<PluginPass>,
     config: &mut StyleXOptionsParams,
   ) -> Self {
+    let conflicts = config.conflicts();
+
+    if !conflicts.is_empty() {
+      panic!("{}", StyleXOptionsError::ConflictingOptions(conflicts));
+    }
+
     let stylex_imports = fill_stylex_imports(&Some(config));
 
     let mut state = Box::new(StateManager::new(config.clone().into()));
@@ -58,7 +86,35 @@ where
     ModuleTransformVisitor {
       comments,
       cycle: ModuleCycle::Initializing,
-      props_declaration: None,
+      phase_state: PhaseState::default(),
+      phase_timings: PhaseTimings::default(),
+      state,
+    }
+  }
+
+  /// Creates a visitor from options built with [`StyleXOptions::builder`],
+  /// for embedders that don't go through the JSON plugin config.
+  pub fn new_with_options(comments: C, plugin_pass: PluginPass, options: StyleXOptions) -> Self {
+    let mut stylex_imports = fill_stylex_imports(&None);
+
+    stylex_imports.extend(options.import_sources.iter().cloned().map(Box::new));
+
+    let mut state = Box::new(StateManager::new(options));
+
+    state.stylex_import.clone_from(&stylex_imports);
+
+    state.options.import_sources = stylex_imports
+      .into_iter()
+      .map(|stylex_import| *stylex_import)
+      .collect();
+
+    state._state = Box::new(plugin_pass);
+
+    ModuleTransformVisitor {
+      comments,
+      cycle: ModuleCycle::Initializing,
+      phase_state: PhaseState::default(),
+      phase_timings: PhaseTimings::default(),
       state,
     }
   }
@@ -100,7 +156,8 @@ where
     ModuleTransformVisitor {
       comments,
       cycle: ModuleCycle::Initializing,
-      props_declaration: None,
+      phase_state: PhaseState::default(),
+      phase_timings: PhaseTimings::default(),
       state,
     }
   }
@@ -135,11 +192,72 @@ where
     ModuleTransformVisitor {
       comments,
       cycle: ModuleCycle::Initializing,
-      props_declaration: None,
+      phase_state: PhaseState::default(),
+      phase_timings: PhaseTimings::default(),
       state,
     }
   }
 
+  /// State collected by the last transform, for embedders that need the
+  /// compiled rules without parsing the output code.
+  pub fn state(&self) -> &StateManager {
+    &self.state
+  }
+
+  /// Time spent in each phase of the last transform, measured with the
+  /// `phaseTimings` option only.
+  pub fn phase_timings(&self) -> &PhaseTimings {
+    &self.phase_timings
+  }
+
+  /// Transforms `program`. A panic during the transform is reported as a
+  /// single message naming the file and the phase the plugin was in, since
+  /// the host otherwise only sees an opaque WASM trap. The report is printed
+  /// by a panic hook, as the WASM plugin is built with `panic = "abort"`.
+  pub fn fold_program(&mut self, program: Program) -> Program {
+    install_crash_report_hook();
+
+    let _context = CrashContext::enter(self.state.get_filename(), self.cycle);
+
+    program.fold_with(self)
+  }
+
+  /// Transforms `module`, returning the message of a panic raised by the
+  /// transform as an error instead of printing it, e.g. for hosts reporting
+  /// it as a diagnostic. Only for native hosts, panics abort in WASM.
+  pub fn try_fold_module(&mut self, module: Module) -> Result<Module, String> {
+    install_crash_report_hook();
+
+    SILENT_PANICS.with(|silent| silent.set(true));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| module.fold_with(self)));
+
+    SILENT_PANICS.with(|silent| silent.set(false));
+
+    result.map_err(|payload| panic_message(payload.as_ref()))
+  }
+
+  /// Reports `message` as an error through the SWC diagnostics of the host,
+  /// which fails the build once the transform is done, or as a `tracing`
+  /// error outside of one.
+  pub(crate) fn error(&self, message: &str) {
+    if HANDLER.is_set() {
+      HANDLER.with(|handler| handler.err(message));
+    } else {
+      tracing::error!("{}", message);
+    }
+  }
+
+  /// Reports `message` through the SWC diagnostics of the host, or as a
+  /// `tracing` warning outside of one.
+  pub(crate) fn warn(&self, message: &str) {
+    if HANDLER.is_set() {
+      HANDLER.with(|handler| handler.warn(message));
+    } else {
+      tracing::warn!("{}", message);
+    }
+  }
+
   pub(crate) fn process_declaration(&mut self, call_expr: &mut CallExpr) -> Option<(Id, String)> {
     let stylex_imports = self.state.stylex_import_stringified();
     if let Callee::Expr(callee) = &mut call_expr.callee {
@@ -223,6 +341,30 @@ where
     None
   }
 
+  /// Drops the type arguments of a StyleX call in `expr` and the TypeScript
+  /// wrappers around it and within its arguments, e.g. in
+  /// `stylex.create<Styles>({ ... }) satisfies Styles`, so it is detected and
+  /// evaluated like its plain JavaScript counterpart.
+  pub(crate) fn strip_stylex_call_types(&self, expr: &mut Expr) {
+    let Expr::Call(call) = normalize_expr(expr) else {
+      return;
+    };
+
+    if get_stylex_api(call, &self.state).is_none() {
+      return;
+    }
+
+    call.type_args = None;
+
+    for arg in &mut call.args {
+      strip_ts_exprs(&mut arg.expr);
+    }
+
+    let call = call.take();
+
+    *expr = Expr::Call(call);
+  }
+
   pub(crate) fn get_call_var_name(
     &mut self,
     call: &CallExpr,
@@ -247,6 +389,16 @@ where
       if let Some(ident) = parent_var_decl.name.as_ident() {
         var_name = Some(ident.sym.to_string());
       }
+    } else {
+      // Calls nested in a top level object are named by their path, e.g.
+      // `config.styles`, so `config.styles.root` resolves statically. Default
+      // exports are named `default`, the name other files import them by
+      var_name = get_object_wrapper_path(call, &self.state).or_else(|| {
+        self
+          .state
+          .get_top_level_expr(&TopLevelExpressionKind::DefaultExport, call)
+          .map(|_| DEFAULT_EXPORT_NAME.to_string())
+      });
     }
 
     (var_name, parent_var_decl)
@@ -275,3 +427,76 @@ fn fill_stylex_imports(config: &Option<&mut StyleXOptionsParams>) -> HashSet<Box
 
   stylex_imports
 }
+
+thread_local! {
+  /// File and phase of the transform running on the current thread.
+  static CRASH_CONTEXT: RefCell<Option<(String, ModuleCycle)>> = const { RefCell::new(None) };
+  /// Whether panics on the current thread are caught and reported by the
+  /// caller, see `ModuleTransformVisitor::try_fold_module`.
+  static SILENT_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Marks a transform of `file_name` as running on the current thread until
+/// dropped, so a panic in it is reported with its file and phase.
+struct CrashContext;
+
+impl CrashContext {
+  fn enter(file_name: String, cycle: ModuleCycle) -> Self {
+    CRASH_CONTEXT.with(|context| *context.borrow_mut() = Some((file_name, cycle)));
+
+    CrashContext
+  }
+
+  /// Updates the phase reported for the running transform.
+  fn set_cycle(cycle: ModuleCycle) {
+    CRASH_CONTEXT.with(|context| {
+      if let Some((_, current)) = context.borrow_mut().as_mut() {
+        *current = cycle;
+      }
+    });
+  }
+}
+
+impl Drop for CrashContext {
+  fn drop(&mut self) {
+    CRASH_CONTEXT.with(|context| context.borrow_mut().take());
+  }
+}
+
+/// Prints the crash report of panics raised during a transform instead of
+/// the default panic message, or nothing for silenced ones. Panics outside
+/// of a transform are passed on to the previous hook.
+fn install_crash_report_hook() {
+  static INSTALL: Once = Once::new();
+
+  INSTALL.call_once(|| {
+    let previous_hook = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+      if SILENT_PANICS.with(Cell::get) {
+        return;
+      }
+
+      let report = CRASH_CONTEXT.with(|context| {
+        context
+          .borrow()
+          .as_ref()
+          .map(|(file_name, cycle)| crash_report(&panic_message(info.payload()), file_name, *cycle))
+      });
+
+      match report {
+        Some(report) => eprintln!("{}", report),
+        None => previous_hook(info),
+      }
+    }));
+  });
+}
+
+/// Message of a panic payload.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+  payload
+    .downcast_ref::<&str>()
+    .map(|message| message.to_string())
+    .or_else(|| payload.downcast_ref::<String>().cloned())
+    .unwrap_or_else(|| "Unknown error".to_string())
+}
@@ -0,0 +1,85 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use swc_core::atoms::Atom;
+
+use crate::shared::{
+  enums::{
+    core::ModuleCycle,
+    data_structures::style_vars_to_keep::{NonNullProp, NonNullProps, StyleVarsToKeep},
+  },
+  structures::state_manager::StateManager,
+};
+
+/// Phases run after `Initializing` on a module importing StyleX, in order.
+pub(crate) fn transform_pipeline(runtime_injection: bool) -> Vec<ModuleCycle> {
+  let mut pipeline = vec![ModuleCycle::TransformEnter, ModuleCycle::TransformExit];
+
+  if runtime_injection {
+    pipeline.push(ModuleCycle::InjectStyles);
+  }
+
+  pipeline.extend([ModuleCycle::PreCleaning, ModuleCycle::Cleaning]);
+
+  pipeline
+}
+
+/// State owned by the running phase. The pipeline replaces it whenever a
+/// phase starts, so nothing a phase keeps here leaks into the next one;
+/// state shared by phases belongs to the `StateManager`.
+#[derive(Debug, Default)]
+pub(crate) enum PhaseState {
+  Cleaning(CleaningState),
+  #[default]
+  Stateless,
+}
+
+impl PhaseState {
+  pub(crate) fn new(cycle: ModuleCycle, state: &StateManager) -> Self {
+    match cycle {
+      ModuleCycle::Cleaning => PhaseState::Cleaning(CleaningState::new(state)),
+      _ => PhaseState::Stateless,
+    }
+  }
+
+  pub(crate) fn cleaning(&self) -> Option<&CleaningState> {
+    match self {
+      PhaseState::Cleaning(state) => Some(state),
+      PhaseState::Stateless => None,
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CleaningState {
+  /// Namespaces used by the module of each style variable, collected while
+  /// pre-cleaning.
+  pub(crate) vars_to_keep: HashMap<Atom, NonNullProps>,
+}
+
+impl CleaningState {
+  fn new(state: &StateManager) -> Self {
+    let mut vars_to_keep: HashMap<Atom, NonNullProps> = HashMap::new();
+
+    for StyleVarsToKeep(var_name, namespace_name, _) in
+      state.style_vars_to_keep.iter().map(AsRef::as_ref)
+    {
+      match vars_to_keep.entry(var_name.clone()) {
+        // A style object used as a whole keeps every namespace
+        Entry::Occupied(mut entry) => match (entry.get_mut(), namespace_name) {
+          (NonNullProps::Vec(vec), NonNullProp::Atom(id)) => vec.push(id.clone()),
+          (props, NonNullProp::True) => *props = NonNullProps::True,
+          (NonNullProps::True, NonNullProp::Atom(_)) => {}
+        },
+        Entry::Vacant(entry) => {
+          let value = match namespace_name {
+            NonNullProp::Atom(namespace_name) => NonNullProps::Vec(vec![namespace_name.clone()]),
+            NonNullProp::True => NonNullProps::True,
+          };
+          entry.insert(value);
+        }
+      }
+    }
+
+    CleaningState { vars_to_keep }
+  }
+}
@@ -18,10 +18,11 @@ where
     match &call.callee {
       Callee::Expr(expr) => match expr.as_ref() {
         Expr::Ident(ident) => {
-          if self
-            .state
-            .stylex_import
-            .contains(&ImportSources::Regular(ident.sym.to_string()))
+          if self.state.options.transforms.props
+            && self
+              .state
+              .stylex_import
+              .contains(&ImportSources::Regular(ident.sym.to_string()))
           {
             if let Some(value) = stylex_merge(call, stylex, &mut self.state) {
               return Some(value);
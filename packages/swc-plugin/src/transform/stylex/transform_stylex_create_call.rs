@@ -2,25 +2,47 @@ use std::collections::HashMap;
 
 use indexmap::IndexMap;
 use swc_core::common::DUMMY_SP;
-use swc_core::ecma::ast::{ArrowExpr, BlockStmtOrExpr, ExprOrSpread, Pat, PropName};
+use swc_core::ecma::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, Decl, ExprOrSpread, ObjectLit, Pat, Prop, PropName,
+  ReturnStmt, Stmt,
+};
 use swc_core::{
   common::comments::Comments,
   ecma::ast::{CallExpr, Expr, PropOrSpread},
 };
 
-use crate::shared::utils::validators::{is_create_call, validate_stylex_create};
+use crate::shared::utils::validators::{
+  is_create_call, validate_namespace, validate_stylex_create,
+};
 use crate::shared::utils::{
   ast::factories::array_expression_factory,
   core::js_to_expr::{convert_object_to_ast, remove_objects_with_spreads, NestedStringObject},
+  core::runtime_create::runtime_create_spread,
 };
 use crate::shared::utils::{
   ast::factories::object_expression_factory,
   common::{get_key_str, get_key_values_from_object},
 };
 use crate::shared::{
-  constants::messages::NON_STATIC_VALUE,
+  constants::messages::{
+    NON_STATIC_VALUE, REASSIGNED_STYLEX_CREATE_VALUE, RUNTIME_CREATED_NAMESPACE,
+    UNCOMPILED_NAMESPACE,
+  },
   utils::core::dev_class_name::{convert_to_test_styles, inject_dev_class_names},
 };
+use crate::shared::{
+  enums::data_structures::evaluate_result_value::EvaluateResultValue,
+  structures::{
+    exported_styles::ExportedStyles,
+    state_manager::StateManager,
+    types::{
+      EvaluateResultFns, FlatCompiledStyles, FunctionMapMemberExpression, InjectableStylesMap,
+    },
+  },
+  utils::core::{
+    alias_properties::alias_properties, evaluate_stylex_create_arg::evaluate_stylex_create_arg,
+  },
+};
 use crate::shared::{
   structures::functions::{FunctionConfig, FunctionMap, FunctionType},
   transformers::{
@@ -28,10 +50,6 @@ use crate::shared::{
     stylex_include::stylex_include, stylex_keyframes::get_keyframes_fn,
   },
 };
-use crate::shared::{
-  structures::types::{FlatCompiledStyles, FunctionMapMemberExpression},
-  utils::core::evaluate_stylex_create_arg::evaluate_stylex_create_arg,
-};
 use crate::shared::{
   structures::{functions::FunctionConfigType, types::FunctionMapIdentifiers},
   utils::ast::factories::prop_or_spread_expression_factory,
@@ -51,7 +69,7 @@ where
 
       let first_arg = call.args.first();
 
-      let mut first_arg = first_arg.map(|first_arg| match &first_arg.spread {
+      let first_arg = first_arg.map(|first_arg| match &first_arg.spread {
         Some(_) => unimplemented!(),
         None => first_arg.expr.clone(),
       })?;
@@ -120,20 +138,15 @@ where
         member_expressions,
       });
 
-      let evaluated_arg =
-        evaluate_stylex_create_arg(&mut first_arg, &mut self.state, &function_map);
+      let (compiled_arg, uncompiled_namespaces) =
+        self.compile_create_arg(&first_arg, &function_map);
 
-      let value = match evaluated_arg.value {
-        Some(value) => value,
-        None => {
-          panic!("{}", NON_STATIC_VALUE)
-        }
-      };
-
-      assert!(evaluated_arg.confident, "{}", NON_STATIC_VALUE);
-
-      let (mut compiled_styles, injected_styles_sans_keyframes) =
-        stylex_create_set(&value, &mut self.state, &function_map);
+      let CompiledCreateArg {
+        mut compiled_styles,
+        injected_styles: injected_styles_sans_keyframes,
+        exported_styles,
+        fns,
+      } = compiled_arg;
 
       for (namespace, properties) in compiled_styles.iter() {
         resolved_namespaces
@@ -148,6 +161,22 @@ where
 
       let (var_name, parent_var_decl) = &self.get_call_var_name(call);
 
+      if let Some(ident) = parent_var_decl
+        .as_ref()
+        .and_then(|decl| decl.name.as_ident())
+      {
+        assert!(
+          !self.state.reassigned_bindings.contains(&ident.to_id()),
+          "{} {}",
+          REASSIGNED_STYLEX_CREATE_VALUE,
+          ident.sym
+        );
+      }
+
+      if let Some(var_name) = var_name.as_ref() {
+        self.add_class_name_namespaces(var_name, &compiled_styles);
+      }
+
       if self.state.is_test() {
         compiled_styles = convert_to_test_styles(&compiled_styles, var_name, &self.state);
       }
@@ -157,6 +186,13 @@ where
       }
 
       if let Some(var_name) = var_name.as_ref() {
+        if self.state.options.export_styles {
+          self
+            .state
+            .exported_styles
+            .insert(var_name.clone(), exported_styles);
+        }
+
         let styles_to_remember = Box::new(remove_objects_with_spreads(&compiled_styles));
 
         self
@@ -164,16 +200,20 @@ where
           .style_map
           .insert(var_name.clone(), styles_to_remember);
 
-        self
-          .state
-          .style_vars
-          .insert(var_name.clone(), parent_var_decl.clone().unwrap());
+        // Objects nested in a wrapper stay with it, so only variables are
+        // candidates for removal
+        if let Some(parent_var_decl) = parent_var_decl {
+          self
+            .state
+            .style_vars
+            .insert(var_name.clone(), parent_var_decl.clone());
+        }
       }
 
       let mut result_ast =
         convert_object_to_ast(&NestedStringObject::FlatCompiledStyles(compiled_styles));
 
-      if let Some(fns) = evaluated_arg.fns {
+      if let Some(fns) = fns {
         if let Some(object) = result_ast.as_object() {
           let key_values = get_key_values_from_object(object);
 
@@ -192,29 +232,47 @@ where
               let mut prop: Option<PropOrSpread> = None;
 
               if let Some(key) = key {
-                if let Some((params, inline_styles)) = fns.get(&key) {
+                if let Some((params, declarations, inline_styles)) = fns.get(&key) {
+                  let styles = array_expression_factory(vec![
+                    Some(ExprOrSpread {
+                      spread: None,
+                      expr: Box::new(*value.clone()),
+                    }),
+                    Some(ExprOrSpread {
+                      spread: None,
+                      expr: Box::new(object_expression_factory(
+                        inline_styles
+                          .iter()
+                          .map(|(key, value)| {
+                            prop_or_spread_expression_factory(key.as_str(), *value.clone())
+                          })
+                          .collect(),
+                      )),
+                    }),
+                  ]);
+
+                  // Locals computed at runtime keep running before the styles
+                  // are returned
+                  let body = if declarations.is_empty() {
+                    BlockStmtOrExpr::from(Box::new(styles))
+                  } else {
+                    BlockStmtOrExpr::BlockStmt(BlockStmt {
+                      span: DUMMY_SP,
+                      stmts: declarations
+                        .iter()
+                        .map(|declaration| Stmt::Decl(Decl::Var(Box::new(declaration.clone()))))
+                        .chain(std::iter::once(Stmt::Return(ReturnStmt {
+                          span: DUMMY_SP,
+                          arg: Some(Box::new(styles)),
+                        })))
+                        .collect(),
+                    })
+                  };
+
                   let value = Expr::from(ArrowExpr {
                     span: DUMMY_SP,
                     params: params.iter().map(|arg| Pat::Ident(arg.clone())).collect(),
-                    body: Box::new(BlockStmtOrExpr::from(Box::new(array_expression_factory(
-                      vec![
-                        Some(ExprOrSpread {
-                          spread: None,
-                          expr: Box::new(*value.clone()),
-                        }),
-                        Some(ExprOrSpread {
-                          spread: None,
-                          expr: Box::new(object_expression_factory(
-                            inline_styles
-                              .iter()
-                              .map(|(key, value)| {
-                                prop_or_spread_expression_factory(key.as_str(), *value.clone())
-                              })
-                              .collect(),
-                          )),
-                        }),
-                      ],
-                    )))),
+                    body: Box::new(body),
                     is_async: false,
                     is_generator: false,
                     type_params: None,
@@ -236,6 +294,21 @@ where
         }
       };
 
+      if let Some(object) = result_ast.as_mut_object() {
+        for (index, prop) in uncompiled_namespaces {
+          // Each namespace created at runtime is spread where it is declared,
+          // so it keeps its place among the compiled ones
+          let prop = match call.callee.as_expr() {
+            Some(callee) if self.state.options.runtime_create_fallback => {
+              runtime_create_spread(self.state.runtime_create_callee(callee), vec![prop])
+            }
+            _ => prop,
+          };
+
+          object.props.insert(index.min(object.props.len()), prop);
+        }
+      }
+
       self
         .state
         .register_styles(call, &injected_styles, &result_ast, var_name);
@@ -249,4 +322,202 @@ where
 
     result
   }
+
+  /// Compiles the namespaces of the `stylex.create()` argument `arg`. Each
+  /// namespace is evaluated and validated on its own before any of them is
+  /// compiled, so one that fails is reported and returned with its index, to
+  /// be created at runtime with the `runtimeCreateFallback` option or else
+  /// kept as written, while the others are compiled. When every
+  /// namespace fails, or a spread or a computed key hides some of them, the
+  /// first error is raised as is.
+  fn compile_create_arg(
+    &mut self,
+    arg: &Expr,
+    function_map: &FunctionMap,
+  ) -> (CompiledCreateArg, Vec<(usize, PropOrSpread)>) {
+    let Some(namespaces) = arg.as_object().and_then(get_namespace_props) else {
+      let evaluated_arg = evaluate_namespaces(&mut arg.clone(), &mut self.state, function_map)
+        .unwrap_or_else(|error| panic!("{}", error));
+
+      return (
+        compile_namespaces(evaluated_arg, &mut self.state, function_map),
+        vec![],
+      );
+    };
+
+    let mut evaluated_namespaces = vec![];
+    let mut failures = vec![];
+
+    for (index, (name, prop)) in namespaces.into_iter().enumerate() {
+      let mut namespace_arg = object_expression_factory(vec![prop.clone()]);
+
+      match evaluate_namespaces(&mut namespace_arg, &mut self.state, function_map) {
+        Ok(evaluated_namespace) => evaluated_namespaces.push(evaluated_namespace),
+        Err(error) => failures.push((index, name, prop, error)),
+      }
+    }
+
+    if evaluated_namespaces.is_empty() {
+      if let Some((_, _, _, error)) = failures.into_iter().next() {
+        panic!("{}", error);
+      }
+
+      return (CompiledCreateArg::default(), vec![]);
+    }
+
+    let mut compiled_arg = CompiledCreateArg::default();
+
+    for evaluated_namespace in evaluated_namespaces {
+      compiled_arg.extend(compile_namespaces(
+        evaluated_namespace,
+        &mut self.state,
+        function_map,
+      ));
+    }
+
+    let uncompiled_namespaces = failures
+      .into_iter()
+      .map(|(index, name, prop, error)| {
+        let runtime_create_fallback = self.state.options.runtime_create_fallback;
+
+        let message = format!(
+          "{} {} in {}: {}",
+          if runtime_create_fallback {
+            RUNTIME_CREATED_NAMESPACE
+          } else {
+            UNCOMPILED_NAMESPACE
+          },
+          name,
+          self.state.get_filename(),
+          error
+        );
+
+        // Namespaces left as written fail the build, as they aren't compiled
+        if runtime_create_fallback {
+          self.warn(&message);
+        } else {
+          self.error(&message);
+        }
+
+        (index, prop)
+      })
+      .collect();
+
+    (compiled_arg, uncompiled_namespaces)
+  }
+
+  /// Records the namespaces of the `stylex.create()` call bound to
+  /// `var_name` using each of their class names.
+  fn add_class_name_namespaces(
+    &mut self,
+    var_name: &str,
+    compiled_styles: &IndexMap<String, Box<FlatCompiledStyles>>,
+  ) {
+    for (namespace, properties) in compiled_styles {
+      let class_names = properties
+        .values()
+        .filter_map(|value| value.as_string())
+        .flat_map(|class_names| class_names.split_whitespace());
+
+      for class_name in class_names {
+        self
+          .state
+          .class_name_namespaces
+          .entry(class_name.to_string())
+          .or_default()
+          .push(format!("{}.{}", var_name, namespace));
+      }
+    }
+  }
+}
+
+/// Output of compiling a `stylex.create()` argument.
+#[derive(Default)]
+struct CompiledCreateArg {
+  compiled_styles: IndexMap<String, Box<FlatCompiledStyles>>,
+  injected_styles: InjectableStylesMap,
+  exported_styles: ExportedStyles,
+  fns: Option<EvaluateResultFns>,
+}
+
+impl CompiledCreateArg {
+  fn extend(&mut self, other: CompiledCreateArg) {
+    self.compiled_styles.extend(other.compiled_styles);
+    self.injected_styles.extend(other.injected_styles);
+    self.exported_styles.extend(other.exported_styles);
+
+    if let Some(fns) = other.fns {
+      self.fns.get_or_insert_with(IndexMap::new).extend(fns);
+    }
+  }
+}
+
+/// Evaluated namespaces of a `stylex.create()` argument.
+struct EvaluatedNamespaces {
+  value: Box<EvaluateResultValue>,
+  fns: Option<EvaluateResultFns>,
+}
+
+/// Evaluates the namespaces of `arg` and validates them, or returns the error
+/// to report when one of them isn't static or invalid.
+fn evaluate_namespaces(
+  arg: &mut Expr,
+  state: &mut StateManager,
+  function_map: &FunctionMap,
+) -> Result<EvaluatedNamespaces, String> {
+  let evaluated_arg = evaluate_stylex_create_arg(arg, state, function_map);
+
+  let value = match evaluated_arg.value {
+    Some(value) if evaluated_arg.confident => value,
+    _ => return Err(NON_STATIC_VALUE.to_string()),
+  };
+
+  if let Some(namespaces) = value.as_map() {
+    for namespace in namespaces.values() {
+      validate_namespace(&alias_properties(namespace, state), &[])?;
+    }
+  }
+
+  Ok(EvaluatedNamespaces {
+    value,
+    fns: evaluated_arg.fns,
+  })
+}
+
+fn compile_namespaces(
+  evaluated_namespaces: EvaluatedNamespaces,
+  state: &mut StateManager,
+  function_map: &FunctionMap,
+) -> CompiledCreateArg {
+  let (compiled_styles, injected_styles, exported_styles) =
+    stylex_create_set(&evaluated_namespaces.value, state, function_map);
+
+  CompiledCreateArg {
+    compiled_styles,
+    injected_styles,
+    exported_styles,
+    fns: evaluated_namespaces.fns,
+  }
+}
+
+/// The namespaces of a `stylex.create()` argument with their names, or
+/// `None` when a spread or a computed key hides some of them.
+fn get_namespace_props(object: &ObjectLit) -> Option<Vec<(String, PropOrSpread)>> {
+  object
+    .props
+    .iter()
+    .map(|prop| {
+      let name = match prop.as_prop()?.as_ref() {
+        Prop::KeyValue(key_value) => match &key_value.key {
+          PropName::Ident(ident) => ident.sym.to_string(),
+          PropName::Str(str) => str.value.to_string(),
+          _ => return None,
+        },
+        Prop::Shorthand(ident) => ident.sym.to_string(),
+        _ => return None,
+      };
+
+      Some((name, prop.clone()))
+    })
+    .collect()
 }
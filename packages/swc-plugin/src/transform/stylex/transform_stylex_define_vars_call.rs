@@ -5,21 +5,25 @@ use swc_core::{
   ecma::ast::{CallExpr, Expr},
 };
 
+use crate::shared::enums::data_structures::evaluate_result_value::EvaluateResultValue;
 use crate::shared::structures::functions::FunctionConfigType;
-use crate::shared::utils::{common::gen_file_based_identifier, js::evaluate::evaluate};
-use crate::shared::{
-  constants::messages::NON_OBJECT_FOR_STYLEX_CALL,
-  utils::validators::{is_define_vars_call, validate_stylex_define_vars},
+use crate::shared::utils::{
+  common::{gen_file_based_identifier, get_key_str, get_key_values_from_object, scope_hash_input},
+  js::evaluate::evaluate,
 };
 use crate::shared::{
   constants::messages::NON_STATIC_VALUE,
   utils::core::js_to_expr::{convert_object_to_ast, NestedStringObject},
 };
 use crate::shared::{
-  enums::data_structures::top_level_expression::TopLevelExpressionKind,
+  constants::{common::THEME_NAME_KEY, messages::NON_OBJECT_FOR_STYLEX_CALL},
+  utils::validators::{is_define_vars_call, validate_stylex_define_vars},
+};
+use crate::shared::{
   structures::{
     functions::FunctionMap,
     types::{FunctionMapIdentifiers, FunctionMapMemberExpression},
+    vars_cache::{hash_define_vars_value, CachedVarGroup, VarsCache},
   },
   transformers::{
     stylex_define_vars::stylex_define_vars, stylex_keyframes::get_keyframes_fn,
@@ -109,20 +113,37 @@ where
         }
       };
 
+      let cache_key = self.get_vars_cache_key(call, &value);
+
+      if let Some((path, value_hash, export_name)) = &cache_key {
+        if let Some(group) = VarsCache::global().get(path, value_hash, export_name) {
+          self.state.theme_name = Some(group.theme_name);
+
+          let (var_name, _) = self.get_call_var_name(call);
+
+          self
+            .state
+            .register_styles(call, &group.injected_styles, &group.ast, &var_name);
+
+          self.add_defined_vars(call, &group.ast);
+
+          return Some(group.ast);
+        }
+      }
+
       let Some(file_name) = self.state.get_filename_for_hashing() else {
         panic!("No filename found for generating theme name.")
       };
 
-      let export_expr = self
+      let export_name = self
         .state
-        .get_top_level_expr(&TopLevelExpressionKind::NamedExport, call);
-
-      let export_name = export_expr
-        .and_then(|expr| expr.2)
-        .map(|decl| decl.to_string())
+        .get_export_name(call)
         .expect("Export variable not found");
 
-      self.state.theme_name = Some(gen_file_based_identifier(&file_name, &export_name, None));
+      self.state.theme_name = Some(scope_hash_input(
+        self.state.get_theme_package_scope(&file_name),
+        gen_file_based_identifier(&file_name, &export_name, None),
+      ));
 
       let (variables_obj, injected_styles_sans_keyframes) =
         stylex_define_vars(&value, &mut self.state);
@@ -139,6 +160,21 @@ where
         .state
         .register_styles(call, &injected_styles, &result_ast, &var_name);
 
+      self.add_defined_vars(call, &result_ast);
+
+      if let Some((path, value_hash, export_name)) = &cache_key {
+        VarsCache::global().insert(
+          path,
+          value_hash,
+          export_name,
+          CachedVarGroup {
+            theme_name: self.state.theme_name.clone().unwrap_or_default(),
+            ast: result_ast.clone(),
+            injected_styles,
+          },
+        );
+      }
+
       return Some(result_ast);
     } else {
       None
@@ -146,4 +182,43 @@ where
 
     result
   }
+
+  /// Records the keys of the compiled `stylex.defineVars()` object `ast` under
+  /// the file name and export name its variables are hashed with.
+  fn add_defined_vars(&mut self, call: &CallExpr, ast: &Expr) {
+    let (Some(file_name), Some(export_name), Some(object)) = (
+      self.state.get_filename_for_hashing(),
+      self.state.get_export_name(call),
+      ast.as_object(),
+    ) else {
+      return;
+    };
+
+    for key_value in get_key_values_from_object(object) {
+      let key = get_key_str(&key_value);
+
+      if key != THEME_NAME_KEY {
+        self.state.defined_vars.add(&file_name, &export_name, &key);
+      }
+    }
+  }
+
+  /// Returns the file path, value hash and export name a `stylex.defineVars()`
+  /// call evaluated to `value` is cached under, or `None` when var caching is
+  /// disabled.
+  fn get_vars_cache_key(
+    &self,
+    call: &CallExpr,
+    value: &EvaluateResultValue,
+  ) -> Option<(String, String, String)> {
+    if !self.state.options.cache_vars {
+      return None;
+    }
+
+    let value_hash = hash_define_vars_value(value.as_expr()?, &self.state.options);
+
+    let export_name = self.state.get_export_name(call)?;
+
+    Some((self.state.get_filename(), value_hash, export_name))
+  }
 }
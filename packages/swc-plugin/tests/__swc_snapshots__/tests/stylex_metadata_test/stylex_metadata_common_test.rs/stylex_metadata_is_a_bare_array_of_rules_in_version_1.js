@@ -0,0 +1,8 @@
+//__stylex_metadata_start__[{"class_name":"x1e2nbdu","style":{"rtl":null,"ltr":".x1e2nbdu{color:red}"},"priority":3000}]__stylex_metadata_end__
+import stylex from 'stylex';
+export const styles = {
+    foo: {
+        color: "x1e2nbdu",
+        $$css: true
+    }
+};
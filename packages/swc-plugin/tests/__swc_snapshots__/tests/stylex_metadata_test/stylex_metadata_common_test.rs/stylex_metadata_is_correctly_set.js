@@ -1,4 +1,4 @@
-//__stylex_metadata_start__[{"class_name":"x1e2nbdu","style":{"rtl":null,"ltr":".x1e2nbdu{color:red}"},"priority":3000},{"class_name":"x1ycjhwn","style":{"rtl":null,"ltr":".x1ycjhwn{height:5px}"},"priority":4000},{"class_name":"xaiupp8","style":{"rtl":null,"ltr":".xaiupp8:hover{inset-inline-start:10px}"},"priority":3130},{"class_name":"x1uy60zq","style":{"rtl":null,"ltr":"@media (min-width: 1000px){.x1uy60zq.x1uy60zq{inset-inline-end:5px}}"},"priority":3200},{"class_name":"xqv9ub1-B","style":{"rtl":null,"ltr":"@keyframes xqv9ub1-B{from{inset-inline-start:0;}to{inset-inline-start:100px;}}"},"priority":1}]__stylex_metadata_end__
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"x1e2nbdu","style":{"rtl":null,"ltr":".x1e2nbdu{color:red}"},"priority":3000},{"class_name":"x1ycjhwn","style":{"rtl":null,"ltr":".x1ycjhwn{height:5px}"},"priority":4000},{"class_name":"xaiupp8","style":{"rtl":null,"ltr":".xaiupp8:hover{inset-inline-start:10px}"},"priority":3130},{"class_name":"x1uy60zq","style":{"rtl":null,"ltr":"@media (min-width: 1000px){.x1uy60zq.x1uy60zq{inset-inline-end:5px}}"},"priority":3200},{"class_name":"xqv9ub1-B","style":{"rtl":null,"ltr":"@keyframes xqv9ub1-B{from{inset-inline-start:0;}to{inset-inline-start:100px;}}"},"priority":1}]}__stylex_metadata_end__
 import stylex from 'stylex';
 export const styles = {
     foo: {
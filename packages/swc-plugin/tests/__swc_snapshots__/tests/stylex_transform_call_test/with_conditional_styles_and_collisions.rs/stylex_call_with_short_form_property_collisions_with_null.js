@@ -2,6 +2,6 @@ import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
 _inject2(".x14odnwx{padding:5px}", 1000);
-_inject2(".x2vl965{padding-inline-end:10px}", 3000);
 _inject2(".x1i3ajwb{padding:2px}", 1000);
+_inject2(".x2vl965{padding-inline-end:10px}", 3000);
 "x2vl965 x1i3ajwb";
@@ -2,6 +2,7 @@ import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
 _inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".x1t391ir{background-color:blue}", 3000);
 const styles = {
     default: {
         "FooBar__styles.default": "FooBar__styles.default",
@@ -9,7 +10,6 @@ const styles = {
         $$css: true
     }
 };
-_inject2(".x1t391ir{background-color:blue}", 3000);
 const otherStyles = {
     default: {
         "FooBar__otherStyles.default": "FooBar__otherStyles.default",
@@ -0,0 +1,13 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1wsgiic{transition-duration:.5s}", 3000);
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2("@media (min-width: 800px){.x9q3ppf.x9q3ppf:hover{color:blue}}", 3330);
+export const styles = {
+    root: {
+        transitionDuration: "x1wsgiic",
+        color: "x1e2nbdu x9q3ppf",
+        $$css: true
+    }
+};
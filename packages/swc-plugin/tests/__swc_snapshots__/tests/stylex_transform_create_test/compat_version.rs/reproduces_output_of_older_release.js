@@ -0,0 +1,13 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1p4pvp6{transition-duration:500ms}", 3000);
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2("@media (min-width: 800px){.xc26q2a.xc26q2a:hover{color:blue}}", 3330);
+export const styles = {
+    root: {
+        transitionDuration: "x1p4pvp6",
+        color: "x1e2nbdu xc26q2a",
+        $$css: true
+    }
+};
@@ -0,0 +1,68 @@
+var _inject = globalThis.__stylexSheet || (globalThis.__stylexSheet = function() {
+    var sheet = typeof CSSStyleSheet === "function" ? new CSSStyleSheet() : null;
+    if (sheet && typeof document !== "undefined" && document.adoptedStyleSheets) {
+        document.adoptedStyleSheets = [
+            ...document.adoptedStyleSheets,
+            sheet
+        ];
+    }
+    return {
+        sheet: sheet,
+        priorities: [],
+        rules: new Set()
+    };
+}());
+var _inject2 = function(css, priority, rtl) {
+    if (!_inject.sheet) {
+        return;
+    }
+    var rules = rtl == null ? [
+        css
+    ] : css.startsWith("@keyframes") ? [
+        css,
+        rtl
+    ] : [
+        ":where(:dir(ltr)){" + css + "}",
+        ":where(:dir(rtl)){" + rtl + "}"
+    ];
+    rules.forEach(function(rule) {
+        if (_inject.rules.has(rule)) {
+            return;
+        }
+        var index = _inject.priorities.findIndex(function(other) {
+            return other > priority;
+        });
+        if (index === -1) {
+            index = _inject.priorities.length;
+        }
+        try {
+            _inject.sheet.insertRule(rule, index);
+            _inject.priorities.splice(index, 0, priority);
+            _inject.rules.add(rule);
+        } catch (error) {}
+    });
+};
+import stylex from 'stylex';
+_inject2("@keyframes x18re5ia-B{from{opacity:0;}to{opacity:1;}}", 1);
+_inject2(".xfawy5m{padding:4px}", 1000);
+_inject2(".xqcmdr3{animation-name:x18re5ia-B}", 3000);
+export const styles = {
+    root: {
+        animationName: "xqcmdr3",
+        $$css: true
+    }
+};
+export const other = {
+    root: {
+        padding: "xfawy5m",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        $$css: true
+    }
+};
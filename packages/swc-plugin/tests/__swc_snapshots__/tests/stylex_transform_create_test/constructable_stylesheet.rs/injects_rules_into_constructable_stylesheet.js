@@ -0,0 +1,56 @@
+var _inject = globalThis.__stylexSheet || (globalThis.__stylexSheet = function() {
+    var sheet = typeof CSSStyleSheet === "function" ? new CSSStyleSheet() : null;
+    if (sheet && typeof document !== "undefined" && document.adoptedStyleSheets) {
+        document.adoptedStyleSheets = [
+            ...document.adoptedStyleSheets,
+            sheet
+        ];
+    }
+    return {
+        sheet: sheet,
+        priorities: [],
+        rules: new Set()
+    };
+}());
+var _inject2 = function(css, priority, rtl) {
+    if (!_inject.sheet) {
+        return;
+    }
+    var rules = rtl == null ? [
+        css
+    ] : css.startsWith("@keyframes") ? [
+        css,
+        rtl
+    ] : [
+        ":where(:dir(ltr)){" + css + "}",
+        ":where(:dir(rtl)){" + rtl + "}"
+    ];
+    rules.forEach(function(rule) {
+        if (_inject.rules.has(rule)) {
+            return;
+        }
+        var index = _inject.priorities.findIndex(function(other) {
+            return other > priority;
+        });
+        if (index === -1) {
+            index = _inject.priorities.length;
+        }
+        try {
+            _inject.sheet.insertRule(rule, index);
+            _inject.priorities.splice(index, 0, priority);
+            _inject.rules.add(rule);
+        } catch (error) {}
+    });
+};
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".x13fj5qh{margin-inline-start:8px}", 3000);
+export const styles = {
+    root: {
+        color: "x1e2nbdu",
+        marginInlineStart: "x13fj5qh",
+        marginLeft: null,
+        marginRight: null,
+        $$css: true
+    }
+};
@@ -0,0 +1,12 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+import styles from './styles';
+_inject2(".x1e2nbdu{color:red}", 3000);
+const local = {
+    root: {
+        color: "x1e2nbdu",
+        $$css: true
+    }
+};
+export const props = stylex.props(local.root, styles.root);
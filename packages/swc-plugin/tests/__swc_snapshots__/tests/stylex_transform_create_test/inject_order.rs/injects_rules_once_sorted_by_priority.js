@@ -0,0 +1,30 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1ghz6dp{margin:0}", 1000);
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".x17z2mba:hover{color:blue}", 3130);
+_inject2(".x1gslohp{margin-top:4px}", 4000);
+export const card = {
+    root: {
+        color: "x1e2nbdu",
+        ":hover_color": "x17z2mba",
+        margin: "x1ghz6dp",
+        marginInline: null,
+        marginInlineStart: null,
+        marginLeft: null,
+        marginInlineEnd: null,
+        marginRight: null,
+        marginBlock: null,
+        marginTop: null,
+        marginBottom: null,
+        $$css: true
+    }
+};
+export const button = {
+    root: {
+        color: "x1e2nbdu",
+        marginTop: "x1gslohp",
+        $$css: true
+    }
+};
@@ -0,0 +1,16 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+const config = {
+    styles: {
+        root: {
+            color: "x1e2nbdu",
+            $$css: true
+        }
+    }
+};
+export default config;
+export const className = {
+    className: "x1e2nbdu"
+};
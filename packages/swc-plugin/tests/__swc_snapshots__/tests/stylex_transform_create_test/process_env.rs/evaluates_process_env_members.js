@@ -0,0 +1,12 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1trkj60{color:tomato}", 3000);
+_inject2(".x1de99jn{outline-width:2px}", 3000);
+export const styles = {
+    root: {
+        color: "x1trkj60",
+        outlineWidth: "x1de99jn",
+        $$css: true
+    }
+};
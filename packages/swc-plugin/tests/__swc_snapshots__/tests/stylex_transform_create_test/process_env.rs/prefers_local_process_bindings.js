@@ -0,0 +1,10 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1n0khkq{color:rebeccapurple}", 3000);
+export const styles = {
+    root: {
+        color: "x1n0khkq",
+        $$css: true
+    }
+};
@@ -0,0 +1,17 @@
+/** @stylex-shadow-dom */ import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(":host-context(.dark){--x1axj9dq:blue;}", 0);
+_inject2(":host-context(.dark) .x119ugd6{margin-inline-start:4px}", 3000);
+export const vars = {
+    accent: "var(--x1axj9dq)",
+    __themeName__: "xsg933n"
+};
+export const styles = {
+    root: {
+        marginInlineStart: "x119ugd6",
+        marginLeft: null,
+        marginRight: null,
+        $$css: true
+    }
+};
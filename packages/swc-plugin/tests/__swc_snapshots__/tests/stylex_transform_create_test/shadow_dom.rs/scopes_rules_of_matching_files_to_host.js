@@ -0,0 +1,22 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@media (min-width: 800px){:host .x5ia2lo.x5ia2lo{padding:8px}}", 1200);
+_inject2(":host .x1ls7taf{color:red}", 3000);
+_inject2(":host .xe0guch:hover{color:blue}", 3130);
+export const styles = {
+    root: {
+        color: "x1ls7taf",
+        ":hover_color": "xe0guch",
+        "@media (min-width: 800px)_padding": "x5ia2lo",
+        "@media (min-width: 800px)_paddingInline": null,
+        "@media (min-width: 800px)_paddingStart": null,
+        "@media (min-width: 800px)_paddingLeft": null,
+        "@media (min-width: 800px)_paddingEnd": null,
+        "@media (min-width: 800px)_paddingRight": null,
+        "@media (min-width: 800px)_paddingBlock": null,
+        "@media (min-width: 800px)_paddingTop": null,
+        "@media (min-width: 800px)_paddingBottom": null,
+        $$css: true
+    }
+};
@@ -1,4 +1,4 @@
-//__stylex_metadata_start__[{"class_name":"xe4njm9","style":{"rtl":null,"ltr":".xe4njm9{margin:calc((100% - 50px) * .5) 20px 0}"},"priority":1000},{"class_name":"xs4buau","style":{"rtl":null,"ltr":".xs4buau{border-color:red blue}"},"priority":2000},{"class_name":"xbsl7fq","style":{"rtl":null,"ltr":".xbsl7fq{border-style:dashed}"},"priority":2000},{"class_name":"xn43iik","style":{"rtl":null,"ltr":".xn43iik{border-width:0 0 2px 0}"},"priority":2000},{"class_name":"xmkeg23","style":{"rtl":null,"ltr":".xmkeg23{border-width:1px}"},"priority":2000},{"class_name":"xa309fb","style":{"rtl":null,"ltr":".xa309fb{border-bottom-width:5px}"},"priority":4000},{"class_name":"x1y0btm7","style":{"rtl":null,"ltr":".x1y0btm7{border-style:solid}"},"priority":2000},{"class_name":"x1q0q8m5","style":{"rtl":null,"ltr":".x1q0q8m5{border-bottom-style:solid}"},"priority":4000},{"class_name":"x1lh7sze","style":{"rtl":null,"ltr":".x1lh7sze{border-color:var(--divider)}"},"priority":2000},{"class_name":"xud65wk","style":{"rtl":null,"ltr":".xud65wk{border-bottom-color:red}"},"priority":4000},{"class_name":"x12oqio5","style":{"rtl":null,"ltr":".x12oqio5{border-radius:4px}"},"priority":2000},{"class_name":"x1lmef92","style":{"rtl":null,"ltr":".x1lmef92{padding:calc((100% - 50px) * .5) var(--rightpadding,20px)}"},"priority":1000},{"class_name":"xexx8yu","style":{"rtl":null,"ltr":".xexx8yu{padding-top:0}"},"priority":4000},{"class_name":"x1bg2uv5","style":{"rtl":null,"ltr":".x1bg2uv5{border-color:green}"},"priority":2000}]__stylex_metadata_end__
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"xe4njm9","style":{"rtl":null,"ltr":".xe4njm9{margin:calc((100% - 50px) * .5) 20px 0}"},"priority":1000},{"class_name":"xs4buau","style":{"rtl":null,"ltr":".xs4buau{border-color:red blue}"},"priority":2000},{"class_name":"xbsl7fq","style":{"rtl":null,"ltr":".xbsl7fq{border-style:dashed}"},"priority":2000},{"class_name":"xn43iik","style":{"rtl":null,"ltr":".xn43iik{border-width:0 0 2px 0}"},"priority":2000},{"class_name":"xmkeg23","style":{"rtl":null,"ltr":".xmkeg23{border-width:1px}"},"priority":2000},{"class_name":"xa309fb","style":{"rtl":null,"ltr":".xa309fb{border-bottom-width:5px}"},"priority":4000},{"class_name":"x1y0btm7","style":{"rtl":null,"ltr":".x1y0btm7{border-style:solid}"},"priority":2000},{"class_name":"x1q0q8m5","style":{"rtl":null,"ltr":".x1q0q8m5{border-bottom-style:solid}"},"priority":4000},{"class_name":"x1lh7sze","style":{"rtl":null,"ltr":".x1lh7sze{border-color:var(--divider)}"},"priority":2000},{"class_name":"xud65wk","style":{"rtl":null,"ltr":".xud65wk{border-bottom-color:red}"},"priority":4000},{"class_name":"x12oqio5","style":{"rtl":null,"ltr":".x12oqio5{border-radius:4px}"},"priority":2000},{"class_name":"x1lmef92","style":{"rtl":null,"ltr":".x1lmef92{padding:calc((100% - 50px) * .5) var(--rightpadding,20px)}"},"priority":1000},{"class_name":"xexx8yu","style":{"rtl":null,"ltr":".xexx8yu{padding-top:0}"},"priority":4000},{"class_name":"x1bg2uv5","style":{"rtl":null,"ltr":".x1bg2uv5{border-color:green}"},"priority":2000}]}__stylex_metadata_end__
 import stylex from 'stylex';
 const borderRadius = 2;
 export const styles = {
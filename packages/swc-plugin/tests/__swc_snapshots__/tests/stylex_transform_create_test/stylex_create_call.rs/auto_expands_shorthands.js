@@ -3,16 +3,16 @@ var _inject2 = _inject;
 import stylex from 'stylex';
 const borderRadius = 2;
 _inject2(".xe4njm9{margin:calc((100% - 50px) * .5) 20px 0}", 1000);
+_inject2(".x1lmef92{padding:calc((100% - 50px) * .5) var(--rightpadding,20px)}", 1000);
 _inject2(".xs4buau{border-color:red blue}", 2000);
 _inject2(".xbsl7fq{border-style:dashed}", 2000);
 _inject2(".xn43iik{border-width:0 0 2px 0}", 2000);
 _inject2(".xmkeg23{border-width:1px}", 2000);
-_inject2(".xa309fb{border-bottom-width:5px}", 4000);
 _inject2(".x1y0btm7{border-style:solid}", 2000);
-_inject2(".x1q0q8m5{border-bottom-style:solid}", 4000);
 _inject2(".x1lh7sze{border-color:var(--divider)}", 2000);
-_inject2(".xud65wk{border-bottom-color:red}", 4000);
 _inject2(".x12oqio5{border-radius:4px}", 2000);
-_inject2(".x1lmef92{padding:calc((100% - 50px) * .5) var(--rightpadding,20px)}", 1000);
-_inject2(".xexx8yu{padding-top:0}", 4000);
 _inject2(".x1bg2uv5{border-color:green}", 2000);
+_inject2(".xa309fb{border-bottom-width:5px}", 4000);
+_inject2(".x1q0q8m5{border-bottom-style:solid}", 4000);
+_inject2(".xud65wk{border-bottom-color:red}", 4000);
+_inject2(".xexx8yu{padding-top:0}", 4000);
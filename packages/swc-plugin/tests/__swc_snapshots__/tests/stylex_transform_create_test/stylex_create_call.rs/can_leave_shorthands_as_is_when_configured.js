@@ -1,4 +1,4 @@
-//__stylex_metadata_start__[{"class_name":"xxsse2n","style":{"rtl":null,"ltr":".xxsse2n{margin-top:calc((100% - 50px) * .5)}"},"priority":4000},{"class_name":"x1wh8b8d","style":{"rtl":null,"ltr":".x1wh8b8d{margin-right:20px}"},"priority":4000},{"class_name":"xat24cr","style":{"rtl":null,"ltr":".xat24cr{margin-bottom:0}"},"priority":4000},{"class_name":"xzu6wam","style":{"rtl":null,"ltr":".xzu6wam{border-block-color:red}"},"priority":3000},{"class_name":"xgomli1","style":{"rtl":null,"ltr":".xgomli1{border-inline-color:blue}"},"priority":2000},{"class_name":"xbsl7fq","style":{"rtl":null,"ltr":".xbsl7fq{border-style:dashed}"},"priority":2000},{"class_name":"xlxy82","style":{"rtl":null,"ltr":".xlxy82{border-bottom-width:2px}"},"priority":4000},{"class_name":"xmkeg23","style":{"rtl":null,"ltr":".xmkeg23{border-width:1px}"},"priority":2000},{"class_name":"x1y0btm7","style":{"rtl":null,"ltr":".x1y0btm7{border-style:solid}"},"priority":2000},{"class_name":"x1lh7sze","style":{"rtl":null,"ltr":".x1lh7sze{border-color:var(--divider)}"},"priority":2000},{"class_name":"x12oqio5","style":{"rtl":null,"ltr":".x12oqio5{border-radius:4px}"},"priority":2000},{"class_name":"xa309fb","style":{"rtl":null,"ltr":".xa309fb{border-bottom-width:5px}"},"priority":4000},{"class_name":"x1q0q8m5","style":{"rtl":null,"ltr":".x1q0q8m5{border-bottom-style:solid}"},"priority":4000},{"class_name":"xud65wk","style":{"rtl":null,"ltr":".xud65wk{border-bottom-color:red}"},"priority":4000},{"class_name":"x190pm2f","style":{"rtl":null,"ltr":".x190pm2f{padding-block:calc((100% - 50px) * .5)}"},"priority":2000},{"class_name":"x1n86tx6","style":{"rtl":null,"ltr":".x1n86tx6{padding-inline:var(--rightpadding,20px)}"},"priority":2000},{"class_name":"xexx8yu","style":{"rtl":null,"ltr":".xexx8yu{padding-top:0}"},"priority":4000},{"class_name":"x1bg2uv5","style":{"rtl":null,"ltr":".x1bg2uv5{border-color:green}"},"priority":2000}]__stylex_metadata_end__
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"xxsse2n","style":{"rtl":null,"ltr":".xxsse2n{margin-top:calc((100% - 50px) * .5)}"},"priority":4000},{"class_name":"x1wh8b8d","style":{"rtl":null,"ltr":".x1wh8b8d{margin-right:20px}"},"priority":4000},{"class_name":"xat24cr","style":{"rtl":null,"ltr":".xat24cr{margin-bottom:0}"},"priority":4000},{"class_name":"xzu6wam","style":{"rtl":null,"ltr":".xzu6wam{border-block-color:red}"},"priority":3000},{"class_name":"xgomli1","style":{"rtl":null,"ltr":".xgomli1{border-inline-color:blue}"},"priority":2000},{"class_name":"xbsl7fq","style":{"rtl":null,"ltr":".xbsl7fq{border-style:dashed}"},"priority":2000},{"class_name":"xlxy82","style":{"rtl":null,"ltr":".xlxy82{border-bottom-width:2px}"},"priority":4000},{"class_name":"xmkeg23","style":{"rtl":null,"ltr":".xmkeg23{border-width:1px}"},"priority":2000},{"class_name":"x1y0btm7","style":{"rtl":null,"ltr":".x1y0btm7{border-style:solid}"},"priority":2000},{"class_name":"x1lh7sze","style":{"rtl":null,"ltr":".x1lh7sze{border-color:var(--divider)}"},"priority":2000},{"class_name":"x12oqio5","style":{"rtl":null,"ltr":".x12oqio5{border-radius:4px}"},"priority":2000},{"class_name":"xa309fb","style":{"rtl":null,"ltr":".xa309fb{border-bottom-width:5px}"},"priority":4000},{"class_name":"x1q0q8m5","style":{"rtl":null,"ltr":".x1q0q8m5{border-bottom-style:solid}"},"priority":4000},{"class_name":"xud65wk","style":{"rtl":null,"ltr":".xud65wk{border-bottom-color:red}"},"priority":4000},{"class_name":"x190pm2f","style":{"rtl":null,"ltr":".x190pm2f{padding-block:calc((100% - 50px) * .5)}"},"priority":2000},{"class_name":"x1n86tx6","style":{"rtl":null,"ltr":".x1n86tx6{padding-inline:var(--rightpadding,20px)}"},"priority":2000},{"class_name":"xexx8yu","style":{"rtl":null,"ltr":".xexx8yu{padding-top:0}"},"priority":4000},{"class_name":"x1bg2uv5","style":{"rtl":null,"ltr":".x1bg2uv5{border-color:green}"},"priority":2000}]}__stylex_metadata_end__
 import stylex from 'stylex';
 const borderRadius = 2;
 export const styles = {
@@ -1,9 +1,10 @@
 import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
-_inject2(".x1ok221b{margin-top:5px}", 4000);
+const borderRadius = 2;
+_inject2(".x1ghz6dp{margin:0}", 1000);
 _inject2(".x1sa5p1d{margin-inline-end:10px}", 3000);
-_inject2(".x1fqp7bg{margin-bottom:15px}", 4000);
 _inject2(".xqsn43r{margin-inline-start:20px}", 3000);
-_inject2(".x1ghz6dp{margin:0}", 1000);
+_inject2(".x1ok221b{margin-top:5px}", 4000);
+_inject2(".x1fqp7bg{margin-bottom:15px}", 4000);
 "x1ghz6dp";
@@ -0,0 +1,17 @@
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"x1prwzq3","style":{"rtl":null,"ltr":".x1prwzq3{color:green}"},"priority":3000},{"class_name":"xju2f9n","style":{"rtl":null,"ltr":".xju2f9n{color:blue}"},"priority":3000},{"class_name":"xbyyjgo","style":{"rtl":null,"ltr":".xbyyjgo{opacity:.5}"},"priority":3000}]}__stylex_metadata_end__
+import stylex from 'stylex';
+export const styles = {
+    default: {
+        color: "x1prwzq3",
+        $$css: true
+    },
+    active: {
+        color: "xju2f9n",
+        $$css: true
+    },
+    disabled: {
+        color: "xju2f9n",
+        opacity: "xbyyjgo",
+        $$css: true
+    }
+};
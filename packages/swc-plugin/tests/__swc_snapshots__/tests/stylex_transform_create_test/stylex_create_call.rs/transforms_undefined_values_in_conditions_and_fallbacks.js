@@ -0,0 +1,12 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2(".xju2f9n{color:blue}", 3000);
+export const styles = {
+    default: {
+        backgroundColor: "xrkmrrc",
+        color: "xju2f9n",
+        $$css: true
+    }
+};
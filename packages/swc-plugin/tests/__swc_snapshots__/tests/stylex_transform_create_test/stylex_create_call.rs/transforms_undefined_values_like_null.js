@@ -0,0 +1,11 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1fsd2vl{width:10px}", 4000);
+export const styles = {
+    default: {
+        color: null,
+        width: "x1fsd2vl",
+        $$css: true
+    }
+};
@@ -0,0 +1,8 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xva1sz4{--Ñame:red}", 1);
+_inject2(".xu68j6u{--my\\ var:blue}", 1);
+_inject2(".x1b8wi3h{--\\31 x:green}", 1);
+_inject2(".x1ignnz{color:var(--Ñame)}", 3000);
+_inject2(".xdbryqo::before{content:'→ ✓ 😀'}", 8000);
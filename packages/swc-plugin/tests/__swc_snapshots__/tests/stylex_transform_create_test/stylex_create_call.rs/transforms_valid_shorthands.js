@@ -1,9 +1,10 @@
 import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
+const MEDIA_MOBILE = "@media (max-width: 700px)";
 import stylex from 'stylex';
 _inject2(".xb3r6kr{overflow:hidden}", 2000);
 _inject2(".xbsl7fq{border-style:dashed}", 2000);
 _inject2(".xmkeg23{border-width:1px}", 2000);
-_inject2("@media (max-width: 700px){.x1m60m6i.x1m60m6i{border-width:0}}", 2200);
 _inject2(".x1x9kz2p{border-color:rgba(131,134,135,0)}", 2000);
 _inject2(".xn8xhnf:hover{border-color:rgba(var(--xpue81e),var(--x1gflzcx),var(--x1363ko0),.1)}", 2130);
+_inject2("@media (max-width: 700px){.x1m60m6i.x1m60m6i{border-width:0}}", 2200);
@@ -1,7 +1,7 @@
 import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
+_inject2(".x126ychx:nth-child(2n){color:purple}", 3060);
 _inject2(".x17z2mba:hover{color:blue}", 3130);
-_inject2(".x96fq8s:active{color:red}", 3170);
 _inject2(".x1wvtd7d:focus{color:yellow}", 3150);
-_inject2(".x126ychx:nth-child(2n){color:purple}", 3060);
+_inject2(".x96fq8s:active{color:red}", 3170);
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@keyframes xbopttm-B{from{background-color:red;}to{background-color:blue;}}", 1);
+export const name = "xbopttm-B";
@@ -0,0 +1,32 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xu1pgs9{margin:1.5rem}", 1000);
+_inject2(".x10y37je{padding:.5rem .25rem}", 1000);
+_inject2(".x1c3i2sq{font-size:1.125rem}", 3000);
+_inject2(".x1itdkj{letter-spacing:.5em}", 3000);
+export const styles = {
+    root: {
+        fontSize: "x1c3i2sq",
+        letterSpacing: "x1itdkj",
+        margin: "xu1pgs9",
+        marginInline: null,
+        marginInlineStart: null,
+        marginLeft: null,
+        marginInlineEnd: null,
+        marginRight: null,
+        marginBlock: null,
+        marginTop: null,
+        marginBottom: null,
+        padding: "x10y37je",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        $$css: true
+    }
+};
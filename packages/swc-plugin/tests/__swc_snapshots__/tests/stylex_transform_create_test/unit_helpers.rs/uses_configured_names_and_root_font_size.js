@@ -0,0 +1,10 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xngnso2{font-size:1.5rem}", 3000);
+export const styles = {
+    root: {
+        fontSize: "xngnso2",
+        $$css: true
+    }
+};
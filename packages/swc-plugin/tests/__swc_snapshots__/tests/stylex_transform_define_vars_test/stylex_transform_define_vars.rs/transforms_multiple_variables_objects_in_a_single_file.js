@@ -1,4 +1,4 @@
-//__stylex_metadata_start__[{"class_name":"x568ih9","style":{"rtl":null,"ltr":":root{--xgck17p:blue;--xpegid5:grey;--xrqfjmn:10;--x4y59db:pink;}"},"priority":0},{"class_name":"x568ih9-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){:root{--xgck17p:lightblue;--xpegid5:rgba(0, 0, 0, 0.8);}}"},"priority":0.1},{"class_name":"x568ih9-bdddrq","style":{"rtl":null,"ltr":"@media print{:root{--xgck17p:white;}}"},"priority":0.1},{"class_name":"xb35w82","style":{"rtl":null,"ltr":":root{--xcateir:white;--xmj7ivn:black;--x13gxjix:8;}"},"priority":0},{"class_name":"xb35w82-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){:root{--xmj7ivn:white;}}"},"priority":0.1}]__stylex_metadata_end__
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"x568ih9","style":{"rtl":null,"ltr":":root{--xgck17p:blue;--xpegid5:grey;--xrqfjmn:10;--x4y59db:pink;}"},"priority":0},{"class_name":"x568ih9-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){:root{--xgck17p:lightblue;--xpegid5:rgba(0, 0, 0, 0.8);}}"},"priority":0.1},{"class_name":"x568ih9-bdddrq","style":{"rtl":null,"ltr":"@media print{:root{--xgck17p:white;}}"},"priority":0.1},{"class_name":"xb35w82","style":{"rtl":null,"ltr":":root{--xcateir:white;--xmj7ivn:black;--x13gxjix:8;}"},"priority":0},{"class_name":"xb35w82-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){:root{--xmj7ivn:white;}}"},"priority":0.1}]}__stylex_metadata_end__
 import stylex from 'stylex';
 export const buttonTheme = {
     bgColor: "var(--xgck17p)",
@@ -0,0 +1,6 @@
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"x18re5ia-B","style":{"rtl":null,"ltr":"@keyframes x18re5ia-B{from{opacity:0;}to{opacity:1;}}"},"priority":1},{"class_name":"x3cpup2","style":{"rtl":null,"ltr":":root{--xhs5bwh:x18re5ia-B 1s ease;}"},"priority":0}]}__stylex_metadata_end__
+import stylex from 'stylex';
+export const motion = {
+    fade: "var(--xhs5bwh)",
+    __themeName__: "x3cpup2"
+};
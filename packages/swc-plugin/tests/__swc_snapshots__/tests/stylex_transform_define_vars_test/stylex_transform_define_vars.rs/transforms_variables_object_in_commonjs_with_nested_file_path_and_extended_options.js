@@ -1,4 +1,4 @@
-//__stylex_metadata_start__[{"class_name":"xtaagyt","style":{"rtl":null,"ltr":":root{--xxkg2fb:blue;--x1849978:white;--x1cugzri:4px;--xkdi634:4px;--xfympxu:8px;}"},"priority":0}]__stylex_metadata_end__
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"xtaagyt","style":{"rtl":null,"ltr":":root{--xxkg2fb:blue;--x1849978:white;--x1cugzri:4px;--xkdi634:4px;--xfympxu:8px;}"},"priority":0}]}__stylex_metadata_end__
 import * as stylex from "@stylexjs/stylex";
 export const buttonTokens = {
     bgColor: "var(--xxkg2fb)",
@@ -1,4 +1,4 @@
-//__stylex_metadata_start__[{"class_name":"x568ih9","style":{"rtl":null,"ltr":":root{--bgColor:blue;--bgColorDisabled:grey;--cornerRadius:10;--fgColor:pink;}"},"priority":0},{"class_name":"x568ih9-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){:root{--bgColor:lightblue;--bgColorDisabled:rgba(0, 0, 0, 0.8);}}"},"priority":0.1},{"class_name":"x568ih9-bdddrq","style":{"rtl":null,"ltr":"@media print{:root{--bgColor:white;}}"},"priority":0.1}]__stylex_metadata_end__
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"x568ih9","style":{"rtl":null,"ltr":":root{--bgColor:blue;--bgColorDisabled:grey;--cornerRadius:10;--fgColor:pink;}"},"priority":0},{"class_name":"x568ih9-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){:root{--bgColor:lightblue;--bgColorDisabled:rgba(0, 0, 0, 0.8);}}"},"priority":0.1},{"class_name":"x568ih9-bdddrq","style":{"rtl":null,"ltr":"@media print{:root{--bgColor:white;}}"},"priority":0.1}]}__stylex_metadata_end__
 import stylex from 'stylex';
 export const buttonTheme = {
     "--bgColor": "var(--bgColor)",
@@ -1,9 +1,9 @@
 import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import { create as css } from '@stylexjs/stylex';
+_inject2(".x14odnwx{padding:5px}", 1000);
 _inject2(".xrkmrrc{background-color:red}", 3000);
 _inject2(".xju2f9n{color:blue}", 3000);
-_inject2(".x14odnwx{padding:5px}", 1000);
 const styles = {
     default: {
         backgroundColor: "xrkmrrc",
@@ -0,0 +1,7 @@
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"x1e2nbdu","style":{"rtl":null,"ltr":".x1e2nbdu{color:red}"},"priority":3000}]}__stylex_metadata_end__
+"use client";
+import "virtual:stylex.css";
+import * as stylex from '@stylexjs/stylex';
+export const className = {
+    className: "x1e2nbdu"
+};
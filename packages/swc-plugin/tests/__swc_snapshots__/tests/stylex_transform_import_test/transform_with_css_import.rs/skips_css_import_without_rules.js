@@ -0,0 +1,3 @@
+//__stylex_metadata_start__{"version":2,"rules":[]}__stylex_metadata_end__
+import * as stylex from '@stylexjs/stylex';
+export const className = stylex.props(null);
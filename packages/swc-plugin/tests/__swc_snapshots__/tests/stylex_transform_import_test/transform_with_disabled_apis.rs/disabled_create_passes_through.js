@@ -0,0 +1,7 @@
+import { create, props } from '@stylexjs/stylex';
+const styles = create({
+    root: {
+        color: 'red'
+    }
+});
+export const className = props(styles.root);
@@ -0,0 +1,7 @@
+import stylex from '@stylexjs/stylex';
+export const vars = stylex.defineVars({
+    color: 'red'
+});
+export const theme = stylex.createTheme(vars, {
+    color: 'blue'
+});
@@ -0,0 +1,16 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+const fadeIn = stylex.keyframes({
+    from: {
+        opacity: 0
+    },
+    to: {
+        opacity: 1
+    }
+});
+_inject2(".x1e2nbdu{color:red}", 3000);
+export const className = {
+    className: "x1e2nbdu"
+};
+export { fadeIn };
@@ -0,0 +1,13 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from '@stylexjs/stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+const styles = {
+    root: {
+        color: "x1e2nbdu",
+        $$css: true
+    }
+};
+export const props = stylex.props(styles.root);
+export const attrs = stylex.attrs(styles.root);
+export const legacy = stylex(styles.root);
@@ -0,0 +1,7 @@
+import * as stylex from '@stylexjs/stylex';
+const styles = stylex.create({
+    root: {
+        color: 'red'
+    }
+});
+export const className = stylex.props(styles.root);
@@ -1,10 +1,10 @@
 import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
-_inject2(".x123j3cw{padding-top:5px}", 4000);
 _inject2(".x1mpkggp{padding-right:5px}", 3000, ".x1mpkggp{padding-left:5px}");
-_inject2(".xs9asl8{padding-bottom:5px}", 4000);
 _inject2(".x1t2a60a{padding-left:5px}", 3000, ".x1t2a60a{padding-right:5px}");
+_inject2(".x123j3cw{padding-top:5px}", 4000);
+_inject2(".xs9asl8{padding-bottom:5px}", 4000);
 export const styles = {
     foo: {
         paddingTop: "x123j3cw",
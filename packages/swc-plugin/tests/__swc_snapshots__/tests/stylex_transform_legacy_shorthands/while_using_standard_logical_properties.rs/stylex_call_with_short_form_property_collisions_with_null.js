@@ -1,11 +1,11 @@
 import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
-_inject2(".x123j3cw{padding-top:5px}", 4000);
 _inject2(".x1iji9kk{padding-right:10px}", 3000, ".x1iji9kk{padding-left:10px}");
-_inject2(".xs9asl8{padding-bottom:5px}", 4000);
 _inject2(".x1t2a60a{padding-left:5px}", 3000, ".x1t2a60a{padding-right:5px}");
-_inject2(".x1nn3v0j{padding-top:2px}", 4000);
 _inject2(".xg83lxy{padding-right:2px}", 3000, ".xg83lxy{padding-left:2px}");
+_inject2(".x123j3cw{padding-top:5px}", 4000);
+_inject2(".xs9asl8{padding-bottom:5px}", 4000);
+_inject2(".x1nn3v0j{padding-top:2px}", 4000);
 _inject2(".x1120s5i{padding-bottom:2px}", 4000);
 "x1nn3v0j xg83lxy x1120s5i";
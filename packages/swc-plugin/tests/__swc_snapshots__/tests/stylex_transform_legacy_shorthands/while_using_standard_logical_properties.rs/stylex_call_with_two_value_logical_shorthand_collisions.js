@@ -0,0 +1,10 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xsgj6o6{margin-left:4px}", 3000, ".xsgj6o6{margin-right:4px}");
+_inject2(".xw3qccf{margin-right:4px}", 3000, ".xw3qccf{margin-left:4px}");
+_inject2(".x12mruv9{margin-left:2px}", 3000, ".x12mruv9{margin-right:2px}");
+_inject2(".x1emribx{margin-right:8px}", 3000, ".x1emribx{margin-left:8px}");
+_inject2(".x4p5aij{padding-top:1px}", 4000);
+_inject2(".x1120s5i{padding-bottom:2px}", 4000);
+"x12mruv9 x1emribx x4p5aij x1120s5i";
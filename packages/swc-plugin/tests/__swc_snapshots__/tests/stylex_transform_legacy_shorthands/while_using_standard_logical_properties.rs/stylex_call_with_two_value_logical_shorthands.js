@@ -0,0 +1,8 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e558r4{padding-left:4px}", 3000, ".x1e558r4{padding-right:4px}");
+_inject2(".x1sxyh0{padding-right:8px}", 3000, ".x1sxyh0{padding-left:8px}");
+_inject2(".xdj266r{margin-top:0}", 4000);
+_inject2(".x4ii5y1{margin-bottom:auto}", 4000);
+"x1e558r4 x1sxyh0 xdj266r x4ii5y1";
@@ -1,4 +1,4 @@
-//__stylex_metadata_start__[{"class_name":"xtrlmmh","style":{"rtl":null,"ltr":".xtrlmmh{--xgck17p:green;--xpegid5:antiquewhite;--xrqfjmn:6px;--x4y59db:coral;}"},"priority":0.5},{"class_name":"xtrlmmh-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){.xtrlmmh{--xgck17p:lightgreen;--xpegid5:floralwhite;}}"},"priority":0.6},{"class_name":"xtrlmmh-bdddrq","style":{"rtl":null,"ltr":"@media print{.xtrlmmh{--xgck17p:transparent;}}"},"priority":0.6}]__stylex_metadata_end__
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"xtrlmmh","style":{"rtl":null,"ltr":".xtrlmmh{--xgck17p:green;--xpegid5:antiquewhite;--xrqfjmn:6px;--x4y59db:coral;}"},"priority":0.5},{"class_name":"xtrlmmh-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){.xtrlmmh{--xgck17p:lightgreen;--xpegid5:floralwhite;}}"},"priority":0.6},{"class_name":"xtrlmmh-bdddrq","style":{"rtl":null,"ltr":"@media print{.xtrlmmh{--xgck17p:transparent;}}"},"priority":0.6}]}__stylex_metadata_end__
 import stylex from 'stylex';
 export const buttonTheme = {
     bgColor: "var(--xgck17p)",
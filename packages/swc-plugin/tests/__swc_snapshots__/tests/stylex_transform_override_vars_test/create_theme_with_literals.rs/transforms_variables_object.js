@@ -1,4 +1,4 @@
-//__stylex_metadata_start__[{"class_name":"x4znj40","style":{"rtl":null,"ltr":".x4znj40{--bgColor:green;--bgColorDisabled:antiquewhite;--cornerRadius:6px;--fgColor:coral;}"},"priority":0.5},{"class_name":"x4znj40-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){.x4znj40{--bgColor:lightgreen;--bgColorDisabled:floralwhite;}}"},"priority":0.6},{"class_name":"x4znj40-bdddrq","style":{"rtl":null,"ltr":"@media print{.x4znj40{--bgColor:transparent;}}"},"priority":0.6}]__stylex_metadata_end__
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"x4znj40","style":{"rtl":null,"ltr":".x4znj40{--bgColor:green;--bgColorDisabled:antiquewhite;--cornerRadius:6px;--fgColor:coral;}"},"priority":0.5},{"class_name":"x4znj40-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){.x4znj40{--bgColor:lightgreen;--bgColorDisabled:floralwhite;}}"},"priority":0.6},{"class_name":"x4znj40-bdddrq","style":{"rtl":null,"ltr":"@media print{.x4znj40{--bgColor:transparent;}}"},"priority":0.6}]}__stylex_metadata_end__
 import stylex from 'stylex';
 export const buttonTheme = {
     "--bgColor": "var(--bgColor)",
@@ -3,8 +3,8 @@ var _inject2 = _inject;
 import stylex from 'stylex';
 _inject2(".x2y918k{--xgck17p:green;}", 0.5);
 _inject2("@media (prefers-color-scheme: dark){.x2y918k{--xgck17p:lightgreen;}}", 0.6);
-_inject2("@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){.x2y918k{--xgck17p:oklab(0.7 -0.2 -0.4);}}}", 0.7);
 _inject2("@supports (color: oklab(0 0 0)){.x2y918k{--xgck17p:oklab(0.7 -0.3 -0.4);}}", 0.6);
+_inject2("@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){.x2y918k{--xgck17p:oklab(0.7 -0.2 -0.4);}}}", 0.7);
 export const variables = {
     $$css: true,
     "TestTheme.stylex.js//buttonTheme": "x2y918k"
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@keyframes x18re5ia-B{from{opacity:0;}to{opacity:1;}}", 1);
+_inject2(".x1g7qadp{animation:x18re5ia-B 3s ease-in}", 1000);
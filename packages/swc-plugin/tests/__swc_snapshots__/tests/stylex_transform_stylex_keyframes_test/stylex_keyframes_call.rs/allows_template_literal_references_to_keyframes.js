@@ -2,5 +2,5 @@ import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
 _inject2("@keyframes x3zqmp-B{from{background-color:blue;}to{background-color:red;}}", 1);
-export const name = "x3zqmp-B";
 _inject2(".x1qs41r0{animation:3s x3zqmp-B}", 1000);
+export const name = "x3zqmp-B";
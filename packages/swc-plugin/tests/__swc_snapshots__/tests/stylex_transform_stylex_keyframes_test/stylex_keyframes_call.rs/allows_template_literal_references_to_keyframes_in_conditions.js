@@ -0,0 +1,7 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@keyframes x18re5ia-B{from{opacity:0;}to{opacity:1;}}", 1);
+_inject2(".x1g5t3ys{animation:x18re5ia-B 1s ease}", 1000);
+_inject2("@media (prefers-reduced-motion){.xw4h24m.xw4h24m{animation:x18re5ia-B 0s}}", 1200);
+const fadeIn = "x18re5ia-B";
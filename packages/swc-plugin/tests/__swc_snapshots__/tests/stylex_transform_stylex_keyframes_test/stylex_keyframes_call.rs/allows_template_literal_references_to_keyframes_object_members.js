@@ -0,0 +1,12 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@keyframes x18re5ia-B{from{opacity:0;}to{opacity:1;}}", 1);
+_inject2("@keyframes x1rl21mk-B{from{rotate:0deg;}to{rotate:360deg;}}", 1);
+_inject2(".xwc7xr3{animation:x1rl21mk-B 1s linear infinite,x18re5ia-B 1s}", 1000);
+const fadeIn = "x18re5ia-B";
+const spin = "x1rl21mk-B";
+const animations = {
+    fadeIn,
+    spin
+};
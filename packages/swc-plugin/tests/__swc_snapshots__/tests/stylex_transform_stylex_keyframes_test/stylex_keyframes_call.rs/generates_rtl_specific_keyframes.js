@@ -2,8 +2,8 @@ import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
 _inject2("@keyframes x1jkcf39-B{from{inset-inline-start:0;}to{inset-inline-start:500px;}}", 1);
-export const name = "x1jkcf39-B";
 _inject2(".x1vfi257{animation-name:x1jkcf39-B}", 3000);
+export const name = "x1jkcf39-B";
 export const styles = {
     root: {
         animationName: "x1vfi257",
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@keyframes x9reznu-B{from{opacity:0;color:red;}to{opacity:1;color:blue;}}", 1);
+export const name = "x9reznu-B";
@@ -0,0 +1,14 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+const styles = {
+    default: {
+        color: "x1e2nbdu",
+        $$css: true
+    }
+};
+({
+    className: "x1e2nbdu"
+});
+export default styles;
@@ -0,0 +1,9 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+import { track } from './analytics';
+const unused = track('render');
+_inject2(".x1e2nbdu{color:red}", 3000);
+({
+    className: "x1e2nbdu"
+});
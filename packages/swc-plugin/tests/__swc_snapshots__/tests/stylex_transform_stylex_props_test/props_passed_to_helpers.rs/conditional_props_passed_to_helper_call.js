@@ -0,0 +1,15 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".xju2f9n{color:blue}", 3000);
+export function Button({ active }) {
+    return <button {...cx({
+        0: {
+            className: "x1e2nbdu"
+        },
+        1: {
+            className: "xju2f9n"
+        }
+    }[!!active << 0])}/>;
+}
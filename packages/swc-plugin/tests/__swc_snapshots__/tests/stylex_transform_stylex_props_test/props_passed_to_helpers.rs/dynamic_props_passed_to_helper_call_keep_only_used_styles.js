@@ -0,0 +1,14 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".xju2f9n{color:blue}", 3000);
+const styles = {
+    root: {
+        color: "x1e2nbdu",
+        $$css: true
+    }
+};
+export function Button({ style }) {
+    return <button {...cx(stylex.props(styles.root, style))}/>;
+}
@@ -0,0 +1,14 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+export function Button() {
+    const rootProps = {
+        className: "x1e2nbdu"
+    };
+    return <button className={cx({
+        className: "x1e2nbdu"
+    }.className, 'button')} {...mergeProps(rootProps, {
+        id: 'a'
+    })}/>;
+}
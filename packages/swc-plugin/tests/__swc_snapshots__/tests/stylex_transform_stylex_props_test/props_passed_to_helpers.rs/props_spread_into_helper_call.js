@@ -0,0 +1,13 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+import { cx } from './cx';
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".xju2f9n{color:blue}", 3000);
+export function Button({ active, style }) {
+    return <button {...cx({
+        className: "x1e2nbdu"
+    }, active && {
+        className: "xju2f9n"
+    }, style)}/>;
+}
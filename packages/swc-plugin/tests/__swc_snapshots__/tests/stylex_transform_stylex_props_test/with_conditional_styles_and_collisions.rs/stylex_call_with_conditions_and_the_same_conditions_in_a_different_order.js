@@ -0,0 +1,14 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1ehdwse:hover{color:green}", 3130);
+_inject2("@media (min-width: 800px){.xl7d0ox.xl7d0ox:hover{color:red}}", 3330);
+_inject2("@media (min-width: 800px){.x9q3ppf.x9q3ppf:hover{color:blue}}", 3330);
+({
+    0: {
+        className: "x1ehdwse xl7d0ox"
+    },
+    1: {
+        className: "x1ehdwse x9q3ppf"
+    }
+})[!!isActive << 0];
@@ -0,0 +1,14 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2(".xju2f9n{color:blue}", 3000);
+_inject2(".xkn7p67{color:gray}", 3000);
+({
+    0: {
+        className: "xrkmrrc"
+    },
+    1: {
+        className: "xrkmrrc xju2f9n"
+    }
+})[!!(isEnabled && isActive) << 0];
@@ -0,0 +1,8 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".x1t391ir{background-color:blue}", 3000);
+({
+    className: "ÜnïcodePage__styles.ключ x1e2nbdu ÜnïcodePage__styles.twowords x1t391ir"
+});
@@ -1,9 +1,9 @@
 import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
-_inject2(".x1egiwwb{height:500px}", 4000);
 _inject2(".x1oin6zd{margin:10px}", 1000);
-_inject2(".xvue9z{width:500px}", 4000);
 _inject2(".xk50ysn{font-weight:500}", 3000);
 _inject2(".x1evy7pa{line-height:1.5}", 3000);
 _inject2(".xbyyjgo{opacity:.5}", 3000);
+_inject2(".x1egiwwb{height:500px}", 4000);
+_inject2(".xvue9z{width:500px}", 4000);
@@ -1 +1,2 @@
 import stylex from "@stylexjs/stylex";
+const borderSize = 2;
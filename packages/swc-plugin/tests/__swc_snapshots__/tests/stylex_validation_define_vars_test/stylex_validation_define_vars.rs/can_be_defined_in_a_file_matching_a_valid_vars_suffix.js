@@ -0,0 +1,8 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(":root{--xpmemnz:red;}", 0);
+export const styles = {
+    labelColor: "var(--xpmemnz)",
+    __themeName__: "x11d0oqd"
+};
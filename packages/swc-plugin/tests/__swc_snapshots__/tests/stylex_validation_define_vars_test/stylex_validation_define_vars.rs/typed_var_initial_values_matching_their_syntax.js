@@ -0,0 +1,10 @@
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"x1pz6m86","style":{"rtl":null,"ltr":"@property --x1pz6m86 { syntax: \"<color>\"; inherits: true; initial-value: rgb(0 0 0) }"},"priority":0},{"class_name":"x1rqz8xa","style":{"rtl":null,"ltr":"@property --x1rqz8xa { syntax: \"<length>\"; inherits: true; initial-value: calc(4px + 1rem) }"},"priority":0},{"class_name":"x1cmr60t","style":{"rtl":null,"ltr":"@property --x1cmr60t { syntax: \"<number>\"; inherits: true; initial-value: 0.5 }"},"priority":0},{"class_name":"x13tq8c8","style":{"rtl":null,"ltr":"@property --x13tq8c8 { syntax: \"<angle>\"; inherits: true; initial-value: 45deg }"},"priority":0},{"class_name":"x179qqkf","style":{"rtl":null,"ltr":"@property --x179qqkf { syntax: \"<transformList>\"; inherits: true; initial-value: rotate(45deg) scale(2) }"},"priority":0},{"class_name":"x1vn2wpz","style":{"rtl":null,"ltr":":root{--x1pz6m86:rgb(0 0 0);--x1rqz8xa:calc(4px + 1rem);--x1cmr60t:0.5;--x13tq8c8:45deg;--x179qqkf:rotate(45deg) scale(2);}"},"priority":0}]}__stylex_metadata_end__
+import stylex from 'stylex';
+export const styles = {
+    labelColor: "var(--x1pz6m86)",
+    cornerRadius: "var(--x1rqz8xa)",
+    opacity: "var(--x1cmr60t)",
+    rotation: "var(--x13tq8c8)",
+    transform: "var(--x179qqkf)",
+    __themeName__: "x1vn2wpz"
+};
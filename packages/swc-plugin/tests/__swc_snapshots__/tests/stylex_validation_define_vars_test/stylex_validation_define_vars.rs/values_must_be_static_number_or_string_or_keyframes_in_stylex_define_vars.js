@@ -1,4 +1,4 @@
-//__stylex_metadata_start__[{"class_name":"xu9ay7p","style":{"rtl":null,"ltr":":root{--x1fsfvwb:5;}"},"priority":0},{"class_name":"x17235c5","style":{"rtl":null,"ltr":":root{--x8eqzj6:red;}"},"priority":0},{"class_name":"xekv6nw-B","style":{"rtl":null,"ltr":"@keyframes xekv6nw-B{0%{opacity:0;}100%{opacity:1;}}"},"priority":1},{"class_name":"x2wfqvm","style":{"rtl":null,"ltr":":root{--xt8h53x:xekv6nw-B;}"},"priority":0}]__stylex_metadata_end__
+//__stylex_metadata_start__{"version":2,"rules":[{"class_name":"xu9ay7p","style":{"rtl":null,"ltr":":root{--x1fsfvwb:5;}"},"priority":0},{"class_name":"x17235c5","style":{"rtl":null,"ltr":":root{--x8eqzj6:red;}"},"priority":0},{"class_name":"xekv6nw-B","style":{"rtl":null,"ltr":"@keyframes xekv6nw-B{0%{opacity:0;}100%{opacity:1;}}"},"priority":1},{"class_name":"x2wfqvm","style":{"rtl":null,"ltr":":root{--xt8h53x:xekv6nw-B;}"},"priority":0}]}__stylex_metadata_end__
 import stylex from 'stylex';
 export const styles1 = {
     cornerRadius: "var(--x1fsfvwb)",
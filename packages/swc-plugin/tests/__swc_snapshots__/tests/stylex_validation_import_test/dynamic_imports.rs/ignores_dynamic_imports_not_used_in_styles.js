@@ -0,0 +1,11 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+const { Button } = await import('./Button');
+_inject2(".x1e2nbdu{color:red}", 3000);
+export const styles = {
+    red: {
+        color: "x1e2nbdu",
+        $$css: true
+    }
+};
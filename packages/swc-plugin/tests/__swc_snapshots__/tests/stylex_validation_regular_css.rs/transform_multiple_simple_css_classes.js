@@ -1,8 +1,8 @@
 import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import s from "@stylexjs/stylex";
-_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".x7z7khe{padding:10px}", 1000);
 _inject2(".x1118g2m{border-color:blue}", 2000);
 _inject2(".x15hxx75{border-color:pink}", 2000);
-_inject2(".x7z7khe{padding:10px}", 1000);
+_inject2(".x1e2nbdu{color:red}", 3000);
 _inject2(".x16ydxro{margin-left:10px}", 4000);
@@ -43,6 +43,48 @@ fn function_with_a_two_params() {
   )
 }
 
+#[test]
+fn function_with_a_block_body() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const double = x => {
+              return x * 2;
+            };
+        "#,
+    r#"
+            4;
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn function_with_declarations_in_a_block_body() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const sum = (a, b) => {
+              const total = a + b;
+              const doubled = total * 2;
+              return doubled;
+            };
+        "#,
+    r#"
+            18;
+        "#,
+    false,
+  )
+}
+
 #[test]
 fn array_map() {
   test_transform(
@@ -143,6 +185,86 @@ fn array_methods() {
   )
 }
 
+#[test]
+fn array_constructors() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const a = Array.from({ length: 4 }, (_, i) => i * 4);
+            const b = Array.from([1, 2, 3], x => x * 2);
+            const c = Array.from('ab');
+            const d = Array.of(1, 2, 3);
+        "#,
+    r#"
+            [0, 4, 8, 12];
+            [2, 4, 6];
+            ["a", "b"];
+            [1, 2, 3];
+        "#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Failed to evaluate expression")]
+fn array_like_objects_longer_than_the_limit() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const a = Array.from({ length: 4294967295 });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Array.from() requires an array, a string or an array-like object such as { length: 12 }."
+)]
+fn array_from_without_a_source() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const a = Array.from();
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+fn array_spread() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const a = [0, ...[1, 2], ...Array.of(3, 4)];
+            const b = [...'ab', 'c'];
+        "#,
+    r#"
+            [0, 1, 2, 3, 4];
+            ["a", "b", 'c'];
+        "#,
+    false,
+  )
+}
+
 #[test]
 fn object_methods() {
   test_transform(
@@ -198,6 +320,63 @@ fn object_entries() {
   )
 }
 
+#[test]
+fn object_from_entries_with_evaluated_values() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = Object.fromEntries([["sm", {width: 4}], ["md", {width: 2 * 4}]]);
+            const x = Object.fromEntries(["sm", "md"].map((size) => [size, {width: size}]));
+        "#,
+    r#"
+            ({
+                sm: {
+                    width: 4,
+                },
+                md: {
+                    width: 8,
+                },
+            });
+
+            ({
+                sm: {
+                    width: "sm",
+                },
+                md: {
+                    width: "md",
+                },
+            });
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn object_from_entries_with_keys_that_are_not_identifiers() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = Object.fromEntries([[16, 1], ["is-active", 2], ["24", 3]]);
+        "#,
+    r#"
+            ({
+                16: 1,
+                "is-active": 2,
+                "24": 3,
+            });
+        "#,
+    false,
+  )
+}
+
 #[test]
 fn methods_called_by_string_should_be_bind() {
   test_transform(
@@ -5,6 +5,7 @@ use stylex_swc_plugin::shared::{
     functions::{FunctionConfig, FunctionConfigType, FunctionMap, FunctionType},
     named_import_source::ImportSources,
     state_manager::StateManager,
+    stylex_options::StyleXOptions,
   },
   utils::ast::convertors::{ident_to_expression, string_to_expression},
 };
@@ -76,6 +77,271 @@ fn evaluates_primitive_value_expressions() {
   )
 }
 
+#[test]
+fn evaluates_arithmetic_on_quantities_with_units() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            '8px' * 2;
+            2 * '1.5rem';
+            '1rem' / 3;
+            '50%' - '12.5%';
+            '8px' + '2px';
+            '10px' / '4px';
+            '8' * 2;
+        "#,
+    r#"
+            "16px";
+            "3rem";
+            "0.3333rem";
+            "37.5%";
+            "10px";
+            2.5;
+            16;
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_equality_of_strings_null_and_undefined() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            'production' === 'production';
+            'production' !== 'production';
+            'production' == 'development';
+            'production' != 'development';
+            undefined === 'production';
+            undefined == null;
+            undefined === null;
+            'production' === 1;
+            '1' === 1;
+        "#,
+    r#"
+            1;
+            0;
+            0;
+            1;
+            0;
+            1;
+            0;
+            0;
+            1;
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_conditional_expressions() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            true ? 16 : 12;
+            0 ? '4px' : '8px';
+            '' ? 'red' : 'blue';
+            null ? 1 : 2;
+            [] ? 1 : 2;
+            1 + 1 === 2 ? 'yes' : 'no';
+            false ? 1 : true ? 2 : 3;
+        "#,
+    r#"
+            16;
+            '8px';
+            'blue';
+            2;
+            1;
+            'yes';
+            2;
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_unary_expressions() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            !true;
+            !0;
+            !'';
+            !'a';
+            ![];
+            -'4';
+            +'8';
+            +true;
+            -(2 * 3);
+            ~5;
+            ~-1;
+            typeof 'a';
+            typeof 1;
+            typeof true;
+            typeof undefined;
+            typeof null;
+            typeof [];
+            typeof (() => 1);
+            void 0;
+        "#,
+    r#"
+            false;
+            true;
+            true;
+            false;
+            false;
+            -4;
+            8;
+            1;
+            -6;
+            -6;
+            0;
+            "string";
+            "number";
+            "boolean";
+            "undefined";
+            "object";
+            "object";
+            "function";
+            undefined;
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_expressions_wrapped_in_type_assertions() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            (4 as const) * 2;
+            ('red' satisfies string);
+            (16 as unknown as number)!;
+        "#,
+    r#"
+            8;
+            'red';
+            16;
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_string_raw_tagged_templates() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            String.raw`${4 * 2}px`;
+            String.raw`a\nb`;
+        "#,
+    r#"
+            "8px";
+            "a\\nb";
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_process_env_members_from_options() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor {
+      state: StateManager::new(StyleXOptions {
+        env: Some(HashMap::from([
+          ("NODE_ENV".to_string(), "production".to_string()),
+          ("BRAND_COLOR".to_string(), "tomato".to_string()),
+        ])),
+        ..StyleXOptions::default()
+      }),
+      ..EvaluationModuleTransformVisitor::default()
+    },
+    r#"
+            process.env.BRAND_COLOR;
+            process.env['BRAND_COLOR'];
+            process.env.NODE_ENV === 'production';
+            process.env.NODE_ENV !== 'production';
+            process.env.DEBUG;
+            process.env.DEBUG === undefined;
+        "#,
+    r#"
+            "tomato";
+            "tomato";
+            1;
+            0;
+            undefined;
+            1;
+        "#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"Arithmetic on values with units needs compatible units, e.g. '8px' * 2 or '8px' + '2px', but got: "8px" + "2rem""#
+)]
+fn disallows_arithmetic_on_quantities_with_mixed_units() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            '8px' + '2rem';
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"Arithmetic on values with units needs compatible units, e.g. '8px' * 2 or '8px' + '2px', but got: "8px" * "2px""#
+)]
+fn disallows_multiplying_quantities_with_units() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            '8px' * '2px';
+        "#,
+    r#""#,
+    false,
+  )
+}
+
 #[test]
 fn evaluates_simple_arrays_and_objects() {
   test_transform(
@@ -102,6 +368,28 @@ fn evaluates_simple_arrays_and_objects() {
   )
 }
 
+#[test]
+fn evaluates_literal_only_objects() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = {color: "red", "fontSize": 12, 1: null};
+            const x = {default: {color: "red", color: "blue"}, hover: {opacity: 0.5, cursor: true}};
+            const x = {default: {color: "red"}, other: {width: 10 + 2}};
+        "#,
+    r#"
+            ({color: "red", fontSize: 12, 1: null});
+            ({default: {color: "blue"}, hover: {opacity: 0.5, cursor: true}});
+            ({default: {color: "red"}, other: {width: 12}});
+        "#,
+    false,
+  )
+}
+
 #[test]
 fn evaluates_objects_with_spreads() {
   test_transform(
@@ -0,0 +1,75 @@
+use std::env;
+
+use stylex_swc_plugin::shared::structures::stylex_options::{StyleXOptions, StyleXOptionsParams};
+use stylex_swc_plugin::shared::utils::common::create_hash;
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::common::FileName;
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+use crate::utils::transform::stringify_js;
+
+fn tranform(input: &str, filename: String) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      let mut config = StyleXOptionsParams {
+        class_name_prefix: Some("__hashed_var__".to_string()),
+        runtime_injection: Some(true),
+        treeshake_compensation: Some(true),
+        unstable_module_resolution: Some(StyleXOptions::get_common_js_module_resolution(Some(
+          env::current_dir().unwrap().display().to_string(),
+        ))),
+        resolve_external_packages: Some(false),
+        ..Default::default()
+      };
+
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass {
+          filename: FileName::Real(filename.into()),
+          ..Default::default()
+        },
+        Some(&mut config),
+      )
+    },
+  )
+}
+
+#[test]
+fn package_specifiers_are_hashed_without_resolution() {
+  let input = r#"import stylex from 'stylex';
+    import { colors } from '@acme/tokens/colors.stylex';
+    const styles = stylex.create({
+        red: {
+            color: colors.primary,
+        }
+    });
+    stylex(styles.red);"#;
+
+  let transformation = tranform(
+    input,
+    format!("{}/src/test.skip.js", env::current_dir().unwrap().display()),
+  );
+
+  let expected_var_name = format!(
+    "var(--__hashed_var__{})",
+    create_hash("@acme/tokens/colors.stylex//colors.primary")
+  );
+
+  assert!(transformation.contains(&expected_var_name));
+}
+
+#[test]
+#[should_panic(expected = "Path is outside of the root directory and workspace packages")]
+fn theme_files_outside_of_root_are_not_resolved() {
+  let input = r#"import stylex from 'stylex';
+    export const colors = stylex.defineVars({
+        primary: 'red',
+    });"#;
+
+  tranform(input, "/elsewhere/tokens/colors.stylex.js".to_string());
+}
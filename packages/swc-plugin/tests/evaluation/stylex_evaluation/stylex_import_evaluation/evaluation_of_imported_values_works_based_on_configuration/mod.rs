@@ -1 +1,2 @@
 mod theme_name_hashing_based_on_filename_alone_works;
+mod external_package_resolution_disabled;
@@ -21,6 +21,10 @@ static OPTIONS: Options = Options {
 };
 
 fn tranform(input: &str) -> String {
+  tranform_with(input, |_| {})
+}
+
+fn tranform_with(input: &str, configure: impl Fn(&mut StyleXOptionsParams)) -> String {
   stringify_js(
     input,
     Syntax::Typescript(TsSyntax {
@@ -36,6 +40,8 @@ fn tranform(input: &str) -> String {
         ..Default::default()
       };
 
+      configure(&mut config);
+
       ModuleTransformVisitor::new_test_styles(
         tr.comments.clone(),
         &PluginPass {
@@ -227,3 +233,62 @@ fn imported_vars_with_stylex_suffix_can_be_used_as_style_keys_dynamically() {
 
   assert_snapshot!(transformation);
 }
+
+#[test]
+fn arithmetic_on_imported_vars_produces_calc() {
+  let input = r#"import stylex from 'stylex';
+    import { spacing, sizes } from 'otherFile.stylex';
+    export const styles = stylex.create({
+        root: {
+            paddingTop: spacing.md + 4,
+            marginTop: spacing.md * 2,
+            width: (spacing.md + '1rem') / 2,
+            lineHeight: sizes.lineHeight * 1.5,
+            height: 100 - sizes.header,
+        }
+    });
+    stylex.props(styles.root);"#;
+
+  let transformation = tranform(input);
+
+  let spacing_md = format!(
+    "var(--{}{})",
+    OPTIONS.class_name_prefix,
+    create_hash("otherFile.stylex.js//spacing.md")
+  );
+
+  assert!(transformation.contains(&format!("calc({} + 4px)", spacing_md)));
+  assert!(transformation.contains(&format!("calc({} * 2)", spacing_md)));
+  assert!(transformation.contains(&format!("calc(({} + 1rem) / 2)", spacing_md)));
+
+  assert_snapshot!(transformation);
+}
+
+#[test]
+fn preserves_side_effect_imports_of_compensated_files() {
+  let input = r#"import stylex from 'stylex';
+    import { spacing } from 'otherFile.stylex';
+    import { colors } from 'colors.stylex';
+    export const styles = stylex.create({
+        root: {
+            paddingTop: spacing.md,
+            marginTop: spacing.lg,
+            color: colors.primary,
+        }
+    });
+    stylex.props(styles.root);"#;
+
+  let transformation = tranform_with(input, |config| {
+    config.preserve_side_effect_imports = Some(true);
+  });
+
+  assert!(!transformation.contains(r#"import "otherFile.stylex""#));
+  assert_eq!(
+    transformation
+      .matches(r#"import * as _sideEffect from "otherFile.stylex";"#)
+      .count(),
+    1
+  );
+
+  assert_snapshot!(transformation);
+}
@@ -37,21 +37,21 @@ export default function ButtonsDemo(_props) {
     </div>;
 }
 _inject2(".x8j0i83{--x1tvn83n:red;--xlb9c25:4px;--xk30bbq:4px;--xkhjxis:8px;--xte9ugm:white;}", 0.5);
-const redTheme = {
-    Page__redTheme: "Page__redTheme",
-    $$css: true,
-    "var(--x1p0kudt)": "x8j0i83"
-};
-_inject2(".x78zum5{display:flex}", 3000);
-_inject2(".xdt5ytf{flex-direction:column}", 3000);
-_inject2(".x6s0dn4{align-items:center}", 3000);
-_inject2(".xl56j7k{justify-content:center}", 3000);
 _inject2(".xou54vl{gap:16px}", 2000);
-_inject2(".xzk7aed{padding-bottom:64px}", 4000);
 _inject2(".xdh2fpr{border-width:2px}", 2000);
 _inject2(".x1y0btm7{border-style:solid}", 2000);
 _inject2(".x71xlcl{border-color:red}", 2000);
 _inject2(".x1bg2uv5{border-color:green}", 2000);
+_inject2(".x78zum5{display:flex}", 3000);
+_inject2(".xdt5ytf{flex-direction:column}", 3000);
+_inject2(".x6s0dn4{align-items:center}", 3000);
+_inject2(".xl56j7k{justify-content:center}", 3000);
+_inject2(".xzk7aed{padding-bottom:64px}", 4000);
+const redTheme = {
+    Page__redTheme: "Page__redTheme",
+    $$css: true,
+    "var(--x1p0kudt)": "x8j0i83"
+};
 const styles = {
     bordered: {
         "Page__styles.bordered": "Page__styles.bordered",
@@ -71,6 +71,9 @@ const INTERCEPT = {
     h1: Math.round(100 * (MIN_FONT.h1 - SLOPE.h1 * (MIN_WIDTH / 16))) / 100
 };
 _inject2(":root{--x1ql1w94:clamp(0.58rem, calc(0.6rem + -0.09vw), 0.53rem);--x1ogzt1a:clamp(0.69rem, calc(0.69rem + 0.02vw), 0.7rem);--x16zehmx:clamp(0.83rem, calc(0.79rem + 0.19vw), 0.94rem);--xhk4hdt:clamp(1rem, calc(0.91rem + 0.43vw), 1.25rem);--xwuz3e6:clamp(1.2rem, calc(1.04rem + 0.82vw), 1.67rem);--xcuma3z:clamp(1.44rem, calc(1.17rem + 1.36vw), 2.22rem);--x1d2707x:clamp(1.73rem, calc(1.3rem + 2.14vw), 2.96rem);--xvxqfsp:clamp(2.07rem, calc(1.42rem + 3.27vw), 3.95rem);--x1cypdqd:clamp(2.49rem, calc(1.53rem + 4.82vw), 5.26rem);}", 0);
+_inject2(":root{--xe27369:clamp(4px, calc(3.75px - 0.11vw), 5px);--xbjetdn:clamp(8px, calc(7.25px - 0.22vw), 10px);--x1ixl80x:clamp(12px, calc(11px - 0.33vw), 15px);--x1kvcwuq:clamp(16px, calc(14.5px - 0.43vw), 20px);--xmdt6tw:clamp(24px, calc(22px - 0.65vw), 30px);--x1wksnfy:clamp(32px, calc(29.25px - 0.87vw), 40px);--xoxmq3b:clamp(48px, calc(43.75px - 1.3vw), 60px);--xdo4ik8:clamp(64px, calc(58.5px - 1.74vw), 80px);--x2u3u4d:clamp(96px, calc(87.75px - 2.61vw), 120px);--xmk1p5w:clamp(128px, calc(116.75px - 3.48vw), 160px);}", 0);
+_inject2(':root{--xdkvadk:1240px;--x1v9y61d:ui-monospace, Menlo, Monaco, "Cascadia Mono", "Segoe UI Mono", "Roboto Mono", "Oxygen Mono", "Ubuntu Monospace", "Source Code Pro", "Fira Mono", "Droid Sans Mono", "Courier New", monospace;--xu8xumw:-apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, "Helvetica Neue", Arial, "Noto Sans", sans-serif, "Apple Color Emoji", "Segoe UI Emoji", "Segoe UI Symbol", "Noto Color Emoji";--x9q2m40:0;--xpzz690:0;--x16lcx6o:0;--xjk46kt:rgb(214, 219, 220);--x19cfreg:255;--x5f91dp:255;--xtrkg9t:255;--xrj4b28:rgb(238, 240, 241);--x13ytpr0:rgba(238, 240, 241, 0.5);--xjray:172;--x1ats3qd:175;--x12b45e3:176;--x1efhglm:180;--x1w81gmp:185;--x13v9q97:188;--x14edl43:131;--xdi7wre:134;--x1livm2j:135;--x1r7qzpr:conic-gradient(from 180deg at 50% 50%, #16abff33 0deg, #0885ff33 55deg, #54d6ff33 120deg, #0071ff33 160deg, transparent 360deg);--x1xmdc3p:radial-gradient(rgba(255, 255, 255, 1), rgba(255, 255, 255, 0));}', 0);
+_inject2("@media (prefers-color-scheme: dark){:root{--x9q2m40:255;--xpzz690:255;--x16lcx6o:255;--xjk46kt:rgb(0, 0, 0);--x19cfreg:0;--x5f91dp:0;--xtrkg9t:0;--xrj4b28:rgb(20, 20, 20);--x13ytpr0:rgba(20, 20, 20, 0.5);--xjray:108;--x1ats3qd:108;--x12b45e3:108;--x1efhglm:100;--x1w81gmp:100;--x13v9q97:100;--x14edl43:200;--xdi7wre:200;--x1livm2j:200;--x1r7qzpr:radial-gradient(rgba(1, 65, 255, 0.4), rgba(1, 65, 255, 0));--x1xmdc3p:linear-gradient(to bottom right, rgba(1, 65, 255, 0), rgba(1, 65, 255, 0), rgba(1, 65, 255, 0.3));}}", 0.1);
 export const text = {
     xxs: "var(--x1ql1w94)",
     xs: "var(--x1ogzt1a)",
@@ -170,7 +173,6 @@ const INTERCEPT_SPACE = {
     xxxl: Math.round(4 * (MIN_SPACE.xxxl - SLOPE_SPACE.xxxl * MIN_WIDTH)) / 4,
     xxxxl: Math.round(4 * (MIN_SPACE.xxxxl - SLOPE_SPACE.xxxxl * MIN_WIDTH)) / 4
 };
-_inject2(":root{--xe27369:clamp(4px, calc(3.75px - 0.11vw), 5px);--xbjetdn:clamp(8px, calc(7.25px - 0.22vw), 10px);--x1ixl80x:clamp(12px, calc(11px - 0.33vw), 15px);--x1kvcwuq:clamp(16px, calc(14.5px - 0.43vw), 20px);--xmdt6tw:clamp(24px, calc(22px - 0.65vw), 30px);--x1wksnfy:clamp(32px, calc(29.25px - 0.87vw), 40px);--xoxmq3b:clamp(48px, calc(43.75px - 1.3vw), 60px);--xdo4ik8:clamp(64px, calc(58.5px - 1.74vw), 80px);--x2u3u4d:clamp(96px, calc(87.75px - 2.61vw), 120px);--xmk1p5w:clamp(128px, calc(116.75px - 3.48vw), 160px);}", 0);
 export const spacing = {
     xxxs: "var(--xe27369)",
     xxs: "var(--xbjetdn)",
@@ -187,8 +189,6 @@ export const spacing = {
 /**
  * Color Tokens
  */ const DARK_MODE = "@media (prefers-color-scheme: dark)";
-_inject2(':root{--xdkvadk:1240px;--x1v9y61d:ui-monospace, Menlo, Monaco, "Cascadia Mono", "Segoe UI Mono", "Roboto Mono", "Oxygen Mono", "Ubuntu Monospace", "Source Code Pro", "Fira Mono", "Droid Sans Mono", "Courier New", monospace;--xu8xumw:-apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, "Helvetica Neue", Arial, "Noto Sans", sans-serif, "Apple Color Emoji", "Segoe UI Emoji", "Segoe UI Symbol", "Noto Color Emoji";--x9q2m40:0;--xpzz690:0;--x16lcx6o:0;--xjk46kt:rgb(214, 219, 220);--x19cfreg:255;--x5f91dp:255;--xtrkg9t:255;--xrj4b28:rgb(238, 240, 241);--x13ytpr0:rgba(238, 240, 241, 0.5);--xjray:172;--x1ats3qd:175;--x12b45e3:176;--x1efhglm:180;--x1w81gmp:185;--x13v9q97:188;--x14edl43:131;--xdi7wre:134;--x1livm2j:135;--x1r7qzpr:conic-gradient(from 180deg at 50% 50%, #16abff33 0deg, #0885ff33 55deg, #54d6ff33 120deg, #0071ff33 160deg, transparent 360deg);--x1xmdc3p:radial-gradient(rgba(255, 255, 255, 1), rgba(255, 255, 255, 0));}', 0);
-_inject2("@media (prefers-color-scheme: dark){:root{--x9q2m40:255;--xpzz690:255;--x16lcx6o:255;--xjk46kt:rgb(0, 0, 0);--x19cfreg:0;--x5f91dp:0;--xtrkg9t:0;--xrj4b28:rgb(20, 20, 20);--x13ytpr0:rgba(20, 20, 20, 0.5);--xjray:108;--x1ats3qd:108;--x12b45e3:108;--x1efhglm:100;--x1w81gmp:100;--x13v9q97:100;--x14edl43:200;--xdi7wre:200;--x1livm2j:200;--x1r7qzpr:radial-gradient(rgba(1, 65, 255, 0.4), rgba(1, 65, 255, 0));--x1xmdc3p:linear-gradient(to bottom right, rgba(1, 65, 255, 0), rgba(1, 65, 255, 0), rgba(1, 65, 255, 0.3));}}", 0.1);
 export const globalTokens = {
     maxWidth: "var(--xdkvadk)",
     fontMono: "var(--x1v9y61d)",
@@ -0,0 +1,40 @@
+import stylex from "@stylexjs/stylex";
+
+const styles = stylex.create({
+  linear: {
+    backgroundImage: "linear-gradient(to right, rgba(0, 0, 0, 0.5) 0%, red 100%)",
+  },
+  radial: {
+    backgroundImage: "radial-gradient(circle at 50% 50%, #fff 0%, #000 100%)",
+  },
+  conic: {
+    backgroundImage: "conic-gradient(from 0deg at 50% 50%, red, blue)",
+  },
+  layered: {
+    backgroundImage: "linear-gradient(red, blue), url(image.png)",
+  },
+  svg: {
+    backgroundImage:
+      "url(\"data:image/svg+xml,<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 8 8'><path d='M0 0L8 8'/></svg>\")",
+  },
+  encodedSvg: {
+    maskImage:
+      "url(data:image/svg+xml;charset=utf-8,%3Csvg%20xmlns%3D%22http%3A%2F%2Fwww.w3.org%2F2000%2Fsvg%22%2F%3E)",
+  },
+  base64: {
+    backgroundImage: "url('data:image/png;base64,iVBORw0KGgo/AAA*B=')",
+  },
+  fragment: {
+    clipPath: "url(#clip)",
+  },
+  imageSet: {
+    backgroundImage: "image-set(\"image.png\" 1x, \"image-2x.png\" 2x)",
+  },
+  content: {
+    content: "\"it's 10px / 0ms\"",
+  },
+});
+
+export default function Gradients() {
+  return <div {...stylex.props(styles.linear, styles.svg, styles.content)} />;
+}
@@ -0,0 +1,18 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from "@stylexjs/stylex";
+_inject2(".xbkbwgn{background-image:linear-gradient(to right,rgba(0,0,0,.5) 0%,red 100%)}", 3000);
+_inject2(".xohtlzd{background-image:radial-gradient(circle at 50% 50%,#fff 0%,#000 100%)}", 3000);
+_inject2(".x1orlm31{background-image:conic-gradient(from 0deg at 50% 50%,red,blue)}", 3000);
+_inject2(".xk9823n{background-image:linear-gradient(red,blue),url(image.png)}", 3000);
+_inject2(".x27z4aa{background-image:url(\"data:image/svg+xml,<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 8 8'><path d='M0 0L8 8'/></svg>\")}", 3000);
+_inject2(".x1r1jzss{mask-image:url(data:image/svg+xml;charset=utf-8,%3Csvg%20xmlns%3D%22http%3A%2F%2Fwww.w3.org%2F2000%2Fsvg%22%2F%3E)}", 3000);
+_inject2('.x4jkd8d{background-image:url("data:image/png;base64,iVBORw0KGgo/AAA*B=")}', 3000);
+_inject2(".x13nvrl6{clip-path:url(#clip)}", 3000);
+_inject2('.x18jz4o0{background-image:image-set("image.png" 1x,"image-2x.png" 2x)}', 3000);
+_inject2('.x1d1xc26{content:"it\'s 10px / 0ms"}', 3000);
+export default function Gradients() {
+    return <div {...{
+        className: "Page__styles.linear Page__styles.svg x27z4aa Page__styles.content x1d1xc26"
+    }}/>;
+}
@@ -13,7 +13,6 @@ use swc_core::{
     parser::{Syntax, TsSyntax},
     transforms::{base::resolver, testing::test_fixture},
   },
-  plugin::proxies::PluginCommentsProxy,
 };
 
 #[testing::fixture("tests/fixture/**/input.js")]
@@ -23,25 +22,24 @@ fn fixture(input: PathBuf) {
   test_fixture(
     Syntax::Typescript(TsSyntax {
       tsx: true,
+      decorators: true,
       ..Default::default()
     }),
-    &|_| {
+    &|tester| {
       let unresolved_mark = Mark::new();
       let top_level_mark = Mark::new();
 
       let mut config = StyleXOptionsParams {
         dev: Some(true),
         treeshake_compensation: Some(true),
-        unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(
-          None,
-        )),
+        unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
         ..StyleXOptionsParams::default()
       };
 
       chain!(
         resolver(unresolved_mark, top_level_mark, false),
         ModuleTransformVisitor::new_test_styles(
-          PluginCommentsProxy,
+          tester.comments.clone(),
           &PluginPass {
             cwd: None,
             filename: FileName::Real("/app/pages/Page.stylex.tsx".into()),
@@ -1,5 +1,8 @@
 mod evaluation;
+mod stylex_crash_report;
 mod stylex_metadata_test;
+#[cfg(feature = "testing")]
+mod stylex_testing_test;
 mod stylex_transform_call_test;
 mod stylex_transform_create_test;
 mod stylex_transform_define_vars_test;
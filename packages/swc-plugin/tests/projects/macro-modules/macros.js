@@ -0,0 +1,13 @@
+export const rem = (px) => `${px / 16}rem`;
+
+export function space(steps) {
+  return `${steps * 4}px ${steps * 8}px`;
+}
+
+export const clampRem = (min, max) => {
+  return `clamp(${min / 16}rem, 2vw, ${max / 16}rem)`;
+};
+
+export default (color) => `1px solid ${color}`;
+
+export const total = (...values) => values.length;
@@ -0,0 +1 @@
+export const scale = (steps) => steps * 4;
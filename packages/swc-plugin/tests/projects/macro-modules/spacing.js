@@ -0,0 +1,5 @@
+import { scale } from './scale';
+
+const toPx = (value) => `${value}px`;
+
+export const gap = (steps) => toPx(scale(steps));
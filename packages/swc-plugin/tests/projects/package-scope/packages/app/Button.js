@@ -0,0 +1,9 @@
+import stylex from 'stylex';
+import { colors } from '../tokens/tokens.stylex.js';
+
+export const styles = stylex.create({
+  root: {
+    color: colors.accent,
+    padding: 4,
+  },
+});
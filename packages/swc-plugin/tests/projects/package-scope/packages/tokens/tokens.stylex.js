@@ -0,0 +1,5 @@
+import stylex from 'stylex';
+
+export const colors = stylex.defineVars({
+  accent: 'blue',
+});
@@ -0,0 +1,6 @@
+export const typography = {
+  body: { fontSize: 16, lineHeight: 1.5 },
+  heading: { fontSize: 32, fontWeight: 700 },
+};
+
+export default { inset: { top: 0, left: 0 } };
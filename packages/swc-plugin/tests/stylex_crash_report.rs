@@ -0,0 +1,91 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::{
+  common::{comments::SingleThreadedComments, sync::Lrc, FileName, SourceMap},
+  ecma::{
+    ast::{EsVersion, Program},
+    parser::{lexer::Lexer, Parser, StringInput, Syntax},
+  },
+};
+
+fn fold_program(source: &str) -> Program {
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Anon, source.into());
+
+  let lexer = Lexer::new(
+    Syntax::default(),
+    EsVersion::EsNext,
+    StringInput::from(&*fm),
+    None,
+  );
+
+  let program = Parser::new_from(lexer)
+    .parse_program()
+    .expect("Failed to parse program");
+
+  ModuleTransformVisitor::new_test_styles(
+    SingleThreadedComments::default(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/app/src/Component.js".into()),
+    },
+    None,
+  )
+  .fold_program(program)
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn keeps_the_message_of_a_crash() {
+  fold_program(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        default: {
+          color: getColor(),
+        },
+      });
+    "#,
+  );
+}
+
+#[test]
+fn transforms_after_a_crash_on_the_same_thread() {
+  let crashed = std::panic::catch_unwind(|| {
+    fold_program(
+      r#"
+        import stylex from 'stylex';
+        const styles = stylex.create(null);
+      "#,
+    )
+  });
+
+  assert!(crashed.is_err());
+
+  assert!(fold_program(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        default: {
+          color: 'red',
+        },
+      });
+    "#
+  )
+  .is_module());
+}
+
+#[test]
+fn returns_the_transformed_program() {
+  let program = fold_program(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        default: {
+          color: 'red',
+        },
+      });
+    "#,
+  );
+
+  assert!(program.is_module());
+}
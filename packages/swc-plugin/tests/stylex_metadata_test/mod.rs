@@ -1 +1,2 @@
 mod stylex_metadata_common_test;
+mod stylex_metadata_accessors_test;
@@ -0,0 +1,435 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::{comments::SingleThreadedComments, sync::Lrc, FileName, SourceMap},
+  ecma::{
+    ast::{EsVersion, Program},
+    parser::{lexer::Lexer, Parser, StringInput, Syntax},
+  },
+};
+
+fn parse(source: &str) -> Program {
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Anon, source.into());
+
+  let lexer = Lexer::new(
+    Syntax::default(),
+    EsVersion::EsNext,
+    StringInput::from(&*fm),
+    None,
+  );
+
+  Parser::new_from(lexer)
+    .parse_program()
+    .expect("Failed to parse program")
+}
+
+fn transform(
+  source: &str,
+  config: Option<&mut StyleXOptionsParams>,
+) -> ModuleTransformVisitor<SingleThreadedComments> {
+  let mut visitor = ModuleTransformVisitor::new_test(
+    SingleThreadedComments::default(),
+    &PluginPass::default(),
+    config,
+  );
+
+  visitor.fold_program(parse(source));
+
+  visitor
+}
+
+const SOURCE: &str = r#"
+  import stylex from 'stylex';
+  export const styles = stylex.create({
+    default: {
+      color: 'red',
+      height: 5,
+    },
+  });
+"#;
+
+#[test]
+fn exposes_compiled_rules_after_transform() {
+  let visitor = transform(SOURCE, None);
+
+  let rules = visitor
+    .state()
+    .injected_rules()
+    .into_iter()
+    .map(|rule| (rule.get_class_name(), rule.get_css(), *rule.get_priority()))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    rules,
+    vec![
+      ("x1e2nbdu", ".x1e2nbdu{color:red}", 3000.0),
+      ("x1ycjhwn", ".x1ycjhwn{height:5px}", 4000.0),
+    ]
+  );
+  assert_eq!(
+    visitor.state().metadata().keys().collect::<Vec<_>>(),
+    vec!["styles"]
+  );
+}
+
+#[test]
+fn exposes_inject_statements_with_runtime_injection() {
+  let visitor = transform(
+    SOURCE,
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  );
+
+  assert_eq!(visitor.state().injected_rules().len(), 2);
+  assert_eq!(visitor.state().inject_statements().len(), 2);
+  assert!(visitor.state().prepend_import_module_items().is_empty());
+}
+
+#[test]
+fn transforms_with_options_from_the_builder() {
+  let options = StyleXOptions::builder()
+    .class_name_prefix("app")
+    .build()
+    .expect("Invalid options");
+
+  let mut visitor = ModuleTransformVisitor::new_with_options(
+    SingleThreadedComments::default(),
+    PluginPass::default(),
+    options,
+  );
+
+  visitor.fold_program(parse(SOURCE));
+
+  let class_names = visitor
+    .state()
+    .injected_rules()
+    .into_iter()
+    .map(|rule| rule.get_class_name())
+    .collect::<Vec<_>>();
+
+  assert_eq!(class_names, vec!["app1e2nbdu", "app1ycjhwn"]);
+}
+
+#[test]
+fn exposes_exported_styles_when_enabled() {
+  let visitor = transform(
+    SOURCE,
+    Some(&mut StyleXOptionsParams {
+      export_styles: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  );
+
+  assert_eq!(
+    serde_json::to_string(visitor.state().exported_styles()).unwrap(),
+    r#"{"styles":{"default":[{"property":"color","value":"red"},{"property":"height","value":"5"}]}}"#
+  );
+}
+
+#[test]
+fn exposes_exported_styles_of_default_exports() {
+  let visitor = transform(
+    r#"
+      import stylex from 'stylex';
+      export default stylex.create({
+        root: {
+          color: 'red',
+        },
+      });
+    "#,
+    Some(&mut StyleXOptionsParams {
+      export_styles: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  );
+
+  assert_eq!(
+    serde_json::to_string(visitor.state().exported_styles()).unwrap(),
+    r#"{"default":{"root":[{"property":"color","value":"red"}]}}"#
+  );
+  assert_eq!(
+    visitor.state().class_name_namespaces().get("x1e2nbdu"),
+    Some(&vec!["default.root".to_string()])
+  );
+}
+
+#[test]
+fn adds_the_namespaces_of_class_names_to_the_metadata_output() {
+  let comments = SingleThreadedComments::default();
+
+  let mut visitor = ModuleTransformVisitor::new_test(
+    comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      export_styles: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  );
+
+  visitor.fold_program(parse(SOURCE));
+
+  let (leading, _) = comments.borrow_all();
+
+  assert!(leading.values().flatten().any(|comment| comment.text.contains(
+    r#"__stylex_class_names_start__{"x1e2nbdu":["styles.default"],"x1ycjhwn":["styles.default"]}__stylex_class_names_end__"#
+  )));
+}
+
+#[test]
+fn does_not_export_styles_by_default() {
+  let visitor = transform(SOURCE, None);
+
+  assert!(visitor.state().exported_styles().is_empty());
+}
+
+#[test]
+fn exposes_var_usage_graph() {
+  let visitor = transform(
+    r#"
+      import stylex from 'stylex';
+      import { colors, spacing } from 'tokens.stylex';
+      export const styles = stylex.create({
+        default: {
+          color: colors.primary,
+          backgroundColor: colors.background,
+          padding: spacing.small,
+        },
+        hover: {
+          color: colors.primary,
+        },
+      });
+    "#,
+    Some(&mut StyleXOptionsParams {
+      var_usage_graph: Some(true),
+      unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+      ..StyleXOptionsParams::default()
+    }),
+  );
+
+  assert_eq!(
+    serde_json::to_string(visitor.state().var_usage()).unwrap(),
+    r#"{"tokens.stylex":{"colors":["background","primary"],"spacing":["small"]}}"#
+  );
+}
+
+#[test]
+fn measures_phase_timings_when_enabled() {
+  let visitor = transform(SOURCE, None);
+
+  assert!(visitor.phase_timings().is_empty());
+
+  let visitor = transform(
+    SOURCE,
+    Some(&mut StyleXOptionsParams {
+      phase_timings: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  );
+
+  let phases = visitor
+    .phase_timings()
+    .iter()
+    .map(|(cycle, _)| format!("{:?}", cycle))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    phases,
+    vec![
+      "Initializing",
+      "TransformEnter",
+      "TransformExit",
+      "PreCleaning",
+      "Cleaning"
+    ]
+  );
+}
+
+#[test]
+fn evicts_least_recently_used_evaluations_past_the_seen_cache_capacity() {
+  let source = r#"
+    import stylex from 'stylex';
+    const red = 'red';
+    const size = 5;
+    export const styles = stylex.create({
+      default: {
+        color: red,
+        height: size,
+        width: size,
+      },
+    });
+  "#;
+
+  let injected_rules = |visitor: &ModuleTransformVisitor<SingleThreadedComments>| {
+    visitor
+      .state()
+      .injected_rules()
+      .into_iter()
+      .map(|rule| rule.get_css().to_string())
+      .collect::<Vec<_>>()
+  };
+
+  let unbounded = transform(source, None);
+
+  assert_eq!(unbounded.state().seen.evictions(), 0);
+
+  let bounded = transform(
+    source,
+    Some(&mut StyleXOptionsParams {
+      seen_cache_capacity: Some(1),
+      ..StyleXOptionsParams::default()
+    }),
+  );
+
+  assert_eq!(bounded.state().seen.capacity(), 1);
+  assert_eq!(bounded.state().seen.len(), 1);
+  assert!(bounded.state().seen.evictions() > 0);
+  assert_eq!(injected_rules(&bounded), injected_rules(&unbounded));
+}
+
+#[test]
+fn applies_pseudo_classes_in_the_configured_order() {
+  let source = r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      default: {
+        color: {
+          default: 'black',
+          ':hover': 'blue',
+          ':disabled': 'gray',
+        },
+      },
+    });
+  "#;
+
+  let priorities = |visitor: &ModuleTransformVisitor<SingleThreadedComments>| {
+    visitor
+      .state()
+      .injected_rules()
+      .into_iter()
+      .map(|rule| *rule.get_priority())
+      .collect::<Vec<_>>()
+  };
+
+  assert_eq!(
+    priorities(&transform(source, None)),
+    vec![3000.0, 3130.0, 3092.0]
+  );
+
+  let reordered = transform(
+    source,
+    Some(&mut StyleXOptionsParams {
+      pseudo_class_order: Some(vec![":hover".to_string(), ":disabled".to_string()]),
+      ..StyleXOptionsParams::default()
+    }),
+  );
+
+  assert_eq!(priorities(&reordered), vec![3000.0, 3130.0, 3140.0]);
+}
+
+const CALL_SITES_SOURCE: &str = r#"
+  import stylex from 'stylex';
+  const styles = stylex.create({
+    main: {
+      color: 'red',
+    },
+    active: {
+      color: 'blue',
+    },
+  });
+  export function Button({ isActive, variant }) {
+    return [
+      stylex.props(styles.main, isActive && styles.active),
+      stylex.props(styles[variant]),
+    ];
+  }
+"#;
+
+#[test]
+fn exposes_call_sites_when_enabled() {
+  let visitor = transform(
+    CALL_SITES_SOURCE,
+    Some(&mut StyleXOptionsParams {
+      call_sites: Some(true),
+      gen_conditional_classes: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  );
+
+  let call_sites = visitor
+    .state()
+    .call_sites()
+    .iter()
+    .map(|call_site| {
+      (
+        call_site.api.as_str(),
+        call_site.namespaces_defined.clone(),
+        call_site.namespaces_used.clone(),
+        call_site.is_static,
+      )
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    call_sites,
+    vec![
+      (
+        "create",
+        vec!["styles.main".to_string(), "styles.active".to_string()],
+        vec![],
+        true
+      ),
+      (
+        "props",
+        vec![],
+        vec!["styles.main".to_string(), "styles.active".to_string()],
+        true
+      ),
+      ("props", vec![], vec![], false),
+    ]
+  );
+}
+
+#[test]
+fn locates_call_sites() {
+  let visitor = transform(
+    CALL_SITES_SOURCE,
+    Some(&mut StyleXOptionsParams {
+      call_sites: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  );
+
+  let lengths = visitor
+    .state()
+    .call_sites()
+    .iter()
+    .map(|call_site| call_site.end - call_site.start)
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    lengths,
+    vec![
+      CALL_SITES_SOURCE[CALL_SITES_SOURCE.find("stylex.create").unwrap()..]
+        .find("});")
+        .unwrap() as u32
+        + 2,
+      "stylex.props(styles.main, isActive && styles.active)".len() as u32,
+      "stylex.props(styles[variant])".len() as u32,
+    ]
+  );
+}
+
+#[test]
+fn does_not_collect_call_sites_by_default() {
+  let visitor = transform(CALL_SITES_SOURCE, None);
+
+  assert!(visitor.state().call_sites().is_empty());
+}
@@ -1,4 +1,7 @@
-use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
 use swc_core::ecma::{
   parser::{Syntax, TsSyntax},
   transforms::testing::test,
@@ -38,3 +41,29 @@ test!(
         });
     "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    ModuleTransformVisitor::new_test(
+      tr.comments.clone(),
+      &PluginPass::default(),
+      Some(&mut StyleXOptionsParams {
+        metadata_version: Some(1),
+        ..StyleXOptionsParams::default()
+      }),
+    )
+  },
+  stylex_metadata_is_a_bare_array_of_rules_in_version_1,
+  r#"
+        import stylex from 'stylex';
+        export const styles = stylex.create({
+          foo: {
+              color: 'red',
+          },
+        });
+    "#
+);
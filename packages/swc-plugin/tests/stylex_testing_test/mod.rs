@@ -0,0 +1 @@
+mod transform_str_test;
@@ -0,0 +1,79 @@
+use stylex_swc_plugin::{
+  shared::structures::stylex_options::StyleXOptionsParams, testing::transform_str,
+};
+
+const SOURCE: &str = r#"
+  import * as stylex from '@stylexjs/stylex';
+  export const styles = stylex.create({
+    default: {
+      color: 'red',
+      height: 5,
+    },
+  });
+"#;
+
+#[test]
+fn transforms_source_into_code_and_rules() {
+  let output = transform_str(
+    SOURCE,
+    "/app/src/Component.js",
+    StyleXOptionsParams::default(),
+  );
+
+  assert!(!output.code.contains("stylex.create"));
+  assert!(output.code.contains("x1e2nbdu"));
+  assert_eq!(
+    output
+      .rules
+      .iter()
+      .map(|rule| (rule.get_class_name(), rule.get_css()))
+      .collect::<Vec<_>>(),
+    vec![
+      ("x1e2nbdu", ".x1e2nbdu{color:red}"),
+      ("x1ycjhwn", ".x1ycjhwn{height:5px}"),
+    ]
+  );
+}
+
+#[test]
+fn parses_typescript_by_extension() {
+  let output = transform_str(
+    "import * as stylex from '@stylexjs/stylex';\n\
+     export const styles = stylex.create({ default: { color: 'red' as const } });",
+    "/app/src/Component.tsx",
+    StyleXOptionsParams::default(),
+  );
+
+  assert_eq!(output.rules.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Failed to parse /app/src/Component.js")]
+fn panics_on_unparsable_source() {
+  transform_str(
+    "const = ;",
+    "/app/src/Component.js",
+    StyleXOptionsParams::default(),
+  );
+}
+
+#[test]
+fn accepts_byte_order_marks_shebangs_and_decorators() {
+  let source = format!(
+    "\u{feff}#!/usr/bin/env node\n{}\n@observer\nexport class Store {{}}\n",
+    SOURCE
+  );
+
+  for filename in ["/app/src/Component.js", "/app/src/Component.ts"] {
+    let output = transform_str(&source, filename, StyleXOptionsParams::default());
+
+    assert!(
+      output.code.starts_with("#!/usr/bin/env node\n"),
+      "{}",
+      filename
+    );
+    assert!(output.code.contains("@observer"), "{}", filename);
+    assert!(output.code.contains("x1e2nbdu"), "{}", filename);
+    assert_eq!(output.rules.len(), 2, "{}", filename);
+  }
+}
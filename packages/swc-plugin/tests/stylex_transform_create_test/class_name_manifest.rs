@@ -0,0 +1,91 @@
+use std::fs;
+
+use stylex_swc_plugin::{
+  shared::structures::{
+    class_name_manifest::ClassNameManifest, plugin_pass::PluginPass,
+    stylex_options::StyleXOptionsParams,
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+use crate::utils::transform::stringify_js;
+
+fn transform(input: &str, class_name_manifest: &str) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          class_name_manifest: Some(class_name_manifest.to_string()),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+  )
+}
+
+fn manifest_path(name: &str) -> String {
+  let path = std::env::temp_dir()
+    .join(format!(
+      "stylex-plugin-class-name-manifest-{}-{}.json",
+      name,
+      std::process::id()
+    ))
+    .to_str()
+    .unwrap()
+    .to_string();
+
+  let _ = fs::remove_file(&path);
+  ClassNameManifest::invalidate(&path);
+
+  path
+}
+
+const STYLES: &str = r#"
+  import stylex from 'stylex';
+  export const styles = stylex.create({
+    root: {
+      color: 'red',
+      padding: 4,
+    },
+    active: {
+      color: 'blue',
+    },
+  });
+"#;
+
+#[test]
+fn uses_short_class_names_from_manifest() {
+  let path = manifest_path("short");
+  fs::write(&path, "{}").unwrap();
+
+  let output = transform(STYLES, &path);
+
+  assert!(output.contains(r#"color: "xa""#), "{}", output);
+  assert!(output.contains(r#"padding: "xb""#), "{}", output);
+  assert!(output.contains(r#"color: "xc""#), "{}", output);
+  assert!(output.contains(".xa{color:red}"), "{}", output);
+
+  ClassNameManifest::invalidate(&path);
+
+  assert_eq!(transform(STYLES, &path), output);
+
+  fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn uses_hashes_without_manifest() {
+  let path = manifest_path("missing");
+
+  let output = transform(STYLES, &path);
+
+  assert!(output.contains(".x1e2nbdu{color:red}"), "{}", output);
+  assert!(!std::path::Path::new(&path).exists());
+}
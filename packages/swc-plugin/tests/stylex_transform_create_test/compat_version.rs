@@ -0,0 +1,50 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+const STYLES: &str = r#"
+  import stylex from 'stylex';
+  export const styles = stylex.create({
+    root: {
+      transitionDuration: '500ms',
+      color: {
+        default: 'red',
+        '@media (min-width: 800px)': {
+          ':hover': 'blue',
+        },
+      },
+    },
+  });
+"#;
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  follows_latest_release_by_default,
+  STYLES
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      compat_version: Some("0.2.0".to_string()),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  reproduces_output_of_older_release,
+  STYLES
+);
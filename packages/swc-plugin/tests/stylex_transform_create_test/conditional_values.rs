@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+use crate::utils::transform::stringify_js;
+
+fn transform(input: &str) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          env: Some(HashMap::from([(
+            "NODE_ENV".to_string(),
+            "production".to_string(),
+          )])),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+  )
+}
+
+#[test]
+fn evaluates_conditionals_of_static_constants() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      const isBig = true;
+      const isDense = 0;
+      export const styles = stylex.create({
+        root: {
+          fontSize: isBig ? 16 : 12,
+          padding: isDense ? 4 : 8,
+          color: isBig ? isDense ? 'red' : 'blue' : 'green',
+        },
+      });
+    "#,
+  );
+
+  assert!(transformation.contains("font-size:16px"));
+  assert!(transformation.contains("padding:8px"));
+  assert!(transformation.contains("color:blue"));
+  assert!(!transformation.contains("font-size:12px"));
+}
+
+#[test]
+fn evaluates_conditionals_of_process_env_members() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: {
+          outlineColor: process.env.NODE_ENV === 'production' ? 'transparent' : 'red',
+        },
+      });
+    "#,
+  );
+
+  assert!(transformation.contains("outline-color:transparent"));
+}
+
+#[test]
+fn evaluates_unary_expressions_of_static_constants() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      const size = 16;
+      const isDark = false;
+      export const styles = stylex.create({
+        root: {
+          fontSize: typeof size === 'number' ? size : 12,
+          marginTop: -8,
+          color: !isDark ? 'black' : 'white',
+        },
+      });
+    "#,
+  );
+
+  assert!(transformation.contains("font-size:16px"));
+  assert!(transformation.contains("margin-top:-8px"));
+  assert!(transformation.contains("color:black"));
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn disallows_conditionals_of_non_static_values() {
+  transform(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: {
+          fontSize: isBig ? 16 : 12,
+        },
+      });
+    "#,
+  );
+}
+
+#[test]
+fn evaluates_constants_wrapped_in_type_assertions() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      const size = 4 as const;
+      const color = 'red' as Color;
+      const spacing = { s: 4, m: 8 } as const satisfies Record<string, number>;
+      export const styles = stylex.create({
+        root: {
+          padding: spacing.m,
+          margin: spacing.s * 2,
+          borderWidth: `${size}px`,
+          color: color,
+        },
+      });
+    "#,
+  );
+
+  assert!(transformation.contains("padding:8px"));
+  assert!(transformation.contains("margin:8px"));
+  assert!(transformation.contains("border-width:4px"));
+  assert!(transformation.contains("color:red"));
+}
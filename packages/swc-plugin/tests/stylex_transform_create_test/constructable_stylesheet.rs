@@ -0,0 +1,69 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    named_import_source::RuntimeInjectionTarget, plugin_pass::PluginPass,
+    stylex_options::StyleXOptionsParams,
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection_target: Some(RuntimeInjectionTarget::ConstructableStylesheet),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  injects_rules_into_constructable_stylesheet,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'red',
+        marginInlineStart: 8,
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection_target: Some(RuntimeInjectionTarget::ConstructableStylesheet),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  declares_runtime_once_per_module,
+  r#"
+    import stylex from 'stylex';
+    const fade = stylex.keyframes({
+      from: { opacity: 0 },
+      to: { opacity: 1 },
+    });
+    export const styles = stylex.create({
+      root: {
+        animationName: fade,
+      },
+    });
+    export const other = stylex.create({
+      root: {
+        padding: 4,
+      },
+    });
+  "#
+);
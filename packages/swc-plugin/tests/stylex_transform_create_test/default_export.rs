@@ -0,0 +1,24 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  default_imported_style_object_stays_a_runtime_reference,
+  r#"
+        import stylex from 'stylex';
+        import styles from './styles';
+        const local = stylex.create({
+            root: {
+                color: 'red',
+            },
+        });
+        export const props = stylex.props(local.root, styles.root);
+    "#
+);
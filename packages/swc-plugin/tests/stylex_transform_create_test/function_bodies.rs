@@ -0,0 +1,95 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+use crate::utils::transform::stringify_js;
+
+fn transform(input: &str) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams::default()),
+      )
+    },
+  )
+}
+
+fn transform_namespace(namespace: &str) -> String {
+  transform(&format!(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({{
+        root: {},
+      }});
+    "#,
+    namespace
+  ))
+}
+
+#[test]
+fn compiles_dynamic_styles_returned_from_block_bodies() {
+  assert_eq!(
+    transform_namespace("(width) => { return { width }; }"),
+    transform_namespace("(width) => ({ width })")
+  );
+}
+
+#[test]
+fn inlines_declarations_of_dynamic_style_bodies() {
+  assert_eq!(
+    transform_namespace(
+      "(width, color) => { const doubled = width * 2; const fallback = 'red'; return { width: doubled, color, backgroundColor: fallback }; }"
+    ),
+    transform_namespace(
+      "(width, color) => ({ width: (width * 2), color, backgroundColor: 'red' })"
+    )
+  );
+}
+
+#[test]
+fn keeps_declarations_computed_at_runtime_in_dynamic_style_bodies() {
+  let output = transform_namespace(
+    "(width) => { const doubled = width * 2; const color = getColor(); return { width: doubled, height: doubled, color }; }",
+  );
+
+  assert!(output.contains("const doubled = width * 2;"));
+  assert!(output.contains("const color = getColor();"));
+  assert!(output.contains("return ["));
+  assert!(output.contains(r#""--width": ((val)=>typeof val === "number" ? val + "px" : val != null ? val : "initial")(doubled)"#));
+  assert!(output.contains(r#""--color": color != null ? color : "initial""#));
+}
+
+#[test]
+fn keeps_declarations_apart_from_module_bindings_of_the_same_name() {
+  let output = transform(
+    r#"
+      import stylex from 'stylex';
+      const size = 10;
+      export const styles = stylex.create({
+        root: (width) => { const size = getSize(width); return { width: size }; },
+      });
+    "#,
+  );
+
+  assert!(output.contains("const size = getSize(width);"));
+  assert!(output.contains("width:var(--width,revert)"));
+}
+
+#[test]
+#[should_panic(
+  expected = "A dynamic style function can only declare variables before returning a style object."
+)]
+fn disallows_other_statements_in_dynamic_style_bodies() {
+  transform_namespace(
+    "(width) => { if (width > 100) { return { width: 100 }; } return { width }; }",
+  );
+}
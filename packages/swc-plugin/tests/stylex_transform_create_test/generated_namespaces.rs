@@ -0,0 +1,64 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+use crate::utils::transform::stringify_js;
+
+fn transform(input: &str) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams::default()),
+      )
+    },
+  )
+}
+
+#[test]
+fn evaluates_namespaces_built_from_entries() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      const sizes = [4, 8];
+      export const styles = stylex.create(
+        Object.fromEntries(sizes.map((size) => [size, { width: size, height: size * 2 }]))
+      );
+    "#,
+  );
+
+  assert!(transformation.contains(r#""4": {"#));
+  assert!(transformation.contains(r#""8": {"#));
+  assert!(transformation.contains("width:4px"));
+  assert!(transformation.contains("height:8px"));
+  assert!(transformation.contains("width:8px"));
+  assert!(transformation.contains("height:16px"));
+}
+
+#[test]
+fn evaluates_namespaces_of_literal_entries() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create(
+        Object.fromEntries([
+          ['small', { padding: 4 }],
+          ['large', { padding: 4 * 4 }],
+        ])
+      );
+    "#,
+  );
+
+  assert!(transformation.contains("small: {"));
+  assert!(transformation.contains("large: {"));
+  assert!(transformation.contains("padding:4px"));
+  assert!(transformation.contains("padding:16px"));
+}
@@ -0,0 +1,39 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams::default())
+  ),
+  injects_rules_once_sorted_by_priority,
+  r#"
+    import stylex from 'stylex';
+    export const card = stylex.create({
+      root: {
+        color: 'red',
+        ':hover': {
+          color: 'blue',
+        },
+        margin: 0,
+      },
+    });
+    export const button = stylex.create({
+      root: {
+        color: 'red',
+        marginTop: 4,
+      },
+    });
+  "#
+);
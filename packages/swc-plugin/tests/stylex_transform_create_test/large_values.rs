@@ -0,0 +1,123 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{LargeValues, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::{comments::SingleThreadedComments, sync::Lrc, FileName, SourceMap},
+  ecma::{
+    ast::EsVersion,
+    parser::{lexer::Lexer, Parser, StringInput, Syntax},
+  },
+};
+
+const DATA_URI: &str =
+  "data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciLz4=";
+
+fn source() -> String {
+  format!(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({{
+        hero: {{
+          color: 'red',
+          backgroundImage: {{
+            default: 'url("{0}")',
+            '@media (min-width: 800px)': 'url("{0}")',
+          }},
+        }},
+      }});
+    "#,
+    DATA_URI
+  )
+}
+
+/// The CSS of the rules compiled from `source` with `config`.
+fn transform(source: &str, config: &mut StyleXOptionsParams) -> Vec<String> {
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Anon, source.into());
+
+  let lexer = Lexer::new(
+    Syntax::default(),
+    EsVersion::EsNext,
+    StringInput::from(&*fm),
+    None,
+  );
+
+  let program = Parser::new_from(lexer)
+    .parse_program()
+    .expect("Failed to parse program");
+
+  let mut visitor = ModuleTransformVisitor::new_test(
+    SingleThreadedComments::default(),
+    &PluginPass::default(),
+    Some(config),
+  );
+
+  visitor.fold_program(program);
+
+  visitor
+    .state()
+    .injected_rules()
+    .into_iter()
+    .map(|rule| rule.get_css().to_string())
+    .collect()
+}
+
+#[test]
+fn inlines_large_values_by_default() {
+  let rules = transform(&source(), &mut StyleXOptionsParams::default());
+
+  assert_eq!(rules.iter().filter(|css| css.contains(DATA_URI)).count(), 2);
+}
+
+#[test]
+fn declares_large_values_once_as_custom_properties() {
+  let rules = transform(
+    &source(),
+    &mut StyleXOptionsParams {
+      max_rule_size: Some(64),
+      large_values: Some(LargeValues::Variable),
+      ..StyleXOptionsParams::default()
+    },
+  );
+
+  let declarations = rules
+    .iter()
+    .filter(|css| css.contains(DATA_URI))
+    .collect::<Vec<&String>>();
+
+  assert_eq!(declarations.len(), 1);
+  assert!(declarations[0].starts_with(":root{--x"));
+
+  let name = declarations[0]
+    .trim_start_matches(":root{")
+    .split(':')
+    .next()
+    .unwrap();
+
+  assert_eq!(
+    rules
+      .iter()
+      .filter(|css| css.contains(&format!("background-image:var({})", name)))
+      .count(),
+    2
+  );
+  assert!(rules.iter().any(|css| css.contains("color:red")));
+}
+
+#[test]
+fn keeps_values_under_the_size_inline() {
+  let rules = transform(
+    &source(),
+    &mut StyleXOptionsParams {
+      max_rule_size: Some(1024),
+      large_values: Some(LargeValues::Variable),
+      ..StyleXOptionsParams::default()
+    },
+  );
+
+  assert!(!rules.iter().any(|css| css.starts_with(":root")));
+}
@@ -0,0 +1,151 @@
+use std::path::PathBuf;
+
+use insta::assert_snapshot;
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::parser::{Syntax, TsSyntax},
+};
+
+use crate::utils::transform::stringify_js;
+
+const ROOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/projects/macro-modules");
+
+fn transform(input: &str, macro_module: &str) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass {
+          filename: FileName::Real(PathBuf::from(ROOT_DIR).join("test.js")),
+          ..PluginPass::default()
+        },
+        Some(&mut StyleXOptionsParams {
+          macro_modules: Some(vec![macro_module.to_string()]),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+  )
+}
+
+#[test]
+fn evaluates_calls_of_macros() {
+  assert_snapshot!(transform(
+    r#"
+      import stylex from 'stylex';
+      import border, { rem, space, clampRem } from './macros';
+      export const styles = stylex.create({
+        root: {
+          fontSize: rem(18),
+          padding: space(2),
+          width: clampRem(320, 640),
+          borderBottom: border('red'),
+        },
+      });
+    "#,
+    "./macros"
+  ));
+}
+
+#[test]
+fn passes_evaluated_arguments_to_macros() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      import { rem } from './macros';
+      const base = 8;
+      export const styles = stylex.create({
+        root: {
+          marginTop: rem(base * 2),
+          marginBottom: rem(base),
+        },
+      });
+    "#,
+    "./macros",
+  );
+
+  assert!(transformation.contains("margin-top:1rem"));
+  assert!(transformation.contains("margin-bottom:.5rem"));
+}
+
+#[test]
+#[should_panic(
+  expected = "Functions imported from macro modules must be exported functions with identifier parameters whose body is a single expression or return statement: total"
+)]
+fn rejects_functions_that_are_not_macros() {
+  transform(
+    r#"
+      import stylex from 'stylex';
+      import { total } from './macros';
+      export const styles = stylex.create({
+        root: {
+          zIndex: total(1, 2),
+        },
+      });
+    "#,
+    "./macros",
+  );
+}
+
+#[test]
+#[should_panic(expected = "The macro module could not be read or parsed: ./missing")]
+fn rejects_missing_macro_modules() {
+  transform(
+    r#"
+      import stylex from 'stylex';
+      import { rem } from './missing';
+      export const styles = stylex.create({
+        root: {
+          fontSize: rem(18),
+        },
+      });
+    "#,
+    "./missing",
+  );
+}
+
+#[test]
+fn expands_macros_calling_imported_helpers() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      import { gap } from './spacing';
+      export const styles = stylex.create({
+        root: {
+          rowGap: gap(2),
+        },
+      });
+    "#,
+    "./spacing",
+  );
+
+  assert!(transformation.contains("row-gap:8px"));
+}
+
+#[test]
+#[should_panic(
+  expected = "Macros can't call themselves, directly or through other macros. Found the recursive calls: fib -> fib"
+)]
+fn rejects_recursive_macros() {
+  transform(
+    r#"
+      import stylex from 'stylex';
+      import { fib } from './recursive';
+      export const styles = stylex.create({
+        root: {
+          zIndex: fib(30),
+        },
+      });
+    "#,
+    "./recursive",
+  );
+}
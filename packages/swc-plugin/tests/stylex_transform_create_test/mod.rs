@@ -1,3 +1,18 @@
+mod class_name_manifest;
+mod compat_version;
+mod conditional_values;
+mod constructable_stylesheet;
+mod default_export;
+mod function_bodies;
+mod generated_namespaces;
+mod inject_order;
+mod large_values;
+mod macro_modules;
+mod namespace_order;
+mod object_wrappers;
+mod process_env;
+mod property_aliases;
+mod shadow_dom;
 mod stylex_create_call;
 mod stylex_create_call_pseudo_classes;
 mod stylex_create_call_pseudo_classes_within_properties;
@@ -5,3 +20,7 @@ mod stylex_create_call_pseudo_elements;
 mod stylex_create_call_queries;
 mod stylex_create_call_queries_with_functions;
 mod stylex_create_call_queries_with_properties;
+mod tagged_templates;
+mod token_spreads;
+mod type_annotations;
+mod unit_helpers;
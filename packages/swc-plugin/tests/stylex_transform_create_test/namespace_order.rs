@@ -0,0 +1,88 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+use crate::utils::transform::stringify_js;
+
+const INPUT: &str = r#"
+  import * as stylex from '@stylexjs/stylex';
+  export const styles = stylex.create({
+    zebra: {
+      color: 'red',
+    },
+    apple: (width) => ({
+      width,
+    }),
+    mango: {
+      color: 'blue',
+      backgroundColor: 'white',
+    },
+  });
+  export const props = stylex.props(styles.zebra, styles.mango);
+  export const attrs = stylex.attrs(styles.zebra);
+"#;
+
+fn transform(input: &str) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams::default()),
+      )
+    },
+  )
+}
+
+fn assert_in_order(transformation: &str, keys: &[&str]) {
+  let positions = keys
+    .iter()
+    .map(|key| {
+      transformation
+        .find(key)
+        .unwrap_or_else(|| panic!("{} not found in {}", key, transformation))
+    })
+    .collect::<Vec<usize>>();
+
+  assert!(
+    positions.windows(2).all(|pair| pair[0] < pair[1]),
+    "{:?} out of order in {}",
+    keys,
+    transformation
+  );
+}
+
+#[test]
+fn keeps_namespaces_and_properties_in_source_order() {
+  let transformation = transform(INPUT);
+
+  assert_in_order(&transformation, &["zebra: {", "apple: (", "mango: {"]);
+
+  let mango = &transformation[transformation.find("mango: {").unwrap()..];
+
+  assert_in_order(mango, &["color:", "backgroundColor:"]);
+}
+
+#[test]
+fn compiles_props_and_attrs_into_the_same_keys() {
+  let transformation = transform(INPUT);
+
+  assert!(transformation.contains("export const props = {\n    className:"));
+  assert!(transformation.contains("export const attrs = {\n    class:"));
+}
+
+#[test]
+fn compiles_the_same_output_on_every_build() {
+  let transformation = transform(INPUT);
+
+  for _ in 0..5 {
+    assert_eq!(transform(INPUT), transformation);
+  }
+}
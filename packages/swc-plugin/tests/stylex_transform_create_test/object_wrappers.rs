@@ -0,0 +1,45 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  create_in_default_exported_object,
+  r#"
+        import stylex from 'stylex';
+        export default {
+            styles: stylex.create({
+                root: {
+                    color: 'red',
+                },
+            }),
+        };
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  create_in_object_wrapper_resolves_statically,
+  r#"
+        import stylex from 'stylex';
+        const config = {
+            styles: stylex.create({
+                root: {
+                    color: 'red',
+                },
+            }),
+        };
+        export default config;
+        export const className = stylex.props(config.styles.root);
+    "#
+);
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_transform},
+};
+
+fn env() -> Option<HashMap<String, String>> {
+  Some(HashMap::from([
+    ("NODE_ENV".to_string(), "development".to_string()),
+    ("BRAND_COLOR".to_string(), "tomato".to_string()),
+  ]))
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      env: env(),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  evaluates_process_env_members,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      root: {
+        color: process.env.BRAND_COLOR,
+        outlineWidth: (process.env.NODE_ENV === 'development') * 2,
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      env: env(),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  prefers_local_process_bindings,
+  r#"
+    import stylex from 'stylex';
+    const process = { env: { BRAND_COLOR: 'rebeccapurple' } };
+    export const styles = stylex.create({
+      root: {
+        color: process.env.BRAND_COLOR,
+      },
+    });
+  "#
+);
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn leaves_process_env_members_without_env_option() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: {
+          color: process.env.BRAND_COLOR,
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
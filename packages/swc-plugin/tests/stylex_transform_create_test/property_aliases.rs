@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::{comments::SingleThreadedComments, sync::Lrc, FileName, SourceMap},
+  ecma::{
+    ast::EsVersion,
+    parser::{lexer::Lexer, Parser, StringInput, Syntax},
+  },
+};
+
+const SOURCE: &str = r#"
+  import stylex from 'stylex';
+  export const styles = stylex.create({
+    label: {
+      textAlignVertical: 'middle',
+      ':hover': {
+        textAlignVertical: 'top',
+      },
+    },
+  });
+"#;
+
+/// The CSS of the rules compiled from `SOURCE` with `config`, and the
+/// properties compiled through an alias.
+fn transform(config: &mut StyleXOptionsParams) -> (Vec<String>, IndexMap<String, String>) {
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Anon, SOURCE.into());
+
+  let lexer = Lexer::new(
+    Syntax::default(),
+    EsVersion::EsNext,
+    StringInput::from(&*fm),
+    None,
+  );
+
+  let program = Parser::new_from(lexer)
+    .parse_program()
+    .expect("Failed to parse program");
+
+  let mut visitor = ModuleTransformVisitor::new_test(
+    SingleThreadedComments::default(),
+    &PluginPass::default(),
+    Some(config),
+  );
+
+  visitor.fold_program(program);
+
+  let rules = visitor
+    .state()
+    .injected_rules()
+    .into_iter()
+    .map(|rule| rule.get_css().to_string())
+    .collect();
+
+  (rules, visitor.state().aliased_properties.clone())
+}
+
+#[test]
+fn compiles_aliased_properties_as_their_standard_property() {
+  let (rules, aliased_properties) = transform(&mut StyleXOptionsParams {
+    property_aliases: Some(HashMap::from([(
+      "textAlignVertical".to_string(),
+      "verticalAlign".to_string(),
+    )])),
+    ..StyleXOptionsParams::default()
+  });
+
+  assert!(rules
+    .iter()
+    .any(|css| css.contains("{vertical-align:middle}")));
+  assert!(rules
+    .iter()
+    .any(|css| css.contains(":hover{vertical-align:top}")));
+  assert!(!rules.iter().any(|css| css.contains("text-align-vertical")));
+
+  assert_eq!(
+    aliased_properties,
+    IndexMap::from([("textAlignVertical".to_string(), "verticalAlign".to_string())])
+  );
+}
+
+#[test]
+fn leaves_properties_as_written_without_aliases() {
+  let (rules, aliased_properties) = transform(&mut StyleXOptionsParams::default());
+
+  assert!(rules
+    .iter()
+    .any(|css| css.contains("{text-align-vertical:middle}")));
+  assert!(aliased_properties.is_empty());
+}
@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{ShadowDom, StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::test,
+  },
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      filename: FileName::Real(PathBuf::from("/app/components/button.shadow.js")),
+      ..PluginPass::default()
+    },
+    Some(&mut StyleXOptionsParams {
+      shadow_dom: Some(ShadowDom {
+        file_suffixes: vec![".shadow".to_string()],
+        host_context: None,
+      }),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  scopes_rules_of_matching_files_to_host,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'red',
+        ':hover': {
+          color: 'blue',
+        },
+        '@media (min-width: 800px)': {
+          padding: 8,
+        },
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      filename: FileName::Real(PathBuf::from("/app/components/button.js")),
+      ..PluginPass::default()
+    },
+    Some(&mut StyleXOptionsParams {
+      shadow_dom: Some(ShadowDom {
+        file_suffixes: vec![".shadow".to_string()],
+        host_context: None,
+      }),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  keeps_rules_of_other_files_unscoped,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'red',
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      filename: FileName::Real(PathBuf::from("/app/tokens.stylex.js")),
+      ..PluginPass::default()
+    },
+    Some(&mut StyleXOptionsParams {
+      shadow_dom: Some(ShadowDom {
+        file_suffixes: vec![],
+        host_context: Some(".dark".to_string()),
+      }),
+      unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  scopes_rules_of_files_with_pragma_to_host_context,
+  r#"
+    /** @stylex-shadow-dom */
+    import stylex from 'stylex';
+    export const vars = stylex.defineVars({
+      accent: 'blue',
+    });
+    export const styles = stylex.create({
+      root: {
+        marginInlineStart: 4,
+      },
+    });
+  "#
+);
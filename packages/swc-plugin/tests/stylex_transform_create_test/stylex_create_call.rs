@@ -693,3 +693,107 @@ test!(
     });
     "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(tr.comments.clone(), &PluginPass::default(), None),
+  transforms_namespaces_spread_from_a_local_object,
+  r#"
+    import stylex from 'stylex';
+    const shared = {
+      active: {
+        color: 'blue',
+      },
+      default: {
+        color: 'green',
+      },
+    };
+    export const styles = stylex.create({
+      default: {
+        color: 'red',
+      },
+      ...shared,
+      disabled: {
+        ...shared.active,
+        opacity: 0.5,
+      },
+    });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_unicode_and_escaped_identifiers,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            'ключ': {
+                '--Ñame': 'red',
+                '--my var': 'blue',
+                '--\\31 x': 'green',
+                color: 'var(--Ñame)',
+                '::before': {
+                    content: "'→ ✓ 😀'",
+                },
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_undefined_values_like_null,
+  r#"
+        import stylex from 'stylex';
+        export const styles = stylex.create({
+            default: {
+                color: undefined,
+                width: 10,
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_undefined_values_in_conditions_and_fallbacks,
+  r#"
+        import stylex from 'stylex';
+        export const styles = stylex.create({
+            default: {
+                backgroundColor: {
+                    default: 'red',
+                    ':hover': undefined,
+                },
+                color: [undefined, 'blue'],
+            },
+        });
+    "#
+);
@@ -0,0 +1,59 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+use crate::utils::transform::stringify_js;
+
+fn transform(input: &str) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams::default()),
+      )
+    },
+  )
+}
+
+#[test]
+fn evaluates_string_raw_tagged_templates() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      const spacing = 8;
+      const gap = String.raw`${spacing / 2}px`;
+      export const styles = stylex.create({
+        root: {
+          padding: String.raw`${spacing}px`,
+          gap: gap,
+        },
+      });
+    "#,
+  );
+
+  assert!(transformation.contains("padding:8px"));
+  assert!(transformation.contains("gap:4px"));
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn disallows_other_tagged_templates() {
+  transform(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: {
+          padding: px`8`,
+        },
+      });
+    "#,
+  );
+}
@@ -0,0 +1,93 @@
+use std::path::PathBuf;
+
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::parser::{Syntax, TsSyntax},
+};
+
+use crate::utils::transform::stringify_js;
+
+const ROOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/projects/token-spreads");
+
+fn transform(input: &str) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass {
+          filename: FileName::Real(PathBuf::from(ROOT_DIR).join("test.js")),
+          ..PluginPass::default()
+        },
+        Some(&mut StyleXOptionsParams::default()),
+      )
+    },
+  )
+}
+
+#[test]
+fn evaluates_spreads_of_imported_objects() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      import { typography } from './tokens';
+      export const styles = stylex.create({
+        root: {
+          ...typography.body,
+          color: 'red',
+        },
+      });
+    "#,
+  );
+
+  assert!(transformation.contains("font-size:16px"));
+  assert!(transformation.contains("line-height:1.5"));
+  assert!(transformation.contains("color:red"));
+}
+
+#[test]
+fn later_properties_override_imported_spreads() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      import * as tokens from './tokens';
+      export const styles = stylex.create({
+        root: {
+          ...tokens.typography.heading,
+          fontSize: 24,
+        },
+      });
+    "#,
+  );
+
+  assert!(transformation.contains("font-size:24px"));
+  assert!(!transformation.contains("font-size:32px"));
+  assert!(transformation.contains("font-weight:700"));
+}
+
+#[test]
+fn evaluates_spreads_of_default_exports() {
+  let transformation = transform(
+    r#"
+      import stylex from 'stylex';
+      import positions from './tokens';
+      export const styles = stylex.create({
+        root: {
+          position: 'absolute',
+          ...positions.inset,
+        },
+      });
+    "#,
+  );
+
+  assert!(transformation.contains("top:0"));
+  assert!(transformation.contains("left:0"));
+}
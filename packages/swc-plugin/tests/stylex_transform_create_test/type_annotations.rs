@@ -0,0 +1,60 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  ignores_type_arguments_and_satisfies_on_create,
+  r#"
+        import stylex from 'stylex';
+        export const styles = stylex.create<Styles>({
+            root: {
+                color: 'red',
+            },
+        }) satisfies Styles;
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  ignores_type_assertions_in_create_and_props_arguments,
+  r#"
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({
+            root: {
+                color: 'red' as const,
+            },
+        } as const);
+        export const className = stylex.props<unknown>(styles.root!);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  ignores_type_arguments_and_satisfies_on_keyframes,
+  r#"
+        import stylex from 'stylex';
+        export const name = stylex.keyframes<Keyframes>({
+            from: {
+                backgroundColor: 'red',
+            },
+            to: {
+                backgroundColor: 'blue',
+            }
+        }) satisfies string;
+    "#
+);
@@ -0,0 +1,133 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptionsParams, UnitHelpers},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_transform},
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      unit_helpers: Some(UnitHelpers::default()),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  converts_pixels_with_unit_helpers,
+  r#"
+    import stylex from 'stylex';
+    const gap = 12;
+    export const styles = stylex.create({
+      root: {
+        fontSize: rem(18),
+        letterSpacing: em(2 * 4),
+        margin: rem(gap * 2),
+        padding: `${rem(8)} ${rem(4)}`,
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      unit_helpers: Some(UnitHelpers {
+        rem: Some("toRem".to_string()),
+        em: None,
+        root_font_size: 10.0,
+      }),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  uses_configured_names_and_root_font_size,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      root: {
+        fontSize: toRem(15),
+      },
+    });
+  "#
+);
+
+#[test]
+#[should_panic(
+  expected = "Unit helpers take a single number of pixels, e.g. rem(24). Check the arguments of: rem()"
+)]
+fn rejects_non_numeric_arguments() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          unit_helpers: Some(UnitHelpers::default()),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: {
+          fontSize: rem('16px'),
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Unit helpers take a single number of pixels, e.g. rem(24). Check the arguments of: em()"
+)]
+fn rejects_multiple_arguments() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          unit_helpers: Some(UnitHelpers::default()),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: {
+          fontSize: em(16, 20),
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
@@ -0,0 +1,53 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::test,
+  },
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/stylex/packages/TestTheme.stylex.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(false),
+      unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+      light_dark_vars: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  compiles_color_scheme_vars_into_light_dark,
+  r#"
+        import * as foo from 'stylex';
+        export const buttonTheme = foo.defineVars({
+            bgColor: {
+                default: 'blue',
+                '@media (prefers-color-scheme: dark)': 'lightblue',
+                '@media print': 'white',
+            },
+            bgColorDisabled: {
+                default: 'grey',
+                '@media (prefers-color-scheme: dark)': 'rgba(0, 0, 0, 0.8)',
+            },
+            cornerRadius: 10,
+            fgColor: {
+                default: 'pink',
+            },
+        });
+    "#
+);
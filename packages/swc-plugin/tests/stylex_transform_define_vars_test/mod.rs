@@ -1 +1,4 @@
+mod light_dark_vars;
+mod package_scope;
 mod stylex_transform_define_vars;
+mod vars_cache;
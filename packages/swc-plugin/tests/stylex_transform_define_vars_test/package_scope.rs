@@ -0,0 +1,85 @@
+use std::{fs, path::PathBuf};
+
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::parser::{Syntax, TsSyntax},
+};
+
+use crate::utils::transform::stringify_js;
+
+const ROOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/projects/package-scope");
+
+fn transform(file: &str, hash_package_scope: bool) -> String {
+  let path = PathBuf::from(ROOT_DIR).join(file);
+
+  stringify_js(
+    &fs::read_to_string(&path).unwrap(),
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass {
+          cwd: None,
+          filename: FileName::Real(path.clone()),
+        },
+        Some(&mut StyleXOptionsParams {
+          hash_package_scope: Some(hash_package_scope),
+          unstable_module_resolution: Some(StyleXOptions::get_common_js_module_resolution(Some(
+            ROOT_DIR.to_string(),
+          ))),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+  )
+}
+
+fn var_name(output: &str) -> String {
+  let start = output.find("var(--").unwrap() + 4;
+  let end = start + output[start..].find(')').unwrap();
+
+  output[start..end].to_string()
+}
+
+#[test]
+fn scopes_hashes_by_package_version() {
+  assert_eq!(
+    transform("packages/app/Button.js", false),
+    transform("packages/app-next/Button.js", false)
+  );
+  assert_ne!(
+    transform("packages/app/Button.js", true),
+    transform("packages/app-next/Button.js", true)
+  );
+  assert_ne!(
+    transform("packages/app/Button.js", true),
+    transform("packages/app/Button.js", false)
+  );
+}
+
+#[test]
+fn scopes_imported_vars_by_the_package_defining_them() {
+  let tokens = transform("packages/tokens/tokens.stylex.js", true);
+  let unscoped_tokens = transform("packages/tokens/tokens.stylex.js", false);
+
+  for file in ["packages/app/Button.js", "packages/app-next/Button.js"] {
+    let styles = transform(file, true);
+
+    assert!(
+      tokens.contains(&format!("{}:blue", var_name(&styles))),
+      "{}",
+      tokens
+    );
+    assert!(!unscoped_tokens.contains(&format!("{}:blue", var_name(&styles))));
+  }
+}
@@ -691,3 +691,29 @@ test!(
     });
   "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/stylex/packages/TestTheme.stylex.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(false),
+      unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  transforms_template_literal_references_to_keyframes,
+  r#"
+        import stylex from 'stylex';
+        export const motion = stylex.defineVars({
+            fade: `${stylex.keyframes({ from: { opacity: 0 }, to: { opacity: 1 } })} 1s ease`,
+        });
+    "#
+);
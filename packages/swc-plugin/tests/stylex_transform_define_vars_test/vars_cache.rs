@@ -0,0 +1,82 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+    vars_cache::VarsCache,
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::parser::{Syntax, TsSyntax},
+};
+
+use crate::utils::transform::stringify_js;
+
+fn transform(input: &str, filename: &str) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass {
+          cwd: None,
+          filename: FileName::Real(filename.into()),
+        },
+        Some(&mut StyleXOptionsParams {
+          cache_vars: Some(true),
+          unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+  )
+}
+
+const VARS: &str = r#"
+  import stylex from 'stylex';
+  export const colors = stylex.defineVars({
+    accent: 'blue',
+  });
+"#;
+
+#[test]
+fn reuses_var_groups_of_unchanged_files() {
+  let filename = "/stylex/packages/CachedColors.stylex.js";
+
+  let first = transform(VARS, filename);
+  let second = transform(VARS, filename);
+
+  assert_eq!(first, second);
+  assert!(first.contains(":blue;"));
+
+  assert!(VarsCache::global().invalidate(filename));
+  assert!(!VarsCache::global().invalidate(filename));
+}
+
+#[test]
+fn recompiles_var_groups_of_edited_files() {
+  let filename = "/stylex/packages/EditedColors.stylex.js";
+
+  let first = transform(VARS, filename);
+  let edited = transform(&VARS.replace("'blue'", "'red'"), filename);
+
+  assert!(first.contains(":blue;"));
+  assert!(edited.contains(":red;"));
+  assert!(!edited.contains(":blue;"));
+
+  VarsCache::global().invalidate(filename);
+}
+
+#[test]
+fn invalidates_windows_paths() {
+  let filename = "C:\\stylex\\packages\\WindowsColors.stylex.js";
+
+  transform(VARS, filename);
+
+  assert!(VarsCache::global().invalidate("C:/stylex/packages/WindowsColors.stylex.js"));
+}
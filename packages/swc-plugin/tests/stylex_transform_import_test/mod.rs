@@ -1,3 +1,6 @@
 mod stylex_validation_import_test;
 mod transform_import_aliases;
+mod transform_with_css_import;
 mod transform_with_custom_imports;
+mod transform_with_disabled_apis;
+mod transform_with_file_filters;
@@ -0,0 +1,86 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{CssImport, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(false),
+      css_import: Some(CssImport::Enabled(true)),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  adds_css_import_after_directives,
+  r#"
+        "use client";
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+        });
+        export const className = stylex.props(styles.root);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(false),
+      css_import: Some(CssImport::Specifier("virtual:app.css".to_string())),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  adds_custom_css_import_once,
+  r#"
+        import 'virtual:app.css';
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+        });
+        export const className = stylex.props(styles.root);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(false),
+      css_import: Some(CssImport::Enabled(true)),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  skips_css_import_without_rules,
+  r#"
+        import * as stylex from '@stylexjs/stylex';
+        export const className = stylex.props(null);
+    "#
+);
@@ -0,0 +1,132 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptionsParams, Transforms},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      transforms: Some(Transforms {
+        keyframes: false,
+        ..Transforms::default()
+      }),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  disabled_keyframes_pass_through,
+  r#"
+        import * as stylex from '@stylexjs/stylex';
+        const fadeIn = stylex.keyframes({
+            from: { opacity: 0 },
+            to: { opacity: 1 },
+        });
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+        });
+        export const className = stylex.props(styles.root);
+        export { fadeIn };
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      transforms: Some(Transforms {
+        props: false,
+        attrs: false,
+        ..Transforms::default()
+      }),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  disabled_props_and_attrs_pass_through,
+  r#"
+        import stylex from '@stylexjs/stylex';
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+        });
+        export const props = stylex.props(styles.root);
+        export const attrs = stylex.attrs(styles.root);
+        export const legacy = stylex(styles.root);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      transforms: Some(Transforms {
+        create: false,
+        ..Transforms::default()
+      }),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  disabled_create_passes_through,
+  r#"
+        import { create, props } from '@stylexjs/stylex';
+        const styles = create({
+            root: {
+                color: 'red',
+            },
+        });
+        export const className = props(styles.root);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      transforms: Some(Transforms {
+        define_vars: false,
+        create_theme: false,
+        ..Transforms::default()
+      }),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  disabled_define_vars_and_create_theme_pass_through,
+  r#"
+        import stylex from '@stylexjs/stylex';
+        export const vars = stylex.defineVars({
+            color: 'red',
+        });
+        export const theme = stylex.createTheme(vars, {
+            color: 'blue',
+        });
+    "#
+);
@@ -0,0 +1,124 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::test,
+  },
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: Some("/project".into()),
+      filename: FileName::Real("/project/node_modules/ui/button.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      exclude: Some(vec!["**/node_modules/**".to_string()]),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  excluded_files_pass_through,
+  r#"
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+        });
+        export const className = stylex.props(styles.root);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: Some("/project".into()),
+      filename: FileName::Real("/project/src/app.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      include: Some(vec!["src/**".to_string()]),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  transforms_files_matching_a_relative_include,
+  r#"
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+        });
+        export const className = stylex.props(styles.root);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: Some("/project".into()),
+      filename: FileName::Real("/project/scripts/build.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      include: Some(vec!["src/**".to_string()]),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  files_not_included_pass_through,
+  r#"
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+        });
+        export const className = stylex.props(styles.root);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: Some("/project".into()),
+      filename: FileName::Real("/project/src/__generated__/styles.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      include: Some(vec!["src/**".to_string()]),
+      exclude: Some(vec!["**/__generated__/**".to_string()]),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  exclude_takes_precedence_over_include,
+  r#"
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+        });
+        export const className = stylex.props(styles.root);
+    "#
+);
@@ -112,3 +112,71 @@ test!(
     stylex(styles.foo, styles.bar);
     "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    let mut config = StyleXOptionsParams {
+      runtime_injection: Some(true),
+      style_resolution: Some(StyleResolution::LegacyExpandShorthands),
+
+      ..StyleXOptionsParams::default()
+    };
+
+    ModuleTransformVisitor::new_test_styles(
+      tr.comments.clone(),
+      &PluginPass::default(),
+      Some(&mut config),
+    )
+  },
+  stylex_call_with_two_value_logical_shorthands,
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({
+      foo: {
+        paddingInline: '4px 8px',
+        marginBlock: '0 auto',
+      },
+    });
+    stylex(styles.foo);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    let mut config = StyleXOptionsParams {
+      runtime_injection: Some(true),
+      style_resolution: Some(StyleResolution::LegacyExpandShorthands),
+
+      ..StyleXOptionsParams::default()
+    };
+
+    ModuleTransformVisitor::new_test_styles(
+      tr.comments.clone(),
+      &PluginPass::default(),
+      Some(&mut config),
+    )
+  },
+  stylex_call_with_two_value_logical_shorthand_collisions,
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({
+      foo: {
+        marginInline: 4,
+      },
+
+      bar: {
+        marginInline: '2px 8px',
+        paddingBlock: '1px 2px',
+      },
+    });
+    stylex(styles.foo, styles.bar);
+    "#
+);
@@ -0,0 +1,7 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+<div class="card" {...{
+    class: "x1e2nbdu"
+}}/>;
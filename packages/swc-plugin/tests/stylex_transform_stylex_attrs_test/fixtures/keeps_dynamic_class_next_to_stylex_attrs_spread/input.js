@@ -0,0 +1,7 @@
+import * as stylex from 'stylex';
+const styles = stylex.create({
+  red: {
+    color: 'red',
+  },
+});
+<div class={className} {...stylex.attrs(styles.red)} />;
@@ -0,0 +1,110 @@
+use std::path::PathBuf;
+
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{AttrsConflicts, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_fixture, FixtureTestConfig},
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      attrs_conflicts: Some(AttrsConflicts::Merge),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  merges_class_before_stylex_attrs_spread,
+  r#"
+        import * as stylex from 'stylex';
+        const styles = stylex.create({
+            red: {
+                color: 'red',
+            }
+        });
+        <div class="card" {...stylex.attrs(styles.red)} />;
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      attrs_conflicts: Some(AttrsConflicts::Merge),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  merges_class_after_stylex_attrs_spread,
+  r#"
+        import * as stylex from 'stylex';
+        const styles = stylex.create({
+            red: {
+                color: 'red',
+            }
+        });
+        <div {...stylex.attrs(styles.red)} class="card" />;
+    "#
+);
+
+/// Runs the fixture `name`, whose expected warnings are kept in its
+/// `output.stderr`.
+fn test_warning_fixture(name: &str, attrs_conflicts: AttrsConflicts) {
+  let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    .join("tests/stylex_transform_stylex_attrs_test/fixtures")
+    .join(name);
+
+  test_fixture(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    &|tester| {
+      ModuleTransformVisitor::new_test_styles(
+        tester.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          attrs_conflicts: Some(attrs_conflicts),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    &dir.join("input.js"),
+    &dir.join("output.js"),
+    FixtureTestConfig {
+      allow_error: true,
+      ..Default::default()
+    },
+  );
+}
+
+#[test]
+fn keeps_dynamic_class_next_to_stylex_attrs_spread() {
+  test_warning_fixture(
+    "keeps_dynamic_class_next_to_stylex_attrs_spread",
+    AttrsConflicts::Merge,
+  );
+}
+
+#[test]
+fn keeps_class_next_to_stylex_attrs_spread_when_warning() {
+  test_warning_fixture(
+    "keeps_class_next_to_stylex_attrs_spread_when_warning",
+    AttrsConflicts::Warn,
+  );
+}
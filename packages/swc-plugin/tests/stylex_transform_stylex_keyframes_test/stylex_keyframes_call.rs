@@ -184,3 +184,126 @@ test!(
         });
     "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  keeps_every_declaration_of_a_frame,
+  r#"
+        import stylex from 'stylex';
+        export const name = stylex.keyframes({
+            from: { opacity: 0, color: 'red' },
+            to: { opacity: 1, color: 'blue' },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  allows_template_literal_references_to_keyframes_in_conditions,
+  r#"
+        import stylex from 'stylex';
+        const fadeIn = stylex.keyframes({
+            from: {
+                opacity: 0,
+            },
+            to: {
+                opacity: 1,
+            },
+        });
+
+        const styles = stylex.create({
+            default: {
+                animation: {
+                    default: `${fadeIn} 1s ease`,
+                    '@media (prefers-reduced-motion)': `${fadeIn} 0s`,
+                },
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  allows_template_literal_references_to_keyframes_object_members,
+  r#"
+        import stylex from 'stylex';
+        const fadeIn = stylex.keyframes({
+            from: {
+                opacity: 0,
+            },
+            to: {
+                opacity: 1,
+            },
+        });
+        const spin = stylex.keyframes({
+            from: {
+                rotate: '0deg',
+            },
+            to: {
+                rotate: '360deg',
+            },
+        });
+        const animations = { fadeIn, spin };
+
+        const styles = stylex.create({
+            default: {
+                animation: `${animations.spin} 1s linear infinite, ${animations.fadeIn} 1s`,
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  allows_nested_template_literal_references_to_keyframes,
+  r#"
+        import stylex from 'stylex';
+        const fadeIn = stylex.keyframes({
+            from: {
+                opacity: 0,
+            },
+            to: {
+                opacity: 1,
+            },
+        });
+        const slow = `${fadeIn} 3s`;
+
+        const styles = stylex.create({
+            default: {
+                animation: `${slow} ease-in`,
+            },
+        });
+    "#
+);
@@ -65,3 +65,85 @@ test!(
         stylex.props(styles.default);
     "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  stylex_create_kept_when_some_references_stay_dynamic,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                color: 'red',
+            },
+            active: {
+                color: 'blue',
+            },
+        });
+        export const className = stylex.props(styles.default);
+        export function getStyles() {
+            return styles;
+        }
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  unrelated_unused_declarations_are_kept,
+  r#"
+        import stylex from 'stylex';
+        import { track } from './analytics';
+        const unused = track('render');
+        const styles = stylex.create({
+            default: {
+                color: 'red',
+            },
+        });
+        stylex.props(styles.default);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  default_exported_component_is_kept,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                color: 'red',
+            },
+        });
+        const Component = () => <div {...stylex.props(styles.default)} />;
+        export default Component;
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  stylex_create_kept_when_default_exported,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                color: 'red',
+            },
+        });
+        stylex.props(styles.default);
+        export default styles;
+    "#
+);
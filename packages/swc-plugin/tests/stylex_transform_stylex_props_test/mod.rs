@@ -1,5 +1,6 @@
 mod even_when_stylex_calls_come_first;
 mod keep_stylex_create_when_needed;
+mod props_passed_to_helpers;
 mod setting_custom_import_paths;
 mod specific_edge_case_bugs;
 mod stylex_props_call;
@@ -0,0 +1,106 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  props_spread_into_helper_call,
+  r#"
+        import * as stylex from '@stylexjs/stylex';
+        import { cx } from './cx';
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+            active: {
+                color: 'blue',
+            },
+        });
+        export function Button({ active, style }) {
+            return <button {...cx(stylex.props(styles.root), active && stylex.props(styles.active), style)} />;
+        }
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  props_members_and_bindings_passed_to_helpers,
+  r#"
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+        });
+        export function Button() {
+            const rootProps = stylex.props(styles.root);
+            return <button className={cx(stylex.props(styles.root).className, 'button')} {...mergeProps(rootProps, { id: 'a' })} />;
+        }
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      gen_conditional_classes: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  conditional_props_passed_to_helper_call,
+  r#"
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+            active: {
+                color: 'blue',
+            },
+        });
+        export function Button({ active }) {
+            return <button {...cx(stylex.props(styles.root, active && styles.active))} />;
+        }
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  dynamic_props_passed_to_helper_call_keep_only_used_styles,
+  r#"
+        import * as stylex from '@stylexjs/stylex';
+        const styles = stylex.create({
+            root: {
+                color: 'red',
+            },
+            unused: {
+                color: 'blue',
+            },
+        });
+        export function Button({ style }) {
+            return <button {...cx(stylex.props(styles.root, style))} />;
+        }
+    "#
+);
@@ -416,3 +416,79 @@ test!(
         stylex.props(styles.default);
     "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  stylex_call_with_spread_arguments,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+            },
+            active: {
+                color: 'blue',
+            },
+        });
+        stylex.props(styles.default, ...xstyle);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  stylex_call_with_spread_array_elements,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+            },
+            active: {
+                color: 'blue',
+            },
+        });
+        stylex.props([styles.default, ...xstyle, styles.active]);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  stylex_calls_with_spread_arguments_share_the_import,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+            },
+            active: {
+                color: 'blue',
+            },
+        });
+        stylex.props(styles.default, ...xstyle);
+        stylex.props(styles.active, ...ystyle);
+    "#
+);
@@ -273,3 +273,234 @@ test!(
         stylex.props([styles.red, isActive && styles.blue]);
     "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  stylex_call_with_pseudo_and_base_values_of_the_same_property,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            red: {
+                color: 'red',
+            },
+            blue: {
+                color: 'blue',
+            },
+            hover: {
+                ':hover': {
+                    color: 'green',
+                },
+            },
+        });
+        stylex.props(styles.red, styles.hover);
+        stylex.props(styles.hover, styles.red);
+        stylex.props(styles.red, styles.hover, styles.blue);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  stylex_call_with_the_same_conditions_in_a_different_order,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            mediaHover: {
+                '@media (min-width: 800px)': {
+                    ':hover': {
+                        color: 'red',
+                    },
+                },
+            },
+            hoverMedia: {
+                ':hover': {
+                    '@media (min-width: 800px)': {
+                        color: 'blue',
+                    },
+                },
+            },
+        });
+        stylex.props(styles.mediaHover, styles.hoverMedia);
+        stylex.props(styles.hoverMedia, styles.mediaHover);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      gen_conditional_classes: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  stylex_call_with_conditions_and_the_same_conditions_in_a_different_order,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            hover: {
+                ':hover': {
+                    color: 'green',
+                },
+            },
+            mediaHover: {
+                '@media (min-width: 800px)': {
+                    ':hover': {
+                        color: 'red',
+                    },
+                },
+            },
+            hoverMedia: {
+                ':hover': {
+                    '@media (min-width: 800px)': {
+                        color: 'blue',
+                    },
+                },
+            },
+        });
+        stylex.props([styles.hover, styles.mediaHover, isActive && styles.hoverMedia]);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      gen_conditional_classes: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  stylex_call_with_ternary_and_nullish_branch,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+            },
+            active: {
+                color: 'blue',
+            },
+            inactive: {
+                color: 'gray',
+            }
+        });
+        stylex.props([styles.default, isActive ? styles.active : null]);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      gen_conditional_classes: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  stylex_call_with_ternary_inside_logical_and,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+            },
+            active: {
+                color: 'blue',
+            },
+            inactive: {
+                color: 'gray',
+            }
+        });
+        stylex.props([styles.default, isEnabled && (isActive ? styles.active : styles.inactive)]);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      gen_conditional_classes: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  stylex_call_with_logical_and_inside_ternary,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+            },
+            active: {
+                color: 'blue',
+            },
+            inactive: {
+                color: 'gray',
+            }
+        });
+        stylex.props(isEnabled ? isActive && styles.active : styles.inactive);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      gen_conditional_classes: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  stylex_call_with_nested_logical_and,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+            },
+            active: {
+                color: 'blue',
+            },
+            inactive: {
+                color: 'gray',
+            }
+        });
+        stylex.props(styles.default, isEnabled && isActive && styles.active);
+    "#
+);
@@ -136,3 +136,34 @@ test!(
         stylex.props([styles.default, isActive && styles.active]);
     "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/html/js/Ünïcode Page.react.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      dev: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  stylex_call_produces_dev_class_names_with_unicode_namespaces,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            'ключ': {
+                color: 'red',
+            },
+            'two words': {
+                backgroundColor: 'blue',
+            },
+        });
+        stylex.props(styles['ключ'], styles['two words']);
+    "#
+);
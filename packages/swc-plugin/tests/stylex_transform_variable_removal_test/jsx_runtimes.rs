@@ -0,0 +1,198 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+use crate::utils::transform::stringify_js;
+
+const STYLES: &str = r#"
+  import * as stylex from '@stylexjs/stylex';
+  const styles = stylex.create({
+    base: {
+      color: 'red',
+    },
+    extra: {
+      padding: 4,
+    },
+    unused: {
+      margin: 8,
+    },
+  });
+"#;
+
+fn transform(input: &str) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams::default()),
+      )
+    },
+  )
+}
+
+/// The compiled `styles` declaration of a transformed module.
+fn compiled_styles(transformation: &str) -> &str {
+  let start = transformation
+    .find("const styles")
+    .expect("Styles should be kept");
+  let end = transformation[start..]
+    .find("};")
+    .expect("Styles should be an object");
+
+  &transformation[start..start + end]
+}
+
+/// The same `element` in JSX and compiled by each runtime, with the
+/// `props` object of the compiled calls.
+fn runtimes(element: &str, props: &str) -> Vec<(&'static str, String)> {
+  vec![
+    (
+      "jsx",
+      format!("{}\nexport const App = () => {};", STYLES, element),
+    ),
+    (
+      "automatic",
+      format!(
+        "import {{ jsx as _jsx }} from 'react/jsx-runtime';\n{}\nexport const App = () => _jsx(Card, {});",
+        STYLES, props
+      ),
+    ),
+    (
+      "automatic development",
+      format!(
+        "import {{ jsxDEV as _jsxDEV }} from 'react/jsx-dev-runtime';\n{}\nexport const App = () => _jsxDEV(Card, {}, void 0, false);",
+        STYLES, props
+      ),
+    ),
+    (
+      "classic",
+      format!(
+        "import React from 'react';\n{}\nexport const App = () => React.createElement(Card, {});",
+        STYLES, props
+      ),
+    ),
+    (
+      "import source pragma",
+      format!(
+        "/** @jsxImportSource preact */\nimport {{ jsx as _jsx }} from 'preact/jsx-runtime';\n{}\nexport const App = () => _jsx(Card, {});",
+        STYLES, props
+      ),
+    ),
+    (
+      "classic pragma",
+      format!(
+        "/** @jsx h */\nimport {{ h }} from 'preact';\n{}\nexport const App = () => h(Card, {});",
+        STYLES, props
+      ),
+    ),
+  ]
+}
+
+fn assert_equivalent(element: &str, props: &str, kept: &[&str], removed: &[&str]) {
+  let jsx_transformation = transform(&runtimes(element, props)[0].1);
+  let jsx_styles = compiled_styles(&jsx_transformation);
+
+  for (runtime, source) in runtimes(element, props) {
+    let transformation = transform(&source);
+    let styles = compiled_styles(&transformation);
+
+    assert_eq!(styles, jsx_styles, "{} runtime", runtime);
+
+    for namespace in kept {
+      assert!(
+        styles.contains(&format!("{}: {{", namespace)),
+        "{} runtime should keep {}",
+        runtime,
+        namespace
+      );
+    }
+
+    for namespace in removed {
+      assert!(
+        !styles.contains(&format!("{}: {{", namespace)),
+        "{} runtime should remove {}",
+        runtime,
+        namespace
+      );
+    }
+  }
+}
+
+#[test]
+fn keeps_static_members_passed_as_props() {
+  assert_equivalent(
+    "<Card style={styles.base} />",
+    "{ style: styles.base }",
+    &["base"],
+    &["extra", "unused"],
+  );
+}
+
+#[test]
+fn keeps_every_namespace_of_style_objects_passed_whole() {
+  assert_equivalent(
+    "<Card styles={styles} />",
+    "{ styles: styles }",
+    &["base", "extra", "unused"],
+    &[],
+  );
+}
+
+#[test]
+fn keeps_every_namespace_of_style_objects_passed_as_shorthands() {
+  assert_equivalent(
+    "<Card styles={styles} />",
+    "{ styles }",
+    &["base", "extra", "unused"],
+    &[],
+  );
+}
+
+#[test]
+fn keeps_every_namespace_of_dynamic_members_passed_as_props() {
+  assert_equivalent(
+    "<Card style={styles[variant]} />",
+    "{ style: styles[variant] }",
+    &["base", "extra", "unused"],
+    &[],
+  );
+}
+
+#[test]
+fn keeps_every_namespace_of_spread_style_objects() {
+  assert_equivalent(
+    "<Card {...styles} />",
+    "{ ...styles }",
+    &["base", "extra", "unused"],
+    &[],
+  );
+}
+
+#[test]
+fn compiles_props_calls_inside_compiled_elements() {
+  for (runtime, source) in runtimes(
+    "<Card {...stylex.props(styles.base, styles.extra)} />",
+    "{ ...stylex.props(styles.base, styles.extra) }",
+  ) {
+    let transformation = transform(&source);
+
+    assert!(
+      !transformation.contains("stylex.props"),
+      "{} runtime",
+      runtime
+    );
+    assert!(
+      transformation.contains(r#"className: "x1e2nbdu "#),
+      "{} runtime",
+      runtime
+    );
+  }
+}
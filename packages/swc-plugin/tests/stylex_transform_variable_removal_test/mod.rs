@@ -1 +1,3 @@
+mod jsx_runtimes;
 mod optimization_removes_styles_variable_when_not_needed;
+mod styles_passed_as_props;
@@ -0,0 +1,102 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+use crate::utils::transform::stringify_js;
+
+fn transform(input: &str) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          warn_escaping_styles: Some(true),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+  )
+}
+
+#[test]
+fn keeps_only_namespaces_passed_as_props() {
+  let transformation = transform(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      const styles = stylex.create({
+        card: {
+          color: 'red',
+        },
+        compact: {
+          padding: 4,
+        },
+        unused: {
+          margin: 8,
+        },
+      });
+      export const App = () => (
+        <>
+          <Card style={styles.card} />
+          <Card style={styles['compact']} />
+        </>
+      );
+    "#,
+  );
+
+  assert!(transformation.contains("card: {"));
+  assert!(transformation.contains("compact: {"));
+  assert!(!transformation.contains("unused: {"));
+}
+
+#[test]
+fn keeps_every_namespace_of_style_objects_passed_whole() {
+  let transformation = transform(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      const styles = stylex.create({
+        base: {
+          color: 'red',
+        },
+        extra: {
+          padding: 4,
+        },
+      });
+      export const App = () => (
+        <div {...stylex.props(styles.base)}>
+          <Card styles={styles} />
+        </div>
+      );
+    "#,
+  );
+
+  assert!(transformation.contains("extra: {"));
+}
+
+#[test]
+fn keeps_every_namespace_of_dynamic_members_passed_as_props() {
+  let transformation = transform(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      const styles = stylex.create({
+        primary: {
+          color: 'blue',
+        },
+        secondary: {
+          color: 'gray',
+        },
+      });
+      export const Button = ({ variant }) => <Card style={styles[variant]} />;
+    "#,
+  );
+
+  assert!(transformation.contains("primary: {"));
+  assert!(transformation.contains("secondary: {"));
+}
@@ -1,2 +1,3 @@
 mod stylex_validation_create_dynamic_test;
 mod stylex_validation_create;
+mod stylex_validation_create_recovery;
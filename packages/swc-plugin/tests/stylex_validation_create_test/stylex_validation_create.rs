@@ -56,6 +56,70 @@ fn must_be_called_at_top_level() {
   )
 }
 
+#[test]
+#[should_panic(
+  expected = "The result of stylex.create() must be bound to a constant and cannot be reassigned: styles"
+)]
+fn must_not_be_reassigned() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+
+            let styles = stylex.create({
+                default: {
+                    color: 'red',
+                },
+            });
+            styles = other;
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "The result of stylex.create() must be bound to a constant and cannot be reassigned: styles"
+)]
+fn must_not_be_updated() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+
+            let styles = stylex.create({
+                default: {
+                    color: 'red',
+                },
+            });
+            styles++;
+        "#,
+    r#""#,
+    false,
+  )
+}
+
 #[test]
 #[should_panic(expected = "stylex.create() can only accept a style object.")]
 fn its_only_argument_must_be_a_single_object_non_object() {
@@ -107,7 +171,9 @@ fn its_only_argument_must_be_a_single_object_argument() {
 }
 
 #[test]
-#[should_panic(expected = "stylex() should have 1 argument.")]
+#[should_panic(
+  expected = "stylex.create() expects a single style object, remove the extra arguments."
+)]
 fn its_only_argument_must_be_a_single_object_illegal_argument_length() {
   test_transform(
     Syntax::Typescript(TsSyntax {
@@ -131,6 +197,33 @@ fn its_only_argument_must_be_a_single_object_illegal_argument_length() {
   )
 }
 
+#[test]
+#[should_panic(
+  expected = "stylex.create() expects a single style object, remove the extra arguments."
+)]
+fn its_only_argument_must_be_a_single_object_extra_non_object_argument() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+
+            const styles = stylex.create({ default: { color: 'red' } }, 'extra');
+        "#,
+    r#""#,
+    false,
+  )
+}
+
 test!(
   Default::default(),
   |tr| {
@@ -237,6 +330,64 @@ fn properties_must_be_a_static_value() {
   )
 }
 
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn values_must_not_be_unsupported_expressions() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+
+            const styles = stylex.create({
+                root: {
+                    backgroundColor: new Color('red'),
+                }
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn values_must_not_be_arithmetic_on_non_numbers() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+
+            const styles = stylex.create({
+                root: {
+                    width: 'full' * 2,
+                }
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
 test!(
   Default::default(),
   |tr| {
@@ -576,3 +727,305 @@ test!(
         });
     "#
 );
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn invalid_spread_of_non_static_namespaces() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const styles = stylex.create({
+                default: {
+                    color: 'red',
+                },
+                ...getStyles(),
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "NaN and Infinity are not valid CSS values. Check for divisions by zero or arithmetic with undefined. Found: Infinity"
+)]
+fn values_must_not_be_infinity() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+            export const styles = stylex.create({
+                default: {
+                    width: Infinity,
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "NaN and Infinity are not valid CSS values. Check for divisions by zero or arithmetic with undefined. Found: NaN"
+)]
+fn values_must_not_be_nan() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+            export const styles = stylex.create({
+                default: {
+                    opacity: NaN,
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "NaN and Infinity are not valid CSS values. Check for divisions by zero or arithmetic with undefined. Found: Infinity"
+)]
+fn values_must_not_divide_by_zero() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+            export const styles = stylex.create({
+                default: {
+                    width: 100 / 0,
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "NaN and Infinity are not valid CSS values. Check for divisions by zero or arithmetic with undefined. Found: NaN"
+)]
+fn values_must_not_do_arithmetic_with_undefined() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+            export const styles = stylex.create({
+                default: {
+                    width: 10 * undefined,
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "NaN and Infinity are not valid CSS values. Check for divisions by zero or arithmetic with undefined. Found: Infinity"
+)]
+fn template_literal_values_must_not_interpolate_infinity() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+            export const styles = stylex.create({
+                default: {
+                    width: `${Infinity}px`,
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Functions are only allowed as dynamic styles at the namespace value level, e.g. `root: (color) => ({ color })`."
+)]
+fn style_values_must_not_be_functions() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+            export const styles = stylex.create({
+                default: {
+                    color: () => 'red',
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Functions are only allowed as dynamic styles at the namespace value level, e.g. `root: (color) => ({ color })`."
+)]
+fn conditional_style_values_must_not_be_functions() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+            export const styles = stylex.create({
+                default: {
+                    color: {
+                        default: 'red',
+                        ':hover': function () { return 'blue'; },
+                    },
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Functions are only allowed as dynamic styles at the namespace value level, e.g. `root: (color) => ({ color })`."
+)]
+fn style_values_must_not_be_classes() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+            export const styles = stylex.create({
+                default: {
+                    color: class {},
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Functions are only allowed as dynamic styles at the namespace value level, e.g. `root: (color) => ({ color })`."
+)]
+fn dynamic_styles_must_be_arrow_functions() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from "@stylexjs/stylex";
+            export const styles = stylex.create({
+                default: function (color) {
+                    return { color };
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
@@ -0,0 +1,385 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::{comments::SingleThreadedComments, sync::Lrc, FileName, SourceMap},
+  ecma::{
+    ast::{
+      Decl, EsVersion, Expr, ImportSpecifier, Lit, Module, ModuleDecl, ModuleExportName,
+      ModuleItem, ObjectLit, PropName,
+    },
+    parser::{lexer::Lexer, Parser, StringInput, Syntax},
+    visit::FoldWith,
+  },
+};
+
+fn transform(source: &str) -> Module {
+  transform_with_options(source, None)
+}
+
+/// Transforms `source` creating the namespaces that can't be compiled at
+/// runtime.
+fn transform_with_runtime_create(source: &str) -> Module {
+  transform_with_options(
+    source,
+    Some(&mut StyleXOptionsParams {
+      runtime_create_fallback: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  )
+}
+
+fn transform_with_options(source: &str, config: Option<&mut StyleXOptionsParams>) -> Module {
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Anon, source.into());
+
+  let lexer = Lexer::new(
+    Syntax::default(),
+    EsVersion::EsNext,
+    StringInput::from(&*fm),
+    None,
+  );
+
+  let module = Parser::new_from(lexer)
+    .parse_module()
+    .expect("Failed to parse module");
+
+  module.fold_with(&mut ModuleTransformVisitor::new_test_styles(
+    SingleThreadedComments::default(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/app/src/Component.js".into()),
+    },
+    config,
+  ))
+}
+
+/// The exported `styles` object.
+fn exported_styles(module: &Module) -> &ObjectLit {
+  module
+    .body
+    .iter()
+    .find_map(|item| match item {
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => match &export.decl {
+        Decl::Var(var_decl) => var_decl.decls.first()?.init.as_deref()?.as_object(),
+        _ => None,
+      },
+      _ => None,
+    })
+    .expect("The styles must be compiled to an object")
+}
+
+/// The namespaces of `object`, by name.
+fn get_namespaces(object: &ObjectLit) -> Vec<(String, Expr)> {
+  object
+    .props
+    .iter()
+    .filter_map(|prop| prop.as_prop()?.as_key_value())
+    .map(|key_value| {
+      let name = match &key_value.key {
+        PropName::Ident(ident) => ident.sym.to_string(),
+        PropName::Str(str) => str.value.to_string(),
+        _ => panic!("Unexpected namespace key"),
+      };
+
+      (name, *key_value.value.clone())
+    })
+    .collect()
+}
+
+/// The index, callee and argument of each runtime `create` call spread into
+/// `styles`.
+fn get_runtime_creates(styles: &ObjectLit) -> Vec<(usize, &Expr, &ObjectLit)> {
+  styles
+    .props
+    .iter()
+    .enumerate()
+    .filter_map(|(index, prop)| {
+      let call = prop.as_spread()?.expr.as_call()?;
+
+      Some((
+        index,
+        call.callee.as_expr()?.as_ref(),
+        call.args.first()?.expr.as_object()?,
+      ))
+    })
+    .collect()
+}
+
+fn get_color(namespace: &ObjectLit) -> &Expr {
+  namespace
+    .props
+    .iter()
+    .filter_map(|prop| prop.as_prop()?.as_key_value())
+    .find(|key_value| {
+      key_value
+        .key
+        .as_ident()
+        .is_some_and(|ident| &*ident.sym == "color")
+    })
+    .map(|key_value| key_value.value.as_ref())
+    .expect("The namespace must set a color")
+}
+
+fn is_class_name(value: &Expr) -> bool {
+  matches!(value, Expr::Lit(Lit::Str(class_name)) if class_name.value.starts_with('x'))
+}
+
+/// The `import` declarations of `module` printed as `<specifier> from <source>`.
+fn get_imports(module: &Module) -> Vec<String> {
+  module
+    .body
+    .iter()
+    .filter_map(|item| item.as_module_decl()?.as_import())
+    .flat_map(|import| {
+      import.specifiers.iter().map(|specifier| {
+        let specifier = match specifier {
+          ImportSpecifier::Default(default) => default.local.sym.to_string(),
+          ImportSpecifier::Namespace(namespace) => format!("* as {}", namespace.local.sym),
+          ImportSpecifier::Named(named) => match &named.imported {
+            Some(ModuleExportName::Ident(imported)) => {
+              format!("{{ {} as {} }}", imported.sym, named.local.sym)
+            }
+            _ => format!("{{ {} }}", named.local.sym),
+          },
+        };
+
+        format!("{} from {}", specifier, import.src.value)
+      })
+    })
+    .collect()
+}
+
+#[test]
+fn keeps_the_failing_namespace_as_written() {
+  let module = transform(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        default: {
+          color: 'red',
+        },
+        dynamic: {
+          color: getColor(),
+        },
+        hovered: {
+          color: 'blue',
+        },
+      });
+    "#,
+  );
+
+  let styles = exported_styles(&module);
+  let namespaces = get_namespaces(styles);
+
+  assert_eq!(
+    namespaces
+      .iter()
+      .map(|(name, _)| name.as_str())
+      .collect::<Vec<_>>(),
+    ["default", "dynamic", "hovered"]
+  );
+
+  for (name, value) in &namespaces {
+    let color = get_color(value.as_object().unwrap());
+
+    match name.as_str() {
+      "dynamic" => assert!(color.is_call()),
+      _ => assert!(is_class_name(color)),
+    }
+  }
+
+  assert!(get_runtime_creates(styles).is_empty());
+}
+
+#[test]
+fn compiles_the_other_namespaces_when_one_fails() {
+  let module = transform_with_runtime_create(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        default: {
+          color: 'red',
+        },
+        dynamic: {
+          color: getColor(),
+        },
+        hovered: {
+          color: 'blue',
+        },
+      });
+    "#,
+  );
+
+  let styles = exported_styles(&module);
+  let namespaces = get_namespaces(styles);
+
+  assert_eq!(
+    namespaces
+      .iter()
+      .map(|(name, _)| name.as_str())
+      .collect::<Vec<_>>(),
+    ["default", "hovered"]
+  );
+
+  for (_, value) in &namespaces {
+    assert!(is_class_name(get_color(value.as_object().unwrap())));
+  }
+
+  let runtime_creates = get_runtime_creates(styles);
+
+  assert_eq!(runtime_creates.len(), 1);
+
+  let (index, _, runtime_styles) = runtime_creates[0];
+  let runtime_namespaces = get_namespaces(runtime_styles);
+
+  assert_eq!(index, 1);
+  assert_eq!(runtime_namespaces.len(), 1);
+  assert_eq!(runtime_namespaces[0].0, "dynamic");
+  assert!(get_color(runtime_namespaces[0].1.as_object().unwrap()).is_call());
+}
+
+#[test]
+fn compiles_the_other_namespaces_when_one_is_invalid() {
+  let module = transform_with_runtime_create(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        default: {
+          color: 'red',
+        },
+        invalid: {
+          color: {
+            hover: 'blue',
+          },
+        },
+      });
+    "#,
+  );
+
+  let styles = exported_styles(&module);
+  let namespaces = get_namespaces(styles);
+
+  assert_eq!(namespaces.len(), 1);
+  assert_eq!(namespaces[0].0, "default");
+
+  let (index, _, runtime_styles) = get_runtime_creates(styles)[0];
+
+  assert_eq!(index, 1);
+  assert_eq!(get_namespaces(runtime_styles)[0].0, "invalid");
+}
+
+#[test]
+fn creates_each_failing_namespace_in_its_place_with_a_runtime_import() {
+  let module = transform_with_runtime_create(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        dynamic: {
+          color: getColor(),
+        },
+        default: {
+          color: 'red',
+        },
+        hovered: {
+          color: getHoverColor(),
+        },
+      });
+    "#,
+  );
+
+  let styles = exported_styles(&module);
+  let runtime_creates = get_runtime_creates(styles);
+
+  assert_eq!(
+    runtime_creates
+      .iter()
+      .map(|(index, _, runtime_styles)| (*index, get_namespaces(runtime_styles)[0].0.clone()))
+      .collect::<Vec<_>>(),
+    [(0, "dynamic".to_string()), (2, "hovered".to_string())]
+  );
+
+  for (_, callee, _) in &runtime_creates {
+    let callee = callee.as_member().expect("The callee must be a member");
+
+    assert_eq!(&*callee.obj.as_ident().unwrap().sym, "_stylex");
+  }
+
+  assert_eq!(
+    get_imports(&module)
+      .iter()
+      .filter(|import| *import == "_stylex from stylex")
+      .count(),
+    1
+  );
+}
+
+#[test]
+fn shares_the_runtime_import_of_named_create_functions() {
+  let module = transform_with_runtime_create(
+    r#"
+      import { create } from 'stylex';
+      export const styles = create({
+        default: {
+          color: 'red',
+        },
+        dynamic: {
+          color: getColor(),
+        },
+      });
+      export const otherStyles = create({
+        default: {
+          color: 'blue',
+        },
+        dynamic: {
+          color: getColor(),
+        },
+      });
+    "#,
+  );
+
+  let imports = get_imports(&module);
+
+  assert_eq!(
+    imports
+      .iter()
+      .filter(|import| *import == "{ create as _create } from stylex")
+      .count(),
+    1
+  );
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn fails_when_every_namespace_fails() {
+  transform(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        default: {
+          color: getColor(),
+        },
+        hovered: {
+          color: getHoverColor(),
+        },
+      });
+    "#,
+  );
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn fails_when_a_spread_hides_the_failing_namespace() {
+  transform(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        default: {
+          color: 'red',
+        },
+        ...getStyles(),
+      });
+    "#,
+  );
+}
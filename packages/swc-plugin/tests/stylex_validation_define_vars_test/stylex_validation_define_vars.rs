@@ -1,4 +1,10 @@
-use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
 use swc_core::{
   common::FileName,
   ecma::{
@@ -33,6 +39,32 @@ fn must_be_bound_to_a_named_export_const() {
   )
 }
 
+#[test]
+#[should_panic(
+  expected = "The return value of stylex.defineVars() must be bound to a named export."
+)]
+fn must_not_be_a_default_export() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            export default stylex.defineVars({});
+        "#,
+    r#""#,
+    false,
+  )
+}
+
 #[test]
 #[should_panic(expected = "stylex.create calls must be bound to a bare variable.")]
 fn must_be_bound_to_a_named_export() {
@@ -232,6 +264,97 @@ test!(
     "#
 );
 
+#[test]
+#[should_panic(
+  expected = "The initial value of a typed variable must match its syntax: \"labelColor\" is declared as <color> but its initial value \"10px\" is invalid at 0..4"
+)]
+fn typed_var_initial_value_must_match_its_syntax_color() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass {
+          cwd: None,
+          filename: FileName::Real("/stylex/packages/TestTheme.stylex.js".into()),
+        },
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            export const styles = stylex.defineVars({
+                labelColor: stylex.types.color('10px'),
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "The initial value of a typed variable must match its syntax: \"cornerRadius\" is declared as <length> but its initial value \"10px 20px\" is invalid at 5..9"
+)]
+fn typed_var_initial_value_must_match_its_syntax_length() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass {
+          cwd: None,
+          filename: FileName::Real("/stylex/packages/TestTheme.stylex.js".into()),
+        },
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            export const styles = stylex.defineVars({
+                cornerRadius: stylex.types.length({
+                    default: '10px 20px',
+                    '@media print': '5px',
+                }),
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/stylex/packages/TestTheme.stylex.js".into()),
+    },
+    None
+  ),
+  typed_var_initial_values_matching_their_syntax,
+  r#"
+        import stylex from 'stylex';
+        export const styles = stylex.defineVars({
+            labelColor: stylex.types.color('rgb(0 0 0)'),
+            cornerRadius: stylex.types.length('calc(4px + 1rem)'),
+            opacity: stylex.types.number(0.5),
+            rotation: stylex.types.angle('45deg'),
+            transform: stylex.types.transformList('rotate(45deg) scale(2)'),
+        });
+    "#
+);
+
 #[test]
 #[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
 fn values_must_be_static_number_or_string_or_keyframes_in_stylex_define_vars_var() {
@@ -283,3 +406,64 @@ fn values_must_be_static_number_or_string_or_keyframes_in_stylex_define_vars_fn(
     false,
   )
 }
+
+#[test]
+#[should_panic(
+  expected = "stylex.defineVars() is only allowed in files matching the configured validVarsFileSuffixes."
+)]
+fn must_be_defined_in_a_valid_vars_file() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass {
+          cwd: None,
+          filename: FileName::Real("/stylex/packages/Button.tsx".into()),
+        },
+        Some(&mut StyleXOptionsParams {
+          valid_vars_file_suffixes: Some(vec![".stylex".to_string(), ".vars.ts".to_string()]),
+          unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            export const styles = stylex.defineVars({
+                labelColor: 'red',
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/stylex/packages/TestTheme.vars.ts".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      valid_vars_file_suffixes: Some(vec![".stylex".to_string(), ".vars.ts".to_string()]),
+      unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  can_be_defined_in_a_file_matching_a_valid_vars_suffix,
+  r#"
+        import stylex from 'stylex';
+        export const styles = stylex.defineVars({
+            labelColor: 'red',
+        });
+    "#
+);
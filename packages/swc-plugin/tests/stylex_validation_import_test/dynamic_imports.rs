@@ -0,0 +1,142 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_transform},
+};
+
+#[test]
+#[should_panic(
+  expected = "Dynamic imports cannot be statically evaluated. Import the values with a top level import declaration instead of: import('./tokens.stylex')"
+)]
+fn dynamic_import_bound_to_a_variable() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const tokens = await import('./tokens.stylex');
+            export const styles = stylex.create({
+                red: { color: tokens.colors.red },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Dynamic imports cannot be statically evaluated. Import the values with a top level import declaration instead of: import('./tokens.stylex')"
+)]
+fn destructured_dynamic_import() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const { colors } = await import('./tokens.stylex');
+            export const styles = stylex.create({
+                red: { color: colors.red },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Dynamic imports cannot be statically evaluated. Import the values with a top level import declaration instead of: import('./tokens.stylex')"
+)]
+fn inline_dynamic_import() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            export const styles = stylex.create({
+                red: { color: (await import('./tokens.stylex')).colors.red },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Dynamic imports cannot be statically evaluated. Import the values with a top level import declaration instead of: import('./tokens.stylex')"
+)]
+fn dynamic_import_passed_to_create_theme() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const tokens = await import('./tokens.stylex');
+            export const theme = stylex.createTheme(tokens.colors, {
+                red: 'blue',
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    ModuleTransformVisitor::new_test_styles(
+      tr.comments.clone(),
+      &PluginPass::default(),
+      None,
+    )
+  },
+  ignores_dynamic_imports_not_used_in_styles,
+  r#"
+    import stylex from 'stylex';
+    const { Button } = await import('./Button');
+    export const styles = stylex.create({
+        red: { color: 'red' },
+    });
+  "#
+);
@@ -1 +1,2 @@
+mod dynamic_imports;
 mod stylex_imports;
@@ -0,0 +1,29 @@
+#!/usr/bin/env sh
+
+# Runs the criterion benchmarks of the package.
+#
+# Usage:
+#   pnpm run bench                   # run and save results as the "main" baseline
+#   pnpm run bench -- compare        # compare against the "main" baseline
+#   pnpm run bench -- compare <name> # compare against a named baseline
+#   pnpm run bench -- save <name>    # save results as a named baseline
+
+script_dir="$(cd "$(dirname "$0")" && pwd)"
+
+# shellcheck disable=SC1091
+. "$script_dir"/../../functions.sh
+
+command="${1:-save}"
+baseline="${2:-main}"
+
+case "$command" in
+save)
+  cargo bench --benches -- --save-baseline "$baseline" || handle_error "Failed to run the benchmarks"
+  ;;
+compare)
+  cargo bench --benches -- --baseline "$baseline" || handle_error "Failed to compare against the $baseline baseline"
+  ;;
+*)
+  handle_error "Unknown bench command $command, expected save or compare"
+  ;;
+esac